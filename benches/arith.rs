@@ -0,0 +1,61 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Arithmetic benchmarks.
+//!
+//! These cover the four basic operations and square roots for both `Double` and `Quad`.
+//!
+//! ```text
+//! cargo bench --bench arith
+//! ```
+//!
+//! All of the benchmarks use Criterion, which compares each run with the one before it.
+//! To check a change that's meant to make something faster (or that might make something
+//! slower), save the results from before the change as a named baseline and compare
+//! against that:
+//!
+//! ```text
+//! cargo bench -- --save-baseline main
+//! # ...make changes...
+//! cargo bench -- --baseline main
+//! ```
+//!
+//! Baselines are stored under `target/criterion`. They're specific to the machine they
+//! were recorded on, so they aren't checked in.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use qd::{Double, Quad};
+use std::hint::black_box;
+
+fn double(c: &mut Criterion) {
+    let x = Double::PI;
+    let y = Double::E;
+    c.bench_function("Double + Double", |b| {
+        b.iter(|| black_box(x) + black_box(y))
+    });
+    c.bench_function("Double - Double", |b| {
+        b.iter(|| black_box(x) - black_box(y))
+    });
+    c.bench_function("Double * Double", |b| {
+        b.iter(|| black_box(x) * black_box(y))
+    });
+    c.bench_function("Double / Double", |b| {
+        b.iter(|| black_box(x) / black_box(y))
+    });
+    c.bench_function("Double::sqrt", |b| b.iter(|| black_box(x).sqrt()));
+}
+
+fn quad(c: &mut Criterion) {
+    let x = Quad::PI;
+    let y = Quad::E;
+    c.bench_function("Quad + Quad", |b| b.iter(|| black_box(x) + black_box(y)));
+    c.bench_function("Quad - Quad", |b| b.iter(|| black_box(x) - black_box(y)));
+    c.bench_function("Quad * Quad", |b| b.iter(|| black_box(x) * black_box(y)));
+    c.bench_function("Quad / Quad", |b| b.iter(|| black_box(x) / black_box(y)));
+    c.bench_function("Quad::sqrt", |b| b.iter(|| black_box(x).sqrt()));
+}
+
+criterion_group!(benches, double, quad);
+criterion_main!(benches);
@@ -0,0 +1,66 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Multiplication benchmarks.
+//!
+//! These exist primarily to compare the FMA and non-FMA implementations of the underlying
+//! `two_prod` and `two_sqr` primitives. Each benchmark's ID ends in the implementation
+//! that it measured (`fma` or `no_fma`), so run them once normally and once with the
+//! `no_fma` feature enabled, and the two sets of results sit side by side in the `mul`
+//! group under `target/criterion`:
+//!
+//! ```text
+//! cargo bench --bench mul
+//! cargo bench --bench mul --features no_fma
+//! ```
+//!
+//! If the `fma` target feature is enabled (e.g. with `-C target-cpu=native` on a processor
+//! that has FMA), the FMA implementation is used regardless of the `no_fma` feature.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use qd::eft::{two_prod, two_sqr};
+use qd::{Double, Quad};
+use std::hint::black_box;
+
+fn mul(c: &mut Criterion) {
+    let feature = if cfg!(any(target_feature = "fma", not(feature = "no_fma"))) {
+        "fma"
+    } else {
+        "no_fma"
+    };
+    let mut group = c.benchmark_group("mul");
+
+    let x = std::f64::consts::PI;
+    let y = std::f64::consts::E;
+    group.bench_function(BenchmarkId::new("two_prod", feature), |b| {
+        b.iter(|| two_prod(black_box(x), black_box(y)))
+    });
+    group.bench_function(BenchmarkId::new("two_sqr", feature), |b| {
+        b.iter(|| two_sqr(black_box(x)))
+    });
+
+    let x = Double::PI;
+    let y = Double::E;
+    group.bench_function(BenchmarkId::new("Double * Double", feature), |b| {
+        b.iter(|| black_box(x) * black_box(y))
+    });
+    group.bench_function(BenchmarkId::new("Double::sqr", feature), |b| {
+        b.iter(|| black_box(x).sqr())
+    });
+
+    let x = Quad::PI;
+    let y = Quad::E;
+    group.bench_function(BenchmarkId::new("Quad * Quad", feature), |b| {
+        b.iter(|| black_box(x) * black_box(y))
+    });
+    group.bench_function(BenchmarkId::new("Quad::sqr", feature), |b| {
+        b.iter(|| black_box(x).sqr())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, mul);
+criterion_main!(benches);
@@ -0,0 +1,112 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Batched arithmetic benchmarks.
+//!
+//! These compare the operators of `Doublex4` with the same operations done on four
+//! `Double`s one at a time, and the slice functions with loops over the slices.
+//! Throughput is reported in `Double`s. The difference is largest with
+//! `-C target-cpu=native` on a processor with 256-bit vectors and FMA:
+//!
+//! ```text
+//! cargo bench --bench simd --features simd
+//! RUSTFLAGS="-C target-cpu=native" cargo bench --bench simd --features simd
+//! ```
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use qd::simd::{self, Doublex4};
+use qd::Double;
+use std::hint::black_box;
+
+fn simd(c: &mut Criterion) {
+    let mut group = c.benchmark_group("simd");
+    group.throughput(Throughput::Elements(4));
+
+    let a = [Double::PI, Double::E, Double::LN_2, Double::LN_10];
+    let b = [
+        Double::SQRT_2,
+        Double::FRAC_PI_3,
+        Double::LOG2_E,
+        Double::FRAC_1_PI,
+    ];
+    let scalar = |f: fn(Double, Double) -> Double| {
+        move || {
+            let (a, b) = (black_box(a), black_box(b));
+            [f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])]
+        }
+    };
+    let (x, y) = (Doublex4::from(a), Doublex4::from(b));
+
+    group.bench_function("4 x (Double + Double)", |b| b.iter(scalar(|a, b| a + b)));
+    group.bench_function("Doublex4 + Doublex4", |b| {
+        b.iter(|| black_box(x) + black_box(y))
+    });
+    group.bench_function("4 x (Double - Double)", |b| b.iter(scalar(|a, b| a - b)));
+    group.bench_function("Doublex4 - Doublex4", |b| {
+        b.iter(|| black_box(x) - black_box(y))
+    });
+    group.bench_function("4 x (Double * Double)", |b| b.iter(scalar(|a, b| a * b)));
+    group.bench_function("Doublex4 * Doublex4", |b| {
+        b.iter(|| black_box(x) * black_box(y))
+    });
+    group.bench_function("4 x (Double / Double)", |b| b.iter(scalar(|a, b| a / b)));
+    group.bench_function("Doublex4 / Doublex4", |b| {
+        b.iter(|| black_box(x) / black_box(y))
+    });
+
+    group.finish();
+}
+
+const LEN: usize = 1024;
+
+fn slices(c: &mut Criterion) {
+    let mut group = c.benchmark_group("simd-slices");
+    group.throughput(Throughput::Elements(LEN as u64));
+
+    let xs: Vec<Double> = (1..=LEN)
+        .map(|i| Double::PI / Double::from(i as f64))
+        .collect();
+    let ys: Vec<Double> = (1..=LEN)
+        .map(|i| Double::E * Double::from(i as f64))
+        .collect();
+    let zs = vec![Double::LN_2; LEN];
+    let mut out = vec![Double::ZERO; LEN];
+
+    group.bench_function("Double slice +", |b| {
+        b.iter(|| {
+            for i in 0..LEN {
+                out[i] = black_box(xs[i]) + ys[i];
+            }
+        })
+    });
+    group.bench_function("add_slices", |b| {
+        b.iter(|| simd::add_slices(black_box(&xs), &ys, &mut out))
+    });
+    group.bench_function("Double slice *", |b| {
+        b.iter(|| {
+            for i in 0..LEN {
+                out[i] = black_box(xs[i]) * ys[i];
+            }
+        })
+    });
+    group.bench_function("mul_slices", |b| {
+        b.iter(|| simd::mul_slices(black_box(&xs), &ys, &mut out))
+    });
+    group.bench_function("Double slice * +", |b| {
+        b.iter(|| {
+            for i in 0..LEN {
+                out[i] = black_box(xs[i]) * ys[i] + zs[i];
+            }
+        })
+    });
+    group.bench_function("fma_slices", |b| {
+        b.iter(|| simd::fma_slices(black_box(&xs), &ys, &zs, &mut out))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, simd, slices);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Summation benchmarks.
+//!
+//! These compare summing a slice with the `Sum` implementations of `Double` and `Quad`,
+//! which renormalize after every addition, against summing it with the accumulators in
+//! the `accumulator` module, which renormalize only once at the end.
+//!
+//! ```text
+//! cargo bench --bench sum
+//! ```
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use qd::accumulator::{DoubleAccumulator, QuadAccumulator};
+use qd::{Double, Quad};
+use std::hint::black_box;
+
+const TERMS: u32 = 10_000;
+
+fn sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum");
+    group.throughput(Throughput::Elements(TERMS.into()));
+
+    let doubles: Vec<Double> = (1..=TERMS).map(|i| Double::ONE / Double::from(i)).collect();
+    group.bench_function("Double Sum", |b| {
+        b.iter(|| black_box(&doubles).iter().sum::<Double>())
+    });
+    group.bench_function("DoubleAccumulator", |b| {
+        b.iter(|| {
+            let mut acc = DoubleAccumulator::new();
+            acc.extend(black_box(&doubles));
+            acc.sum()
+        })
+    });
+
+    let quads: Vec<Quad> = (1..=TERMS).map(|i| Quad::ONE / Quad::from(i)).collect();
+    group.bench_function("Quad Sum", |b| {
+        b.iter(|| black_box(&quads).iter().sum::<Quad>())
+    });
+    group.bench_function("QuadAccumulator", |b| {
+        b.iter(|| {
+            let mut acc = QuadAccumulator::new();
+            acc.extend(black_box(&quads));
+            acc.sum()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, sum);
+criterion_main!(benches);
@@ -0,0 +1,41 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Parsing and formatting benchmarks.
+//!
+//! These cover converting `Double`s and `Quad`s to and from strings.
+//!
+//! ```text
+//! cargo bench --bench text
+//! ```
+//!
+//! See `benches/arith.rs` for saving and comparing against baselines.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use qd::{Double, Quad};
+use std::hint::black_box;
+
+fn double(c: &mut Criterion) {
+    let s = Double::PI.to_string();
+    let x = Double::PI;
+    c.bench_function("Double parse", |b| {
+        b.iter(|| black_box(s.as_str()).parse::<Double>())
+    });
+    c.bench_function("Double to_string", |b| b.iter(|| black_box(x).to_string()));
+    c.bench_function("Double {:e}", |b| b.iter(|| format!("{:e}", black_box(x))));
+}
+
+fn quad(c: &mut Criterion) {
+    let s = Quad::PI.to_string();
+    let x = Quad::PI;
+    c.bench_function("Quad parse", |b| {
+        b.iter(|| black_box(s.as_str()).parse::<Quad>())
+    });
+    c.bench_function("Quad to_string", |b| b.iter(|| black_box(x).to_string()));
+    c.bench_function("Quad {:e}", |b| b.iter(|| format!("{:e}", black_box(x))));
+}
+
+criterion_group!(benches, double, quad);
+criterion_main!(benches);
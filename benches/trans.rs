@@ -0,0 +1,42 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Transcendental function benchmarks.
+//!
+//! These cover the exponential, logarithmic, and trigonometric functions for both `Double`
+//! and `Quad`.
+//!
+//! ```text
+//! cargo bench --bench trans
+//! ```
+//!
+//! See `benches/arith.rs` for saving and comparing against baselines.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use qd::{Double, Quad};
+use std::hint::black_box;
+
+fn double(c: &mut Criterion) {
+    let x = Double::E;
+    c.bench_function("Double::exp", |b| b.iter(|| black_box(x).exp()));
+    c.bench_function("Double::ln", |b| b.iter(|| black_box(x).ln()));
+    c.bench_function("Double::sin", |b| b.iter(|| black_box(x).sin()));
+    c.bench_function("Double::cos", |b| b.iter(|| black_box(x).cos()));
+    c.bench_function("Double::tan", |b| b.iter(|| black_box(x).tan()));
+    c.bench_function("Double::atan", |b| b.iter(|| black_box(x).atan()));
+}
+
+fn quad(c: &mut Criterion) {
+    let x = Quad::E;
+    c.bench_function("Quad::exp", |b| b.iter(|| black_box(x).exp()));
+    c.bench_function("Quad::ln", |b| b.iter(|| black_box(x).ln()));
+    c.bench_function("Quad::sin", |b| b.iter(|| black_box(x).sin()));
+    c.bench_function("Quad::cos", |b| b.iter(|| black_box(x).cos()));
+    c.bench_function("Quad::tan", |b| b.iter(|| black_box(x).tan()));
+    c.bench_function("Quad::atan", |b| b.iter(|| black_box(x).atan()));
+}
+
+criterion_group!(benches, double, quad);
+criterion_main!(benches);
@@ -0,0 +1,326 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Procedural macros that check `Double` and `Quad` literals at compile time.
+//!
+//! These aren't meant to be used directly. Enable the `macros` feature of `qd` and use its
+//! `dd_lit!` and `qd_lit!` macros instead, which pass their arguments through the macros
+//! here and then convert the checked literals.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+// The number of significant decimal digits it takes to tell apart any two numbers of each
+// type. Literals with more digits than this can't be represented exactly, and the extra
+// digits are rounded away.
+const DOUBLE_DIGITS: usize = 33;
+const QUAD_DIGITS: usize = 65;
+
+// The largest decimal exponent of a finite `f64`, which `Double` and `Quad` share, and
+// the leading digits of a number a little larger than the largest finite `Double`.
+const MAX_EXP: i64 = 308;
+const MAX_DIGITS: &str = "17976931348623159";
+
+/// Checks a `Double` literal, expanding to the literal as a string.
+///
+/// The literal can be a string or a number, and a number may be preceded by `-`. It's a
+/// compile-time error if the literal isn't a number that `Double` can parse, if it's a
+/// finite number too large for a `Double`, or if it has more significant digits than a
+/// `Double` can represent. Adding `, truncate` after the literal turns the last of those
+/// errors into a warning.
+#[proc_macro]
+pub fn double_literal(input: TokenStream) -> TokenStream {
+    expand(input, "Double", DOUBLE_DIGITS)
+}
+
+/// Checks a `Quad` literal, expanding to the literal as a string.
+///
+/// This works just like `double_literal`, except with the precision of a `Quad`.
+#[proc_macro]
+pub fn quad_literal(input: TokenStream) -> TokenStream {
+    expand(input, "Quad", QUAD_DIGITS)
+}
+
+fn expand(input: TokenStream, name: &str, max_digits: usize) -> TokenStream {
+    let (text, truncate, span) = match arguments(input) {
+        Ok(args) => args,
+        Err((message, span)) => return compile_error(&message, span),
+    };
+    match check(&text, max_digits) {
+        Ok(()) => string(&text, span),
+        Err(Error::TooManyDigits(digits)) => {
+            let message = format!(
+                "literal has {} significant digits, but a {} can only represent {}",
+                digits, name, max_digits
+            );
+            if truncate {
+                warning(
+                    &format!("{}; the rest are rounded away", message),
+                    &text,
+                    span,
+                )
+            } else {
+                let hint = "; add `, truncate` to round it anyway";
+                compile_error(&format!("{}{}", message, hint), span)
+            }
+        }
+        Err(Error::Malformed(reason)) => {
+            compile_error(&format!("invalid {} literal: {}", name, reason), span)
+        }
+        Err(Error::Overflow) => {
+            compile_error(&format!("literal is too large for a {}", name), span)
+        }
+    }
+}
+
+// Splits the input into the text of the literal, whether `truncate` was given, and the
+// span of the literal for error messages.
+fn arguments(input: TokenStream) -> Result<(String, bool, Span), (String, Span)> {
+    let mut tokens = flatten(input).into_iter().peekable();
+
+    let negative = match tokens.peek() {
+        Some(TokenTree::Punct(p)) if p.as_char() == '-' => {
+            tokens.next();
+            true
+        }
+        _ => false,
+    };
+
+    let literal = match tokens.next() {
+        Some(TokenTree::Literal(lit)) => lit,
+        Some(other) => return Err(("expected a literal".to_string(), other.span())),
+        None => return Err(("expected a literal".to_string(), Span::call_site())),
+    };
+    let span = literal.span();
+    let source = literal.to_string();
+
+    let text = if source.starts_with('"') {
+        if negative {
+            return Err(("`-` can't be applied to a string".to_string(), span));
+        }
+        match source.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+            Some(s) if !s.contains('\\') => s.to_string(),
+            _ => return Err(("expected a plain string without escapes".to_string(), span)),
+        }
+    } else if negative {
+        format!("-{}", source)
+    } else {
+        source
+    };
+
+    let truncate = match (tokens.next(), tokens.next(), tokens.next()) {
+        (None, _, _) => false,
+        (Some(TokenTree::Punct(p)), None, _) if p.as_char() == ',' => false,
+        (Some(TokenTree::Punct(p)), Some(TokenTree::Ident(i)), None)
+            if p.as_char() == ',' && i.to_string() == "truncate" =>
+        {
+            true
+        }
+        (Some(other), _, _) => {
+            return Err(("expected `, truncate` or nothing".to_string(), other.span()))
+        }
+    };
+
+    Ok((text, truncate, span))
+}
+
+// Removes the invisible groups that a literal is wrapped in when it's passed through a
+// `macro_rules!` macro as an `expr` or `literal` fragment.
+fn flatten(input: TokenStream) -> Vec<TokenTree> {
+    input
+        .into_iter()
+        .flat_map(|tree| match tree {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::None => flatten(g.stream()),
+            other => vec![other],
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Malformed(&'static str),
+    TooManyDigits(usize),
+    Overflow,
+}
+
+// Checks the text of a literal against the grammar that `Double` and `Quad` parse: an
+// optional sign, digits with an optional decimal point and underscores, and an optional
+// exponent. This is stricter than the parsers, which accept a lone decimal point or sign.
+fn check(text: &str, max_digits: usize) -> Result<(), Error> {
+    let lower = text.trim().to_ascii_lowercase();
+    let unsigned = lower.trim_start_matches(['+', '-']);
+    if lower.len() - unsigned.len() > 1 {
+        return Err(Error::Malformed("more than one sign"));
+    }
+    if ["nan", "inf", "infinity"].contains(&unsigned) {
+        return Ok(());
+    }
+
+    let (mantissa, exp) = match unsigned.find('e') {
+        Some(i) => {
+            let exp = &unsigned[(i + 1)..];
+            match exp.parse::<i32>() {
+                Ok(e) => (&unsigned[..i], e as i64),
+                Err(_) => return Err(Error::Malformed("the exponent isn't an integer")),
+            }
+        }
+        None => (unsigned, 0),
+    };
+
+    let mut digits = String::new();
+    let mut point = None;
+    for ch in mantissa.chars() {
+        match ch {
+            '0'..='9' => digits.push(ch),
+            '.' if point.is_none() => point = Some(digits.len()),
+            '.' => return Err(Error::Malformed("more than one decimal point")),
+            '_' => {}
+            _ => {
+                return Err(Error::Malformed(
+                    "it contains a character that isn't a digit",
+                ))
+            }
+        }
+    }
+    if digits.is_empty() {
+        return Err(Error::Malformed("it has no digits"));
+    }
+
+    // Leading and trailing zeros don't count as significant
+    let integer_digits = point.unwrap_or(digits.len()) as i64;
+    let leading = digits.len() - digits.trim_start_matches('0').len();
+    let significant = digits.trim_matches('0').len();
+    if significant == 0 {
+        return Ok(());
+    }
+    if significant > max_digits {
+        return Err(Error::TooManyDigits(significant));
+    }
+    // The decimal exponent of the first significant digit
+    let magnitude = integer_digits - leading as i64 - 1 + exp;
+    let first = digits.trim_start_matches('0');
+    let first = &first[..first.len().min(MAX_DIGITS.len())];
+    if magnitude > MAX_EXP
+        || magnitude == MAX_EXP && format!("{:0<17}", first).as_str() > MAX_DIGITS
+    {
+        return Err(Error::Overflow);
+    }
+    Ok(())
+}
+
+fn string(text: &str, span: Span) -> TokenStream {
+    let mut lit = Literal::string(text);
+    lit.set_span(span);
+    TokenStream::from(TokenTree::Literal(lit))
+}
+
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut group = Group::new(Delimiter::Parenthesis, string(message, span));
+    group.set_span(span);
+    vec![
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(group),
+    ]
+    .into_iter()
+    .collect()
+}
+
+// There's no way to emit a warning from a procedural macro on stable Rust, so this uses a
+// deprecated item instead. The expansion is a block that uses the item and then evaluates
+// to the literal.
+fn warning(message: &str, text: &str, span: Span) -> TokenStream {
+    let note = string(message, span);
+    let lit = string(text, span);
+    let source = format!(
+        "{{ #[deprecated(note = {})] #[allow(non_camel_case_types)] struct truncated_literal; \
+         let _ = truncated_literal; {} }}",
+        note, lit
+    );
+    let stream: TokenStream = source.parse().unwrap();
+    stream.into_iter().map(|tree| respan(tree, span)).collect()
+}
+
+fn respan(tree: TokenTree, span: Span) -> TokenTree {
+    match tree {
+        TokenTree::Group(g) => {
+            let stream = g.stream().into_iter().map(|t| respan(t, span)).collect();
+            let mut group = Group::new(g.delimiter(), stream);
+            group.set_span(span);
+            TokenTree::Group(group)
+        }
+        mut other => {
+            other.set_span(span);
+            other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        for text in &[
+            "0",
+            "-1.5",
+            "+.5",
+            "5.",
+            "1e-300",
+            "1.25E+10",
+            "1_000_000.0",
+            "0.000000000000000000000000000000000000001",
+            "100000000000000000000000000000000000000000",
+            "nan",
+            "-Infinity",
+            "1.7976931348623157e308",
+            " 3.0 ",
+        ] {
+            assert_eq!(check(text, DOUBLE_DIGITS), Ok(()), "{}", text);
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        for text in &[
+            "", ".", "-", "--1", "1.2.3", "1e", "1e1.5", "1.5f64", "0x10", "1 2",
+        ] {
+            assert!(
+                matches!(check(text, DOUBLE_DIGITS), Err(Error::Malformed(_))),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn digits() {
+        let ones = |n: usize| format!("1.{}", "1".repeat(n - 1));
+        assert_eq!(check(&ones(33), DOUBLE_DIGITS), Ok(()));
+        assert_eq!(
+            check(&ones(34), DOUBLE_DIGITS),
+            Err(Error::TooManyDigits(34))
+        );
+        assert_eq!(check(&ones(65), QUAD_DIGITS), Ok(()));
+        assert_eq!(check(&ones(66), QUAD_DIGITS), Err(Error::TooManyDigits(66)));
+        assert_eq!(
+            check("0.000123456789012345678901234567890123", DOUBLE_DIGITS),
+            Ok(())
+        );
+        assert_eq!(
+            check("1.5000000000000000000000000000000000000000", 2),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(check("1.7976931348623157e308", DOUBLE_DIGITS), Ok(()));
+        assert_eq!(check("1.8e308", DOUBLE_DIGITS), Err(Error::Overflow));
+        assert_eq!(check("1e309", DOUBLE_DIGITS), Err(Error::Overflow));
+        assert_eq!(check("12345e305", DOUBLE_DIGITS), Err(Error::Overflow));
+        assert_eq!(check("0.001e311", DOUBLE_DIGITS), Ok(()));
+    }
+}
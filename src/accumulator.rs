@@ -0,0 +1,594 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Accumulators for summing long sequences of [`Double`]s and [`Quad`]s.
+//!
+//! Adding two double-doubles or quad-doubles with the `+` operator ends with a
+//! renormalization, which rearranges the components of the sum into their non-overlapping
+//! form. That's necessary for a value that's going to be used in further calculations, but
+//! in a long summation loop the intermediate sums are never used for anything but the next
+//! addition, and the renormalization can account for much of the running time.
+//!
+//! The accumulators in this module skip it. Each addition only runs the error-free
+//! transformations that capture the rounding error of the running sum, and the errors are
+//! collected in a few extra `f64`s. Renormalization happens once, when the sum is read.
+//!
+//! # Examples
+//! ```
+//! use qd::accumulator::DoubleAccumulator;
+//! use qd::{dd, Double};
+//!
+//! let mut acc = DoubleAccumulator::new();
+//! for i in 1..=100 {
+//!     acc += dd!(1) / dd!(i);
+//! }
+//!
+//! let expected = dd!("5.1873775176396202608051176756582531");
+//! let diff = (acc.sum() - expected).abs();
+//! assert!(diff < dd!(1e-30));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+
+use crate::common::primitive as p;
+use crate::common::utils as u;
+use crate::double::Double;
+use crate::quad::Quad;
+use std::ops::AddAssign;
+
+/// An accumulator that sums [`Double`]s without renormalizing after every addition.
+///
+/// The leading components of the added numbers are summed into one `f64`, with the exact
+/// error of each addition (and the trailing components of the numbers) collected in a
+/// second one. This is the summation algorithm known as Sum2, and its result is as
+/// accurate as if the sum had been calculated with twice the precision of its leading
+/// component. The error bound of the sum therefore grows with the number of terms in the
+/// same way as it does for repeated `+`, though each addition takes far fewer
+/// floating-point operations.
+///
+/// The sum is renormalized into a `Double` when it's read with [`sum`] or converted with
+/// `From`.
+///
+/// # Examples
+/// ```
+/// use qd::accumulator::DoubleAccumulator;
+/// use qd::{dd, Double};
+///
+/// let mut acc = DoubleAccumulator::new();
+/// acc += Double::PI;
+/// acc += Double::E;
+/// let diff = (acc.sum() - (Double::PI + Double::E)).abs();
+/// assert!(diff < dd!(1e-30));
+///
+/// let values = vec![dd!(1), dd!(2), dd!(3)];
+/// acc.extend(values);
+/// let diff = (Double::from(acc) - (Double::PI + Double::E + dd!(6))).abs();
+/// assert!(diff < dd!(1e-30));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`sum`]: #method.sum
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DoubleAccumulator(f64, f64);
+
+impl DoubleAccumulator {
+    /// Creates a new accumulator with a sum of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::DoubleAccumulator;
+    /// use qd::Double;
+    ///
+    /// let acc = DoubleAccumulator::new();
+    /// assert!(acc.sum() == Double::ZERO);
+    /// ```
+    #[inline]
+    pub fn new() -> DoubleAccumulator {
+        DoubleAccumulator(0.0, 0.0)
+    }
+
+    /// Returns the sum of all of the numbers added to the accumulator so far, renormalized
+    /// into a `Double`.
+    ///
+    /// Reading the sum doesn't change the accumulator, so more numbers can be added to it
+    /// afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::DoubleAccumulator;
+    /// use qd::dd;
+    ///
+    /// let mut acc = DoubleAccumulator::new();
+    /// acc += dd!(1.5);
+    /// assert!(acc.sum() == dd!(1.5));
+    /// acc += dd!(2.5);
+    /// assert!(acc.sum() == dd!(4));
+    /// ```
+    pub fn sum(&self) -> Double {
+        if self.0.is_finite() {
+            let (a, b) = p::two_sum(self.0, self.1);
+            Double::from((a, b))
+        } else {
+            // The error terms of an infinite sum are meaningless (and usually `NaN`)
+            Double::from((self.0, 0.0))
+        }
+    }
+}
+
+impl AddAssign<Double> for DoubleAccumulator {
+    /// Adds a `Double` to the accumulator.
+    ///
+    /// This implements the `+=` operator between an accumulator and a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::DoubleAccumulator;
+    /// use qd::dd;
+    ///
+    /// let mut acc = DoubleAccumulator::new();
+    /// acc += dd!(3);
+    /// assert!(acc.sum() == dd!(3));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Double) {
+        let (s, e) = p::two_sum(self.0, other[0]);
+        self.0 = s;
+        self.1 += e + other[1];
+    }
+}
+
+impl AddAssign<&Double> for DoubleAccumulator {
+    /// Adds a reference to a `Double` to the accumulator.
+    ///
+    /// This implements the `+=` operator between an accumulator and a reference to a
+    /// `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::DoubleAccumulator;
+    /// use qd::dd;
+    ///
+    /// let mut acc = DoubleAccumulator::new();
+    /// acc += &dd!(3);
+    /// assert!(acc.sum() == dd!(3));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &Double) {
+        self.add_assign(*other);
+    }
+}
+
+impl Extend<Double> for DoubleAccumulator {
+    /// Adds all of the `Double`s in an iterator to the accumulator.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::DoubleAccumulator;
+    /// use qd::dd;
+    ///
+    /// let mut acc = DoubleAccumulator::new();
+    /// acc.extend(vec![dd!(1), dd!(2), dd!(3)]);
+    /// assert!(acc.sum() == dd!(6));
+    /// ```
+    fn extend<I: IntoIterator<Item = Double>>(&mut self, iter: I) {
+        for x in iter {
+            *self += x;
+        }
+    }
+}
+
+impl<'a> Extend<&'a Double> for DoubleAccumulator {
+    /// Adds all of the referenced `Double`s in an iterator to the accumulator.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::DoubleAccumulator;
+    /// use qd::dd;
+    ///
+    /// let mut acc = DoubleAccumulator::new();
+    /// acc.extend(&[dd!(1), dd!(2), dd!(3)]);
+    /// assert!(acc.sum() == dd!(6));
+    /// ```
+    fn extend<I: IntoIterator<Item = &'a Double>>(&mut self, iter: I) {
+        for x in iter {
+            *self += x;
+        }
+    }
+}
+
+impl From<DoubleAccumulator> for Double {
+    /// Generates a `Double` from the sum in an accumulator.
+    ///
+    /// This is the same as calling [`DoubleAccumulator::sum`].
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::DoubleAccumulator;
+    /// use qd::{dd, Double};
+    ///
+    /// let mut acc = DoubleAccumulator::new();
+    /// acc += dd!(7);
+    /// assert!(Double::from(acc) == dd!(7));
+    /// ```
+    #[inline]
+    fn from(acc: DoubleAccumulator) -> Double {
+        acc.sum()
+    }
+}
+
+/// An accumulator that sums [`Quad`]s without renormalizing after every addition.
+///
+/// The components of the added numbers are collected in four levels of `f64`s, each of
+/// which holds the exact errors of the additions to the level above it (along with the
+/// component of each added number that has the same order of magnitude). The last level
+/// is summed with plain `f64` arithmetic, the way the last component of an addition is in
+/// [`Quad`]'s own algorithms. Because the rounding errors of that last level are never
+/// recovered, the error grows a little faster with the number of terms than it does for
+/// repeated `+`; a sum of a thousand terms is typically off by a few units in the last
+/// place. In exchange, each addition takes roughly half as many floating-point operations.
+///
+/// The levels are renormalized into a `Quad` when the sum is read with [`sum`] or
+/// converted with `From`.
+///
+/// # Examples
+/// ```
+/// use qd::accumulator::QuadAccumulator;
+/// use qd::{qd, Quad};
+///
+/// let mut acc = QuadAccumulator::new();
+/// acc += Quad::PI;
+/// acc += Quad::E;
+///
+/// let diff = (acc.sum() - (Quad::PI + Quad::E)).abs();
+/// assert!(diff < qd!(1e-60));
+/// ```
+///
+/// [`Quad`]: crate::Quad
+/// [`sum`]: #method.sum
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuadAccumulator(f64, f64, f64, f64);
+
+impl QuadAccumulator {
+    /// Creates a new accumulator with a sum of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::QuadAccumulator;
+    /// use qd::Quad;
+    ///
+    /// let acc = QuadAccumulator::new();
+    /// assert!(acc.sum() == Quad::ZERO);
+    /// ```
+    #[inline]
+    pub fn new() -> QuadAccumulator {
+        QuadAccumulator(0.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Returns the sum of all of the numbers added to the accumulator so far, renormalized
+    /// into a `Quad`.
+    ///
+    /// Reading the sum doesn't change the accumulator, so more numbers can be added to it
+    /// afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::QuadAccumulator;
+    /// use qd::qd;
+    ///
+    /// let mut acc = QuadAccumulator::new();
+    /// acc += qd!(1.5);
+    /// assert!(acc.sum() == qd!(1.5));
+    /// acc += qd!(2.5);
+    /// assert!(acc.sum() == qd!(4));
+    /// ```
+    pub fn sum(&self) -> Quad {
+        if self.0.is_finite() {
+            // The levels can overlap after many additions, so they're distilled rather than
+            // renormalized with `renorm4`, which assumes they're already nearly in order
+            let (a, b, c, d) = u::distill4(&mut [self.3, self.2, self.1, self.0]);
+            Quad::from((a, b, c, d))
+        } else {
+            // The error terms of an infinite sum are meaningless (and usually `NaN`)
+            Quad::from((self.0, 0.0, 0.0, 0.0))
+        }
+    }
+}
+
+impl AddAssign<Quad> for QuadAccumulator {
+    /// Adds a `Quad` to the accumulator.
+    ///
+    /// This implements the `+=` operator between an accumulator and a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::QuadAccumulator;
+    /// use qd::qd;
+    ///
+    /// let mut acc = QuadAccumulator::new();
+    /// acc += qd!(3);
+    /// assert!(acc.sum() == qd!(3));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Quad) {
+        let (s0, e0) = p::two_sum(self.0, other[0]);
+
+        let (s1, e1) = p::two_sum(self.1, other[1]);
+        let (s1, f1) = p::two_sum(s1, e0);
+
+        let (s2, e2) = p::two_sum(self.2, other[2]);
+        let (s2, f2) = p::two_sum(s2, e1);
+        let (s2, g2) = p::two_sum(s2, f1);
+
+        self.0 = s0;
+        self.1 = s1;
+        self.2 = s2;
+        self.3 += other[3] + e2 + f2 + g2;
+    }
+}
+
+impl AddAssign<&Quad> for QuadAccumulator {
+    /// Adds a reference to a `Quad` to the accumulator.
+    ///
+    /// This implements the `+=` operator between an accumulator and a reference to a
+    /// `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::QuadAccumulator;
+    /// use qd::qd;
+    ///
+    /// let mut acc = QuadAccumulator::new();
+    /// acc += &qd!(3);
+    /// assert!(acc.sum() == qd!(3));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &Quad) {
+        self.add_assign(*other);
+    }
+}
+
+impl Extend<Quad> for QuadAccumulator {
+    /// Adds all of the `Quad`s in an iterator to the accumulator.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::QuadAccumulator;
+    /// use qd::qd;
+    ///
+    /// let mut acc = QuadAccumulator::new();
+    /// acc.extend(vec![qd!(1), qd!(2), qd!(3)]);
+    /// assert!(acc.sum() == qd!(6));
+    /// ```
+    fn extend<I: IntoIterator<Item = Quad>>(&mut self, iter: I) {
+        for x in iter {
+            *self += x;
+        }
+    }
+}
+
+impl<'a> Extend<&'a Quad> for QuadAccumulator {
+    /// Adds all of the referenced `Quad`s in an iterator to the accumulator.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::QuadAccumulator;
+    /// use qd::qd;
+    ///
+    /// let mut acc = QuadAccumulator::new();
+    /// acc.extend(&[qd!(1), qd!(2), qd!(3)]);
+    /// assert!(acc.sum() == qd!(6));
+    /// ```
+    fn extend<I: IntoIterator<Item = &'a Quad>>(&mut self, iter: I) {
+        for x in iter {
+            *self += x;
+        }
+    }
+}
+
+impl From<QuadAccumulator> for Quad {
+    /// Generates a `Quad` from the sum in an accumulator.
+    ///
+    /// This is the same as calling [`QuadAccumulator::sum`].
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::accumulator::QuadAccumulator;
+    /// use qd::{qd, Quad};
+    ///
+    /// let mut acc = QuadAccumulator::new();
+    /// acc += qd!(7);
+    /// assert!(Quad::from(acc) == qd!(7));
+    /// ```
+    #[inline]
+    fn from(acc: QuadAccumulator) -> Quad {
+        acc.sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn harmonic_double(n: i32) -> DoubleAccumulator {
+        let mut acc = DoubleAccumulator::new();
+        for i in 1..=n {
+            acc += Double::ONE / Double::from(i);
+        }
+        acc
+    }
+
+    fn harmonic_quad(n: i32) -> QuadAccumulator {
+        let mut acc = QuadAccumulator::new();
+        for i in 1..=n {
+            acc += Quad::ONE / Quad::from(i);
+        }
+        acc
+    }
+
+    #[test]
+    fn double_empty() {
+        assert!(DoubleAccumulator::new().sum() == Double::ZERO);
+        assert!(DoubleAccumulator::default().sum() == Double::ZERO);
+    }
+
+    #[test]
+    fn double_harmonic() {
+        let expected = Double::from("7.4854708605503449126565182043339001765");
+        let diff = (harmonic_double(1000).sum() - expected).abs();
+        assert!(diff < Double::from(1e-29));
+    }
+
+    #[test]
+    fn double_matches_sum() {
+        let values: Vec<Double> = (1..=1000).map(|i| Double::ONE / Double::from(i)).collect();
+        let expected: Double = values.iter().sum();
+        let diff = (harmonic_double(1000).sum() - expected).abs();
+        assert!(diff < Double::from(1e-29));
+    }
+
+    #[test]
+    fn double_normalized() {
+        let sum = harmonic_double(1000).sum();
+        assert!(sum[0] + sum[1] == sum[0]);
+    }
+
+    #[test]
+    fn double_cancellation() {
+        let mut acc = DoubleAccumulator::new();
+        acc += Double::PI;
+        acc += Double::from(1e20);
+        acc += -Double::PI;
+        acc += Double::from(-1e20);
+        acc += Double::from((1e-20, 0.0));
+        assert!(acc.sum() == Double::from((1e-20, 0.0)));
+    }
+
+    #[test]
+    fn double_trailing_components() {
+        let mut acc = DoubleAccumulator::new();
+        for _ in 0..8 {
+            acc += Double::from((1.0, 2f64.powi(-60)));
+        }
+        assert!(acc.sum() == Double::from((8.0, 2f64.powi(-57))));
+    }
+
+    #[test]
+    fn double_read_twice() {
+        let mut acc = harmonic_double(10);
+        let first = acc.sum();
+        acc += Double::ONE;
+        assert!(acc.sum() == first + Double::ONE);
+    }
+
+    #[test]
+    fn double_inf() {
+        let mut acc = DoubleAccumulator::new();
+        acc += Double::PI;
+        acc += Double::INFINITY;
+        acc += Double::E;
+        assert!(acc.sum() == Double::INFINITY);
+    }
+
+    #[test]
+    fn double_opposite_infs() {
+        let mut acc = DoubleAccumulator::new();
+        acc += Double::INFINITY;
+        acc += Double::NEG_INFINITY;
+        assert!(acc.sum().is_nan());
+    }
+
+    #[test]
+    fn double_nan() {
+        let mut acc = DoubleAccumulator::new();
+        acc += Double::PI;
+        acc += Double::NAN;
+        assert!(acc.sum().is_nan());
+    }
+
+    #[test]
+    fn quad_empty() {
+        assert!(QuadAccumulator::new().sum() == Quad::ZERO);
+        assert!(QuadAccumulator::default().sum() == Quad::ZERO);
+    }
+
+    #[test]
+    fn quad_harmonic() {
+        let expected =
+            Quad::from("7.485470860550344912656518204333900176521679169708803665773626749958");
+        let diff = (harmonic_quad(1000).sum() - expected).abs();
+        assert!(diff < Quad::from(1e-58));
+    }
+
+    #[test]
+    fn quad_matches_sum() {
+        let values: Vec<Quad> = (1..=1000).map(|i| Quad::ONE / Quad::from(i)).collect();
+        let expected: Quad = values.iter().sum();
+        let diff = (harmonic_quad(1000).sum() - expected).abs();
+        assert!(diff < Quad::from(1e-58));
+    }
+
+    #[test]
+    fn quad_normalized() {
+        let sum = harmonic_quad(1000).sum();
+        assert!(sum[0] + sum[1] == sum[0]);
+        assert!(sum[1] + sum[2] == sum[1]);
+        assert!(sum[2] + sum[3] == sum[2]);
+    }
+
+    #[test]
+    fn quad_cancellation() {
+        let mut acc = QuadAccumulator::new();
+        acc += Quad::PI;
+        acc += Quad::from(1e40);
+        acc += -Quad::PI;
+        acc += Quad::from(-1e40);
+        acc += Quad::from((1e-40, 0.0));
+        assert!(acc.sum() == Quad::from((1e-40, 0.0)));
+    }
+
+    #[test]
+    fn quad_trailing_components() {
+        let mut acc = QuadAccumulator::new();
+        for _ in 0..8 {
+            acc += Quad::from((1.0, 2f64.powi(-60), 2f64.powi(-120), 2f64.powi(-180)));
+        }
+        let expected = Quad::from((8.0, 2f64.powi(-57), 2f64.powi(-117), 2f64.powi(-177)));
+        assert!(acc.sum() == expected);
+    }
+
+    #[test]
+    fn quad_read_twice() {
+        let mut acc = harmonic_quad(10);
+        let first = acc.sum();
+        acc += Quad::ONE;
+        assert!(acc.sum() == first + Quad::ONE);
+    }
+
+    #[test]
+    fn quad_inf() {
+        let mut acc = QuadAccumulator::new();
+        acc += Quad::PI;
+        acc += Quad::INFINITY;
+        acc += Quad::E;
+        assert!(acc.sum() == Quad::INFINITY);
+    }
+
+    #[test]
+    fn quad_opposite_infs() {
+        let mut acc = QuadAccumulator::new();
+        acc += Quad::INFINITY;
+        acc += Quad::NEG_INFINITY;
+        assert!(acc.sum().is_nan());
+    }
+
+    #[test]
+    fn quad_nan() {
+        let mut acc = QuadAccumulator::new();
+        acc += Quad::PI;
+        acc += Quad::NAN;
+        assert!(acc.sum().is_nan());
+    }
+}
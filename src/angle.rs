@@ -0,0 +1,575 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Angles in degrees, minutes, and seconds (DMS) and in hours, minutes, and seconds (HMS).
+//!
+//! [`Dms`] holds an angle like 51°28′38″ (a latitude or a declination), and [`Hms`] holds
+//! one like 5h35m17.3s (a right ascension or an hour angle). Both convert to and from
+//! [`Double`]s and [`Quad`]s, parse the usual written forms, and display themselves with
+//! the seconds rounded to the formatter's precision.
+//!
+//! Neither 1/60 nor 1/3600 has an exact binary representation, so converting by adding up
+//! `minutes / 60` and `seconds / 3600` rounds several times, and converting back by
+//! repeatedly multiplying the fractional part by 60 compounds the error of each step. The
+//! conversions here instead work with whole seconds as integers, so that the only rounding
+//! is a single multiplication or division by 3600 (or 240, between HMS and degrees).
+//!
+//! # Examples
+//! ```
+//! use qd::angle::{Dms, Hms};
+//! use qd::{dd, qd, Quad};
+//!
+//! // Greenwich's latitude
+//! let lat: Dms<Quad> = "51°28'38.2\"N".parse().unwrap();
+//! let expected = qd!("51.47727777777777777777777777777777777777777777777777777777777778");
+//! assert!((lat.to_degrees() - expected).abs() < qd!(1e-60));
+//! assert_eq!(format!("{:.1}", lat), "51°28'38.2\"");
+//!
+//! // Betelgeuse's right ascension
+//! let ra = Hms::from_degrees(dd!(88.79293899)).unwrap();
+//! assert_eq!(format!("{:.4}", ra), "5h55m10.3054s");
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`Dms`]: crate::angle::Dms
+//! [`Hms`]: crate::angle::Hms
+
+use crate::error::ErrorKind;
+use crate::precision::MultiPrecision;
+use core::error::Error;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// An angle in degrees, minutes, and seconds.
+///
+/// The sign is kept separately from the other fields so that angles between -1° and 0°
+/// can be represented. The minutes are normally less than 60 and the seconds are normally
+/// at least 0 and less than 60; every `Dms` that this module creates is like that, but
+/// [`to_degrees`] works with any values.
+///
+/// A `Dms` can be parsed from strings like `12°34'56.7"`, `-12° 34′ 56.7″`, `12d34m56.7s`,
+/// `12:34:56.7`, and `12 34 56.7`, with an optional `N`, `S`, `E`, or `W` at the end in
+/// place of a sign. Any of the components can be left off the end, and the last one given
+/// can have a fractional part (as in `12°34.945'` or `12.58253°`).
+///
+/// # Examples
+/// ```
+/// # use qd::angle::Dms;
+/// # use qd::{dd, Double};
+/// let angle = Dms::new(true, 12, 33, dd!(45));
+/// assert_eq!(angle.to_degrees(), dd!(-12.5625));
+/// assert_eq!(angle.to_string(), "-12°33'45\"");
+/// assert_eq!("12°33'45\"S".parse::<Dms<Double>>(), Ok(angle));
+/// ```
+///
+/// [`to_degrees`]: #method.to_degrees
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dms<T> {
+    /// Whether the angle is negative.
+    pub negative: bool,
+    /// The whole degrees of the angle.
+    pub degrees: u32,
+    /// The whole minutes of arc of the angle.
+    pub minutes: u32,
+    /// The seconds of arc of the angle.
+    pub seconds: T,
+}
+
+impl<T: MultiPrecision> Dms<T> {
+    /// Creates a new angle from its sign, degrees, minutes, and seconds.
+    pub const fn new(negative: bool, degrees: u32, minutes: u32, seconds: T) -> Dms<T> {
+        Dms {
+            negative,
+            degrees,
+            minutes,
+            seconds,
+        }
+    }
+
+    /// Splits an angle in degrees into degrees, minutes, and seconds. Returns `None` if the
+    /// angle isn't finite or has more degrees than fit into a `u32`.
+    ///
+    /// Most decimal fractions of a degree aren't exactly representable, so an angle that's
+    /// within a few units in the last place of a whole number of seconds is taken to be
+    /// that number of seconds; 0.51° gives exactly 30′36″ rather than 30′35.999…″.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::angle::Dms;
+    /// # use qd::qd;
+    /// assert_eq!(Dms::from_degrees(qd!(-0.51)), Some(Dms::new(true, 0, 30, qd!(36))));
+    /// assert_eq!(Dms::from_degrees(qd!(1e10)), None);
+    /// ```
+    pub fn from_degrees(degrees: T) -> Option<Dms<T>> {
+        let (negative, degrees, minutes, seconds) = split(degrees, 3600.0)?;
+        Some(Dms::new(negative, degrees, minutes, seconds))
+    }
+
+    /// Converts the angle to degrees.
+    pub fn to_degrees(self) -> T {
+        join(
+            self.negative,
+            self.degrees,
+            self.minutes,
+            self.seconds,
+            3600.0,
+        )
+    }
+
+    /// Splits an angle in radians into degrees, minutes, and seconds. Returns `None` if the
+    /// angle isn't finite or has more degrees than fit into a `u32`.
+    pub fn from_radians(radians: T) -> Option<Dms<T>> {
+        Dms::from_degrees(radians * T::from(180.0) / T::PI)
+    }
+
+    /// Converts the angle to radians.
+    pub fn to_radians(self) -> T {
+        self.to_degrees() * T::PI / T::from(180.0)
+    }
+}
+
+impl<T: MultiPrecision> Display for Dms<T> {
+    /// Formats the angle as in `-12°34'56.789"`, with the seconds rounded to the precision
+    /// of the formatter (if it has one) and the minutes and seconds padded to two digits.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (degrees, minutes, seconds) =
+            round_seconds(self.degrees, self.minutes, self.seconds, f);
+        let sign = if self.negative { "-" } else { "" };
+        write!(f, "{}{}°{:02}'{}\"", sign, degrees, minutes, seconds)
+    }
+}
+
+impl<T: MultiPrecision + FromStr> FromStr for Dms<T> {
+    type Err = ParseAngleError;
+
+    /// Parses an angle in degrees, minutes, and seconds, in any of the forms in [`Dms`].
+    ///
+    /// [`Dms`]: crate::angle::Dms
+    fn from_str(s: &str) -> Result<Dms<T>, ParseAngleError> {
+        let (negative, degrees, minutes, seconds) = parse(s, ["°d", "'′m", "\"″s"], true)?;
+        Ok(Dms::new(negative, degrees, minutes, seconds))
+    }
+}
+
+/// An angle or a time in hours, minutes, and seconds.
+///
+/// An hour is 15°, so a full circle is 24 hours. As with [`Dms`], the sign is kept
+/// separately, and every `Hms` that this module creates has minutes less than 60 and
+/// seconds of at least 0 and less than 60.
+///
+/// An `Hms` can be parsed from strings like `5h35m17.3s`, `-5h 35m 17.3s`, `5:35:17.3`, and
+/// `5 35 17.3`. Any of the components can be left off the end, and the last one given can
+/// have a fractional part.
+///
+/// # Examples
+/// ```
+/// # use qd::angle::Hms;
+/// # use qd::{qd, Quad};
+/// let ra: Hms<Quad> = "5h35m17.3s".parse().unwrap();
+/// assert_eq!(ra, Hms::new(false, 5, 35, qd!(17.3)));
+/// let expected = qd!("83.82208333333333333333333333333333333333333333333333333333333333");
+/// assert!((ra.to_degrees() - expected).abs() < qd!(1e-60));
+/// assert_eq!(format!("{:.2}", ra), "5h35m17.30s");
+/// ```
+///
+/// [`Dms`]: crate::angle::Dms
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hms<T> {
+    /// Whether the angle is negative.
+    pub negative: bool,
+    /// The whole hours of the angle.
+    pub hours: u32,
+    /// The whole minutes of the angle.
+    pub minutes: u32,
+    /// The seconds of the angle.
+    pub seconds: T,
+}
+
+impl<T: MultiPrecision> Hms<T> {
+    /// Creates a new angle from its sign, hours, minutes, and seconds.
+    pub const fn new(negative: bool, hours: u32, minutes: u32, seconds: T) -> Hms<T> {
+        Hms {
+            negative,
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+
+    /// Splits a number of hours into hours, minutes, and seconds. Returns `None` if the
+    /// number isn't finite or has more hours than fit into a `u32`.
+    pub fn from_hours(hours: T) -> Option<Hms<T>> {
+        let (negative, hours, minutes, seconds) = split(hours, 3600.0)?;
+        Some(Hms::new(negative, hours, minutes, seconds))
+    }
+
+    /// Converts the angle to hours.
+    pub fn to_hours(self) -> T {
+        join(
+            self.negative,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            3600.0,
+        )
+    }
+
+    /// Splits an angle in degrees into hours, minutes, and seconds. Returns `None` if the
+    /// angle isn't finite or has more hours than fit into a `u32`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::angle::Hms;
+    /// # use qd::dd;
+    /// assert_eq!(Hms::from_degrees(dd!(-15.5)), Some(Hms::new(true, 1, 2, dd!(0))));
+    /// ```
+    pub fn from_degrees(degrees: T) -> Option<Hms<T>> {
+        // A second of time is 1/240 of a degree
+        let (negative, hours, minutes, seconds) = split(degrees, 240.0)?;
+        Some(Hms::new(negative, hours, minutes, seconds))
+    }
+
+    /// Converts the angle to degrees.
+    pub fn to_degrees(self) -> T {
+        join(self.negative, self.hours, self.minutes, self.seconds, 240.0)
+    }
+}
+
+impl<T: MultiPrecision> Display for Hms<T> {
+    /// Formats the angle as in `-12h34m56.789s`, with the seconds rounded to the precision
+    /// of the formatter (if it has one) and the minutes and seconds padded to two digits.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (hours, minutes, seconds) = round_seconds(self.hours, self.minutes, self.seconds, f);
+        let sign = if self.negative { "-" } else { "" };
+        write!(f, "{}{}h{:02}m{}s", sign, hours, minutes, seconds)
+    }
+}
+
+impl<T: MultiPrecision + FromStr> FromStr for Hms<T> {
+    type Err = ParseAngleError;
+
+    /// Parses an angle in hours, minutes, and seconds, in any of the forms in [`Hms`].
+    ///
+    /// [`Hms`]: crate::angle::Hms
+    fn from_str(s: &str) -> Result<Hms<T>, ParseAngleError> {
+        let (negative, hours, minutes, seconds) = parse(s, ["h", "m", "s"], false)?;
+        Ok(Hms::new(negative, hours, minutes, seconds))
+    }
+}
+
+/// An error generated when a problem is encountered parsing a string into a [`Dms`] or an
+/// [`Hms`].
+///
+/// [`Dms`]: crate::angle::Dms
+/// [`Hms`]: crate::angle::Hms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAngleError {
+    pub kind: ErrorKind,
+}
+
+impl Display for ParseAngleError {
+    /// Displays an English-language message describing the kind of the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let description = match self.kind {
+            ErrorKind::Empty => "cannot parse angle from empty string",
+            ErrorKind::Invalid => "invalid angle",
+        };
+        description.fmt(f)
+    }
+}
+
+impl Error for ParseAngleError {}
+
+// Splits a value into its sign, its whole units, its whole minutes, and its seconds, where
+// `scale` is the number of seconds in one unit of the value. The value is multiplied by
+// the scale once, and everything after that (apart from the snapping) is exact.
+fn split<T: MultiPrecision>(value: T, scale: f64) -> Option<(bool, u32, u32, T)> {
+    if !value.is_finite() {
+        return None;
+    }
+    let mut total = value.abs() * T::from(scale);
+    // Snap to a nearby whole number of seconds, which is almost always what was meant
+    let nearest = total.round();
+    if (total - nearest).abs() <= total.precision_ulp().mul_pwr2(4.0) {
+        total = nearest;
+    }
+    let whole = total.floor_i128()?;
+    let units = u32::try_from(whole / 3600).ok()?;
+    let minutes = (whole % 3600 / 60) as u32;
+    // `whole` is less than 2⁵³ here, so it and its remainder convert to `f64` exactly
+    let seconds = T::from((whole % 60) as f64) + (total - T::from(whole as f64));
+    Some((value < T::ZERO, units, minutes, seconds))
+}
+
+// The inverse of `split`, which adds up the whole units and then the rest divided by the
+// scale, so that only the fractional part of the value is rounded.
+fn join<T: MultiPrecision>(negative: bool, units: u32, minutes: u32, seconds: T, scale: f64) -> T {
+    let whole = T::from(units as f64 * (3600.0 / scale));
+    let rest = T::from(minutes as f64 * 60.0) + seconds;
+    let value = whole + rest / T::from(scale);
+    if negative {
+        -value
+    } else {
+        value
+    }
+}
+
+// Formats the seconds with the formatter's precision and carries into the minutes and
+// units if they round up to 60. The seconds are padded to two digits before the point.
+fn round_seconds<T: MultiPrecision>(
+    units: u32,
+    minutes: u32,
+    seconds: T,
+    f: &Formatter,
+) -> (u64, u32, String) {
+    let format = |s: T| match f.precision() {
+        Some(p) => format!("{:.*}", p, s),
+        None => s.to_string(),
+    };
+    let (mut units, mut minutes) = (units as u64, minutes);
+    let mut text = format(seconds);
+    if seconds < T::from(60.0) && text.starts_with("60") {
+        text = format(T::ZERO);
+        minutes += 1;
+        if minutes == 60 {
+            minutes = 0;
+            units += 1;
+        }
+    }
+    if text.find('.').unwrap_or(text.len()) == 1 {
+        text.insert(0, '0');
+    }
+    (units, minutes, text)
+}
+
+// Parses an angle with three components, where `markers` has the characters that can
+// follow each component. Components can also be separated by colons or whitespace, in
+// which case each is taken to be the one after the previous component. If `hemispheres`
+// is set, the sign can be given by a letter at the end instead.
+fn parse<T: MultiPrecision + FromStr>(
+    s: &str,
+    markers: [&str; 3],
+    hemispheres: bool,
+) -> Result<(bool, u32, u32, T), ParseAngleError> {
+    let invalid = ParseAngleError {
+        kind: ErrorKind::Invalid,
+    };
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseAngleError {
+            kind: ErrorKind::Empty,
+        });
+    }
+
+    let (s, hemisphere) = match s.chars().last() {
+        Some(c @ ('N' | 'E' | 'S' | 'W')) if hemispheres => {
+            (s[..s.len() - 1].trim_end(), Some(c == 'S' || c == 'W'))
+        }
+        _ => (s, None),
+    };
+    let (s, sign) = match s.chars().next() {
+        Some(c @ ('-' | '−' | '+')) => (&s[c.len_utf8()..], Some(c != '+')),
+        _ => (s, None),
+    };
+    let negative = match (hemisphere, sign) {
+        (Some(_), Some(_)) => return Err(invalid),
+        (h, s) => h.or(s).unwrap_or(false),
+    };
+
+    // The components as their positions (0 for degrees or hours, 1 for minutes, and 2 for
+    // seconds) and their text
+    let mut parts: Vec<(usize, &str)> = Vec::new();
+    let mut rest = s;
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number = &rest[..end];
+        if !number.chars().any(|c| c.is_ascii_digit()) {
+            return Err(invalid);
+        }
+        rest = &rest[end..];
+
+        let next = parts.last().map_or(0, |&(p, _)| p + 1);
+        let marker = rest
+            .chars()
+            .next()
+            .and_then(|c| markers.iter().position(|m| m.contains(c)).map(|p| (p, c)));
+        let position = match marker {
+            Some((p, c)) => {
+                rest = &rest[c.len_utf8()..];
+                p
+            }
+            None => next,
+        };
+        let trimmed = rest.trim_start_matches(|c: char| c == ':' || c.is_whitespace());
+        if marker.is_none() && !trimmed.is_empty() && trimmed.len() == rest.len() {
+            return Err(invalid);
+        }
+        rest = trimmed;
+        if position < next || position > 2 {
+            return Err(invalid);
+        }
+        parts.push((position, number));
+    }
+
+    let (mut units, mut minutes, mut seconds) = (0, 0, T::ZERO);
+    let (last_position, last) = parts.pop().ok_or_else(|| invalid.clone())?;
+    for (position, text) in parts {
+        let n = text.parse::<u32>().map_err(|_| invalid.clone())?;
+        if position == 0 {
+            units = n;
+        } else {
+            minutes = n;
+        }
+    }
+    if last_position == 2 {
+        seconds = last.parse::<T>().map_err(|_| invalid.clone())?;
+    } else if !last.contains('.') {
+        let n = last.parse::<u32>().map_err(|_| invalid.clone())?;
+        if last_position == 0 {
+            units = n;
+        } else {
+            minutes = n;
+        }
+    } else {
+        // A fractional number of units or minutes, which is split like any other value
+        let value = last.parse::<T>().map_err(|_| invalid.clone())?;
+        let scale = if last_position == 0 { 3600.0 } else { 60.0 };
+        let (_, u, m, s) = split(value, scale).ok_or_else(|| invalid.clone())?;
+        if last_position == 0 {
+            units = u;
+        } else if u > 0 {
+            return Err(invalid);
+        }
+        minutes += m;
+        seconds = s;
+    }
+
+    if minutes >= 60 || !(seconds >= T::ZERO && seconds < T::from(60.0)) {
+        return Err(invalid);
+    }
+    Ok((negative, units, minutes, seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd, Double, Quad};
+
+    #[test]
+    fn conversions() {
+        let angle = Dms::from_degrees(qd!(12.5625)).unwrap();
+        assert_eq!(angle, Dms::new(false, 12, 33, qd!(45)));
+        assert_eq!(angle.to_degrees(), qd!(12.5625));
+        let angle = Dms::from_degrees(qd!(-0.51)).unwrap();
+        assert_eq!(angle, Dms::new(true, 0, 30, qd!(36)));
+        assert!((angle.to_degrees() - qd!(-0.51)).abs() < qd!(1e-63));
+
+        // A third of a degree is 20', with no seconds left over
+        let third = qd!(1) / qd!(3);
+        assert_eq!(
+            Dms::from_degrees(third),
+            Some(Dms::new(false, 0, 20, qd!(0)))
+        );
+        assert_eq!(Dms::new(false, 0, 20, qd!(0)).to_degrees(), third);
+
+        let angle = Dms::from_radians(Double::FRAC_PI_4).unwrap();
+        assert_eq!((angle.degrees, angle.minutes), (45, 0));
+        assert!(angle.seconds < dd!(1e-25));
+        assert!((angle.to_radians() - Double::FRAC_PI_4).abs() < dd!(1e-31));
+
+        let time = Hms::from_hours(qd!(-23.999_75)).unwrap();
+        assert_eq!((time.negative, time.hours, time.minutes), (true, 23, 59));
+        assert!((time.seconds - qd!(59.1)).abs() < qd!(1e-58));
+        assert!((time.to_hours() - qd!(-23.999_75)).abs() < qd!(1e-62));
+        assert_eq!(
+            Hms::from_degrees(qd!(360)),
+            Some(Hms::new(false, 24, 0, qd!(0)))
+        );
+
+        assert_eq!(Dms::from_degrees(Double::NAN), None);
+        assert_eq!(Hms::from_hours(Quad::INFINITY), None);
+    }
+
+    #[test]
+    fn display() {
+        let angle = Dms::new(false, 9, 5, dd!(3.25));
+        assert_eq!(angle.to_string(), "9°05'03.25\"");
+        assert_eq!(format!("{:.0}", angle), "9°05'03\"");
+        assert_eq!(format!("{:.3}", angle), "9°05'03.250\"");
+
+        // Seconds that round up to 60 carry into the minutes and degrees
+        let angle = Dms::new(true, 9, 59, qd!(59.96));
+        assert_eq!(format!("{:.1}", angle), "-10°00'00.0\"");
+        assert_eq!(format!("{:.2}", angle), "-9°59'59.96\"");
+        let time = Hms::new(false, 23, 4, qd!(59.5));
+        assert_eq!(format!("{:.0}", time), "23h05m00s");
+    }
+
+    #[test]
+    fn parsing() {
+        let expected = Dms::new(false, 12, 34, dd!(56.5));
+        for s in &[
+            "12°34'56.5\"",
+            " 12° 34′ 56.5″ ",
+            "12d34m56.5s",
+            "12:34:56.5",
+            "12 34 56.5",
+            "+12°34'56.5\"",
+            "12°34'56.5\"N",
+            "12°34.941666666666666666666666666667'",
+        ] {
+            let angle = s.parse::<Dms<Double>>().unwrap();
+            assert!(
+                (angle.to_degrees() - expected.to_degrees()).abs() < dd!(1e-29),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            "12°34'56.5\"W".parse(),
+            Ok(Dms::new(true, 12, 34, dd!(56.5)))
+        );
+        assert_eq!("-0°0'1\"".parse(), Ok(Dms::new(true, 0, 0, qd!(1))));
+        assert_eq!("45°".parse(), Ok(Dms::new(false, 45, 0, qd!(0))));
+        assert_eq!("45°12\"".parse(), Ok(Dms::new(false, 45, 0, qd!(12))));
+        assert_eq!("30.5'".parse(), Ok(Dms::new(false, 0, 30, qd!(30))));
+        assert_eq!("12.25°".parse(), Ok(Dms::new(false, 12, 15, qd!(0))));
+
+        assert_eq!("5h35m17.3s".parse(), Ok(Hms::new(false, 5, 35, qd!(17.3))));
+        assert_eq!("-5:35".parse(), Ok(Hms::new(true, 5, 35, dd!(0))));
+        assert_eq!("5.5h".parse(), Ok(Hms::new(false, 5, 30, dd!(0))));
+
+        fn error<A>(kind: ErrorKind) -> Result<A, ParseAngleError> {
+            Err(ParseAngleError { kind })
+        }
+        assert_eq!("".parse::<Dms<Double>>(), error(ErrorKind::Empty));
+        assert_eq!(" ".parse::<Hms<Quad>>(), error(ErrorKind::Empty));
+        for s in &[
+            "12°60'",
+            "12°30'60\"",
+            "12.5°30'",
+            "12'30°",
+            "12°30'15\"20",
+            "-12°N",
+            "12x",
+            "°",
+            "12°.'",
+            "90.5'",
+            "1:2:3:4",
+            "12°34'56.5\"Q",
+        ] {
+            assert_eq!(s.parse::<Dms<Double>>(), error(ErrorKind::Invalid), "{}", s);
+        }
+        assert_eq!("5h35mN".parse::<Hms<Double>>(), error(ErrorKind::Invalid));
+        assert_eq!(
+            "1°2'x".parse::<Dms<Quad>>().unwrap_err().to_string(),
+            "invalid angle"
+        );
+    }
+}
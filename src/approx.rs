@@ -0,0 +1,523 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Polynomial approximations of functions, computed in [`Quad`] precision.
+//!
+//! A common use of quad-doubles is generating the coefficients of the polynomial
+//! approximations that `f64` math libraries are built on. Those coefficients have to be
+//! computed in much higher precision than they're finally used in, or the rounding errors
+//! of the fitting swamp the error of the approximation itself. This module does the fitting
+//! for any function given as a closure:
+//!
+//! * [`chebyshev`] interpolates the function at Chebyshev nodes and returns the
+//!   coefficients of its Chebyshev series, which is very nearly the best approximation of
+//!   its degree and can be evaluated with [`Quad::chebyshev_eval`].
+//! * [`minimax`] runs the Remez exchange algorithm to find the polynomial with the smallest
+//!   maximum error (absolute or relative) over the interval.
+//! * [`chebyshev_to_polynomial`] converts a Chebyshev series into an ordinary
+//!   [`Polynomial`] in *x*, whose coefficients can be rounded to `f64`s.
+//!
+//! # Examples
+//! ```
+//! use qd::approx::{minimax, Options};
+//! use qd::{qd, Quad};
+//!
+//! // A degree 6 approximation of cos x over [0, π/4], with minimal relative error
+//! let options = Options::relative();
+//! let fit = minimax(|x| x.cos(), qd!(0), Quad::FRAC_PI_4, 6, options).unwrap();
+//! assert!(fit.error < qd!(2e-9));
+//!
+//! let coeffs: Vec<f64> = fit.polynomial.coeffs().iter().map(|&c| c.into()).collect();
+//! assert_eq!(coeffs.len(), 7);
+//! assert!((coeffs[0] - 1.0).abs() < 1e-8);
+//! assert!((coeffs[2] + 0.5).abs() < 1e-5);
+//! ```
+//!
+//! [`Quad`]: crate::Quad
+//! [`Quad::chebyshev_eval`]: crate::Quad::chebyshev_eval
+//! [`Polynomial`]: crate::poly::Polynomial
+//! [`chebyshev`]: crate::approx::chebyshev
+//! [`minimax`]: crate::approx::minimax
+//! [`chebyshev_to_polynomial`]: crate::approx::chebyshev_to_polynomial
+
+use crate::linalg::{Matrix, Vector};
+use crate::poly::Polynomial;
+use crate::quad::Quad;
+use std::fmt::{self, Display, Formatter};
+
+/// Computes the first `n` coefficients of the Chebyshev series of `f` over [`a`, `b`].
+///
+/// The coefficients are those of the polynomial of degree `n - 1` that interpolates `f` at
+/// the `n` Chebyshev nodes of the interval. For smooth functions they decrease quickly, and
+/// the size of the first one left out is a good estimate of the error of the series. The
+/// first coefficient isn't halved, so the series can be passed straight to
+/// [`Quad::chebyshev_eval`], evaluated at (2*x* - *a* - *b*) / (*b* - *a*).
+///
+/// # Panics
+/// Panics if `a` isn't less than `b`.
+///
+/// # Examples
+/// ```
+/// # use qd::approx::chebyshev;
+/// # use qd::{qd, Quad};
+/// let coeffs = chebyshev(|x| x.exp(), qd!(-1), qd!(1), 45);
+/// let x = qd!(0.3);
+/// assert!((Quad::chebyshev_eval(&coeffs, x) - x.exp()).abs() < qd!(1e-60));
+/// ```
+///
+/// [`Quad::chebyshev_eval`]: crate::Quad::chebyshev_eval
+pub fn chebyshev<F>(f: F, a: Quad, b: Quad, n: usize) -> Vec<Quad>
+where
+    F: Fn(Quad) -> Quad,
+{
+    assert!(a < b, "the interval must have a < b");
+    if n == 0 {
+        return vec![];
+    }
+    let (mid, half) = ((a + b).mul_pwr2(0.5), (b - a).mul_pwr2(0.5));
+
+    // The nodes are cos(π(2j + 1) / 2n), and the cosines needed for the coefficients are
+    // all cos(πm / 2n) for some integer m, so they're computed once
+    let cosines: Vec<Quad> = (0..4 * n)
+        .map(|m| {
+            (Quad::from(m as f64) / Quad::from(2.0 * n as f64))
+                .sin_cos_pi()
+                .1
+        })
+        .collect();
+    let values: Vec<Quad> = (0..n).map(|j| f(mid + half * cosines[2 * j + 1])).collect();
+
+    let scale = Quad::from(2.0) / Quad::from(n as f64);
+    (0..n)
+        .map(|k| {
+            let sum: Quad = values
+                .iter()
+                .enumerate()
+                .map(|(j, &v)| v * cosines[k * (2 * j + 1) % (4 * n)])
+                .sum();
+            if k == 0 {
+                sum * scale.mul_pwr2(0.5)
+            } else {
+                sum * scale
+            }
+        })
+        .collect()
+}
+
+/// Converts a Chebyshev series over [`a`, `b`] into a polynomial in *x*.
+///
+/// `coeffs` has the coefficients of the series in the form that [`chebyshev`] returns and
+/// [`Quad::chebyshev_eval`] takes. The conversion is exact apart from rounding, but the
+/// coefficients of the polynomial can be much larger than the values it takes, especially
+/// for high degrees and for intervals far from the origin, and then evaluating it loses
+/// precision through cancellation.
+///
+/// # Panics
+/// Panics if `a` isn't less than `b`.
+///
+/// # Examples
+/// ```
+/// # use qd::approx::chebyshev_to_polynomial;
+/// # use qd::qd;
+/// // T₂(x) = 2x² - 1
+/// let p = chebyshev_to_polynomial(&[qd!(0), qd!(0), qd!(1)], qd!(-1), qd!(1));
+/// assert_eq!(p.coeffs(), &[qd!(-1), qd!(0), qd!(2)]);
+/// ```
+///
+/// [`chebyshev`]: crate::approx::chebyshev
+/// [`Quad::chebyshev_eval`]: crate::Quad::chebyshev_eval
+pub fn chebyshev_to_polynomial(coeffs: &[Quad], a: Quad, b: Quad) -> Polynomial<Quad> {
+    assert!(a < b, "the interval must have a < b");
+    // The variable of the series is t = αx + β
+    let alpha = Quad::from(2.0) / (b - a);
+    let beta = -(a + b) / (b - a);
+
+    let mut result = vec![Quad::ZERO; coeffs.len()];
+    // Tₖ₋₁ and Tₖ as polynomials in x, using Tₖ₊₁ = 2tTₖ - Tₖ₋₁
+    let mut previous = vec![Quad::ONE];
+    let mut current = vec![beta, alpha];
+    for (k, &c) in coeffs.iter().enumerate() {
+        let term = if k == 0 { &previous } else { &current };
+        for (r, &t) in result.iter_mut().zip(term.iter()) {
+            *r += c * t;
+        }
+        if k > 0 {
+            let mut next = vec![Quad::ZERO; current.len() + 1];
+            for (i, &t) in current.iter().enumerate() {
+                next[i] += (beta * t).mul_pwr2(2.0);
+                next[i + 1] += (alpha * t).mul_pwr2(2.0);
+            }
+            for (n, &p) in next.iter_mut().zip(previous.iter()) {
+                *n -= p;
+            }
+            previous = std::mem::replace(&mut current, next);
+        }
+    }
+    Polynomial::new(result)
+}
+
+/// The options of a minimax approximation.
+///
+/// `relative` chooses whether the relative error or the absolute error is minimized.
+/// The Remez algorithm stops when the extrema of the error are level to within a relative
+/// `tolerance` of each other, and it gives up after `max_iterations` exchanges. The default
+/// minimizes the absolute error with a tolerance of 10⁻¹² and 50 iterations.
+///
+/// # Examples
+/// ```
+/// # use qd::approx::Options;
+/// let options = Options::new(true, 1e-20, 100);
+/// assert!(options.relative);
+/// assert_eq!(Options::relative(), Options::new(true, 1e-12, 50));
+/// assert_eq!(Options::default(), Options::new(false, 1e-12, 50));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Options {
+    /// Whether to minimize the relative error instead of the absolute error.
+    pub relative: bool,
+    /// How close the extrema of the error must be to each other, relative to their size.
+    pub tolerance: f64,
+    /// The maximum number of exchanges before the algorithm gives up.
+    pub max_iterations: usize,
+}
+
+impl Options {
+    /// Creates new minimax options.
+    pub const fn new(relative: bool, tolerance: f64, max_iterations: usize) -> Options {
+        Options {
+            relative,
+            tolerance,
+            max_iterations,
+        }
+    }
+
+    /// Creates the default options, except that the relative error is minimized.
+    pub const fn relative() -> Options {
+        Options::new(true, 1e-12, 50)
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options::new(false, 1e-12, 50)
+    }
+}
+
+/// The result of a minimax approximation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Minimax {
+    /// The approximating polynomial, in *x*.
+    pub polynomial: Polynomial<Quad>,
+    /// The maximum error (absolute or relative) of the polynomial over the interval.
+    pub error: Quad,
+    /// The number of exchanges that the Remez algorithm made.
+    pub iterations: usize,
+}
+
+/// The reasons that a minimax approximation can fail.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MinimaxError {
+    /// The function wasn't finite at some point of the interval, or it was zero while the
+    /// relative error was being minimized.
+    NotFinite,
+    /// The linear system for the next polynomial was singular.
+    Singular,
+    /// The error didn't level out in the allowed number of iterations. This gives the last
+    /// approximation, which is often still usable.
+    NoConvergence(Minimax),
+}
+
+impl Display for MinimaxError {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let description = match self {
+            MinimaxError::NotFinite => "function is not finite or has a zero",
+            MinimaxError::Singular => "minimax system is singular",
+            MinimaxError::NoConvergence(_) => "minimax approximation did not converge",
+        };
+        description.fmt(f)
+    }
+}
+
+/// Finds the polynomial of the given degree with the smallest maximum error in
+/// approximating `f` over [`a`, `b`].
+///
+/// This is the Remez exchange algorithm. It starts from the extrema of the Chebyshev
+/// polynomial of the next higher degree, and in each exchange it finds the polynomial whose
+/// error alternates in sign and has equal size at the reference points, then moves the
+/// reference points to the extrema of that error. The best approximation is reached when
+/// the error at those extrema is level, which usually takes a handful of exchanges. The
+/// extrema are located on a grid of 16 points per reference point and then refined by a
+/// golden section search, so `f` is called some thousands of times per exchange.
+///
+/// The polynomial is computed in the Chebyshev basis over the interval and converted with
+/// [`chebyshev_to_polynomial`] at the end, so the same caveat applies to its coefficients.
+///
+/// # Errors
+/// Returns [`MinimaxError::NotFinite`] if `f` isn't finite at a point where it's evaluated
+/// (or is zero there, if `options.relative` is set), [`MinimaxError::Singular`] if a
+/// linear system can't be solved, and [`MinimaxError::NoConvergence`] if the error hasn't
+/// leveled out after `options.max_iterations` exchanges.
+///
+/// # Panics
+/// Panics if `a` isn't less than `b`.
+///
+/// # Examples
+/// ```
+/// # use qd::approx::{minimax, Options};
+/// # use qd::qd;
+/// // The best straight line through x² over [0, 1] is x - 1/8
+/// let fit = minimax(|x| x * x, qd!(0), qd!(1), 1, Options::default()).unwrap();
+/// assert!((fit.error - qd!(0.125)).abs() < qd!(1e-30));
+/// assert!((fit.polynomial.eval(qd!(0.5)) - qd!(0.375)).abs() < qd!(1e-30));
+/// ```
+///
+/// [`chebyshev_to_polynomial`]: crate::approx::chebyshev_to_polynomial
+/// [`MinimaxError::NotFinite`]: crate::approx::MinimaxError::NotFinite
+/// [`MinimaxError::Singular`]: crate::approx::MinimaxError::Singular
+/// [`MinimaxError::NoConvergence`]: crate::approx::MinimaxError::NoConvergence
+pub fn minimax<F>(
+    f: F,
+    a: Quad,
+    b: Quad,
+    degree: usize,
+    options: Options,
+) -> Result<Minimax, MinimaxError>
+where
+    F: Fn(Quad) -> Quad,
+{
+    assert!(a < b, "the interval must have a < b");
+    let (mid, half) = ((a + b).mul_pwr2(0.5), (b - a).mul_pwr2(0.5));
+    let n = degree + 2;
+    let tolerance = Quad::from(options.tolerance);
+
+    // Everything is done in t ∈ [-1, 1], where x = mid + half·t. The weight turns the
+    // absolute error into the relative error when that's what is minimized.
+    let g = |t: Quad| {
+        let y = f(mid + half * t);
+        let w = if options.relative {
+            y.abs().recip()
+        } else {
+            Quad::ONE
+        };
+        if y.is_finite() && w.is_finite() {
+            Ok((y, w))
+        } else {
+            Err(MinimaxError::NotFinite)
+        }
+    };
+    let error = |coeffs: &[Quad], t: Quad| -> Result<Quad, MinimaxError> {
+        let (y, w) = g(t)?;
+        Ok((Quad::chebyshev_eval(coeffs, t) - y) * w)
+    };
+
+    let grid: Vec<Quad> = (0..=16 * n)
+        .map(|k| {
+            -(Quad::from(k as f64) / Quad::from(16.0 * n as f64))
+                .sin_cos_pi()
+                .1
+        })
+        .collect();
+    let samples = grid
+        .iter()
+        .map(|&t| g(t))
+        .collect::<Result<Vec<(Quad, Quad)>, MinimaxError>>()?;
+    // The size of the weighted function, against which the error is negligible when the
+    // function is itself a polynomial of the degree (or lower)
+    let scale = samples
+        .iter()
+        .fold(Quad::ZERO, |m, &(y, w)| m.max((y * w).abs()));
+    let mut reference: Vec<Quad> = (0..n)
+        .map(|i| {
+            -(Quad::from(i as f64) / Quad::from((n - 1) as f64))
+                .sin_cos_pi()
+                .1
+        })
+        .collect();
+
+    let mut iterations = 0;
+    loop {
+        iterations += 1;
+
+        // Solve Σ cⱼTⱼ(tᵢ) + (-1)ⁱE/w(tᵢ) = g(tᵢ) for the coefficients and the level E
+        let mut rhs = Vec::with_capacity(n);
+        let mut rows = Vec::with_capacity(n * n);
+        for (i, &t) in reference.iter().enumerate() {
+            let (y, w) = g(t)?;
+            rhs.push(y);
+            let (mut t0, mut t1) = (Quad::ONE, t);
+            for _ in 0..n - 1 {
+                rows.push(t0);
+                let t2 = (t * t1).mul_pwr2(2.0) - t0;
+                t0 = t1;
+                t1 = t2;
+            }
+            let level = w.recip();
+            rows.push(if i % 2 == 0 { level } else { -level });
+        }
+        let solution = Matrix::new(n, n, rows)
+            .solve(&Vector::new(rhs))
+            .ok_or(MinimaxError::Singular)?;
+        let coeffs = &solution.as_slice()[..n - 1];
+
+        // The extrema of the error, one per run of the same sign on the grid
+        let errors: Vec<Quad> = grid
+            .iter()
+            .zip(samples.iter())
+            .map(|(&t, &(y, w))| (Quad::chebyshev_eval(coeffs, t) - y) * w)
+            .collect();
+        let mut extrema: Vec<(usize, Quad)> = Vec::new();
+        for (k, &e) in errors.iter().enumerate() {
+            match extrema.last_mut() {
+                Some(last) if last.1.is_sign_negative() == e.is_sign_negative() => {
+                    if e.abs() > last.1.abs() {
+                        *last = (k, e);
+                    }
+                }
+                _ => extrema.push((k, e)),
+            }
+        }
+
+        let max_error = errors.iter().fold(Quad::ZERO, |m, e| m.max(e.abs()));
+        let done = |error| Minimax {
+            polynomial: chebyshev_to_polynomial(coeffs, a, b),
+            error,
+            iterations,
+        };
+        if max_error <= scale * Quad::EPSILON.mul_pwr2(16.0) || extrema.len() < n {
+            return Ok(done(max_error));
+        }
+        // Drop extrema from whichever end is smaller until there are exactly enough, which
+        // keeps both the alternation and the largest error
+        while extrema.len() > n {
+            if extrema[0].1.abs() < extrema[extrema.len() - 1].1.abs() {
+                extrema.remove(0);
+            } else {
+                extrema.pop();
+            }
+        }
+
+        let mut new_reference = Vec::with_capacity(n);
+        let (mut smallest, mut largest) = (Quad::INFINITY, Quad::ZERO);
+        for &(k, _) in &extrema {
+            let lo = grid[k.saturating_sub(1)];
+            let hi = grid[(k + 1).min(grid.len() - 1)];
+            let (t, e) = golden_max(|t| error(coeffs, t).map(|e| e.abs()), lo, hi)?;
+            new_reference.push(t);
+            smallest = smallest.min(e);
+            largest = largest.max(e);
+        }
+        reference = new_reference;
+
+        if largest - smallest <= tolerance * largest {
+            return Ok(done(largest.max(max_error)));
+        }
+        if iterations >= options.max_iterations {
+            return Err(MinimaxError::NoConvergence(done(largest.max(max_error))));
+        }
+    }
+}
+
+// Finds the maximum of a unimodal function over [lo, hi] with a golden section search,
+// returning the point and the value there.
+fn golden_max<F>(f: F, lo: Quad, hi: Quad) -> Result<(Quad, Quad), MinimaxError>
+where
+    F: Fn(Quad) -> Result<Quad, MinimaxError>,
+{
+    let ratio = (Quad::from(5.0).sqrt() - Quad::ONE).mul_pwr2(0.5);
+    let (mut lo, mut hi) = (lo, hi);
+    let mut x1 = hi - ratio * (hi - lo);
+    let mut x2 = lo + ratio * (hi - lo);
+    let (mut f1, mut f2) = (f(x1)?, f(x2)?);
+    // Each step shrinks the bracket by the golden ratio; 100 of them leave about 10⁻²¹ of
+    // it, which puts the maximum value within 10⁻⁴² of the true one
+    for _ in 0..100 {
+        if f1 > f2 {
+            hi = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = hi - ratio * (hi - lo);
+            f1 = f(x1)?;
+        } else {
+            lo = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = lo + ratio * (hi - lo);
+            f2 = f(x2)?;
+        }
+    }
+    // The ends of the bracket are candidates too, since extrema can be at the endpoints of
+    // the whole interval
+    let candidates = [(lo, f(lo)?), (hi, f(hi)?), (x1, f1), (x2, f2)];
+    Ok(candidates.iter().fold(
+        candidates[0],
+        |best, &c| if c.1 > best.1 { c } else { best },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::qd;
+
+    #[test]
+    fn chebyshev_series() {
+        let coeffs = chebyshev(|x| x.ln(), qd!(1), qd!(3), 120);
+        let p = chebyshev_to_polynomial(&coeffs[..12], qd!(1), qd!(3));
+        for i in 0..=20 {
+            let x = qd!(1) + Quad::from(i as f64) / qd!(10);
+            let t = x - qd!(2);
+            let series = Quad::chebyshev_eval(&coeffs, t);
+            assert!((series - x.ln()).abs() < qd!(1e-58));
+            let truncated = Quad::chebyshev_eval(&coeffs[..12], t);
+            assert!((p.eval(x) - truncated).abs() < qd!(1e-55));
+        }
+        assert!(chebyshev(|x| x, qd!(0), qd!(1), 0).is_empty());
+    }
+
+    #[test]
+    fn minimax_exp() {
+        let fit = minimax(|x| x.exp(), qd!(0), qd!(1), 6, Options::relative()).unwrap();
+        // The error of a degree 6 fit is about 2.4 × 10⁻⁸, and nowhere bigger than reported
+        assert!(fit.error > qd!(2e-8) && fit.error < qd!(3e-8));
+        let mut largest = Quad::ZERO;
+        for i in 0..=1000 {
+            let x = Quad::from(i as f64) / qd!(1000);
+            let e = ((fit.polynomial.eval(x) - x.exp()) / x.exp()).abs();
+            largest = largest.max(e);
+        }
+        assert!(largest <= fit.error * qd!(1.000_000_000_001));
+        assert!(largest >= fit.error * qd!(0.999));
+    }
+
+    #[test]
+    fn minimax_polynomial() {
+        // A function that the polynomial can match exactly
+        let f = |x: Quad| qd!(3) * x * x - x + qd!(0.5);
+        let fit = minimax(f, qd!(-2), qd!(5), 4, Options::default()).unwrap();
+        assert!(fit.error < qd!(1e-60));
+        let coeffs = fit.polynomial.coeffs();
+        assert!((coeffs[0] - qd!(0.5)).abs() < qd!(1e-60));
+        assert!((coeffs[2] - qd!(3)).abs() < qd!(1e-60));
+    }
+
+    #[test]
+    fn failures() {
+        let result = minimax(|x| x, qd!(-1), qd!(1), 3, Options::relative());
+        assert_eq!(result, Err(MinimaxError::NotFinite));
+        let result = minimax(
+            |x| x.abs().sqrt(),
+            qd!(-1),
+            qd!(1),
+            8,
+            Options::new(false, 0.0, 2),
+        );
+        assert!(matches!(result, Err(MinimaxError::NoConvergence(_))));
+        assert_eq!(
+            MinimaxError::Singular.to_string(),
+            "minimax system is singular"
+        );
+    }
+}
@@ -0,0 +1,401 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Conversions between slices of [`Double`]s and [`Quad`]s and Apache Arrow arrays.
+//!
+//! This module is enabled by the `arrow` feature. Arrow has no double-double or quad-double
+//! type, so there are two ways to store these numbers in an Arrow array (and so in a
+//! Parquet file, or anything else that reads and writes Arrow data):
+//!
+//! * As a [`FixedSizeBinaryArray`], with each number taking 16 bytes (for a `Double`) or 32
+//!   bytes (for a `Quad`). Those bytes are the components in order, each one an `f64` in
+//!   little-endian byte order. This keeps each number in one column.
+//! * As two or four [`Float64Array`]s, one for each component. Each column is then an
+//!   ordinary floating-point column that other tools can read, and the first column alone
+//!   is the number rounded to an `f64`.
+//!
+//! Either way, converting back gives exactly the numbers that went in. Arrow arrays with
+//! nulls can't be converted back, since there's no `Double` or `Quad` for a null to become.
+//!
+//! # Examples
+//! ```
+//! use qd::arrow;
+//! use qd::{qd, Quad};
+//!
+//! let values = [Quad::PI, qd!(1) / qd!(3), -Quad::E];
+//!
+//! let array = arrow::quad_to_binary(&values);
+//! assert_eq!(arrow::quad_from_binary(&array).unwrap(), values);
+//!
+//! let columns = arrow::quad_to_columns(&values);
+//! assert_eq!(columns[0].value(0), std::f64::consts::PI);
+//! let [a, b, c, d] = &columns;
+//! assert_eq!(arrow::quad_from_columns([a, b, c, d]).unwrap(), values);
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`FixedSizeBinaryArray`]: arrow_array::FixedSizeBinaryArray
+//! [`Float64Array`]: arrow_array::Float64Array
+
+use crate::double::Double;
+use crate::quad::Quad;
+use arrow_array::{Array, FixedSizeBinaryArray, Float64Array};
+use arrow_buffer::Buffer;
+use arrow_schema::ArrowError;
+
+// Writes the components of a number into a fixed-size binary array's value buffer.
+fn to_binary<const N: usize>(
+    components: impl ExactSizeIterator<Item = [f64; N]>,
+) -> FixedSizeBinaryArray {
+    let mut bytes = Vec::with_capacity(components.len() * N * 8);
+    for c in components {
+        for x in c {
+            bytes.extend_from_slice(&x.to_le_bytes());
+        }
+    }
+    FixedSizeBinaryArray::new((N * 8) as i32, Buffer::from_vec(bytes), None)
+}
+
+// Reads the components of each number from a fixed-size binary array, checking that the
+// values are the right size and that there are no nulls.
+fn from_binary<const N: usize>(array: &FixedSizeBinaryArray) -> Result<Vec<[f64; N]>, ArrowError> {
+    if array.value_length() as usize != N * 8 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "expected values of {} bytes, found {}",
+            N * 8,
+            array.value_length()
+        )));
+    }
+    check_nulls(array)?;
+
+    Ok((0..array.len())
+        .map(|i| {
+            let bytes = array.value(i);
+            let mut c = [0.0; N];
+            for (j, x) in c.iter_mut().enumerate() {
+                let mut b = [0; 8];
+                b.copy_from_slice(&bytes[j * 8..j * 8 + 8]);
+                *x = f64::from_le_bytes(b);
+            }
+            c
+        })
+        .collect())
+}
+
+// Splits the components of each number into columns.
+fn to_columns<const N: usize>(
+    components: impl ExactSizeIterator<Item = [f64; N]>,
+) -> [Float64Array; N] {
+    let len = components.len();
+    let mut columns: [Vec<f64>; N] = std::array::from_fn(|_| Vec::with_capacity(len));
+    for c in components {
+        for (column, x) in columns.iter_mut().zip(c) {
+            column.push(x);
+        }
+    }
+    columns.map(Float64Array::from)
+}
+
+// Gathers the components of each number from columns, checking that the columns are the
+// same length and that there are no nulls.
+fn from_columns<const N: usize>(columns: [&Float64Array; N]) -> Result<Vec<[f64; N]>, ArrowError> {
+    let len = columns[0].len();
+    for column in columns {
+        if column.len() != len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "expected columns of the same length, found lengths {} and {}",
+                len,
+                column.len()
+            )));
+        }
+        check_nulls(column)?;
+    }
+
+    Ok((0..len)
+        .map(|i| std::array::from_fn(|j| columns[j].value(i)))
+        .collect())
+}
+
+fn check_nulls(array: &dyn Array) -> Result<(), ArrowError> {
+    if array.null_count() > 0 {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "expected no nulls, found {}",
+            array.null_count()
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Converts a slice of `Double`s into a `FixedSizeBinaryArray` with 16-byte values.
+///
+/// # Examples
+/// ```
+/// use qd::{arrow, Double};
+///
+/// let array = arrow::double_to_binary(&[Double::PI, Double::E]);
+/// assert_eq!(array.value_length(), 16);
+/// assert_eq!(array.value(0)[..8], std::f64::consts::PI.to_le_bytes());
+/// ```
+pub fn double_to_binary(values: &[Double]) -> FixedSizeBinaryArray {
+    to_binary(values.iter().map(|x| [x.hi(), x.lo()]))
+}
+
+/// Converts a `FixedSizeBinaryArray` that was made by [`double_to_binary`] back into
+/// `Double`s.
+///
+/// # Errors
+/// An [`ArrowError::InvalidArgumentError`] if the values of the array aren't 16 bytes long
+/// or if the array has any nulls.
+///
+/// # Examples
+/// ```
+/// use qd::{arrow, dd};
+///
+/// let values = [dd!(0.1), dd!(0.2), dd!(0.3)];
+/// let array = arrow::double_to_binary(&values);
+/// assert_eq!(arrow::double_from_binary(&array).unwrap(), values);
+/// ```
+///
+/// [`double_to_binary`]: crate::arrow::double_to_binary
+/// [`ArrowError::InvalidArgumentError`]: arrow_schema::ArrowError::InvalidArgumentError
+pub fn double_from_binary(array: &FixedSizeBinaryArray) -> Result<Vec<Double>, ArrowError> {
+    Ok(from_binary(array)?
+        .into_iter()
+        .map(|[a, b]| Double::new(a, b))
+        .collect())
+}
+
+/// Converts a slice of `Double`s into two `Float64Array`s, the first holding the first
+/// component of each number and the second holding the second component.
+///
+/// # Examples
+/// ```
+/// use qd::{arrow, Double};
+///
+/// let [hi, lo] = arrow::double_to_columns(&[Double::PI]);
+/// assert_eq!(hi.value(0), Double::PI.hi());
+/// assert_eq!(lo.value(0), Double::PI.lo());
+/// ```
+pub fn double_to_columns(values: &[Double]) -> [Float64Array; 2] {
+    to_columns(values.iter().map(|x| [x.hi(), x.lo()]))
+}
+
+/// Converts two `Float64Array`s that were made by [`double_to_columns`] back into
+/// `Double`s.
+///
+/// # Errors
+/// An [`ArrowError::InvalidArgumentError`] if the arrays aren't the same length or if
+/// either of them has any nulls.
+///
+/// # Examples
+/// ```
+/// use qd::{arrow, dd};
+///
+/// let values = [dd!(0.1), dd!(0.2), dd!(0.3)];
+/// let [hi, lo] = arrow::double_to_columns(&values);
+/// assert_eq!(arrow::double_from_columns([&hi, &lo]).unwrap(), values);
+/// ```
+///
+/// [`double_to_columns`]: crate::arrow::double_to_columns
+/// [`ArrowError::InvalidArgumentError`]: arrow_schema::ArrowError::InvalidArgumentError
+pub fn double_from_columns(columns: [&Float64Array; 2]) -> Result<Vec<Double>, ArrowError> {
+    Ok(from_columns(columns)?
+        .into_iter()
+        .map(|[a, b]| Double::new(a, b))
+        .collect())
+}
+
+/// Converts a slice of `Quad`s into a `FixedSizeBinaryArray` with 32-byte values.
+///
+/// # Examples
+/// ```
+/// use qd::{arrow, Quad};
+///
+/// let array = arrow::quad_to_binary(&[Quad::PI, Quad::E]);
+/// assert_eq!(array.value_length(), 32);
+/// assert_eq!(array.value(1)[..8], std::f64::consts::E.to_le_bytes());
+/// ```
+pub fn quad_to_binary(values: &[Quad]) -> FixedSizeBinaryArray {
+    to_binary(values.iter().map(|x| [x.c0(), x.c1(), x.c2(), x.c3()]))
+}
+
+/// Converts a `FixedSizeBinaryArray` that was made by [`quad_to_binary`] back into
+/// `Quad`s.
+///
+/// # Errors
+/// An [`ArrowError::InvalidArgumentError`] if the values of the array aren't 32 bytes long
+/// or if the array has any nulls.
+///
+/// # Examples
+/// ```
+/// use qd::{arrow, qd};
+///
+/// let values = [qd!(0.1), qd!(0.2), qd!(0.3)];
+/// let array = arrow::quad_to_binary(&values);
+/// assert_eq!(arrow::quad_from_binary(&array).unwrap(), values);
+/// ```
+///
+/// [`quad_to_binary`]: crate::arrow::quad_to_binary
+/// [`ArrowError::InvalidArgumentError`]: arrow_schema::ArrowError::InvalidArgumentError
+pub fn quad_from_binary(array: &FixedSizeBinaryArray) -> Result<Vec<Quad>, ArrowError> {
+    Ok(from_binary(array)?
+        .into_iter()
+        .map(|[a, b, c, d]| Quad::new(a, b, c, d))
+        .collect())
+}
+
+/// Converts a slice of `Quad`s into four `Float64Array`s, one for each component.
+///
+/// # Examples
+/// ```
+/// use qd::{arrow, Quad};
+///
+/// let columns = arrow::quad_to_columns(&[Quad::PI]);
+/// assert_eq!(columns[0].value(0), Quad::PI.c0());
+/// assert_eq!(columns[3].value(0), Quad::PI.c3());
+/// ```
+pub fn quad_to_columns(values: &[Quad]) -> [Float64Array; 4] {
+    to_columns(values.iter().map(|x| [x.c0(), x.c1(), x.c2(), x.c3()]))
+}
+
+/// Converts four `Float64Array`s that were made by [`quad_to_columns`] back into `Quad`s.
+///
+/// # Errors
+/// An [`ArrowError::InvalidArgumentError`] if the arrays aren't all the same length or if
+/// any of them has any nulls.
+///
+/// # Examples
+/// ```
+/// use qd::{arrow, qd};
+///
+/// let values = [qd!(0.1), qd!(0.2), qd!(0.3)];
+/// let [a, b, c, d] = &arrow::quad_to_columns(&values);
+/// assert_eq!(arrow::quad_from_columns([a, b, c, d]).unwrap(), values);
+/// ```
+///
+/// [`quad_to_columns`]: crate::arrow::quad_to_columns
+/// [`ArrowError::InvalidArgumentError`]: arrow_schema::ArrowError::InvalidArgumentError
+pub fn quad_from_columns(columns: [&Float64Array; 4]) -> Result<Vec<Quad>, ArrowError> {
+    Ok(from_columns(columns)?
+        .into_iter()
+        .map(|[a, b, c, d]| Quad::new(a, b, c, d))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+    use arrow_array::builder::FixedSizeBinaryBuilder;
+
+    // Compares components rather than using `==`, so that NaNs and the signs of zeros count
+    fn same_doubles(a: &[Double], b: &[Double]) -> bool {
+        a.len() == b.len()
+            && a.iter().zip(b).all(|(x, y)| {
+                x.hi().to_bits() == y.hi().to_bits() && x.lo().to_bits() == y.lo().to_bits()
+            })
+    }
+
+    fn same_quads(a: &[Quad], b: &[Quad]) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b)
+                .all(|(x, y)| (0..4).all(|i| x[i].to_bits() == y[i].to_bits()))
+    }
+
+    fn doubles() -> Vec<Double> {
+        vec![
+            Double::PI,
+            dd!(1) / dd!(3),
+            -Double::LN_2,
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double::NEG_ZERO,
+            Double::INFINITY,
+            Double::NAN,
+        ]
+    }
+
+    fn quads() -> Vec<Quad> {
+        vec![
+            Quad::PI,
+            qd!(1) / qd!(3),
+            -Quad::LN_2,
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+            Quad::NEG_ZERO,
+            Quad::NEG_INFINITY,
+            Quad::NAN,
+        ]
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let values = doubles();
+        let array = double_to_binary(&values);
+        assert_eq!(array.len(), values.len());
+        assert!(same_doubles(&double_from_binary(&array).unwrap(), &values));
+
+        let values = quads();
+        let array = quad_to_binary(&values);
+        assert_eq!(array.len(), values.len());
+        assert!(same_quads(&quad_from_binary(&array).unwrap(), &values));
+
+        // Slices of an array keep their own values
+        let sliced = array.slice(2, 3);
+        assert!(same_quads(
+            &quad_from_binary(&sliced).unwrap(),
+            &values[2..5]
+        ));
+    }
+
+    #[test]
+    fn columns_round_trip() {
+        let values = doubles();
+        let [hi, lo] = double_to_columns(&values);
+        assert_eq!(hi.value(1), (dd!(1) / dd!(3)).hi());
+        assert!(same_doubles(
+            &double_from_columns([&hi, &lo]).unwrap(),
+            &values
+        ));
+
+        let values = quads();
+        let [a, b, c, d] = &quad_to_columns(&values);
+        assert!(same_quads(
+            &quad_from_columns([a, b, c, d]).unwrap(),
+            &values
+        ));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(double_from_binary(&double_to_binary(&[]))
+            .unwrap()
+            .is_empty());
+        let [a, b, c, d] = &quad_to_columns(&[]);
+        assert!(quad_from_columns([a, b, c, d]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        let array = double_to_binary(&[Double::PI]);
+        assert!(quad_from_binary(&array).is_err());
+
+        let mut builder = FixedSizeBinaryBuilder::new(16);
+        builder
+            .append_value(1.0f64.to_le_bytes().repeat(2))
+            .unwrap();
+        builder.append_null();
+        assert!(double_from_binary(&builder.finish()).is_err());
+
+        let hi = Float64Array::from(vec![1.0, 2.0]);
+        let lo = Float64Array::from(vec![0.0]);
+        assert!(double_from_columns([&hi, &lo]).is_err());
+        let lo = Float64Array::from(vec![Some(0.0), None]);
+        assert!(double_from_columns([&hi, &lo]).is_err());
+    }
+}
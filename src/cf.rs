@@ -0,0 +1,265 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Evaluation of continued fractions of [`Double`]s and [`Quad`]s.
+//!
+//! A continued fraction
+//!
+//! ```text
+//!                 a₁
+//! f = b₀ + ─────────────────
+//!                    a₂
+//!          b₁ + ────────────
+//!                       a₃
+//!               b₂ + ───────
+//!                    b₃ + ⋯
+//! ```
+//!
+//! is evaluated by [`lentz`], which takes closures that give the terms aₙ and bₙ and works
+//! with either [`Double`]s or [`Quad`]s through the [`MultiPrecision`] trait. Continued
+//! fractions converge where power series often don't, which makes them the usual way to
+//! calculate special functions (incomplete gamma and beta functions, ratios of Bessel
+//! functions, and the like) for large arguments.
+//!
+//! # Examples
+//! ```
+//! use qd::cf::{lentz, Options};
+//! use qd::{qd, Quad};
+//!
+//! // Lambert's continued fraction for the tangent:
+//! // tan x = x / (1 - x² / (3 - x² / (5 - ...)))
+//! let x = qd!(0.5);
+//! let tan = lentz(
+//!     |n| if n == 1 { x } else { -x * x },
+//!     |n| if n == 0 { qd!(0) } else { Quad::from((2 * n - 1) as f64) },
+//!     Options::default(),
+//! )
+//! .unwrap();
+//! assert!((tan - x.tan()).abs() < qd!(1e-62));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`MultiPrecision`]: crate::MultiPrecision
+//! [`lentz`]: crate::cf::lentz
+
+use crate::precision::MultiPrecision;
+use std::fmt::{self, Display, Formatter};
+
+/// The tolerance and the iteration limit of a continued fraction evaluation.
+///
+/// Evaluation stops successfully when the last term changed the value by a relative amount
+/// of no more than `epsilons` times the [`EPSILON`] of the type, and it gives up after
+/// `max_iterations` terms. The default is 4 epsilons and 10,000 iterations.
+///
+/// # Examples
+/// ```
+/// # use qd::cf::Options;
+/// let options = Options::new(1, 50);
+/// assert_eq!(options.epsilons, 1);
+/// assert_eq!(Options::default(), Options::new(4, 10_000));
+/// ```
+///
+/// [`EPSILON`]: crate::MultiPrecision::EPSILON
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Options {
+    /// The number of epsilons that the relative change of the value must be within.
+    pub epsilons: u32,
+    /// The maximum number of terms before the evaluation gives up.
+    pub max_iterations: usize,
+}
+
+impl Options {
+    /// Creates new continued fraction options with a tolerance in epsilons and an iteration
+    /// limit.
+    pub const fn new(epsilons: u32, max_iterations: usize) -> Options {
+        Options {
+            epsilons,
+            max_iterations,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options::new(4, 10_000)
+    }
+}
+
+/// The reasons that a continued fraction evaluation can fail.
+///
+/// Each variant gives the last value of the continued fraction before the failure.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CfError<T> {
+    /// A term or the value became infinite or NaN.
+    NotFinite(T),
+    /// The value didn't settle within the tolerance in the allowed number of terms.
+    NoConvergence(T),
+}
+
+impl<T> Display for CfError<T> {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let description = match self {
+            CfError::NotFinite(_) => "continued fraction is not finite",
+            CfError::NoConvergence(_) => "continued fraction did not converge",
+        };
+        description.fmt(f)
+    }
+}
+
+/// Evaluates a continued fraction with the modified Lentz algorithm.
+///
+/// `a` gives the partial numerators aₙ for n ≥ 1, and `b` gives the partial denominators
+/// bₙ for n ≥ 0 (including the leading term b₀, which is often zero). The closures are
+/// called in order of increasing `n`, each once per term.
+///
+/// The algorithm evaluates the fraction from the top down, multiplying the value by a
+/// correction factor for each new term, so the number of terms doesn't have to be known
+/// in advance. It replaces denominators that become zero by a tiny number, which makes the
+/// fraction well-behaved through terms where a naive evaluation would divide by zero.
+/// Each correction factor carries its own rounding error, so the error of the result is
+/// usually a few times the tolerance, growing slowly with the number of terms.
+///
+/// # Errors
+/// Returns [`CfError::NotFinite`] if a term or the value isn't finite and
+/// [`CfError::NoConvergence`] if the value hasn't converged after `max_iterations` terms.
+///
+/// # Examples
+/// ```
+/// # use qd::cf::{lentz, Options};
+/// # use qd::{dd, Double};
+/// // √2 = 1 + 1 / (2 + 1 / (2 + ...))
+/// let b = |n| if n == 0 { dd!(1) } else { dd!(2) };
+/// let sqrt2 = lentz(|_| dd!(1), b, Options::default());
+/// assert!((sqrt2.unwrap() - Double::SQRT_2).abs() < dd!(1e-30));
+/// ```
+///
+/// [`CfError::NotFinite`]: crate::cf::CfError::NotFinite
+/// [`CfError::NoConvergence`]: crate::cf::CfError::NoConvergence
+pub fn lentz<T, A, B>(mut a: A, mut b: B, options: Options) -> Result<T, CfError<T>>
+where
+    T: MultiPrecision,
+    A: FnMut(usize) -> T,
+    B: FnMut(usize) -> T,
+{
+    // Small enough to be negligible next to any reasonable term, large enough that its
+    // reciprocal (and the square of its reciprocal) doesn't overflow
+    let tiny = T::from(2f64.powi(-500));
+    let tolerance = T::EPSILON * T::from(options.epsilons as f64);
+
+    let b0 = b(0);
+    if !b0.is_finite() {
+        return Err(CfError::NotFinite(b0));
+    }
+    let mut f = if b0 == T::ZERO { tiny } else { b0 };
+    let mut c = f;
+    let mut d = T::ZERO;
+
+    for n in 1..=options.max_iterations {
+        let an = a(n);
+        let bn = b(n);
+        if !an.is_finite() || !bn.is_finite() {
+            return Err(CfError::NotFinite(f));
+        }
+
+        d = bn + an * d;
+        if d == T::ZERO {
+            d = tiny;
+        }
+        c = bn + an / c;
+        if c == T::ZERO {
+            c = tiny;
+        }
+        d = T::ONE / d;
+        let delta = c * d;
+        f = f * delta;
+
+        if !f.is_finite() {
+            return Err(CfError::NotFinite(f));
+        }
+        if (delta - T::ONE).abs() <= tolerance {
+            return Ok(f);
+        }
+    }
+    Err(CfError::NoConvergence(f))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd, Double, Quad};
+
+    #[test]
+    fn golden_ratio() {
+        let phi = lentz(|_| qd!(1), |_| qd!(1), Options::default()).unwrap();
+        let expected = (qd!(1) + qd!(5).sqrt()) / qd!(2);
+        assert!((phi - expected).abs() < qd!(1e-62));
+    }
+
+    #[test]
+    fn e() {
+        // e = 2 + 1 / (1 + 1 / (2 + 2 / (3 + 3 / (4 + ...))))
+        let e = lentz(
+            |n| Double::from((n.max(2) - 1) as f64),
+            |n| {
+                if n == 0 {
+                    dd!(2)
+                } else {
+                    Double::from(n as f64)
+                }
+            },
+            Options::default(),
+        )
+        .unwrap();
+        assert!((e - Double::E).abs() < dd!(1e-30));
+    }
+
+    #[test]
+    fn zero_denominator() {
+        // tan(π/2 - 0.25), whose first denominator in Lambert's fraction comes close to a
+        // pole without hitting it, and 1 / (0 + 1 / (1 + ...)), whose b₀ is zero
+        let x = Quad::FRAC_PI_2 - qd!(0.25);
+        let tan = lentz(
+            |n| if n == 1 { x } else { -x * x },
+            |n| {
+                if n == 0 {
+                    qd!(0)
+                } else {
+                    Quad::from((2 * n - 1) as f64)
+                }
+            },
+            Options::default(),
+        )
+        .unwrap();
+        assert!((tan - x.tan()).abs() < qd!(1e-60));
+
+        let inv_phi = lentz(
+            |_| qd!(1),
+            |n| if n == 0 { qd!(0) } else { qd!(1) },
+            Options::default(),
+        )
+        .unwrap();
+        assert!((inv_phi - (qd!(5).sqrt() - qd!(1)) / qd!(2)).abs() < qd!(1e-62));
+    }
+
+    #[test]
+    fn failures() {
+        // 1 / (0 + 1 / (0 + ...)) alternates between tiny and huge forever
+        let result = lentz(|_| dd!(1), |_| dd!(0), Options::new(4, 100));
+        assert!(matches!(result, Err(CfError::NoConvergence(_))));
+
+        let result = lentz(
+            |_| dd!(1),
+            |n| if n < 3 { dd!(1) } else { Double::NAN },
+            Options::default(),
+        );
+        assert!(matches!(result, Err(CfError::NotFinite(_))));
+        assert_eq!(
+            CfError::NoConvergence(dd!(0)).to_string(),
+            "continued fraction did not converge"
+        );
+    }
+}
@@ -3,6 +3,9 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+pub mod bigint;
+#[cfg(feature = "display")]
 pub mod display;
+pub mod fraction;
 pub mod primitive;
 pub mod utils;
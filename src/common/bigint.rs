@@ -0,0 +1,328 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::cmp::Ordering;
+
+/// A minimal arbitrary-size natural number, used to calculate integer results (factorials,
+/// binomial coefficients, and the like) exactly before they're rounded into a `Double` or a
+/// `Quad`, and to convert between numbers and their exact decimal representations. It
+/// supports only what those calculations need: addition, subtraction, and shifts,
+/// multiplication and division by small numbers, and correctly rounded conversion into
+/// `f64` components.
+///
+/// The limbs are stored least significant first, and there are never any leading zero
+/// limbs (so zero has no limbs at all).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigNat(Vec<u64>);
+
+impl BigNat {
+    /// Creates a new `BigNat` with the value of the argument.
+    pub fn from_u64(n: u64) -> BigNat {
+        if n == 0 {
+            BigNat(vec![])
+        } else {
+            BigNat(vec![n])
+        }
+    }
+
+    /// Returns the number of significant bits in the `BigNat`, which is 0 for zero.
+    pub fn bits(&self) -> u32 {
+        match self.0.last() {
+            None => 0,
+            Some(top) => 64 * (self.0.len() as u32 - 1) + (64 - top.leading_zeros()),
+        }
+    }
+
+    /// Multiplies the `BigNat` by a `u64` in place.
+    pub fn mul_small(&mut self, m: u64) {
+        if m == 0 {
+            self.0.clear();
+            return;
+        }
+        let mut carry = 0u128;
+        for limb in self.0.iter_mut() {
+            let p = *limb as u128 * m as u128 + carry;
+            *limb = p as u64;
+            carry = p >> 64;
+        }
+        if carry > 0 {
+            self.0.push(carry as u64);
+        }
+    }
+
+    /// Divides the `BigNat` by a non-zero `u64` in place, discarding any remainder. This is
+    /// only used when the division is known to be exact.
+    pub fn div_small(&mut self, d: u64) {
+        self.div_rem_small(d);
+    }
+
+    /// Divides the `BigNat` by a non-zero `u64` in place, returning the remainder.
+    pub fn div_rem_small(&mut self, d: u64) -> u64 {
+        let mut rem = 0u128;
+        for limb in self.0.iter_mut().rev() {
+            let cur = (rem << 64) | *limb as u128;
+            *limb = (cur / d as u128) as u64;
+            rem = cur % d as u128;
+        }
+        self.trim();
+        rem as u64
+    }
+
+    /// Converts the `BigNat` into `f64` components, filling the output slice, so that they
+    /// can be used as the components of a `Double` or a `Quad`.
+    ///
+    /// Each component is the correctly rounded (ties to even) value of what's left after
+    /// the previous components are subtracted, which is exactly the normalized form that
+    /// the number types expect. This means that the result is exact if the number fits into
+    /// the components and is correctly rounded otherwise.
+    ///
+    /// The caller is responsible for making sure that the number isn't too large to fit
+    /// into an `f64`. If it rounds up past `f64::MAX`, the first component will be
+    /// infinite.
+    pub fn to_components(&self, out: &mut [f64]) {
+        self.to_scaled_components(0, out);
+    }
+
+    /// Converts the `BigNat` times 2<sup>exp</sup> into `f64` components in the same way
+    /// as [`to_components`](#method.to_components). Components that are small enough to be
+    /// subnormal are rounded to the precision that they actually have, and what's left
+    /// past the smallest subnormal is rounded away.
+    pub fn to_scaled_components(&self, exp: i32, out: &mut [f64]) {
+        let mut rest = self.clone();
+        let mut negative = false;
+        for c in out.iter_mut() {
+            // The lowest bit that a component can keep, relative to the `BigNat`'s own bits
+            let bits = rest.bits() as i32;
+            let shift = (bits - 53).max(-1074 - exp).max(0) as u32;
+            if shift == 0 {
+                // What's left fits into one f64 exactly
+                let v = scale(rest.0.first().copied().unwrap_or(0) as f64, exp);
+                *c = if negative { -v } else { v };
+                rest = BigNat::from_u64(0);
+                continue;
+            }
+
+            let mut m = rest.get_bits(shift, 53);
+            let half = rest.bit(shift - 1);
+            let sticky = rest.any_below(shift - 1);
+            let up = half && (sticky || m & 1 == 1);
+            rest.truncate(shift);
+            if up {
+                m += 1;
+                // The remainder is now 2^shift - rest, with the opposite sign
+                rest = rest.complement(shift);
+            }
+
+            let v = scale(m as f64, shift as i32 + exp);
+            *c = if negative { -v } else { v };
+            if up {
+                negative = !negative;
+            }
+        }
+    }
+
+    // Returns the bit at the given position.
+    fn bit(&self, pos: u32) -> bool {
+        let limb = (pos / 64) as usize;
+        limb < self.0.len() && (self.0[limb] >> (pos % 64)) & 1 == 1
+    }
+
+    // Returns `count` bits (at most 64) starting at the given position, as a `u64`.
+    fn get_bits(&self, pos: u32, count: u32) -> u64 {
+        let limb = (pos / 64) as usize;
+        let offset = pos % 64;
+        let lo = self.0.get(limb).copied().unwrap_or(0) >> offset;
+        let hi = if offset == 0 {
+            0
+        } else {
+            self.0.get(limb + 1).copied().unwrap_or(0) << (64 - offset)
+        };
+        let v = lo | hi;
+        if count == 64 {
+            v
+        } else {
+            v & ((1 << count) - 1)
+        }
+    }
+
+    // Determines whether any of the bits below the given position are set.
+    fn any_below(&self, pos: u32) -> bool {
+        let limb = (pos / 64) as usize;
+        let offset = pos % 64;
+        self.0.iter().take(limb.min(self.0.len())).any(|&l| l != 0)
+            || (offset > 0 && limb < self.0.len() && self.0[limb] & ((1 << offset) - 1) != 0)
+    }
+
+    // Discards all of the bits at and above the given position.
+    fn truncate(&mut self, pos: u32) {
+        let limb = (pos / 64) as usize;
+        let offset = pos % 64;
+        if limb < self.0.len() {
+            self.0.truncate(limb + 1);
+            self.0[limb] &= (1 << offset) - 1;
+        }
+        self.trim();
+    }
+
+    // Returns 2^pos minus the `BigNat`, which must be less than 2^pos. This is the two's
+    // complement within `pos` bits.
+    fn complement(&self, pos: u32) -> BigNat {
+        let len = (pos / 64) as usize + 1;
+        let mut limbs = vec![0u64; len];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let a = self.0.get(i).copied().unwrap_or(0);
+            let (d, b1) = 0u64.overflowing_sub(a);
+            let (d, b2) = d.overflowing_sub(borrow as u64);
+            *limb = d;
+            borrow = b1 || b2;
+        }
+        let mut n = BigNat(limbs);
+        n.truncate(pos);
+        n
+    }
+
+    // Removes leading zero limbs.
+    fn trim(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+}
+
+// The operations that the exact decimal conversions of the `serde` implementations need.
+#[cfg(feature = "serde")]
+impl BigNat {
+    /// Returns whether the `BigNat` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of zero bits below the lowest set bit, which is 0 for zero.
+    pub fn trailing_zeros(&self) -> u32 {
+        match self.0.iter().position(|&l| l != 0) {
+            None => 0,
+            Some(i) => 64 * i as u32 + self.0[i].trailing_zeros(),
+        }
+    }
+
+    /// Adds another `BigNat` to this one in place.
+    pub fn add(&mut self, other: &BigNat) {
+        if self.0.len() < other.0.len() {
+            self.0.resize(other.0.len(), 0);
+        }
+        let mut carry = false;
+        for (i, limb) in self.0.iter_mut().enumerate() {
+            let (s, c1) = limb.overflowing_add(other.0.get(i).copied().unwrap_or(0));
+            let (s, c2) = s.overflowing_add(carry as u64);
+            *limb = s;
+            carry = c1 || c2;
+        }
+        if carry {
+            self.0.push(1);
+        }
+    }
+
+    /// Subtracts another `BigNat`, which must not be larger, from this one in place.
+    pub fn sub(&mut self, other: &BigNat) {
+        let mut borrow = false;
+        for (i, limb) in self.0.iter_mut().enumerate() {
+            let (d, b1) = limb.overflowing_sub(other.0.get(i).copied().unwrap_or(0));
+            let (d, b2) = d.overflowing_sub(borrow as u64);
+            *limb = d;
+            borrow = b1 || b2;
+        }
+        self.trim();
+    }
+
+    /// Multiplies the `BigNat` by 2<sup>n</sup> in place.
+    pub fn shl(&mut self, n: u32) {
+        if self.is_zero() {
+            return;
+        }
+        let offset = n % 64;
+        if offset > 0 {
+            let mut carry = 0;
+            for limb in self.0.iter_mut() {
+                let next = *limb >> (64 - offset);
+                *limb = (*limb << offset) | carry;
+                carry = next;
+            }
+            if carry > 0 {
+                self.0.push(carry);
+            }
+        }
+        let limbs = (n / 64) as usize;
+        self.0.splice(0..0, std::iter::repeat_n(0, limbs));
+    }
+
+    /// Divides the `BigNat` by 2<sup>n</sup> in place, discarding the bits shifted out.
+    pub fn shr(&mut self, n: u32) {
+        let limbs = ((n / 64) as usize).min(self.0.len());
+        self.0.drain(..limbs);
+        let offset = n % 64;
+        if offset > 0 {
+            let mut carry = 0;
+            for limb in self.0.iter_mut().rev() {
+                let next = *limb << (64 - offset);
+                *limb = (*limb >> offset) | carry;
+                carry = next;
+            }
+        }
+        self.trim();
+    }
+
+    /// Returns the decimal digits of the `BigNat`, with no leading zeros (and so an empty
+    /// string for zero).
+    pub fn to_decimal(&self) -> String {
+        // 10^19 is the largest power of ten that fits into a u64
+        let mut n = self.clone();
+        let mut chunks = vec![];
+        while !n.is_zero() {
+            chunks.push(n.div_rem_small(10_000_000_000_000_000_000));
+        }
+        let mut s = String::new();
+        for (i, chunk) in chunks.iter().rev().enumerate() {
+            if i == 0 {
+                s.push_str(&chunk.to_string());
+            } else {
+                s.push_str(&format!("{:019}", chunk));
+            }
+        }
+        s
+    }
+}
+
+impl PartialOrd for BigNat {
+    fn partial_cmp(&self, other: &BigNat) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigNat {
+    fn cmp(&self, other: &BigNat) -> Ordering {
+        // With no leading zero limbs, a longer `BigNat` is always the larger one
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+// Returns x * 2^exp, where x is an integer of no more than 53 bits. The power of two is
+// applied in two steps when it's too large or too small to be an `f64` itself, which keeps
+// the result exact unless it's too large or too small to be represented.
+fn scale(x: f64, exp: i32) -> f64 {
+    if x == 0.0 {
+        // Avoids 0 * inf for very large exponents
+        0.0
+    } else if exp > 1000 {
+        x * 2f64.powi(exp - 1000) * 2f64.powi(1000)
+    } else if exp < -1000 {
+        x * 2f64.powi(exp + 1000) * 2f64.powi(-1000)
+    } else {
+        x * 2f64.powi(exp)
+    }
+}
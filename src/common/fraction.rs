@@ -0,0 +1,117 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use crate::quad::Quad;
+use std::ops::{Div, Sub};
+
+/// The operations that `best_fraction` needs. `MultiPrecision` has them all, but it's only
+/// available with the `transcendental` and `display` features.
+pub trait Real: Copy + PartialOrd + Sub<Output = Self> + Div<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn is_finite(self) -> bool;
+    fn floor_i128(self) -> Option<i128>;
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_real {
+    ($($t:ident)*) => {$(
+        impl Real for $t {
+            const ZERO: $t = $t::ZERO;
+            const ONE: $t = $t::ONE;
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            #[inline]
+            fn floor_i128(self) -> Option<i128> {
+                $t::floor_i128(self)
+            }
+
+            #[inline]
+            fn abs(self) -> $t {
+                $t::abs(self)
+            }
+        }
+    )*};
+}
+
+impl_real!(Double Quad);
+
+/// Finds the fraction with a denominator of at most `max_den` that's closest to `x`, using
+/// the continued fraction expansion of `x`. `from_i128` converts integers into the number
+/// type exactly, since `Double` doesn't have a `From<i128>` implementation.
+///
+/// The convergents of the expansion are the best approximations with denominators up to
+/// their own, and the best approximation between two convergents is either the earlier one
+/// or the semiconvergent with the largest allowed denominator. This returns `None` if `x`
+/// isn't finite, if `max_den` is zero, or if the numerator doesn't fit into an `i128`.
+pub fn best_fraction<T, F>(x: T, max_den: u64, from_i128: F) -> Option<(i128, u64)>
+where
+    T: Real,
+    F: Fn(i128) -> T,
+{
+    if !x.is_finite() || max_den == 0 {
+        return None;
+    }
+
+    // The two latest convergents, starting from the conventional 0/1 and 1/0
+    let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1u64, 1i128, 0u64);
+    let mut r = x;
+    let mut exact = false;
+
+    loop {
+        let a = match r.floor_i128() {
+            Some(a) => a,
+            // A term too large for an `i128` makes a denominator too large for a `u64`,
+            // except for the integer part itself
+            None if q1 > 0 => break,
+            None => return None,
+        };
+        let q2 = if q1 == 0 {
+            Some(q0 as u128)
+        } else {
+            (a as u128)
+                .checked_mul(q1 as u128)
+                .and_then(|aq| aq.checked_add(q0 as u128))
+        };
+        match q2 {
+            Some(q2) if q2 <= max_den as u128 => {
+                let p2 = a.checked_mul(p1)?.checked_add(p0)?;
+                p0 = p1;
+                q0 = q1;
+                p1 = p2;
+                q1 = q2 as u64;
+            }
+            _ => break,
+        }
+
+        let frac = r - from_i128(a);
+        if frac == T::ZERO {
+            exact = true;
+            break;
+        }
+        r = T::ONE / frac;
+    }
+
+    if exact {
+        return Some((p1, q1));
+    }
+    // The semiconvergent with the largest denominator that's still allowed
+    let k = (max_den - q0) / q1;
+    let ps = (k as i128)
+        .checked_mul(p1)
+        .and_then(|kp| kp.checked_add(p0));
+    let qs = q0 + k * q1;
+    let distance = |p: i128, q: u64| (x - from_i128(p) / from_i128(q as i128)).abs();
+    match ps {
+        Some(ps) if k > 0 && distance(ps, qs) < distance(p1, q1) => Some((ps, qs)),
+        _ => Some((p1, q1)),
+    }
+}
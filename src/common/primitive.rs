@@ -8,10 +8,12 @@
 //! higher-precision numbers; they all work with `f64`s and return tuples of `f64`s, which
 //! are then used in higher-precision operations.
 //!
-//! This module is aware of the `no_fma` feature. If it's enabled, further constants and
-//! functions are used to split floats into high and low words, necessary for the
-//! multiplication algorithm. If the feature is not enabled, multiplication instead depends
-//! on `f64`'s `mul_add` function, which uses FMA under the hood if it's available.
+//! This module is aware of the `no_fma` feature. If it's enabled (and the target is not
+//! known at compile time to have hardware FMA), [`split`] is used to split floats into high
+//! and low words, necessary for the multiplication algorithm. Otherwise, multiplication
+//! instead depends on `f64`'s `mul_add` function, which uses FMA under the hood if it's
+//! available. [`split`] is available either way, as
+//! it's part of the public [`eft`](crate::eft) module.
 
 #![allow(clippy::many_single_char_names)]
 
@@ -21,7 +23,6 @@
 /// therefore chosen to be a point halfway into the mantissa's bit field.
 ///
 /// This value is 2<sup>27</sup> + 1.
-#[cfg(no_fma)]
 const SPLIT_FACTOR: f64 = 134217729.0; // = 2^27 + 1
 
 /// The threshold over which special handling is done when splitting an `f64`.
@@ -33,19 +34,16 @@ const SPLIT_FACTOR: f64 = 134217729.0; // = 2^27 + 1
 ///
 /// This value is 2<sup>996</sup>, which is the highest power of two that is less than
 /// 10<sup>300</sup>.
-#[cfg(no_fma)]
-const SPLIT_THRESHOLD: f64 = 6.69692879491417e+299; // = 2^996
+const SPLIT_THRESHOLD: f64 = 6.696928794914171e+299; // = 2^996
 
 /// The factor by which a very large number is multiplied before being split.
 ///
 /// This value is 2<sup>-28</sup>.
-#[cfg(no_fma)]
-const SPLIT_SHIFT_DOWN: f64 = 3.7252902984619140625e-9; // = 2^-28
+const SPLIT_SHIFT_DOWN: f64 = 3.725290298461914e-9; // = 2^-28
 
 /// The factor by which a very large number is multiplied after being split.
 ///
 /// This value is 2<sup>28</sup>, or the inverse of the value used before splitting.
-#[cfg(no_fma)]
 const SPLIT_SHIFT_UP: f64 = 268435456.0; // = 2^28
 
 /// Calculates fl(a + b) and err(a + b).
@@ -96,10 +94,9 @@ pub fn two_diff(a: f64, b: f64) -> (f64, f64) {
 /// unless the number's absolute value is greater than
 /// [`SPLIT_THRESHOLD`](constant.SPLIT_THRESHOLD.html). In this case it performs 7
 /// floating-point operations to increase precision in the large number.
-#[cfg(no_fma)]
 #[inline]
-fn split(a: f64) -> (f64, f64) {
-    if a > SPLIT_THRESHOLD || a < -SPLIT_THRESHOLD {
+pub fn split(a: f64) -> (f64, f64) {
+    if a.abs() > SPLIT_THRESHOLD {
         let s = a * SPLIT_SHIFT_DOWN;
         let t = SPLIT_FACTOR * s;
         let hi = t - (t - s);
@@ -118,7 +115,11 @@ fn split(a: f64) -> (f64, f64) {
 /// This implementation uses FMA and requires 2 floating-point operations because of it. If
 /// FMA is not available but the `no_fma` feature is not enabled, it will use considerably
 /// more operations.
-#[cfg(not(no_fma))]
+///
+/// This implementation is always used when the target is known at compile time to have
+/// hardware FMA (i.e., when the `fma` target feature is enabled), even if the `no_fma`
+/// feature is enabled.
+#[cfg(any(target_feature = "fma", not(feature = "no_fma")))]
 #[inline]
 pub fn two_prod(a: f64, b: f64) -> (f64, f64) {
     let p = a * b;
@@ -131,7 +132,7 @@ pub fn two_prod(a: f64, b: f64) -> (f64, f64) {
 /// This implementation does not use FMA and consequently requires at least 17
 /// floating-point operations. If the arguments are particularly large, it can require as
 /// many as 23 floating-point operations.
-#[cfg(no_fma)]
+#[cfg(all(not(target_feature = "fma"), feature = "no_fma"))]
 #[inline]
 pub fn two_prod(a: f64, b: f64) -> (f64, f64) {
     let p = a * b;
@@ -146,7 +147,10 @@ pub fn two_prod(a: f64, b: f64) -> (f64, f64) {
 /// This implementation uses FMA and therefore requires 2 floating-point instructions, the
 /// same as for multiplication. If FMA is not available but the `no_fma` feature is not
 /// enabled, it will use considerably more operations.
-#[cfg(not(no_fma))]
+///
+/// As with [`two_prod`](#fn.two_prod), this implementation is always used when the `fma`
+/// target feature is enabled.
+#[cfg(any(target_feature = "fma", not(feature = "no_fma")))]
 #[inline]
 pub fn two_sqr(a: f64) -> (f64, f64) {
     let p = a * a;
@@ -160,7 +164,7 @@ pub fn two_sqr(a: f64) -> (f64, f64) {
 /// made in multiplication due to the multiplied numbers being the same. It therefore only
 /// uses a minimum of 12 floating-point operations, though with a very large argument it can
 /// be 15.
-#[cfg(no_fma)]
+#[cfg(all(not(target_feature = "fma"), feature = "no_fma"))]
 #[inline]
 pub fn two_sqr(a: f64) -> (f64, f64) {
     let p = a * a;
@@ -196,6 +196,148 @@ pub fn renorm5(a: f64, b: f64, c: f64, d: f64, e: f64) -> (f64, f64, f64, f64) {
     }
 }
 
+/// Performs one pass of cascaded error-free summation over a slice of `f64`s.
+///
+/// After the pass, the last element holds the floating-point sum of all of the elements and
+/// the others hold the errors of each of the additions, so the exact sum of the slice is
+/// unchanged. Repeated passes concentrate more and more of the sum into the last element.
+#[inline]
+pub fn vec_sum(xs: &mut [f64]) {
+    for i in 1..xs.len() {
+        let (s, e) = p::two_sum(xs[i], xs[i - 1]);
+        xs[i] = s;
+        xs[i - 1] = e;
+    }
+}
+
+/// Distills the leading component out of the exact sum of the first `len` elements of a
+/// slice of `f64`s.
+///
+/// The leading component is returned, and the elements before position `len - 1` are left
+/// holding the exact remainder.
+#[inline]
+fn extract(xs: &mut [f64], len: usize) -> f64 {
+    vec_sum(&mut xs[..len]);
+    vec_sum(&mut xs[..len]);
+    xs[len - 1]
+}
+
+/// Sums an arbitrary number of `f64`s into a two-component value.
+///
+/// This is done by repeated cascaded summation ("distillation"), which produces a result
+/// that is accurate to the full precision of the return value no matter how much the
+/// summands cancel each other out. The contents of the slice are destroyed in the process.
+pub fn distill2(xs: &mut [f64]) -> (f64, f64) {
+    let n = xs.len();
+    let a = extract(xs, n);
+    let b = xs[..n - 1].iter().sum::<f64>();
+    renorm2(a, b)
+}
+
+/// Sums an arbitrary number of `f64`s into a four-component value.
+///
+/// This is done by repeated cascaded summation ("distillation"), which produces a result
+/// that is accurate to the full precision of the return value no matter how much the
+/// summands cancel each other out. The contents of the slice are destroyed in the process.
+pub fn distill4(xs: &mut [f64]) -> (f64, f64, f64, f64) {
+    let n = xs.len();
+    let a = extract(xs, n);
+    let b = extract(xs, n - 1);
+    let c = extract(xs, n - 2);
+    let d = xs[..n - 3].iter().sum::<f64>();
+    renorm4(a, b, c, d)
+}
+
+/// Sums an arbitrary number of `f64`s into a value with as many components as `out` has.
+///
+/// This is the distillation of [`distill2`] and [`distill4`] for any number of
+/// components, except that the last component is extracted like the others instead of
+/// being a plain sum of what's left, which matters most when there's only one component.
+/// There must be at least as many `f64`s to sum as there are components; the caller can
+/// pad the slice with zeros if necessary. The contents of the slice are destroyed in the
+/// process.
+///
+/// [`distill2`]: fn.distill2.html
+/// [`distill4`]: fn.distill4.html
+pub fn distill(xs: &mut [f64], out: &mut [f64]) {
+    let n = xs.len();
+    for (i, x) in out.iter_mut().enumerate() {
+        *x = extract(xs, n - i);
+    }
+    renorm(out);
+}
+
+/// Renormalizes any number of components in place.
+///
+/// This is the same renormalization as [`renorm4`], for any number of components: the
+/// components are swept from last to first and then from first to last, and zeros that
+/// turn up in the second sweep are moved to the end.
+///
+/// [`renorm4`]: fn.renorm4.html
+pub fn renorm(xs: &mut [f64]) {
+    let n = xs.len();
+    for i in (1..n).rev() {
+        let (s, e) = p::quick_two_sum(xs[i - 1], xs[i]);
+        xs[i - 1] = s;
+        xs[i] = e;
+    }
+    let mut k = 0;
+    let mut s = xs[0];
+    for i in 1..n {
+        let (a, b) = p::quick_two_sum(s, xs[i]);
+        if b != 0.0 {
+            xs[k] = a;
+            k += 1;
+            s = b;
+        } else {
+            s = a;
+        }
+    }
+    xs[k] = s;
+    for x in xs[k + 1..].iter_mut() {
+        *x = 0.0;
+    }
+}
+
+/// Returns the binary exponent of an `f64`, the power of two of its leading bit.
+///
+/// Zero and subnormal numbers return the exponent of the smallest normal number, -1022,
+/// and infinite numbers and `NaN` return 1024.
+#[inline]
+pub fn exponent(a: f64) -> i32 {
+    let biased = ((a.to_bits() >> 52) & 0x7ff) as i32;
+    if biased == 0 {
+        -1022
+    } else {
+        biased - 1023
+    }
+}
+
+/// Returns the unit in the last place of an `f64`, the distance from its magnitude to the
+/// next larger `f64`. The argument must be finite.
+///
+/// Zero returns the smallest positive subnormal number, 2<sup>-1074</sup>.
+#[inline]
+pub fn ulp(a: f64) -> f64 {
+    let a = a.abs();
+    f64::from_bits(a.to_bits() + 1) - a
+}
+
+/// Returns the smallest `f64` greater than a finite `f64`.
+///
+/// Both zeros return the smallest positive subnormal number, and the largest finite number
+/// returns infinity.
+#[inline]
+pub fn next_up(a: f64) -> f64 {
+    if a == 0.0 {
+        f64::from_bits(1)
+    } else if a > 0.0 {
+        f64::from_bits(a.to_bits() + 1)
+    } else {
+        f64::from_bits(a.to_bits() - 1)
+    }
+}
+
 /// Determines whether a number is exact (true) or has floating-point error (false).
 ///
 /// A number is exactly representable in binary if it can be rendered as a fraction with a
@@ -0,0 +1,2022 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Complex numbers whose real and imaginary parts are [`Double`]s or [`Quad`]s.
+//!
+//! [`ComplexDouble`] and [`ComplexQuad`] provide the usual arithmetic along with the
+//! absolute value and argument, the exponential, logarithm, square root and powers, and
+//! the trigonometric and hyperbolic functions. The absolute value and division scale their
+//! arguments so that they don't overflow or underflow unless their results do, and the
+//! functions with branch cuts follow the conventions of C99's `<complex.h>`: the cut is on
+//! the negative real axis, and the sign of a zero imaginary part picks its side.
+//!
+//! # Examples
+//! ```
+//! use qd::complex::ComplexDouble;
+//! use qd::{dd, Double};
+//!
+//! let z = ComplexDouble::new(dd!(-1), dd!(0));
+//! assert!(z.sqrt() == ComplexDouble::I);
+//! assert!(z.ln() == ComplexDouble::new(dd!(0), Double::PI));
+//!
+//! // The other side of the branch cut
+//! let w = z.conj();
+//! assert!(w.sqrt() == -ComplexDouble::I);
+//! assert!(w.ln() == ComplexDouble::new(dd!(0), -Double::PI));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`ComplexDouble`]: crate::complex::ComplexDouble
+//! [`ComplexQuad`]: crate::complex::ComplexQuad
+
+use crate::common::utils as u;
+use crate::double::Double;
+use crate::quad::Quad;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A complex number whose real and imaginary parts are [`Double`]s.
+///
+/// The parts are public fields, so a `ComplexDouble` can be built and taken apart
+/// directly as well as with [`new`] and [`from_polar`]. Arithmetic is available through
+/// the usual operators, both between two `ComplexDouble`s and between a `ComplexDouble`
+/// and a `Double`.
+///
+/// Functions with branch cuts ([`ln`], [`sqrt`], and the powers) put them on the negative
+/// real axis, and the sign of a zero imaginary part decides which side of the cut a number
+/// on it is taken to be on, as in C99's `<complex.h>`.
+///
+/// # Examples
+/// ```
+/// use qd::complex::ComplexDouble;
+/// use qd::{dd, Double};
+///
+/// let z = ComplexDouble::new(dd!(3), dd!(4));
+/// assert!(z.abs() == dd!(5));
+/// assert!(z * z.conj() == ComplexDouble::from(dd!(25)));
+///
+/// // e^(iπ) + 1 = 0
+/// let w = (ComplexDouble::I * Double::PI).exp() + Double::ONE;
+/// assert!(w.abs() < dd!(1e-31));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`new`]: #method.new
+/// [`from_polar`]: #method.from_polar
+/// [`ln`]: #method.ln
+/// [`sqrt`]: #method.sqrt
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComplexDouble {
+    /// The real part.
+    pub re: Double,
+    /// The imaginary part.
+    pub im: Double,
+}
+
+impl ComplexDouble {
+    /// Zero (0 + 0*i*)
+    pub const ZERO: ComplexDouble = ComplexDouble::new(Double::ZERO, Double::ZERO);
+
+    /// One (1 + 0*i*)
+    pub const ONE: ComplexDouble = ComplexDouble::new(Double::ONE, Double::ZERO);
+
+    /// The imaginary unit (0 + 1*i*)
+    pub const I: ComplexDouble = ComplexDouble::new(Double::ZERO, Double::ONE);
+
+    /// Not a Number (NaN + NaN*i*)
+    pub const NAN: ComplexDouble = ComplexDouble::new(Double::NAN, Double::NAN);
+
+    /// Creates a new `ComplexDouble` from its real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::new(dd!(1), dd!(-2));
+    /// assert!(z.re == dd!(1));
+    /// assert!(z.im == dd!(-2));
+    /// ```
+    pub const fn new(re: Double, im: Double) -> ComplexDouble {
+        ComplexDouble { re, im }
+    }
+
+    /// Creates a new `ComplexDouble` from its polar form, an absolute value `r` and an
+    /// argument `theta`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::{dd, Double};
+    /// let z = ComplexDouble::from_polar(dd!(2), Double::FRAC_PI_2);
+    /// assert!(z.re.abs() < dd!(1e-31));
+    /// assert!(z.im == dd!(2));
+    /// ```
+    pub fn from_polar(r: Double, theta: Double) -> ComplexDouble {
+        let (s, c) = theta.sin_cos();
+        ComplexDouble::new(r * c, r * s)
+    }
+
+    /// Converts the `ComplexDouble` into its polar form, a tuple of its absolute value
+    /// and its argument.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::{dd, Double};
+    /// let (r, theta) = ComplexDouble::new(dd!(-2), dd!(0)).to_polar();
+    /// assert!(r == dd!(2));
+    /// assert!(theta == Double::PI);
+    /// ```
+    pub fn to_polar(self) -> (Double, Double) {
+        (self.abs(), self.arg())
+    }
+
+    /// Returns the complex conjugate of the `ComplexDouble`, which has the same real part
+    /// and the negative of its imaginary part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::new(dd!(1), dd!(2)).conj();
+    /// assert!(z == ComplexDouble::new(dd!(1), dd!(-2)));
+    /// ```
+    #[inline]
+    pub fn conj(self) -> ComplexDouble {
+        ComplexDouble::new(self.re, -self.im)
+    }
+
+    /// Calculates the square of the absolute value of the `ComplexDouble`.
+    ///
+    /// This is cheaper than [`abs`] and is exact for the same numbers that squaring is,
+    /// but it overflows for numbers larger than about the square root of [`MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// assert!(ComplexDouble::new(dd!(3), dd!(4)).norm_sqr() == dd!(25));
+    /// ```
+    ///
+    /// [`abs`]: #method.abs
+    /// [`MAX`]: crate::Double::MAX
+    #[inline]
+    pub fn norm_sqr(self) -> Double {
+        self.re.sqr() + self.im.sqr()
+    }
+
+    /// Calculates the absolute value (or modulus) of the `ComplexDouble`.
+    ///
+    /// This is calculated with [`hypot`], so it doesn't overflow or underflow unless the
+    /// result itself does. It's infinite if either part is infinite, even if the other is
+    /// `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::new(dd!(-5), dd!(12));
+    /// assert!((z.abs() - dd!(13)).abs() < dd!(1e-30));
+    ///
+    /// // The square of the absolute value would overflow
+    /// let z = ComplexDouble::new(dd!(3e300), dd!(4e300));
+    /// assert!((z.abs() / dd!(5e300) - dd!(1)).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`hypot`]: crate::Double::hypot
+    #[inline]
+    pub fn abs(self) -> Double {
+        self.re.hypot(self.im)
+    }
+
+    /// Calculates the argument (or phase) of the `ComplexDouble`, the angle between the
+    /// positive real axis and the number, in the range [-π, π].
+    ///
+    /// Numbers on the negative real axis have an argument of π if their imaginary part is
+    /// 0 and -π if it's -0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::{dd, Double};
+    /// assert!(ComplexDouble::new(dd!(1), dd!(1)).arg() == Double::FRAC_PI_4);
+    /// assert!(ComplexDouble::new(dd!(-1), dd!(0)).arg() == Double::PI);
+    /// assert!(ComplexDouble::new(dd!(-1), Double::NEG_ZERO).arg() == -Double::PI);
+    /// ```
+    pub fn arg(self) -> Double {
+        if self.im.is_zero() && !self.re.is_nan() {
+            // `atan2` doesn't look at the sign of a zero, but it decides which side of the
+            // branch cut a number on the negative real axis is on
+            let a = if self.re.is_sign_negative() {
+                Double::PI
+            } else {
+                Double::ZERO
+            };
+            a.copysign(self.im)
+        } else {
+            self.im.atan2(self.re)
+        }
+    }
+
+    /// Determines whether either part of the `ComplexDouble` is `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// Determines whether either part of the `ComplexDouble` is infinite.
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.re.is_infinite() || self.im.is_infinite()
+    }
+
+    /// Determines whether both parts of the `ComplexDouble` are finite.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// Calculates the reciprocal of the `ComplexDouble`.
+    ///
+    /// The number is scaled by a power of two before its parts are squared, so this
+    /// doesn't overflow or underflow unless the result itself does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::new(dd!(0), dd!(2)).recip();
+    /// assert!(z == ComplexDouble::new(dd!(0), dd!(-0.5)));
+    /// ```
+    pub fn recip(self) -> ComplexDouble {
+        ComplexDouble::ONE / self
+    }
+
+    /// Calculates *e* raised to the power of the `ComplexDouble`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::{dd, Double};
+    /// let z = ComplexDouble::new(Double::LN_2, Double::FRAC_PI_2).exp();
+    /// assert!(z.re.abs() < dd!(1e-31));
+    /// assert!((z.im - dd!(2)).abs() < dd!(1e-30));
+    /// ```
+    pub fn exp(self) -> ComplexDouble {
+        let r = self.re.exp();
+        if self.im.is_zero() {
+            // Keep real arguments real, even if `r` is infinite
+            ComplexDouble::new(r, self.im)
+        } else {
+            let (s, c) = self.im.sin_cos();
+            ComplexDouble::new(r * c, r * s)
+        }
+    }
+
+    /// Calculates the principal value of the natural logarithm of the `ComplexDouble`.
+    ///
+    /// The branch cut is on the negative real axis, where the imaginary part of the result
+    /// is π or -π depending on the sign of the argument's zero imaginary part. The
+    /// logarithm of zero has a real part of -∞.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::{dd, Double};
+    /// let z = ComplexDouble::new(dd!(-1), dd!(0)).ln();
+    /// assert!(z == ComplexDouble::new(dd!(0), Double::PI));
+    /// ```
+    pub fn ln(self) -> ComplexDouble {
+        let r = self.abs();
+        let re = if r > Double::new(0.5, 0.0) && r < Double::new(2.0, 0.0) {
+            // Close to the unit circle ln |z| is small, and calculating it from |z| would
+            // lose its relative precision to the rounding of |z|. Instead, |z|² - 1 is
+            // calculated with the 1 subtracted from the larger part, where it cancels.
+            let (a, b) = if self.re.abs() > self.im.abs() {
+                (self.re, self.im)
+            } else {
+                (self.im, self.re)
+            };
+            ((a - Double::ONE) * (a + Double::ONE) + b.sqr())
+                .ln_1p()
+                .mul_pwr2(0.5)
+        } else {
+            r.ln()
+        };
+        ComplexDouble::new(re, self.arg())
+    }
+
+    /// Calculates the principal value of the square root of the `ComplexDouble`.
+    ///
+    /// The result always has a non-negative real part. The branch cut is on the negative
+    /// real axis, where the sign of the imaginary part of the result is the sign of the
+    /// argument's zero imaginary part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::new(dd!(-4), dd!(0)).sqrt();
+    /// assert!(z == ComplexDouble::new(dd!(0), dd!(2)));
+    ///
+    /// let w = ComplexDouble::new(dd!(3), dd!(4)).sqrt();
+    /// assert!(w == ComplexDouble::new(dd!(2), dd!(1)));
+    /// ```
+    pub fn sqrt(self) -> ComplexDouble {
+        if self.re.is_zero() && self.im.is_zero() {
+            return ComplexDouble::new(Double::ZERO, self.im);
+        }
+        if self.im.is_infinite() {
+            return ComplexDouble::new(Double::INFINITY, self.im);
+        }
+        if self.re.abs().max(self.im.abs()) > Double::MAX.mul_pwr2(0.25) {
+            // |z| would overflow, but √(z / 4) is exactly half of √z
+            let r = ComplexDouble::new(self.re.mul_pwr2(0.25), self.im.mul_pwr2(0.25)).sqrt();
+            return ComplexDouble::new(r.re.mul_pwr2(2.0), r.im.mul_pwr2(2.0));
+        }
+        // t = √((|re| + |z|) / 2), halving before adding so the sum can't overflow
+        let a = self.re.abs();
+        let t = (a.mul_pwr2(0.5) + self.abs().mul_pwr2(0.5)).sqrt();
+        if self.re.is_sign_negative() {
+            ComplexDouble::new((self.im.abs() / t).mul_pwr2(0.5), t.copysign(self.im))
+        } else {
+            ComplexDouble::new(t, (self.im / t).mul_pwr2(0.5))
+        }
+    }
+
+    /// Calculates the `ComplexDouble` raised to an integer power.
+    ///
+    /// This uses repeated squaring, so the result is exact as long as each of the products
+    /// is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::new(dd!(1), dd!(1));
+    /// assert!(z.powi(4) == ComplexDouble::from(dd!(-4)));
+    /// assert!(z.powi(-2) == ComplexDouble::new(dd!(0), dd!(-0.5)));
+    /// ```
+    pub fn powi(self, n: i32) -> ComplexDouble {
+        let mut base = self;
+        let mut result = ComplexDouble::ONE;
+        let mut k = n.unsigned_abs();
+        while k > 0 {
+            if k & 1 == 1 {
+                result *= base;
+            }
+            k >>= 1;
+            if k > 0 {
+                base *= base;
+            }
+        }
+        if n < 0 {
+            result.recip()
+        } else {
+            result
+        }
+    }
+
+    /// Calculates the principal value of the `ComplexDouble` raised to a real power.
+    ///
+    /// Zero raised to a positive power is zero, and anything raised to the power of zero
+    /// is one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::new(dd!(-8), dd!(0)).powf(dd!(1) / dd!(3));
+    /// assert!((z - ComplexDouble::new(dd!(1), dd!(3).sqrt())).abs() < dd!(1e-30));
+    /// ```
+    pub fn powf(self, exp: Double) -> ComplexDouble {
+        if exp.is_zero() {
+            ComplexDouble::ONE
+        } else if self.re.is_zero() && self.im.is_zero() && exp > Double::ZERO {
+            ComplexDouble::ZERO
+        } else {
+            let (r, theta) = self.to_polar();
+            ComplexDouble::from_polar(r.powf(exp), theta * exp)
+        }
+    }
+
+    /// Calculates the principal value of the `ComplexDouble` raised to a complex power.
+    ///
+    /// This is *e*<sup>*w* ln *z*</sup>. Zero raised to a power with a positive real part
+    /// is zero, and anything raised to the power of zero is one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::{dd, Double};
+    /// // i^i = e^(-π/2)
+    /// let z = ComplexDouble::I.powc(ComplexDouble::I);
+    /// assert!((z.re - (-Double::FRAC_PI_2).exp()).abs() < dd!(1e-31));
+    /// assert!(z.im == dd!(0));
+    /// ```
+    pub fn powc(self, exp: ComplexDouble) -> ComplexDouble {
+        if exp.re.is_zero() && exp.im.is_zero() {
+            ComplexDouble::ONE
+        } else if self.re.is_zero() && self.im.is_zero() && exp.re > Double::ZERO {
+            ComplexDouble::ZERO
+        } else if exp.im.is_zero() {
+            self.powf(exp.re)
+        } else {
+            (exp * self.ln()).exp()
+        }
+    }
+
+    /// Calculates the sine of the `ComplexDouble`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// // sin(ix) = i sinh(x)
+    /// let z = ComplexDouble::new(dd!(0), dd!(1)).sin();
+    /// assert!(z == ComplexDouble::new(dd!(0), dd!(1).sinh()));
+    /// ```
+    pub fn sin(self) -> ComplexDouble {
+        let (s, c) = self.re.sin_cos();
+        let (sh, ch) = self.im.sinh_cosh();
+        ComplexDouble::new(s * ch, c * sh)
+    }
+
+    /// Calculates the cosine of the `ComplexDouble`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// // cos(ix) = cosh(x)
+    /// let z = ComplexDouble::new(dd!(0), dd!(1)).cos();
+    /// assert!(z == ComplexDouble::new(dd!(1).cosh(), dd!(0)));
+    /// ```
+    pub fn cos(self) -> ComplexDouble {
+        let (s, c) = self.re.sin_cos();
+        let (sh, ch) = self.im.sinh_cosh();
+        ComplexDouble::new(c * ch, -(s * sh))
+    }
+
+    /// Calculates the tangent of the `ComplexDouble`.
+    ///
+    /// For large imaginary parts the result approaches ±*i*, and it's calculated so that
+    /// it does so without overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::new(dd!(1), dd!(1000)).tan();
+    /// assert!(z == ComplexDouble::new(dd!(0), dd!(1)));
+    /// ```
+    pub fn tan(self) -> ComplexDouble {
+        // tan(z) = -i tanh(iz)
+        let t = ComplexDouble::new(-self.im, self.re).tanh();
+        ComplexDouble::new(t.im, -t.re)
+    }
+
+    /// Calculates the hyperbolic sine of the `ComplexDouble`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// // sinh(ix) = i sin(x)
+    /// let z = ComplexDouble::new(dd!(0), dd!(1)).sinh();
+    /// assert!(z == ComplexDouble::new(dd!(0), dd!(1).sin()));
+    /// ```
+    pub fn sinh(self) -> ComplexDouble {
+        let (s, c) = self.im.sin_cos();
+        let (sh, ch) = self.re.sinh_cosh();
+        ComplexDouble::new(sh * c, ch * s)
+    }
+
+    /// Calculates the hyperbolic cosine of the `ComplexDouble`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// // cosh(ix) = cos(x)
+    /// let z = ComplexDouble::new(dd!(0), dd!(1)).cosh();
+    /// assert!(z == ComplexDouble::new(dd!(1).cos(), dd!(0)));
+    /// ```
+    pub fn cosh(self) -> ComplexDouble {
+        let (s, c) = self.im.sin_cos();
+        let (sh, ch) = self.re.sinh_cosh();
+        ComplexDouble::new(ch * c, sh * s)
+    }
+
+    /// Calculates the hyperbolic tangent of the `ComplexDouble`.
+    ///
+    /// For large real parts the result approaches ±1, and it's calculated so that it does
+    /// so without overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::new(dd!(-1000), dd!(1)).tanh();
+    /// assert!(z == ComplexDouble::new(dd!(-1), dd!(0)));
+    /// ```
+    pub fn tanh(self) -> ComplexDouble {
+        // tanh(a + bi) = (sinh 2a + i sin 2b) / (cosh 2a + cos 2b), but cosh 2a overflows
+        // once |a| passes about 355. Long before that the real part is ±1 to full
+        // precision and the denominator is e^2|a| / 2 to full precision, so past this
+        // point the imaginary part is calculated without the overflowing terms.
+        let (s, c) = self.im.mul_pwr2(2.0).sin_cos();
+        if self.re.abs() > Double::new(40.0, 0.0) {
+            let im = if self.im.is_zero() {
+                self.im
+            } else {
+                // The product is copysigned so that the sign survives underflow
+                (s.abs() * (self.re.abs().mul_pwr2(-2.0)).exp())
+                    .mul_pwr2(2.0)
+                    .copysign(s)
+            };
+            ComplexDouble::new(Double::ONE.copysign(self.re), im)
+        } else {
+            let (sh, ch) = self.re.mul_pwr2(2.0).sinh_cosh();
+            let d = ch + c;
+            ComplexDouble::new(sh / d, s / d)
+        }
+    }
+}
+
+impl From<Double> for ComplexDouble {
+    /// Converts a `Double` into a `ComplexDouble` with an imaginary part of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::from(dd!(2));
+    /// assert!(z == ComplexDouble::new(dd!(2), dd!(0)));
+    /// ```
+    #[inline]
+    fn from(re: Double) -> ComplexDouble {
+        ComplexDouble::new(re, Double::ZERO)
+    }
+}
+
+impl From<(Double, Double)> for ComplexDouble {
+    /// Converts a tuple of a real and an imaginary part into a `ComplexDouble`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::from((dd!(2), dd!(3)));
+    /// assert!(z == ComplexDouble::new(dd!(2), dd!(3)));
+    /// ```
+    #[inline]
+    fn from((re, im): (Double, Double)) -> ComplexDouble {
+        ComplexDouble::new(re, im)
+    }
+}
+
+impl Display for ComplexDouble {
+    /// Formats the `ComplexDouble` as *a*+*b*i or *a*-*b*i. Any precision given in the
+    /// format string is applied to both parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexDouble;
+    /// # use qd::dd;
+    /// let z = ComplexDouble::new(dd!(1.5), dd!(-2));
+    /// assert!(format!("{}", z) == "1.5-2i");
+    /// assert!(format!("{:.2}", z) == "1.50-2.00i");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let sign = if self.im.is_sign_negative() { '-' } else { '+' };
+        match f.precision() {
+            Some(p) => write!(f, "{:.*}{}{:.*}i", p, self.re, sign, p, self.im.abs()),
+            None => write!(f, "{}{}{}i", self.re, sign, self.im.abs()),
+        }
+    }
+}
+
+impl Add for ComplexDouble {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn add(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Add<Double> for ComplexDouble {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn add(self, other: Double) -> ComplexDouble {
+        ComplexDouble::new(self.re + other, self.im)
+    }
+}
+
+impl Add<ComplexDouble> for Double {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn add(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(self + other.re, other.im)
+    }
+}
+
+impl Sub for ComplexDouble {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn sub(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Sub<Double> for ComplexDouble {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn sub(self, other: Double) -> ComplexDouble {
+        ComplexDouble::new(self.re - other, self.im)
+    }
+}
+
+impl Sub<ComplexDouble> for Double {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn sub(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(self - other.re, -other.im)
+    }
+}
+
+impl Mul for ComplexDouble {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn mul(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Mul<Double> for ComplexDouble {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn mul(self, other: Double) -> ComplexDouble {
+        ComplexDouble::new(self.re * other, self.im * other)
+    }
+}
+
+impl Mul<ComplexDouble> for Double {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn mul(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::new(self * other.re, self * other.im)
+    }
+}
+
+impl Div for ComplexDouble {
+    type Output = ComplexDouble;
+
+    /// Divides one `ComplexDouble` by another.
+    ///
+    /// Both numbers are scaled by powers of two before their parts are multiplied, so the
+    /// division doesn't overflow or underflow unless the result itself does.
+    fn div(self, other: ComplexDouble) -> ComplexDouble {
+        let m = other.re.abs().max(other.im.abs());
+        let n = self.re.abs().max(self.im.abs());
+        if m.is_zero() || !m.is_finite() || !n.is_finite() {
+            // Leave the special cases to the real arithmetic
+            let d = other.norm_sqr();
+            return ComplexDouble::new(
+                (self.re * other.re + self.im * other.im) / d,
+                (self.im * other.re - self.re * other.im) / d,
+            );
+        }
+        let j = -u::exponent(n[0]);
+        let k = -u::exponent(m[0]);
+        let (a, b) = (self.re.scale_b(j), self.im.scale_b(j));
+        let (c, d) = (other.re.scale_b(k), other.im.scale_b(k));
+        let den = c.sqr() + d.sqr();
+        ComplexDouble::new(
+            ((a * c + b * d) / den).scale_b(k - j),
+            ((b * c - a * d) / den).scale_b(k - j),
+        )
+    }
+}
+
+impl Div<Double> for ComplexDouble {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn div(self, other: Double) -> ComplexDouble {
+        ComplexDouble::new(self.re / other, self.im / other)
+    }
+}
+
+impl Div<ComplexDouble> for Double {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn div(self, other: ComplexDouble) -> ComplexDouble {
+        ComplexDouble::from(self) / other
+    }
+}
+
+impl Neg for ComplexDouble {
+    type Output = ComplexDouble;
+
+    #[inline]
+    fn neg(self) -> ComplexDouble {
+        ComplexDouble::new(-self.re, -self.im)
+    }
+}
+
+impl AddAssign for ComplexDouble {
+    #[inline]
+    fn add_assign(&mut self, other: ComplexDouble) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Double> for ComplexDouble {
+    #[inline]
+    fn add_assign(&mut self, other: Double) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for ComplexDouble {
+    #[inline]
+    fn sub_assign(&mut self, other: ComplexDouble) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<Double> for ComplexDouble {
+    #[inline]
+    fn sub_assign(&mut self, other: Double) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for ComplexDouble {
+    #[inline]
+    fn mul_assign(&mut self, other: ComplexDouble) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<Double> for ComplexDouble {
+    #[inline]
+    fn mul_assign(&mut self, other: Double) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for ComplexDouble {
+    #[inline]
+    fn div_assign(&mut self, other: ComplexDouble) {
+        *self = *self / other;
+    }
+}
+
+impl DivAssign<Double> for ComplexDouble {
+    #[inline]
+    fn div_assign(&mut self, other: Double) {
+        *self = *self / other;
+    }
+}
+
+/// A complex number whose real and imaginary parts are [`Quad`]s.
+///
+/// The parts are public fields, so a `ComplexQuad` can be built and taken apart
+/// directly as well as with [`new`] and [`from_polar`]. Arithmetic is available through
+/// the usual operators, both between two `ComplexQuad`s and between a `ComplexQuad`
+/// and a `Quad`.
+///
+/// Functions with branch cuts ([`ln`], [`sqrt`], and the powers) put them on the negative
+/// real axis, and the sign of a zero imaginary part decides which side of the cut a number
+/// on it is taken to be on, as in C99's `<complex.h>`.
+///
+/// # Examples
+/// ```
+/// use qd::complex::ComplexQuad;
+/// use qd::{qd, Quad};
+///
+/// let z = ComplexQuad::new(qd!(3), qd!(4));
+/// assert!((z.abs() - qd!(5)).abs() < qd!(1e-60));
+/// assert!(z * z.conj() == ComplexQuad::from(qd!(25)));
+///
+/// // e^(iπ) + 1 = 0
+/// let w = (ComplexQuad::I * Quad::PI).exp() + Quad::ONE;
+/// assert!(w.abs() < qd!(1e-62));
+/// ```
+///
+/// [`Quad`]: crate::Quad
+/// [`new`]: #method.new
+/// [`from_polar`]: #method.from_polar
+/// [`ln`]: #method.ln
+/// [`sqrt`]: #method.sqrt
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComplexQuad {
+    /// The real part.
+    pub re: Quad,
+    /// The imaginary part.
+    pub im: Quad,
+}
+
+impl ComplexQuad {
+    /// Zero (0 + 0*i*)
+    pub const ZERO: ComplexQuad = ComplexQuad::new(Quad::ZERO, Quad::ZERO);
+
+    /// One (1 + 0*i*)
+    pub const ONE: ComplexQuad = ComplexQuad::new(Quad::ONE, Quad::ZERO);
+
+    /// The imaginary unit (0 + 1*i*)
+    pub const I: ComplexQuad = ComplexQuad::new(Quad::ZERO, Quad::ONE);
+
+    /// Not a Number (NaN + NaN*i*)
+    pub const NAN: ComplexQuad = ComplexQuad::new(Quad::NAN, Quad::NAN);
+
+    /// Creates a new `ComplexQuad` from its real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::new(qd!(1), qd!(-2));
+    /// assert!(z.re == qd!(1));
+    /// assert!(z.im == qd!(-2));
+    /// ```
+    pub const fn new(re: Quad, im: Quad) -> ComplexQuad {
+        ComplexQuad { re, im }
+    }
+
+    /// Creates a new `ComplexQuad` from its polar form, an absolute value `r` and an
+    /// argument `theta`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::{qd, Quad};
+    /// let z = ComplexQuad::from_polar(qd!(2), Quad::FRAC_PI_2);
+    /// assert!(z.re.abs() < qd!(1e-62));
+    /// assert!(z.im == qd!(2));
+    /// ```
+    pub fn from_polar(r: Quad, theta: Quad) -> ComplexQuad {
+        let (s, c) = theta.sin_cos();
+        ComplexQuad::new(r * c, r * s)
+    }
+
+    /// Converts the `ComplexQuad` into its polar form, a tuple of its absolute value
+    /// and its argument.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::{qd, Quad};
+    /// let (r, theta) = ComplexQuad::new(qd!(-2), qd!(0)).to_polar();
+    /// assert!(r == qd!(2));
+    /// assert!(theta == Quad::PI);
+    /// ```
+    pub fn to_polar(self) -> (Quad, Quad) {
+        (self.abs(), self.arg())
+    }
+
+    /// Returns the complex conjugate of the `ComplexQuad`, which has the same real part
+    /// and the negative of its imaginary part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::new(qd!(1), qd!(2)).conj();
+    /// assert!(z == ComplexQuad::new(qd!(1), qd!(-2)));
+    /// ```
+    #[inline]
+    pub fn conj(self) -> ComplexQuad {
+        ComplexQuad::new(self.re, -self.im)
+    }
+
+    /// Calculates the square of the absolute value of the `ComplexQuad`.
+    ///
+    /// This is cheaper than [`abs`] and is exact for the same numbers that squaring is,
+    /// but it overflows for numbers larger than about the square root of [`MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// assert!(ComplexQuad::new(qd!(3), qd!(4)).norm_sqr() == qd!(25));
+    /// ```
+    ///
+    /// [`abs`]: #method.abs
+    /// [`MAX`]: crate::Quad::MAX
+    #[inline]
+    pub fn norm_sqr(self) -> Quad {
+        self.re.sqr() + self.im.sqr()
+    }
+
+    /// Calculates the absolute value (or modulus) of the `ComplexQuad`.
+    ///
+    /// This is calculated with [`hypot`], so it doesn't overflow or underflow unless the
+    /// result itself does. It's infinite if either part is infinite, even if the other is
+    /// `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::new(qd!(-5), qd!(12));
+    /// assert!((z.abs() - qd!(13)).abs() < qd!(1e-60));
+    ///
+    /// // The square of the absolute value would overflow
+    /// let z = ComplexQuad::new(qd!(3e300), qd!(4e300));
+    /// assert!((z.abs() / qd!(5e300) - qd!(1)).abs() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`hypot`]: crate::Quad::hypot
+    #[inline]
+    pub fn abs(self) -> Quad {
+        self.re.hypot(self.im)
+    }
+
+    /// Calculates the argument (or phase) of the `ComplexQuad`, the angle between the
+    /// positive real axis and the number, in the range [-π, π].
+    ///
+    /// Numbers on the negative real axis have an argument of π if their imaginary part is
+    /// 0 and -π if it's -0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::{qd, Quad};
+    /// assert!(ComplexQuad::new(qd!(1), qd!(1)).arg() == Quad::FRAC_PI_4);
+    /// assert!(ComplexQuad::new(qd!(-1), qd!(0)).arg() == Quad::PI);
+    /// assert!(ComplexQuad::new(qd!(-1), Quad::NEG_ZERO).arg() == -Quad::PI);
+    /// ```
+    pub fn arg(self) -> Quad {
+        if self.im.is_zero() && !self.re.is_nan() {
+            // `atan2` doesn't look at the sign of a zero, but it decides which side of the
+            // branch cut a number on the negative real axis is on
+            let a = if self.re.is_sign_negative() {
+                Quad::PI
+            } else {
+                Quad::ZERO
+            };
+            a.copysign(self.im)
+        } else {
+            self.im.atan2(self.re)
+        }
+    }
+
+    /// Determines whether either part of the `ComplexQuad` is `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// Determines whether either part of the `ComplexQuad` is infinite.
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.re.is_infinite() || self.im.is_infinite()
+    }
+
+    /// Determines whether both parts of the `ComplexQuad` are finite.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// Calculates the reciprocal of the `ComplexQuad`.
+    ///
+    /// The number is scaled by a power of two before its parts are squared, so this
+    /// doesn't overflow or underflow unless the result itself does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::new(qd!(0), qd!(2)).recip();
+    /// assert!(z == ComplexQuad::new(qd!(0), qd!(-0.5)));
+    /// ```
+    pub fn recip(self) -> ComplexQuad {
+        ComplexQuad::ONE / self
+    }
+
+    /// Calculates *e* raised to the power of the `ComplexQuad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::{qd, Quad};
+    /// let z = ComplexQuad::new(Quad::LN_2, Quad::FRAC_PI_2).exp();
+    /// assert!(z.re.abs() < qd!(1e-62));
+    /// assert!((z.im - qd!(2)).abs() < qd!(1e-60));
+    /// ```
+    pub fn exp(self) -> ComplexQuad {
+        let r = self.re.exp();
+        if self.im.is_zero() {
+            // Keep real arguments real, even if `r` is infinite
+            ComplexQuad::new(r, self.im)
+        } else {
+            let (s, c) = self.im.sin_cos();
+            ComplexQuad::new(r * c, r * s)
+        }
+    }
+
+    /// Calculates the principal value of the natural logarithm of the `ComplexQuad`.
+    ///
+    /// The branch cut is on the negative real axis, where the imaginary part of the result
+    /// is π or -π depending on the sign of the argument's zero imaginary part. The
+    /// logarithm of zero has a real part of -∞.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::{qd, Quad};
+    /// let z = ComplexQuad::new(qd!(-1), qd!(0)).ln();
+    /// assert!(z == ComplexQuad::new(qd!(0), Quad::PI));
+    /// ```
+    pub fn ln(self) -> ComplexQuad {
+        let r = self.abs();
+        let re = if r > Quad::new(0.5, 0.0, 0.0, 0.0) && r < Quad::new(2.0, 0.0, 0.0, 0.0) {
+            // Close to the unit circle ln |z| is small, and calculating it from |z| would
+            // lose its relative precision to the rounding of |z|. Instead, |z|² - 1 is
+            // calculated with the 1 subtracted from the larger part, where it cancels.
+            let (a, b) = if self.re.abs() > self.im.abs() {
+                (self.re, self.im)
+            } else {
+                (self.im, self.re)
+            };
+            ((a - Quad::ONE) * (a + Quad::ONE) + b.sqr())
+                .ln_1p()
+                .mul_pwr2(0.5)
+        } else {
+            r.ln()
+        };
+        ComplexQuad::new(re, self.arg())
+    }
+
+    /// Calculates the principal value of the square root of the `ComplexQuad`.
+    ///
+    /// The result always has a non-negative real part. The branch cut is on the negative
+    /// real axis, where the sign of the imaginary part of the result is the sign of the
+    /// argument's zero imaginary part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::new(qd!(-4), qd!(0)).sqrt();
+    /// assert!(z == ComplexQuad::new(qd!(0), qd!(2)));
+    ///
+    /// let w = ComplexQuad::new(qd!(3), qd!(4)).sqrt();
+    /// assert!((w - ComplexQuad::new(qd!(2), qd!(1))).abs() < qd!(1e-60));
+    /// ```
+    pub fn sqrt(self) -> ComplexQuad {
+        if self.re.is_zero() && self.im.is_zero() {
+            return ComplexQuad::new(Quad::ZERO, self.im);
+        }
+        if self.im.is_infinite() {
+            return ComplexQuad::new(Quad::INFINITY, self.im);
+        }
+        if self.re.abs().max(self.im.abs()) > Quad::MAX.mul_pwr2(0.25) {
+            // |z| would overflow, but √(z / 4) is exactly half of √z
+            let r = ComplexQuad::new(self.re.mul_pwr2(0.25), self.im.mul_pwr2(0.25)).sqrt();
+            return ComplexQuad::new(r.re.mul_pwr2(2.0), r.im.mul_pwr2(2.0));
+        }
+        // t = √((|re| + |z|) / 2), halving before adding so the sum can't overflow
+        let a = self.re.abs();
+        let t = (a.mul_pwr2(0.5) + self.abs().mul_pwr2(0.5)).sqrt();
+        if self.re.is_sign_negative() {
+            ComplexQuad::new((self.im.abs() / t).mul_pwr2(0.5), t.copysign(self.im))
+        } else {
+            ComplexQuad::new(t, (self.im / t).mul_pwr2(0.5))
+        }
+    }
+
+    /// Calculates the `ComplexQuad` raised to an integer power.
+    ///
+    /// This uses repeated squaring, so the result is exact as long as each of the products
+    /// is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::new(qd!(1), qd!(1));
+    /// assert!(z.powi(4) == ComplexQuad::from(qd!(-4)));
+    /// assert!(z.powi(-2) == ComplexQuad::new(qd!(0), qd!(-0.5)));
+    /// ```
+    pub fn powi(self, n: i32) -> ComplexQuad {
+        let mut base = self;
+        let mut result = ComplexQuad::ONE;
+        let mut k = n.unsigned_abs();
+        while k > 0 {
+            if k & 1 == 1 {
+                result *= base;
+            }
+            k >>= 1;
+            if k > 0 {
+                base *= base;
+            }
+        }
+        if n < 0 {
+            result.recip()
+        } else {
+            result
+        }
+    }
+
+    /// Calculates the principal value of the `ComplexQuad` raised to a real power.
+    ///
+    /// Zero raised to a positive power is zero, and anything raised to the power of zero
+    /// is one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::new(qd!(-8), qd!(0)).powf(qd!(1) / qd!(3));
+    /// assert!((z - ComplexQuad::new(qd!(1), qd!(3).sqrt())).abs() < qd!(1e-60));
+    /// ```
+    pub fn powf(self, exp: Quad) -> ComplexQuad {
+        if exp.is_zero() {
+            ComplexQuad::ONE
+        } else if self.re.is_zero() && self.im.is_zero() && exp > Quad::ZERO {
+            ComplexQuad::ZERO
+        } else {
+            let (r, theta) = self.to_polar();
+            ComplexQuad::from_polar(r.powf(exp), theta * exp)
+        }
+    }
+
+    /// Calculates the principal value of the `ComplexQuad` raised to a complex power.
+    ///
+    /// This is *e*<sup>*w* ln *z*</sup>. Zero raised to a power with a positive real part
+    /// is zero, and anything raised to the power of zero is one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::{qd, Quad};
+    /// // i^i = e^(-π/2)
+    /// let z = ComplexQuad::I.powc(ComplexQuad::I);
+    /// assert!((z.re - (-Quad::FRAC_PI_2).exp()).abs() < qd!(1e-62));
+    /// assert!(z.im == qd!(0));
+    /// ```
+    pub fn powc(self, exp: ComplexQuad) -> ComplexQuad {
+        if exp.re.is_zero() && exp.im.is_zero() {
+            ComplexQuad::ONE
+        } else if self.re.is_zero() && self.im.is_zero() && exp.re > Quad::ZERO {
+            ComplexQuad::ZERO
+        } else if exp.im.is_zero() {
+            self.powf(exp.re)
+        } else {
+            (exp * self.ln()).exp()
+        }
+    }
+
+    /// Calculates the sine of the `ComplexQuad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// // sin(ix) = i sinh(x)
+    /// let z = ComplexQuad::new(qd!(0), qd!(1)).sin();
+    /// assert!(z == ComplexQuad::new(qd!(0), qd!(1).sinh()));
+    /// ```
+    pub fn sin(self) -> ComplexQuad {
+        let (s, c) = self.re.sin_cos();
+        let (sh, ch) = self.im.sinh_cosh();
+        ComplexQuad::new(s * ch, c * sh)
+    }
+
+    /// Calculates the cosine of the `ComplexQuad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// // cos(ix) = cosh(x)
+    /// let z = ComplexQuad::new(qd!(0), qd!(1)).cos();
+    /// assert!(z == ComplexQuad::new(qd!(1).cosh(), qd!(0)));
+    /// ```
+    pub fn cos(self) -> ComplexQuad {
+        let (s, c) = self.re.sin_cos();
+        let (sh, ch) = self.im.sinh_cosh();
+        ComplexQuad::new(c * ch, -(s * sh))
+    }
+
+    /// Calculates the tangent of the `ComplexQuad`.
+    ///
+    /// For large imaginary parts the result approaches ±*i*, and it's calculated so that
+    /// it does so without overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::new(qd!(1), qd!(1000)).tan();
+    /// assert!(z == ComplexQuad::new(qd!(0), qd!(1)));
+    /// ```
+    pub fn tan(self) -> ComplexQuad {
+        // tan(z) = -i tanh(iz)
+        let t = ComplexQuad::new(-self.im, self.re).tanh();
+        ComplexQuad::new(t.im, -t.re)
+    }
+
+    /// Calculates the hyperbolic sine of the `ComplexQuad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// // sinh(ix) = i sin(x)
+    /// let z = ComplexQuad::new(qd!(0), qd!(1)).sinh();
+    /// assert!(z == ComplexQuad::new(qd!(0), qd!(1).sin()));
+    /// ```
+    pub fn sinh(self) -> ComplexQuad {
+        let (s, c) = self.im.sin_cos();
+        let (sh, ch) = self.re.sinh_cosh();
+        ComplexQuad::new(sh * c, ch * s)
+    }
+
+    /// Calculates the hyperbolic cosine of the `ComplexQuad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// // cosh(ix) = cos(x)
+    /// let z = ComplexQuad::new(qd!(0), qd!(1)).cosh();
+    /// assert!(z == ComplexQuad::new(qd!(1).cos(), qd!(0)));
+    /// ```
+    pub fn cosh(self) -> ComplexQuad {
+        let (s, c) = self.im.sin_cos();
+        let (sh, ch) = self.re.sinh_cosh();
+        ComplexQuad::new(ch * c, sh * s)
+    }
+
+    /// Calculates the hyperbolic tangent of the `ComplexQuad`.
+    ///
+    /// For large real parts the result approaches ±1, and it's calculated so that it does
+    /// so without overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::new(qd!(-1000), qd!(1)).tanh();
+    /// assert!(z == ComplexQuad::new(qd!(-1), qd!(0)));
+    /// ```
+    pub fn tanh(self) -> ComplexQuad {
+        // tanh(a + bi) = (sinh 2a + i sin 2b) / (cosh 2a + cos 2b), but cosh 2a overflows
+        // once |a| passes about 355. Long before that the real part is ±1 to full
+        // precision and the denominator is e^2|a| / 2 to full precision, so past this
+        // point the imaginary part is calculated without the overflowing terms.
+        let (s, c) = self.im.mul_pwr2(2.0).sin_cos();
+        if self.re.abs() > Quad::new(80.0, 0.0, 0.0, 0.0) {
+            let im = if self.im.is_zero() {
+                self.im
+            } else {
+                // The product is copysigned so that the sign survives underflow
+                (s.abs() * (self.re.abs().mul_pwr2(-2.0)).exp())
+                    .mul_pwr2(2.0)
+                    .copysign(s)
+            };
+            ComplexQuad::new(Quad::ONE.copysign(self.re), im)
+        } else {
+            let (sh, ch) = self.re.mul_pwr2(2.0).sinh_cosh();
+            let d = ch + c;
+            ComplexQuad::new(sh / d, s / d)
+        }
+    }
+}
+
+impl From<Quad> for ComplexQuad {
+    /// Converts a `Quad` into a `ComplexQuad` with an imaginary part of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::from(qd!(2));
+    /// assert!(z == ComplexQuad::new(qd!(2), qd!(0)));
+    /// ```
+    #[inline]
+    fn from(re: Quad) -> ComplexQuad {
+        ComplexQuad::new(re, Quad::ZERO)
+    }
+}
+
+impl From<(Quad, Quad)> for ComplexQuad {
+    /// Converts a tuple of a real and an imaginary part into a `ComplexQuad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::from((qd!(2), qd!(3)));
+    /// assert!(z == ComplexQuad::new(qd!(2), qd!(3)));
+    /// ```
+    #[inline]
+    fn from((re, im): (Quad, Quad)) -> ComplexQuad {
+        ComplexQuad::new(re, im)
+    }
+}
+
+impl Display for ComplexQuad {
+    /// Formats the `ComplexQuad` as *a*+*b*i or *a*-*b*i. Any precision given in the
+    /// format string is applied to both parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::complex::ComplexQuad;
+    /// # use qd::qd;
+    /// let z = ComplexQuad::new(qd!(1.5), qd!(-2));
+    /// assert!(format!("{}", z) == "1.5-2i");
+    /// assert!(format!("{:.2}", z) == "1.50-2.00i");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let sign = if self.im.is_sign_negative() { '-' } else { '+' };
+        match f.precision() {
+            Some(p) => write!(f, "{:.*}{}{:.*}i", p, self.re, sign, p, self.im.abs()),
+            None => write!(f, "{}{}{}i", self.re, sign, self.im.abs()),
+        }
+    }
+}
+
+impl Add for ComplexQuad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn add(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Add<Quad> for ComplexQuad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn add(self, other: Quad) -> ComplexQuad {
+        ComplexQuad::new(self.re + other, self.im)
+    }
+}
+
+impl Add<ComplexQuad> for Quad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn add(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(self + other.re, other.im)
+    }
+}
+
+impl Sub for ComplexQuad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn sub(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Sub<Quad> for ComplexQuad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn sub(self, other: Quad) -> ComplexQuad {
+        ComplexQuad::new(self.re - other, self.im)
+    }
+}
+
+impl Sub<ComplexQuad> for Quad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn sub(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(self - other.re, -other.im)
+    }
+}
+
+impl Mul for ComplexQuad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn mul(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Mul<Quad> for ComplexQuad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn mul(self, other: Quad) -> ComplexQuad {
+        ComplexQuad::new(self.re * other, self.im * other)
+    }
+}
+
+impl Mul<ComplexQuad> for Quad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn mul(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::new(self * other.re, self * other.im)
+    }
+}
+
+impl Div for ComplexQuad {
+    type Output = ComplexQuad;
+
+    /// Divides one `ComplexQuad` by another.
+    ///
+    /// Both numbers are scaled by powers of two before their parts are multiplied, so the
+    /// division doesn't overflow or underflow unless the result itself does.
+    fn div(self, other: ComplexQuad) -> ComplexQuad {
+        let m = other.re.abs().max(other.im.abs());
+        let n = self.re.abs().max(self.im.abs());
+        if m.is_zero() || !m.is_finite() || !n.is_finite() {
+            // Leave the special cases to the real arithmetic
+            let d = other.norm_sqr();
+            return ComplexQuad::new(
+                (self.re * other.re + self.im * other.im) / d,
+                (self.im * other.re - self.re * other.im) / d,
+            );
+        }
+        let j = -u::exponent(n[0]);
+        let k = -u::exponent(m[0]);
+        let (a, b) = (self.re.scale_b(j), self.im.scale_b(j));
+        let (c, d) = (other.re.scale_b(k), other.im.scale_b(k));
+        let den = c.sqr() + d.sqr();
+        ComplexQuad::new(
+            ((a * c + b * d) / den).scale_b(k - j),
+            ((b * c - a * d) / den).scale_b(k - j),
+        )
+    }
+}
+
+impl Div<Quad> for ComplexQuad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn div(self, other: Quad) -> ComplexQuad {
+        ComplexQuad::new(self.re / other, self.im / other)
+    }
+}
+
+impl Div<ComplexQuad> for Quad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn div(self, other: ComplexQuad) -> ComplexQuad {
+        ComplexQuad::from(self) / other
+    }
+}
+
+impl Neg for ComplexQuad {
+    type Output = ComplexQuad;
+
+    #[inline]
+    fn neg(self) -> ComplexQuad {
+        ComplexQuad::new(-self.re, -self.im)
+    }
+}
+
+impl AddAssign for ComplexQuad {
+    #[inline]
+    fn add_assign(&mut self, other: ComplexQuad) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Quad> for ComplexQuad {
+    #[inline]
+    fn add_assign(&mut self, other: Quad) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for ComplexQuad {
+    #[inline]
+    fn sub_assign(&mut self, other: ComplexQuad) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<Quad> for ComplexQuad {
+    #[inline]
+    fn sub_assign(&mut self, other: Quad) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for ComplexQuad {
+    #[inline]
+    fn mul_assign(&mut self, other: ComplexQuad) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<Quad> for ComplexQuad {
+    #[inline]
+    fn mul_assign(&mut self, other: Quad) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for ComplexQuad {
+    #[inline]
+    fn div_assign(&mut self, other: ComplexQuad) {
+        *self = *self / other;
+    }
+}
+
+impl DivAssign<Quad> for ComplexQuad {
+    #[inline]
+    fn div_assign(&mut self, other: Quad) {
+        *self = *self / other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+
+    // Reference values, calculated with mpmath
+    const DIV_RE: &str =
+        "0.666666666666666666666666666666666666666666666666666666666666666666666666667";
+    const DIV_IM: &str =
+        "-0.666666666666666666666666666666666666666666666666666666666666666666666666667";
+    const EXP_RE: &str =
+        "0.538676490387943896030238571800498396832536574614083670380650243663846378073";
+    const EXP_IM: &str =
+        "-1.94036790468921176046663980547777703827908280796988732457184086895775754172";
+    const LN_RE: &str =
+        "0.389662438400498820879282832149324901966823020302970227564448881119575843405";
+    const LN_IM: &str =
+        "-1.07685495787531540161126546725276691455245994885584187451594223116425311648";
+    const SQRT_RE: &str =
+        "1.04318797587571437113728258249741640454117740483273233882479757938389109829";
+    const SQRT_IM: &str =
+        "-0.623090003941380870534427628174215815447787387832148351189268479759887168855";
+    const SIN_RE: &str =
+        "1.26969780720487449005646638121660886814135647103856986770668131358132272588";
+    const SIN_IM: &str =
+        "-1.29899453818444478081805228631372169043965533389971256675409859270394409998";
+    const COS_RE: &str =
+        "1.50743835087314248648063460655460049960736415513668379443439699558479682144";
+    const COS_IM: &str =
+        "1.09412800579776180949860661430704329555125004429275978678702200054501077154";
+    const TAN_RE: &str =
+        "0.142016655369480354272294207158284329326540028006149127362532040215689300584";
+    const TAN_IM: &str =
+        "-0.964801603509366105514375525691749268857550302011414253619678270354184635346";
+    const SINH_RE: &str =
+        "0.202920251655778483565136552438489578045662683928740210505047842964558705513";
+    const SINH_IM: &str =
+        "-1.20942836945765391308159289497455443649963483820097665557455570308799872839";
+    const COSH_RE: &str =
+        "0.335756238732165412465102019362008818786873890685343459875602400699287672561";
+    const COSH_IM: &str =
+        "-0.730939535231557847385046910503222601779447969768910668997285165869758813332";
+    const TANH_RE: &str =
+        "1.47162844587361992626796269732229244201163957382746129231555822438299435645";
+    const TANH_IM: &str =
+        "-0.398375195356977525781812756128751962137829281753993964511908097834792567179";
+    const LN_UNIT_RE: &str = "4.99999999999999999999999999999999999999975e-41";
+    const LN_UNIT_IM: &str =
+        "1.57079632679489661922132169163975144209858469968755291048747262948724153648";
+    const SQRT23_RE: &str =
+        "1.67414922803554004044803930084905182167470867788392036672728783600339924034";
+    const SQRT23_IM: &str =
+        "0.895977476129838124715733755290043441043324199554931493244900698987447058216";
+    const POWC_RE: &str =
+        "6.24860483633407480859883511170216983076216402050736319575090218689273324841";
+    const POWC_IM: &str =
+        "-4.78485858063106241359837852266237158585005934287303037100150475097265429697";
+    const POWF_RE: &str =
+        "3.5079764595389958831244236862847579571449034115744446850300377586856242731";
+    const POWF_IM: &str =
+        "4.9930851024872136080858177591080582329900305579811717006368505555731559749";
+    const LN_NEG2_RE: &str =
+        "0.69314718055994530941723212145817656807550013436025525412068000949339362197";
+    const LN_NEG2_IM: &str =
+        "3.14159265358979323846264338327950288419716939937510582097494459230781640629";
+    const TANH60_IM: &str =
+        "1.39443453264806183331123567345948824553813072993485877608428466911429615352e-52";
+    const TANH100_IM: &str =
+        "2.51674710150942729341018987831028280740055821227378910813405213884650219588e-87";
+
+    fn double_close(actual: ComplexDouble, re: &str, im: &str) {
+        let (re, im) = (Double::from(re), Double::from(im));
+        let tol = dd!(1e-30);
+        assert!(
+            (actual.re - re).abs() <= re.abs() * tol && (actual.im - im).abs() <= im.abs() * tol,
+            "expected {}{:+}i, got {}",
+            re,
+            im,
+            actual
+        );
+    }
+
+    #[test]
+    fn double_arithmetic() {
+        let a = ComplexDouble::new(dd!(1.5), dd!(2.5));
+        let b = ComplexDouble::new(dd!(-0.75), dd!(3));
+        assert!(a + b == ComplexDouble::new(dd!(0.75), dd!(5.5)));
+        assert!(a - b == ComplexDouble::new(dd!(2.25), dd!(-0.5)));
+        assert!(a * b == ComplexDouble::new(dd!(-8.625), dd!(2.625)));
+        double_close(a / b, DIV_RE, DIV_IM);
+        assert!(-a == ComplexDouble::new(dd!(-1.5), dd!(-2.5)));
+
+        assert!(a + dd!(1) == ComplexDouble::new(dd!(2.5), dd!(2.5)));
+        assert!(dd!(1) - a == ComplexDouble::new(dd!(-0.5), dd!(-2.5)));
+        assert!(a * dd!(2) == dd!(2) * a);
+        assert!(a / dd!(2) == ComplexDouble::new(dd!(0.75), dd!(1.25)));
+        assert!(
+            dd!(1) / ComplexDouble::new(dd!(3), dd!(-4))
+                == ComplexDouble::new(dd!(0.12), dd!(0.16))
+        );
+
+        let mut c = a;
+        c += b;
+        c -= b;
+        c *= b;
+        c /= b;
+        double_close(c, "1.5", "2.5");
+    }
+
+    #[test]
+    fn double_div_scaling() {
+        // The squares of the parts of `b` overflow or underflow
+        let a = ComplexDouble::new(dd!(1).scale_b(1020), dd!(2).scale_b(1020));
+        let b = ComplexDouble::new(dd!(3).scale_b(1020), dd!(4).scale_b(1020));
+        double_close(a / b, "0.44", "0.08");
+
+        let a = ComplexDouble::new(dd!(1).scale_b(-1060), dd!(2).scale_b(-1060));
+        let b = ComplexDouble::new(dd!(3).scale_b(-1060), dd!(4).scale_b(-1060));
+        double_close(a / b, "0.44", "0.08");
+    }
+
+    #[test]
+    fn double_div_special() {
+        let z = ComplexDouble::ONE / ComplexDouble::ZERO;
+        assert!(z.is_nan() || z.is_infinite());
+        assert!((ComplexDouble::ONE / ComplexDouble::NAN).is_nan());
+    }
+
+    #[test]
+    fn double_abs_arg() {
+        assert!(ComplexDouble::new(dd!(3), dd!(-4)).abs() == dd!(5));
+        let z = ComplexDouble::new(dd!(3e307), dd!(4e307));
+        assert!(z.abs().is_finite());
+        assert!(ComplexDouble::new(Double::INFINITY, Double::NAN).abs() == Double::INFINITY);
+
+        assert!(ComplexDouble::ZERO.arg() == dd!(0));
+        assert!(ComplexDouble::new(Double::NEG_ZERO, dd!(0)).arg() == Double::PI);
+        assert!(ComplexDouble::new(dd!(-2), dd!(0)).arg() == Double::PI);
+        assert!(ComplexDouble::new(dd!(-2), Double::NEG_ZERO).arg() == -Double::PI);
+        assert!(ComplexDouble::new(dd!(0), dd!(-2)).arg() == -Double::FRAC_PI_2);
+        assert!(ComplexDouble::NAN.arg().is_nan());
+    }
+
+    #[test]
+    fn double_polar() {
+        let z = ComplexDouble::new(dd!(1.5), dd!(-2.5));
+        let (r, theta) = z.to_polar();
+        double_close(ComplexDouble::from_polar(r, theta), "1.5", "-2.5");
+    }
+
+    #[test]
+    fn double_exp_ln() {
+        let z = ComplexDouble::new(dd!(0.7), dd!(-1.3));
+        double_close(z.exp(), EXP_RE, EXP_IM);
+        double_close(z.ln(), LN_RE, LN_IM);
+        double_close(z.ln().exp(), "0.7", "-1.3");
+        assert!(
+            ComplexDouble::new(dd!(1000), dd!(0)).exp()
+                == ComplexDouble::new(Double::INFINITY, dd!(0))
+        );
+    }
+
+    #[test]
+    fn double_ln_precision() {
+        // |z| is 1 + 5e-41, so ln |z| can't be calculated from a rounded |z|
+        let z = ComplexDouble::new(dd!("1e-20"), dd!(1));
+        double_close(z.ln(), LN_UNIT_RE, LN_UNIT_IM);
+    }
+
+    #[test]
+    fn double_ln_branch_cut() {
+        double_close(
+            ComplexDouble::new(dd!(-2), dd!(0)).ln(),
+            LN_NEG2_RE,
+            LN_NEG2_IM,
+        );
+        let z = ComplexDouble::new(dd!(-2), Double::NEG_ZERO).ln();
+        assert!(z.im == -Double::PI);
+        let z = ComplexDouble::ZERO.ln();
+        assert!(z.re == Double::NEG_INFINITY && z.im == dd!(0));
+    }
+
+    #[test]
+    fn double_sqrt() {
+        double_close(
+            ComplexDouble::new(dd!(0.7), dd!(-1.3)).sqrt(),
+            SQRT_RE,
+            SQRT_IM,
+        );
+        double_close(
+            ComplexDouble::new(dd!(2), dd!(3)).sqrt(),
+            SQRT23_RE,
+            SQRT23_IM,
+        );
+        assert!(ComplexDouble::new(dd!(-3), dd!(4)).sqrt() == ComplexDouble::new(dd!(1), dd!(2)));
+        assert!(ComplexDouble::new(dd!(-3), dd!(-4)).sqrt() == ComplexDouble::new(dd!(1), dd!(-2)));
+
+        let z = ComplexDouble::new(dd!(-4), Double::NEG_ZERO).sqrt();
+        assert!(z == ComplexDouble::new(dd!(0), dd!(-2)));
+        let z = ComplexDouble::new(Double::MAX, Double::MAX).sqrt();
+        assert!(z.is_finite());
+        let z = ComplexDouble::new(Double::NEG_ZERO, Double::NEG_ZERO).sqrt();
+        assert!(z.re == dd!(0) && z.im.is_sign_negative());
+        let z = ComplexDouble::new(dd!(1), Double::INFINITY).sqrt();
+        assert!(z == ComplexDouble::new(Double::INFINITY, Double::INFINITY));
+    }
+
+    #[test]
+    fn double_powers() {
+        let a = ComplexDouble::new(dd!(1), dd!(2));
+        double_close(
+            a.powc(ComplexDouble::new(dd!(0.5), dd!(-1.5))),
+            POWC_RE,
+            POWC_IM,
+        );
+        double_close(
+            ComplexDouble::new(dd!(-2), dd!(0.5)).powf(dd!(2.5)),
+            POWF_RE,
+            POWF_IM,
+        );
+        assert!(a.powi(3) == a * a * a);
+        assert!((a.powi(-3) * a.powi(3) - ComplexDouble::ONE).abs() < dd!(1e-30));
+        assert!(a.powi(0) == ComplexDouble::ONE);
+
+        assert!(ComplexDouble::ZERO.powc(ComplexDouble::ZERO) == ComplexDouble::ONE);
+        assert!(ComplexDouble::ZERO.powc(a) == ComplexDouble::ZERO);
+        assert!(ComplexDouble::ZERO.powf(dd!(2)) == ComplexDouble::ZERO);
+        assert!(a.powf(dd!(0)) == ComplexDouble::ONE);
+    }
+
+    #[test]
+    fn double_trig() {
+        let z = ComplexDouble::new(dd!(0.7), dd!(-1.3));
+        double_close(z.sin(), SIN_RE, SIN_IM);
+        double_close(z.cos(), COS_RE, COS_IM);
+        double_close(z.tan(), TAN_RE, TAN_IM);
+    }
+
+    #[test]
+    fn double_hyper() {
+        let z = ComplexDouble::new(dd!(0.7), dd!(-1.3));
+        double_close(z.sinh(), SINH_RE, SINH_IM);
+        double_close(z.cosh(), COSH_RE, COSH_IM);
+        double_close(z.tanh(), TANH_RE, TANH_IM);
+    }
+
+    #[test]
+    fn double_tanh_large() {
+        let z = ComplexDouble::new(dd!(60), dd!(1)).tanh();
+        double_close(z, "1", TANH60_IM);
+        let z = ComplexDouble::new(dd!(-1e10), dd!(-1)).tanh();
+        assert!(z.re == dd!(-1) && z.im == dd!(0) && z.im.is_sign_negative());
+        let z = ComplexDouble::new(dd!(100), dd!(0)).tanh();
+        assert!(z == ComplexDouble::ONE);
+        let z = ComplexDouble::new(dd!(1), dd!(-100)).tan();
+        assert!(z.im == dd!(-1));
+    }
+
+    #[test]
+    fn double_display() {
+        let z = ComplexDouble::new(dd!(1.5), dd!(-2));
+        assert!(format!("{}", z) == "1.5-2i");
+        assert!(format!("{}", z.conj()) == "1.5+2i");
+        assert!(format!("{:.3}", z) == "1.500-2.000i");
+    }
+
+    fn quad_close(actual: ComplexQuad, re: &str, im: &str) {
+        let (re, im) = (Quad::from(re), Quad::from(im));
+        let tol = qd!(1e-60);
+        assert!(
+            (actual.re - re).abs() <= re.abs() * tol && (actual.im - im).abs() <= im.abs() * tol,
+            "expected {}{:+}i, got {}",
+            re,
+            im,
+            actual
+        );
+    }
+
+    #[test]
+    fn quad_arithmetic() {
+        let a = ComplexQuad::new(qd!(1.5), qd!(2.5));
+        let b = ComplexQuad::new(qd!(-0.75), qd!(3));
+        assert!(a + b == ComplexQuad::new(qd!(0.75), qd!(5.5)));
+        assert!(a - b == ComplexQuad::new(qd!(2.25), qd!(-0.5)));
+        assert!(a * b == ComplexQuad::new(qd!(-8.625), qd!(2.625)));
+        quad_close(a / b, DIV_RE, DIV_IM);
+        assert!(-a == ComplexQuad::new(qd!(-1.5), qd!(-2.5)));
+
+        assert!(a + qd!(1) == ComplexQuad::new(qd!(2.5), qd!(2.5)));
+        assert!(qd!(1) - a == ComplexQuad::new(qd!(-0.5), qd!(-2.5)));
+        assert!(a * qd!(2) == qd!(2) * a);
+        assert!(a / qd!(2) == ComplexQuad::new(qd!(0.75), qd!(1.25)));
+        assert!(
+            qd!(1) / ComplexQuad::new(qd!(3), qd!(-4)) == ComplexQuad::new(qd!(0.12), qd!(0.16))
+        );
+
+        let mut c = a;
+        c += b;
+        c -= b;
+        c *= b;
+        c /= b;
+        quad_close(c, "1.5", "2.5");
+    }
+
+    #[test]
+    fn quad_div_scaling() {
+        // The squares of the parts of `b` overflow or underflow
+        let a = ComplexQuad::new(qd!(1).scale_b(1020), qd!(2).scale_b(1020));
+        let b = ComplexQuad::new(qd!(3).scale_b(1020), qd!(4).scale_b(1020));
+        quad_close(a / b, "0.44", "0.08");
+
+        let a = ComplexQuad::new(qd!(1).scale_b(-1060), qd!(2).scale_b(-1060));
+        let b = ComplexQuad::new(qd!(3).scale_b(-1060), qd!(4).scale_b(-1060));
+        quad_close(a / b, "0.44", "0.08");
+    }
+
+    #[test]
+    fn quad_div_special() {
+        let z = ComplexQuad::ONE / ComplexQuad::ZERO;
+        assert!(z.is_nan() || z.is_infinite());
+        assert!((ComplexQuad::ONE / ComplexQuad::NAN).is_nan());
+    }
+
+    #[test]
+    fn quad_abs_arg() {
+        quad_close(
+            ComplexQuad::from(ComplexQuad::new(qd!(3), qd!(-4)).abs()),
+            "5",
+            "0",
+        );
+        let z = ComplexQuad::new(qd!(3e307), qd!(4e307));
+        assert!(z.abs().is_finite());
+        assert!(ComplexQuad::new(Quad::INFINITY, Quad::NAN).abs() == Quad::INFINITY);
+
+        assert!(ComplexQuad::ZERO.arg() == qd!(0));
+        assert!(ComplexQuad::new(Quad::NEG_ZERO, qd!(0)).arg() == Quad::PI);
+        assert!(ComplexQuad::new(qd!(-2), qd!(0)).arg() == Quad::PI);
+        assert!(ComplexQuad::new(qd!(-2), Quad::NEG_ZERO).arg() == -Quad::PI);
+        assert!(ComplexQuad::new(qd!(0), qd!(-2)).arg() == -Quad::FRAC_PI_2);
+        assert!(ComplexQuad::NAN.arg().is_nan());
+    }
+
+    #[test]
+    fn quad_polar() {
+        let z = ComplexQuad::new(qd!(1.5), qd!(-2.5));
+        let (r, theta) = z.to_polar();
+        quad_close(ComplexQuad::from_polar(r, theta), "1.5", "-2.5");
+    }
+
+    #[test]
+    fn quad_exp_ln() {
+        let z = ComplexQuad::new(qd!(0.7), qd!(-1.3));
+        quad_close(z.exp(), EXP_RE, EXP_IM);
+        quad_close(z.ln(), LN_RE, LN_IM);
+        quad_close(z.ln().exp(), "0.7", "-1.3");
+        assert!(
+            ComplexQuad::new(qd!(1000), qd!(0)).exp() == ComplexQuad::new(Quad::INFINITY, qd!(0))
+        );
+    }
+
+    #[test]
+    fn quad_ln_precision() {
+        // |z| is 1 + 5e-41, so ln |z| can't be calculated from a rounded |z|
+        let z = ComplexQuad::new(qd!("1e-20"), qd!(1));
+        quad_close(z.ln(), LN_UNIT_RE, LN_UNIT_IM);
+    }
+
+    #[test]
+    fn quad_ln_branch_cut() {
+        quad_close(
+            ComplexQuad::new(qd!(-2), qd!(0)).ln(),
+            LN_NEG2_RE,
+            LN_NEG2_IM,
+        );
+        let z = ComplexQuad::new(qd!(-2), Quad::NEG_ZERO).ln();
+        assert!(z.im == -Quad::PI);
+        let z = ComplexQuad::ZERO.ln();
+        assert!(z.re == Quad::NEG_INFINITY && z.im == qd!(0));
+    }
+
+    #[test]
+    fn quad_sqrt() {
+        quad_close(
+            ComplexQuad::new(qd!(0.7), qd!(-1.3)).sqrt(),
+            SQRT_RE,
+            SQRT_IM,
+        );
+        quad_close(
+            ComplexQuad::new(qd!(2), qd!(3)).sqrt(),
+            SQRT23_RE,
+            SQRT23_IM,
+        );
+        quad_close(ComplexQuad::new(qd!(-3), qd!(4)).sqrt(), "1", "2");
+        quad_close(ComplexQuad::new(qd!(-3), qd!(-4)).sqrt(), "1", "-2");
+
+        let z = ComplexQuad::new(qd!(-4), Quad::NEG_ZERO).sqrt();
+        assert!(z == ComplexQuad::new(qd!(0), qd!(-2)));
+        let z = ComplexQuad::new(Quad::MAX, Quad::MAX).sqrt();
+        assert!(z.is_finite());
+        let z = ComplexQuad::new(Quad::NEG_ZERO, Quad::NEG_ZERO).sqrt();
+        assert!(z.re == qd!(0) && z.im.is_sign_negative());
+        let z = ComplexQuad::new(qd!(1), Quad::INFINITY).sqrt();
+        assert!(z == ComplexQuad::new(Quad::INFINITY, Quad::INFINITY));
+    }
+
+    #[test]
+    fn quad_powers() {
+        let a = ComplexQuad::new(qd!(1), qd!(2));
+        quad_close(
+            a.powc(ComplexQuad::new(qd!(0.5), qd!(-1.5))),
+            POWC_RE,
+            POWC_IM,
+        );
+        quad_close(
+            ComplexQuad::new(qd!(-2), qd!(0.5)).powf(qd!(2.5)),
+            POWF_RE,
+            POWF_IM,
+        );
+        assert!(a.powi(3) == a * a * a);
+        assert!((a.powi(-3) * a.powi(3) - ComplexQuad::ONE).abs() < qd!(1e-60));
+        assert!(a.powi(0) == ComplexQuad::ONE);
+
+        assert!(ComplexQuad::ZERO.powc(ComplexQuad::ZERO) == ComplexQuad::ONE);
+        assert!(ComplexQuad::ZERO.powc(a) == ComplexQuad::ZERO);
+        assert!(ComplexQuad::ZERO.powf(qd!(2)) == ComplexQuad::ZERO);
+        assert!(a.powf(qd!(0)) == ComplexQuad::ONE);
+    }
+
+    #[test]
+    fn quad_trig() {
+        let z = ComplexQuad::new(qd!(0.7), qd!(-1.3));
+        quad_close(z.sin(), SIN_RE, SIN_IM);
+        quad_close(z.cos(), COS_RE, COS_IM);
+        quad_close(z.tan(), TAN_RE, TAN_IM);
+    }
+
+    #[test]
+    fn quad_hyper() {
+        let z = ComplexQuad::new(qd!(0.7), qd!(-1.3));
+        quad_close(z.sinh(), SINH_RE, SINH_IM);
+        quad_close(z.cosh(), COSH_RE, COSH_IM);
+        quad_close(z.tanh(), TANH_RE, TANH_IM);
+    }
+
+    #[test]
+    fn quad_tanh_large() {
+        let z = ComplexQuad::new(qd!(100), qd!(1)).tanh();
+        quad_close(z, "1", TANH100_IM);
+        let z = ComplexQuad::new(qd!(-1e10), qd!(-1)).tanh();
+        assert!(z.re == qd!(-1) && z.im == qd!(0) && z.im.is_sign_negative());
+        let z = ComplexQuad::new(qd!(200), qd!(0)).tanh();
+        assert!(z == ComplexQuad::ONE);
+        let z = ComplexQuad::new(qd!(1), qd!(-200)).tan();
+        assert!(z.im == qd!(-1));
+    }
+
+    #[test]
+    fn quad_display() {
+        let z = ComplexQuad::new(qd!(1.5), qd!(-2));
+        assert!(format!("{}", z) == "1.5-2i");
+        assert!(format!("{}", z.conj()) == "1.5+2i");
+        assert!(format!("{:.3}", z) == "1.500-2.000i");
+    }
+}
@@ -0,0 +1,22 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Collections of constants that aren't associated constants of [`Double`] or [`Quad`]
+//! because they're specific to a particular field.
+//!
+//! Each collection is in its own module and is enabled by its own feature, so that
+//! nobody pays for constants they don't use. The [`compute`] module, which is always
+//! available, can calculate new constants at compile time.
+//!
+//! * [`compute`]: compile-time calculation of new constants to full precision.
+//! * [`physics`] (feature `physics`): CODATA 2022 physical constants.
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+
+pub mod compute;
+
+#[cfg(feature = "physics")]
+pub mod physics;
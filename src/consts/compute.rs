@@ -0,0 +1,829 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Compile-time calculation of constants.
+//!
+//! Defining a new constant for a [`Double`] or a [`Quad`] usually means finding its value
+//! to 70 or so digits somewhere, splitting it into components, and checking by hand that
+//! the components are right. [`Constant`] does all of that in a `const` context instead.
+//! It holds a value as an exact fixed-point binary number, it can calculate a handful of
+//! fundamental constants (π, *e*, ln 2, and ln 10) and combine them with arithmetic and
+//! square roots, and it rounds the result into correctly rounded components.
+//!
+//! Because every method is a `const fn`, the calculation happens entirely at compile time
+//! when the result is assigned to a `const`, and the constant costs nothing at runtime.
+//!
+//! # Examples
+//! ```
+//! use qd::consts::compute::Constant;
+//! use qd::{qd, Quad};
+//!
+//! // π/5 and √3, to the full precision of a `Quad`
+//! const FRAC_PI_5: Quad = Constant::pi().over(Constant::int(5)).to_quad();
+//! const SQRT_3: Quad = Constant::int(3).sqrt().to_quad();
+//!
+//! assert!(Constant::pi().over(Constant::int(3)).to_quad() == Quad::FRAC_PI_3);
+//! assert!((FRAC_PI_5 * qd!(5) - Quad::PI).abs() < qd!(1e-62));
+//! assert!((SQRT_3 * SQRT_3 - qd!(3)).abs() < qd!(1e-62));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+
+use crate::{Double, Quad};
+
+// The number of 64-bit limbs in a value. The top limb is the integer part and the rest
+// are the fraction.
+const LIMBS: usize = 7;
+
+// The number of bits in the fractional part of a value.
+const FRAC_BITS: i32 = 384;
+
+/// An exact fixed-point value that can be used to calculate constants at compile time.
+///
+/// A `Constant` has a sign, a 64-bit integer part, and a 384-bit fractional part. All of
+/// the calculations on it truncate at the last fractional bit, so results are accurate to
+/// about 2<sup>-380</sup>. That's far more than the 212 bits of a [`Quad`] for any
+/// constant of reasonable size, but it does mean that a result smaller than about
+/// 2<sup>-160</sup> won't have the full precision of a [`Quad`] in its trailing
+/// components. Calculations whose integer part would overflow 64 bits panic, which is a
+/// compile error in a `const` context.
+///
+/// The arithmetic methods are called [`plus`], [`minus`], [`times`], and [`over`] rather
+/// than implementing the operator traits, since trait methods can't be called in a
+/// `const` context.
+///
+/// [`Quad`]: crate::Quad
+/// [`plus`]: #method.plus
+/// [`minus`]: #method.minus
+/// [`times`]: #method.times
+/// [`over`]: #method.over
+#[derive(Clone, Copy, Debug)]
+pub struct Constant {
+    negative: bool,
+    mag: [u64; LIMBS],
+}
+
+impl Constant {
+    /// Creates a `Constant` with the value of an integer.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// # use qd::dd;
+    /// assert!(Constant::int(-7).to_double() == dd!(-7));
+    /// ```
+    pub const fn int(n: i64) -> Constant {
+        let mut mag = [0; LIMBS];
+        mag[LIMBS - 1] = n.unsigned_abs();
+        Constant {
+            negative: n < 0,
+            mag,
+        }
+    }
+
+    /// Creates a `Constant` with the value of the fraction `num`/`den`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// # use qd::qd;
+    /// assert!(Constant::ratio(1, 3).to_quad() == qd!(1) / qd!(3));
+    /// ```
+    pub const fn ratio(num: i64, den: u64) -> Constant {
+        let n = Constant::int(num);
+        Constant {
+            negative: n.negative,
+            mag: div_small(n.mag, den),
+        }
+    }
+
+    /// Calculates π.
+    ///
+    /// This uses Machin's formula, π = 16 arctan(1/5) - 4 arctan(1/239).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// # use qd::Quad;
+    /// assert!(Constant::pi().to_quad() == Quad::PI);
+    /// ```
+    pub const fn pi() -> Constant {
+        let a = Constant {
+            negative: false,
+            mag: mul_small(atan_inv(5), 16),
+        };
+        let b = Constant {
+            negative: false,
+            mag: mul_small(atan_inv(239), 4),
+        };
+        a.minus(b)
+    }
+
+    /// Calculates *e*, the base of the natural logarithm.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// # use qd::Quad;
+    /// assert!(Constant::e().to_quad() == Quad::E);
+    /// ```
+    pub const fn e() -> Constant {
+        // e = Σ 1/k!
+        let mut sum = Constant::int(1).mag;
+        let mut term = sum;
+        let mut k = 1;
+        while !is_zero(term) {
+            term = div_small(term, k);
+            sum = add(sum, term);
+            k += 1;
+        }
+        Constant {
+            negative: false,
+            mag: sum,
+        }
+    }
+
+    /// Calculates ln 2, the natural logarithm of 2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// # use qd::Quad;
+    /// assert!(Constant::ln_2().to_quad() == Quad::LN_2);
+    /// ```
+    pub const fn ln_2() -> Constant {
+        // ln 2 = 2 artanh(1/3)
+        Constant {
+            negative: false,
+            mag: mul_small(atanh_inv(3), 2),
+        }
+    }
+
+    /// Calculates ln 10, the natural logarithm of 10.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// # use qd::Quad;
+    /// assert!(Constant::ln_10().to_quad() == Quad::LN_10);
+    /// ```
+    pub const fn ln_10() -> Constant {
+        // ln 10 = 3 ln 2 + ln(5/4) = 3 ln 2 + 2 artanh(1/9)
+        let a = mul_small(Constant::ln_2().mag, 3);
+        let b = mul_small(atanh_inv(9), 2);
+        Constant {
+            negative: false,
+            mag: add(a, b),
+        }
+    }
+
+    /// Returns the `Constant` with its sign reversed.
+    pub const fn negated(self) -> Constant {
+        Constant {
+            negative: !self.negative,
+            mag: self.mag,
+        }
+    }
+
+    /// Adds another `Constant` to this one.
+    pub const fn plus(self, other: Constant) -> Constant {
+        if self.negative == other.negative {
+            Constant {
+                negative: self.negative,
+                mag: add(self.mag, other.mag),
+            }
+        } else if cmp(self.mag, other.mag) >= 0 {
+            Constant {
+                negative: self.negative,
+                mag: sub(self.mag, other.mag),
+            }
+        } else {
+            Constant {
+                negative: other.negative,
+                mag: sub(other.mag, self.mag),
+            }
+        }
+    }
+
+    /// Subtracts another `Constant` from this one.
+    pub const fn minus(self, other: Constant) -> Constant {
+        self.plus(other.negated())
+    }
+
+    /// Multiplies this `Constant` by another one.
+    pub const fn times(self, other: Constant) -> Constant {
+        Constant {
+            negative: self.negative != other.negative,
+            mag: mul(self.mag, other.mag),
+        }
+    }
+
+    /// Divides this `Constant` by another one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero or if the quotient is too large for the integer part.
+    pub const fn over(self, other: Constant) -> Constant {
+        Constant {
+            negative: self.negative != other.negative,
+            mag: div(self.mag, other.mag),
+        }
+    }
+
+    /// Calculates the reciprocal of the `Constant`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// # use qd::Quad;
+    /// assert!(Constant::pi().recip().to_quad() == Quad::FRAC_1_PI);
+    /// ```
+    pub const fn recip(self) -> Constant {
+        Constant::int(1).over(self)
+    }
+
+    /// Calculates the square root of the `Constant`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Constant` is negative and not zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// # use qd::Quad;
+    /// assert!(Constant::int(2).sqrt().to_quad() == Quad::SQRT_2);
+    /// ```
+    pub const fn sqrt(self) -> Constant {
+        assert!(
+            !self.negative || is_zero(self.mag),
+            "square root of a negative constant"
+        );
+        // Digit-by-digit calculation: set each bit of the root, from the highest possible
+        // one down, as long as the square of the root stays no larger than the argument.
+        // `rem` is the argument minus the square of the root so far, and adding bit 2^p to
+        // root y increases its square by 2^(p + 1) y + 2^(2p).
+        let mut rem = self.mag;
+        let mut root = [0; LIMBS];
+        let mut p = 31;
+        while p >= -FRAC_BITS {
+            let mut t = shift(root, p + 1);
+            let exact = 2 * p >= -FRAC_BITS;
+            if exact {
+                t = add(t, bit_value(2 * p + FRAC_BITS));
+            }
+            // If 2^(2p) is too small to represent, the real increase is a little larger
+            // than `t`, so `rem` has to be strictly larger to accommodate it
+            if cmp(rem, t) > if exact { -1 } else { 0 } {
+                rem = sub(rem, t);
+                root = add(root, bit_value(p + FRAC_BITS));
+            }
+            p -= 1;
+        }
+        Constant {
+            negative: false,
+            mag: root,
+        }
+    }
+
+    /// Rounds the `Constant` into `N` `f64` components.
+    ///
+    /// Each component is the correctly rounded value of what's left after the previous
+    /// components are subtracted, which is the normalized form that [`Double`] and
+    /// [`Quad`] use. Components beyond the precision of the `Constant` are zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// let c: [f64; 3] = Constant::pi().components();
+    /// assert!(c[0] == std::f64::consts::PI);
+    /// assert!(c[1] == 1.2246467991473532e-16);
+    /// ```
+    ///
+    /// [`Double`]: crate::Double
+    /// [`Quad`]: crate::Quad
+    pub const fn components<const N: usize>(self) -> [f64; N] {
+        let mut out = [0.0; N];
+        let mut rest = self;
+        let mut i = 0;
+        while i < N {
+            let top = highest_bit(rest.mag);
+            if top < 0 {
+                break;
+            }
+            // Take the top 53 bits and round them to nearest, ties to even
+            let low = if top > 52 { top - 52 } else { 0 };
+            let mut m = shift(rest.mag, -low)[0];
+            if low > 0 && bit(rest.mag, low - 1) && (any_below(rest.mag, low - 1) || m & 1 == 1) {
+                m += 1;
+            }
+            let mut mag = [0; LIMBS];
+            mag[0] = m;
+            let value = Constant {
+                negative: rest.negative,
+                mag: shift(mag, low),
+            };
+
+            let c = m as f64 * pow2(low - FRAC_BITS);
+            out[i] = if rest.negative { -c } else { c };
+            rest = rest.minus(value);
+            i += 1;
+        }
+        out
+    }
+
+    /// Rounds the `Constant` into a [`Double`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// # use qd::Double;
+    /// assert!(Constant::e().to_double() == Double::E);
+    /// ```
+    ///
+    /// [`Double`]: crate::Double
+    pub const fn to_double(self) -> Double {
+        let c: [f64; 2] = self.components();
+        Double::new(c[0], c[1])
+    }
+
+    /// Rounds the `Constant` into a [`Quad`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::consts::compute::Constant;
+    /// # use qd::Quad;
+    /// assert!(Constant::e().to_quad() == Quad::E);
+    /// ```
+    ///
+    /// [`Quad`]: crate::Quad
+    pub const fn to_quad(self) -> Quad {
+        let c: [f64; 4] = self.components();
+        Quad::new(c[0], c[1], c[2], c[3])
+    }
+}
+
+// Calculates arctan(1/n) = Σ (-1)^k / ((2k + 1) n^(2k + 1)).
+const fn atan_inv(n: u64) -> [u64; LIMBS] {
+    let mut sum = [0; LIMBS];
+    let mut power = div_small(Constant::int(1).mag, n);
+    let mut k = 0;
+    while !is_zero(power) {
+        let term = div_small(power, 2 * k + 1);
+        sum = if k % 2 == 0 {
+            add(sum, term)
+        } else {
+            sub(sum, term)
+        };
+        power = div_small(power, n * n);
+        k += 1;
+    }
+    sum
+}
+
+// Calculates artanh(1/n) = Σ 1 / ((2k + 1) n^(2k + 1)).
+const fn atanh_inv(n: u64) -> [u64; LIMBS] {
+    let mut sum = [0; LIMBS];
+    let mut power = div_small(Constant::int(1).mag, n);
+    let mut k = 0;
+    while !is_zero(power) {
+        sum = add(sum, div_small(power, 2 * k + 1));
+        power = div_small(power, n * n);
+        k += 1;
+    }
+    sum
+}
+
+// Returns 2^e as an `f64`. `e` must be in the normal range.
+const fn pow2(e: i32) -> f64 {
+    f64::from_bits(((e + 1023) as u64) << 52)
+}
+
+// Determines whether a magnitude is zero.
+const fn is_zero<const N: usize>(a: [u64; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if a[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// Compares two magnitudes, returning -1, 0, or 1.
+const fn cmp<const N: usize>(a: [u64; N], b: [u64; N]) -> i32 {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return if a[i] > b[i] { 1 } else { -1 };
+        }
+    }
+    0
+}
+
+// Adds two magnitudes, panicking on overflow.
+const fn add<const N: usize>(a: [u64; N], b: [u64; N]) -> [u64; N] {
+    let mut r = [0; N];
+    let mut carry = false;
+    let mut i = 0;
+    while i < N {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        r[i] = s;
+        carry = c1 || c2;
+        i += 1;
+    }
+    assert!(!carry, "constant overflow");
+    r
+}
+
+// Subtracts magnitude `b` from magnitude `a`, which must be at least as large.
+const fn sub<const N: usize>(a: [u64; N], b: [u64; N]) -> [u64; N] {
+    let mut r = [0; N];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < N {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        r[i] = d;
+        borrow = b1 || b2;
+        i += 1;
+    }
+    r
+}
+
+// Multiplies a magnitude by a small integer, panicking on overflow.
+const fn mul_small(a: [u64; LIMBS], m: u64) -> [u64; LIMBS] {
+    let mut r = [0; LIMBS];
+    let mut carry = 0u128;
+    let mut i = 0;
+    while i < LIMBS {
+        let p = a[i] as u128 * m as u128 + carry;
+        r[i] = p as u64;
+        carry = p >> 64;
+        i += 1;
+    }
+    assert!(carry == 0, "constant overflow");
+    r
+}
+
+// Divides a magnitude by a small non-zero integer, truncating the result.
+const fn div_small(a: [u64; LIMBS], d: u64) -> [u64; LIMBS] {
+    let mut r = [0; LIMBS];
+    let mut rem = 0u128;
+    let mut i = LIMBS;
+    while i > 0 {
+        i -= 1;
+        let cur = (rem << 64) | a[i] as u128;
+        r[i] = (cur / d as u128) as u64;
+        rem = cur % d as u128;
+    }
+    r
+}
+
+// Multiplies two fixed-point magnitudes, truncating the result.
+const fn mul(a: [u64; LIMBS], b: [u64; LIMBS]) -> [u64; LIMBS] {
+    let mut p = [0u64; 2 * LIMBS];
+    let mut i = 0;
+    while i < LIMBS {
+        let mut carry = 0u128;
+        let mut j = 0;
+        while j < LIMBS {
+            let t = a[i] as u128 * b[j] as u128 + p[i + j] as u128 + carry;
+            p[i + j] = t as u64;
+            carry = t >> 64;
+            j += 1;
+        }
+        p[i + LIMBS] = carry as u64;
+        i += 1;
+    }
+    // The product has twice as many fractional limbs, so drop the extra ones
+    let mut r = [0; LIMBS];
+    let mut k = 0;
+    while k < LIMBS {
+        r[k] = p[k + LIMBS - 1];
+        k += 1;
+    }
+    assert!(p[2 * LIMBS - 1] == 0, "constant overflow");
+    r
+}
+
+// Divides two fixed-point magnitudes, truncating the result. This is binary long division
+// of `a` shifted left by the number of fractional bits.
+const fn div(a: [u64; LIMBS], b: [u64; LIMBS]) -> [u64; LIMBS] {
+    assert!(!is_zero(b), "constant division by zero");
+    let mut num = [0u64; 2 * LIMBS];
+    let mut i = 0;
+    while i < LIMBS {
+        num[i + LIMBS - 1] = a[i];
+        i += 1;
+    }
+    let mut den = [0u64; LIMBS + 1];
+    i = 0;
+    while i < LIMBS {
+        den[i] = b[i];
+        i += 1;
+    }
+
+    let mut q = [0; LIMBS];
+    let mut rem = [0u64; LIMBS + 1];
+    let mut pos = (2 * LIMBS * 64) as i32;
+    while pos > 0 {
+        pos -= 1;
+        rem = shift(rem, 1);
+        if bit(num, pos) {
+            rem[0] |= 1;
+        }
+        if cmp(rem, den) >= 0 {
+            rem = sub(rem, den);
+            assert!(pos < (LIMBS * 64) as i32, "constant overflow");
+            q = add(q, bit_value(pos));
+        }
+    }
+    q
+}
+
+// Shifts a magnitude left by `n` bits (right if `n` is negative), discarding bits that are
+// shifted out of either end.
+const fn shift<const N: usize>(a: [u64; N], n: i32) -> [u64; N] {
+    let mut r = [0; N];
+    let limbs = n.unsigned_abs() as usize / 64;
+    let bits = n.unsigned_abs() % 64;
+    let mut i = 0;
+    while i < N {
+        r[i] = if n >= 0 {
+            let lo = if i >= limbs { a[i - limbs] << bits } else { 0 };
+            let hi = if bits > 0 && i > limbs {
+                a[i - limbs - 1] >> (64 - bits)
+            } else {
+                0
+            };
+            lo | hi
+        } else {
+            let lo = if i + limbs < N {
+                a[i + limbs] >> bits
+            } else {
+                0
+            };
+            let hi = if bits > 0 && i + limbs + 1 < N {
+                a[i + limbs + 1] << (64 - bits)
+            } else {
+                0
+            };
+            lo | hi
+        };
+        i += 1;
+    }
+    r
+}
+
+// Returns a magnitude with only the given bit set.
+const fn bit_value(pos: i32) -> [u64; LIMBS] {
+    let mut r = [0; LIMBS];
+    r[pos as usize / 64] = 1 << (pos % 64);
+    r
+}
+
+// Returns the bit of a magnitude at the given position.
+const fn bit<const N: usize>(a: [u64; N], pos: i32) -> bool {
+    (a[pos as usize / 64] >> (pos % 64)) & 1 == 1
+}
+
+// Determines whether any of the bits of a magnitude below the given position are set.
+const fn any_below(a: [u64; LIMBS], pos: i32) -> bool {
+    let limb = pos as usize / 64;
+    let mut i = 0;
+    while i < limb {
+        if a[i] != 0 {
+            return true;
+        }
+        i += 1;
+    }
+    a[limb] & ((1 << (pos % 64)) - 1) != 0
+}
+
+// Returns the position of the highest set bit in a magnitude, or -1 if it's zero.
+const fn highest_bit(a: [u64; LIMBS]) -> i32 {
+    let mut i = LIMBS;
+    while i > 0 {
+        i -= 1;
+        if a[i] != 0 {
+            return (i * 64) as i32 + 63 - a[i].leading_zeros() as i32;
+        }
+    }
+    -1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pi() -> Constant {
+        Constant::pi()
+    }
+
+    fn int(n: i64) -> Constant {
+        Constant::int(n)
+    }
+
+    // Checks that a constant rounds to the expected components, which were calculated to
+    // well beyond the precision of a `Quad` elsewhere.
+    fn check(c: Constant, expected: [f64; 4]) {
+        let four: [f64; 4] = c.components();
+        let two: [f64; 2] = c.components();
+        assert_eq!(four, expected);
+        assert_eq!(two, [expected[0], expected[1]]);
+        let q = c.to_quad();
+        assert_eq!([q[0], q[1], q[2], q[3]], expected);
+        let d = c.to_double();
+        assert_eq!([d[0], d[1]], [expected[0], expected[1]]);
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn values() {
+        check(
+            pi(),
+            [
+                3.141592653589793,
+                1.2246467991473532e-16,
+                -2.9947698097183397e-33,
+                1.1124542208633653e-49,
+            ],
+        );
+        check(
+            pi().times(int(2)),
+            [
+                6.283185307179586,
+                2.4492935982947064e-16,
+                -5.989539619436679e-33,
+                2.2249084417267306e-49,
+            ],
+        );
+        check(
+            pi().over(int(3)),
+            [
+                1.0471975511965979,
+                -1.072081766451091e-16,
+                -9.982566032394464e-34,
+                -7.6956153601821505e-50,
+            ],
+        );
+        check(
+            pi().times(Constant::ratio(7, 4)),
+            [
+                5.497787143782138,
+                2.143131898507868e-16,
+                9.221286550320605e-34,
+                2.3622547205188412e-50,
+            ],
+        );
+        check(
+            pi().recip(),
+            [
+                0.3183098861837907,
+                -1.9678676675182486e-17,
+                -1.0721436282893004e-33,
+                8.053563926594112e-50,
+            ],
+        );
+        check(
+            int(2).over(pi().sqrt()),
+            [
+                1.1283791670955126,
+                1.533545961316588e-17,
+                -4.765684596693686e-34,
+                -2.007794661655263e-50,
+            ],
+        );
+        check(
+            Constant::e(),
+            [
+                2.718281828459045,
+                1.4456468917292502e-16,
+                -2.1277171080381768e-33,
+                1.5156301598412191e-49,
+            ],
+        );
+        check(
+            Constant::ln_2(),
+            [
+                0.6931471805599453,
+                2.3190468138462996e-17,
+                5.707708438416212e-34,
+                -3.5824322106018114e-50,
+            ],
+        );
+        check(
+            Constant::ln_10(),
+            [
+                2.302585092994046,
+                -2.1707562233822494e-16,
+                -9.984262454465777e-33,
+                -4.023357454450206e-49,
+            ],
+        );
+        check(
+            Constant::ln_10().over(Constant::ln_2()),
+            [
+                3.321928094887362,
+                1.661617516973592e-16,
+                1.2215512178458181e-32,
+                5.9551189702782496e-49,
+            ],
+        );
+        check(
+            Constant::ln_2().recip(),
+            [
+                1.4426950408889634,
+                2.0355273740931033e-17,
+                -1.0614659956117258e-33,
+                -1.3836716780181402e-50,
+            ],
+        );
+        check(
+            int(2).sqrt(),
+            [
+                1.4142135623730951,
+                -9.667293313452913e-17,
+                4.1386753086994136e-33,
+                4.935546991468351e-50,
+            ],
+        );
+        check(
+            int(2).sqrt().recip(),
+            [
+                0.7071067811865476,
+                -4.833646656726457e-17,
+                2.0693376543497068e-33,
+                2.4677734957341755e-50,
+            ],
+        );
+        check(
+            int(3).sqrt(),
+            [
+                1.7320508075688772,
+                1.0035084221806903e-16,
+                -1.4959542475733896e-33,
+                5.306147563296169e-50,
+            ],
+        );
+    }
+
+    #[test]
+    fn roots() {
+        assert!(int(144).sqrt().to_quad() == Quad::from(12));
+        assert!(int(0).sqrt().to_quad() == Quad::ZERO);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert!(int(3).plus(int(-5)).to_quad() == Quad::from(-2));
+        assert!(int(-3).minus(int(-5)).to_quad() == Quad::from(2));
+        assert!(int(-3).times(int(5)).to_quad() == Quad::from(-15));
+        assert!(int(-15).over(int(-5)).to_quad() == Quad::from(3));
+        assert!(Constant::ratio(-1, 3).to_quad() == Quad::from(-1) / Quad::from(3));
+        assert!(Constant::ratio(1, 10).to_double() == Double::from(1) / Double::from(10));
+    }
+
+    #[test]
+    fn components() {
+        let c: [f64; 1] = pi().components();
+        assert!(c == [std::f64::consts::PI]);
+        let c: [f64; 4] = int(1).components();
+        assert!(c == [1.0, 0.0, 0.0, 0.0]);
+        let c: [f64; 2] = int(-1).plus(Constant::ratio(1, 1 << 60)).components();
+        assert!(c == [-1.0, 2f64.powi(-60)]);
+        let c: [f64; 2] = int(0).components();
+        assert!(c == [0.0, 0.0]);
+    }
+
+    #[test]
+    fn const_context() {
+        const FRAC_PI_5: Quad = Constant::pi().over(Constant::int(5)).to_quad();
+        assert!((FRAC_PI_5 * Quad::from(5) - Quad::PI).abs() < Quad::EPSILON * Quad::from(4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn overflow() {
+        int(i64::MAX).times(int(i64::MAX));
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        pi().over(int(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_sqrt() {
+        int(-2).sqrt();
+    }
+}
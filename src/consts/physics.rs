@@ -0,0 +1,321 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Physical constants from the CODATA 2022 recommended values, as `Quad`s.
+//!
+//! Since the 2019 redefinition of the SI units, several constants (the speed of light,
+//! the Planck constant, the elementary charge, the Boltzmann constant, and the Avogadro
+//! constant) are exact by definition, as are constants calculated only from them. These
+//! are given here to the full precision of a [`Quad`]. The rest are measured values, and
+//! they're given as the exact decimal expansion of the recommended value. Their
+//! uncertainties are far larger than the precision of a [`Quad`], so they're noted in
+//! each constant's documentation.
+//!
+//! This module is only available with the `physics` feature.
+//!
+//! # Examples
+//! ```
+//! use qd::consts::physics::{PLANCK, REDUCED_PLANCK};
+//! use qd::Quad;
+//!
+//! let hbar = PLANCK / Quad::TAU;
+//! assert!((hbar - REDUCED_PLANCK).abs() < REDUCED_PLANCK * Quad::EPSILON);
+//! ```
+//!
+//! [`Quad`]: crate::Quad
+
+use crate::Quad;
+
+/// Speed of light in vacuum (*c*), in m s<sup>-1</sup>.
+///
+/// This is exact by the definition of the SI units: 299792458.
+pub const SPEED_OF_LIGHT: Quad = Quad::new(299792458.0, 0.0, 0.0, 0.0);
+
+/// Planck constant (*h*), in J Hz<sup>-1</sup>.
+///
+/// This is exact by the definition of the SI units: 6.62607015e-34.
+pub const PLANCK: Quad = Quad::new(
+    6.62607015e-34,
+    1.7027509332425372e-50,
+    -1.955841722404396e-67,
+    -6.384237611025037e-84,
+);
+
+/// Reduced Planck constant (*ħ* = *h*/2π), in J s.
+///
+/// This is exact by the definition of the SI units, but since it's irrational it's
+/// rounded to the precision of a `Quad`. Its first 40 significant digits are
+/// 1.054571817646156391262428003302280744723e-34.
+pub const REDUCED_PLANCK: Quad = Quad::new(
+    1.0545718176461565e-34,
+    -8.073714639585126e-51,
+    1.6842488207925324e-67,
+    -9.303414450705542e-85,
+);
+
+/// Elementary charge (*e*), in C.
+///
+/// This is exact by the definition of the SI units: 1.602176634e-19.
+pub const ELEMENTARY_CHARGE: Quad = Quad::new(
+    1.602176634e-19,
+    1.0624376995477963e-35,
+    3.249516508591199e-52,
+    -7.684932830054804e-69,
+);
+
+/// Boltzmann constant (*k*<sub>B</sub>), in J K<sup>-1</sup>.
+///
+/// This is exact by the definition of the SI units: 1.380649e-23.
+pub const BOLTZMANN: Quad = Quad::new(
+    1.380649e-23,
+    -9.215216055820379e-40,
+    -1.8113892650423207e-56,
+    -9.823209479892906e-73,
+);
+
+/// Avogadro constant (*N*<sub>A</sub>), in mol<sup>-1</sup>.
+///
+/// This is exact by the definition of the SI units: 6.02214076e23.
+pub const AVOGADRO: Quad = Quad::new(6.02214076e23, 12976128.0, 0.0, 0.0);
+
+/// Molar gas constant (*R*), in J mol<sup>-1</sup> K<sup>-1</sup>.
+///
+/// This is *N*<sub>A</sub>*k*<sub>B</sub>, which is exact because both
+/// factors are exact by the definition of the SI units: 8.31446261815324.
+pub const MOLAR_GAS: Quad = Quad::new(
+    8.31446261815324,
+    4.962734185392037e-16,
+    6.652905632452358e-33,
+    -2.014544074650928e-49,
+);
+
+/// Faraday constant (*F*), in C mol<sup>-1</sup>.
+///
+/// This is *N*<sub>A</sub>*e*, which is exact because both
+/// factors are exact by the definition of the SI units: 96485.3321233100184.
+pub const FARADAY: Quad = Quad::new(
+    96485.33212331001,
+    4.26013913154602e-12,
+    3.5074826885173825e-28,
+    1.8331539866270295e-44,
+);
+
+/// Newtonian gravitational constant (*G*), in m<sup>3</sup> kg<sup>-1</sup> s<sup>-2</sup>.
+///
+/// This is the CODATA 2022 recommended value, 6.67430(15)e-11, where the digits in
+/// parentheses are the standard uncertainty in the last digits.
+pub const GRAVITATIONAL: Quad = Quad::new(
+    6.6743e-11,
+    6.209987563307357e-27,
+    -2.873449426038521e-43,
+    1.801077933473442e-59,
+);
+
+/// Fine-structure constant (*α*), which is dimensionless.
+///
+/// This is the CODATA 2022 recommended value, 7.2973525643(11)e-3, where the digits in
+/// parentheses are the standard uncertainty in the last digits.
+pub const FINE_STRUCTURE: Quad = Quad::new(
+    0.0072973525643,
+    3.7861467916400215e-20,
+    8.791277342505556e-37,
+    -7.807236768368097e-53,
+);
+
+/// Rydberg constant (*R*<sub>∞</sub>), in m<sup>-1</sup>.
+///
+/// This is the CODATA 2022 recommended value, 10973731.568157(12), where the digits in
+/// parentheses are the standard uncertainty in the last digits.
+pub const RYDBERG: Quad = Quad::new(
+    10973731.568157,
+    -4.671812057495117e-10,
+    -3.0910085129881537e-26,
+    -1.2908397339967245e-42,
+);
+
+/// Vacuum magnetic permeability (*μ*<sub>0</sub>), in N A<sup>-2</sup>.
+///
+/// This is the CODATA 2022 recommended value, 1.25663706127(20)e-6, where the digits in
+/// parentheses are the standard uncertainty in the last digits.
+pub const VACUUM_PERMEABILITY: Quad = Quad::new(
+    1.25663706127e-06,
+    -5.128224128375658e-23,
+    -2.6234569140031444e-39,
+    5.3053225441285995e-56,
+);
+
+/// Vacuum electric permittivity (*ε*<sub>0</sub>), in F m<sup>-1</sup>.
+///
+/// This is the CODATA 2022 recommended value, 8.8541878188(14)e-12, where the digits in
+/// parentheses are the standard uncertainty in the last digits.
+pub const VACUUM_PERMITTIVITY: Quad = Quad::new(
+    8.8541878188e-12,
+    -4.144484891152275e-28,
+    -1.3467620328539087e-44,
+    3.089449348291068e-61,
+);
+
+/// Electron mass (*m*<sub>e</sub>), in kg.
+///
+/// This is the CODATA 2022 recommended value, 9.1093837139(28)e-31, where the digits in
+/// parentheses are the standard uncertainty in the last digits.
+pub const ELECTRON_MASS: Quad = Quad::new(
+    9.1093837139e-31,
+    1.8355547719265066e-47,
+    2.8215197911413495e-64,
+    -1.4975355972145507e-81,
+);
+
+/// Proton mass (*m*<sub>p</sub>), in kg.
+///
+/// This is the CODATA 2022 recommended value, 1.67262192595(52)e-27, where the digits in
+/// parentheses are the standard uncertainty in the last digits.
+pub const PROTON_MASS: Quad = Quad::new(
+    1.67262192595e-27,
+    8.91920469397126e-44,
+    -5.372373885758616e-61,
+    2.3205506866267123e-77,
+);
+
+/// Neutron mass (*m*<sub>n</sub>), in kg.
+///
+/// This is the CODATA 2022 recommended value, 1.67492750056(85)e-27, where the digits in
+/// parentheses are the standard uncertainty in the last digits.
+pub const NEUTRON_MASS: Quad = Quad::new(
+    1.67492750056e-27,
+    -1.0748198156317934e-43,
+    -7.843085790902114e-60,
+    3.702950541762088e-76,
+);
+
+/// Atomic mass constant (*m*<sub>u</sub>), in kg.
+///
+/// This is the CODATA 2022 recommended value, 1.66053906892(52)e-27, where the digits in
+/// parentheses are the standard uncertainty in the last digits.
+pub const ATOMIC_MASS: Quad = Quad::new(
+    1.66053906892e-27,
+    6.742165401264056e-44,
+    7.535962866330329e-61,
+    1.2606435155850579e-77,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::qd;
+
+    // Each constant should be its decimal value rounded to the precision of a `Quad`.
+    fn check(constant: Quad, value: &str) {
+        let expected: Quad = value.parse().unwrap();
+        let diff = (constant - expected).abs();
+        assert!(
+            diff <= expected.abs() * Quad::EPSILON,
+            "expected {}, got {}",
+            expected,
+            constant
+        );
+        assert!(constant.is_normalized());
+    }
+
+    #[test]
+    fn speed_of_light() {
+        check(SPEED_OF_LIGHT, "299792458");
+    }
+
+    #[test]
+    fn planck() {
+        check(PLANCK, "6.62607015e-34");
+    }
+
+    #[test]
+    fn reduced_planck() {
+        check(
+            REDUCED_PLANCK,
+            concat!(
+                "1.054571817646156391262428003302280744722",
+                "826330020413122421923471e-34"
+            ),
+        );
+    }
+
+    #[test]
+    fn elementary_charge() {
+        check(ELEMENTARY_CHARGE, "1.602176634e-19");
+    }
+
+    #[test]
+    fn boltzmann() {
+        check(BOLTZMANN, "1.380649e-23");
+    }
+
+    #[test]
+    fn avogadro() {
+        check(AVOGADRO, "6.02214076e23");
+    }
+
+    #[test]
+    fn molar_gas() {
+        check(MOLAR_GAS, "8.31446261815324");
+    }
+
+    #[test]
+    fn faraday() {
+        check(FARADAY, "96485.3321233100184");
+    }
+
+    #[test]
+    fn gravitational() {
+        check(GRAVITATIONAL, "6.67430e-11");
+    }
+
+    #[test]
+    fn fine_structure() {
+        check(FINE_STRUCTURE, "7.2973525643e-3");
+    }
+
+    #[test]
+    fn rydberg() {
+        check(RYDBERG, "10973731.568157");
+    }
+
+    #[test]
+    fn vacuum_permeability() {
+        check(VACUUM_PERMEABILITY, "1.25663706127e-6");
+    }
+
+    #[test]
+    fn vacuum_permittivity() {
+        check(VACUUM_PERMITTIVITY, "8.8541878188e-12");
+    }
+
+    #[test]
+    fn electron_mass() {
+        check(ELECTRON_MASS, "9.1093837139e-31");
+    }
+
+    #[test]
+    fn proton_mass() {
+        check(PROTON_MASS, "1.67262192595e-27");
+    }
+
+    #[test]
+    fn neutron_mass() {
+        check(NEUTRON_MASS, "1.67492750056e-27");
+    }
+
+    #[test]
+    fn atomic_mass() {
+        check(ATOMIC_MASS, "1.66053906892e-27");
+    }
+
+    #[test]
+    fn derived() {
+        check(AVOGADRO * BOLTZMANN, "8.31446261815324");
+        assert!((PLANCK / Quad::TAU - REDUCED_PLANCK).abs() < qd!(1e-97));
+        let c2 = SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+        let product = VACUUM_PERMEABILITY * VACUUM_PERMITTIVITY * c2;
+        assert!((product - Quad::ONE).abs() < qd!(1e-9));
+    }
+}
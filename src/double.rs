@@ -3,7 +3,9 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use std::array::IntoIter;
 use std::f64;
+use std::fmt::{self, Debug, Formatter};
 use std::ops::Index;
 
 #[macro_use]
@@ -15,6 +17,15 @@ mod macros {
     /// number that is not a `u128` or `i128`, and 2-tuples of any of those primitive number
     /// types.
     ///
+    /// There are two other forms:
+    ///
+    /// * `dd!(hi, lo)` takes the two components of the `Double` as `f64`s. Like [`new`], it
+    ///   uses them as they are, so they must already be normalized.
+    /// * `dd!(n; d)` divides `n` by `d`, each of which can be anything that the
+    ///   single-argument form accepts. This is ordinary `Double` division, which is accurate
+    ///   to within a few ulps but not correctly rounded, so `dd!(1; 3)` is 1/3 computed in
+    ///   `Double` precision rather than necessarily the `Double` closest to it.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
@@ -24,11 +35,74 @@ mod macros {
     /// let expected = dd!("0.70710678118654752440084436210485");
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
+    ///
+    /// assert!(dd!(3.141592653589793, 1.2246467991473532e-16) == Double::PI);
+    /// let third = dd!("0.33333333333333333333333333333333");
+    /// assert!((dd!(1; 3) - third).abs() < dd!(1e-32));
     /// ```
+    ///
+    /// [`new`]: crate::Double::new
     #[macro_export]
     macro_rules! dd {
+        ($n:expr; $d:expr) => {
+            <$crate::Double as ::std::convert::From<_>>::from($n)
+                / <$crate::Double as ::std::convert::From<_>>::from($d)
+        };
+        ($hi:expr, $lo:expr) => {
+            <$crate::Double as ::std::convert::From<_>>::from(($hi, $lo))
+        };
         ($x:expr) => {
-            $crate::Double::from($x)
+            <$crate::Double as ::std::convert::From<_>>::from($x)
+        };
+    }
+
+    /// Creates a new double-double from a literal that's checked at compile time.
+    ///
+    /// The argument is a string or a number, like the ones that [`dd!`] takes, except that
+    /// it must be a literal. It's a compile-time error if the literal isn't a valid number,
+    /// if it's too large for a `Double`, or if it has more significant digits than a
+    /// `Double` can represent (33). This makes sure that a table of constants is correct as
+    /// soon as it builds, instead of when it's first used. If rounding away the extra
+    /// digits is intended, `, truncate` can be added after the literal, which turns the
+    /// error into a warning.
+    ///
+    /// The checked literal is then converted just like [`dd!`] converts it, so the result
+    /// is the same. This macro is only available with the `macros` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, dd_lit, Double};
+    /// let x = dd_lit!("0.70710678118654752440084436210485");
+    /// assert!((x - dd!(2).sqrt().recip()).abs() < dd!(1e-32));
+    /// assert!(dd_lit!(-1.5e-10) == dd!(-1.5e-10));
+    ///
+    /// // Compiles with a warning that the extra digits are rounded away
+    /// let pi = dd_lit!("3.1415926535897932384626433832795028841971", truncate);
+    /// assert!((pi - Double::PI).abs() < dd!(1e-31));
+    /// ```
+    ///
+    /// These don't compile:
+    /// ```compile_fail
+    /// # use qd::dd_lit;
+    /// let x = dd_lit!("1.2.3");
+    /// ```
+    /// ```compile_fail
+    /// # use qd::dd_lit;
+    /// let x = dd_lit!("3.1415926535897932384626433832795028841971");
+    /// ```
+    /// ```compile_fail
+    /// # use qd::dd_lit;
+    /// let x = dd_lit!("1e400");
+    /// ```
+    ///
+    /// [`dd!`]: crate::dd
+    #[cfg(feature = "macros")]
+    #[macro_export]
+    macro_rules! dd_lit {
+        ($($arg:tt)+) => {
+            <$crate::Double as ::std::convert::From<_>>::from(
+                $crate::__macros::double_literal!($($arg)+)
+            )
         };
     }
 }
@@ -40,20 +114,33 @@ mod test_macros;
 mod aconsts; // "a" for "associated", or maybe just to make it appear first in docs
 mod add;
 mod alg;
+#[cfg(feature = "transcendental")]
+mod bessel;
+mod comb;
 mod common;
 mod comp;
+#[cfg(feature = "display")]
 mod display;
 mod div;
+#[cfg(feature = "transcendental")]
+mod expint;
 mod from;
 mod from_str;
+#[cfg(feature = "transcendental")]
 mod hyper;
 mod iter;
 mod misc;
 mod mul;
 mod neg;
+#[cfg(feature = "transcendental")]
+mod polylog;
 mod rem;
+#[cfg(feature = "transcendental")]
+mod special;
 mod sub;
+#[cfg(feature = "transcendental")]
 mod trans;
+#[cfg(feature = "transcendental")]
 mod trig;
 
 /// A 128-bit floating-point number implemented as the unevaluated sum of two 64-bit
@@ -118,6 +205,115 @@ impl Double {
     pub const fn new(a: f64, b: f64) -> Double {
         Double(a, b)
     }
+
+    /// Creates a `Double` from components that are already normalized, without checking or
+    /// renormalizing them.
+    ///
+    /// The components have to satisfy the invariant that [`is_normalized`] checks: `lo`
+    /// can be no more than half a unit in the last place of `hi`, so that `hi + lo == hi`
+    /// in `f64` arithmetic. Components like that come from tables of precomputed constants,
+    /// from other double-double libraries, or from the components of an existing `Double`.
+    /// This does the same thing as [`new`], but its name makes that assumption visible
+    /// where it's called.
+    ///
+    /// Components that aren't normalized make a `Double` that compares, classifies, and
+    /// computes incorrectly, so when in doubt, check the result with [`is_normalized`] or
+    /// repair it with [`canonicalize`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// // The components of Double::PI
+    /// let x = Double::from_parts_unchecked(3.141592653589793, 1.2246467991473532e-16);
+    /// assert!(x.is_normalized());
+    /// assert!(x == Double::PI);
+    /// ```
+    ///
+    /// [`is_normalized`]: #method.is_normalized
+    /// [`canonicalize`]: #method.canonicalize
+    /// [`new`]: #method.new
+    #[inline]
+    pub const fn from_parts_unchecked(hi: f64, lo: f64) -> Double {
+        Double(hi, lo)
+    }
+
+    /// Returns the leading component of the `Double`.
+    ///
+    /// This is the `f64` closest to the value of the `Double`, the same `f64` that
+    /// converting it with `into` produces.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.hi() == std::f64::consts::PI);
+    /// ```
+    #[inline]
+    pub const fn hi(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the trailing component of the `Double`.
+    ///
+    /// This is the difference between the value of the `Double` and its leading component,
+    /// rounded to an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.lo() == 1.2246467991473532e-16);
+    /// assert!(Double::ONE.lo() == 0.0);
+    /// ```
+    #[inline]
+    pub const fn lo(self) -> f64 {
+        self.1
+    }
+
+    /// Returns a `Double` with the leading component replaced by `hi` and the trailing
+    /// component kept.
+    ///
+    /// The result is renormalized with [`canonicalize`] if the trailing component is too
+    /// large to go with the new leading component, so it's always a valid `Double` equal
+    /// to `hi + self.lo()`, to within the precision of a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::PI.with_hi(3.0);
+    /// assert!(x.hi() == 3.0);
+    /// assert!(x.lo() == Double::PI.lo());
+    ///
+    /// // The trailing component is too large to follow a leading 1e-20
+    /// let y = Double::new(1.0, 1e-17).with_hi(1e-20);
+    /// assert!(y.is_normalized());
+    /// assert!(y == Double::new(1e-20, 0.0) + Double::new(1e-17, 0.0));
+    /// ```
+    ///
+    /// [`canonicalize`]: #method.canonicalize
+    #[inline]
+    pub fn with_hi(self, hi: f64) -> Double {
+        Double(hi, self.1).canonicalize()
+    }
+
+    /// Returns an iterator over the components of the `Double`.
+    ///
+    /// The iterator yields the first component and then the second. Iterating over the
+    /// `Double` itself does the same thing. Like [indexing], this is meant for code that
+    /// needs the components themselves, such as code that passes them to another language
+    /// or prints them for debugging.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let components: Vec<f64> = Double::PI.iter().collect();
+    /// assert_eq!(components.len(), 2);
+    /// assert_eq!(components[0], Double::PI[0]);
+    /// ```
+    ///
+    /// [indexing]: #impl-Index<usize>
+    #[inline]
+    pub fn iter(&self) -> IntoIter<f64, 2> {
+        IntoIterator::into_iter([self.0, self.1])
+    }
 }
 
 impl Index<usize> for Double {
@@ -155,6 +351,70 @@ impl Index<usize> for Double {
         }
     }
 }
+
+impl IntoIterator for Double {
+    type Item = f64;
+    type IntoIter = IntoIter<f64, 2>;
+
+    /// Returns an iterator over the components of the `Double`.
+    ///
+    /// This is the same as [`iter`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// for (i, component) in Double::PI.into_iter().enumerate() {
+    ///     assert_eq!(component, Double::PI[i]);
+    /// }
+    /// ```
+    ///
+    /// [`iter`]: #method.iter
+    #[inline]
+    fn into_iter(self) -> IntoIter<f64, 2> {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &Double {
+    type Item = f64;
+    type IntoIter = IntoIter<f64, 2>;
+
+    /// Returns an iterator over the components of the referenced `Double`.
+    ///
+    /// This is the same as [`iter`].
+    ///
+    /// [`iter`]: struct.Double.html#method.iter
+    #[inline]
+    fn into_iter(self) -> IntoIter<f64, 2> {
+        self.iter()
+    }
+}
+
+impl Debug for Double {
+    /// Formats a `Double` for display when the "`?`" formatting option is specified.
+    ///
+    /// See [`Display::fmt`](#method.fmt-1) for more information.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let alt = f.alternate();
+        let mut str = String::from("Double(");
+        if alt {
+            str.push_str("\n    ");
+        }
+        str.push_str(format!("{:e},", self.0).as_str());
+        if alt {
+            str.push_str("\n    ");
+        } else {
+            str.push(' ');
+        }
+        str.push_str(format!("{:e}", self.1).as_str());
+        if alt {
+            str.push('\n');
+        }
+        str.push(')');
+        write!(f, "{}", str)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +429,37 @@ mod tests {
         index_zero: Double::PI[0], Double::PI.0;
         index_one: Double::PI[1], Double::PI.1;
     );
+
+    test_all_exact!(
+        macro_expr: dd!(2.0 * 3.0 + 1.0), dd!(7);
+        macro_components: dd!(Double::PI[0], Double::PI[1]), Double::PI;
+        macro_ratio: dd!(1; 3), Double::ONE / Double::from(3);
+        macro_ratio_exprs: dd!(2 + 5; "4"), dd!(1.75);
+    );
+
+    test!(iter_components: {
+        let v: Vec<f64> = Double::PI.iter().collect();
+        assert_eq!(v, vec![Double::PI.0, Double::PI.1]);
+        let w: Vec<f64> = Double::PI.into_iter().collect();
+        assert_eq!(w, v);
+        let x: Vec<f64> = (&Double::PI).into_iter().collect();
+        assert_eq!(x, v);
+    });
+
+    test!(accessors: {
+        let x = Double::from_parts_unchecked(Double::PI.hi(), Double::PI.lo());
+        assert_eq!(x, Double::PI);
+        assert_eq!(x.hi(), x[0]);
+        assert_eq!(x.lo(), x[1]);
+        assert_eq!(Double::NAN.lo(), 0.0);
+    });
+    test!(with_hi: {
+        let x = Double::PI.with_hi(-3.0);
+        assert_eq!((x.hi(), x.lo()), (-3.0, Double::PI.lo()));
+        let y = Double::new(1.0, 1e-17).with_hi(-1e-20);
+        assert!(y.is_normalized());
+        assert_eq!(y, Double::new(-1e-20, 0.0) + Double::new(1e-17, 0.0));
+        assert!(Double::PI.with_hi(f64::NAN).is_nan());
+        assert_eq!(Double::PI.with_hi(f64::INFINITY), Double::INFINITY);
+    });
 }
@@ -3,6 +3,7 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::consts::compute::Constant;
 use crate::double::Double;
 use std::f64;
 
@@ -28,9 +29,33 @@ impl Double {
     pub const MIN: Double = Double(-1.7976931348623157e308, -9.979201547673598e291);
 
     /// Smallest positive normal `Double` value.
+    ///
+    /// This is the same as the smallest positive normal `f64`. `Double`s this small can be
+    /// represented, but their second component is subnormal or zero, so they have less
+    /// precision than [`MANTISSA_DIGITS`]. Full precision is only available down to
+    /// [`MIN_FULL_PRECISION`].
+    ///
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    /// [`MIN_FULL_PRECISION`]: #associatedconstant.MIN_FULL_PRECISION
     pub const MIN_POSITIVE: Double = Double(2.2250738585072014e-308, 0e0);
 
+    /// Smallest positive `Double` value that has the full precision of [`MANTISSA_DIGITS`],
+    /// 2<sup>-969</sup>.
+    ///
+    /// Below this, the second component would have to be smaller than the smallest normal
+    /// `f64`, so it loses bits and the effective precision of the `Double` degrades
+    /// smoothly until, at [`MIN_POSITIVE`], it's no better than an `f64`. The precision of
+    /// a particular value can be found with [`effective_precision`].
+    ///
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    /// [`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+    /// [`effective_precision`]: #method.effective_precision
+    pub const MIN_FULL_PRECISION: Double = Double(2.004168360008973e-292, 0e0);
+
     /// Largest finite `Double` value.
+    ///
+    /// The first component is the largest finite `f64`, and the second is the largest value
+    /// that can be added to it without the sum rounding up to infinity.
     pub const MAX: Double = Double(1.7976931348623157e308, 9.979201547673598e291);
 
     /// One greater than the minimum possible normal power of 2 exponent.
@@ -45,6 +70,24 @@ impl Double {
     /// Maximum possible power of 10 exponent.
     pub const MAX_10_EXP: i32 = 308;
 
+    /// The largest argument for which [`exp`] doesn't overflow. Larger arguments make
+    /// [`exp`] return [`INFINITY`].
+    ///
+    /// This is the natural logarithm of the largest `f64`. [`MAX`] is a tiny bit larger
+    /// than that, but a result in between would have a first component that rounds up to
+    /// infinity.
+    ///
+    /// [`exp`]: #method.exp
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub const MAX_EXP_ARG: Double = Double(709.782712893384, 2.3636017071323592e-14);
+
+    /// The smallest argument for which [`exp`] doesn't underflow, ln(2<sup>-1075</sup>).
+    /// Smaller arguments make [`exp`] return zero.
+    ///
+    /// [`exp`]: #method.exp
+    pub const MIN_EXP_ARG: Double = Double(-745.1332191019412, 1.4483164125345337e-14);
+
     /// Not a Number (NaN).
     pub const NAN: Double = Double(f64::NAN, 0.0);
 
@@ -67,74 +110,100 @@ impl Double {
     pub const NEG_ONE: Double = Double(-1.0, 0.0);
 
     /// Archimedes' constant (π)
-    pub const PI: Double = Double(3.141592653589793e0, 1.2246467991473532e-16);
+    pub const PI: Double = Constant::pi().to_double();
 
     /// The full circle constant (τ), or 2π
-    pub const TAU: Double = Double(6.283185307179586e0, 2.449293598294704e-16);
+    pub const TAU: Double = Constant::pi().times(Constant::int(2)).to_double();
 
     /// π/2
-    pub const FRAC_PI_2: Double = Double(1.5707963267948966e0, 6.12323399573676e-17);
+    pub const FRAC_PI_2: Double = Constant::pi().over(Constant::int(2)).to_double();
 
     /// π/3
-    pub const FRAC_PI_3: Double = Double(1.0471975511965979e0, -1.0720817664510912e-16);
+    pub const FRAC_PI_3: Double = Constant::pi().over(Constant::int(3)).to_double();
 
     /// π/4
-    pub const FRAC_PI_4: Double = Double(7.853981633974483e-1, 3.0616169978683836e-17);
+    pub const FRAC_PI_4: Double = Constant::pi().over(Constant::int(4)).to_double();
 
     /// π/6
-    pub const FRAC_PI_6: Double = Double(5.235987755982989e-1, -5.360408832255455e-17);
+    pub const FRAC_PI_6: Double = Constant::pi().over(Constant::int(6)).to_double();
 
     /// π/8
-    pub const FRAC_PI_8: Double = Double(3.9269908169872414e-1, 1.5308084989341906e-17);
+    pub const FRAC_PI_8: Double = Constant::pi().over(Constant::int(8)).to_double();
 
     /// π/16
-    pub const FRAC_PI_16: Double = Double(1.9634954084936207e-1, 7.654042494670953e-18);
+    pub const FRAC_PI_16: Double = Constant::pi().over(Constant::int(16)).to_double();
 
     /// 3π/2
-    pub const FRAC_3_PI_2: Double = Double(4.71238898038469e0, 1.8369701987210292e-16);
+    pub const FRAC_3_PI_2: Double = Constant::pi().times(Constant::ratio(3, 2)).to_double();
 
     /// 3π/4
-    pub const FRAC_3_PI_4: Double = Double(2.356194490192345e0, 9.184850993605146e-17);
+    pub const FRAC_3_PI_4: Double = Constant::pi().times(Constant::ratio(3, 4)).to_double();
 
     /// 5π/4
-    pub const FRAC_5_PI_4: Double = Double(3.9269908169872414e0, 1.5308084989341908e-16);
+    pub const FRAC_5_PI_4: Double = Constant::pi().times(Constant::ratio(5, 4)).to_double();
 
     /// 7π/4
-    pub const FRAC_7_PI_4: Double = Double(5.497787143782138e0, 2.143131898507869e-16);
+    pub const FRAC_7_PI_4: Double = Constant::pi().times(Constant::ratio(7, 4)).to_double();
 
     /// 1/π
-    pub const FRAC_1_PI: Double = Double(3.183098861837907e-1, -1.967867667518248e-17);
+    pub const FRAC_1_PI: Double = Constant::pi().recip().to_double();
 
     /// 2/π
-    pub const FRAC_2_PI: Double = Double(6.366197723675814e-1, -3.935735335036497e-17);
+    pub const FRAC_2_PI: Double = Constant::int(2).over(Constant::pi()).to_double();
 
     /// 2/√π
-    pub const FRAC_2_SQRT_PI: Double = Double(1.1283791670955126e0, 1.533545961316588e-17);
+    pub const FRAC_2_SQRT_PI: Double = Constant::int(2).over(Constant::pi().sqrt()).to_double();
+
+    /// 1/√π
+    pub const FRAC_1_SQRT_PI: Double = Constant::pi().sqrt().recip().to_double();
+
+    /// 1/√(2π)
+    pub const FRAC_1_SQRT_2PI: Double = Constant::pi()
+        .times(Constant::int(2))
+        .sqrt()
+        .recip()
+        .to_double();
 
     /// √2
-    pub const SQRT_2: Double = Double(1.4142135623730951e0, -9.667293313452915e-17);
+    pub const SQRT_2: Double = Constant::int(2).sqrt().to_double();
 
     /// 1/√2
-    pub const FRAC_1_SQRT_2: Double = Double(7.071067811865476e-1, -4.833646656726457e-17);
+    pub const FRAC_1_SQRT_2: Double = Constant::int(2).sqrt().recip().to_double();
+
+    /// √3
+    pub const SQRT_3: Double = Constant::int(3).sqrt().to_double();
+
+    /// 1/√3
+    pub const FRAC_1_SQRT_3: Double = Constant::int(3).sqrt().recip().to_double();
 
     /// Euler's number (*e*)
-    pub const E: Double = Double(2.718281828459045e0, 1.4456468917292497e-16);
+    pub const E: Double = Constant::e().to_double();
+
+    /// The golden ratio (φ)
+    pub const PHI: Double = Constant::int(5)
+        .sqrt()
+        .plus(Constant::int(1))
+        .over(Constant::int(2))
+        .to_double();
+
+    /// The Euler-Mascheroni constant (γ)
+    pub const EGAMMA: Double = Double(5.772156649015329e-1, -4.942915152430645e-18);
 
     /// log<sub>2</sub> 10
-    pub const LOG2_10: Double = Double(3.321928094887362e0, 1.6616175169735918e-16);
+    pub const LOG2_10: Double = Constant::ln_10().over(Constant::ln_2()).to_double();
 
     /// log<sub>2</sub> *e*
-    pub const LOG2_E: Double = Double(1.4426950408889634e0, 2.035527374093102e-17);
+    pub const LOG2_E: Double = Constant::ln_2().recip().to_double();
 
     /// log<sub>10</sub> 2
-    pub const LOG10_2: Double = Double(3.010299956639812e-1, -2.8037281277851654e-18);
+    pub const LOG10_2: Double = Constant::ln_2().over(Constant::ln_10()).to_double();
 
     /// log<sub>10</sub> *e*
-    pub const LOG10_E: Double = Double(4.342944819032518e-1, 1.0983196502167654e-17);
+    pub const LOG10_E: Double = Constant::ln_10().recip().to_double();
 
     /// log<sub>*e*</sub> 2
-    pub const LN_2: Double = Double(6.931471805599453e-1, 2.319046813846301e-17);
+    pub const LN_2: Double = Constant::ln_2().to_double();
 
     /// log<sub>*e*</sub> 10
-    pub const LN_10: Double = Double(2.302585092994046e0, -2.1707562233822496e-16);
+    pub const LN_10: Double = Constant::ln_10().to_double();
 }
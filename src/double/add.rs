@@ -25,16 +25,12 @@ impl Add for Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    #[inline]
     fn add(self, other: Double) -> Double {
-        match self.pre_add(&other) {
-            Some(r) => r,
-            None => {
-                let (s0, e0) = p::two_sum(self.0, other.0);
-                let (s1, e1) = p::two_sum(self.1, other.1);
-                let (s2, e2) = p::quick_two_sum(s0, s1 + e0);
-                let (a, b) = u::renorm2(s2, e1 + e2);
-                Double(a, b)
-            }
+        if cfg!(feature = "fast_arith") {
+            self.add_fast(other)
+        } else {
+            self.add_accurate(other)
         }
     }
 }
@@ -154,7 +150,126 @@ impl AddAssign<&Double> for Double {
     }
 }
 
+impl AddAssign<f64> for Double {
+    /// Adds an `f64` to this `Double`, modifying this one to equal the result.
+    ///
+    /// This implements the `+=` operator between a `Double` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = Double::PI;
+    /// x += 2.0;
+    /// let expected = dd!("5.141592653589793238462643383279");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: f64) {
+        let r = self.add(Double(other, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+    }
+}
+
 impl Double {
+    /// Adds this `Double` to another using the accurate (IEEE-style) addition algorithm.
+    ///
+    /// This is the algorithm used by the `+` operator, unless the `fast_arith` feature is
+    /// enabled. It adds the high and low components separately, each with its own error
+    /// term, which keeps the relative error of the result within about 2 ULPs even when the
+    /// two numbers nearly cancel each other out.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::E.add_accurate(Double::PI);
+    /// let expected = dd!("5.859874482048838473822930854632");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    pub fn add_accurate(self, other: Double) -> Double {
+        match self.pre_add(&other) {
+            Some(r) => r,
+            None => {
+                let (s0, e0) = p::two_sum(self.0, other.0);
+                let (s1, e1) = p::two_sum(self.1, other.1);
+                let (s2, e2) = p::quick_two_sum(s0, s1 + e0);
+                let (a, b) = u::renorm2(s2, e1 + e2);
+                Double(a, b)
+            }
+        }
+    }
+
+    /// Adds this `Double` to another using the fast ("sloppy") addition algorithm.
+    ///
+    /// This skips computing the error of adding the low components together, so it takes
+    /// roughly half the floating-point operations of [`add_accurate`]. The result is just
+    /// as accurate when the two numbers have the same sign, but when they have opposite
+    /// signs and nearly cancel, the relative error can grow well beyond 2 ULPs. This is a
+    /// good choice for sums of values that are known to have the same sign, like many
+    /// statistical accumulations.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::E.add_fast(Double::PI);
+    /// let expected = dd!("5.859874482048838473822930854632");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`add_accurate`]: #method.add_accurate
+    pub fn add_fast(self, other: Double) -> Double {
+        match self.pre_add(&other) {
+            Some(r) => r,
+            None => {
+                let (s, e) = p::two_sum(self.0, other.0);
+                let (a, b) = u::renorm2(s, e + self.1 + other.1);
+                Double(a, b)
+            }
+        }
+    }
+
+    /// Adds this `Double` to another, returning both the sum and the rounding error of the
+    /// addition.
+    ///
+    /// The sum is the same one that the `+` operator produces. The error is the difference
+    /// between the exact sum and the rounded one, itself rounded to a `Double`, so that the
+    /// two together represent the exact sum to about twice the precision of a `Double`.
+    /// This is the double-double counterpart of the [`two_sum`] error-free transformation,
+    /// and it's what compensated algorithms (like compensated summation) are built from.
+    ///
+    /// If the sum is infinite or `NaN`, the error is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::PI;
+    /// let y = Double::ONE.ldexp(-130);
+    /// let (s, e) = x.add_with_err(y);
+    ///
+    /// // The sum is too wide to fit, so `y` ends up entirely in the error
+    /// assert!(s == x);
+    /// assert!(e == y);
+    /// ```
+    ///
+    /// [`two_sum`]: crate::eft::two_sum
+    pub fn add_with_err(self, other: Double) -> (Double, Double) {
+        let r = self + other;
+        if r.is_finite() {
+            let mut terms = [self.1, other.1, -r.1, self.0, other.0, -r.0];
+            let (a, b) = u::distill2(&mut terms);
+            (r, Double(a, b))
+        } else {
+            (r, Double::ZERO)
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -198,9 +313,34 @@ impl Double {
     }
 }
 
+impl Double {
+    /// Adds another `Double` to this one, returning `None` if the result is not finite.
+    ///
+    /// This mirrors the `checked_add` methods of the primitive integer types. The result is
+    /// `None` if the sum overflows to infinity or the result is `NaN`; this is always the
+    /// case when either argument is already infinite or `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.checked_add(Double::E).is_some());
+    /// assert!(Double::MAX.checked_add(Double::MAX).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_add(self, other: Double) -> Option<Double> {
+        let r = self + other;
+        if r.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Quad;
 
     // add tests
     test_all_near!(
@@ -292,6 +432,36 @@ mod tests {
             Double::NAN + Double::NAN;
     );
 
+    // add_accurate/add_fast tests
+    test_all_near!(
+        accurate_num_num:
+            dd!("5.8598744820488384738229308546321643"),
+            Double::PI.add_accurate(Double::E);
+        accurate_num_neg_num:
+            dd!("0.42331082513074800310235591192684125"),
+            Double::PI.add_accurate(-Double::E);
+        fast_num_num:
+            dd!("5.8598744820488384738229308546321643"),
+            Double::PI.add_fast(Double::E);
+        fast_num_neg_num:
+            dd!("0.42331082513074800310235591192684125"),
+            Double::PI.add_fast(-Double::E);
+        fast_num_small:
+            dd!("3.1415926535897932384626433832805043"),
+            Double::PI.add_fast(dd!("1e-30"));
+    );
+    test_all_exact!(
+        fast_inf_num:
+            Double::INFINITY,
+            Double::INFINITY.add_fast(Double::ONE);
+        fast_inf_neg_inf:
+            Double::NAN,
+            Double::INFINITY.add_fast(Double::NEG_INFINITY);
+        fast_nan_num:
+            Double::NAN,
+            Double::NAN.add_fast(Double::ONE);
+    );
+
     // Assign tests. Assign code delegates to add code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -305,5 +475,64 @@ mod tests {
             b += &Double::E;
             near!(dd!("5.8598744820488384738229308546322"), b);
         }
+        assign_f64: {
+            let mut c = Double::PI;
+            c += 2.0;
+            near!(dd!("5.1415926535897932384626433832795028"), c);
+        }
+    );
+
+    // add_with_err tests
+    test_all!(
+        add_with_err_split: {
+            let x = Double(1.0, 2f64.powi(-60));
+            let y = Double(2f64.powi(-120), 2f64.powi(-180));
+            let (s, e) = x.add_with_err(y);
+            exact!(x, s);
+            exact!(y, e);
+        }
+        add_with_err_no_err: {
+            let (s, e) = dd!(1).add_with_err(dd!(2));
+            exact!(dd!(3), s);
+            exact!(Double::ZERO, e);
+        }
+        add_with_err_cancel: {
+            let (s, e) = Double::PI.add_with_err(-Double::PI);
+            exact!(Double::ZERO, s);
+            exact!(Double::ZERO, e);
+        }
+        add_with_err_pi_e: {
+            let (s, e) = Double::PI.add_with_err(Double::E);
+            exact!(Double::PI + Double::E, s);
+            assert!(e.abs() < s * Double::EPSILON);
+            let sum = Quad::from((s[0], s[1])) + Quad::from((e[0], e[1]));
+            let pi = Quad::from((Double::PI[0], Double::PI[1]));
+            let euler = Quad::from((Double::E[0], Double::E[1]));
+            let expected = pi + euler;
+            assert!((sum - expected).abs() < Quad::from(1e-60));
+        }
+        add_with_err_inf: {
+            let (s, e) = Double::INFINITY.add_with_err(Double::ONE);
+            exact!(Double::INFINITY, s);
+            exact!(Double::ZERO, e);
+        }
+        add_with_err_overflow: {
+            let (s, e) = Double::MAX.add_with_err(Double::MAX);
+            exact!(Double::MAX + Double::MAX, s);
+            exact!(Double::ZERO, e);
+        }
+        add_with_err_nan: {
+            let (s, e) = Double::NAN.add_with_err(Double::ONE);
+            exact!(Double::NAN, s);
+            exact!(Double::ZERO, e);
+        }
+    );
+
+    // checked_add tests
+    test_all_assert!(
+        checked_add_num: Double::PI.checked_add(Double::E) == Some(Double::PI + Double::E);
+        checked_add_nan: Double::PI.checked_add(Double::NAN).is_none();
+        checked_add_inf: Double::INFINITY.checked_add(Double::PI).is_none();
+        checked_add_overflow: Double::MAX.checked_add(Double::MAX).is_none();
     );
 }
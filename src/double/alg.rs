@@ -30,6 +30,73 @@ impl Double {
         Double(self.0 * factor, self.1 * factor)
     }
 
+    /// Multiplies the `Double` by `k`, which must be a power of two.
+    ///
+    /// Multiplying by a power of two only changes the exponents of the components, so this
+    /// just multiplies each component by `k` instead of running the full multiplication
+    /// algorithm. The result is exact unless it overflows or some of its components become
+    /// subnormal. This is useful when the scale factor is already available as an `f64`;
+    /// [`scale_b`] takes an exponent instead.
+    ///
+    /// If `k` is not a power of two, the result is neither exact nor necessarily
+    /// normalized. Use ordinary multiplication for those values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3).mul_pwr2(0.25) == dd!(0.75));
+    /// assert!(Double::PI.mul_pwr2(2.0) == Double::PI.ldexp(1));
+    /// ```
+    ///
+    /// [`scale_b`]: #method.scale_b
+    #[inline]
+    pub fn mul_pwr2(self, k: f64) -> Double {
+        Double(self.0 * k, self.1 * k)
+    }
+
+    /// Calculates x · 2<sup>n</sup>, where *x* is the `Double` and *n* is an integer,
+    /// exactly and over the whole range of exponents.
+    ///
+    /// This is like [`ldexp`], except that [`ldexp`] computes 2<sup>n</sup> as an `f64`,
+    /// which overflows or underflows when *n* is outside of the range of normal `f64`
+    /// exponents (-1022 to 1023). This function applies larger scales in several steps,
+    /// so, for example, scaling by 2<sup>-2000</sup> works as expected. Like C's `scalbn`,
+    /// the result is only rounded if it ends up subnormal, and it saturates to zero or
+    /// infinity if it's out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(5).scale_b(3) == dd!(40));
+    ///
+    /// let x = dd!(3).scale_b(1000);
+    /// assert!(x.scale_b(-2000) == dd!(3).scale_b(-1000));
+    /// // `ldexp` can't represent 2^-2000, so its result underflows
+    /// assert!(x.ldexp(-2000) == Double::ZERO);
+    ///
+    /// // The smallest subnormal number, 2^-1074
+    /// assert!(Double::ONE.scale_b(-1074)[0] == f64::from_bits(1));
+    /// ```
+    ///
+    /// [`ldexp`]: #method.ldexp
+    pub fn scale_b(self, n: i32) -> Double {
+        // Every finite nonzero `Double` overflows or underflows by the time it's scaled by
+        // 2^2100 or 2^-2100, so there's no need to go any further than that. When scaling
+        // down, the partial step is applied first so that only the last step can produce
+        // subnormal (and therefore rounded) components.
+        let n = n.clamp(-2100, 2100);
+        let (step, count) = if n < 0 {
+            (-1022, n / -1022)
+        } else {
+            (1023, n / 1023)
+        };
+        let mut r = self.mul_pwr2(2f64.powi(n - step * count));
+        for _ in 0..count {
+            r = r.mul_pwr2(2f64.powi(step));
+        }
+        r
+    }
+
     /// Calculates the square of the `Double`.
     ///
     /// This method takes advantage of optimizations in multiplication that are available
@@ -86,6 +153,13 @@ impl Double {
 
     /// Calculates the *n*th root of the `Double`.
     ///
+    /// Odd roots of negative numbers are defined and negative, while even roots of negative
+    /// numbers are [`NAN`]. A negative `n` produces the reciprocal of the |`n`|th root, so
+    /// `x.nroot(-3)` is 1 / ∛*x*. The 0th root of any number is [`NAN`].
+    ///
+    /// This is both faster and more accurate than raising the number to a fractional power
+    /// with [`powf`].
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -95,6 +169,9 @@ impl Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`powf`]: #method.powf
     pub fn nroot(self, n: i32) -> Double {
         match self.pre_nroot(n) {
             Some(r) => r,
@@ -112,15 +189,26 @@ impl Double {
                 // This converges quadratically, which is pretty fast. We can then find
                 // a^(1/n) by taking the reciprocal.
 
-                let r = self.abs();
-                // a^(-1/n) = exp(-ln(a) / n)
-                let mut x = Double::from((-(r.0.ln()) / n as f64).exp());
+                // The argument is first scaled by a power of two that is a multiple of n
+                // (chosen to bring it close to 1), so that x^n can neither overflow nor
+                // underflow and so that the `f64` seed from `powf` is as accurate as it can
+                // be. Since a^(1/n) = (a * 2^(-kn))^(1/n) * 2^k, the scaling is undone
+                // exactly at the end. Subnormal arguments take a second pass, because the
+                // exponent reported for them is that of the smallest normal number.
+                let mut r = self.abs();
+                let mut k = 0;
+                for _ in 0..2 {
+                    let j = u::exponent(r.0) / n;
+                    r = r.ldexp(-j * n);
+                    k += j;
+                }
+                let mut x = Double::from(r.0.powf(-1.0 / n as f64));
 
-                x += x * (Double::ONE - r * x.powi(n)) / Double(n.into(), 0.0);
+                x += x * (Double::ONE - r * x.powi(n.into())) / Double(n.into(), 0.0);
                 if self.is_sign_negative() {
                     x = -x;
                 }
-                x.recip()
+                x.recip().ldexp(k)
             }
         }
     }
@@ -152,36 +240,85 @@ impl Double {
     /// * `x.powi(n)` is ±0 for `x` = ±0 and any odd positive `n`
     /// * `x.powi(n)` is +0 for `x` = ±0 and any even positive `n`
     ///
+    /// If the result is too large in magnitude to be represented, it is ±∞, and if it is
+    /// too small, it is ±0 (with the sign in both cases being negative only for negative
+    /// `x` and odd `n`). The computation stops as soon as this is known, so even very large
+    /// exponents are cheap in that case. A result in the subnormal range is rounded to a
+    /// subnormal number, as an `f64` operation would do, even when `x` is large and `n` is
+    /// negative.
+    ///
     /// # Examples
     /// ```
-    /// # use qd::dd;
+    /// # use qd::{dd, Double};
     /// let x = dd!(3);
     /// assert!(x.powi(3) == dd!(27));
+    ///
+    /// assert!(dd!(10).powi(400) == Double::INFINITY);
+    /// assert!(dd!(-10).powi(-401) == Double::NEG_ZERO);
     /// ```
-    pub fn powi(self, n: i32) -> Double {
+    pub fn powi(self, n: i64) -> Double {
         match self.pre_powi(n) {
             Some(r) => r,
             None => {
-                let mut r = self;
-                let mut s = Double::ONE;
-                let mut i = n.abs();
-
-                if i > 1 {
-                    while i > 0 {
-                        if i % 2 == 1 {
-                            s *= r;
-                        }
-                        i /= 2;
-                        if i > 0 {
-                            r = r.sqr();
-                        }
+                // Strategy: exponentiation by squaring on the absolute value, with the sign
+                // applied at the end. Every intermediate is a full (renormalized) product,
+                // so no precision is lost beyond the usual rounding of each operation.
+                //
+                // The running square and product are kept between 2^-300 and 2^300, with
+                // the powers of two that are taken out of them (which is exact) counted
+                // separately. That way no intermediate overflows or underflows, even when
+                // the result itself is close to the ends of the range or, with a negative
+                // exponent, is the reciprocal of a number that's out of range. The powers of
+                // two are put back at the end, and that's the only place where the result
+                // can round to a subnormal number, to zero, or to infinity.
+                //
+                // Once the square is further than 2^4200 from 1, the result is out of range
+                // no matter what's left to do, since every remaining step only moves it
+                // further away. The loop stops there, so even very large exponents are
+                // cheap in that case.
+                let (mut r, mut re) = rescale(self.abs(), 0);
+                let (mut s, mut se) = (Double::ONE, 0);
+                let mut i = n.unsigned_abs();
+                let mut out_of_range = false;
+
+                loop {
+                    if i & 1 == 1 {
+                        let (m, e) = rescale(s * r, se + re);
+                        s = m;
+                        se = e;
+                    }
+                    i >>= 1;
+                    if i == 0 {
+                        break;
+                    }
+                    let (m, e) = rescale(r.sqr(), 2 * re);
+                    r = m;
+                    re = e;
+                    if re.abs() > 4200 {
+                        out_of_range = true;
+                        break;
                     }
-                } else {
-                    s = r;
                 }
 
-                if n < 0 {
-                    s.recip()
+                // A negative exponent swaps the roles of overflow and underflow
+                let s = if out_of_range {
+                    if (re > 0) == (n > 0) {
+                        Double::INFINITY
+                    } else {
+                        Double::ZERO
+                    }
+                } else {
+                    let (s, se) = if n < 0 { (s.recip(), -se) } else { (s, se) };
+                    let s = s.scale_b(se.clamp(-5000, 5000) as i32);
+                    if s.is_finite() {
+                        s
+                    } else {
+                        Double::INFINITY
+                    }
+                };
+
+                if self.is_sign_negative() && n % 2 != 0 {
+                    -s
                 } else {
                     s
                 }
@@ -214,6 +351,7 @@ impl Double {
     /// ```
     ///
     /// [`NAN`]: #associatedconstant.NAN
+    #[cfg(feature = "transcendental")]
     #[inline]
     pub fn powf(self, n: Double) -> Double {
         match self.pre_powf(&n) {
@@ -222,6 +360,75 @@ impl Double {
         }
     }
 
+    /// Calculates 1 plus the `Double`, raised to a `Double` power.
+    ///
+    /// When x is tiny, forming 1 + x before raising it to a power rounds away most of the
+    /// digits of x, and those are exactly the digits that the result depends on. This
+    /// function calculates *e*<sup>n ln(1 + x)</sup> using [`ln_1p`] instead, so that x
+    /// keeps its full precision however small it is.
+    ///
+    /// The base 1 + x must not be negative, so any argument less than -1 produces
+    /// [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1e-20).pow1p(dd!(1e10));
+    /// let expected = dd!("1.0000000001000000000049999999997");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`NAN`]: #associatedconstant.NAN
+    #[cfg(feature = "transcendental")]
+    pub fn pow1p(self, n: Double) -> Double {
+        match self.pre_pow1p(&n) {
+            Some(r) => r,
+            None => (n * self.ln_1p()).exp(),
+        }
+    }
+
+    /// Calculates the `Double` raised to a `Double` power, minus 1.
+    ///
+    /// When x<sup>n</sup> is close to 1, subtracting 1 from the result of [`powf`] cancels
+    /// away most of its digits. This function calculates *e*<sup>n ln x</sup> - 1 with
+    /// [`exp_m1`] instead, taking the logarithm with [`ln_1p`] when x is close to 1, so the
+    /// result is accurate to full precision even when it's tiny.
+    ///
+    /// As with [`powf`], a negative `Double` produces [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(2).powm1(dd!(0.5));
+    /// let expected = dd!("0.41421356237309504880168872420970");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`powf`]: #method.powf
+    /// [`exp_m1`]: #method.exp_m1
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`NAN`]: #associatedconstant.NAN
+    #[cfg(feature = "transcendental")]
+    pub fn powm1(self, n: Double) -> Double {
+        match self.pre_powm1(&n) {
+            Some(r) => r,
+            None => {
+                // Subtracting 1 is exact for anything between 1/2 and 2
+                let ln = if self.0 >= 0.5 && self.0 <= 2.0 {
+                    (self - Double::ONE).ln_1p()
+                } else {
+                    self.ln()
+                };
+                (n * ln).exp_m1()
+            }
+        }
+    }
+
     /// Calculates the reciprocal of the `Double`.
     ///
     /// # Examples
@@ -238,6 +445,165 @@ impl Double {
         Double::ONE / self
     }
 
+    /// Calculates the length of the hypotenuse of a right-angle triangle given legs of
+    /// length `self` and `other`, i.e., √(x<sup>2</sup> + y<sup>2</sup>).
+    ///
+    /// Both arguments are scaled by a power of two before they are squared, so the
+    /// intermediate squares can neither overflow nor underflow unless the result itself
+    /// does. As with `f64`'s `hypot`, the result is [`INFINITY`] if either argument is
+    /// infinite, even if the other is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI.hypot(Double::E);
+    /// let expected = dd!("4.1543544023133135729481214666791");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// // Squaring 1e200 would overflow, but the hypotenuse is representable
+    /// let y = dd!("1e200").hypot(dd!("1e200"));
+    /// let expected = dd!("1.4142135623730950488016887242097e200");
+    /// assert!(((y - expected) / expected).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn hypot(self, other: Double) -> Double {
+        match self.pre_hypot(&other) {
+            Some(r) => r,
+            None => {
+                let a = self.abs();
+                let b = other.abs();
+                let k = u::exponent(if a > b { a.0 } else { b.0 });
+                let r = (a.ldexp(-k).sqr() + b.ldexp(-k).sqr()).sqrt().ldexp(k);
+                if r.is_infinite() {
+                    Double::INFINITY
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Calculates the arithmetic-geometric mean of the `Double` and another `Double`.
+    ///
+    /// The arithmetic and geometric means of the two numbers are repeatedly taken until
+    /// they agree to full precision. Convergence is quadratic, so only a handful of
+    /// iterations are needed unless the arguments differ by many orders of magnitude. The
+    /// geometric mean is taken as the product of square roots so that the calculation
+    /// can't overflow or underflow.
+    ///
+    /// The domain of this function is [0, ∞) for both arguments. If either argument is
+    /// negative, the result is [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // The reciprocal of Gauss's constant
+    /// let x = Double::ONE.agm(Double::SQRT_2);
+    /// let expected = dd!("1.1981402347355922074399224922803");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn agm(self, other: Double) -> Double {
+        match self.pre_agm(&other) {
+            Some(r) => r,
+            None => {
+                let mut a = self;
+                let mut b = other;
+                for _ in 0..64 {
+                    if (a - b).abs() <= Double::EPSILON * a {
+                        break;
+                    }
+                    let mean = c::mul_pwr2(a, 0.5) + c::mul_pwr2(b, 0.5);
+                    b = a.sqrt() * b.sqrt();
+                    a = mean;
+                }
+                c::mul_pwr2(a, 0.5) + c::mul_pwr2(b, 0.5)
+            }
+        }
+    }
+
+    /// Calculates the sum of the squares of a slice of `Double`s.
+    ///
+    /// Like [`hypot`], this scales the values by a power of two before they are squared, so
+    /// the result only overflows (or underflows) if the sum itself is too large (or too
+    /// small) to represent. The result is [`INFINITY`] if any of the values are infinite,
+    /// [`NAN`] if any of them are `NaN` and none are infinite, and zero if the slice is
+    /// empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::sum_of_squares(&[dd!(1), dd!(2), dd!(3)]);
+    /// assert!(x == dd!(14));
+    ///
+    /// let y = Double::sum_of_squares(&[dd!("1e150"), dd!("2e150")]);
+    /// assert!(((y - dd!("5e300")) / dd!("5e300")).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`hypot`]: #method.hypot
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn sum_of_squares(values: &[Double]) -> Double {
+        if values.iter().any(|x| x.is_infinite()) {
+            Double::INFINITY
+        } else if values.iter().any(|x| x.is_nan()) {
+            Double::NAN
+        } else {
+            let max = values.iter().fold(0.0, |m: f64, x| m.max(x.0.abs()));
+            let k = u::exponent(max);
+            let sum: Double = values.iter().map(|x| x.ldexp(-k).sqr()).sum();
+            // Two steps, since 2k may be outside the range of an f64 exponent
+            let r = sum.ldexp(k).ldexp(k);
+            if r.is_infinite() {
+                Double::INFINITY
+            } else {
+                r
+            }
+        }
+    }
+
+    /// Evaluates a Chebyshev series at the given point with Clenshaw's algorithm.
+    ///
+    /// The result is c₀T₀(x) + c₁T₁(x) + c₂T₂(x) + ⋯, where the cₖ are the elements of
+    /// `coeffs` in order and Tₖ is the Chebyshev polynomial of the first kind of degree k.
+    /// Note that the first coefficient is used as is and not halved, as it is in some
+    /// presentations. An empty slice of coefficients results in zero.
+    ///
+    /// Chebyshev series are usually fitted over [-1, 1], and that's where the evaluation is
+    /// best behaved. An approximation fitted over some other interval [a, b] should be
+    /// evaluated at (2x - a - b) / (b - a). Clenshaw's recurrence is numerically stable
+    /// over [-1, 1], so the result is accurate to about the precision of the coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // 1 + 2x + 3(2x² - 1) at x = 0.5
+    /// let x = Double::chebyshev_eval(&[dd!(1), dd!(2), dd!(3)], dd!(0.5));
+    /// assert!(x == dd!(0.5));
+    /// ```
+    pub fn chebyshev_eval(coeffs: &[Double], x: Double) -> Double {
+        let two_x = c::mul_pwr2(x, 2.0);
+        let mut b1 = Double::ZERO;
+        let mut b2 = Double::ZERO;
+        match coeffs.split_first() {
+            None => Double::ZERO,
+            Some((c0, rest)) => {
+                for &ck in rest.iter().rev() {
+                    let b0 = ck + two_x * b1 - b2;
+                    b2 = b1;
+                    b1 = b0;
+                }
+                *c0 + x * b1 - b2
+            }
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -275,6 +641,8 @@ impl Double {
             Some(Double::NAN)
         } else if n == 1 {
             Some(*self)
+        } else if n == -1 {
+            Some(self.recip())
         } else if self.is_zero() {
             if n % 2 == 0 || self.is_sign_positive() {
                 if n > 0 {
@@ -311,7 +679,7 @@ impl Double {
     }
 
     #[inline]
-    fn pre_powi(&self, n: i32) -> Option<Double> {
+    fn pre_powi(&self, n: i64) -> Option<Double> {
         if n == 0 {
             Some(Double::ONE)
         } else if self.is_nan() {
@@ -345,6 +713,7 @@ impl Double {
         }
     }
 
+    #[cfg(feature = "transcendental")]
     #[inline]
     fn pre_powf(&self, n: &Double) -> Option<Double> {
         if self.is_zero() {
@@ -367,6 +736,90 @@ impl Double {
             None
         }
     }
+
+    #[cfg(feature = "transcendental")]
+    #[inline]
+    fn pre_pow1p(&self, n: &Double) -> Option<Double> {
+        if self.is_nan() || n.is_nan() || *self < -Double::ONE {
+            Some(Double::NAN)
+        } else if n.is_zero() || self.is_zero() {
+            Some(Double::ONE)
+        } else if *self == -Double::ONE {
+            if n.is_sign_positive() {
+                Some(Double::ZERO)
+            } else {
+                Some(Double::INFINITY)
+            }
+        } else if n.is_infinite() || self.is_infinite() {
+            if self.is_sign_positive() == n.is_sign_positive() {
+                Some(Double::INFINITY)
+            } else {
+                Some(Double::ZERO)
+            }
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "transcendental")]
+    #[inline]
+    fn pre_powm1(&self, n: &Double) -> Option<Double> {
+        if self.is_nan() || n.is_nan() || *self < Double::ZERO {
+            Some(Double::NAN)
+        } else if n.is_zero() || *self == Double::ONE {
+            Some(Double::ZERO)
+        } else if self.is_zero() || self.is_infinite() || n.is_infinite() {
+            if (*self > Double::ONE) == n.is_sign_positive() {
+                Some(Double::INFINITY)
+            } else {
+                Some(-Double::ONE)
+            }
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn pre_hypot(&self, other: &Double) -> Option<Double> {
+        if self.is_infinite() || other.is_infinite() {
+            Some(Double::INFINITY)
+        } else if self.is_nan() || other.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_zero() {
+            Some(other.abs())
+        } else if other.is_zero() {
+            Some(self.abs())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_agm(&self, other: &Double) -> Option<Double> {
+        if self.is_nan() || other.is_nan() || *self < Double::ZERO || *other < Double::ZERO {
+            Some(Double::NAN)
+        } else if self.is_zero() || other.is_zero() {
+            Some(Double::ZERO)
+        } else if self.is_infinite() || other.is_infinite() {
+            Some(Double::INFINITY)
+        } else if self == other {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+}
+
+// Takes a power of two out of `x` (a finite, positive number) if it's outside of the range
+// 2^-300 to 2^300, adding its exponent to `exp`. This is exact, and a product or square of
+// two numbers in that range has all of its components well inside the range of normal
+// `f64`s.
+fn rescale(x: Double, exp: i64) -> (Double, i64) {
+    if x.0 > 2f64.powi(-300) && x.0 < 2f64.powi(300) {
+        (x, exp)
+    } else {
+        let e = x.0.log2().floor() as i32;
+        (x.scale_b(-e), exp + i64::from(e))
+    }
 }
 
 #[cfg(test)]
@@ -441,6 +894,103 @@ mod tests {
             Double::NAN.ldexp(5);
     );
 
+    // mul_pwr2 tests
+    test_all_exact!(
+        mul_pwr2_pi:
+            Double::PI.ldexp(1),
+            Double::PI.mul_pwr2(2.0);
+        mul_pwr2_pi_half:
+            Double::PI.ldexp(-1),
+            Double::PI.mul_pwr2(0.5);
+        mul_pwr2_ldexp:
+            Double::E.ldexp(-10),
+            Double::E.mul_pwr2(2f64.powi(-10));
+        mul_pwr2_neg:
+            dd!(-12),
+            dd!(3).mul_pwr2(-4.0);
+
+        mul_pwr2_zero:
+            Double::ZERO,
+            Double::ZERO.mul_pwr2(8.0);
+        mul_pwr2_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.mul_pwr2(8.0);
+        mul_pwr2_inf:
+            Double::INFINITY,
+            Double::INFINITY.mul_pwr2(0.5);
+        mul_pwr2_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.mul_pwr2(0.5);
+        mul_pwr2_nan:
+            Double::NAN,
+            Double::NAN.mul_pwr2(2.0);
+    );
+
+    // scale_b tests
+    test_all_exact!(
+        scale_b_pi:
+            Double::PI.ldexp(2),
+            Double::PI.scale_b(2);
+        scale_b_neg_e:
+            (-Double::E).ldexp(-14),
+            (-Double::E).scale_b(-14);
+        scale_b_zero_exp:
+            Double::PI,
+            Double::PI.scale_b(0);
+        scale_b_max_exp:
+            Double(2f64.powi(1023), 0.0),
+            Double::ONE.scale_b(1023);
+        scale_b_min_exp:
+            Double(2f64.powi(-1022), 0.0),
+            Double::ONE.scale_b(-1022);
+        scale_b_large_down:
+            Double(3.0 * 2f64.powi(-500) * 2f64.powi(-500), 0.0),
+            dd!(3).scale_b(1000).scale_b(-2000);
+        scale_b_large_up:
+            Double(2f64.powi(1023), 0.0),
+            Double(5e-324, 0.0).scale_b(2097);
+        scale_b_min_subnormal:
+            Double(5e-324, 0.0),
+            Double::ONE.scale_b(-1074);
+        scale_b_subnormal_rounded:
+            Double(1e-323, 0.0),
+            dd!(3).scale_b(-1075);
+        scale_b_subnormal_tie:
+            Double::ZERO,
+            Double::ONE.scale_b(-1075);
+        scale_b_overflow:
+            Double::INFINITY,
+            Double::ONE.scale_b(1024);
+        scale_b_neg_overflow:
+            Double::NEG_INFINITY,
+            (-Double::MIN_POSITIVE).scale_b(2100);
+        scale_b_i32_max:
+            Double::INFINITY,
+            Double(5e-324, 0.0).scale_b(i32::MAX);
+        scale_b_i32_min:
+            Double::ZERO,
+            Double::MAX.scale_b(i32::MIN);
+
+        scale_b_zero:
+            Double::ZERO,
+            Double::ZERO.scale_b(2000);
+        scale_b_inf:
+            Double::INFINITY,
+            Double::INFINITY.scale_b(-2000);
+        scale_b_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.scale_b(-2000);
+        scale_b_nan:
+            Double::NAN,
+            Double::NAN.scale_b(4);
+    );
+    test_all_assert!(
+        scale_b_neg_zero:
+            Double::NEG_ZERO.scale_b(2000).is_sign_negative();
+        scale_b_neg_underflow:
+            (-Double::PI).scale_b(-2000).is_sign_negative();
+    );
+
     // sqr tests
     test_all_near!(
         sqr_pi:
@@ -667,6 +1217,23 @@ mod tests {
             Double::NAN,
             Double::NAN.nroot(4);
     );
+    test_all_near!(
+        nroot_huge:
+            dd!(2).cbrt().ldexp(333),
+            Double(2f64.powi(1000), 0.0).nroot(3);
+        nroot_neg_huge:
+            -dd!(2).nroot(5).ldexp(204),
+            Double(-2f64.powi(1021), 0.0).nroot(5);
+        nroot_tiny_neg:
+            Double(2f64.powi(340), 0.0),
+            Double(2f64.powi(-1020), 0.0).nroot(-3);
+        nroot_subnormal:
+            Double(2f64.powi(-214), 0.0),
+            Double(2f64.powi(-1000) * 2f64.powi(-70), 0.0).nroot(5);
+        nroot_neg_one:
+            dd!(0.25),
+            dd!(4).nroot(-1);
+    );
 
     // cbrt tests
     test_all_near!(
@@ -818,8 +1385,80 @@ mod tests {
             Double::NAN,
             Double::NAN.powi(2);
     );
+    test_all_prec!(
+        powi_large_exp:
+            dd!("2.7182818589778950338306474046576127618660387784694290599318239131704"),
+            (Double::ONE + Double(2f64.powi(-40), 0.0)).powi((1 << 40) + 12345),
+            18;
+        powi_3_neg_200:
+            dd!("3.7648619495990264198834218900111162409703509768819192559503485475330e-96"),
+            dd!(3).powi(-200),
+            28;
+    );
+    test_all_exact!(
+        powi_overflow:
+            Double::INFINITY,
+            dd!(10).powi(400);
+        powi_neg_overflow:
+            Double::NEG_INFINITY,
+            dd!(-10).powi(401);
+        powi_neg_overflow_even:
+            Double::INFINITY,
+            dd!(-10).powi(402);
+        powi_underflow:
+            Double::ZERO,
+            dd!(0.1).powi(400);
+        powi_neg_exp_overflow:
+            Double::ZERO,
+            dd!(10).powi(-400);
+        powi_neg_exp_underflow:
+            Double::INFINITY,
+            dd!(0.1).powi(-400);
+        powi_huge_exp_overflow:
+            Double::INFINITY,
+            dd!(1.5).powi(i64::MAX);
+        powi_one_max:
+            Double::ONE,
+            Double::ONE.powi(i64::MAX);
+        powi_neg_one_min:
+            Double::ONE,
+            (-Double::ONE).powi(i64::MIN);
+        powi_2_1023:
+            Double(2f64.powi(1023), 0.0),
+            dd!(2).powi(1023);
+        powi_2_neg_1000:
+            Double(2f64.powi(-1000), 0.0),
+            dd!(2).powi(-1000);
+        powi_2_neg_1030:
+            Double(f64::from_bits(1 << 44), 0.0),
+            dd!(2).powi(-1030);
+        powi_10_neg_310:
+            Double(1e-310, 0.0),
+            dd!(10).powi(-310);
+        powi_max_neg_1:
+            Double(f64::from_bits(1 << 50), 0.0),
+            Double::MAX.powi(-1);
+        powi_2_neg_1074:
+            Double(f64::from_bits(1), 0.0),
+            dd!(2).powi(-1074);
+        powi_half_1074:
+            Double(f64::from_bits(1), 0.0),
+            dd!(0.5).powi(1074);
+        powi_2_neg_1075:
+            Double::ZERO,
+            dd!(2).powi(-1075);
+    );
+    test_all_assert!(
+        powi_neg_underflow_sign:
+            dd!(-10).powi(-401).is_sign_negative() && dd!(-10).powi(-401).is_zero();
+        powi_neg_underflow_even_sign:
+            dd!(-0.1).powi(400).is_sign_positive() && dd!(-0.1).powi(400).is_zero();
+        powi_neg_subnormal_sign:
+            dd!(-2).powi(-1073)[0] == -f64::from_bits(2);
+    );
 
     // powf_tests
+    #[cfg(feature = "transcendental")]
     test_all_near!(
         powf_e:
             dd!("7.3890560989306502272304274605750057"),
@@ -837,6 +1476,7 @@ mod tests {
             dd!("1.2715371297141403818925670498911262"),
             Double::FRAC_1_SQRT_2.powf(-Double::LN_2);
     );
+    #[cfg(feature = "transcendental")]
     test_all_prec!(
         powf_pi:
             dd!("93648.047476083020973716690184919311"),
@@ -847,6 +1487,7 @@ mod tests {
             dd!(150).powf(-Double::LN_10),
             30;
     );
+    #[cfg(feature = "transcendental")]
     test_all_exact!(
         powf_zero:
             Double::ZERO,
@@ -929,6 +1570,121 @@ mod tests {
             dd!(-1).powf(dd!(1));
     );
 
+    // pow1p tests
+    #[cfg(feature = "transcendental")]
+    test_all_near!(
+        pow1p_tiny:
+            dd!("1.00000000010000000000499999999966666666662083333333425000000037639"),
+            dd!(1e-20).pow1p(dd!(1e10));
+        pow1p_dyadic_tiny:
+            dd!("1.00000095367477115374544637465885616712912502716070278062434866652"),
+            Double::ONE.scale_b(-60).pow1p(Double::ONE.scale_b(40));
+        pow1p_half:
+            dd!("2.75567596063107536047194458404412781596169091573875389448677913816"),
+            dd!(0.5).pow1p(dd!(2.5));
+        pow1p_neg:
+            dd!("2.37037037037037037037037037037037037037037037037037037037037037037"),
+            dd!(-0.25).pow1p(dd!(-3));
+        pow1p_sqrt:
+            dd!(2),
+            dd!(3).pow1p(dd!(0.5));
+    );
+    #[cfg(feature = "transcendental")]
+    test_all_exact!(
+        pow1p_zero_exp:
+            Double::ONE,
+            Double::PI.pow1p(Double::ZERO);
+        pow1p_zero:
+            Double::ONE,
+            Double::ZERO.pow1p(Double::INFINITY);
+        pow1p_neg_one:
+            Double::ZERO,
+            (-Double::ONE).pow1p(Double::PI);
+        pow1p_neg_one_neg_exp:
+            Double::INFINITY,
+            (-Double::ONE).pow1p(-Double::PI);
+        pow1p_inf_exp:
+            Double::INFINITY,
+            Double::PI.pow1p(Double::INFINITY);
+        pow1p_neg_inf_exp:
+            Double::ZERO,
+            Double::PI.pow1p(Double::NEG_INFINITY);
+        pow1p_neg_base_inf_exp:
+            Double::ZERO,
+            dd!(-0.5).pow1p(Double::INFINITY);
+        pow1p_inf:
+            Double::INFINITY,
+            Double::INFINITY.pow1p(Double::E);
+        pow1p_inf_neg_exp:
+            Double::ZERO,
+            Double::INFINITY.pow1p(-Double::E);
+        pow1p_too_small:
+            Double::NAN,
+            dd!(-2).pow1p(dd!(2));
+        pow1p_nan:
+            Double::NAN,
+            Double::NAN.pow1p(Double::ONE);
+        pow1p_nan_exp:
+            Double::NAN,
+            Double::ONE.pow1p(Double::NAN);
+    );
+
+    // powm1 tests
+    #[cfg(feature = "transcendental")]
+    test_all_near!(
+        powm1_sqrt_2:
+            dd!("0.414213562373095048801688724209698078569671875376948073176679737991"),
+            dd!(2).powm1(dd!(0.5));
+        powm1_near_one:
+            dd!("2.54109884176290101720711989648012880517511426104376903980476632063e-21"),
+            Double::new(1.0, 2f64.powi(-70)).powm1(dd!(3));
+        powm1_tiny_exp:
+            dd!("4.05465108108164381978835125233814963720254715532609454613955303938e-21"),
+            dd!(1.5).powm1(dd!(1e-20));
+        powm1_below_one:
+            dd!("-2.55513008486279683038911422106143066664425514072184350832700982756e-16"),
+            dd!(0.75).powm1(Double::ONE.scale_b(-50));
+        powm1_cube:
+            dd!(-0.875),
+            dd!(0.5).powm1(dd!(3));
+        powm1_large:
+            dd!(-0.99),
+            dd!(10).powm1(dd!(-2));
+    );
+    #[cfg(feature = "transcendental")]
+    test_all_exact!(
+        powm1_zero_exp:
+            Double::ZERO,
+            Double::PI.powm1(Double::ZERO);
+        powm1_one:
+            Double::ZERO,
+            Double::ONE.powm1(Double::INFINITY);
+        powm1_zero:
+            -Double::ONE,
+            Double::ZERO.powm1(Double::PI);
+        powm1_zero_neg_exp:
+            Double::INFINITY,
+            Double::ZERO.powm1(-Double::PI);
+        powm1_inf:
+            Double::INFINITY,
+            Double::INFINITY.powm1(Double::PI);
+        powm1_inf_neg_exp:
+            -Double::ONE,
+            Double::INFINITY.powm1(-Double::PI);
+        powm1_inf_exp:
+            Double::INFINITY,
+            Double::PI.powm1(Double::INFINITY);
+        powm1_small_inf_exp:
+            -Double::ONE,
+            dd!(0.5).powm1(Double::INFINITY);
+        powm1_neg:
+            Double::NAN,
+            dd!(-2).powm1(dd!(2));
+        powm1_nan:
+            Double::NAN,
+            Double::NAN.powm1(Double::ONE);
+    );
+
     // recip tests
     test_all_near!(
         recip_pi:
@@ -979,4 +1735,179 @@ mod tests {
             Double::NAN,
             Double::NAN.recip();
     );
+
+    // hypot tests
+    test_all_near!(
+        hypot_pi_e:
+            dd!("4.1543544023133135729481214666791"),
+            Double::PI.hypot(Double::E);
+        hypot_neg:
+            dd!("4.1543544023133135729481214666791"),
+            (-Double::PI).hypot(-Double::E);
+        hypot_3_4:
+            dd!(5),
+            dd!(3).hypot(dd!(4));
+    );
+    test_all_prec!(
+        hypot_large:
+            dd!("1.4142135623730950488016887242097e200"),
+            dd!("1e200").hypot(dd!("1e200")),
+            30;
+        hypot_small:
+            dd!(10).sqrt(),
+            dd!("1e-200").hypot(dd!("3e-200")) / dd!("1e-200"),
+            30;
+    );
+    test_all_exact!(
+        hypot_zero:
+            dd!(3),
+            Double::ZERO.hypot(dd!(-3));
+        hypot_inf:
+            Double::INFINITY,
+            Double::ONE.hypot(Double::NEG_INFINITY);
+        hypot_inf_nan:
+            Double::INFINITY,
+            Double::NAN.hypot(Double::INFINITY);
+        hypot_nan:
+            Double::NAN,
+            Double::NAN.hypot(Double::ONE);
+        hypot_overflow:
+            Double::INFINITY,
+            Double::MAX.hypot(Double::MAX);
+    );
+
+    // agm tests
+    test_all_near!(
+        agm_1_sqrt_2:
+            dd!("1.19814023473559220743992249228032387822721266321565155826367495295"),
+            Double::ONE.agm(Double::SQRT_2);
+        agm_24_6:
+            dd!("13.4581714817256154207668131569743992430538388544396598555129422083"),
+            dd!(24).agm(dd!(6));
+        agm_symmetric:
+            dd!(6).agm(dd!(24)),
+            dd!(24).agm(dd!(6));
+        agm_half_2:
+            dd!("1.12151429014380128506390109641453327025448657120330498795941185069"),
+            dd!(0.5).agm(dd!(2));
+    );
+    test_all_prec!(
+        agm_tiny:
+            dd!("2.28445571664677107340104987183299738415920436881239696783569382457e-3"),
+            Double::ONE.agm(Double::ONE.scale_b(-990)),
+            30;
+        agm_huge:
+            dd!("6.10034567499146435155893692296476607743085020729479743124812566422e150"),
+            Double::ONE.scale_b(500).agm(dd!(3).scale_b(500)),
+            30;
+    );
+    test_all_exact!(
+        agm_equal:
+            Double::PI,
+            Double::PI.agm(Double::PI);
+        agm_max:
+            Double::MAX,
+            Double::MAX.agm(Double::MAX);
+        agm_zero:
+            Double::ZERO,
+            Double::ZERO.agm(Double::PI);
+        agm_inf:
+            Double::INFINITY,
+            Double::PI.agm(Double::INFINITY);
+        agm_neg:
+            Double::NAN,
+            Double::PI.agm(-Double::E);
+        agm_nan:
+            Double::NAN,
+            Double::NAN.agm(Double::ONE);
+    );
+
+    // sum_of_squares tests
+    test_all_near!(
+        sum_of_squares_consts:
+            dd!("21.25866050002000884606491846045115894849401497779263795050047719874262"),
+            Double::sum_of_squares(&[Double::PI, Double::E, dd!(-2)]);
+    );
+    test_all_prec!(
+        sum_of_squares_large:
+            dd!("1.4e301"),
+            Double::sum_of_squares(&[dd!("1e150"), dd!("2e150"), dd!("3e150")]),
+            30;
+    );
+    test_all_exact!(
+        sum_of_squares_empty:
+            Double::ZERO,
+            Double::sum_of_squares(&[]);
+        sum_of_squares_inf:
+            Double::INFINITY,
+            Double::sum_of_squares(&[Double::ONE, Double::NAN, Double::NEG_INFINITY]);
+        sum_of_squares_nan:
+            Double::NAN,
+            Double::sum_of_squares(&[Double::ONE, Double::NAN]);
+        sum_of_squares_overflow:
+            Double::INFINITY,
+            Double::sum_of_squares(&[dd!("1e200"), dd!("1e200")]);
+    );
+
+    // chebyshev_eval tests
+    test_all_exact!(
+        chebyshev_eval_empty:
+            Double::ZERO,
+            Double::chebyshev_eval(&[], dd!(0.5));
+        chebyshev_eval_constant:
+            dd!(7),
+            Double::chebyshev_eval(&[dd!(7)], dd!(0.5));
+        chebyshev_eval_quadratic:
+            dd!(0.5),
+            Double::chebyshev_eval(&[dd!(1), dd!(2), dd!(3)], dd!(0.5));
+        chebyshev_eval_outside:
+            dd!(17),
+            Double::chebyshev_eval(&[dd!(0), dd!(0), dd!(1)], dd!(3));
+        chebyshev_eval_t5:
+            dd!(0.5),
+            Double::chebyshev_eval(&[Double::ZERO, Double::ZERO, Double::ZERO, Double::ZERO, Double::ZERO, Double::ONE], dd!(0.5));
+        chebyshev_eval_nan:
+            Double::NAN,
+            Double::chebyshev_eval(&[dd!(1), dd!(2)], Double::NAN);
+    );
+    #[cfg(feature = "transcendental")]
+    test!(chebyshev_eval_exp: {
+        // The Chebyshev coefficients of eˣ are I₀(1), 2I₁(1), 2I₂(1), ...
+        let coeffs = [
+            dd!("1.26606587775200833559824462521471753760767031135496220680813533121"),
+            dd!("1.13031820798497005441539205521972661465779924324218401896058897896"),
+            dd!("0.27149533953407656236570513998998184589974213622555637569509270451"),
+            dd!("0.044336849848663804952571495259799231058830698339958516180218160918"),
+            dd!("5.47424044209373265027616843118645954675794618580527861378373900212e-3"),
+            dd!("5.42926311913943750362147810307554684767128853516287269948248901049e-4"),
+            dd!("4.49773229542951466546903281109126990866576506424059143012499916264e-5"),
+            dd!("3.19843646240199050586387297660229572723704580741629833324900153265e-6"),
+            dd!("1.99212480667279572596106438480558905339009338577737635763970169328e-7"),
+            dd!("1.10367717255173443261699609133532418128963901724961610254788234043e-8"),
+            dd!("5.50589607967374725047142040200552706874315472806737305351348049761e-10"),
+            dd!("2.49795661698498252271201093421876754100807163614149184518624091169e-11"),
+            dd!("1.03915223067857005049963467242384785253971285560909941037504918814e-12"),
+            dd!("3.99126335641440151288772040153269491276078267965326028612286014939e-14"),
+            dd!("1.42375801082565714882736802534717522190935889925173598310554930277e-15"),
+            dd!("4.74092610256149617108993056060429141457776174839953342732210164274e-17"),
+            dd!("1.48018005720829750038885716588779753603037473187595490891880994386e-18"),
+            dd!("4.34991949494416984558762976333929928056260639647771878190982237692e-20"),
+            dd!("1.20742892727975288906304635243578063908855707353052306947033571026e-21"),
+            dd!("3.17535673705944496066289457048897984380093176783573181661381997691e-23"),
+            dd!("7.93367197163804011146415649968298444203001752944979157084119037784e-25"),
+            dd!("1.88794840422891607723197061578606698892475605581518827734382577046e-26"),
+            dd!("4.28867387659258708987991338150308854604209502600080599712214190363e-28"),
+            dd!("9.31898528177757684808727924708028666234244374833638610083332864881e-30"),
+            dd!("1.94064697490173975976492784615668136457090176606839073881072517364e-31"),
+            dd!("3.87980224922600121562558552821611240211527120811055454184781532113e-33"),
+            dd!("7.45850288739151952135082048625163513266162013113467886817513079677e-35"),
+            dd!("1.38074778241106452315887536526213313122873992052153039674730680722e-36"),
+            dd!("2.46486237177109629289351383611622402642456031841472573967403777315e-38"),
+            dd!("4.24854219250599138507617037047676430986142209283982529845654256024e-40"),
+        ];
+        for x in ["-0.5", "-0.3", "0", "0.5", "1"] {
+            let x = dd!(x);
+            near!(x.exp(), Double::chebyshev_eval(&coeffs, x));
+        }
+    });
 }
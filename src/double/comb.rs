@@ -0,0 +1,420 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bigint::BigNat;
+use crate::double::Double;
+
+impl Double {
+    /// Calculates the factorial of `n` as a `Double`.
+    ///
+    /// The factorial is calculated exactly as an integer and only then rounded, so the
+    /// result is exact for as long as it fits into a `Double` (up to 36!) and correctly
+    /// rounded after that. If the result is too large to be represented (past 170!), it
+    /// overflows to [`INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::factorial(5) == dd!(120));
+    /// assert!(Double::factorial(25) == dd!("15511210043330985984000000"));
+    /// assert!(Double::factorial(171) == Double::INFINITY);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn factorial(n: u32) -> Double {
+        let mut p = BigNat::from_u64(1);
+        for k in 2..=n as u64 {
+            p.mul_small(k);
+            if p.bits() > MAX_BITS {
+                return Double::INFINITY;
+            }
+        }
+        from_big_nat(&p)
+    }
+
+    /// Calculates the binomial coefficient "`n` choose `k`" as a `Double`.
+    ///
+    /// This is the number of ways to choose `k` items from `n`, or n! / (k! (n - k)!),
+    /// which is zero if `k` is greater than `n`. Like [`factorial`], it's calculated
+    /// exactly as an integer and only then rounded, so the result is exact for as long as
+    /// it fits into a `Double` and correctly rounded after that. If the result is too large
+    /// to be represented, it overflows to [`INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::binomial(10, 3) == dd!(120));
+    /// assert!(Double::binomial(100, 50) == dd!("100891344545564193334812497256"));
+    /// assert!(Double::binomial(3, 10) == Double::ZERO);
+    /// ```
+    ///
+    /// [`factorial`]: #method.factorial
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn binomial(n: u32, k: u32) -> Double {
+        if k > n {
+            return Double::ZERO;
+        }
+        let k = k.min(n - k) as u64;
+        let n = n as u64;
+        let mut p = BigNat::from_u64(1);
+        for i in 1..=k {
+            // After this step, p is C(n - k + i, i), which is always an integer
+            p.mul_small(n - k + i);
+            p.div_small(i);
+            if p.bits() > MAX_BITS {
+                return Double::INFINITY;
+            }
+        }
+        from_big_nat(&p)
+    }
+
+    /// Calculates the rising factorial of the `Double` with `n` factors, x (x + 1) (x + 2)
+    /// ⋯ (x + n - 1). This is also known as the Pochhammer symbol (x)ₙ.
+    ///
+    /// If the `Double` is an integer, the product is calculated exactly as an integer and
+    /// only then rounded, so the result is exact for as long as it fits into a `Double` and
+    /// correctly rounded after that. Otherwise, each factor is multiplied in turn and
+    /// rounded normally. If the result is too large to be represented, it overflows to
+    /// [`INFINITY`] or [`NEG_INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(5).rising_factorial(3) == dd!(210));
+    /// assert!(dd!(-3).rising_factorial(3) == dd!(-6));
+    /// assert!(dd!(-3).rising_factorial(5) == dd!(0));
+    ///
+    /// let x = dd!(0.5).rising_factorial(3);
+    /// let expected = dd!(1.875);
+    /// assert!((x - expected).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn rising_factorial(self, n: u32) -> Double {
+        match self.pre_rising_factorial(n) {
+            Some(r) => r,
+            None => product(self, n, 1),
+        }
+    }
+
+    /// Calculates the falling factorial of the `Double` with `n` factors, x (x - 1) (x - 2)
+    /// ⋯ (x - n + 1).
+    ///
+    /// If the `Double` is an integer, the product is calculated exactly as an integer and
+    /// only then rounded, so the result is exact for as long as it fits into a `Double` and
+    /// correctly rounded after that. Otherwise, each factor is multiplied in turn and
+    /// rounded normally. If the result is too large to be represented, it overflows to
+    /// [`INFINITY`] or [`NEG_INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(7).falling_factorial(3) == dd!(210));
+    /// assert!(dd!(3).falling_factorial(5) == dd!(0));
+    ///
+    /// let x = dd!(2.5).falling_factorial(2);
+    /// let expected = dd!(3.75);
+    /// assert!((x - expected).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn falling_factorial(self, n: u32) -> Double {
+        match self.pre_falling_factorial(n) {
+            Some(r) => r,
+            None => product(self, n, -1),
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_rising_factorial(&self, n: u32) -> Option<Double> {
+        if n == 0 {
+            Some(Double::ONE)
+        } else if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            Some(self.powi(n as i64))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_falling_factorial(&self, n: u32) -> Option<Double> {
+        self.pre_rising_factorial(n)
+    }
+}
+
+// The number of bits past which an integer is too large to be represented. Anything with
+// this many bits or fewer is less than 2^1024, which may still round up to infinity.
+const MAX_BITS: u32 = 1024;
+
+// Calculates the product x (x + s) (x + 2s) ⋯ (x + (n - 1)s) for finite x, where the step
+// s is 1 or -1.
+fn product(x: Double, n: u32, step: i64) -> Double {
+    if x.fract().is_zero() {
+        if let Some(m) = x.trunc_i64() {
+            return integer_product(m, n, step);
+        }
+    }
+
+    let mut p = x;
+    for j in 1..n {
+        p *= x + Double::from(step * j as i64);
+        if p.is_zero() || p.is_infinite() {
+            break;
+        }
+    }
+    p
+}
+
+// Calculates the product m (m + s) (m + 2s) ⋯ (m + (n - 1)s) exactly, where the step s is 1
+// or -1, and then rounds it.
+fn integer_product(m: i64, n: u32, step: i64) -> Double {
+    let first = m as i128;
+    let last = first + (step * (n as i64 - 1)) as i128;
+    let (lo, hi) = (first.min(last), first.max(last));
+    if lo <= 0 && hi >= 0 {
+        return Double::ZERO;
+    }
+
+    // Either all of the factors are positive or all of them are negative
+    let negative = hi < 0 && n % 2 == 1;
+    let mut p = BigNat::from_u64(1);
+    for j in 0..n as i128 {
+        p.mul_small((lo + j).unsigned_abs() as u64);
+        if p.bits() > MAX_BITS {
+            return if negative {
+                Double::NEG_INFINITY
+            } else {
+                Double::INFINITY
+            };
+        }
+    }
+    let r = from_big_nat(&p);
+    if negative {
+        -r
+    } else {
+        r
+    }
+}
+
+// Rounds an exact integer into a `Double`.
+fn from_big_nat(n: &BigNat) -> Double {
+    if n.bits() > MAX_BITS {
+        return Double::INFINITY;
+    }
+    let mut c = [0.0; 2];
+    n.to_components(&mut c);
+    if c[0].is_infinite() {
+        Double::INFINITY
+    } else {
+        Double(c[0], c[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // factorial tests
+    test_all_exact!(
+        factorial_0:
+            Double::new(1.0, 0.0),
+            Double::factorial(0);
+        factorial_1:
+            Double::new(1.0, 0.0),
+            Double::factorial(1);
+        factorial_2:
+            Double::new(2.0, 0.0),
+            Double::factorial(2);
+        factorial_5:
+            Double::new(120.0, 0.0),
+            Double::factorial(5);
+        factorial_20:
+            Double::new(2.43290200817664e18, 0.0),
+            Double::factorial(20);
+        factorial_36:
+            Double::new(3.7199332678990125e41, -3.73956733043439e25),
+            Double::factorial(36);
+        factorial_37:
+            Double::new(1.3763753091226346e43, -5.325561352520254e26),
+            Double::factorial(37);
+        factorial_58:
+            Double::new(2.3505613312828785e78, 7.690781796036033e61),
+            Double::factorial(58);
+        factorial_59:
+            Double::new(1.3868311854568984e80, -2.8672092482841673e63),
+            Double::factorial(59);
+        factorial_100:
+            Double::new(9.332621544394415e157, 1.716052534060313e141),
+            Double::factorial(100);
+        factorial_170:
+            Double::new(7.257415615307999e306, -6.760368134125985e289),
+            Double::factorial(170);
+        factorial_171:
+            Double::INFINITY,
+            Double::factorial(171);
+        factorial_max:
+            Double::INFINITY,
+            Double::factorial(u32::MAX);
+    );
+
+    // binomial tests
+    test_all_exact!(
+        binomial_0_0:
+            Double::new(1.0, 0.0),
+            Double::binomial(0, 0);
+        binomial_5_0:
+            Double::new(1.0, 0.0),
+            Double::binomial(5, 0);
+        binomial_5_5:
+            Double::new(1.0, 0.0),
+            Double::binomial(5, 5);
+        binomial_10_3:
+            Double::new(120.0, 0.0),
+            Double::binomial(10, 3);
+        binomial_52_5:
+            Double::new(2598960.0, 0.0),
+            Double::binomial(52, 5);
+        binomial_100_50:
+            Double::new(1.008913445455642e29, -8736902458008.0),
+            Double::binomial(100, 50);
+        binomial_1000_500:
+            Double::new(2.7028824094543655e299, 1.8496308785162572e283),
+            Double::binomial(1000, 500);
+        binomial_1020_510:
+            Double::new(2.8062677682996225e305, 1.6093556670481862e289),
+            Double::binomial(1020, 510);
+        binomial_4000000000_3:
+            Double::new(1.0666666658666666e28, 1016816973824.0),
+            Double::binomial(4000000000, 3);
+        binomial_k_greater:
+            Double::ZERO,
+            Double::binomial(3, 4);
+        binomial_overflow:
+            Double::INFINITY,
+            Double::binomial(2000, 1000);
+        binomial_large_overflow:
+            Double::INFINITY,
+            Double::binomial(u32::MAX, u32::MAX / 2);
+    );
+
+    // rising_factorial tests
+    test_all_exact!(
+        rising_factorial_5_3:
+            Double::new(210.0, 0.0),
+            dd!(5).rising_factorial(3);
+        rising_factorial_1_40:
+            Double::new(8.159152832478977e47, 5.055006274829492e31),
+            dd!(1).rising_factorial(40);
+        rising_factorial_neg_3_3:
+            Double::new(-6.0, -0.0),
+            dd!(-3).rising_factorial(3);
+        rising_factorial_neg_3_4:
+            Double::new(0.0, 0.0),
+            dd!(-3).rising_factorial(4);
+        rising_factorial_neg_50_20:
+            Double::new(1.1466075511211338e32, -8993437468590080.0),
+            dd!(-50).rising_factorial(20);
+        rising_factorial_neg_50_51:
+            Double::new(0.0, 0.0),
+            dd!(-50).rising_factorial(51);
+        rising_factorial_7_0:
+            Double::new(1.0, 0.0),
+            dd!(7).rising_factorial(0);
+        rising_factorial_1000000_30:
+            Double::new(1.0004350903469223e180, -2.9442416268507096e163),
+            dd!(1000000).rising_factorial(30);
+        rising_factorial_neg_1000000_31:
+            Double::new(-9.995351033703702e185, -7.4078835960991515e168),
+            dd!(-1000000).rising_factorial(31);
+        rising_factorial_overflow:
+            Double::INFINITY,
+            dd!(1).rising_factorial(200);
+        rising_factorial_neg_overflow:
+            Double::NEG_INFINITY,
+            dd!(-300).rising_factorial(199);
+        rising_factorial_nan:
+            Double::NAN,
+            Double::NAN.rising_factorial(3);
+        rising_factorial_inf:
+            Double::INFINITY,
+            Double::INFINITY.rising_factorial(3);
+        rising_factorial_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.rising_factorial(3);
+        rising_factorial_nan_zero:
+            Double::ONE,
+            Double::NAN.rising_factorial(0);
+    );
+    test_all_near!(
+        rising_factorial_0_5_3:
+            dd!("1.875"),
+            dd!(0.5).rising_factorial(3);
+        rising_factorial_2_25_10:
+            dd!("6.505852392303943634033203125e7"),
+            dd!(2.25).rising_factorial(10);
+        rising_factorial_neg_1_5_7:
+            dd!("22.1484375"),
+            dd!(-1.5).rising_factorial(7);
+        rising_factorial_0_1_50:
+            dd!("9.44646434431783523246956965447535793253754884256853990253478804198e61"),
+            dd!(0.1).rising_factorial(50);
+        falling_factorial_2_5_2:
+            dd!("3.75"),
+            dd!(2.5).falling_factorial(2);
+        falling_factorial_10_75_6:
+            dd!("2.75863634033203125e5"),
+            dd!(10.75).falling_factorial(6);
+        falling_factorial_neg_1_5_7:
+            dd!("-1.58361328125e4"),
+            dd!(-1.5).falling_factorial(7);
+    );
+
+    // falling_factorial tests
+    test_all_exact!(
+        falling_factorial_7_3:
+            Double::new(210.0, 0.0),
+            dd!(7).falling_factorial(3);
+        falling_factorial_40_40:
+            Double::new(8.159152832478977e47, 5.055006274829492e31),
+            dd!(40).falling_factorial(40);
+        falling_factorial_3_5:
+            Double::new(0.0, 0.0),
+            dd!(3).falling_factorial(5);
+        falling_factorial_neg_3_3:
+            Double::new(-60.0, -0.0),
+            dd!(-3).falling_factorial(3);
+        falling_factorial_neg_4_6:
+            Double::new(60480.0, 0.0),
+            dd!(-4).falling_factorial(6);
+        falling_factorial_100_0:
+            Double::new(1.0, 0.0),
+            dd!(100).falling_factorial(0);
+        falling_factorial_1000000_30:
+            Double::new(9.9956509032308e179, -3.026953437630666e163),
+            dd!(1000000).falling_factorial(30);
+        falling_factorial_overflow:
+            Double::INFINITY,
+            dd!(200).falling_factorial(200);
+        falling_factorial_nan:
+            Double::NAN,
+            Double::NAN.falling_factorial(3);
+        falling_factorial_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.falling_factorial(3);
+    );
+}
@@ -12,9 +12,27 @@ pub fn mul_pwr2(a: Double, b: f64) -> Double {
     Double(a.0 * b, a.1 * b)
 }
 
+/// Multiplies two complex numbers, each represented by a tuple of its real and imaginary
+/// parts.
+#[cfg(feature = "transcendental")]
+#[inline]
+pub fn complex_mul(a: (Double, Double), b: (Double, Double)) -> (Double, Double) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+/// Calculates the reciprocal of a complex number represented by a tuple of its real and
+/// imaginary parts.
+#[cfg(feature = "transcendental")]
+#[inline]
+pub fn complex_recip(a: (Double, Double)) -> (Double, Double) {
+    let m = a.0 * a.0 + a.1 * a.1;
+    (a.0 / m, -a.1 / m)
+}
+
 /// Table of the reciprocals of factorials. This starts with 1/3!, as the inverse factorials
 /// before that are trivial (1/1! is 1 and 1/2! is 1/2). These are used in Taylor series
 /// calculations for exp, sin, and cos. 
+#[cfg(feature = "transcendental")]
 pub const INV_FACTS: [Double; 15] = [
     Double(1.6666666666666666e-1, 9.25185853854297e-18),
     Double(4.1666666666666664e-2, 2.3129646346357427e-18),
@@ -34,6 +52,7 @@ pub const INV_FACTS: [Double; 15] = [
 ];
 
 /// Table of sines of kπ/16, where k is in [1, 4].
+#[cfg(feature = "transcendental")]
 pub const SINES: [Double; 4] = [
     Double(1.9509032201612828e-1, -7.991079068461734e-18),
     Double(3.826834323650898e-1, -1.005077269646159e-17),
@@ -42,9 +61,46 @@ pub const SINES: [Double; 4] = [
 ];
 
 /// Table of cosines of kπ/16, where k is in [1, 4].
+#[cfg(feature = "transcendental")]
 pub const COSINES: [Double; 4] = [
     Double(9.807852804032304e-1, 1.8546939997824996e-17),
     Double(9.238795325112867e-1, 1.764504708433667e-17),
     Double(8.314696123025452e-1, 1.4073856984728008e-18),
     Double(7.071067811865476e-1, -4.8336466567264573e-17),
 ];
+
+/// The Euler-Mascheroni constant, γ.
+#[cfg(feature = "transcendental")]
+pub const EULER_GAMMA: Double = Double::EGAMMA;
+
+/// Table of the even-indexed Bernoulli numbers, starting with B₂ (B₀ is 1, and all of the
+/// odd-indexed ones past B₁ are 0). These are used in the asymptotic expansions of the
+/// gamma function and its relatives.
+#[cfg(feature = "transcendental")]
+pub const BERNOULLI: [Double; 25] = [
+    Double(1.6666666666666666e-1, 9.25185853854297e-18),
+    Double(-3.333333333333333e-2, -4.625929269271486e-19),
+    Double(2.3809523809523808e-2, 1.32169407693471e-18),
+    Double(-3.333333333333333e-2, -4.625929269271486e-19),
+    Double(7.575757575757576e-2, -2.10269512239613e-18),
+    Double(-2.531135531135531e-1, -1.1061562736192037e-17),
+    Double(1.1666666666666667e0, -7.401486830834377e-17),
+    Double(-7.092156862745098e0, -3.274069468698501e-16),
+    Double(5.4971177944862156e1, -1.9588897477095493e-16),
+    Double(-5.291242424242424e2, 6.890111377067638e-16),
+    Double(6.192123188405797e3, 9.226757844073186e-14),
+    Double(-8.658025311355312e4, 3.5926706461242705e-12),
+    Double(1.4255171666666667e6, -7.761021455128987e-11),
+    Double(-2.7298231067816094e7, 1.610010519795034e-9),
+    Double(6.015808739006424e8, -2.6635227381825164e-8),
+    Double(-1.5116315767092157e10, 5.011465035232843e-7),
+    Double(4.296146430611667e11, -2.0345052083333332e-5),
+    Double(-1.3711655205088332e13, -7.409090879485616e-4),
+    Double(4.883323189735932e14, -2.0833333333333332e-2),
+    Double(-1.9296579341940068e16, -1.4863266814486326e-1),
+    Double(8.416930475736826e17, 5.5000553709856035e1),
+    Double(-4.0338071854059454e19, -9.490768115942029e2),
+    Double(2.1150748638081993e21, -1.0164785460992908e5),
+    Double(-1.2086626522296526e23, 2.6786766880629174e6),
+    Double(7.500866746076964e24, 2.0081412007575756e8),
+];
@@ -61,6 +61,151 @@ impl PartialOrd for Double {
     }
 }
 
+// COMPARISONS WITH PRIMITIVES
+//
+// The primitive is converted exactly into a `Double` and then compared with the usual
+// operators. Integers convert exactly through `From`, but `f64`s have to go straight into
+// the first component because `From<f64>` rounds to the closest decimal representation.
+
+#[inline]
+fn from_f64(x: f64) -> Double {
+    Double(x, 0.0)
+}
+
+macro_rules! prim_comp_impl {
+    ($(
+        $(#[$m:meta])*
+        $t:ty, $conv:path;
+    )*) => ($(
+        impl PartialEq<$t> for Double {
+            $(#[$m])*
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                *self == $conv(*other)
+            }
+        }
+
+        impl PartialOrd<$t> for Double {
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                self.partial_cmp(&$conv(*other))
+            }
+        }
+
+        impl PartialEq<Double> for $t {
+            #[inline]
+            fn eq(&self, other: &Double) -> bool {
+                $conv(*self) == *other
+            }
+        }
+
+        impl PartialOrd<Double> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &Double) -> Option<Ordering> {
+                $conv(*self).partial_cmp(other)
+            }
+        }
+    )*);
+}
+
+prim_comp_impl! {
+    /// Compares a `Double` with an `f64`, in either order, with `==`, `!=`, `<`, `>`, `<=`,
+    /// and `>=`.
+    ///
+    /// The comparison uses the exact value of the `f64`, not the value closest to its
+    /// decimal representation that [`From`] would give. It otherwise works just like
+    /// comparing two `Double`s, so `NaN` isn't equal to or ordered with anything.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI > 3.0);
+    /// assert!(3.0 < Double::PI);
+    /// assert!(dd!(0.5) == 0.5);
+    /// assert!(dd!("0.1") != 0.1);
+    /// assert!(!(Double::NAN <= f64::INFINITY));
+    /// ```
+    ///
+    /// [`From`]: struct.Double.html#impl-From<f64>
+    f64, from_f64;
+    /// Compares a `Double` with an `i32`, in either order, with `==`, `!=`, `<`, `>`, `<=`,
+    /// and `>=`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI > 3);
+    /// assert!(4 > Double::PI);
+    /// assert!(dd!(-7) == -7);
+    /// ```
+    i32, Double::from;
+    /// Compares a `Double` with an `i64`, in either order, with `==`, `!=`, `<`, `>`, `<=`,
+    /// and `>=`.
+    ///
+    /// All `i64`s can be compared exactly, even those too large to fit into an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let n = i64::MAX;
+    /// assert!(dd!(n) == n);
+    /// assert!(dd!(n) > n - 1);
+    /// assert!(Double::from(n as f64) > n);
+    /// ```
+    i64, Double::from;
+}
+
+impl Double {
+    /// Returns the ordering between the `Double` and another according to the totalOrder
+    /// predicate defined in IEEE 754.
+    ///
+    /// Unlike [`partial_cmp`], this always produces an ordering, even when `NaN` is
+    /// involved. Values are ordered as follows:
+    ///
+    /// * negative `NaN`
+    /// * [`NEG_INFINITY`]
+    /// * negative numbers
+    /// * [`NEG_ZERO`]
+    /// * [`ZERO`]
+    /// * positive numbers
+    /// * [`INFINITY`]
+    /// * positive `NaN`
+    ///
+    /// Any two numbers that are equal according to `==` are also equal here, except for
+    /// zeros of opposite sign. The signs of zeros and `NaN`s are taken from their first
+    /// components.
+    ///
+    /// This makes `total_cmp` suitable for sorting slices that may contain `NaN`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut v = [Double::NAN, dd!(1), Double::ZERO, Double::NEG_ZERO];
+    /// v.sort_by(|a, b| a.total_cmp(b));
+    ///
+    /// assert!(v[0].is_zero() && v[0].is_sign_negative());
+    /// assert!(v[1].is_zero() && v[1].is_sign_positive());
+    /// assert!(v[2] == dd!(1));
+    /// assert!(v[3].is_nan());
+    /// ```
+    ///
+    /// [`partial_cmp`]: #method.partial_cmp
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NEG_ZERO`]: #associatedconstant.NEG_ZERO
+    /// [`ZERO`]: #associatedconstant.ZERO
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn total_cmp(&self, other: &Double) -> Ordering {
+        match self.0.total_cmp(&other.0) {
+            // The remaining components of a finite, non-zero number are never `NaN`, and
+            // the signs of any zeros among them don't mean anything
+            Ordering::Equal if self.0.is_finite() && self.0 != 0.0 => {
+                self.1.partial_cmp(&other.1).unwrap_or(Ordering::Equal)
+            }
+            x => x,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +275,85 @@ mod tests {
         gte_nan_nan:
             !(Double::NAN >= Double::NAN);
     );
+
+    // total_cmp tests
+    test_all_assert!(
+        total_cmp_pi_e:
+            Double::PI.total_cmp(&Double::E) == Ordering::Greater;
+        total_cmp_e_pi:
+            Double::E.total_cmp(&Double::PI) == Ordering::Less;
+        total_cmp_pi_pi:
+            Double::PI.total_cmp(&Double::PI) == Ordering::Equal;
+        total_cmp_low_component:
+            Double::PI.next_up().total_cmp(&Double::PI) == Ordering::Greater;
+        total_cmp_neg_low_component:
+            (-Double::PI).next_down().total_cmp(&-Double::PI) == Ordering::Less;
+        total_cmp_zero_neg_zero:
+            Double::ZERO.total_cmp(&Double::NEG_ZERO) == Ordering::Greater;
+        total_cmp_neg_zero_zero:
+            Double::NEG_ZERO.total_cmp(&Double::ZERO) == Ordering::Less;
+        total_cmp_inf_max:
+            Double::INFINITY.total_cmp(&Double::MAX) == Ordering::Greater;
+        total_cmp_neg_inf_min:
+            Double::NEG_INFINITY.total_cmp(&Double::MIN) == Ordering::Less;
+        total_cmp_nan_inf:
+            Double::NAN.total_cmp(&Double::INFINITY) == Ordering::Greater;
+        total_cmp_neg_nan_neg_inf:
+            (-Double::NAN).total_cmp(&Double::NEG_INFINITY) == Ordering::Less;
+        total_cmp_nan_nan:
+            Double::NAN.total_cmp(&Double::NAN) == Ordering::Equal;
+        total_cmp_neg_nan_nan:
+            (-Double::NAN).total_cmp(&Double::NAN) == Ordering::Less;
+    );
+    test!(total_cmp_sort: {
+        let mut v = [
+            Double::NAN,
+            Double::PI,
+            -Double::NAN,
+            Double::ZERO,
+            Double::NEG_INFINITY,
+            -Double::E,
+            Double::NEG_ZERO,
+            Double::INFINITY,
+        ];
+        v.sort_by(|a, b| a.total_cmp(b));
+
+        assert!(v[0].is_nan() && v[0].is_sign_negative());
+        assert!(v[1] == Double::NEG_INFINITY);
+        assert!(v[2] == -Double::E);
+        assert!(v[3].is_zero() && v[3].is_sign_negative());
+        assert!(v[4].is_zero() && v[4].is_sign_positive());
+        assert!(v[5] == Double::PI);
+        assert!(v[6] == Double::INFINITY);
+        assert!(v[7].is_nan() && v[7].is_sign_positive());
+    });
+
+    // primitive comparison tests
+    test_all_assert!(
+        eq_f64:
+            dd!(1.5) == 1.5 && 1.5 == dd!(1.5);
+        eq_f64_exact:
+            dd!("0.1") != 0.1 && Double::from(0.1) != 0.1;
+        eq_f64_zero:
+            Double::NEG_ZERO == 0.0;
+        ord_f64:
+            Double::PI > 3.0 && Double::PI < 4.0 && 3.1 < Double::PI && 3.2 >= Double::PI;
+        ord_f64_low_component:
+            Double::ONE.next_up() > 1.0 && Double::ONE.next_down() < 1.0;
+        ord_f64_inf:
+            Double::MAX < f64::INFINITY && f64::NEG_INFINITY <= Double::NEG_INFINITY;
+        nan_f64:
+            Double::NAN.is_nan()
+                && Double::NAN.partial_cmp(&f64::NAN).is_none()
+                && Double::NAN.partial_cmp(&1.0).is_none()
+                && 1f64.partial_cmp(&Double::NAN).is_none();
+        eq_i32:
+            dd!(-3) == -3 && i32::MIN == dd!(i32::MIN);
+        ord_i32:
+            Double::E > 2 && 3 > Double::E && Double::E >= 2i32;
+        eq_i64:
+            dd!(i64::MAX) == i64::MAX && i64::MIN == dd!(i64::MIN);
+        ord_i64:
+            dd!(i64::MAX) > i64::MAX - 1 && Double::from(i64::MAX as f64) > i64::MAX;
+    );
 }
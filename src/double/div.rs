@@ -35,21 +35,12 @@ impl Div for Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    #[inline]
     fn div(self, other: Double) -> Double {
-        match self.pre_div(&other) {
-            Some(r) => r,
-            None => {
-                let q1 = self.0 / other.0;
-                let mut r = self - mul_f64(other, q1);
-
-                let q2 = r.0 / other.0;
-                r -= mul_f64(other, q2);
-
-                let q3 = r.0 / other.0;
-
-                let (a, b) = u::renorm3(q1, q2, q3);
-                Double(a, b)
-            }
+        if cfg!(feature = "fast_arith") {
+            self.div_fast(other)
+        } else {
+            self.div_accurate(other)
         }
     }
 }
@@ -168,7 +159,118 @@ impl DivAssign<&Double> for Double {
     }
 }
 
+impl DivAssign<f64> for Double {
+    /// Divides this `Double` by an `f64`, modifying this one to equal the result.
+    ///
+    /// This implements the `/=` operator between a `Double` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = Double::PI;
+    /// x /= 2.0;
+    /// let expected = dd!("1.570796326794896619231321691639");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: f64) {
+        let r = self.div(Double(other, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+    }
+}
+
 impl Double {
+    /// Divides this `Double` by another using the accurate division algorithm.
+    ///
+    /// This is the algorithm used by the `/` operator, unless the `fast_arith` feature is
+    /// enabled. It's long division with `f64` digits: each step divides the high component
+    /// of the remainder by the high component of the divisor and subtracts that multiple of
+    /// the divisor, and three such quotients are renormalized into the result.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::E.div_accurate(Double::PI);
+    /// let expected = dd!("0.8652559794322650872177747896461");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    pub fn div_accurate(self, other: Double) -> Double {
+        match self.pre_div(&other) {
+            Some(r) => r,
+            None => {
+                let q1 = self.0 / other.0;
+                let mut r = self.add_accurate(-mul_f64(other, q1));
+
+                let q2 = r.0 / other.0;
+                r = r.add_accurate(-mul_f64(other, q2));
+
+                let q3 = r.0 / other.0;
+
+                let (a, b) = u::renorm3(q1, q2, q3);
+                Double(a, b)
+            }
+        }
+    }
+
+    /// Divides this `Double` by another using the fast division algorithm.
+    ///
+    /// This does the same long division as [`div_accurate`] but stops after two quotients
+    /// instead of three, which saves a multiplication and a subtraction. The last bit or
+    /// two of the result can differ from the one `div_accurate` produces.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::E.div_fast(Double::PI);
+    /// let expected = dd!("0.8652559794322650872177747896461");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`div_accurate`]: #method.div_accurate
+    pub fn div_fast(self, other: Double) -> Double {
+        match self.pre_div(&other) {
+            Some(r) => r,
+            None => {
+                let q1 = self.0 / other.0;
+                let r = self.add_fast(-mul_f64(other, q1));
+                let q2 = r.0 / other.0;
+
+                let (a, b) = u::renorm2(q1, q2);
+                Double(a, b)
+            }
+        }
+    }
+
+    /// Divides this `Double` by another, returning `None` if the result is not finite.
+    ///
+    /// This mirrors the `checked_div` methods of the primitive integer types. The result is
+    /// `None` if the divisor is zero, the quotient overflows to infinity, or the result is
+    /// `NaN`; this is always the case when the dividend is infinite or either argument is
+    /// `NaN`. A finite number divided by infinity is zero, so it does return a result.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.checked_div(Double::E).is_some());
+    /// assert!(Double::ONE.checked_div(Double::ZERO).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_div(self, other: Double) -> Option<Double> {
+        let r = self / other;
+        if r.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
     // precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -337,6 +439,11 @@ mod tests {
             b /= &Double::E;
             near!(dd!("1.1557273497909217179100931833126961"), b);
         }
+        assign_f64: {
+            let mut c = Double::PI;
+            c /= 2.0;
+            near!(dd!("1.5707963267948966192313216916397514"), c);
+        }
     );
 
     test!(chain_tens: {
@@ -403,4 +510,12 @@ mod tests {
         value /= ten;
         near!("6.9314718055994530941723212145818e-30", value);
     });
+
+    // checked_div tests
+    test_all_assert!(
+        checked_div_num: Double::PI.checked_div(Double::E) == Some(Double::PI / Double::E);
+        checked_div_nan: Double::PI.checked_div(Double::NAN).is_none();
+        checked_div_inf: Double::INFINITY.checked_div(Double::PI).is_none();
+        checked_div_overflow: Double::ONE.checked_div(Double::ZERO).is_none();
+    );
 }
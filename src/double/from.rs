@@ -5,6 +5,7 @@
 
 use crate::common::utils as u;
 use crate::double::Double;
+use crate::error::InexactIntError;
 use std::f64;
 
 #[inline]
@@ -71,6 +72,7 @@ from_int_impl! {
     /// # use qd::Double;
     /// let x = -128i8;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "-128");
     /// ```
     i8
@@ -81,6 +83,7 @@ from_int_impl! {
     /// # use qd::Double;
     /// let x = 255u8;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "255");
     /// ```
     u8
@@ -91,6 +94,7 @@ from_int_impl! {
     /// # use qd::Double;
     /// let x = -32768i16;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "-32768");
     /// ```
     i16
@@ -101,6 +105,7 @@ from_int_impl! {
     /// # use qd::Double;
     /// let x = 65535u16;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "65535");
     /// ```
     u16
@@ -111,6 +116,7 @@ from_int_impl! {
     /// # use qd::Double;
     /// let x = -2_147_483_648i32;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "-2147483648");
     /// ```
     i32
@@ -121,6 +127,7 @@ from_int_impl! {
     /// # use qd::Double;
     /// let x = 4_294_967_295u32;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "4294967295");
     /// ```
     u32
@@ -137,6 +144,7 @@ impl From<u64> for Double {
     /// # use qd::Double;
     /// let x = 18_446_744_073_709_551_615u64;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "18446744073709551615");
     /// ```
     fn from(a: u64) -> Double {
@@ -152,6 +160,7 @@ impl From<i64> for Double {
     /// # use qd::Double;
     /// let x = -9_223_372_036_854_775_808i64;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "-9223372036854775808");
     /// ```
     fn from(a: i64) -> Double {
@@ -226,11 +235,13 @@ from_float_impl! {
     /// // Exactly representable in binary
     /// let x = 0.9921875f32;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "0.9921875");
     ///
     /// // Xot exactly representable in binary
     /// let x = 0.9921876f32;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "0.9921876");
     /// ```
     f32
@@ -252,11 +263,13 @@ from_float_impl! {
     /// // Exactly representable in binary
     /// let x = 0.999969482421875f64;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "0.999969482421875");
     ///
     /// // Not exactly representable in binary
     /// let x = 0.999969482421876f64;
     /// let a = Double::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "0.999969482421876");
     /// ```
     f64
@@ -277,7 +290,7 @@ impl From<(f64, f64)> for Double {
     /// # Examples
     /// ```
     /// # use qd::Double;
-    /// // These are the components used to define Double::PI
+    /// // These are the components of Double::PI
     /// let d = Double::from((3.141592653589793e0, 1.2246467991473532e-16));
     /// assert!(d == Double::PI);
     /// ```
@@ -368,6 +381,61 @@ impl From<Double> for (f64, f64) {
     }
 }
 
+impl Double {
+    /// Converts an integer into a `Double`, returning an error if the `Double` can't hold
+    /// it exactly.
+    ///
+    /// The [`From`] implementations for integers never round, but they don't exist for
+    /// `i128` and `u128`, which can need more precision than a `Double` has. An integer
+    /// converts exactly if it's the sum of two `f64`s, which is always true of integers
+    /// of up to 106 bits and of many larger ones as well. This function
+    /// takes any integer type that converts into an `i128` (including `i64` and `u64`,
+    /// which always succeed) and fails instead of rounding, so it can be used where
+    /// silently losing the low digits of a large integer is unacceptable.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert_eq!(Double::try_from_exact(u64::MAX), Ok(dd!(u64::MAX)));
+    /// assert_eq!(Double::try_from_exact(1i128 << 120), Ok(dd!(2).powi(120)));
+    ///
+    /// // 2^120 - 1 is 2^120 plus -1, but 2^120 + 2^60 + 1 can't be split into two f64s
+    /// assert_eq!(Double::try_from_exact((1i128 << 120) - 1), Ok(dd!(2).powi(120) - dd!(1)));
+    /// assert!(Double::try_from_exact((1i128 << 120) + (1 << 60) + 1).is_err());
+    /// ```
+    ///
+    /// [`From`]: #impl-From<i64>
+    pub fn try_from_exact<T>(n: T) -> Result<Double, InexactIntError>
+    where
+        T: Into<i128>,
+    {
+        let n = n.into();
+        let m = n.unsigned_abs();
+
+        // The high component is the integer rounded to an f64, and the low component is
+        // whatever's left over, which has to fit into an f64 as well
+        let hi = m as f64;
+        let h = hi as u128;
+        let (lo, r) = if h >= m {
+            let r = h - m;
+            (-(r as f64), r)
+        } else {
+            let r = m - h;
+            (r as f64, r)
+        };
+        if lo.abs() as u128 != r {
+            return Err(InexactIntError);
+        }
+
+        let (a, b) = u::renorm2(hi, lo);
+        if n < 0 {
+            Ok(Double(-a, -b))
+        } else {
+            Ok(Double(a, b))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -432,6 +500,7 @@ mod tests {
     });
 
     // integer tests
+    #[cfg(feature = "display")]
     test_all_eq!(
         i8_min: i8::MIN.to_string(), dd!(i8::MIN).to_string();
         u8_max: u8::MAX.to_string(), dd!(u8::MAX).to_string();
@@ -442,4 +511,39 @@ mod tests {
         i64_min: i64::MIN.to_string(), dd!(i64::MIN).to_string();
         u64_max: u64::MAX.to_string(), dd!(u64::MAX).to_string();
     );
+    // try_from_exact tests
+    test_all_exact!(
+        exact_i64_min:
+            dd!(i64::MIN),
+            Double::try_from_exact(i64::MIN).unwrap();
+        exact_u64_max:
+            dd!(u64::MAX),
+            Double::try_from_exact(u64::MAX).unwrap();
+        exact_zero:
+            Double::ZERO,
+            Double::try_from_exact(0).unwrap();
+        exact_i128_min:
+            -dd!(2).powi(127),
+            Double::try_from_exact(i128::MIN).unwrap();
+        exact_106_bits:
+            dd!(2).powi(105) + dd!(2).powi(53) + dd!(1),
+            Double::try_from_exact((1i128 << 105) + (1 << 53) + 1).unwrap();
+        exact_gap:
+            dd!(2).powi(120) - dd!(1),
+            Double::try_from_exact((1i128 << 120) - 1).unwrap();
+        exact_neg_gap:
+            -(dd!(2).powi(120) + dd!(2).powi(70) - dd!(1)),
+            Double::try_from_exact(-((1i128 << 120) + (1 << 70) - 1)).unwrap();
+        exact_i128_max:
+            dd!(2).powi(127) - dd!(1),
+            Double::try_from_exact(i128::MAX).unwrap();
+    );
+    test_all_assert!(
+        inexact_low_bits:
+            Double::try_from_exact((1i128 << 110) + (1 << 55) + 1).is_err();
+        inexact_neg:
+            Double::try_from_exact(-((1i128 << 110) + (1 << 55) + 1)) == Err(InexactIntError);
+        inexact_i128_min_plus_one:
+            Double::try_from_exact(i128::MIN + (1 << 70) + 1).is_err();
+    );
 }
@@ -123,10 +123,10 @@ impl FromStr for Double {
                     // not overflow, but doing the -337 all at once WOULD overflow
                     if exp < -307 {
                         let adjust = exp + 307;
-                        result *= TEN.powi(adjust);
+                        result *= TEN.powi(adjust.into());
                         exp -= adjust;
                     }
-                    result *= TEN.powi(exp);
+                    result *= TEN.powi(exp.into());
                 }
                 if sign == -1 {
                     result = -result;
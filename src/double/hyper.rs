@@ -159,6 +159,9 @@ impl Double {
     ///
     /// The domain and range of this function are both (-∞, ∞).
     ///
+    /// Arguments near zero are handled with [`ln_1p`] rather than by taking the logarithm
+    /// of a number near 1, so they keep their full precision.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -168,10 +171,32 @@ impl Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    ///
+    /// [`ln_1p`]: #method.ln_1p
     pub fn asinh(self) -> Double {
         match self.pre_asinh() {
             Some(r) => r,
-            None => (self + (self.sqr() + Double::ONE).sqrt()).ln(),
+            None => {
+                // Working with |x| keeps the function odd, and it avoids the cancellation
+                // that x + √(x² + 1) suffers when x is negative
+                let a = self.abs();
+                let r = if a.0 > 1e17 {
+                    // √(x² + 1) is x to full precision here (and x² might overflow), so
+                    // this is ln 2x, with the logarithm taken in base 2 because that's
+                    // accurate over the whole range of `Double`s
+                    (a.log2() + Double::ONE) * Double::LN_2
+                } else if a.0 > 2.0 {
+                    (c::mul_pwr2(a, 2.0) + (a + (a.sqr() + Double::ONE).sqrt()).recip()).ln()
+                } else {
+                    let a2 = a.sqr();
+                    (a + a2 / (Double::ONE + (a2 + Double::ONE).sqrt())).ln_1p()
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
         }
     }
 
@@ -180,6 +205,9 @@ impl Double {
     /// The domain of the function is [1, ∞) and the range is [0, ∞). Any argument outside
     /// the range will result in [`NAN`].
     ///
+    /// Arguments near 1 are handled with [`ln_1p`], so the result keeps its full precision
+    /// even though it's close to zero.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -190,11 +218,24 @@ impl Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     ///
+    /// [`ln_1p`]: #method.ln_1p
     /// [`NAN`]: #associatedconstant.NAN
     pub fn acosh(self) -> Double {
         match self.pre_acosh() {
             Some(r) => r,
-            None => (self + (self.sqr() - Double::ONE).sqrt()).ln(),
+            None => {
+                if self.0 > 1e17 {
+                    // As in asinh, √(x² - 1) is x to full precision here
+                    (self.log2() + Double::ONE) * Double::LN_2
+                } else if self.0 > 2.0 {
+                    let r = (self + (self.sqr() - Double::ONE).sqrt()).recip();
+                    (c::mul_pwr2(self, 2.0) - r).ln()
+                } else {
+                    // Subtracting 1 is exact in this range, and x² - 1 = t(t + 2)
+                    let t = self - Double::ONE;
+                    (t + (c::mul_pwr2(t, 2.0) + t.sqr()).sqrt()).ln_1p()
+                }
+            }
         }
     }
 
@@ -203,6 +244,9 @@ impl Double {
     /// The domain of the function is (-1, 1) and the range is (-∞, ∞). Any argument whose
     /// absolute value is greater than or equal to 1 will result in [`NAN`].
     ///
+    /// The logarithm is calculated with [`ln_1p`] so that arguments near zero keep their
+    /// full precision.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -213,11 +257,76 @@ impl Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     ///
+    /// [`ln_1p`]: #method.ln_1p
     /// [`NAN`]: #associatedconstant.NAN
     pub fn atanh(self) -> Double {
         match self.pre_atanh() {
             Some(r) => r,
-            None => c::mul_pwr2(((Double::ONE + self) / (Double::ONE - self)).ln(), 0.5),
+            None => {
+                // (1 + x) / (1 - x) = 1 + 2x / (1 - x), and leaving out the 1 keeps all of
+                // the digits of small arguments
+                let a = self.abs();
+                let r = c::mul_pwr2((c::mul_pwr2(a, 2.0) / (Double::ONE - a)).ln_1p(), 0.5);
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Calculates the Gudermannian function (gd) of the `Double`.
+    ///
+    /// The Gudermannian function relates the hyperbolic functions to the circular ones
+    /// without using complex numbers. It's calculated as 2 tan<sup>-1</sup>(tanh(x / 2)),
+    /// which is accurate for small arguments and doesn't overflow for large ones.
+    ///
+    /// The domain of the function is (-∞, ∞) and the range is (-π/2, π/2).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1).gd();
+    /// let expected = dd!("0.86576948323965862428960184619184");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    pub fn gd(self) -> Double {
+        match self.pre_gd() {
+            Some(r) => r,
+            None => c::mul_pwr2(c::mul_pwr2(self, 0.5).tanh().atan(), 2.0),
+        }
+    }
+
+    /// Calculates the inverse Gudermannian function (gd<sup>-1</sup>) of the `Double`.
+    ///
+    /// This is calculated as 2 tanh<sup>-1</sup>(tan(x / 2)), with the inverse hyperbolic
+    /// tangent evaluated through [`ln_1p`] so that small arguments don't lose precision.
+    ///
+    /// The domain of the function is [-π/2, π/2] and the range is (-∞, ∞). Any argument
+    /// whose absolute value is greater than π/2 will result in [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(0.5).agd();
+    /// let expected = dd!("0.52223810327844033018988714493645");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn agd(self) -> Double {
+        match self.pre_agd() {
+            Some(r) => r,
+            None => {
+                let t = c::mul_pwr2(self, 0.5).tan();
+                (c::mul_pwr2(t, 2.0) / (Double::ONE - t)).ln_1p()
+            }
         }
     }
 
@@ -316,6 +425,35 @@ impl Double {
             None
         }
     }
+
+    #[inline]
+    fn pre_gd(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if self.abs().0 > 80.0 {
+            // Past this point the result rounds to ±π/2, and tanh would overflow anyway
+            Some(if self.is_sign_negative() {
+                -Double::FRAC_PI_2
+            } else {
+                Double::FRAC_PI_2
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_agd(&self) -> Option<Double> {
+        if self.is_nan() || self.abs() > Double::FRAC_PI_2 {
+            Some(Double::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -647,11 +785,11 @@ mod tests {
         asinh_small:
             dd!("0.0000099999999998333333333408333333328982"),
             dd!("0.00001").asinh(),
-            26;
+            30;
         asinh_neg_small:
             dd!("-0.000099999999833333334083333328869047707"),
             dd!("-0.0001").asinh(),
-            28;
+            30;
     );
     test_all_exact!(
         asinh_zero:
@@ -670,6 +808,23 @@ mod tests {
             Double::NAN,
             Double::NAN.asinh();
     );
+    test_all_near!(
+        asinh_tiny:
+            dd!("9.99999999999999999999999999999999999999983333333333333333333333333e-21"),
+            dd!("1e-20").asinh();
+        asinh_2_neg_30:
+            dd!("9.31322574615478515490367738842280651928947017270585123601615767555e-10"),
+            Double::ONE.scale_b(-30).asinh();
+        asinh_3:
+            dd!("1.81844645923206682348369896356070899378625394276812161745174416723"),
+            dd!(3).asinh();
+        asinh_2_60:
+            dd!("42.2819780141566638744511594089487706527935872921071365026364784251"),
+            Double::ONE.scale_b(60).asinh();
+        asinh_neg_2_1000:
+            dd!("-693.840327740505254726649353579634744643575634494615509374800689503"),
+            (-Double::ONE.scale_b(1000)).asinh();
+    );
 
     // acosh tests
     test_all_near!(
@@ -693,8 +848,10 @@ mod tests {
             dd!(150).acosh();
     );
     test_all_prec!(
+        // The expected value is for the `Double` closest to 1.00001, since the derivative
+        // near 1 is large enough to magnify the difference between the two
         acosh_small:
-            dd!("0.0044721322282280021231284466331917651"),
+            dd!("0.0044721322282280021231284466345698454"),
             dd!("1.00001").acosh(),
             28;
     );
@@ -727,6 +884,20 @@ mod tests {
             Double::NAN,
             Double::NAN.acosh();
     );
+    test_all_near!(
+        acosh_near_one:
+            dd!("1.31708901596543849324758086642015086470826592095507955176675904932e-9"),
+            Double::new(1.0, 2f64.powi(-60)).acosh();
+        acosh_1_25:
+            dd!("0.693147180559945309417232121458176568075500134360255254120680009493"),
+            dd!(1.25).acosh();
+        acosh_2_60:
+            dd!("42.2819780141566638744511594089487706524174290998440045000864827331"),
+            Double::ONE.scale_b(60).acosh();
+        acosh_2_1000:
+            dd!("693.840327740505254726649353579634744643575634494615509374800689503"),
+            Double::ONE.scale_b(1000).acosh();
+    );
 
     // atanh tests
     test_all_near!(
@@ -778,4 +949,99 @@ mod tests {
             Double::NAN,
             Double::NAN.atanh();
     );
+    test_all_near!(
+        atanh_tiny:
+            dd!("1.00000000000000000000000000000000000000003333333333333333333333333e-20"),
+            dd!("1e-20").atanh();
+        atanh_neg_2_neg_40:
+            dd!("-9.09494701772928237915039313272128175421335033330585734384635157483e-13"),
+            (-Double::ONE.scale_b(-40)).atanh();
+        atanh_near_one:
+            dd!("21.1409890070783319370087392699772844394542437637809054080632401938"),
+            Double::new(1.0, -2f64.powi(-60)).atanh();
+        atanh_half:
+            dd!("0.549306144334054845697622618461262852323745278911374725867347166819"),
+            dd!(0.5).atanh();
+    );
+
+    // gd tests
+    test_all_near!(
+        gd_one:
+            dd!("0.865769483239658624289601846191844441379679199248760099611848229742"),
+            dd!(1).gd();
+        gd_neg_two_and_a_half:
+            dd!("-1.40699356893615375337343081917136154516563210423769234994075512891"),
+            dd!(-2.5).gd();
+        gd_small:
+            dd!("9.76562344779607904844149783145849353952794402901433691196860510047e-4"),
+            dd!(0.0009765625).gd();
+        gd_forty:
+            dd!("1.57079632679489661073461318105657345144011513397023692584599501263"),
+            dd!(40).gd();
+        gd_agd_round_trip:
+            dd!("0.75"),
+            dd!(0.75).gd().agd();
+    );
+    test_all_exact!(
+        gd_zero:
+            Double::ZERO,
+            Double::ZERO.gd();
+        gd_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.gd();
+        gd_large:
+            Double::FRAC_PI_2,
+            dd!(1000).gd();
+        gd_neg_large:
+            -Double::FRAC_PI_2,
+            dd!(-1000).gd();
+        gd_inf:
+            Double::FRAC_PI_2,
+            Double::INFINITY.gd();
+        gd_neg_inf:
+            -Double::FRAC_PI_2,
+            Double::NEG_INFINITY.gd();
+        gd_nan:
+            Double::NAN,
+            Double::NAN.gd();
+    );
+
+    // agd tests
+    test_all_near!(
+        agd_half:
+            dd!("0.522238103278440330189887144936448275399289926251707283432438885734"),
+            dd!(0.5).agd();
+        agd_one_and_a_half:
+            dd!("3.34067754279831100332081266903768876035632219977707950293668182396"),
+            dd!(1.5).agd();
+        agd_neg_quarter:
+            dd!("-0.25264561035786753829411005567842654758447013504231964097018810803"),
+            dd!(-0.25).agd();
+        agd_small:
+            dd!("9.31322574615478515759632261157719348152795393148362538849160228164e-10"),
+            dd!(2).powi(-30).agd();
+    );
+    test_all_exact!(
+        agd_zero:
+            Double::ZERO,
+            Double::ZERO.agd();
+        agd_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.agd();
+        agd_two:
+            Double::NAN,
+            dd!(2).agd();
+        agd_neg_two:
+            Double::NAN,
+            dd!(-2).agd();
+        agd_inf:
+            Double::NAN,
+            Double::INFINITY.agd();
+        agd_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.agd();
+        agd_nan:
+            Double::NAN,
+            Double::NAN.agd();
+    );
 }
@@ -9,7 +9,8 @@
 // https://opensource.org/licenses/MIT
 
 use crate::double::Double;
-use std::iter::{Product, Sum};
+use crate::expansion;
+use std::iter::{FromIterator, Product, Sum};
 
 impl Sum for Double {
     /// Sums all of the values in an iterator of `Double`s.
@@ -91,6 +92,74 @@ impl<'a> Product<&'a Double> for Double {
     }
 }
 
+impl FromIterator<f64> for Double {
+    /// Sums all of the values in an iterator of `f64`s, exactly.
+    ///
+    /// Unlike summing the `f64`s one at a time, which rounds after every addition, this
+    /// accumulates them in an [expansion] that holds their sum exactly and only rounds
+    /// once, at the end. The result is the correctly rounded sum no matter how many `f64`s
+    /// there are or how much they cancel each other out. A partial sum that's too large
+    /// for an `f64` doesn't matter as long as the whole sum isn't, and if the whole sum is,
+    /// the result is the infinity of its sign.
+    ///
+    /// If any of the `f64`s is infinite or `NaN`, the result is the same as it would be for
+    /// the `+` operator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let readings = vec![1e30, 1.0, 1e-30, -1e30];
+    /// let sum: Double = readings.iter().copied().collect();
+    /// assert_eq!(sum, Double::new(1.0, 1e-30));
+    /// ```
+    ///
+    /// [expansion]: crate::expansion
+    fn from_iter<I>(iter: I) -> Double
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        // Non-finite values can't go into an expansion, so they're summed separately
+        let mut special = 0.0;
+        let e = expansion::sum_f64s(iter.into_iter().filter(|&x| {
+            let finite = x.is_finite();
+            if !finite {
+                special += x;
+            }
+            finite
+        }));
+        // A sum that overflows is an infinity like any other
+        let top = e[e.len() - 1];
+        if top.is_infinite() {
+            special += top;
+        }
+        if special.is_finite() {
+            expansion::to_double(&e)
+        } else {
+            Double(special, 0.0)
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a f64> for Double {
+    /// Sums all of the referenced values in an iterator of `f64`s, exactly.
+    ///
+    /// This is the same as collecting the `f64`s themselves.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let readings = vec![1e30, 1.0, 1e-30, -1e30];
+    /// let sum: Double = readings.iter().collect();
+    /// assert_eq!(sum, Double::new(1.0, 1e-30));
+    /// ```
+    fn from_iter<I>(iter: I) -> Double
+    where
+        I: IntoIterator<Item = &'a f64>,
+    {
+        iter.into_iter().copied().collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,4 +237,46 @@ mod tests {
             Double::NAN,
             vec![dd!(1), dd!(2), Double::NAN].iter().product::<Double>();
     );
+
+    // from_iter tests
+    test_all_near!(
+        from_iter_tenths:
+            dd!("1.000000000000000055511151231257827"),
+            vec![0.1; 10].into_iter().collect::<Double>();
+    );
+    test_all_exact!(
+        from_iter_cancel:
+            Double::new(1.0, 1e-30),
+            vec![1e30, 1.0, 1e-30, -1e30].into_iter().collect::<Double>();
+        from_iter_refs:
+            Double::new(1.0, 1e-30),
+            [1e30, 1.0, 1e-30, -1e30].iter().collect::<Double>();
+        from_iter_empty:
+            Double::ZERO,
+            Vec::<f64>::new().into_iter().collect::<Double>();
+        from_iter_inf:
+            Double::INFINITY,
+            vec![1.0, f64::INFINITY, 3.0].into_iter().collect::<Double>();
+        from_iter_both_inf:
+            Double::NAN,
+            vec![f64::INFINITY, 1.0, f64::NEG_INFINITY].into_iter().collect::<Double>();
+        from_iter_nan:
+            Double::NAN,
+            vec![1.0, f64::NAN].into_iter().collect::<Double>();
+        from_iter_partial_overflow:
+            Double::new(1e308, 0.0),
+            vec![1e308, 1e308, -1e308].into_iter().collect::<Double>();
+        from_iter_partial_overflow_tiny:
+            Double::new(-1e308, 1e-300),
+            vec![-1e308, 1e-320, -1e308, 1e-300, 1e308].into_iter().collect::<Double>();
+        from_iter_overflow:
+            Double::INFINITY,
+            vec![1e308, 1e308, 1.0].into_iter().collect::<Double>();
+        from_iter_neg_overflow:
+            Double::NEG_INFINITY,
+            vec![-1e308, 1e300, -1e308].into_iter().collect::<Double>();
+        from_iter_overflow_inf:
+            Double::NAN,
+            vec![1e308, 1e308, f64::NEG_INFINITY].into_iter().collect::<Double>();
+    );
 }
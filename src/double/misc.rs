@@ -3,11 +3,43 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::fraction;
 use crate::common::utils as u;
 use crate::double::Double;
+use crate::precision::AbsRel;
+use std::convert::TryFrom;
 use std::f64;
 use std::num::FpCategory;
 
+// The smallest positive subnormal `f64`, 2^-1074.
+const MIN_SUBNORMAL: f64 = 5e-324;
+
+const HALF: Double = Double(0.5, 0.0);
+
+// Converts an `i128` into a `Double`, rounding it if it has more than 106 significant bits.
+fn from_i128(n: i128) -> Double {
+    let high = Double::from((n >> 64) as i64).mul_pwr2(18_446_744_073_709_551_616.0);
+    high + Double::from(n as u64)
+}
+
+// 2^127, the magnitude of the smallest `i128`.
+const I128_LIMIT: f64 = 1.7014118346046923e38;
+
+// Returns 10^n, correctly rounded. Up to 10^45, 5^n fits into the 106 bits of a `Double`'s
+// mantissa, so it's calculated exactly as an integer and then scaled by 2^n. Larger
+// powers fall back to `powi`; they only come into play for numbers so small that their
+// digits are already mostly lost to the exponent.
+fn pow10(n: u32) -> Double {
+    if n <= 45 {
+        let five = 5u128.pow(n);
+        let hi = five as f64;
+        let lo = (five as i128 - hi as i128) as f64;
+        Double(hi, lo).ldexp(n as i32)
+    } else {
+        Double(10.0, 0.0).powi(n.into())
+    }
+}
+
 impl Double {
     /// Calculates the absolute value of the `Double`.
     ///
@@ -43,7 +75,7 @@ impl Double {
     pub fn floor(self) -> Double {
         let hi = self.0.floor();
 
-        if (hi - self.0).abs() < f64::EPSILON {
+        if hi == self.0 && hi.is_finite() {
             let (a, b) = u::renorm2(hi, self.1.floor());
             Double(a, b)
         } else {
@@ -68,7 +100,7 @@ impl Double {
     pub fn ceil(self) -> Double {
         let hi = self.0.ceil();
 
-        if (hi - self.0).abs() < f64::EPSILON {
+        if hi == self.0 && hi.is_finite() {
             let (a, b) = u::renorm2(hi, self.1.ceil());
             Double(a, b)
         } else {
@@ -92,16 +124,51 @@ impl Double {
     /// ```
     #[inline]
     pub fn round(self) -> Double {
-        let hi = self.0.round();
+        if !self.is_finite() {
+            self
+        } else {
+            // Working from the floor and the distance above it (which is calculated
+            // exactly) takes the lower components into account, which matters when the
+            // leading component alone is exactly halfway between two integers
+            let f = self.floor();
+            let d = self - f;
+            if d > HALF || (d == HALF && self.is_sign_positive()) {
+                (f + Double::ONE).copysign(self)
+            } else {
+                f.copysign(self)
+            }
+        }
+    }
 
-        if (hi - self.0).abs() < f64::EPSILON {
-            let lo = self.1.round();
-            let (a, b) = u::renorm2(hi, lo);
-            Double(a, b)
-        } else if ((hi - self.0).abs() - 0.5).abs() < f64::EPSILON && self.1 < 0.0 {
-            Double(hi - 1.0, 0.0)
+    /// Returns the nearest integer value to the `Double`. Half-way cases are rounded to the
+    /// nearest even integer, per the behavior of `f64`'s `round_ties_even` method.
+    ///
+    /// This is the rounding mode used by default in IEEE 754 arithmetic, and unlike
+    /// [`round`] it doesn't introduce a bias when rounding a large number of half-way
+    /// values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(2.5).round_ties_even() == dd!(2));
+    /// assert!(dd!(3.5).round_ties_even() == dd!(4));
+    /// assert!(dd!(-2.5).round_ties_even() == dd!(-2));
+    /// assert!(dd!(2.6).round_ties_even() == dd!(3));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    pub fn round_ties_even(self) -> Double {
+        if !self.is_finite() {
+            self
         } else {
-            Double(hi, 0.0)
+            let f = self.floor();
+            let d = self - f;
+            let half_f = f.ldexp(-1);
+            if d > HALF || (d == HALF && half_f.floor() != half_f) {
+                (f + Double::ONE).copysign(self)
+            } else {
+                f.copysign(self)
+            }
         }
     }
 
@@ -144,6 +211,236 @@ impl Double {
         self - self.trunc()
     }
 
+    /// Rounds the `Double` to `n` decimal places. Half-way cases are rounded away from
+    /// zero, as with [`round`].
+    ///
+    /// The `Double` is scaled by a correctly rounded power of ten in full double-double
+    /// precision, so this is both faster and more accurate than formatting the number to a
+    /// string and parsing it back. A negative `n` rounds to the left of the decimal point,
+    /// so `round_to_places(-2)` rounds to the nearest hundred.
+    ///
+    /// Since most decimal fractions can't be represented exactly in binary, the result is
+    /// the closest `Double` to the rounded decimal value (to within rounding error), and
+    /// whether a half-way case is recognized as one depends on the binary value that's
+    /// actually stored. Infinities and `NaN` are returned unchanged, as are numbers so
+    /// large that scaling them overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!("12.3456");
+    /// # #[cfg(feature = "display")]
+    /// assert!(x.round_to_places(2).to_string() == "12.35");
+    /// assert!(x.round_to_places(0) == dd!(12));
+    /// assert!(x.round_to_places(-1) == dd!(10));
+    /// # #[cfg(feature = "display")]
+    /// assert!((-x).round_to_places(3).to_string() == "-12.346");
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    pub fn round_to_places(self, n: i32) -> Double {
+        self.to_places(n, Double::round)
+    }
+
+    /// Truncates the `Double` to `n` decimal places, discarding any further digits.
+    ///
+    /// This works like [`round_to_places`], except that the number is rounded toward zero
+    /// rather than to the nearest value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!("12.3456");
+    /// # #[cfg(feature = "display")]
+    /// assert!(x.trunc_to_places(2).to_string() == "12.34");
+    /// assert!(x.trunc_to_places(-1) == dd!(10));
+    /// # #[cfg(feature = "display")]
+    /// assert!((-x).trunc_to_places(3).to_string() == "-12.345");
+    /// ```
+    ///
+    /// [`round_to_places`]: #method.round_to_places
+    pub fn trunc_to_places(self, n: i32) -> Double {
+        self.to_places(n, Double::trunc)
+    }
+
+    /// Returns the largest integer less than or equal to the `Double`, as an `i64`.
+    ///
+    /// All of the components of the `Double` are taken into account, so the result is exact
+    /// even when the leading component alone would round to a different integer (as it
+    /// does for numbers near or above 2<sup>53</sup>). If the result doesn't fit into an
+    /// `i64`, or if the `Double` is infinite or `NaN`, `None` is returned instead of a
+    /// saturated value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(999_999_999_999_999_999i64) + dd!(0.5);
+    /// assert!(x.floor_i64() == Some(999_999_999_999_999_999));
+    /// // The leading component alone is off by one
+    /// assert!(x[0] as i64 == 1_000_000_000_000_000_000);
+    ///
+    /// assert!(dd!(-2.5).floor_i64() == Some(-3));
+    /// assert!(dd!(1e19).floor_i64().is_none());
+    /// assert!(Double::NAN.floor_i64().is_none());
+    /// ```
+    #[inline]
+    pub fn floor_i64(self) -> Option<i64> {
+        self.floor_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    /// Returns the smallest integer greater than or equal to the `Double`, as an `i64`.
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(999_999_999_999_999_998i64) + dd!(0.5);
+    /// assert!(x.ceil_i64() == Some(999_999_999_999_999_999));
+    /// assert!(dd!(-2.5).ceil_i64() == Some(-2));
+    /// assert!(dd!(-1e19).ceil_i64().is_none());
+    /// ```
+    ///
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn ceil_i64(self) -> Option<i64> {
+        self.ceil_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    /// Returns the nearest integer to the `Double`, as an `i64`. Half-way cases are rounded
+    /// away from zero, as with [`round`].
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(999_999_999_999_999_998i64) + dd!(0.5);
+    /// assert!(x.round_i64() == Some(999_999_999_999_999_999));
+    /// assert!(dd!(-2.5).round_i64() == Some(-3));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn round_i64(self) -> Option<i64> {
+        self.round_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    /// Returns the integer part of the `Double`, as an `i64`.
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(999_999_999_999_999_998i64) + dd!(0.5);
+    /// assert!(x.trunc_i64() == Some(999_999_999_999_999_998));
+    /// assert!(dd!(-2.5).trunc_i64() == Some(-2));
+    /// ```
+    ///
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn trunc_i64(self) -> Option<i64> {
+        self.trunc_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    /// Returns the largest integer less than or equal to the `Double`, as an `i128`.
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!("1e30") - dd!(0.5);
+    /// assert!(x.floor_i128() == Some(999_999_999_999_999_999_999_999_999_999));
+    /// assert!(dd!(1e39).floor_i128().is_none());
+    /// ```
+    ///
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn floor_i128(self) -> Option<i128> {
+        self.floor().int_to_i128()
+    }
+
+    /// Returns the smallest integer greater than or equal to the `Double`, as an `i128`.
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!("1e30") + dd!(0.5);
+    /// assert!(x.ceil_i128() == Some(1_000_000_000_000_000_000_000_000_000_001));
+    /// ```
+    ///
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn ceil_i128(self) -> Option<i128> {
+        self.ceil().int_to_i128()
+    }
+
+    /// Returns the nearest integer to the `Double`, as an `i128`. Half-way cases are
+    /// rounded away from zero, as with [`round`].
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!("1e30") + dd!(0.5);
+    /// assert!(x.round_i128() == Some(1_000_000_000_000_000_000_000_000_000_001));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn round_i128(self) -> Option<i128> {
+        self.round().int_to_i128()
+    }
+
+    /// Returns the integer part of the `Double`, as an `i128`.
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = -dd!("1e30") - dd!(0.5);
+    /// assert!(x.trunc_i128() == Some(-1_000_000_000_000_000_000_000_000_000_000));
+    /// ```
+    ///
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn trunc_i128(self) -> Option<i128> {
+        self.trunc().int_to_i128()
+    }
+
+    /// Finds the fraction closest to the `Double` whose denominator is no larger than
+    /// `max_denominator`, as a numerator and a denominator.
+    ///
+    /// The fraction is found from the continued fraction expansion of the number, whose
+    /// convergents (and the semiconvergents between them) are its best rational
+    /// approximations. A number that's exactly a fraction with a small enough denominator
+    /// gives that fraction in lowest terms. This is useful for recovering exact ratios from
+    /// calculated values, as long as the denominator is small enough that the precision of
+    /// the `Double` can tell it apart from its neighbors.
+    ///
+    /// This returns `None` if the number isn't finite, if `max_denominator` is zero, or if
+    /// the numerator doesn't fit into an `i128`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert_eq!(Double::PI.to_fraction(1000), Some((355, 113)));
+    /// assert_eq!(Double::PI.to_fraction(100_000), Some((312_689, 99_532)));
+    /// assert_eq!((dd!(-7) / dd!(12)).to_fraction(100), Some((-7, 12)));
+    /// assert_eq!(Double::NAN.to_fraction(100), None);
+    /// ```
+    pub fn to_fraction(self, max_denominator: u64) -> Option<(i128, u64)> {
+        fraction::best_fraction(self, max_denominator, from_i128)
+    }
+
     /// Returns a number that represents the sign of the `Double`.
     ///
     /// * `1.0` if the number is positive, including `+0.0` and [`INFINITY`]
@@ -155,6 +452,7 @@ impl Double {
     /// # use qd::{dd, Double};
     /// assert!(dd!(3.5).signum() == Double::ONE);
     /// assert!(Double::NEG_INFINITY.signum() == Double::NEG_ONE);
+    /// assert!(Double::NEG_ZERO.signum() == Double::NEG_ONE);
     /// assert!(Double::NAN.signum().is_nan());
     /// ```
     ///
@@ -172,6 +470,53 @@ impl Double {
         }
     }
 
+    /// Returns a `Double` with the magnitude of this one and the sign of `sign`.
+    ///
+    /// This works the same as `f64::copysign`. Only the sign bit of `sign` is used, so a
+    /// `sign` of -0 or of a `NaN` with its sign bit set produces a negative result, and a
+    /// `NaN` `Double` keeps its `NaN`-ness while taking on the new sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3.5).copysign(dd!(-1)) == dd!(-3.5));
+    /// assert!(dd!(-3.5).copysign(dd!(1)) == dd!(3.5));
+    /// assert!(dd!(3.5).copysign(Double::NEG_ZERO) == dd!(-3.5));
+    /// assert!(Double::NAN.copysign(dd!(-1)).is_sign_negative());
+    /// ```
+    #[inline]
+    pub fn copysign(self, sign: Double) -> Double {
+        if self.is_sign_negative() == sign.is_sign_negative() {
+            self
+        } else {
+            -self
+        }
+    }
+
+    /// Returns the positive difference between the `Double` and another.
+    ///
+    /// This is `self - other` if `self` is greater than `other`, and 0 otherwise. If either
+    /// argument is `NaN`, [`NAN`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3).abs_sub(dd!(1)) == dd!(2));
+    /// assert!(dd!(1).abs_sub(dd!(3)) == Double::ZERO);
+    /// assert!(dd!(1).abs_sub(Double::NAN).is_nan());
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn abs_sub(self, other: Double) -> Double {
+        if self.is_nan() || other.is_nan() {
+            Double::NAN
+        } else if self <= other {
+            Double::ZERO
+        } else {
+            self - other
+        }
+    }
+
     /// Returns the floating point category of the `Double`.
     ///
     /// The possible return values are the members of [`FpCategory`], as follows:
@@ -319,24 +664,674 @@ impl Double {
         self.0.is_finite()
     }
 
-    /// Returns `true` if the `Double` has an absolute value of less than [`MIN_POSITIVE`].
+    /// Returns `true` if the `Double` has an absolute value of less than [`MIN_POSITIVE`].
+    ///
+    /// Numbers this small can be represented by floating point numbers, but they are not as
+    /// accurate. This inaccuracy is inherent in the IEEE-754 format for 64-bit numbers;
+    /// making a double-double out of an inaccurate number means the double-double is also
+    /// going to be inaccurate.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(!Double::PI.is_subnormal());
+    /// assert!(dd!(1e-308).is_subnormal());
+    /// ```
+    ///
+    /// [`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+    #[inline]
+    pub fn is_subnormal(self) -> bool {
+        self.classify() == FpCategory::Subnormal
+    }
+
+    /// Returns `true` if the `Double` is an integer.
+    ///
+    /// Every component is taken into account, not just the first. A `Double` too large for its
+    /// first component to have a fractional part can still have one in a later component,
+    /// and then it isn't an integer. Infinities and `NaN` are not integers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(7).is_integer());
+    /// assert!(dd!(-1e30).is_integer());
+    /// assert!(!dd!(2.5).is_integer());
+    /// assert!(!Double::INFINITY.is_integer());
+    ///
+    /// // 2^60 + 0.5 has an integer first component but isn't an integer
+    /// let x = dd!(2).powi(60) + dd!(0.5);
+    /// assert!(x.hi().fract() == 0.0);
+    /// assert!(!x.is_integer());
+    /// ```
+    pub fn is_integer(self) -> bool {
+        self.0.is_finite() && self.iter().all(|c| c.fract() == 0.0)
+    }
+
+    /// Returns `true` if the `Double` is an even integer.
+    ///
+    /// Zero is even, while numbers that aren't integers (including infinities and `NaN`) are
+    /// neither even nor odd. As with [`is_integer`], every component is taken into account,
+    /// so a number like 2<sup>60</sup> + 1 is correctly found to be odd even though its first component
+    /// is even.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(4).is_even());
+    /// assert!(dd!(-2).is_even());
+    /// assert!(Double::ZERO.is_even());
+    /// assert!(!dd!(3).is_even());
+    /// assert!(!dd!(2.5).is_even());
+    /// assert!(!(dd!(2).powi(60) + dd!(1)).is_even());
+    /// ```
+    ///
+    /// [`is_integer`]: #method.is_integer
+    pub fn is_even(self) -> bool {
+        self.is_integer() && self.odd_components().is_multiple_of(2)
+    }
+
+    /// Returns `true` if the `Double` is an odd integer.
+    ///
+    /// Numbers that aren't integers (including infinities and `NaN`) are neither even nor
+    /// odd. As with [`is_integer`], every component is taken into account, so a number like
+    /// 2<sup>60</sup> + 1 is correctly found to be odd even though its first component is even.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3).is_odd());
+    /// assert!(dd!(-1).is_odd());
+    /// assert!(!Double::ZERO.is_odd());
+    /// assert!(!dd!(2.5).is_odd());
+    /// assert!((dd!(2).powi(60) + dd!(1)).is_odd());
+    /// ```
+    ///
+    /// [`is_integer`]: #method.is_integer
+    pub fn is_odd(self) -> bool {
+        self.is_integer() && !self.odd_components().is_multiple_of(2)
+    }
+
+    // Counts the components of an integer `Double` that are odd. Each one is an integer that's
+    // exactly representable as an `f64`, so the remainder is exact, and the sum is odd if
+    // and only if an odd number of its components are.
+    #[inline]
+    fn odd_components(self) -> usize {
+        self.iter().filter(|c| c % 2.0 != 0.0).count()
+    }
+
+    /// Returns `true` if the components of the `Double` satisfy the invariant that the rest
+    /// of the library relies on: its second component is no larger than half of a unit in
+    /// the last place of its first, so that no two components overlap.
+    ///
+    /// Every `Double` produced by this library's own functions is normalized. A `Double`
+    /// built directly out of components (for instance with `From` on a tuple, or from raw
+    /// bits) might not be, and arithmetic on such a number can give wrong results. This
+    /// function makes it possible to check, and [`canonicalize`] can repair one that isn't.
+    ///
+    /// Infinities and `NaN` are considered normalized if all of their trailing components
+    /// are zero, which is how this library represents them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.is_normalized());
+    /// assert!(dd!(1).is_normalized());
+    /// assert!(Double::INFINITY.is_normalized());
+    ///
+    /// // The trailing component overlaps the leading one
+    /// assert!(!Double::from((1.0, 1.0)).is_normalized());
+    /// ```
+    ///
+    /// [`canonicalize`]: #method.canonicalize
+    pub fn is_normalized(self) -> bool {
+        if self.0.is_finite() {
+            self.1.is_finite() && self.0 + self.1 == self.0
+        } else {
+            self.1 == 0.0
+        }
+    }
+
+    /// Renormalizes the `Double` so that its components satisfy the invariant checked by
+    /// [`is_normalized`].
+    ///
+    /// The result represents the same value as the exact sum of the components, as
+    /// accurately as a `Double` can. A `Double` that is already normalized is returned
+    /// unchanged. If the leading component is infinite or `NaN`, the trailing components
+    /// are discarded; otherwise, an infinite or `NaN` trailing component, or a sum that
+    /// overflows, results in an infinite or `NaN` `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::from((1.0, 1.0));
+    /// assert!(!x.is_normalized());
+    ///
+    /// let y = x.canonicalize();
+    /// assert!(y.is_normalized());
+    /// assert!(y == dd!(2));
+    /// assert!(Double::PI.canonicalize() == Double::PI);
+    /// ```
+    ///
+    /// [`is_normalized`]: #method.is_normalized
+    pub fn canonicalize(self) -> Double {
+        if self.is_normalized() {
+            self
+        } else if !self.0.is_finite() {
+            Double(self.0, 0.0)
+        } else if !self.1.is_finite() {
+            Double(self.0 + self.1, 0.0)
+        } else {
+            let (a, b) = u::distill2(&mut [self.1, self.0]);
+            if a.is_finite() {
+                Double(a, b)
+            } else {
+                Double(self.0 + self.1, 0.0)
+            }
+        }
+    }
+
+    /// Returns the approximate number of significant decimal digits that a `Double` can
+    /// represent. This is the same as [`DIGITS`].
+    ///
+    /// This is useful in code that works with both `Double` and `Quad`, where a tolerance
+    /// can be chosen from the precision of the type instead of being hard-coded.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::precision_digits() == 31);
+    /// let tol = dd!(10).powi(-(Double::precision_digits() as i64) + 1);
+    /// assert!((dd!(2).sqrt().powi(2) - dd!(2)).abs() < tol);
+    /// ```
+    ///
+    /// [`DIGITS`]: #associatedconstant.DIGITS
+    pub const fn precision_digits() -> u32 {
+        Double::DIGITS
+    }
+
+    /// Returns the number of significant bits that the `Double` actually has.
+    ///
+    /// This is [`MANTISSA_DIGITS`] for every value with an absolute value of at least
+    /// [`MIN_FULL_PRECISION`]. Smaller numbers can only be represented with trailing
+    /// components that are subnormal, so they have fewer bits, down to 1 for the smallest
+    /// positive subnormal `f64`. Zero, infinities, and `NaN` have no significant bits and
+    /// return 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1).effective_precision() == Double::MANTISSA_DIGITS);
+    /// let min = Double::MIN_FULL_PRECISION;
+    /// assert!(min.effective_precision() == Double::MANTISSA_DIGITS);
+    /// assert!(Double::ONE.scale_b(-1042).effective_precision() == 33);
+    /// ```
+    ///
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    /// [`MIN_FULL_PRECISION`]: #associatedconstant.MIN_FULL_PRECISION
+    pub fn effective_precision(self) -> u32 {
+        if self.is_zero() || !self.is_finite() {
+            0
+        } else {
+            let a = self.0.abs();
+            let bits = if a < f64::MIN_POSITIVE {
+                // Subnormal, so the leading bit can't be found from the exponent
+                64 - a.to_bits().leading_zeros()
+            } else {
+                // There are 1075 bit positions from 2^0 down to the smallest subnormal
+                (u::exponent(a) + 1075) as u32
+            };
+            bits.min(Double::MANTISSA_DIGITS)
+        }
+    }
+
+    /// Returns the unit in the last place (ULP) of the `Double`.
+    ///
+    /// This is the spacing of the representable `Double`s at this one: the ULP of its low
+    /// component as an `f64`, which is the size of the step taken by [`next_up`] and
+    /// [`next_down`]. Because the low component of a `Double` can be much smaller than an
+    /// ulp of the high one, or zero, this varies a great deal between numbers of about the
+    /// same size. Zero and any `Double` with a zero low component have the smallest ULP,
+    /// 2<sup>-1074</sup>. For a tolerance that's proportional to the size of the number,
+    /// see [`precision_ulp`].
+    ///
+    /// The ULP is always positive. The ULP of an infinity is [`INFINITY`], and the ULP of
+    /// `NaN` is [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::ONE.ulp() == Double::new(5e-324, 0.0));
+    /// assert!(Double::PI.ulp() == Double::ONE.ldexp(-105));
+    /// assert!(Double::PI.ulp() < Double::PI.precision_ulp());
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`next_up`]: #method.next_up
+    /// [`next_down`]: #method.next_down
+    /// [`precision_ulp`]: #method.precision_ulp
+    pub fn ulp(self) -> Double {
+        if self.is_nan() {
+            Double::NAN
+        } else if self.is_infinite() {
+            Double::INFINITY
+        } else {
+            Double(u::ulp(self.1), 0.0)
+        }
+    }
+
+    /// Returns the unit in the last place (ULP) of a `Double` with the full 106 bits of
+    /// precision.
+    ///
+    /// This is 2<sup>-104</sup>, roughly the size of [`EPSILON`], scaled to the binary
+    /// exponent of the `Double`'s leading component. Unlike [`ulp`], it depends only on the
+    /// size of the number and not on how it happens to be split between its components,
+    /// which makes it the natural unit for error bounds and for tolerances in iterative
+    /// algorithms. For numbers so small that this would underflow, the result is instead
+    /// the smallest positive subnormal `f64`.
+    ///
+    /// The result is always positive. That of an infinity is [`INFINITY`], and that of
+    /// `NaN` is [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::ONE.precision_ulp() == Double::ONE.ldexp(-104));
+    /// assert!(dd!(-8).precision_ulp() == Double::ONE.precision_ulp() * dd!(8));
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`ulp`]: #method.ulp
+    pub fn precision_ulp(self) -> Double {
+        if self.is_nan() {
+            Double::NAN
+        } else if self.is_infinite() {
+            Double::INFINITY
+        } else {
+            // 2^(e - 104), in two steps because `powi` can't produce subnormal results
+            let ulp = 2f64.powi(u::exponent(self.0)) * 2f64.powi(-104);
+            Double(ulp.max(MIN_SUBNORMAL), 0.0)
+        }
+    }
+
+    /// Returns the next representable `Double` greater than this one.
+    ///
+    /// This steps the low component up to the next `f64` and then renormalizes, both of
+    /// which are exact, so the result is greater by exactly one [`ulp`] unless the low
+    /// component is a negative power of two. Zero steps up to the smallest positive
+    /// subnormal number, [`NEG_INFINITY`] steps up to [`MIN`], and [`MAX`] steps up to
+    /// [`INFINITY`]. [`INFINITY`] and `NaN` are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::PI.next_up();
+    /// assert!(x > Double::PI);
+    /// assert!(x - Double::PI == Double::PI.ulp());
+    /// assert!(Double::MAX.next_up() == Double::INFINITY);
+    /// ```
+    ///
+    /// [`ulp`]: #method.ulp
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    pub fn next_up(self) -> Double {
+        if self.is_nan() || self == Double::INFINITY {
+            self
+        } else if self == Double::NEG_INFINITY {
+            Double::MIN
+        } else {
+            let (a, b) = u::renorm2(self.0, u::next_up(self.1));
+            if a.is_finite() {
+                Double(a, b)
+            } else {
+                Double::INFINITY
+            }
+        }
+    }
+
+    /// Returns the next representable `Double` less than this one.
+    ///
+    /// This is the mirror image of [`next_up`]: `x.next_down()` is always equal to
+    /// `-(-x).next_up()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::PI.next_down();
+    /// assert!(x < Double::PI);
+    /// assert!(Double::PI - x == Double::PI.ulp());
+    /// assert!(Double::MIN.next_down() == Double::NEG_INFINITY);
+    /// ```
+    ///
+    /// [`next_up`]: #method.next_up
+    #[inline]
+    pub fn next_down(self) -> Double {
+        -(-self).next_up()
+    }
+
+    /// Returns the next representable `Double` after this one in the direction of `target`.
+    ///
+    /// If the two are equal, `target` is returned, and if either of them is `NaN`, [`NAN`]
+    /// is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.next_after(Double::E) == Double::PI.next_down());
+    /// assert!(Double::PI.next_after(Double::INFINITY) == Double::PI.next_up());
+    /// assert!(Double::PI.next_after(Double::PI) == Double::PI);
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn next_after(self, target: Double) -> Double {
+        if self.is_nan() || target.is_nan() {
+            Double::NAN
+        } else if self == target {
+            target
+        } else if self < target {
+            self.next_up()
+        } else {
+            self.next_down()
+        }
+    }
+
+    /// Returns `true` if the `Double` and another are equal to within an absolute or a
+    /// relative tolerance.
+    ///
+    /// The numbers are approximately equal if the difference between them is no more than
+    /// `tol.abs`, or no more than `tol.rel` times the larger of their magnitudes. Numbers
+    /// that are equal according to `==` are always approximately equal, which includes
+    /// infinities of the same sign, but any other infinity and any `NaN` is never
+    /// approximately equal to anything. See [`AbsRel`] for more about the tolerances.
+    ///
+    /// This is the comparison to use for checking whether an iteration has converged or
+    /// whether a computed result is right, where `==` would fail because of rounding.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, AbsRel};
+    /// let x = Double::ONE / dd!(3) * dd!(3);
+    /// let tol = AbsRel { abs: 0.0, rel: 1e-25 };
+    /// assert!(x.approx_eq(Double::ONE, tol));
+    /// assert!(!dd!(1e-20).approx_eq(Double::ZERO, tol));
+    /// assert!(dd!(1e-20).approx_eq(Double::ZERO, AbsRel { abs: 1e-15, rel: 0.0 }));
+    /// assert!(!Double::NAN.approx_eq(Double::NAN, tol));
+    /// ```
+    ///
+    /// [`AbsRel`]: crate::AbsRel
+    pub fn approx_eq(self, other: Double, tol: AbsRel) -> bool {
+        if self == other {
+            true
+        } else if !self.is_finite() || !other.is_finite() {
+            false
+        } else {
+            let diff = (self - other).abs();
+            let scale = self.abs().max(other.abs());
+            diff <= tol.abs || diff <= scale * Double(tol.rel, 0.0)
+        }
+    }
+
+    /// Returns `true` if the `Double` and another are no more than `max_ulps` ULPs apart.
+    ///
+    /// The ULP is the [`precision_ulp`] of the larger of the two numbers in magnitude,
+    /// 2<sup>-104</sup> relative to its leading component, rather than that of the leading
+    /// component alone. That makes this a much tighter comparison than counting the ULPs
+    /// between the leading `f64`s would be. Zero ULPs means the numbers have to be equal.
+    /// Infinities and `NaN`s compare as they do for [`approx_eq`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI;
+    /// let y = x + x.precision_ulp() * dd!(2);
+    /// assert!(x.ulps_eq(y, 2));
+    /// assert!(!x.ulps_eq(y, 1));
+    /// assert!(x.ulps_eq(x, 0));
+    /// ```
+    ///
+    /// [`precision_ulp`]: #method.precision_ulp
+    /// [`approx_eq`]: #method.approx_eq
+    pub fn ulps_eq(self, other: Double, max_ulps: u32) -> bool {
+        if self == other {
+            true
+        } else if !self.is_finite() || !other.is_finite() {
+            false
+        } else {
+            let ulp = self.abs().max(other.abs()).precision_ulp();
+            (self - other).abs() <= ulp * Double(max_ulps as f64, 0.0)
+        }
+    }
+
+    /// Returns the lesser of the `Double` and another.
+    ///
+    /// This works the same as `f64::min`: if one of the arguments is `NaN`, the other is
+    /// returned, and [`NAN`] is only returned if both are `NaN`. If the arguments are zeros
+    /// of opposite sign, either may be returned. See [`minimum`] for a version that
+    /// propagates `NaN` and orders -0 below +0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.min(Double::E) == Double::E);
+    /// assert!(Double::PI.min(Double::NAN) == Double::PI);
+    /// assert!(Double::NAN.min(Double::PI) == Double::PI);
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`minimum`]: #method.minimum
+    pub fn min(self, other: Double) -> Double {
+        if self.is_nan() || other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the greater of the `Double` and another.
+    ///
+    /// This works the same as `f64::max`: if one of the arguments is `NaN`, the other is
+    /// returned, and [`NAN`] is only returned if both are `NaN`. If the arguments are zeros
+    /// of opposite sign, either may be returned. See [`maximum`] for a version that
+    /// propagates `NaN` and orders -0 below +0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.max(Double::E) == Double::PI);
+    /// assert!(Double::E.max(Double::NAN) == Double::E);
+    /// assert!(Double::NAN.max(Double::E) == Double::E);
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`maximum`]: #method.maximum
+    pub fn max(self, other: Double) -> Double {
+        if self.is_nan() || other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the lesser of the `Double` and another, following IEEE 754-2019 `minimum`.
+    ///
+    /// If either argument is `NaN`, [`NAN`] is returned. Negative zero is considered to be
+    /// less than positive zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.minimum(Double::E) == Double::E);
+    /// assert!(Double::PI.minimum(Double::NAN).is_nan());
+    /// assert!(Double::ZERO.minimum(Double::NEG_ZERO).is_sign_negative());
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn minimum(self, other: Double) -> Double {
+        if self.is_nan() || other.is_nan() {
+            Double::NAN
+        } else if self < other || (self == other && self.is_sign_negative()) {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the greater of the `Double` and another, following IEEE 754-2019 `maximum`.
+    ///
+    /// If either argument is `NaN`, [`NAN`] is returned. Positive zero is considered to be
+    /// greater than negative zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.maximum(Double::E) == Double::PI);
+    /// assert!(Double::NAN.maximum(Double::PI).is_nan());
+    /// assert!(Double::NEG_ZERO.maximum(Double::ZERO).is_sign_positive());
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn maximum(self, other: Double) -> Double {
+        if self.is_nan() || other.is_nan() {
+            Double::NAN
+        } else if self > other || (self == other && self.is_sign_positive()) {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the lesser of the `Double` and another, ignoring `NaN`.
+    ///
+    /// This follows the `minimumNumber` operation of IEEE 754-2019. It's like [`minimum`]
+    /// except that `NaN` is treated as missing data: if one of the arguments is `NaN`, the
+    /// other is returned. Negative zero is considered to be less than positive zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.minimum_number(Double::NAN) == Double::PI);
+    /// assert!(Double::ZERO.minimum_number(Double::NEG_ZERO).is_sign_negative());
+    /// ```
+    ///
+    /// [`minimum`]: #method.minimum
+    pub fn minimum_number(self, other: Double) -> Double {
+        if self.is_nan() {
+            other
+        } else if other.is_nan() {
+            self
+        } else {
+            self.minimum(other)
+        }
+    }
+
+    /// Returns the greater of the `Double` and another, ignoring `NaN`.
+    ///
+    /// This follows the `maximumNumber` operation of IEEE 754-2019. It's like [`maximum`]
+    /// except that `NaN` is treated as missing data: if one of the arguments is `NaN`, the
+    /// other is returned. Positive zero is considered to be greater than negative zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::NAN.maximum_number(Double::PI) == Double::PI);
+    /// assert!(Double::NEG_ZERO.maximum_number(Double::ZERO).is_sign_positive());
+    /// ```
+    ///
+    /// [`maximum`]: #method.maximum
+    pub fn maximum_number(self, other: Double) -> Double {
+        if self.is_nan() {
+            other
+        } else if other.is_nan() {
+            self
+        } else {
+            self.maximum(other)
+        }
+    }
+
+    /// Restricts the `Double` to a certain interval.
     ///
-    /// Numbers this small can be represented by floating point numbers, but they are not as
-    /// accurate. This inaccuracy is inherent in the IEEE-754 format for 64-bit numbers;
-    /// making a double-double out of an inaccurate number means the double-double is also
-    /// going to be inaccurate.
+    /// Returns `max` if the `Double` is greater than `max`, and `min` if it's less than
+    /// `min`. Otherwise it returns the `Double` itself. If the `Double` is `NaN`, [`NAN`]
+    /// is returned.
+    ///
+    /// # Panics
+    /// This works the same as `f64::clamp`, so it panics if `min` is greater than `max` or
+    /// if either of them is `NaN`.
     ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
-    /// assert!(!Double::PI.is_subnormal());
-    /// assert!(dd!(1e-308).is_subnormal());
+    /// assert!(dd!(-3).clamp(dd!(-2), dd!(1)) == dd!(-2));
+    /// assert!(dd!(0).clamp(dd!(-2), dd!(1)) == dd!(0));
+    /// assert!(dd!(2).clamp(dd!(-2), dd!(1)) == dd!(1));
+    /// assert!(Double::NAN.clamp(dd!(-2), dd!(1)).is_nan());
     /// ```
     ///
-    /// [`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
-    #[inline]
-    pub fn is_subnormal(self) -> bool {
-        self.classify() == FpCategory::Subnormal
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn clamp(self, min: Double, max: Double) -> Double {
+        assert!(
+            min <= max,
+            "min > max, or either was NaN. min = {:?}, max = {:?}",
+            min,
+            max
+        );
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    // Applies an integer rounding function to the `Double` after shifting its decimal point
+    // `n` places, then shifts it back. Scaling by a negative power is done by dividing by
+    // the positive power instead, since powers of ten with negative exponents can't be
+    // represented exactly. The sign is restored at the end because multiplying or dividing
+    // a zero doesn't preserve it, and a negative number that rounds to zero should produce
+    // -0.
+    fn to_places(self, n: i32, f: fn(Double) -> Double) -> Double {
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        let p = pow10(n.unsigned_abs());
+        let r = if n >= 0 {
+            let y = self * p;
+            if y.is_finite() {
+                f(y) / p
+            } else {
+                self
+            }
+        } else if p.is_finite() {
+            f(self / p) * p
+        } else {
+            Double::ZERO
+        };
+        r.copysign(self)
+    }
+
+    // Converts an integer-valued `Double` into an `i128`, or `None` if it doesn't fit.
+    //
+    // Every component of an integer-valued `Double` is itself an integer, and since the
+    // sum is known to be in range, adding the components with wrapping arithmetic gives
+    // the exact result. This even works when the leading component alone is out of range,
+    // as it is for 2^127 - 1 (which is stored with a leading component of 2^127).
+    fn int_to_i128(self) -> Option<i128> {
+        let limit = Double(I128_LIMIT, 0.0);
+        if self >= -limit && self < limit {
+            let hi = if self.0 == I128_LIMIT {
+                i128::MIN
+            } else {
+                self.0 as i128
+            };
+            Some(hi.wrapping_add(self.1 as i128))
+        } else {
+            None
+        }
     }
 }
 
@@ -480,6 +1475,210 @@ mod tests {
             Double::NAN,
             Double::NAN.round();
     );
+    test_all_exact!(
+        round_low_tie_up:
+            dd!(3),
+            Double(2.5, 1e-20).round();
+        round_low_tie_down:
+            dd!(2),
+            Double(2.5, -1e-20).round();
+        round_neg_low_tie_up:
+            dd!(-2),
+            Double(-2.5, 1e-20).round();
+        round_neg_low_tie_down:
+            dd!(-3),
+            Double(-2.5, -1e-20).round();
+        round_large_low:
+            Double(1e20, 1.0),
+            Double(1e20, 0.5).round();
+        round_large_neg_low:
+            Double(1e20, 0.0),
+            Double(1e20, -0.5).round();
+        round_large_neg_low_down:
+            Double(1e20, -1.0),
+            Double(1e20, -0.75).round();
+    );
+    test_all_assert!(
+        round_small_neg_sign:
+            dd!(-0.3).round().is_sign_negative() && dd!(-0.3).round().is_zero();
+    );
+
+    // round_ties_even tests
+    test_all_exact!(
+        round_ties_even_pi:
+            dd!(3),
+            Double::PI.round_ties_even();
+        round_ties_even_neg_pi:
+            dd!(-3),
+            (-Double::PI).round_ties_even();
+        round_ties_even_half_even:
+            dd!(2),
+            dd!(2.5).round_ties_even();
+        round_ties_even_half_odd:
+            dd!(4),
+            dd!(3.5).round_ties_even();
+        round_ties_even_neg_half_even:
+            dd!(-2),
+            dd!(-2.5).round_ties_even();
+        round_ties_even_neg_half_odd:
+            dd!(-4),
+            dd!(-3.5).round_ties_even();
+        round_ties_even_half:
+            Double::ZERO,
+            dd!(0.5).round_ties_even();
+        round_ties_even_one_half:
+            dd!(2),
+            dd!(1.5).round_ties_even();
+        round_ties_even_low_up:
+            dd!(3),
+            Double(2.5, 1e-20).round_ties_even();
+        round_ties_even_low_down:
+            dd!(3),
+            Double(3.5, -1e-20).round_ties_even();
+        round_ties_even_large_low:
+            Double(1e20, 2.0),
+            Double(1e20, 2.5).round_ties_even();
+        round_ties_even_int:
+            dd!(7),
+            dd!(7).round_ties_even();
+        round_ties_even_zero:
+            Double::ZERO,
+            Double::ZERO.round_ties_even();
+        round_ties_even_inf:
+            Double::INFINITY,
+            Double::INFINITY.round_ties_even();
+        round_ties_even_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.round_ties_even();
+        round_ties_even_nan:
+            Double::NAN,
+            Double::NAN.round_ties_even();
+    );
+    test_all_assert!(
+        round_ties_even_neg_zero:
+            Double::NEG_ZERO.round_ties_even().is_sign_negative();
+        round_ties_even_neg_half:
+            dd!(-0.5).round_ties_even().is_sign_negative();
+    );
+
+    // integer rounding tests
+    test_all_eq!(
+        floor_i64_pi:
+            Double::PI.floor_i64(),
+            Some(3);
+        floor_i64_neg_pi:
+            (-Double::PI).floor_i64(),
+            Some(-4);
+        floor_i64_low:
+            Double(1e18, -0.5).floor_i64(),
+            Some(999_999_999_999_999_999);
+        floor_i64_max:
+            Double::from(i64::MAX).floor_i64(),
+            Some(i64::MAX);
+        floor_i64_min:
+            Double::from(i64::MIN).floor_i64(),
+            Some(i64::MIN);
+        floor_i64_over:
+            (Double::from(i64::MAX) + Double::ONE).floor_i64(),
+            None;
+        floor_i64_under:
+            (Double::from(i64::MIN) - dd!(0.5)).floor_i64(),
+            None;
+        floor_i64_inf:
+            Double::INFINITY.floor_i64(),
+            None;
+        floor_i64_nan:
+            Double::NAN.floor_i64(),
+            None;
+
+        ceil_i64_pi:
+            Double::PI.ceil_i64(),
+            Some(4);
+        ceil_i64_neg_pi:
+            (-Double::PI).ceil_i64(),
+            Some(-3);
+        ceil_i64_low:
+            Double(1e18, 0.5).ceil_i64(),
+            Some(1_000_000_000_000_000_001);
+        ceil_i64_over:
+            (Double::from(i64::MAX) + dd!(0.5)).ceil_i64(),
+            None;
+        ceil_i64_neg_inf:
+            Double::NEG_INFINITY.ceil_i64(),
+            None;
+
+        round_i64_pi:
+            Double::PI.round_i64(),
+            Some(3);
+        round_i64_half:
+            dd!(-2.5).round_i64(),
+            Some(-3);
+        round_i64_low:
+            Double(1e18, -0.5).round_i64(),
+            Some(1_000_000_000_000_000_000);
+        round_i64_low_down:
+            Double(1e18, -0.75).round_i64(),
+            Some(999_999_999_999_999_999);
+        round_i64_nan:
+            Double::NAN.round_i64(),
+            None;
+
+        trunc_i64_pi:
+            Double::PI.trunc_i64(),
+            Some(3);
+        trunc_i64_neg_pi:
+            (-Double::PI).trunc_i64(),
+            Some(-3);
+        trunc_i64_low:
+            Double(1e18, -0.5).trunc_i64(),
+            Some(999_999_999_999_999_999);
+        trunc_i64_neg_low:
+            Double(-1e18, 0.5).trunc_i64(),
+            Some(-999_999_999_999_999_999);
+
+        floor_i128_pi:
+            Double::PI.floor_i128(),
+            Some(3);
+        floor_i128_low:
+            Double(1e30, -0.5).floor_i128(),
+            Some(1_000_000_000_000_000_019_884_624_838_655);
+        floor_i128_max:
+            Double(1.7014118346046923e38, -1.0).floor_i128(),
+            Some(i128::MAX);
+        floor_i128_min:
+            Double(-1.7014118346046923e38, 0.0).floor_i128(),
+            Some(i128::MIN);
+        floor_i128_over:
+            Double(1.7014118346046923e38, 0.0).floor_i128(),
+            None;
+        floor_i128_under:
+            Double(-1.7014118346046923e38, -1.0).floor_i128(),
+            None;
+        floor_i128_nan:
+            Double::NAN.floor_i128(),
+            None;
+
+        ceil_i128_neg_pi:
+            (-Double::PI).ceil_i128(),
+            Some(-3);
+        ceil_i128_max:
+            Double(1.7014118346046923e38, -1.5).ceil_i128(),
+            Some(i128::MAX);
+
+        round_i128_neg_half:
+            dd!(-0.5).round_i128(),
+            Some(-1);
+        round_i128_low:
+            Double(-1e30, -0.5).round_i128(),
+            Some(-1_000_000_000_000_000_019_884_624_838_657);
+
+        trunc_i128_neg_pi:
+            (-Double::PI).trunc_i128(),
+            Some(-3);
+        trunc_i128_inf:
+            Double::INFINITY.trunc_i128(),
+            None;
+    );
 
     // trunc tests
     test_all_exact!(
@@ -565,6 +1764,136 @@ mod tests {
             Double::NAN.fract();
     );
 
+    // round_to_places tests
+    test_all_near!(
+        round_to_places_pi_2:
+            dd!("3.14"),
+            Double::PI.round_to_places(2);
+        round_to_places_pi_4:
+            dd!("3.1416"),
+            Double::PI.round_to_places(4);
+        round_to_places_pi_long:
+            dd!("3.1415926535897932384626434"),
+            Double::PI.round_to_places(25);
+        round_to_places_neg_e_5:
+            dd!("-2.71828"),
+            (-Double::E).round_to_places(5);
+        round_to_places_small:
+            dd!("0.000123"),
+            dd!("0.00012345").round_to_places(6);
+        round_to_places_beyond_precision:
+            Double::PI,
+            Double::PI.round_to_places(40);
+    );
+    test_all_exact!(
+        round_to_places_zero_places:
+            dd!(3),
+            Double::PI.round_to_places(0);
+        round_to_places_tie:
+            dd!(0.13),
+            dd!(0.125).round_to_places(2);
+        round_to_places_neg_tie:
+            dd!(-0.13),
+            dd!(-0.125).round_to_places(2);
+        round_to_places_neg_places:
+            dd!(1200),
+            dd!(1234.5).round_to_places(-2);
+        round_to_places_neg_places_up:
+            dd!(-1300),
+            dd!(-1250).round_to_places(-2);
+        round_to_places_neg_places_zero:
+            Double::ZERO,
+            dd!(49).round_to_places(-2);
+        round_to_places_large_neg_places:
+            Double::ZERO,
+            dd!(1e300).round_to_places(-400);
+        round_to_places_min_places:
+            Double::ZERO,
+            Double::MAX.round_to_places(i32::MIN);
+        round_to_places_overflow:
+            dd!(1e300),
+            dd!(1e300).round_to_places(20);
+        round_to_places_max_places:
+            Double::PI,
+            Double::PI.round_to_places(i32::MAX);
+
+        round_to_places_zero:
+            Double::ZERO,
+            Double::ZERO.round_to_places(2);
+        round_to_places_inf:
+            Double::INFINITY,
+            Double::INFINITY.round_to_places(2);
+        round_to_places_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.round_to_places(2);
+        round_to_places_nan:
+            Double::NAN,
+            Double::NAN.round_to_places(2);
+    );
+    test_all_assert!(
+        round_to_places_neg_zero:
+            Double::NEG_ZERO.round_to_places(2).is_sign_negative();
+        round_to_places_neg_to_zero:
+            dd!(-0.001).round_to_places(2).is_sign_negative();
+        round_to_places_neg_places_to_zero:
+            dd!(-49).round_to_places(-2).is_sign_negative();
+    );
+    #[cfg(feature = "display")]
+    test_all_assert!(
+        round_to_places_string:
+            dd!(2).sqrt().round_to_places(10).to_string() == "1.4142135624";
+    );
+
+    // trunc_to_places tests
+    test_all_near!(
+        trunc_to_places_pi_2:
+            dd!("3.14"),
+            Double::PI.trunc_to_places(2);
+        trunc_to_places_pi_4:
+            dd!("3.1415"),
+            Double::PI.trunc_to_places(4);
+        trunc_to_places_neg_e_5:
+            dd!("-2.71828"),
+            (-Double::E).trunc_to_places(5);
+        trunc_to_places_neg_e_3:
+            dd!("-2.718"),
+            (-Double::E).trunc_to_places(3);
+    );
+    test_all_exact!(
+        trunc_to_places_zero_places:
+            dd!(2),
+            Double::E.trunc_to_places(0);
+        trunc_to_places_tie:
+            dd!(0.12),
+            dd!(0.125).trunc_to_places(2);
+        trunc_to_places_neg_places:
+            dd!(-1900),
+            dd!(-1999.9).trunc_to_places(-2);
+        trunc_to_places_large_neg_places:
+            Double::ZERO,
+            dd!(1e300).trunc_to_places(-400);
+        trunc_to_places_overflow:
+            dd!(1e300),
+            dd!(1e300).trunc_to_places(20);
+
+        trunc_to_places_zero:
+            Double::ZERO,
+            Double::ZERO.trunc_to_places(2);
+        trunc_to_places_inf:
+            Double::INFINITY,
+            Double::INFINITY.trunc_to_places(2);
+        trunc_to_places_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.trunc_to_places(2);
+        trunc_to_places_nan:
+            Double::NAN,
+            Double::NAN.trunc_to_places(2);
+    );
+    test_all_assert!(
+        trunc_to_places_neg_to_zero:
+            dd!(-0.009).trunc_to_places(2).is_sign_negative();
+    );
+
     // signum tests
     test_all_exact!(
         signum_pi:
@@ -606,6 +1935,71 @@ mod tests {
             Double::NAN.signum();
     );
 
+    // copysign tests
+    test_all_exact!(
+        copysign_pos_pos:
+            Double::PI,
+            Double::PI.copysign(Double::E);
+        copysign_pos_neg:
+            -Double::PI,
+            Double::PI.copysign(-Double::E);
+        copysign_neg_pos:
+            Double::PI,
+            (-Double::PI).copysign(Double::E);
+        copysign_neg_neg:
+            -Double::PI,
+            (-Double::PI).copysign(-Double::E);
+        copysign_neg_zero:
+            -Double::PI,
+            Double::PI.copysign(Double::NEG_ZERO);
+        copysign_zero:
+            Double::PI,
+            (-Double::PI).copysign(Double::ZERO);
+        copysign_inf:
+            Double::NEG_INFINITY,
+            Double::INFINITY.copysign(-Double::PI);
+        copysign_neg_nan:
+            -Double::PI,
+            Double::PI.copysign(-Double::NAN);
+    );
+    test_all_assert!(
+        copysign_zero_neg:
+            Double::ZERO.copysign(-Double::PI).is_sign_negative();
+        copysign_neg_zero_pos:
+            Double::NEG_ZERO.copysign(Double::PI).is_sign_positive();
+        copysign_nan_neg:
+            Double::NAN.copysign(-Double::PI).is_nan();
+        copysign_nan_neg_sign:
+            Double::NAN.copysign(-Double::PI).is_sign_negative();
+        copysign_low_components:
+            (-Double::PI).copysign(Double::ONE) == Double::PI;
+    );
+
+    // abs_sub tests
+    test_all_exact!(
+        abs_sub_pi_e:
+            Double::PI - Double::E,
+            Double::PI.abs_sub(Double::E);
+        abs_sub_e_pi:
+            Double::ZERO,
+            Double::E.abs_sub(Double::PI);
+        abs_sub_equal:
+            Double::ZERO,
+            Double::PI.abs_sub(Double::PI);
+        abs_sub_inf:
+            Double::INFINITY,
+            Double::INFINITY.abs_sub(Double::PI);
+        abs_sub_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.abs_sub(Double::PI);
+        abs_sub_nan_left:
+            Double::NAN,
+            Double::NAN.abs_sub(Double::PI);
+        abs_sub_nan_right:
+            Double::NAN,
+            Double::PI.abs_sub(Double::NAN);
+    );
+
     // classify tests
     test_all_eq!(
         classify_pi:
@@ -790,4 +2184,629 @@ mod tests {
         is_subnormal_sub:
             dd!(1e-308).is_subnormal();
     );
+
+    // is_integer tests
+    test_all_assert!(
+        is_integer_zero:
+            Double::ZERO.is_integer();
+        is_integer_neg_zero:
+            Double::NEG_ZERO.is_integer();
+        is_integer_one:
+            dd!(1).is_integer();
+        is_integer_neg:
+            dd!(-42).is_integer();
+        is_integer_large:
+            dd!(2).powi(60).is_integer();
+        is_integer_max:
+            Double::MAX.is_integer();
+        is_integer_trailing:
+            (dd!(2).powi(60) + dd!(1)).is_integer();
+
+        is_integer_half:
+            !dd!(0.5).is_integer();
+        is_integer_pi:
+            !Double::PI.is_integer();
+        is_integer_tiny:
+            !Double::MIN_POSITIVE.is_integer();
+        is_integer_trailing_fract:
+            !(dd!(2).powi(60) + dd!(0.5)).is_integer();
+        is_integer_trailing_neg_fract:
+            !(dd!(2).powi(60) - dd!(0.25)).is_integer();
+        is_integer_inf:
+            !Double::INFINITY.is_integer();
+        is_integer_neg_inf:
+            !Double::NEG_INFINITY.is_integer();
+        is_integer_nan:
+            !Double::NAN.is_integer();
+    );
+
+    // is_even tests
+    test_all_assert!(
+        is_even_zero:
+            Double::ZERO.is_even();
+        is_even_neg_zero:
+            Double::NEG_ZERO.is_even();
+        is_even_two:
+            dd!(2).is_even();
+        is_even_neg:
+            dd!(-10).is_even();
+        is_even_large:
+            dd!(2).powi(60).is_even();
+        is_even_max:
+            Double::MAX.is_even();
+        is_even_trailing:
+            (dd!(2).powi(60) + dd!(2)).is_even();
+        is_even_trailing_neg:
+            (dd!(2).powi(60) - dd!(1) - dd!(1)).is_even();
+
+        is_even_one:
+            !dd!(1).is_even();
+        is_even_neg_odd:
+            !dd!(-7).is_even();
+        is_even_trailing_odd:
+            !(dd!(2).powi(60) + dd!(1)).is_even();
+        is_even_trailing_neg_odd:
+            !(dd!(2).powi(60) - dd!(1)).is_even();
+        is_even_fract:
+            !dd!(2.5).is_even();
+        is_even_inf:
+            !Double::INFINITY.is_even();
+        is_even_nan:
+            !Double::NAN.is_even();
+    );
+
+    // is_odd tests
+    test_all_assert!(
+        is_odd_one:
+            dd!(1).is_odd();
+        is_odd_neg:
+            dd!(-7).is_odd();
+        is_odd_trailing:
+            (dd!(2).powi(60) + dd!(1)).is_odd();
+        is_odd_trailing_neg:
+            (dd!(2).powi(60) - dd!(1)).is_odd();
+        is_odd_trailing_three:
+            (dd!(2).powi(60) - dd!(3)).is_odd();
+
+        is_odd_zero:
+            !Double::ZERO.is_odd();
+        is_odd_two:
+            !dd!(2).is_odd();
+        is_odd_large:
+            !dd!(2).powi(60).is_odd();
+        is_odd_max:
+            !Double::MAX.is_odd();
+        is_odd_fract:
+            !dd!(1.5).is_odd();
+        is_odd_inf:
+            !Double::NEG_INFINITY.is_odd();
+        is_odd_nan:
+            !Double::NAN.is_odd();
+    );
+
+    // is_normalized tests
+    test_all_assert!(
+        is_normalized_pi:
+            Double::PI.is_normalized();
+        is_normalized_e:
+            Double::E.is_normalized();
+        is_normalized_one:
+            dd!(1).is_normalized();
+        is_normalized_max:
+            Double::MAX.is_normalized();
+        is_normalized_min_positive:
+            Double::MIN_POSITIVE.is_normalized();
+        is_normalized_product:
+            (Double::PI * Double::E).is_normalized();
+        is_normalized_quotient:
+            (dd!(1) / dd!(3)).is_normalized();
+        is_normalized_zero:
+            Double::ZERO.is_normalized();
+        is_normalized_neg_zero:
+            Double::NEG_ZERO.is_normalized();
+        is_normalized_inf:
+            Double::INFINITY.is_normalized();
+        is_normalized_neg_inf:
+            Double::NEG_INFINITY.is_normalized();
+        is_normalized_nan:
+            Double::NAN.is_normalized();
+
+        is_normalized_overlap:
+            !Double(1.0, 1.0).is_normalized();
+        is_normalized_reversed:
+            !Double(1e-20, 1.0).is_normalized();
+        is_normalized_zero_leading:
+            !Double(0.0, 1e-20).is_normalized();
+        is_normalized_nan_trailing:
+            !Double(1.0, f64::NAN).is_normalized();
+        is_normalized_inf_trailing:
+            !Double(1.0, f64::INFINITY).is_normalized();
+        is_normalized_inf_nonzero_trailing:
+            !Double(f64::INFINITY, 1.0).is_normalized();
+        is_normalized_nan_nan:
+            !Double(f64::NAN, f64::NAN).is_normalized();
+    );
+
+    // canonicalize tests
+    test_all_exact!(
+        canonicalize_pi:
+            Double::PI,
+            Double::PI.canonicalize();
+        canonicalize_overlap:
+            dd!(2),
+            Double(1.0, 1.0).canonicalize();
+        canonicalize_reversed:
+            Double(1.0, 1e-20),
+            Double(1e-20, 1.0).canonicalize();
+        canonicalize_tie:
+            Double(1.0 + 2f64.powi(-51), -2f64.powi(-53)),
+            Double(1.0, 3.0 * 2f64.powi(-53)).canonicalize();
+        canonicalize_cancel:
+            Double::ZERO,
+            Double(1.0, -1.0).canonicalize();
+
+        canonicalize_zero:
+            Double::ZERO,
+            Double::ZERO.canonicalize();
+        canonicalize_inf:
+            Double::INFINITY,
+            Double::INFINITY.canonicalize();
+        canonicalize_inf_nan_trailing:
+            Double::INFINITY,
+            Double(f64::INFINITY, f64::NAN).canonicalize();
+        canonicalize_inf_trailing:
+            Double::NEG_INFINITY,
+            Double(1.0, f64::NEG_INFINITY).canonicalize();
+        canonicalize_overflow:
+            Double::INFINITY,
+            Double(f64::MAX, f64::MAX).canonicalize();
+        canonicalize_nan:
+            Double::NAN,
+            Double::NAN.canonicalize();
+        canonicalize_nan_trailing:
+            Double::NAN,
+            Double(1.0, f64::NAN).canonicalize();
+    );
+    test_all_assert!(
+        canonicalize_normalized:
+            Double(1e-20, 1.0).canonicalize().is_normalized();
+        canonicalize_nan_normalized:
+            Double(f64::NAN, f64::NAN).canonicalize().is_normalized();
+        canonicalize_neg_zero:
+            Double::NEG_ZERO.canonicalize().is_sign_negative();
+        canonicalize_same_bits:
+            Double::E.canonicalize()[1].to_bits() == Double::E[1].to_bits();
+    );
+
+    // precision_digits tests
+    test!(precision_digits: {
+        assert_eq!(Double::precision_digits(), Double::DIGITS);
+        assert!(Double::EPSILON < dd!(10).powi(-(Double::precision_digits() as i64)));
+    });
+
+    // effective_precision tests
+    test!(effective_precision: {
+        assert_eq!(dd!(1).effective_precision(), 106);
+        assert_eq!(Double::MAX.effective_precision(), 106);
+        assert_eq!(Double::MIN_FULL_PRECISION.effective_precision(), 106);
+        assert_eq!((-Double::MIN_FULL_PRECISION).effective_precision(), 106);
+        assert_eq!(Double::MIN_FULL_PRECISION.ldexp(-1).effective_precision(), 105);
+        assert_eq!(Double::ONE.scale_b(-1022).effective_precision(), 53);
+        assert_eq!(Double::ONE.scale_b(-1023).effective_precision(), 52);
+        assert_eq!(Double::ONE.scale_b(-1074).effective_precision(), 1);
+        assert_eq!(Double::ZERO.effective_precision(), 0);
+        assert_eq!(Double::INFINITY.effective_precision(), 0);
+        assert_eq!(Double::NAN.effective_precision(), 0);
+    });
+
+    // ulp tests
+    test_all_exact!(
+        ulp_one:
+            Double(MIN_SUBNORMAL, 0.0),
+            Double::ONE.ulp();
+        ulp_pi:
+            Double(2f64.powi(-105), 0.0),
+            Double::PI.ulp();
+        ulp_neg_pi:
+            Double(2f64.powi(-105), 0.0),
+            (-Double::PI).ulp();
+        ulp_neg_lo:
+            Double(2f64.powi(-105), 0.0),
+            Double(1.0, -2f64.powi(-53)).ulp();
+        ulp_zero:
+            Double(MIN_SUBNORMAL, 0.0),
+            Double::ZERO.ulp();
+        ulp_inf:
+            Double::INFINITY,
+            Double::INFINITY.ulp();
+        ulp_neg_inf:
+            Double::INFINITY,
+            Double::NEG_INFINITY.ulp();
+        ulp_nan:
+            Double::NAN,
+            Double::NAN.ulp();
+    );
+
+    // precision_ulp tests
+    test_all_exact!(
+        precision_ulp_one:
+            Double(2f64.powi(-104), 0.0),
+            Double::ONE.precision_ulp();
+        precision_ulp_pi:
+            Double(2f64.powi(-103), 0.0),
+            Double::PI.precision_ulp();
+        precision_ulp_neg_pi:
+            Double(2f64.powi(-103), 0.0),
+            (-Double::PI).precision_ulp();
+        precision_ulp_zero:
+            Double(MIN_SUBNORMAL, 0.0),
+            Double::ZERO.precision_ulp();
+        precision_ulp_sub:
+            Double(MIN_SUBNORMAL, 0.0),
+            dd!(1e-308).precision_ulp();
+        precision_ulp_inf:
+            Double::INFINITY,
+            Double::INFINITY.precision_ulp();
+        precision_ulp_nan:
+            Double::NAN,
+            Double::NAN.precision_ulp();
+    );
+
+    // next_up tests
+    test_all_exact!(
+        next_up_one:
+            Double(1.0, MIN_SUBNORMAL),
+            Double::ONE.next_up();
+        next_up_neg_one:
+            Double(-1.0, MIN_SUBNORMAL),
+            Double::NEG_ONE.next_up();
+        next_up_pi:
+            Double(Double::PI.0, Double::PI.1 + 2f64.powi(-105)),
+            Double::PI.next_up();
+        next_up_zero:
+            Double(MIN_SUBNORMAL, 0.0),
+            Double::ZERO.next_up();
+        next_up_neg_zero:
+            Double(MIN_SUBNORMAL, 0.0),
+            Double::NEG_ZERO.next_up();
+        next_up_renorm:
+            Double(1.0 + f64::EPSILON, -2f64.powi(-53) + 2f64.powi(-105)),
+            Double(1.0, 2f64.powi(-53)).next_up();
+        next_up_subnormal:
+            Double(3.0 * MIN_SUBNORMAL, 0.0),
+            Double(2.0 * MIN_SUBNORMAL, 0.0).next_up();
+        next_up_max:
+            Double::INFINITY,
+            Double::MAX.next_up();
+        next_up_inf:
+            Double::INFINITY,
+            Double::INFINITY.next_up();
+        next_up_neg_inf:
+            Double::MIN,
+            Double::NEG_INFINITY.next_up();
+        next_up_nan:
+            Double::NAN,
+            Double::NAN.next_up();
+    );
+    test_all_assert!(
+        next_up_greater:
+            Double::PI.next_up() > Double::PI;
+        next_up_neg_pi:
+            (-Double::PI).next_up() > -Double::PI;
+        next_up_sub:
+            dd!(1e-308).next_up() > dd!(1e-308);
+        next_up_below_max:
+            Double::MAX.next_down().next_up() == Double::MAX;
+    );
+
+    // next_down tests
+    test_all_exact!(
+        next_down_one:
+            Double(1.0, -MIN_SUBNORMAL),
+            Double::ONE.next_down();
+        next_down_zero:
+            Double(-MIN_SUBNORMAL, 0.0),
+            Double::ZERO.next_down();
+        next_down_min:
+            Double::NEG_INFINITY,
+            Double::MIN.next_down();
+        next_down_inf:
+            Double::MAX,
+            Double::INFINITY.next_down();
+        next_down_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.next_down();
+        next_down_nan:
+            Double::NAN,
+            Double::NAN.next_down();
+    );
+    test_all_assert!(
+        next_down_pi:
+            Double::PI.next_down() < Double::PI;
+        next_down_round_trip:
+            Double::PI.next_down().next_up() == Double::PI;
+    );
+
+    // next_after tests
+    test_all_exact!(
+        next_after_up:
+            Double::E.next_up(),
+            Double::E.next_after(Double::PI);
+        next_after_down:
+            Double::PI.next_down(),
+            Double::PI.next_after(Double::E);
+        next_after_equal:
+            Double::PI,
+            Double::PI.next_after(Double::PI);
+        next_after_zero:
+            Double::NEG_ZERO,
+            Double::ZERO.next_after(Double::NEG_ZERO);
+        next_after_inf:
+            Double::MAX,
+            Double::INFINITY.next_after(Double::ZERO);
+        next_after_nan:
+            Double::NAN,
+            Double::PI.next_after(Double::NAN);
+        next_after_nan_self:
+            Double::NAN,
+            Double::NAN.next_after(Double::PI);
+    );
+
+    // approx_eq tests
+    test_all_assert!(
+        approx_eq_rel:
+            (dd!(1) / dd!(7) * dd!(7)).approx_eq(dd!(1), AbsRel { abs: 0.0, rel: 1e-25 });
+        approx_eq_rel_large:
+            dd!(1e100).approx_eq(dd!(1.0000001e100), AbsRel { abs: 0.0, rel: 1e-6 });
+        approx_eq_rel_fail:
+            !dd!(1).approx_eq(dd!(1.001), AbsRel { abs: 1e-4, rel: 1e-4 });
+        approx_eq_abs:
+            dd!(1e-40).approx_eq(dd!(-1e-40), AbsRel { abs: 1e-30, rel: 0.0 });
+        approx_eq_abs_fail:
+            !dd!(1e-40).approx_eq(Double::ZERO, AbsRel { abs: 0.0, rel: 0.5 });
+        approx_eq_zeros:
+            Double::ZERO.approx_eq(Double::NEG_ZERO, AbsRel::default());
+        approx_eq_inf:
+            Double::INFINITY.approx_eq(Double::INFINITY, AbsRel::default());
+        approx_eq_inf_max:
+            !Double::INFINITY.approx_eq(Double::MAX, AbsRel { abs: f64::INFINITY, rel: 1.0 });
+        approx_eq_nan:
+            !Double::NAN.approx_eq(Double::NAN, AbsRel { abs: f64::INFINITY, rel: 1.0 });
+    );
+
+    // ulps_eq tests
+    test_all_assert!(
+        ulps_eq_equal:
+            Double::E.ulps_eq(Double::E, 0);
+        ulps_eq_one:
+            Double::E.ulps_eq(Double::E.next_down(), 1);
+        ulps_eq_one_fail:
+            !Double::E.ulps_eq(Double::E.next_down(), 0);
+        ulps_eq_many:
+            Double::PI.ulps_eq(Double::PI + Double::PI.precision_ulp() * dd!(100), 100);
+        ulps_eq_many_fail:
+            !Double::PI.ulps_eq(Double::PI + Double::PI.precision_ulp() * dd!(101), 100);
+        ulps_eq_low_component:
+            !Double::ONE.ulps_eq(Double::ONE + dd!(1e-20), 1000);
+        ulps_eq_opposite_signs:
+            !Double::ONE.ulps_eq(-Double::ONE, u32::MAX);
+        ulps_eq_zeros:
+            Double::ZERO.ulps_eq(Double::NEG_ZERO, 0);
+        ulps_eq_inf:
+            Double::NEG_INFINITY.ulps_eq(Double::NEG_INFINITY, 0)
+                && !Double::INFINITY.ulps_eq(Double::MAX, u32::MAX);
+        ulps_eq_nan:
+            !Double::NAN.ulps_eq(Double::NAN, u32::MAX);
+    );
+
+    // min tests
+    test_all_exact!(
+        min_pi_e:
+            Double::E,
+            Double::PI.min(Double::E);
+        min_e_pi:
+            Double::E,
+            Double::E.min(Double::PI);
+        min_neg:
+            -Double::PI,
+            Double::E.min(-Double::PI);
+        min_inf:
+            Double::PI,
+            Double::PI.min(Double::INFINITY);
+        min_neg_inf:
+            Double::NEG_INFINITY,
+            Double::PI.min(Double::NEG_INFINITY);
+        min_nan_right:
+            Double::PI,
+            Double::PI.min(Double::NAN);
+        min_nan_left:
+            Double::PI,
+            Double::NAN.min(Double::PI);
+        min_nan_nan:
+            Double::NAN,
+            Double::NAN.min(Double::NAN);
+    );
+
+    // max tests
+    test_all_exact!(
+        max_pi_e:
+            Double::PI,
+            Double::PI.max(Double::E);
+        max_e_pi:
+            Double::PI,
+            Double::E.max(Double::PI);
+        max_neg:
+            Double::E,
+            Double::E.max(-Double::PI);
+        max_inf:
+            Double::INFINITY,
+            Double::PI.max(Double::INFINITY);
+        max_neg_inf:
+            Double::PI,
+            Double::PI.max(Double::NEG_INFINITY);
+        max_nan_right:
+            Double::PI,
+            Double::PI.max(Double::NAN);
+        max_nan_left:
+            Double::PI,
+            Double::NAN.max(Double::PI);
+        max_nan_nan:
+            Double::NAN,
+            Double::NAN.max(Double::NAN);
+    );
+
+    // minimum tests
+    test_all_exact!(
+        minimum_pi_e:
+            Double::E,
+            Double::PI.minimum(Double::E);
+        minimum_low_component:
+            Double::PI,
+            Double::PI.next_up().minimum(Double::PI);
+        minimum_nan_right:
+            Double::NAN,
+            Double::PI.minimum(Double::NAN);
+        minimum_nan_left:
+            Double::NAN,
+            Double::NAN.minimum(Double::PI);
+    );
+    test_all_assert!(
+        minimum_zero_neg_zero:
+            Double::ZERO.minimum(Double::NEG_ZERO).is_sign_negative();
+        minimum_neg_zero_zero:
+            Double::NEG_ZERO.minimum(Double::ZERO).is_sign_negative();
+    );
+
+    // maximum tests
+    test_all_exact!(
+        maximum_pi_e:
+            Double::PI,
+            Double::PI.maximum(Double::E);
+        maximum_low_component:
+            Double::PI.next_up(),
+            Double::PI.next_up().maximum(Double::PI);
+        maximum_nan_right:
+            Double::NAN,
+            Double::PI.maximum(Double::NAN);
+        maximum_nan_left:
+            Double::NAN,
+            Double::NAN.maximum(Double::PI);
+    );
+    test_all_assert!(
+        maximum_zero_neg_zero:
+            Double::ZERO.maximum(Double::NEG_ZERO).is_sign_positive();
+        maximum_neg_zero_zero:
+            Double::NEG_ZERO.maximum(Double::ZERO).is_sign_positive();
+    );
+
+    // minimum_number tests
+    test_all_exact!(
+        minimum_number_pi_e:
+            Double::E,
+            Double::PI.minimum_number(Double::E);
+        minimum_number_nan_right:
+            Double::PI,
+            Double::PI.minimum_number(Double::NAN);
+        minimum_number_nan_left:
+            Double::PI,
+            Double::NAN.minimum_number(Double::PI);
+        minimum_number_nan_nan:
+            Double::NAN,
+            Double::NAN.minimum_number(Double::NAN);
+    );
+    test_all_assert!(
+        minimum_number_zero_neg_zero:
+            Double::ZERO.minimum_number(Double::NEG_ZERO).is_sign_negative();
+    );
+
+    // maximum_number tests
+    test_all_exact!(
+        maximum_number_pi_e:
+            Double::PI,
+            Double::PI.maximum_number(Double::E);
+        maximum_number_nan_right:
+            Double::PI,
+            Double::PI.maximum_number(Double::NAN);
+        maximum_number_nan_left:
+            Double::PI,
+            Double::NAN.maximum_number(Double::PI);
+        maximum_number_nan_nan:
+            Double::NAN,
+            Double::NAN.maximum_number(Double::NAN);
+    );
+    test_all_assert!(
+        maximum_number_neg_zero_zero:
+            Double::NEG_ZERO.maximum_number(Double::ZERO).is_sign_positive();
+    );
+
+    // clamp tests
+    test_all_exact!(
+        clamp_below:
+            Double::E,
+            dd!(1).clamp(Double::E, Double::PI);
+        clamp_above:
+            Double::PI,
+            dd!(4).clamp(Double::E, Double::PI);
+        clamp_within:
+            dd!(3),
+            dd!(3).clamp(Double::E, Double::PI);
+        clamp_low_component:
+            Double::PI,
+            Double::PI.next_up().clamp(Double::E, Double::PI);
+        clamp_inf:
+            Double::PI,
+            Double::INFINITY.clamp(Double::E, Double::PI);
+        clamp_neg_inf:
+            Double::E,
+            Double::NEG_INFINITY.clamp(Double::E, Double::PI);
+        clamp_equal_bounds:
+            Double::E,
+            Double::PI.clamp(Double::E, Double::E);
+        clamp_nan:
+            Double::NAN,
+            Double::NAN.clamp(Double::E, Double::PI);
+    );
+
+    #[test]
+    #[should_panic]
+    fn clamp_min_gt_max() {
+        Double::ONE.clamp(Double::PI, Double::E);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_min_nan() {
+        Double::ONE.clamp(Double::NAN, Double::E);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_max_nan() {
+        Double::ONE.clamp(Double::E, Double::NAN);
+    }
+
+    #[test]
+    fn to_fraction() {
+        assert_eq!(Double::PI.to_fraction(1), Some((3, 1)));
+        assert_eq!(Double::PI.to_fraction(7), Some((22, 7)));
+        assert_eq!(Double::PI.to_fraction(106), Some((333, 106)));
+        assert_eq!(
+            Double::PI.to_fraction(1_000_000),
+            Some((3_126_535, 995_207))
+        );
+        assert_eq!((-Double::E).to_fraction(1000), Some((-1_457, 536)));
+        assert_eq!(
+            (-Double::E).to_fraction(1_000_000),
+            Some((-1_084_483, 398_959))
+        );
+        assert_eq!(dd!(0).to_fraction(10), Some((0, 1)));
+        assert_eq!(dd!(5).to_fraction(10), Some((5, 1)));
+        assert_eq!((dd!(1) / dd!(3)).to_fraction(u64::MAX), Some((1, 3)));
+        assert_eq!((dd!(-22) / dd!(7)).to_fraction(6), Some((-19, 6)));
+        // Ties go to the convergent
+        assert_eq!(dd!(0.5).to_fraction(1), Some((0, 1)));
+        assert_eq!(dd!(1e40).to_fraction(10), None);
+        assert_eq!(Double::INFINITY.to_fraction(10), None);
+        assert_eq!(Double::PI.to_fraction(0), None);
+    }
 }
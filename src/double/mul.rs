@@ -25,15 +25,9 @@ impl Mul for Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    #[inline]
     fn mul(self, other: Double) -> Double {
-        match self.pre_mul(&other) {
-            Some(r) => r,
-            None => {
-                let (p, e) = p::two_prod(self.0, other.0);
-                let (a, b) = u::renorm2(p, e + self.0 * other.1 + self.1 * other.0);
-                Double(a, b)
-            }
-        }
+        self.mul_fast(other)
     }
 }
 
@@ -153,7 +147,162 @@ impl MulAssign<&Double> for Double {
     }
 }
 
+impl MulAssign<f64> for Double {
+    /// Multiplies this `Double` by an `f64`, modifying this one to equal the result.
+    ///
+    /// This implements the `*=` operator between a `Double` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = Double::PI;
+    /// x *= 2.0;
+    /// let expected = dd!("6.283185307179586476925286766559");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: f64) {
+        let r = self.mul(Double(other, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+    }
+}
+
 impl Double {
+    /// Multiplies this `Double` by another using the accurate multiplication algorithm.
+    ///
+    /// The cross terms (the products of the high component of each number with the low
+    /// component of the other) are computed with their own error terms, and the product of
+    /// the two low components is included as well. This makes the result correct to
+    /// within about 2 ULPs, at the cost of several more floating-point operations than
+    /// [`mul_fast`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::E.mul_accurate(Double::PI);
+    /// let expected = dd!("8.539734222673567065463550869547");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`mul_fast`]: #method.mul_fast
+    pub fn mul_accurate(self, other: Double) -> Double {
+        match self.pre_mul(&other) {
+            Some(r) => r,
+            None => {
+                let (p0, e0) = p::two_prod(self.0, other.0);
+                let (p1, e1) = p::two_prod(self.0, other.1);
+                let (p2, e2) = p::two_prod(self.1, other.0);
+                let (s0, s1) = p::two_sum(p1, p2);
+                let (h, l) = p::two_sum(p0, s0);
+                let (a, b) = u::renorm2(h, l + e0 + s1 + e1 + e2 + self.1 * other.1);
+                Double(a, b)
+            }
+        }
+    }
+
+    /// Multiplies this `Double` by another using the fast multiplication algorithm.
+    ///
+    /// This is the algorithm used by the `*` operator. Only the product of the two high
+    /// components is computed with an error term; the cross terms are added in with plain
+    /// `f64` arithmetic and the product of the two low components is dropped. The result is
+    /// accurate to within a few ULPs, which is sufficient for nearly every use.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::E.mul_fast(Double::PI);
+    /// let expected = dd!("8.539734222673567065463550869547");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    pub fn mul_fast(self, other: Double) -> Double {
+        match self.pre_mul(&other) {
+            Some(r) => r,
+            None => {
+                let (p, e) = p::two_prod(self.0, other.0);
+                let (a, b) = u::renorm2(p, e + self.0 * other.1 + self.1 * other.0);
+                Double(a, b)
+            }
+        }
+    }
+
+    /// Computes `self * a + b` with only one rounding error, yielding a more accurate
+    /// result than a separate multiplication and addition.
+    ///
+    /// The product of two `Double`s is computed exactly as a sum of eight `f64`s, and `b`
+    /// is added to it before anything is rounded. This avoids losing the part of the
+    /// product that doesn't fit into a `Double`, which matters most when `b` nearly cancels
+    /// the product out (as happens regularly in polynomial evaluation and dot products).
+    ///
+    /// If any of the three values is infinite or `NaN`, or if either factor is zero, the
+    /// result is the same as `self * a + b`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI.mul_add(Double::E, Double::LN_2);
+    /// let expected = dd!("9.2328814032335123748807829910048");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    pub fn mul_add(self, a: Double, b: Double) -> Double {
+        match self.pre_mul_add(&a, &b) {
+            Some(r) => r,
+            None => {
+                let (p0, e0) = p::two_prod(self.0, a.0);
+                let (p1, e1) = p::two_prod(self.0, a.1);
+                let (p2, e2) = p::two_prod(self.1, a.0);
+                let (p3, e3) = p::two_prod(self.1, a.1);
+
+                // Roughly in order of increasing magnitude, which makes distillation
+                // converge more quickly
+                let mut terms = [e3, p3, e2, e1, p2, p1, e0, b.1, p0, b.0];
+                let (a, b) = u::distill2(&mut terms);
+                Double(a, b)
+            }
+        }
+    }
+
+    /// Multiplies this `Double` by another, returning both the product and the rounding
+    /// error of the multiplication.
+    ///
+    /// The product is the same one that the `*` operator produces. The error is the
+    /// difference between the exact product and the rounded one, itself rounded to a
+    /// `Double`, so that the two together represent the exact product to about twice the
+    /// precision of a `Double`. This is the double-double counterpart of the [`two_prod`]
+    /// error-free transformation.
+    ///
+    /// If the product is infinite or `NaN`, the error is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let (p, e) = Double::PI.mul_with_err(Double::E);
+    ///
+    /// // The exact product needs more precision than a `Double` has, so some of it ends up
+    /// // in the error, which is much smaller than the product
+    /// assert!(e != Double::ZERO);
+    /// assert!(e.abs() < p * Double::EPSILON);
+    /// ```
+    ///
+    /// [`two_prod`]: crate::eft::two_prod
+    pub fn mul_with_err(self, other: Double) -> (Double, Double) {
+        let r = self * other;
+        if r.is_finite() {
+            (r, self.mul_add(other, -r))
+        } else {
+            (r, Double::ZERO)
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -163,6 +312,15 @@ impl Double {
     // This keeps the public functions from being mucked up with code that does validation
     // rather than calculation.
 
+    #[inline]
+    fn pre_mul_add(&self, a: &Double, b: &Double) -> Option<Double> {
+        if !self.is_finite() || !a.is_finite() || !b.is_finite() || self.is_zero() || a.is_zero() {
+            Some(*self * *a + *b)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_mul(&self, other: &Double) -> Option<Double> {
         if self.is_nan() || other.is_nan() {
@@ -195,9 +353,34 @@ impl Double {
     }
 }
 
+impl Double {
+    /// Multiplies this `Double` by another, returning `None` if the result is not finite.
+    ///
+    /// This mirrors the `checked_mul` methods of the primitive integer types. The result is
+    /// `None` if the product overflows to infinity or the result is `NaN`; this is always
+    /// the case when either argument is already infinite or `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.checked_mul(Double::E).is_some());
+    /// assert!(Double::MAX.checked_mul(dd!(2)).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_mul(self, other: Double) -> Option<Double> {
+        let r = self * other;
+        if r.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Quad;
 
     // mul tests
     test_all_near!(
@@ -300,6 +483,63 @@ mod tests {
             Double::ONE * Double::NAN;
     );
 
+    // mul_accurate/mul_fast tests
+    test_all_near!(
+        accurate_num_num:
+            dd!("8.5397342226735670654635508695465707"),
+            Double::PI.mul_accurate(Double::E);
+        accurate_num_neg_num:
+            dd!("-8.5397342226735670654635508695465707"),
+            Double::PI.mul_accurate(-Double::E);
+        accurate_num_small:
+            dd!("3.1415926535897932384626433832795009e-30"),
+            Double::PI.mul_accurate(dd!("1e-30"));
+        fast_num_num:
+            dd!("8.5397342226735670654635508695465707"),
+            Double::PI.mul_fast(Double::E);
+    );
+    test_all_exact!(
+        accurate_zero_inf:
+            Double::NAN,
+            Double::ZERO.mul_accurate(Double::INFINITY);
+        accurate_neg_zero_num:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.mul_accurate(Double::ONE);
+        accurate_nan_num:
+            Double::NAN,
+            Double::NAN.mul_accurate(Double::ONE);
+    );
+
+    // mul_add tests
+    test_all_near!(
+        mul_add_num_num_num:
+            dd!("9.2328814032335123748807829910047511"),
+            Double::PI.mul_add(Double::E, Double::LN_2);
+        mul_add_neg:
+            dd!("-7.8465870421136217560463187480883979"),
+            Double::PI.mul_add(-Double::E, Double::LN_2);
+    );
+    test_all_exact!(
+        mul_add_cancel:
+            Double(2f64.powi(-156), 2f64.powi(-210)),
+            Double(1.0 + f64::EPSILON, 2f64.powi(-105)).mul_add(
+                Double(1.0 + f64::EPSILON, 2f64.powi(-105)),
+                Double(-1.0 - 2.0 * f64::EPSILON, -2f64.powi(-103)),
+            );
+        mul_add_zero:
+            Double::E,
+            Double::ZERO.mul_add(Double::PI, Double::E);
+        mul_add_inf:
+            Double::INFINITY,
+            Double::INFINITY.mul_add(Double::PI, Double::E);
+        mul_add_inf_neg_inf:
+            Double::NAN,
+            Double::INFINITY.mul_add(Double::PI, Double::NEG_INFINITY);
+        mul_add_nan:
+            Double::NAN,
+            Double::PI.mul_add(Double::E, Double::NAN);
+    );
+
     // Assign tests. Assign code delegates to mul code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -313,6 +553,11 @@ mod tests {
             b *= &Double::E;
             near!(dd!("8.5397342226735670654635508695465707"), b);
         }
+        assign_f64: {
+            let mut c = Double::PI;
+            c *= 2.0;
+            near!(dd!("6.2831853071795864769252867665590057"), c);
+        }
     );
 
     test!(chain_tens: {
@@ -381,4 +626,52 @@ mod tests {
         value *= ten;
         near!("693147180559945309417232121458.18", value);
     });
+
+    // mul_with_err tests
+    test_all!(
+        mul_with_err_split: {
+            let x = Double(1.0, 2f64.powi(-60));
+            let (p, e) = x.mul_with_err(x);
+            exact!(Double(1.0, 2f64.powi(-59)), p);
+            exact!(Double(2f64.powi(-120), 0.0), e);
+        }
+        mul_with_err_no_err: {
+            let (p, e) = dd!(3).mul_with_err(dd!(7));
+            exact!(dd!(21), p);
+            exact!(Double::ZERO, e);
+        }
+        mul_with_err_pi_e: {
+            let (p, e) = Double::PI.mul_with_err(Double::E);
+            exact!(Double::PI * Double::E, p);
+            assert!(e.abs() < p * Double::EPSILON);
+            let product = Quad::from((p[0], p[1])) + Quad::from((e[0], e[1]));
+            let pi = Quad::from((Double::PI[0], Double::PI[1]));
+            let euler = Quad::from((Double::E[0], Double::E[1]));
+            let expected = pi * euler;
+            assert!((product - expected).abs() < Quad::from(1e-60));
+        }
+        mul_with_err_inf: {
+            let (p, e) = Double::INFINITY.mul_with_err(Double::PI);
+            exact!(Double::INFINITY, p);
+            exact!(Double::ZERO, e);
+        }
+        mul_with_err_overflow: {
+            let (p, e) = Double::MAX.mul_with_err(dd!(2));
+            exact!(Double::MAX * dd!(2), p);
+            exact!(Double::ZERO, e);
+        }
+        mul_with_err_nan: {
+            let (p, e) = Double::NAN.mul_with_err(Double::PI);
+            exact!(Double::NAN, p);
+            exact!(Double::ZERO, e);
+        }
+    );
+
+    // checked_mul tests
+    test_all_assert!(
+        checked_mul_num: Double::PI.checked_mul(Double::E) == Some(Double::PI * Double::E);
+        checked_mul_nan: Double::PI.checked_mul(Double::NAN).is_none();
+        checked_mul_inf: Double::INFINITY.checked_mul(Double::PI).is_none();
+        checked_mul_overflow: Double::MAX.checked_mul(dd!(2)).is_none();
+    );
 }
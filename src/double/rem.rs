@@ -188,6 +188,31 @@ impl RemAssign<&Double> for Double {
     }
 }
 
+impl Double {
+    /// Calculates the remainder of dividing this `Double` by another, returning `None` if
+    /// the result is not finite.
+    ///
+    /// This mirrors the `checked_rem` methods of the primitive integer types. The result is
+    /// `None` if the divisor is zero or the result is `NaN`; this is always the case when
+    /// either argument is already infinite or `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.checked_rem(Double::E).is_some());
+    /// assert!(Double::ONE.checked_rem(Double::ZERO).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_rem(self, other: Double) -> Option<Double> {
+        let r = self % other;
+        if r.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -302,4 +327,12 @@ mod tests {
             near!(dd!("0.42331082513074800310235591192684125"), b);
         }
     );
+
+    // checked_rem tests
+    test_all_assert!(
+        checked_rem_num: Double::PI.checked_rem(Double::E) == Some(Double::PI % Double::E);
+        checked_rem_nan: Double::PI.checked_rem(Double::NAN).is_none();
+        checked_rem_inf: Double::INFINITY.checked_rem(Double::PI).is_none();
+        checked_rem_overflow: Double::ONE.checked_rem(Double::ZERO).is_none();
+    );
 }
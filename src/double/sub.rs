@@ -26,6 +26,9 @@ impl Sub for Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     fn sub(self, other: Double) -> Double {
+        if cfg!(feature = "fast_arith") {
+            return self.add_fast(-other);
+        }
         match self.pre_sub(&other) {
             Some(r) => r,
             None => {
@@ -157,7 +160,76 @@ impl SubAssign<&Double> for Double {
     }
 }
 
+impl SubAssign<f64> for Double {
+    /// Subtracts an `f64` from this `Double`, modifying this one to equal the result.
+    ///
+    /// This implements the `-=` operator between a `Double` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = Double::PI;
+    /// x -= 2.0;
+    /// let expected = dd!("1.141592653589793238462643383279");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: f64) {
+        let r = self.sub(Double(other, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+    }
+}
+
 impl Double {
+    /// Subtracts another `Double` from this one, returning `None` if the result is not
+    /// finite.
+    ///
+    /// This mirrors the `checked_sub` methods of the primitive integer types. The result is
+    /// `None` if the difference overflows to infinity or the result is `NaN`; this is
+    /// always the case when either argument is already infinite or `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(Double::PI.checked_sub(Double::E).is_some());
+    /// assert!(Double::MIN.checked_sub(Double::MAX).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_sub(self, other: Double) -> Option<Double> {
+        let r = self - other;
+        if r.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Subtracts another `Double` from this one, returning both the difference and the
+    /// rounding error of the subtraction.
+    ///
+    /// This works just like [`add_with_err`]: the difference is the one that the `-`
+    /// operator produces, and the error is the rest of the exact difference, rounded to a
+    /// `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::PI;
+    /// let y = Double::ONE.ldexp(-130);
+    /// let (d, e) = x.sub_with_err(y);
+    ///
+    /// assert!(d == x);
+    /// assert!(e == -y);
+    /// ```
+    ///
+    /// [`add_with_err`]: #method.add_with_err
+    pub fn sub_with_err(self, other: Double) -> (Double, Double) {
+        self.add_with_err(-other)
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -204,6 +276,7 @@ impl Double {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Quad;
 
     // sub tests
     test_all_near!(
@@ -306,5 +379,43 @@ mod tests {
             b -= &Double::E;
             near!(dd!("0.42331082513074800310235591192684125"), b);
         }
+        assign_f64: {
+            let mut c = Double::PI;
+            c -= 2.0;
+            near!(dd!("1.1415926535897932384626433832795028"), c);
+        }
+    );
+
+    // sub_with_err tests
+    test_all!(
+        sub_with_err_split: {
+            let x = Double(1.0, 2f64.powi(-60));
+            let y = Double(2f64.powi(-120), 2f64.powi(-180));
+            let (d, e) = x.sub_with_err(y);
+            exact!(x, d);
+            exact!(-y, e);
+        }
+        sub_with_err_pi_e: {
+            let (d, e) = Double::PI.sub_with_err(Double::E);
+            exact!(Double::PI - Double::E, d);
+            let diff = Quad::from((d[0], d[1])) + Quad::from((e[0], e[1]));
+            let pi = Quad::from((Double::PI[0], Double::PI[1]));
+            let euler = Quad::from((Double::E[0], Double::E[1]));
+            let expected = pi - euler;
+            assert!((diff - expected).abs() < Quad::from(1e-60));
+        }
+        sub_with_err_inf: {
+            let (d, e) = Double::INFINITY.sub_with_err(Double::INFINITY);
+            exact!(Double::NAN, d);
+            exact!(Double::ZERO, e);
+        }
+    );
+
+    // checked_sub tests
+    test_all_assert!(
+        checked_sub_num: Double::PI.checked_sub(Double::E) == Some(Double::PI - Double::E);
+        checked_sub_nan: Double::PI.checked_sub(Double::NAN).is_none();
+        checked_sub_inf: Double::INFINITY.checked_sub(Double::PI).is_none();
+        checked_sub_overflow: Double::MIN.checked_sub(Double::MAX).is_none();
     );
 }
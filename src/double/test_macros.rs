@@ -3,6 +3,25 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+// Formats a number for a failure message, in decimal if the `display` feature is enabled
+// and as its components otherwise. With `e`, the decimal is in exponential notation.
+#[cfg(feature = "display")]
+macro_rules! show {
+    ($x:expr) => {
+        format!("{}", $x)
+    };
+    ($x:expr, e) => {
+        format!("{:e}", $x)
+    };
+}
+
+#[cfg(not(feature = "display"))]
+macro_rules! show {
+    ($x:expr $(, e)?) => {
+        format!("{:?}", $x)
+    };
+}
+
 macro_rules! prec {
     ($expected:expr, $actual:expr, $digits:expr $(,)?) => {
         let expected = Double::from($expected);
@@ -12,7 +31,7 @@ macro_rules! prec {
         } else {
             expected.0.abs().log10().ceil() as i32
         };
-        let epsilon = Double(10.0, 0.0).powi(mag - $digits);
+        let epsilon = Double(10.0, 0.0).powi((mag - $digits).into());
         let diff = (expected - actual).abs();
         let message = format!(
             concat!(
@@ -20,14 +39,21 @@ macro_rules! prec {
                 "Expected: {0}\n",
                 "Actual:   {1}\n",
                 "\n",
-                "Delta:    {2:e}\n",
-                "Epsilon:  {3:e}\n",
+                "Delta:    {2}\n",
+                "Epsilon:  {3}\n",
                 "\n",
                 "Components:\n",
                 "  Expected: {4:<22e} {5:e}\n",
                 "  Actual:   {6:<22e} {7:e}\n",
             ),
-            expected, actual, diff, epsilon, expected[0], expected[1], actual[0], actual[1]
+            show!(expected),
+            show!(actual),
+            show!(diff, e),
+            show!(epsilon, e),
+            expected[0],
+            expected[1],
+            actual[0],
+            actual[1]
         );
         assert!(diff < epsilon, message);
     };
@@ -53,7 +79,12 @@ macro_rules! exact {
                 "  Expected: {2:<22e} {3:e}\n",
                 "  Actual:   {4:<22e} {5:e}\n",
             ),
-            expected, actual, expected[0], expected[1], actual[0], actual[1]
+            show!(expected),
+            show!(actual),
+            expected[0],
+            expected[1],
+            actual[0],
+            actual[1]
         );
         if expected.is_nan() {
             assert!(actual.is_nan(), message);
@@ -79,8 +110,9 @@ macro_rules! test_prec {
 }
 
 macro_rules! test_near {
-    ($name:ident: $expected:expr, $actual:expr $(,)?) => {
+    ($(#[$attr:meta])* $name:ident: $expected:expr, $actual:expr $(,)?) => {
         #[test]
+        $(#[$attr])*
         fn $name() {
             near!($expected, $actual);
         }
@@ -120,9 +152,11 @@ macro_rules! test_all_prec {
     };
 }
 
+// Each test can be preceded by attributes, like `#[cfg(not(feature = "fast_arith"))]` for
+// a test whose precision depends on the accurate operators.
 macro_rules! test_all_near {
-    ($($name:ident: $expected:expr, $actual:expr);* $(;)?) => {
-        $(test_near!($name: $expected, $actual);)*
+    ($($(#[$attr:meta])* $name:ident: $expected:expr, $actual:expr);* $(;)?) => {
+        $(test_near!($(#[$attr])* $name: $expected, $actual);)*
     }
 }
 
@@ -8,14 +8,35 @@ use crate::double::Double;
 
 const INV_K: Double = Double(0.001953125, 0.0); //   1/512, used for exp
 
+// Guards a logarithm `q` that was calculated as a quotient of natural logarithms. If `q` is
+// within rounding error of an integer `n` and `b` raised to the power of `n` is exactly
+// `x`, then the exact answer is `n`, and that's returned instead of the (possibly
+// slightly-off) quotient.
+fn exact_log(x: Double, b: Double, q: Double) -> Double {
+    let n = q.round();
+    if (q - n).abs() < Double(1e-20, 0.0) && n.0.abs() <= 2100.0 {
+        let k = n.0 as i64;
+        let exact = if k >= 0 {
+            b.powi(k) == x
+        } else {
+            x * b.powi(-k) == Double::ONE
+        };
+        if exact {
+            return n;
+        }
+    }
+    q
+}
+
 impl Double {
     /// Computes the exponential function, *e*<sup>x</sup>, where *x* is this `Double`.
     ///
-    /// The result of this function grows rapidly. Once *x* exceeds 708, the result is too
-    /// large to represent with a `Double`; at that point the function begins to return
-    /// [`INFINITY`]. The limit on the low end is less due to the fact that the second
-    /// component needs to fit in an `f64` rather than the first, along with extra bits used
-    /// in argument reduction; this function begins to return 0 at -600.
+    /// The result of this function grows rapidly. Once *x* exceeds [`MAX_EXP_ARG`]
+    /// (about 709.78), the result is too large to represent and the function returns
+    /// [`INFINITY`]. Once *x* is less than [`MIN_EXP_ARG`] (about -745.13), the result is
+    /// too small to represent even as a subnormal number and the function returns 0. In
+    /// between, results smaller than about 10<sup>-276</sup> lose precision gradually,
+    /// because the lower components of the `Double` become subnormal.
     ///
     /// As *x* grows this function does lose a bit of precision. It's precise to at least 30
     /// digits up to values of -140 <= x <= 150, and from then until the limits, it's
@@ -31,87 +52,142 @@ impl Double {
     /// assert!(diff < dd!(1e-29));
     /// ```
     ///
+    /// [`MAX_EXP_ARG`]: #associatedconstant.MAX_EXP_ARG
+    /// [`MIN_EXP_ARG`]: #associatedconstant.MIN_EXP_ARG
     /// [`INFINITY`]: #associatedconstant.INFINITY
-    #[allow(clippy::many_single_char_names)]
     pub fn exp(self) -> Double {
         match self.pre_exp() {
             Some(r) => r,
             None => {
-                // Strategy:
-                //
-                // We first reduce the range of the argument to a convenient size to perform
-                // the calculation efficiently. This reduction takes advantage of the
-                // following identity.
-                //
-                //      exp(kx) = exp(x)^k
-                //
-                // We in fact go a little further because it makes the reduction easier.
-                //
-                //      exp(kx + m * ln(2)) = 2^m * exp(x)^k
-                //
-                // where m and k are arbitary integers. By choosing m appropriately we can
-                // make |kx| <= ln(2) / 2 = 0.347. Then exp(x) is evaluated using a Taylor
-                // series, which for exp(x) is pleasantly easy:
-                //
-                //      exp(x) = 1 + x + x^2/2! + x^3/3! + x^4/4! ...
-                //
-                // Reducing x substantially speeds up the convergence, so we have to use
-                // fewer terms to reach the required precision.
-                //
-                // Once we have executed the Taylor series to produce an intermediate
-                // answer, we expand it to compensate for the earlier reduction.
-
-                // k = 512 is chosen; INV_K is defined above as that reciprocal
-                let eps = c::mul_pwr2(Double::EPSILON, INV_K.0);
-                // m doesn't need to be *that* accurate, so we calculate it with f64
-                // arithmetic instead of the more expensive Double arithmetic
-                let m = (self.0 / Double::LN_2.0 + 0.5).floor();
-
-                // solving for x in exp(kx + m * ln(2)). INV_K is a power of 2 so we could
-                // use mul_exp2, but on larger numbers that causes a loss of precision when
-                // used with negative powers of two because bits are being shifted to the
-                // right without accounting for the ones that are lost off the right.
-                let x = (self - Double::LN_2 * Double(m, 0.0)) * INV_K;
-
-                // This is the "x + x^2/2! + x^3/3!" part of the Taylor series.
-                let mut p = x.sqr();
-                let mut r = x + c::mul_pwr2(p, 0.5);
-                p *= x;
-                let mut t = p * c::INV_FACTS[0];
-                let mut i = 0;
+                let (r, m) = self.exp_reduced();
 
-                // This is the rest of the Taylor series. We perform it as many times as
-                // we need to reach our desired precision.
-                loop {
-                    r += t;
-                    p *= x;
-                    i += 1;
-                    t = p * c::INV_FACTS[i];
-                    if i >= 5 || t.abs() <= eps {
-                        break;
-                    }
+                // Add the "1 +" part of the Taylor series, then do the final step of
+                // expansion, which is the "* 2^m" part
+                (r + Double::ONE).scale_b(m)
+            }
+        }
+    }
+
+    /// Computes *e*<sup>x</sup> - 1, where *x* is this `Double`.
+    ///
+    /// For *x* near zero, *e*<sup>x</sup> is near 1, and subtracting 1 from the result of
+    /// [`exp`] cancels away most of the precision of the answer. This function calculates
+    /// the difference directly and is accurate to full precision no matter how small *x*
+    /// is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1e-20).exp_m1();
+    /// let expected = dd!("1.000000000000000000005e-20");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-50));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    pub fn exp_m1(self) -> Double {
+        match self.pre_exp_m1() {
+            Some(r) => r,
+            None => {
+                let (r, m) = self.exp_reduced();
+                if m == 0 {
+                    r
+                } else if m > 1000 {
+                    // 2^m might overflow on its own here, but subtracting 1 can't cancel
+                    // anything either
+                    (r + Double::ONE).scale_b(m) - Double::ONE
+                } else {
+                    // exp(self) - 1 = 2^m * (1 + r) - 1 = 2^m * r + (2^m - 1), and the
+                    // subtraction in the last term is exact
+                    r.ldexp(m) + (Double(2f64.powi(m), 0.0) - Double::ONE)
                 }
+            }
+        }
+    }
+
+    /// Computes 2<sup>x</sup>, where *x* is this `Double`.
+    ///
+    /// The argument is split into an integer and a fraction no larger than 1/2 in absolute
+    /// value. Only the fraction goes through [`exp`], and the integer is applied exactly
+    /// as a power of 2 afterwards. This is more accurate than calculating
+    /// *e*<sup>x ln 2</sup> for large arguments, and an integer argument produces an exact
+    /// result over the whole range of `Double`s, including subnormal results.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(0.5).exp2();
+    /// let diff = (x - Double::SQRT_2).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// assert!(dd!(-1074).exp2()[0] == f64::from_bits(1));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    pub fn exp2(self) -> Double {
+        match self.pre_exp2() {
+            Some(r) => r,
+            None => {
+                let n = self.round();
+                let f = self - n;
+                let r = if f.is_zero() {
+                    Double::ONE
+                } else {
+                    (f * Double::LN_2).exp()
+                };
+                r.scale_b(n.0 as i32)
+            }
+        }
+    }
 
-                // Add the Taylor series parts together, then expand by the same number of
-                // times that we reduced earlier.
-                r += t;
-
-                // mul_pwr2 can be used here because multiplication doesn't lose precision
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-
-                // Finally, add the "1 +" part of the Taylor series.
-                r += Double::ONE;
-
-                // Final step of expansion, this is the "* 2^m" part
-                r.ldexp(m as i32)
+    /// Computes 10<sup>x</sup>, where *x* is this `Double`.
+    ///
+    /// As with [`exp2`], the argument is split into an integer and a fraction no larger
+    /// than 1/2 in absolute value. Only the fraction goes through [`exp`], and the integer
+    /// power of 10 is calculated separately with [`powi`]. This keeps large arguments
+    /// accurate, and it makes integer arguments produce exact results as long as the power
+    /// of 10 is representable.
+    ///
+    /// The function returns [`INFINITY`] for arguments greater than log<sub>10</sub> of
+    /// [`MAX`] (about 308.25) and 0 for arguments less than log<sub>10</sub>
+    /// 2<sup>-1075</sup> (about -323.61).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(0.5).exp10();
+    /// let diff = (x - dd!(10).sqrt()).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// assert!(dd!(20).exp10() == dd!(1e20));
+    /// ```
+    ///
+    /// [`exp2`]: #method.exp2
+    /// [`exp`]: #method.exp
+    /// [`powi`]: #method.powi
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn exp10(self) -> Double {
+        match self.pre_exp10() {
+            Some(r) => r,
+            None => {
+                let n = self.round();
+                let f = self - n;
+                let mut r = if f.is_zero() {
+                    Double::ONE
+                } else {
+                    (f * Double::LN_10).exp()
+                };
+
+                // Powers of 10 below 10^-308 underflow when they're calculated on their
+                // own, so the smallest ones are applied in two steps
+                let mut n = n.0 as i64;
+                if n < -300 {
+                    r *= Double(10.0, 0.0).powi(-300);
+                    n += 300;
+                }
+                r * Double(10.0, 0.0).powi(n)
             }
         }
     }
@@ -122,12 +198,11 @@ impl Double {
     /// large positive logarithm, for example, will require the calculation of a large
     /// negative exponential.
     ///
-    /// For the same reasons that negative values of [`exp`] are limited to -600, the
-    /// accurate results of this function are limited to the number whose logarithm is 600,
-    /// which is around 2.65 &times; 10<sup>261</sup>. Take care with this; unlike in
-    /// [`exp`], [`INFINITY`] is *not* returned. In that function, exceeding the maximum
-    /// refers to actually overflowing an `f64`, which is appropriate to call [`INFINITY`];
-    /// here, it means `601`.
+    /// Small results of [`exp`] lose precision once the lower components become subnormal,
+    /// and for the same reason the accurate results of this function are limited to numbers
+    /// whose logarithm is at most about 600, which is around 2.65 &times; 10<sup>261</sup>.
+    /// Beyond that, the result gradually loses precision. Take care with this; [`INFINITY`]
+    /// is *not* returned, since the logarithm itself is nowhere near overflowing.
     ///
     /// # Examples
     /// ```
@@ -181,8 +256,54 @@ impl Double {
         }
     }
 
+    /// Computes ln(1 + *x*), where *x* is this `Double`.
+    ///
+    /// For *x* near zero, adding 1 to *x* before taking the logarithm with [`ln`] loses
+    /// the digits of *x* that don't fit alongside the 1. This function works with *x*
+    /// directly and is accurate to full precision no matter how small *x* is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1e-20).ln_1p();
+    /// let expected = dd!("9.99999999999999999995e-21");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-50));
+    /// ```
+    ///
+    /// [`ln`]: #method.ln
+    pub fn ln_1p(self) -> Double {
+        match self.pre_ln_1p() {
+            Some(r) => r,
+            None => {
+                if self.0 >= 1.0 || self.0 < -0.5 {
+                    // There's no cancellation to worry about this far from zero, and near
+                    // -1 the addition is exact
+                    (self + Double::ONE).ln()
+                } else {
+                    // Newton's iteration on f(y) = exp(y) - 1 - x, which is
+                    //
+                    //      y' = y - (exp(y) - 1 - x) / exp(y)
+                    //
+                    // Each iteration roughly doubles the number of correct digits, starting
+                    // from the f64 approximation.
+                    let mut y = Double(self.0.ln_1p(), 0.0);
+                    for _ in 0..2 {
+                        let e = y.exp_m1();
+                        y -= (e - self) / (e + Double::ONE);
+                    }
+                    y
+                }
+            }
+        }
+    }
+
     /// Calculates the base-10 logarithm, log<sub>10</sub>, of the `Double`.
     ///
+    /// If the `Double` is exactly an integer power of 10, the result is exactly that
+    /// integer.
+    ///
     /// As with [`ln`], this has an upper usable range less than the size of the numbers
     /// themselves. In this case, that upper limit is around 10<sup>261</sup>. Over this
     /// number, the output is not reliable, but it does not return [`INFINITY`] because the
@@ -202,14 +323,16 @@ impl Double {
     /// [`ln`]: #method.ln
     #[inline]
     pub fn log10(self) -> Double {
-        self.ln() / Double::LN_10
+        exact_log(self, Double(10.0, 0.0), self.ln() / Double::LN_10)
     }
 
     /// Calculates the base-2 logarithm, log<sub>2</sub>, of the `Double`.
     ///
-    /// Since 2 is smaller than *e*, this function is constrained even more than [`ln`]. It
-    /// will start returning [`NEG_INFINITY`] at around 10<sup>-213</sup> and will start
-    /// to fail on the positive side at around 2.6 &times; 10<sup>180</sup>.
+    /// The binary exponent of the `Double` is split off first, and only the remaining
+    /// mantissa (which is within a factor of √2 of 1) goes through a natural logarithm.
+    /// This means that the full range of `Double`s, including subnormal numbers, can be
+    /// handled without any loss of accuracy, and if the `Double` is exactly an integer
+    /// power of 2, the result is exactly that integer.
     ///
     /// # Examples
     /// ```
@@ -221,11 +344,17 @@ impl Double {
     /// assert!(diff < dd!(1e-29));
     /// ```
     ///
-    /// [`ln`]: #method.ln
-    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
-    #[inline]
     pub fn log2(self) -> Double {
-        self.ln() / Double::LN_2
+        match self.pre_ln() {
+            Some(r) => r,
+            None => {
+                // f64's log2 is accurate enough that rounding it gives the exponent that
+                // puts the mantissa closest to 1, and scaling by a power of 2 is exact
+                let e = self.0.log2().round() as i32;
+                let m = self.scale_b(-e);
+                Double(e as f64, 0.0) + (m - Double::ONE).ln_1p() / Double::LN_2
+            }
+        }
     }
 
     /// Calculates the base `b` logarithm (log<sub>`b`</sub>) of the `Double`.
@@ -233,6 +362,9 @@ impl Double {
     /// This function will have limits at extreme arguments like the other logarithm
     /// functions. The difference is that those limits will depend on the base argument.
     ///
+    /// If `self` is exactly an integer power of `b`, the result is exactly that integer
+    /// (so, for example, the base 2 logarithm of 8 is exactly 3).
+    ///
     /// If the goal is to calculate the base *e*, base 2, or base 10 logarithms of `self`,
     /// the specialized functions for those purposes([`ln`], [`log2`], and [`log10`]
     /// respectively) will be more efficient.
@@ -254,10 +386,183 @@ impl Double {
     pub fn log(self, b: Double) -> Double {
         match self.pre_log(&b) {
             Some(r) => r,
-            None => self.ln() / b.ln(),
+            None => exact_log(self, b, self.ln() / b.ln()),
+        }
+    }
+
+    /// Calculates ln(*e*<sup>`self`</sup> + *e*<sup>`other`</sup>).
+    ///
+    /// This is the usual way to add two probabilities that are stored as their logarithms.
+    /// The exponentials are never calculated directly, so the result is accurate even when
+    /// they would overflow or underflow.
+    ///
+    /// [`NEG_INFINITY`] acts as the logarithm of zero: if one argument is [`NEG_INFINITY`],
+    /// the result is the other argument.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(2).ln().logaddexp(dd!(3).ln());
+    /// let expected = dd!(5).ln();
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// // exp(1000) overflows, but this doesn't
+    /// let y = dd!(1000).logaddexp(dd!(1000));
+    /// assert!((y - (dd!(1000) + Double::LN_2)).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn logaddexp(self, other: Double) -> Double {
+        match self.pre_logaddexp(&other) {
+            Some(r) => r,
+            None => {
+                // ln(e^a + e^b) = a + ln(1 + e^(b - a)), with a the larger of the two so
+                // that e^(b - a) can't overflow
+                let (a, b) = if self >= other {
+                    (self, other)
+                } else {
+                    (other, self)
+                };
+                a + (b - a).exp().ln_1p()
+            }
+        }
+    }
+
+    /// Calculates the logarithm of the sum of the exponentials of a slice of `Double`s.
+    ///
+    /// This is [`logaddexp`] extended to any number of values. The largest value is
+    /// factored out before the exponentials are calculated, so the result is accurate even
+    /// when they would overflow or underflow. The result is [`NAN`] if any of the values
+    /// are `NaN`, [`INFINITY`] if any of them are positive infinity, and [`NEG_INFINITY`]
+    /// if the slice is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::logsumexp(&[dd!(1).ln(), dd!(2).ln(), dd!(3).ln(), dd!(4).ln()]);
+    /// let expected = Double::LN_10;
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`logaddexp`]: #method.logaddexp
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn logsumexp(values: &[Double]) -> Double {
+        if values.iter().any(|x| x.is_nan()) {
+            return Double::NAN;
+        }
+        let (index, max) =
+            values
+                .iter()
+                .enumerate()
+                .fold((0, Double::NEG_INFINITY), |(i, m), (j, x)| {
+                    if *x > m {
+                        (j, *x)
+                    } else {
+                        (i, m)
+                    }
+                });
+        if max.is_infinite() {
+            max
+        } else {
+            // ln(sum(e^x)) = max + ln(1 + sum(e^(x - max))), where the sum inside the
+            // logarithm skips the maximum itself so that ln_1p can keep its precision
+            let sum: Double = values
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .map(|(_, x)| (*x - max).exp())
+                .sum();
+            max + sum.ln_1p()
         }
     }
 
+    // Calculates exp(x) - 1 for the reduced argument x = self - m * ln(2), returning that
+    // value along with m. exp(self) is then 2^m * (1 + the returned value).
+    //
+    // This is split out of `exp` so that `exp_m1` can use it too; for small arguments m is
+    // 0, and the returned value is exp(self) - 1 without any of the cancellation that
+    // comes from subtracting 1 from exp(self).
+    #[allow(clippy::many_single_char_names)]
+    fn exp_reduced(self) -> (Double, i32) {
+        // Strategy:
+        //
+        // We first reduce the range of the argument to a convenient size to perform
+        // the calculation efficiently. This reduction takes advantage of the
+        // following identity.
+        //
+        //      exp(kx) = exp(x)^k
+        //
+        // We in fact go a little further because it makes the reduction easier.
+        //
+        //      exp(kx + m * ln(2)) = 2^m * exp(x)^k
+        //
+        // where m and k are arbitary integers. By choosing m appropriately we can
+        // make |kx| <= ln(2) / 2 = 0.347. Then exp(x) is evaluated using a Taylor
+        // series, which for exp(x) is pleasantly easy:
+        //
+        //      exp(x) = 1 + x + x^2/2! + x^3/3! + x^4/4! ...
+        //
+        // Reducing x substantially speeds up the convergence, so we have to use
+        // fewer terms to reach the required precision.
+        //
+        // Once we have executed the Taylor series to produce an intermediate
+        // answer, we expand it to compensate for the earlier reduction.
+
+        // k = 512 is chosen; INV_K is defined above as that reciprocal
+        let eps = c::mul_pwr2(Double::EPSILON, INV_K.0);
+        // m doesn't need to be *that* accurate, so we calculate it with f64
+        // arithmetic instead of the more expensive Double arithmetic
+        let m = (self.0 / Double::LN_2.0 + 0.5).floor();
+
+        // solving for x in exp(kx + m * ln(2)). INV_K is a power of 2 so we could
+        // use mul_exp2, but on larger numbers that causes a loss of precision when
+        // used with negative powers of two because bits are being shifted to the
+        // right without accounting for the ones that are lost off the right.
+        let x = (self - Double::LN_2 * Double(m, 0.0)) * INV_K;
+
+        // This is the "x + x^2/2! + x^3/3!" part of the Taylor series.
+        let mut p = x.sqr();
+        let mut r = x + c::mul_pwr2(p, 0.5);
+        p *= x;
+        let mut t = p * c::INV_FACTS[0];
+        let mut i = 0;
+
+        // This is the rest of the Taylor series. We perform it as many times as
+        // we need to reach our desired precision.
+        loop {
+            r += t;
+            p *= x;
+            i += 1;
+            t = p * c::INV_FACTS[i];
+            if i >= 5 || t.abs() <= eps {
+                break;
+            }
+        }
+
+        // Add the Taylor series parts together, then expand by the same number of
+        // times that we reduced earlier.
+        r += t;
+
+        // mul_pwr2 can be used here because multiplication doesn't lose precision
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+
+        (r, m as i32)
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -269,9 +574,9 @@ impl Double {
 
     #[inline]
     fn pre_exp(&self) -> Option<Double> {
-        if self.0 < -600.0 {
+        if *self < Double::MIN_EXP_ARG {
             Some(Double::ZERO)
-        } else if self.0 > 708.0 {
+        } else if *self > Double::MAX_EXP_ARG {
             Some(Double::INFINITY)
         } else if self.is_nan() {
             Some(Double::NAN)
@@ -284,6 +589,67 @@ impl Double {
         }
     }
 
+    #[inline]
+    fn pre_exp_m1(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.0.abs() < 1e-290 {
+            // This includes zero, which keeps its sign
+            Some(*self)
+        } else if self.0 < -600.0 {
+            Some(-Double::ONE)
+        } else if *self > Double::MAX_EXP_ARG {
+            Some(Double::INFINITY)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp2(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.0 >= 1024.0 {
+            Some(Double::INFINITY)
+        } else if self.0 < -1080.0 {
+            Some(Double::ZERO)
+        } else if self.is_zero() {
+            Some(Double::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp10(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.0 > 308.25471555991675 {
+            Some(Double::INFINITY)
+        } else if self.0 < -323.60724533877976 {
+            Some(Double::ZERO)
+        } else if self.is_zero() {
+            Some(Double::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_ln_1p(&self) -> Option<Double> {
+        if self.is_nan() || *self < -Double::ONE {
+            Some(Double::NAN)
+        } else if *self == -Double::ONE {
+            Some(Double::NEG_INFINITY)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if self.is_infinite() {
+            Some(Double::INFINITY)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_ln(&self) -> Option<Double> {
         if self.is_nan() {
@@ -311,6 +677,21 @@ impl Double {
             None
         }
     }
+
+    #[inline]
+    fn pre_logaddexp(&self, other: &Double) -> Option<Double> {
+        if self.is_nan() || other.is_nan() {
+            Some(Double::NAN)
+        } else if *self == Double::NEG_INFINITY {
+            Some(*other)
+        } else if *other == Double::NEG_INFINITY {
+            Some(*self)
+        } else if self.is_infinite() || other.is_infinite() {
+            Some(Double::INFINITY)
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -418,7 +799,7 @@ mod tests {
     test_all_exact!(
         exp_below:
             Double::ZERO,
-            dd!(-710).exp();
+            dd!(-746).exp();
         exp_above:
             Double::INFINITY,
             dd!(710).exp();
@@ -439,6 +820,198 @@ mod tests {
             Double::NAN.exp();
     );
 
+    test_all_prec!(
+        exp_near_max:
+            dd!("1.73983687326416055769825271167383039386476848748305484259749248373e308"),
+            dd!(709.75).exp(),
+            29;
+        exp_m1_near_max:
+            dd!("1.73983687326416055769825271167383039386476848748305484259749248373e308"),
+            dd!(709.75).exp_m1(),
+            29;
+    );
+    test!(exp_max_arg: {
+        assert!(Double::MAX_EXP_ARG.exp().is_finite());
+    });
+    test_all_exact!(
+        exp_above_max_arg:
+            Double::INFINITY,
+            (Double::MAX_EXP_ARG + dd!(1e-10)).exp();
+        exp_m1_above_max_arg:
+            Double::INFINITY,
+            (Double::MAX_EXP_ARG + dd!(1e-10)).exp_m1();
+        exp_subnormal:
+            4.47628622567513e-309,
+            dd!(-710).exp()[0];
+        exp_min_subnormal:
+            f64::from_bits(1),
+            dd!(-745).exp()[0];
+        exp_below_min_arg:
+            Double::ZERO,
+            (Double::MIN_EXP_ARG - dd!(1e-10)).exp();
+    );
+
+    // exp_m1 tests
+    test_all_near!(
+        exp_m1_small:
+            dd!("1.000000000000000000005e-20"),
+            dd!("1e-20").exp_m1();
+        exp_m1_neg_small:
+            dd!("-9.99999999999999999995e-21"),
+            dd!("-1e-20").exp_m1();
+        exp_m1_tiny:
+            dd!("1.00000500001666670833341666680555575e-5"),
+            dd!("1e-5").exp_m1();
+        exp_m1_tenth:
+            dd!("0.105170918075647624811707826490246668"),
+            dd!("0.1").exp_m1();
+        exp_m1_neg_0_3:
+            dd!("-0.259181779318282133933126220682183128"),
+            dd!("-0.3").exp_m1();
+        exp_m1_1:
+            dd!("1.7182818284590452353602874713526625"),
+            dd!("1").exp_m1();
+        exp_m1_neg_1:
+            dd!("-0.632120558828557678404476229838539133"),
+            dd!("-1").exp_m1();
+        exp_m1_10:
+            dd!("2.20254657948067165169579006452842444e4"),
+            dd!("10").exp_m1();
+        exp_m1_neg_50:
+            dd!("-0.999999999999999999999807125015203608"),
+            dd!("-50").exp_m1();
+    );
+    test_all_exact!(
+        exp_m1_zero:
+            Double::ZERO,
+            Double::ZERO.exp_m1();
+        exp_m1_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.exp_m1();
+        exp_m1_inf:
+            Double::INFINITY,
+            Double::INFINITY.exp_m1();
+        exp_m1_neg_inf:
+            -Double::ONE,
+            Double::NEG_INFINITY.exp_m1();
+        exp_m1_nan:
+            Double::NAN,
+            Double::NAN.exp_m1();
+    );
+    test!(exp_m1_neg_zero_sign: {
+        assert!(Double::NEG_ZERO.exp_m1().is_sign_negative());
+    });
+
+    // exp2 tests
+    test_all_near!(
+        exp2_half:
+            Double::SQRT_2,
+            dd!(0.5).exp2();
+        exp2_neg_half:
+            Double::FRAC_1_SQRT_2,
+            dd!(-0.5).exp2();
+        exp2_3_375:
+            dd!("10.374716437208077327470032942339609278682761195399724102462324181"),
+            dd!(3.375).exp2();
+        exp2_neg_3_25:
+            dd!("0.105112051906714317878890684529151861880004282794598063851653260747"),
+            dd!(-3.25).exp2();
+        exp2_1023_5:
+            dd!("1.27116100615364628366052028422228385109549339363277773668712774121e308"),
+            dd!(1023.5).exp2();
+        exp2_log2:
+            Double::PI,
+            Double::PI.log2().exp2();
+    );
+    test_all_exact!(
+        exp2_10:
+            dd!(1024),
+            dd!(10).exp2();
+        exp2_neg_10:
+            dd!(0.0009765625),
+            dd!(-10).exp2();
+        exp2_1023:
+            Double::ONE.scale_b(1023),
+            dd!(1023).exp2();
+        exp2_neg_1074:
+            Double::ONE.scale_b(-1074),
+            dd!(-1074).exp2();
+        exp2_1024:
+            Double::INFINITY,
+            dd!(1024).exp2();
+        exp2_neg_1100:
+            Double::ZERO,
+            dd!(-1100).exp2();
+        exp2_zero:
+            Double::ONE,
+            Double::ZERO.exp2();
+        exp2_inf:
+            Double::INFINITY,
+            Double::INFINITY.exp2();
+        exp2_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.exp2();
+        exp2_nan:
+            Double::NAN,
+            Double::NAN.exp2();
+    );
+
+    // exp10 tests
+    test_all_near!(
+        exp10_half:
+            dd!("3.16227766016837933199889354443271853371955513932521682685750485279"),
+            dd!(0.5).exp10();
+        exp10_neg_2_25:
+            dd!("5.62341325190349080394951039776481231468251043098691664081689423736e-3"),
+            dd!(-2.25).exp10();
+        exp10_3_375:
+            dd!("2.37137370566165526165175275747888983856793665042552794627495175075e3"),
+            dd!(3.375).exp10();
+        exp10_308_25:
+            dd!("1.77827941003892280122542119519268484473579052640225535801183072278e308"),
+            dd!(308.25).exp10();
+    );
+    test_all_prec!(
+        // Parsing the expected value directly isn't accurate this close to the bottom of
+        // the range, so the result is scaled back up instead
+        exp10_neg_280_5:
+            dd!(10).sqrt(),
+            dd!(-280.5).exp10() * dd!(10).powi(281),
+            30;
+    );
+    test!(exp10_neg_310_5: {
+        // Too small for the lower components to be normal, so only the first one is
+        // checked
+        let x = dd!(-310.5).exp10();
+        assert!((x[0] / 3.162277660168e-311 - 1.0).abs() < 1e-12);
+    });
+    test_all_exact!(
+        exp10_20:
+            dd!(1e20),
+            dd!(20).exp10();
+        exp10_zero:
+            Double::ONE,
+            Double::ZERO.exp10();
+        exp10_subnormal:
+            3.16e-321,
+            dd!(-320.5).exp10()[0];
+        exp10_above:
+            Double::INFINITY,
+            dd!(308.5).exp10();
+        exp10_below:
+            Double::ZERO,
+            dd!(-324).exp10();
+        exp10_inf:
+            Double::INFINITY,
+            Double::INFINITY.exp10();
+        exp10_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.exp10();
+        exp10_nan:
+            Double::NAN,
+            Double::NAN.exp10();
+    );
+
     // ln tests
     test_all_near!(
         ln_pi:
@@ -499,6 +1072,62 @@ mod tests {
             Double::NAN.ln();
     );
 
+    // ln_1p tests
+    test_all_near!(
+        ln_1p_small:
+            dd!("9.99999999999999999995e-21"),
+            dd!("1e-20").ln_1p();
+        ln_1p_neg_small:
+            dd!("-1.000000000000000000005e-20"),
+            dd!("-1e-20").ln_1p();
+        ln_1p_tiny:
+            dd!("9.99995000033333083335333316666809523e-6"),
+            dd!("1e-5").ln_1p();
+        ln_1p_tenth:
+            dd!("0.0953101798043248600439521232807650922"),
+            dd!("0.1").ln_1p();
+        ln_1p_neg_0_3:
+            dd!("-0.356674943938732378912638711241184478"),
+            dd!("-0.3").ln_1p();
+        ln_1p_1:
+            dd!("0.693147180559945309417232121458176568"),
+            dd!("1").ln_1p();
+        ln_1p_10:
+            dd!("2.3978952727983705440619435779651293"),
+            dd!("10").ln_1p();
+        ln_1p_1e10:
+            dd!("23.0258509300404568401749145468439754"),
+            dd!("1e10").ln_1p();
+        ln_1p_neg_0_9:
+            dd!("-2.30258509299404568401799145468436421"),
+            dd!("-0.9").ln_1p();
+    );
+    test_all_exact!(
+        ln_1p_zero:
+            Double::ZERO,
+            Double::ZERO.ln_1p();
+        ln_1p_neg_1:
+            Double::NEG_INFINITY,
+            (-Double::ONE).ln_1p();
+        ln_1p_neg_2:
+            Double::NAN,
+            dd!(-2).ln_1p();
+        ln_1p_inf:
+            Double::INFINITY,
+            Double::INFINITY.ln_1p();
+        ln_1p_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.ln_1p();
+        ln_1p_nan:
+            Double::NAN,
+            Double::NAN.ln_1p();
+    );
+    test_all_near!(
+        ln_1p_exp_m1:
+            dd!("1e-10"),
+            dd!("1e-10").exp_m1().ln_1p();
+    );
+
     // log10 tests
     test_all_near!(
         log10_pi:
@@ -618,6 +1247,22 @@ mod tests {
             Double::NAN,
             Double::NAN.log2();
     );
+    test_all_near!(
+        log2_300:
+            dd!("996.578428466208704361095828846817052759449417907374183616426918745"),
+            dd!(10).powi(300).log2();
+        log2_subnormal:
+            dd!("-1058.41503749927884381854626105605218349124018559230751893954424735"),
+            dd!(3).scale_b(-1060).log2();
+    );
+    test_all_exact!(
+        log2_exact_neg_1074:
+            dd!(-1074),
+            Double::ONE.scale_b(-1074).log2();
+        log2_exact_1023:
+            dd!(1023),
+            Double::ONE.scale_b(1023).log2();
+    );
 
     // log tests
     test_all_near!(
@@ -636,6 +1281,7 @@ mod tests {
         log_sqrt_2:
             dd!("0.12159929443072307483899992782618255"),
             Double::SQRT_2.log(dd!(17.29));
+        #[cfg(not(feature = "fast_arith"))]
         log_1_sqrt_2:
             dd!("0.075257498915995298803434723681123436"),
             Double::FRAC_1_SQRT_2.log(dd!(0.01));
@@ -681,4 +1327,121 @@ mod tests {
             Double::NAN,
             Double::PI.log(Double::NAN);
     );
+
+    // logaddexp tests
+    test_all_near!(
+        logaddexp_2_3:
+            dd!("1.609437912434100374600759333226187639525601354268517721912647891474179"),
+            dd!(2).ln().logaddexp(dd!(3).ln());
+        logaddexp_3_2:
+            dd!("1.609437912434100374600759333226187639525601354268517721912647891474179"),
+            dd!(3).ln().logaddexp(dd!(2).ln());
+        logaddexp_large:
+            dd!("1000.69314718055994530941723212145817656807550013436025525412068000949339"),
+            dd!(1000).logaddexp(dd!(1000));
+        logaddexp_neg_large:
+            dd!("-999.306852819440054690582767878541823431924499865639744745879319990506606"),
+            dd!(-1000).logaddexp(dd!(-1000));
+    );
+    test_all_prec!(
+        logaddexp_small:
+            dd!("4.2483542552915889863049778436315821818777506798229745422054513756e-18"),
+            Double::ZERO.logaddexp(dd!(-40)),
+            30;
+    );
+    test_all_exact!(
+        logaddexp_far_apart:
+            dd!(1000),
+            dd!(1000).logaddexp(dd!(-1000));
+        logaddexp_neg_inf:
+            dd!(3),
+            dd!(3).logaddexp(Double::NEG_INFINITY);
+        logaddexp_neg_inf_self:
+            dd!(3),
+            Double::NEG_INFINITY.logaddexp(dd!(3));
+        logaddexp_both_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.logaddexp(Double::NEG_INFINITY);
+        logaddexp_inf:
+            Double::INFINITY,
+            Double::INFINITY.logaddexp(dd!(3));
+        logaddexp_inf_neg_inf:
+            Double::INFINITY,
+            Double::INFINITY.logaddexp(Double::NEG_INFINITY);
+        logaddexp_nan:
+            Double::NAN,
+            Double::NAN.logaddexp(dd!(3));
+        logaddexp_other_nan:
+            Double::NAN,
+            dd!(3).logaddexp(Double::NAN);
+    );
+
+    // logsumexp tests
+    test_all_near!(
+        logsumexp_ln_1_to_4:
+            Double::LN_10,
+            Double::logsumexp(&[dd!(1).ln(), dd!(2).ln(), dd!(3).ln(), dd!(4).ln()]);
+        logsumexp_ln_1_to_3:
+            dd!("1.791759469228055000812477358380702272722990692183004705855374343130888"),
+            Double::logsumexp(&[dd!(3).ln(), dd!(1).ln(), dd!(2).ln()]);
+        logsumexp_large:
+            dd!("1001.09861228866810969139524523692252570464749055782274945173469433363749"),
+            Double::logsumexp(&[dd!(1000), dd!(1000), dd!(1000)]);
+    );
+    test_all_exact!(
+        logsumexp_one:
+            Double::PI,
+            Double::logsumexp(&[Double::PI]);
+        logsumexp_empty:
+            Double::NEG_INFINITY,
+            Double::logsumexp(&[]);
+        logsumexp_neg_inf:
+            Double::PI,
+            Double::logsumexp(&[Double::NEG_INFINITY, Double::PI, Double::NEG_INFINITY]);
+        logsumexp_all_neg_inf:
+            Double::NEG_INFINITY,
+            Double::logsumexp(&[Double::NEG_INFINITY, Double::NEG_INFINITY]);
+        logsumexp_inf:
+            Double::INFINITY,
+            Double::logsumexp(&[Double::PI, Double::INFINITY]);
+        logsumexp_nan:
+            Double::NAN,
+            Double::logsumexp(&[Double::PI, Double::NAN, Double::INFINITY]);
+    );
+
+    // exact log tests
+    test_all_exact!(
+        log_exact_8_2:
+            dd!(3),
+            dd!(8).log(dd!(2));
+        log_exact_eighth_2:
+            dd!(-3),
+            dd!(0.125).log(dd!(2));
+        log_exact_8_half:
+            dd!(-3),
+            dd!(8).log(dd!(0.5));
+        log_exact_1000_10:
+            dd!(3),
+            dd!(1000).log(dd!(10));
+        log_exact_243_3:
+            dd!(5),
+            dd!(243).log(dd!(3));
+        log_exact_2_2:
+            dd!(1),
+            dd!(2).log(dd!(2));
+        log10_exact_30:
+            dd!(30),
+            dd!(10).powi(30).log10();
+        log2_exact_1024:
+            dd!(10),
+            dd!(1024).log2();
+        log2_exact_neg_100:
+            dd!(-100),
+            dd!(2).powi(-100).log2();
+    );
+    test_all_near!(
+        log_inexact_1001_10:
+            dd!("3.0004340774793186406689213877779888660200037751774867729013649474"),
+            dd!(1001).log(dd!(10));
+    );
 }
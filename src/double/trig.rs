@@ -48,6 +48,58 @@ impl Double {
                     }
                 };
 
+                match j {
+                    0 => (s, c),
+                    1 => (c, -s),
+                    3 => (-c, s),
+                    _ => (-s, -c),
+                }
+            }
+        }
+    }
+
+    /// Simultaneously computes the sine and cosine of the `Double` multiplied by π, i.e.,
+    /// `sin(πx)` and `cos(πx)`. Both values come from a single argument reduction.
+    ///
+    /// The reduction is done on the argument itself rather than on the product with π, and
+    /// it's exact. This means that the results are accurate no matter how large the
+    /// argument is, and integers and half-integers produce exact zeros and ones (possibly
+    /// negative). This makes this function a better choice than [`sin_cos`] for generating
+    /// rotation angles and FFT twiddle factors, which are naturally expressed as fractions
+    /// of a half turn.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (sin_x, cos_x) = dd!(0.25).sin_cos_pi();
+    /// let expected = Double::FRAC_1_SQRT_2;
+    ///
+    /// assert!((sin_x - expected).abs() < dd!(1e-30));
+    /// assert!((cos_x - expected).abs() < dd!(1e-30));
+    ///
+    /// let (sin_y, cos_y) = dd!(1.5).sin_cos_pi();
+    /// assert!(sin_y == -Double::ONE);
+    /// assert!(cos_y == Double::ZERO);
+    /// ```
+    ///
+    /// [`sin_cos`]: #method.sin_cos
+    pub fn sin_cos_pi(self) -> (Double, Double) {
+        match self.pre_sin_cos_pi() {
+            Some(r) => r,
+            None => {
+                // Reducing modulo 2 and then to the nearest multiple of 1/2 only subtracts
+                // nearby integers and half-integers, so neither step loses any bits
+                let r = self - c::mul_pwr2(c::mul_pwr2(self, 0.5).round(), 2.0);
+                let q = c::mul_pwr2(r, 2.0).round();
+                let t = r - c::mul_pwr2(q, 0.5);
+                let j = q.0 as i32;
+
+                let (s, c) = if t.is_zero() {
+                    (Double::ZERO, Double::ONE)
+                } else {
+                    (t * Double::PI).sin_cos()
+                };
+
                 match j {
                     0 => (s, c),
                     1 => (c, -s),
@@ -92,7 +144,7 @@ impl Double {
                     match j {
                         0 => sin_taylor(t),
                         1 => cos_taylor(t),
-                        -1 => -cos_taylor(t),
+                        3 => -cos_taylor(t),
                         _ => -sin_taylor(t),
                     }
                 } else {
@@ -104,14 +156,14 @@ impl Double {
                         match j {
                             0 => u * sin_t + v * cos_t,
                             1 => u * cos_t - v * sin_t,
-                            -1 => -u * cos_t + v * sin_t,
+                            3 => -u * cos_t + v * sin_t,
                             _ => -u * sin_t - v * cos_t,
                         }
                     } else {
                         match j {
                             0 => u * sin_t - v * cos_t,
                             1 => u * cos_t + v * sin_t,
-                            -1 => -u * cos_t - v * sin_t,
+                            3 => -u * cos_t - v * sin_t,
                             _ => -u * sin_t + v * cos_t,
                         }
                     }
@@ -145,7 +197,7 @@ impl Double {
                     match j {
                         0 => cos_taylor(t),
                         1 => -sin_taylor(t),
-                        -1 => sin_taylor(t),
+                        3 => sin_taylor(t),
                         _ => -cos_taylor(t),
                     }
                 } else {
@@ -157,14 +209,14 @@ impl Double {
                         match j {
                             0 => u * cos_t - v * sin_t,
                             1 => -u * sin_t - v * cos_t,
-                            -1 => u * sin_t + v * cos_t,
+                            3 => u * sin_t + v * cos_t,
                             _ => -u * cos_t + v * sin_t,
                         }
                     } else {
                         match j {
                             0 => u * cos_t + v * sin_t,
                             1 => v * cos_t - u * sin_t,
-                            -1 => u * sin_t - v * cos_t,
+                            3 => u * sin_t - v * cos_t,
                             _ => -u * cos_t - v * sin_t,
                         }
                     }
@@ -336,6 +388,80 @@ impl Double {
         self.atan2(Double::ONE)
     }
 
+    /// Reduces the `Double` modulo 2π, returning a value in the range [-π, π].
+    ///
+    /// This is the same reduction that the trigonometric functions use internally. It
+    /// subtracts the nearest multiple of [`TAU`], which carries the full precision of a
+    /// `Double`, so the absolute error of the result is roughly the size of the argument
+    /// times [`EPSILON`].
+    ///
+    /// Infinite and `NaN` arguments produce [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(7).rem_2pi();
+    /// let expected = dd!(7) - Double::TAU;
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`TAU`]: #associatedconstant.TAU
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn rem_2pi(self) -> Double {
+        match self.pre_rem_2pi() {
+            Some(r) => r,
+            None => {
+                let z = (self / Double::TAU).round();
+                self - z * Double::TAU
+            }
+        }
+    }
+
+    /// Reduces the `Double` to an angle in the range [-π/4, π/4] and the quadrant that it
+    /// was reduced from.
+    ///
+    /// The result is a pair `(q, r)` where `q` is in the range [0, 3] and x is congruent to
+    /// r + qπ/2 modulo 2π. The reduction is done by [`rem_2pi`] followed by the removal of
+    /// the nearest multiple of π/2, which is the same reduction that the trigonometric
+    /// functions use internally, and it can be used to build other periodic functions in
+    /// the same way. For example, the sine of x is sin r, cos r, -sin r, or -cos r for the
+    /// four quadrants.
+    ///
+    /// Infinite and `NaN` arguments produce a quadrant of 0 and a reduced angle of
+    /// [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (q, r) = dd!(10).reduce_angle();
+    /// assert!(q == 2);
+    ///
+    /// // 10 = r + 2(π/2) + 2π
+    /// let expected = dd!(10) - dd!(3) * Double::PI;
+    /// let diff = (r - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// // so sin 10 = -sin r
+    /// let diff = (dd!(10).sin() + r.sin()).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`rem_2pi`]: #method.rem_2pi
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn reduce_angle(self) -> (i32, Double) {
+        let r = self.rem_2pi();
+        if r.is_nan() {
+            (0, r)
+        } else {
+            let q = (r.0 / Double::FRAC_PI_2.0 + 0.5).floor();
+            let t = r - Double(q, 0.0) * Double::FRAC_PI_2;
+            ((q as i32).rem_euclid(4), t)
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -356,6 +482,26 @@ impl Double {
         }
     }
 
+    #[inline]
+    fn pre_sin_cos_pi(&self) -> Option<(Double, Double)> {
+        if self.is_zero() {
+            Some((*self, Double::ONE))
+        } else if !self.is_finite() {
+            Some((Double::NAN, Double::NAN))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_rem_2pi(&self) -> Option<Double> {
+        if !self.is_finite() {
+            Some(Double::NAN)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_sin(&self) -> Option<Double> {
         if self.is_zero() {
@@ -519,17 +665,11 @@ fn sincos_taylor(a: Double) -> (Double, Double) {
 #[inline]
 #[allow(clippy::many_single_char_names)]
 fn reduce(a: Double) -> (i32, i32, Double) {
-    // reduce modulo 2π
-    let z = (a / Double::TAU).round();
-    let r = a - z * Double::TAU;
-
-    // reduce modulo π/2
-    let mut q = (r.0 / Double::FRAC_PI_2.0 + 0.5).floor();
-    let mut t = r - Double(q, 0.0) * Double::FRAC_PI_2;
-    let j = q as i32;
+    // reduce modulo 2π and then modulo π/2
+    let (j, mut t) = a.reduce_angle();
 
     // reduce modulo π/16
-    q = (t.0 / Double::FRAC_PI_16.0 + 0.5).floor();
+    let q = (t.0 / Double::FRAC_PI_16.0 + 0.5).floor();
     t -= Double(q, 0.0) * Double::FRAC_PI_16;
     let k = q as i32;
 
@@ -836,6 +976,114 @@ mod tests {
             Double::NAN.sin_cos().1;
     );
 
+    // sin_cos_pi tests
+    test_all_near!(
+        sin_cos_pi_eighth_sin:
+            dd!("0.3826834323650897717284599840303988667613445624856270414338006356"),
+            dd!(0.125).sin_cos_pi().0;
+        sin_cos_pi_eighth_cos:
+            dd!("0.9238795325112867561281831893967882868224166258636424861150977313"),
+            dd!(0.125).sin_cos_pi().1;
+        sin_cos_pi_three_eighths_sin:
+            dd!("0.9238795325112867561281831893967882868224166258636424861150977313"),
+            dd!(0.375).sin_cos_pi().0;
+        sin_cos_pi_three_eighths_cos:
+            dd!("0.3826834323650897717284599840303988667613445624856270414338006356"),
+            dd!(0.375).sin_cos_pi().1;
+        sin_cos_pi_neg_five_eighths_sin:
+            dd!("-0.9238795325112867561281831893967882868224166258636424861150977313"),
+            dd!(-0.625).sin_cos_pi().0;
+        sin_cos_pi_neg_five_eighths_cos:
+            dd!("-0.3826834323650897717284599840303988667613445624856270414338006356"),
+            dd!(-0.625).sin_cos_pi().1;
+        sin_cos_pi_two_and_seven_eighths_sin:
+            dd!("0.3826834323650897717284599840303988667613445624856270414338006356"),
+            dd!(2.875).sin_cos_pi().0;
+        sin_cos_pi_two_and_seven_eighths_cos:
+            dd!("-0.9238795325112867561281831893967882868224166258636424861150977313"),
+            dd!(2.875).sin_cos_pi().1;
+        sin_cos_pi_thousand_one_sixteenth_sin:
+            dd!("-0.1950903220161282678482848684770222409276916177519548077545020895"),
+            dd!(1001.0625).sin_cos_pi().0;
+        sin_cos_pi_thousand_one_sixteenth_cos:
+            dd!("-0.9807852804032304491261822361342390369739337308933360950029160885"),
+            dd!(1001.0625).sin_cos_pi().1;
+        sin_cos_pi_neg_three_and_five_sixteenths_sin:
+            dd!("0.8314696123025452370787883776179057567385608119872499634461245902"),
+            dd!(-3.3125).sin_cos_pi().0;
+        sin_cos_pi_neg_three_and_five_sixteenths_cos:
+            dd!("-0.5555702330196022247428308139485328743749371907548040459241535282"),
+            dd!(-3.3125).sin_cos_pi().1;
+        sin_cos_pi_large_sin:
+            dd!("0.9238795325112867561281831893967882868224166258636424861150977313"),
+            Double::new(1e20, 0.375).sin_cos_pi().0;
+        sin_cos_pi_large_cos:
+            dd!("0.3826834323650897717284599840303988667613445624856270414338006356"),
+            Double::new(1e20, 0.375).sin_cos_pi().1;
+    );
+    test_all_exact!(
+        sin_cos_pi_zero_sin:
+            Double::ZERO,
+            Double::ZERO.sin_cos_pi().0;
+        sin_cos_pi_zero_cos:
+            Double::ONE,
+            Double::ZERO.sin_cos_pi().1;
+        sin_cos_pi_neg_zero_sin:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.sin_cos_pi().0;
+        sin_cos_pi_neg_zero_cos:
+            Double::ONE,
+            Double::NEG_ZERO.sin_cos_pi().1;
+        sin_cos_pi_half_sin:
+            Double::ONE,
+            dd!(0.5).sin_cos_pi().0;
+        sin_cos_pi_half_cos:
+            Double::ZERO,
+            dd!(0.5).sin_cos_pi().1;
+        sin_cos_pi_one_sin:
+            Double::ZERO,
+            Double::ONE.sin_cos_pi().0;
+        sin_cos_pi_one_cos:
+            Double::NEG_ONE,
+            Double::ONE.sin_cos_pi().1;
+        sin_cos_pi_three_halves_sin:
+            Double::NEG_ONE,
+            dd!(1.5).sin_cos_pi().0;
+        sin_cos_pi_three_halves_cos:
+            Double::ZERO,
+            dd!(1.5).sin_cos_pi().1;
+        sin_cos_pi_neg_half_sin:
+            Double::NEG_ONE,
+            dd!(-0.5).sin_cos_pi().0;
+        sin_cos_pi_neg_half_cos:
+            Double::ZERO,
+            dd!(-0.5).sin_cos_pi().1;
+        sin_cos_pi_two_sin:
+            Double::ZERO,
+            dd!(2).sin_cos_pi().0;
+        sin_cos_pi_two_cos:
+            Double::ONE,
+            dd!(2).sin_cos_pi().1;
+        sin_cos_pi_inf_sin:
+            Double::NAN,
+            Double::INFINITY.sin_cos_pi().0;
+        sin_cos_pi_inf_cos:
+            Double::NAN,
+            Double::INFINITY.sin_cos_pi().1;
+        sin_cos_pi_neg_inf_sin:
+            Double::NAN,
+            Double::NEG_INFINITY.sin_cos_pi().0;
+        sin_cos_pi_neg_inf_cos:
+            Double::NAN,
+            Double::NEG_INFINITY.sin_cos_pi().1;
+        sin_cos_pi_nan_sin:
+            Double::NAN,
+            Double::NAN.sin_cos_pi().0;
+        sin_cos_pi_nan_cos:
+            Double::NAN,
+            Double::NAN.sin_cos_pi().1;
+    );
+
     // tan tests
     test_all_near!(
         tan_zero:
@@ -1140,4 +1388,78 @@ mod tests {
             Double::NAN,
             Double::NAN.atan();
     );
+
+    // rem_2pi tests
+    // The absolute error is about the size of the argument times EPSILON
+    test_all_prec!(
+        rem_2pi_7:
+            dd!("0.716814692820413523074713233440994231605661201249788358050110815384"),
+            dd!(7).rem_2pi(),
+            29;
+        rem_2pi_neg_5:
+            dd!("1.28318530717958647692528676655900576839433879875021164194988918462"),
+            dd!(-5).rem_2pi(),
+            29;
+        rem_2pi_100:
+            dd!("-0.53096491487338363080458826494409229430942078000338627119822695385"),
+            dd!(100).rem_2pi(),
+            29;
+        rem_2pi_small:
+            Double::FRAC_PI_3,
+            Double::FRAC_PI_3.rem_2pi(),
+            29;
+    );
+    test_all_exact!(
+        rem_2pi_zero:
+            Double::ZERO,
+            Double::ZERO.rem_2pi();
+        rem_2pi_inf:
+            Double::NAN,
+            Double::INFINITY.rem_2pi();
+        rem_2pi_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.rem_2pi();
+        rem_2pi_nan:
+            Double::NAN,
+            Double::NAN.rem_2pi();
+    );
+
+    // reduce_angle tests
+    test_all_prec!(
+        reduce_angle_10:
+            dd!("0.575222039230620284612069850161491347408491801874682537075166223077"),
+            dd!(10).reduce_angle().1,
+            29;
+        reduce_angle_neg_5:
+            dd!("-0.287611019615310142306034925080745673704245900937341268537583111538"),
+            dd!(-5).reduce_angle().1,
+            29;
+        reduce_angle_100:
+            dd!("-0.53096491487338363080458826494409229430942078000338627119822695385"),
+            dd!(100).reduce_angle().1,
+            29;
+    );
+    test_all_exact!(
+        reduce_angle_10_quadrant:
+            2,
+            dd!(10).reduce_angle().0;
+        reduce_angle_neg_5_quadrant:
+            1,
+            dd!(-5).reduce_angle().0;
+        reduce_angle_100_quadrant:
+            0,
+            dd!(100).reduce_angle().0;
+        reduce_angle_neg_2_quadrant:
+            3,
+            dd!(-2).reduce_angle().0;
+        reduce_angle_inf_quadrant:
+            0,
+            Double::INFINITY.reduce_angle().0;
+        reduce_angle_inf:
+            Double::NAN,
+            Double::INFINITY.reduce_angle().1;
+        reduce_angle_nan:
+            Double::NAN,
+            Double::NAN.reduce_angle().1;
+    );
 }
@@ -0,0 +1,97 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Error-free transformations and renormalization of `f64`s.
+//!
+//! These are the building blocks that all of the arithmetic in [`Double`] and [`Quad`] is
+//! built from. An *error-free transformation* takes one or two `f64`s and returns the
+//! rounded result of an operation along with the exact rounding error of that operation,
+//! so that the two together represent the true result with no loss at all. For example,
+//! [`two_sum`] returns `(s, e)` where `s` is `a + b` rounded to an `f64` and `s + e` is
+//! *exactly* `a + b`.
+//!
+//! The renormalization functions take several overlapping components (such as the ones
+//! produced by a chain of error-free transformations) and rearrange them into a
+//! non-overlapping form, where each component has an absolute value of no more than half
+//! an ULP of the component before it. This is the form that [`Double`] and [`Quad`] store
+//! their components in.
+//!
+//! Nothing in this module knows about [`Double`] or [`Quad`]; all of the functions take and
+//! return `f64`s and tuples of `f64`s. They are exposed for writing custom kernels that need
+//! a single transformation without paying for a full extended-precision operation.
+//!
+//! # Examples
+//! ```
+//! use qd::eft::two_sum;
+//!
+//! let (s, e) = two_sum(1.0, 1e-20);
+//! assert!(s == 1.0);
+//! assert!(e == 1e-20); // the part of the sum that didn't fit into `s`
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+
+pub use crate::common::primitive::{quick_two_sum, split, two_diff, two_prod, two_sqr, two_sum};
+pub use crate::common::utils::{
+    renorm2, renorm3, renorm4, renorm5, three_three_sum, three_two_sum,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_sum_exact() {
+        let (s, e) = two_sum(0.1, 0.2);
+        assert!(s == 0.1 + 0.2);
+        assert!(e == -2.7755575615628914e-17);
+    }
+
+    #[test]
+    fn quick_two_sum_exact() {
+        let (s, e) = quick_two_sum(1.0, 1e-20);
+        assert!(s == 1.0);
+        assert!(e == 1e-20);
+    }
+
+    #[test]
+    fn two_diff_exact() {
+        let (s, e) = two_diff(1.0, 1e-20);
+        assert!(s == 1.0);
+        assert!(e == -1e-20);
+    }
+
+    #[test]
+    fn two_prod_exact() {
+        let a = 1.0 + f64::EPSILON;
+        let (p, e) = two_prod(a, a);
+        assert!(p == 1.0 + 2.0 * f64::EPSILON);
+        assert!(e == f64::EPSILON * f64::EPSILON);
+    }
+
+    #[test]
+    fn two_sqr_matches_two_prod() {
+        let x = std::f64::consts::PI;
+        assert!(two_sqr(x) == two_prod(x, x));
+    }
+
+    #[test]
+    fn split_halves() {
+        let x = std::f64::consts::PI;
+        let (hi, lo) = split(x);
+        assert!(hi + lo == x);
+        assert!(hi.to_bits().trailing_zeros() >= 26);
+    }
+
+    #[test]
+    fn renorm_non_overlapping() {
+        let (a, b, c, d) = renorm4(1.0, 1e-10, 1e-20, 1e-30);
+        assert!(a == 1.0 + 1e-10);
+        assert!(b.abs() <= a * f64::EPSILON / 2.0);
+        assert!(c.abs() <= b.abs() * f64::EPSILON / 2.0);
+        assert!(d.abs() <= c.abs() * f64::EPSILON / 2.0);
+    }
+}
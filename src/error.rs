@@ -3,11 +3,13 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-//! Errors that may occur while parsing a string into a [`Double`] or a [`Quad`].
+//! Errors that may occur while parsing a string into a [`Double`] or a [`Quad`], or while
+//! converting an integer into one exactly.
 //! 
 //! [`Double`]: struct.Double.html
 //! [`Quad`]: struct.Quad.html
 
+use core::error::Error;
 use std::fmt::{Display, Formatter, Result};
 
 /// An error generated when a problem is encountered parsing a string into a [`Double`].
@@ -26,6 +28,16 @@ pub struct ParseQuadError {
     pub kind: ErrorKind,
 }
 
+/// An error generated when an integer can't be converted into a [`Double`] exactly.
+///
+/// This is returned by [`Double::try_from_exact`] for integers that need more precision
+/// than a `Double` has.
+///
+/// [`Double`]: crate::Double
+/// [`Double::try_from_exact`]: crate::Double::try_from_exact
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InexactIntError;
+
 /// The different kinds of errors that might be generated during parsing.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ErrorKind {
@@ -56,3 +68,57 @@ impl Display for ParseQuadError {
         description.fmt(f)
     }
 }
+
+impl Display for InexactIntError {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        "integer cannot be represented exactly as a double-double".fmt(f)
+    }
+}
+
+// None of the errors has a source; they're all raised directly by the parsers and
+// converters, so the default methods are all that's needed.
+
+impl Error for ParseDoubleError {}
+
+impl Error for ParseQuadError {}
+
+impl Error for InexactIntError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Double, Quad};
+
+    fn parse_double(s: &str) -> std::result::Result<Double, Box<dyn Error>> {
+        Ok(s.parse::<Double>()?)
+    }
+
+    fn parse_quad(s: &str) -> std::result::Result<Quad, Box<dyn Error>> {
+        Ok(s.parse::<Quad>()?)
+    }
+
+    #[test]
+    fn boxed_errors() {
+        assert!(parse_double("1.5").is_ok());
+        let err = parse_double("").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot parse double-double from empty string"
+        );
+        assert!(err.source().is_none());
+        assert!(err.downcast_ref::<ParseDoubleError>().is_some());
+
+        let err = parse_quad("1.5x").unwrap_err();
+        assert_eq!(err.to_string(), "invalid quad-double literal");
+        assert_eq!(
+            err.downcast_ref::<ParseQuadError>(),
+            Some(&ParseQuadError {
+                kind: ErrorKind::Invalid
+            })
+        );
+
+        let err: Box<dyn Error> = Box::new(InexactIntError);
+        assert!(err.to_string().contains("exactly"));
+    }
+}
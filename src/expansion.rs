@@ -0,0 +1,499 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Exact arithmetic on arbitrary-length expansions of `f64`s.
+//!
+//! An *expansion* is a sequence of `f64`s whose exact sum is the number it represents. The
+//! components are *non-overlapping* (the lowest set bit of each is higher than the highest
+//! set bit of the one before it) and are stored in order of increasing magnitude, so the
+//! last component approximates the whole and always has its sign. Unlike a [`Double`] or
+//! a [`Quad`], an expansion grows as long as it needs to be, so sums and products of
+//! expansions are *exact*.
+//!
+//! This is the arithmetic of Jonathan Shewchuk's [adaptive precision floating-point][1]
+//! paper, which is used for robust geometric predicates such as orientation and in-circle
+//! tests. Those predicates only need the *sign* of a small polynomial in the input
+//! coordinates, but getting that sign wrong because of rounding breaks the algorithms that
+//! depend on them, and no fixed precision is enough for every input.
+//!
+//! All of the functions here eliminate zero components from their results, except that
+//! an expansion equal to zero is returned as a single `0.0`. Their inputs may contain
+//! zeros. The expansions are `Vec<f64>`s and slices of `f64`s, and they can be converted to
+//! and from [`Double`]s and [`Quad`]s, which round them to nearest.
+//!
+//! The functions assume that no operation overflows. Expansions are exact only as long
+//! as every component and every intermediate product is a finite, normal `f64`.
+//!
+//! # Examples
+//! ```
+//! use qd::expansion::{estimate, expansion_sum, scale_expansion};
+//!
+//! // The sign of ad - bc, exactly, for a nearly singular matrix
+//! let (a, b, c, d) = (1e8 + 1.0, 1e8, 1e8, 1e8 - 1.0 + 1e-8);
+//! assert!(a * d - b * c == 0.0); // wrong
+//! let ad = scale_expansion(&[a], d);
+//! let bc = scale_expansion(&[b], -c);
+//! let det = expansion_sum(&ad, &bc);
+//! assert!(estimate(&det) > 0.0);
+//! ```
+//!
+//! [1]: https://www.cs.cmu.edu/~quake/robust.html
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+
+use crate::common::primitive as p;
+use crate::common::utils as u;
+use crate::double::Double;
+use crate::quad::Quad;
+
+/// Adds a single `f64` to an expansion, exactly.
+///
+/// This is Shewchuk's GROW-EXPANSION with zero elimination. The result has at most one
+/// more component than `e`.
+///
+/// # Examples
+/// ```
+/// # use qd::expansion::grow_expansion;
+/// let e = grow_expansion(&[1.0, 1e100], -1e100);
+/// assert_eq!(e, vec![1.0]);
+/// ```
+pub fn grow_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut h = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &x in e {
+        let (s, err) = p::two_sum(q, x);
+        if err != 0.0 {
+            h.push(err);
+        }
+        q = s;
+    }
+    finish(h, q)
+}
+
+/// Sums any number of `f64`s into an expansion, exactly.
+///
+/// This grows the expansion by each of the `f64`s in turn, like repeated calls to
+/// [`grow_expansion`] but without allocating a new expansion each time. Rounding the result
+/// with [`to_double`] or [`to_quad`] gives the correctly rounded sum, no matter how much
+/// the `f64`s cancel each other out.
+///
+/// Unlike the other functions here, this doesn't overflow when a partial sum is too large
+/// for an `f64` but the whole sum isn't, as with `1e308 + 1e308 - 1e308`. If the whole sum
+/// overflows, the result is the infinity of its sign. The `f64`s must all be finite.
+///
+/// # Examples
+/// ```
+/// # use qd::expansion::sum_f64s;
+/// let e = sum_f64s(vec![1e100, 1.0, 1e-100, -1e100]);
+/// assert_eq!(e, vec![1e-100, 1.0]);
+/// assert_eq!(sum_f64s(vec![1e308, 1e308, -1e308]), vec![1e308]);
+/// ```
+///
+/// [`grow_expansion`]: fn.grow_expansion.html
+/// [`to_double`]: fn.to_double.html
+/// [`to_quad`]: fn.to_quad.html
+pub fn sum_f64s<I>(xs: I) -> Vec<f64>
+where
+    I: IntoIterator<Item = f64>,
+{
+    // The large numbers are scaled down by 2^-512 before they're summed, which is exact
+    // and leaves so much headroom that no partial sum can overflow. The small ones are
+    // summed as they are, since scaling them down could lose their lowest bits.
+    let scale = 2f64.powi(-512);
+    let threshold = 2f64.powi(-510);
+    let (mut large, mut small) = (Vec::new(), Vec::new());
+    for b in xs {
+        if b.abs() >= threshold {
+            grow_in_place(&mut large, b * scale);
+        } else {
+            grow_in_place(&mut small, b);
+        }
+    }
+
+    // Scaling back up is exact unless it overflows, which only the largest component can
+    // do, and then only if the whole sum overflows
+    let unscale = 2f64.powi(512);
+    for x in large.iter_mut() {
+        *x *= unscale;
+    }
+    match large.last() {
+        Some(&top) if top.is_infinite() => vec![top],
+        _ => expansion_sum(&small, &large),
+    }
+}
+
+// Adds a single `f64` to a zero-eliminated expansion in place. The errors overwrite the
+// expansion as they're produced, which is safe because there are never more of them than
+// components that have already been read.
+fn grow_in_place(h: &mut Vec<f64>, b: f64) {
+    let mut q = b;
+    let mut k = 0;
+    for i in 0..h.len() {
+        let (s, err) = p::two_sum(q, h[i]);
+        if err != 0.0 {
+            h[k] = err;
+            k += 1;
+        }
+        q = s;
+    }
+    h.truncate(k);
+    if q != 0.0 {
+        h.push(q);
+    }
+}
+
+/// Adds two expansions, exactly.
+///
+/// This is Shewchuk's FAST-EXPANSION-SUM with zero elimination, which merges the components
+/// of the two expansions by magnitude and then sums them in a single pass. The result has
+/// at most as many components as the two expansions together.
+///
+/// # Examples
+/// ```
+/// # use qd::expansion::expansion_sum;
+/// let e = expansion_sum(&[1e-30, 1.0], &[-1e-30, 1e30]);
+/// assert_eq!(e, vec![1.0, 1e30]);
+/// ```
+pub fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut merged = Vec::with_capacity(e.len() + f.len());
+    let (mut i, mut j) = (0, 0);
+    while i < e.len() && j < f.len() {
+        if e[i].abs() < f[j].abs() {
+            merged.push(e[i]);
+            i += 1;
+        } else {
+            merged.push(f[j]);
+            j += 1;
+        }
+    }
+    merged.extend_from_slice(&e[i..]);
+    merged.extend_from_slice(&f[j..]);
+
+    let mut h = Vec::with_capacity(merged.len());
+    let mut q = match merged.first() {
+        Some(&x) => x,
+        None => return vec![0.0],
+    };
+    if let Some(&x) = merged.get(1) {
+        let (s, err) = p::quick_two_sum(x, q);
+        if err != 0.0 {
+            h.push(err);
+        }
+        q = s;
+    }
+    for &x in merged.iter().skip(2) {
+        let (s, err) = p::two_sum(q, x);
+        if err != 0.0 {
+            h.push(err);
+        }
+        q = s;
+    }
+    finish(h, q)
+}
+
+/// Multiplies an expansion by a single `f64`, exactly.
+///
+/// This is Shewchuk's SCALE-EXPANSION with zero elimination. The result has at most twice
+/// as many components as `e`. Multiplying two expansions can be done by scaling one of them
+/// by each component of the other and summing the results with [`expansion_sum`].
+///
+/// # Examples
+/// ```
+/// # use qd::expansion::scale_expansion;
+/// let x = 1.0 + f64::EPSILON;
+/// let e = scale_expansion(&[x], x);
+/// assert_eq!(e, vec![f64::EPSILON * f64::EPSILON, 1.0 + 2.0 * f64::EPSILON]);
+/// ```
+///
+/// [`expansion_sum`]: fn.expansion_sum.html
+pub fn scale_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut h = Vec::with_capacity(2 * e.len());
+    let mut q = match e.first() {
+        Some(&x) => {
+            let (p, err) = p::two_prod(x, b);
+            if err != 0.0 {
+                h.push(err);
+            }
+            p
+        }
+        None => return vec![0.0],
+    };
+    for &x in e.iter().skip(1) {
+        let (p1, p0) = p::two_prod(x, b);
+        let (sum, err) = p::two_sum(q, p0);
+        if err != 0.0 {
+            h.push(err);
+        }
+        let (s, err) = p::quick_two_sum(p1, sum);
+        if err != 0.0 {
+            h.push(err);
+        }
+        q = s;
+    }
+    finish(h, q)
+}
+
+/// Rewrites an expansion with as few components as possible.
+///
+/// This is Shewchuk's COMPRESS. The sum is unchanged, but the result is usually much
+/// shorter than the expansions produced by chains of the other functions, and its largest
+/// component approximates the whole to within an ulp.
+///
+/// # Examples
+/// ```
+/// # use qd::expansion::compress;
+/// let e = compress(&[1.0, 2.0, 4.0, 8.0]);
+/// assert_eq!(e, vec![15.0]);
+/// ```
+pub fn compress(e: &[f64]) -> Vec<f64> {
+    if e.is_empty() {
+        return vec![0.0];
+    }
+    // Top-down pass, collecting the components from the largest
+    let mut g = Vec::with_capacity(e.len());
+    let mut q = e[e.len() - 1];
+    for &x in e.iter().rev().skip(1) {
+        let (s, err) = p::quick_two_sum(q, x);
+        if err != 0.0 {
+            g.push(s);
+            q = err;
+        } else {
+            q = s;
+        }
+    }
+    g.push(q);
+
+    // Bottom-up pass over those components, from the smallest
+    let mut h = Vec::with_capacity(g.len());
+    let mut q = g[g.len() - 1];
+    for &x in g.iter().rev().skip(1) {
+        let (s, err) = p::quick_two_sum(x, q);
+        if err != 0.0 {
+            h.push(err);
+        }
+        q = s;
+    }
+    finish(h, q)
+}
+
+/// Returns an approximation of the value of an expansion as a single `f64`.
+///
+/// The sign of the approximation is always the sign of the expansion.
+///
+/// # Examples
+/// ```
+/// # use qd::expansion::estimate;
+/// assert_eq!(estimate(&[1e-20, 1.0]), 1.0);
+/// assert_eq!(estimate(&[]), 0.0);
+/// ```
+pub fn estimate(e: &[f64]) -> f64 {
+    e.iter().sum()
+}
+
+/// Converts a [`Double`] into an expansion.
+///
+/// # Examples
+/// ```
+/// # use qd::expansion::from_double;
+/// # use qd::{dd, Double};
+/// let x = dd!(1) / dd!(3);
+/// assert_eq!(from_double(x), vec![x[1], x[0]]);
+/// ```
+///
+/// [`Double`]: crate::Double
+pub fn from_double(a: Double) -> Vec<f64> {
+    compress(&[a[1], a[0]])
+}
+
+/// Converts a [`Quad`] into an expansion.
+///
+/// # Examples
+/// ```
+/// # use qd::expansion::from_quad;
+/// # use qd::{qd, Quad};
+/// let x = qd!(1) / qd!(3);
+/// assert_eq!(from_quad(x), vec![x[3], x[2], x[1], x[0]]);
+/// ```
+///
+/// [`Quad`]: crate::Quad
+pub fn from_quad(a: Quad) -> Vec<f64> {
+    compress(&[a[3], a[2], a[1], a[0]])
+}
+
+/// Converts an expansion into a [`Double`], rounding it to the nearest `Double`.
+///
+/// # Examples
+/// ```
+/// # use qd::expansion::to_double;
+/// # use qd::{dd, Double};
+/// let x = to_double(&[1e-40, 1e-20, 1.0]);
+/// assert_eq!(x, dd!(1) + dd!(1e-20));
+/// ```
+///
+/// [`Double`]: crate::Double
+pub fn to_double(e: &[f64]) -> Double {
+    let mut out = [0.0; 2];
+    u::distill(&mut padded(e, 2), &mut out);
+    Double::new(out[0], out[1])
+}
+
+/// Converts an expansion into a [`Quad`], rounding it to the nearest `Quad`.
+///
+/// # Examples
+/// ```
+/// # use qd::expansion::to_quad;
+/// # use qd::{qd, Quad};
+/// let x = to_quad(&[1e-40, 1e-20, 1.0]);
+/// assert_eq!(x, Quad::new(1.0, 1e-20, 1e-40, 0.0));
+/// ```
+///
+/// [`Quad`]: crate::Quad
+pub fn to_quad(e: &[f64]) -> Quad {
+    let mut out = [0.0; 4];
+    u::distill(&mut padded(e, 4), &mut out);
+    Quad::new(out[0], out[1], out[2], out[3])
+}
+
+// Pushes the last, largest component onto a zero-eliminated expansion, keeping it if it's
+// the only one even if it's zero.
+fn finish(mut h: Vec<f64>, q: f64) -> Vec<f64> {
+    if q != 0.0 || h.is_empty() {
+        h.push(q);
+    }
+    h
+}
+
+// Copies an expansion, putting zeros before it to give it at least `n` components.
+fn padded(e: &[f64], n: usize) -> Vec<f64> {
+    let mut xs = vec![0.0; n.saturating_sub(e.len())];
+    xs.extend_from_slice(e);
+    xs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+
+    // Checks that the components of an expansion are in increasing order of magnitude and
+    // don't overlap.
+    fn nonoverlapping(e: &[f64]) -> bool {
+        e.windows(2).all(|w| {
+            let lowest = |x: f64| {
+                let bits = x.abs().to_bits();
+                let exp = ((bits >> 52) as i32 - 1075).max(-1074);
+                exp + (bits & ((1 << 52) - 1) | 1 << 52).trailing_zeros() as i32
+            };
+            w[0] != 0.0
+                && w[0].abs() < w[1].abs()
+                && (w[0].abs().log2().floor() as i32) < lowest(w[1])
+        })
+    }
+
+    // The sign of the determinant of the points a, b, and c: positive if they turn
+    // counterclockwise, negative if clockwise, and zero if they're collinear.
+    fn orient2d(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+        let terms = [
+            (a.0, b.1, 1.0),
+            (a.0, c.1, -1.0),
+            (b.0, c.1, 1.0),
+            (b.0, a.1, -1.0),
+            (c.0, a.1, 1.0),
+            (c.0, b.1, -1.0),
+        ];
+        let det = terms.iter().fold(vec![0.0], |acc, &(x, y, sign)| {
+            expansion_sum(&acc, &scale_expansion(&[x], y * sign))
+        });
+        estimate(&compress(&det))
+    }
+
+    #[test]
+    fn grow() {
+        let e = [1e-40, 1e-20, 1.0];
+        let g = grow_expansion(&e, 1e20);
+        assert_eq!(g, vec![1e-40, 1e-20, 1.0, 1e20]);
+        assert!(nonoverlapping(&g));
+        assert_eq!(grow_expansion(&[], 2.0), vec![2.0]);
+        assert_eq!(grow_expansion(&[1.0], -1.0), vec![0.0]);
+    }
+
+    #[test]
+    fn sum_many() {
+        let xs = [1e-40, 1e20, 1.0, -1e20, 1e-20, 3.0, -1e-40];
+        let e = sum_f64s(xs.iter().copied());
+        assert!(nonoverlapping(&e));
+        assert_eq!(
+            e,
+            xs.iter().fold(vec![0.0], |acc, &x| grow_expansion(&acc, x))
+        );
+        assert_eq!(to_double(&e), Double::new(4.0, 1e-20));
+        assert_eq!(sum_f64s(vec![]), vec![0.0]);
+        assert_eq!(sum_f64s(vec![2.5, -2.5]), vec![0.0]);
+        assert_eq!(sum_f64s(vec![1e308, 1e308, -1e308]), vec![1e308]);
+        assert_eq!(sum_f64s(vec![-1e308, -1e308]), vec![f64::NEG_INFINITY]);
+    }
+
+    #[test]
+    fn sum() {
+        let e = [1e-40, 1e-20, 1.0];
+        let f = [-1e-40, 3e-30, -1.0];
+        let s = expansion_sum(&e, &f);
+        assert!(nonoverlapping(&s));
+        assert_eq!(
+            to_double(&s),
+            Double::new(1e-20, 0.0) + Double::new(3e-30, 0.0)
+        );
+        assert_eq!(expansion_sum(&e, &[]), e.to_vec());
+        assert_eq!(expansion_sum(&[], &[]), vec![0.0]);
+    }
+
+    #[test]
+    fn scale() {
+        let e = from_quad(qd!(1) / qd!(3));
+        let s = scale_expansion(&e, 3.0);
+        assert!(nonoverlapping(&s));
+        // 1/3 rounded to a Quad, times 3, is exactly 1 minus the rounding error times 3
+        let err = to_quad(&grow_expansion(&s, -1.0));
+        assert!(err.abs() < qd!(1e-64));
+        assert_eq!(scale_expansion(&e, 0.0), vec![0.0]);
+    }
+
+    #[test]
+    fn compression() {
+        let e = scale_expansion(&from_double(dd!(1) / dd!(7)), 7.0);
+        let c = compress(&e);
+        assert!(nonoverlapping(&c));
+        assert!(c.len() <= e.len());
+        assert_eq!(to_quad(&c), to_quad(&e));
+        assert_eq!(compress(&[]), vec![0.0]);
+        assert_eq!(compress(&[0.0, 0.0]), vec![0.0]);
+    }
+
+    #[test]
+    fn conversions() {
+        let d = dd!(1) / dd!(3);
+        assert_eq!(to_double(&from_double(d)), d);
+        let q = qd!(1) / qd!(3);
+        assert_eq!(to_quad(&from_quad(q)), q);
+        assert_eq!(to_double(&from_quad(q)), d);
+        assert_eq!(from_double(dd!(0.5)), vec![0.5]);
+        assert_eq!(to_double(&[]), dd!(0));
+    }
+
+    #[test]
+    fn orientation() {
+        // Collinear points, and points off the line by an ulp that the naive determinant
+        // also finds to be collinear
+        let a = (0.5, 0.5);
+        let b = (12.0, 12.0);
+        let c = (24.0, 24.0);
+        assert_eq!(orient2d(a, b, c), 0.0);
+
+        let a = (0.5 + f64::EPSILON, 0.5);
+        assert!(orient2d(a, b, c) < 0.0);
+        let a = (0.5, 0.5 + f64::EPSILON);
+        assert!(orient2d(a, b, c) > 0.0);
+    }
+}
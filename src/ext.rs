@@ -0,0 +1,196 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Extended-precision methods on `f64`.
+//!
+//! The [`F64Ext`] trait adds methods to `f64` that convert it into a [`Double`] or a
+//! [`Quad`], and that add, subtract, or multiply two `f64`s *exactly*, returning the whole
+//! result as a `Double` instead of rounding it back to an `f64`. Those are often the first
+//! step of a computation in extended precision, and they're easier to find and read as
+//! methods than as constructor calls or [error-free transformations].
+//!
+//! # Examples
+//! ```
+//! use qd::ext::F64Ext;
+//!
+//! let x = 0.1f64;
+//! let y = 0.2f64;
+//! assert!(x + y != 0.3);
+//!
+//! // The exact sum of the two `f64`s, which is the rounded sum plus its rounding error
+//! let sum = x.wide_add(y);
+//! assert_eq!(sum[0], x + y);
+//! assert_eq!(sum[1], -2.7755575615628914e-17);
+//! ```
+//!
+//! [`F64Ext`]: crate::ext::F64Ext
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [error-free transformations]: crate::eft
+
+use crate::common::primitive as p;
+use crate::double::Double;
+use crate::quad::Quad;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f64 {}
+}
+
+/// Methods that take an `f64` into extended precision.
+///
+/// This trait is sealed; it's implemented for `f64` and can't be implemented for anything
+/// else.
+pub trait F64Ext: sealed::Sealed {
+    /// Converts the number into a `Double`.
+    ///
+    /// This is the same as [`Double::from`], so the `Double` is the one closest to the
+    /// number's *decimal* representation. This means that `0.1f64.to_dd()` is the `Double`
+    /// closest to 0.1, not the `Double` equal to the `f64` closest to 0.1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::ext::F64Ext;
+    /// # use qd::dd;
+    /// assert_eq!(1.5.to_dd(), dd!(1.5));
+    /// assert_eq!(0.1.to_dd(), dd!("0.1"));
+    /// ```
+    ///
+    /// [`Double::from`]: crate::Double#impl-From<f64>
+    fn to_dd(self) -> Double;
+
+    /// Converts the number into a `Quad`.
+    ///
+    /// This is the same as [`Quad::from`], so the `Quad` is the one closest to the number's
+    /// *decimal* representation, just as for [`to_dd`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::ext::F64Ext;
+    /// # use qd::qd;
+    /// assert_eq!(1.5.to_qd(), qd!(1.5));
+    /// assert_eq!(0.1.to_qd(), qd!("0.1"));
+    /// ```
+    ///
+    /// [`Quad::from`]: crate::Quad#impl-From<f64>
+    /// [`to_dd`]: F64Ext::to_dd
+    fn to_qd(self) -> Quad;
+
+    /// Adds another `f64` to this one, returning the exact sum as a `Double`.
+    ///
+    /// The first component of the result is `self + other` rounded to an `f64`, and the
+    /// second is the rounding error. If the sum overflows or either number is infinite or
+    /// `NaN`, the result is the same as it would be for `f64`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::ext::F64Ext;
+    /// # use qd::Double;
+    /// assert_eq!(1.0.wide_add(1e-20), Double::new(1.0, 1e-20));
+    /// ```
+    fn wide_add(self, other: f64) -> Double;
+
+    /// Subtracts another `f64` from this one, returning the exact difference as a `Double`.
+    ///
+    /// This works just like [`wide_add`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::ext::F64Ext;
+    /// # use qd::Double;
+    /// assert_eq!(1.0.wide_sub(1e-20), Double::new(1.0, -1e-20));
+    /// ```
+    ///
+    /// [`wide_add`]: F64Ext::wide_add
+    fn wide_sub(self, other: f64) -> Double;
+
+    /// Multiplies this `f64` by another, returning the exact product as a `Double`.
+    ///
+    /// The first component of the result is `self * other` rounded to an `f64`, and the
+    /// second is the rounding error. The product is exact unless the rounding error is so
+    /// small that it underflows. If the product overflows or either number is infinite or
+    /// `NaN`, the result is the same as it would be for `f64`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::ext::F64Ext;
+    /// # use qd::Double;
+    /// let x = 1.0 + f64::EPSILON;
+    /// let expected = Double::new(1.0 + 2.0 * f64::EPSILON, f64::EPSILON * f64::EPSILON);
+    /// assert_eq!(x.wide_mul(x), expected);
+    /// ```
+    fn wide_mul(self, other: f64) -> Double;
+}
+
+impl F64Ext for f64 {
+    #[inline]
+    fn to_dd(self) -> Double {
+        Double::from(self)
+    }
+
+    #[inline]
+    fn to_qd(self) -> Quad {
+        Quad::from(self)
+    }
+
+    #[inline]
+    fn wide_add(self, other: f64) -> Double {
+        let (s, e) = p::two_sum(self, other);
+        wide(s, e)
+    }
+
+    #[inline]
+    fn wide_sub(self, other: f64) -> Double {
+        let (s, e) = p::two_diff(self, other);
+        wide(s, e)
+    }
+
+    #[inline]
+    fn wide_mul(self, other: f64) -> Double {
+        let (s, e) = p::two_prod(self, other);
+        wide(s, e)
+    }
+}
+
+// Makes a `Double` out of the result and error of an error-free transformation. The error
+// is meaningless (usually `NaN`) if the result isn't finite, so it's dropped.
+#[inline]
+fn wide(s: f64, e: f64) -> Double {
+    if s.is_finite() {
+        Double::new(s, e)
+    } else {
+        Double::new(s, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(3.0.to_dd(), dd!(3));
+        assert_eq!((-0.1).to_dd(), dd!("-0.1"));
+        assert_eq!(3.0.to_qd(), qd!(3));
+        assert_eq!(0.1.to_qd(), qd!("0.1"));
+        assert!(f64::NAN.to_dd().is_nan());
+        assert_eq!(f64::INFINITY.to_qd(), Quad::INFINITY);
+    }
+
+    #[test]
+    fn exact_operations() {
+        let (x, y) = (0.1, 0.2);
+        assert_eq!(x.wide_add(y), Double::new(x + y, -2.7755575615628914e-17));
+        assert_eq!(x.wide_add(y), Double::new(x, 0.0) + Double::new(y, 0.0));
+        assert_eq!(x.wide_sub(y), Double::new(x, 0.0) - Double::new(y, 0.0));
+        assert_eq!(x.wide_mul(y), Double::new(x, 0.0) * Double::new(y, 0.0));
+        assert_eq!(1e300.wide_mul(1e300), Double::INFINITY);
+        assert_eq!(f64::MAX.wide_add(f64::MAX), Double::INFINITY);
+        assert!(f64::INFINITY.wide_sub(f64::INFINITY).is_nan());
+        assert!(f64::NAN.wide_mul(1.0).is_nan());
+    }
+}
@@ -0,0 +1,418 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Numerical integration of functions of [`Quad`]s.
+//!
+//! Two integrators are provided, each of which computes its nodes and weights to full
+//! [`Quad`] precision once, when it's created, so that it can be reused for any number of
+//! integrals.
+//!
+//! * [`TanhSinh`] uses the tanh-sinh (or double exponential) substitution, which turns the
+//!   integral over a finite interval into one over the whole real line whose integrand
+//!   decays double exponentially, and then applies the trapezoidal rule with smaller and
+//!   smaller steps until the result stops changing. It converges extraordinarily quickly
+//!   for functions that are analytic inside the interval, even if they have singularities
+//!   at its endpoints, and is the best choice for most integrals.
+//! * [`GaussLegendre`] uses a fixed number of nodes, and it's exact for polynomials of up
+//!   to twice that degree. It's faster than tanh-sinh for smooth functions that are well
+//!   approximated by polynomials, but it can't tell how accurate its result is.
+//!
+//! Both integrate over finite intervals. Integrals over infinite intervals can be turned
+//! into ones over finite intervals by a change of variables, such as *x* = *t* / (1 - *t*²)
+//! for the whole real line.
+//!
+//! # Examples
+//! ```
+//! use qd::integrate::{GaussLegendre, TanhSinh};
+//! use qd::{qd, Quad};
+//!
+//! // The integral of 1 / √x from 0 to 1 is 2, despite the singularity at 0
+//! let ts = TanhSinh::default();
+//! let (value, error) = ts.integrate(|x| x.sqrt().recip(), qd!(0), qd!(1));
+//! assert!((value - qd!(2)).abs() < qd!(1e-60));
+//! assert!(error < qd!(1e-30));
+//!
+//! // The integral of eˣ from 0 to 1 is e - 1
+//! let gl = GaussLegendre::new(40);
+//! let value = gl.integrate(|x| x.exp(), qd!(0), qd!(1));
+//! assert!((value - (Quad::E - qd!(1))).abs() < qd!(1e-60));
+//! ```
+//!
+//! [`Quad`]: crate::Quad
+//! [`TanhSinh`]: crate::integrate::TanhSinh
+//! [`GaussLegendre`]: crate::integrate::GaussLegendre
+
+use crate::quad::Quad;
+
+/// A tanh-sinh (double exponential) integrator.
+///
+/// The substitution *x* = tanh(π/2 sinh *t*) turns an integral over (-1, 1) into an
+/// integral over the whole real line, which the trapezoidal rule then approximates with a
+/// step of *h* = 2<sup>-*k*</sup> at level *k*. Each level reuses the nodes of the level
+/// before it and adds as many new ones, and the integrator goes through the levels until
+/// the result changes by less than the tolerance from one level to the next.
+///
+/// The nodes are stored as their distances from the endpoints of the interval rather than
+/// as their positions, so that the function can be evaluated at points extremely close to
+/// the endpoints without those points being rounded onto the endpoints themselves. This is
+/// what lets singularities at the endpoints be integrated accurately. The function is never
+/// evaluated at the endpoints.
+///
+/// # Examples
+/// ```
+/// # use qd::integrate::TanhSinh;
+/// # use qd::{qd, Quad};
+/// // The integral of ln x from 0 to 1 is -1
+/// let ts = TanhSinh::default();
+/// let (value, _) = ts.integrate(|x| x.ln(), qd!(0), qd!(1));
+/// assert!((value + qd!(1)).abs() < qd!(1e-60));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TanhSinh {
+    // The nodes for each level, as pairs of the distance of the node from the endpoint
+    // and its weight. Only the nodes for positive t are stored; the others are their
+    // reflections. The node at t = 0 isn't stored.
+    levels: Vec<Vec<(Quad, Quad)>>,
+    tolerance: Quad,
+}
+
+impl TanhSinh {
+    /// Creates a new integrator that goes up to `max_level` levels and stops when the
+    /// result changes by less than `tolerance`, relative to its magnitude, between levels.
+    ///
+    /// The number of nodes, and therefore the time it takes to create the integrator and
+    /// the maximum number of function evaluations in an integral, doubles with each level.
+    /// Because the convergence is quadratic, the difference between two levels is roughly
+    /// the square root of the error of the second one, so a tolerance of around `1e-30` is
+    /// enough for nearly full `Quad` precision.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::integrate::TanhSinh;
+    /// # use qd::{qd, Quad};
+    /// // A quicker integrator for less accuracy
+    /// let ts = TanhSinh::new(5, qd!(1e-15));
+    /// let (value, _) = ts.integrate(|x| x * x, qd!(0), qd!(3));
+    /// assert!((value - qd!(9)).abs() < qd!(1e-25));
+    /// ```
+    pub fn new(max_level: usize, tolerance: Quad) -> TanhSinh {
+        let mut levels = Vec::with_capacity(max_level + 1);
+        for level in 0..=max_level {
+            let h = Quad::ONE.mul_pwr2(0.5f64.powi(level as i32));
+            // Level 0 has the nodes at whole numbers, and every later level has the nodes
+            // at the odd multiples of its step
+            let step = if level == 0 { h } else { h.mul_pwr2(2.0) };
+            let mut nodes = vec![];
+            let mut t = h;
+            loop {
+                let (comp, weight) = TanhSinh::node(t);
+                if comp[0] < LIMIT {
+                    break;
+                }
+                nodes.push((comp, weight));
+                t += step;
+            }
+            levels.push(nodes);
+        }
+        TanhSinh { levels, tolerance }
+    }
+
+    /// Integrates a function from `a` to `b`, returning the value of the integral and an
+    /// estimate of its error.
+    ///
+    /// The error estimate is the difference between the results at the last two levels,
+    /// which is usually a large overestimate. If the result hasn't converged to within the
+    /// tolerance by the maximum level, the error estimate is larger than the tolerance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::integrate::TanhSinh;
+    /// # use qd::{qd, Quad};
+    /// // The area of a half circle
+    /// let ts = TanhSinh::default();
+    /// let (value, _) = ts.integrate(|x| (qd!(1) - x * x).sqrt(), qd!(-1), qd!(1));
+    /// assert!((value - Quad::FRAC_PI_2).abs() < qd!(1e-60));
+    /// ```
+    pub fn integrate<F>(&self, f: F, a: Quad, b: Quad) -> (Quad, Quad)
+    where
+        F: Fn(Quad) -> Quad,
+    {
+        let half = (b - a).mul_pwr2(0.5);
+        let mid = a + half;
+        // The weight of the node at t = 0 is π/2
+        let mut sum = f(mid) * Quad::FRAC_PI_2;
+        let mut prev = Quad::NAN;
+        let mut error = Quad::INFINITY;
+        let mut result = Quad::NAN;
+
+        for (level, nodes) in self.levels.iter().enumerate() {
+            for &(comp, weight) in nodes {
+                let dx = half * comp;
+                let (lo, hi) = (a + dx, b - dx);
+                // Points that round onto the endpoints are skipped, as their weights are
+                // negligible and the function may not be defined there
+                if lo != a {
+                    sum += weight * f(lo);
+                }
+                if hi != b {
+                    sum += weight * f(hi);
+                }
+            }
+            let h = Quad::ONE.mul_pwr2(0.5f64.powi(level as i32));
+            result = sum * h * half;
+            if level > 0 {
+                error = (result - prev).abs();
+                if error <= self.tolerance * result.abs() {
+                    break;
+                }
+            }
+            prev = result;
+        }
+        (result, error)
+    }
+
+    // Calculates the distance from 1 and the weight of the node at t.
+    //
+    // With u = π/2 sinh t and q = e^(-2u), the node is tanh u = (1 - q) / (1 + q), so its
+    // distance from 1 is 2q / (1 + q), and its weight is π/2 cosh t / cosh² u, which is
+    // π/2 cosh t · 4q / (1 + q)².
+    fn node(t: Quad) -> (Quad, Quad) {
+        let (sinh, cosh) = t.sinh_cosh();
+        let q = (-(Quad::PI * sinh)).exp();
+        let p = Quad::ONE + q;
+        let comp = q.mul_pwr2(2.0) / p;
+        let weight = Quad::FRAC_PI_2 * cosh * q.mul_pwr2(4.0) / (p * p);
+        (comp, weight)
+    }
+}
+
+impl Default for TanhSinh {
+    /// Creates an integrator that goes up to level 7 with a tolerance of `1e-30`.
+    ///
+    /// Most integrals converge to full precision by level 6.
+    fn default() -> TanhSinh {
+        TanhSinh::new(7, Quad::from(1e-30))
+    }
+}
+
+// The smallest distance from an endpoint of a tanh-sinh node. Beyond this the weights are
+// so small that even functions with strong singularities at the endpoints contribute
+// nothing to the integral.
+const LIMIT: f64 = 1e-300;
+
+/// A Gauss-Legendre integrator.
+///
+/// An *n*-point Gauss-Legendre rule evaluates the function at the roots of the Legendre
+/// polynomial of degree *n* and is exact for polynomials of degree up to 2*n* - 1. The
+/// roots and their weights are calculated to full `Quad` precision when the integrator is
+/// created, by Newton's method from `f64` estimates.
+///
+/// # Examples
+/// ```
+/// # use qd::integrate::GaussLegendre;
+/// # use qd::{qd, Quad};
+/// // Exact for polynomials of degree up to 5
+/// let gl = GaussLegendre::new(3);
+/// let value = gl.integrate(|x| x.powi(5) - x * x, qd!(0), qd!(2));
+/// assert!((value - (qd!(32) / qd!(3) - qd!(8) / qd!(3))).abs() < qd!(1e-62));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GaussLegendre {
+    nodes: Vec<Quad>,
+    weights: Vec<Quad>,
+}
+
+impl GaussLegendre {
+    /// Creates a new Gauss-Legendre integrator with `n` nodes.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn new(n: usize) -> GaussLegendre {
+        assert!(n > 0, "Gauss-Legendre rule needs at least one node");
+        let mut nodes = vec![Quad::ZERO; n];
+        let mut weights = vec![Quad::ZERO; n];
+        let nq = Quad::from(n as f64);
+
+        for i in 0..n.div_ceil(2) {
+            // The usual estimate of the (i + 1)th largest root, which is good to several
+            // digits, and then Newton's method with the derivative from the recurrence
+            let guess = (std::f64::consts::PI * (i as f64 + 0.75) / (n as f64 + 0.5)).cos();
+            let mut x = Quad::from(guess);
+            let mut dp = Quad::ONE;
+            for _ in 0..MAX_NEWTON {
+                let (p, p1) = GaussLegendre::legendre(n, x);
+                dp = nq * (x * p - p1) / (x * x - Quad::ONE);
+                let dx = p / dp;
+                x -= dx;
+                if dx.abs() <= Quad::EPSILON * x.abs() {
+                    let (p, p1) = GaussLegendre::legendre(n, x);
+                    dp = nq * (x * p - p1) / (x * x - Quad::ONE);
+                    break;
+                }
+            }
+            let w = Quad::from(2.0) / ((Quad::ONE - x * x) * dp * dp);
+            nodes[i] = x;
+            weights[i] = w;
+            nodes[n - 1 - i] = -x;
+            weights[n - 1 - i] = w;
+        }
+        if n % 2 == 1 {
+            // The middle root is exactly zero
+            nodes[n / 2] = Quad::ZERO;
+        }
+        GaussLegendre { nodes, weights }
+    }
+
+    /// Returns the nodes of the rule on the interval [-1, 1], from the largest down.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::integrate::GaussLegendre;
+    /// # use qd::{qd, Quad};
+    /// let gl = GaussLegendre::new(2);
+    /// assert!((gl.nodes()[0] - Quad::FRAC_1_SQRT_3).abs() < qd!(1e-63));
+    /// ```
+    pub fn nodes(&self) -> &[Quad] {
+        &self.nodes
+    }
+
+    /// Returns the weights of the rule on the interval [-1, 1], in the same order as the
+    /// nodes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::integrate::GaussLegendre;
+    /// # use qd::{qd, Quad};
+    /// let gl = GaussLegendre::new(3);
+    /// assert!((gl.weights()[0] - qd!(5) / qd!(9)).abs() < qd!(1e-63));
+    /// ```
+    pub fn weights(&self) -> &[Quad] {
+        &self.weights
+    }
+
+    /// Integrates a function from `a` to `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::integrate::GaussLegendre;
+    /// # use qd::{qd, Quad};
+    /// let gl = GaussLegendre::new(40);
+    /// let value = gl.integrate(|x| x.sin(), qd!(0), Quad::PI);
+    /// assert!((value - qd!(2)).abs() < qd!(1e-60));
+    /// ```
+    pub fn integrate<F>(&self, f: F, a: Quad, b: Quad) -> Quad
+    where
+        F: Fn(Quad) -> Quad,
+    {
+        let half = (b - a).mul_pwr2(0.5);
+        let mid = a + half;
+        let sum = self
+            .nodes
+            .iter()
+            .zip(self.weights.iter())
+            .fold(Quad::ZERO, |acc, (&x, &w)| acc + w * f(mid + half * x));
+        sum * half
+    }
+
+    // Calculates the Legendre polynomials of degree n and n - 1 at x.
+    fn legendre(n: usize, x: Quad) -> (Quad, Quad) {
+        let (mut p0, mut p1) = (Quad::ONE, Quad::ZERO);
+        for k in 0..n {
+            let kq = Quad::from(k as f64);
+            let p = ((kq + kq + Quad::ONE) * x * p0 - kq * p1) / (kq + Quad::ONE);
+            p1 = p0;
+            p0 = p;
+        }
+        (p0, p1)
+    }
+}
+
+// The maximum number of Newton steps for a root of a Legendre polynomial. Starting from
+// an f64 estimate, three or four are always enough.
+const MAX_NEWTON: usize = 10;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::qd;
+
+    fn near(a: Quad, b: Quad) -> bool {
+        ((a - b) / b).abs() < qd!(1e-60)
+    }
+
+    #[test]
+    fn tanh_sinh_singular() {
+        let ts = TanhSinh::default();
+        let (value, error) = ts.integrate(|x| x.sqrt().recip(), qd!(0), qd!(1));
+        assert!(near(value, qd!(2)));
+        assert!(error < qd!(1e-30));
+        let (value, _) = ts.integrate(|x| x.ln(), qd!(0), qd!(1));
+        assert!(near(value, qd!(-1)));
+        let (value, _) = ts.integrate(|x| (qd!(1) - x * x).sqrt(), qd!(-1), qd!(1));
+        assert!(near(value, Quad::FRAC_PI_2));
+    }
+
+    #[test]
+    fn tanh_sinh_smooth() {
+        let ts = TanhSinh::default();
+        let (value, _) = ts.integrate(|x| (qd!(1) + x * x).recip(), qd!(0), qd!(1));
+        assert!(near(value, Quad::FRAC_PI_4));
+        let (value, _) = ts.integrate(|x| x.exp(), qd!(1), qd!(0));
+        assert!(near(value, qd!(1) - Quad::E));
+        let (value, _) = ts.integrate(|x| x.sin(), qd!(0), Quad::PI);
+        assert!(near(value, qd!(2)));
+    }
+
+    #[test]
+    fn tanh_sinh_no_convergence() {
+        // A tolerance of zero can't be met, so every level is used and the error estimate
+        // is the difference between the last two
+        let ts = TanhSinh::new(3, qd!(0));
+        let (value, error) = ts.integrate(|x| x.exp(), qd!(0), qd!(1));
+        assert!(error > qd!(0));
+        assert!((value - (Quad::E - qd!(1))).abs() < qd!(1e-20));
+    }
+
+    #[test]
+    fn gauss_legendre_rules() {
+        let gl = GaussLegendre::new(1);
+        assert_eq!(gl.nodes(), &[qd!(0)]);
+        assert_eq!(gl.weights(), &[qd!(2)]);
+
+        let gl = GaussLegendre::new(7);
+        for i in 0..7 {
+            assert_eq!(gl.nodes()[i], -gl.nodes()[6 - i]);
+            assert!(i == 0 || gl.nodes()[i] < gl.nodes()[i - 1]);
+        }
+        let total = gl.weights().iter().fold(qd!(0), |acc, &w| acc + w);
+        assert!(near(total, qd!(2)));
+    }
+
+    #[test]
+    fn gauss_legendre_exact() {
+        let gl = GaussLegendre::new(5);
+        // Every monomial of degree up to 9
+        for k in 0..10 {
+            let value = gl.integrate(|x| x.powi(k), qd!(0), qd!(1));
+            assert!(near(value, qd!(1) / Quad::from(k as f64 + 1.0)));
+        }
+    }
+
+    #[test]
+    fn gauss_legendre_smooth() {
+        let gl = GaussLegendre::new(40);
+        assert!(near(
+            gl.integrate(|x| x.exp(), qd!(0), qd!(1)),
+            Quad::E - qd!(1)
+        ));
+        assert!(near(gl.integrate(|x| x.sin(), qd!(0), Quad::PI), qd!(2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn gauss_legendre_empty() {
+        GaussLegendre::new(0);
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Implementations of other crates' traits for `Double` and `Quad`. Each of these modules is
+// enabled by the feature of the same name, which also brings in the crate that it works
+// with. None of them has any public items of its own; everything they add is a trait
+// implementation, documented with the trait.
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "simba")]
+mod simba;
@@ -0,0 +1,181 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// `arbitrary::Arbitrary` for `Double` and `Quad`, for structure-aware fuzzing.
+//
+// Taking the components straight from the fuzzer's bytes would almost never make a valid
+// number, since each component has to be no more than half an ulp of the one before it.
+// Instead, the first component is any finite `f64` (so every exponent, subnormals included,
+// is equally likely), and each of the others is a random fraction of half an ulp of the
+// component before it. About a quarter of the values are edge cases instead: zeros,
+// infinities, NaN, the largest and smallest numbers, and epsilon.
+
+use crate::common::utils as u;
+use crate::double::Double;
+use crate::quad::Quad;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+// The distance from `x` to the next `f64` toward zero, which for a nonzero, finite `x` is
+// its ulp, or half of it if `x` is a power of two.
+fn ulp_below(x: f64) -> f64 {
+    let x = x.abs();
+    x - f64::from_bits(x.to_bits() - 1)
+}
+
+// Any finite `f64`. The bit patterns of infinities and NaNs have their highest exponent bit
+// cleared, which makes them into numbers between 1 and 2.
+fn first_component(u: &mut Unstructured) -> Result<f64> {
+    let bits = u64::arbitrary(u)?;
+    let x = f64::from_bits(bits);
+    Ok(if x.is_finite() {
+        x
+    } else {
+        f64::from_bits(bits & !(1 << 62))
+    })
+}
+
+// A random number whose magnitude is less than half an ulp of `prev`. The lowest 53 bits
+// make a fraction in [0, 1) and the highest one is the sign.
+fn next_component(u: &mut Unstructured, prev: f64) -> Result<f64> {
+    let bits = u64::arbitrary(u)?;
+    if prev == 0.0 {
+        return Ok(0.0);
+    }
+    let fraction = (bits & ((1 << 53) - 1)) as f64 / (1u64 << 53) as f64;
+    let x = fraction * ulp_below(prev) * 0.5;
+    Ok(if bits >> 63 == 1 { -x } else { x })
+}
+
+impl<'a> Arbitrary<'a> for Double {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Double> {
+        if u.ratio(1, 4)? {
+            return Ok(*u.choose(&[
+                Double::ZERO,
+                Double::NEG_ZERO,
+                Double::ONE,
+                Double::INFINITY,
+                Double::NEG_INFINITY,
+                Double::NAN,
+                Double::MAX,
+                Double::MIN,
+                Double::MIN_POSITIVE,
+                Double::EPSILON,
+            ])?);
+        }
+        let a = first_component(u)?;
+        let b = next_component(u, a)?;
+        let (a, b) = u::renorm2(a, b);
+        Ok(Double::new(a, b))
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(2 + 2 * 8))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Quad {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Quad> {
+        if u.ratio(1, 4)? {
+            return Ok(*u.choose(&[
+                Quad::ZERO,
+                Quad::NEG_ZERO,
+                Quad::ONE,
+                Quad::INFINITY,
+                Quad::NEG_INFINITY,
+                Quad::NAN,
+                Quad::MAX,
+                Quad::MIN,
+                Quad::MIN_POSITIVE,
+                Quad::EPSILON,
+            ])?);
+        }
+        let a = first_component(u)?;
+        let b = next_component(u, a)?;
+        let c = next_component(u, b)?;
+        let d = next_component(u, c)?;
+        let (a, b, c, d) = u::renorm4(a, b, c, d);
+        Ok(Quad::new(a, b, c, d))
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(2 + 4 * 8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fills a buffer with bytes from a simple linear congruential generator
+    fn bytes(seed: u64, len: usize) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 56) as u8
+            })
+            .collect()
+    }
+
+    // Whether each component is no more than half an ulp of the one before it, which is
+    // when adding it to the one before it doesn't change that one
+    fn normalized(components: &[f64]) -> bool {
+        components.windows(2).all(|w| w[0] + w[1] == w[0])
+    }
+
+    #[test]
+    fn double() {
+        let data = bytes(1, 20000);
+        let mut u = Unstructured::new(&data);
+        let (mut special, mut tiny, mut huge) = (0, 0, 0);
+
+        for _ in 0..1000 {
+            let x = Double::arbitrary(&mut u).unwrap();
+            if x.is_nan() || x.is_infinite() || x == Double::ZERO {
+                special += 1;
+            } else {
+                assert!(normalized(&[x.hi(), x.lo()]), "{:?}", x);
+                if x.hi().abs() < 1e-300 {
+                    tiny += 1;
+                } else if x.hi().abs() > 1e300 {
+                    huge += 1;
+                }
+            }
+        }
+        assert!(special > 0 && tiny > 0 && huge > 0);
+    }
+
+    #[test]
+    fn quad() {
+        let data = bytes(2, 40000);
+        let mut u = Unstructured::new(&data);
+        let (mut special, mut full) = (0, 0);
+
+        for _ in 0..1000 {
+            let x = Quad::arbitrary(&mut u).unwrap();
+            if x.is_nan() || x.is_infinite() || x == Quad::ZERO {
+                special += 1;
+            } else {
+                assert!(normalized(&[x[0], x[1], x[2], x[3]]), "{:?}", x);
+                if x[3] != 0.0 {
+                    full += 1;
+                }
+            }
+        }
+        assert!(special > 0 && full > 0);
+    }
+
+    #[test]
+    fn exhausted() {
+        // With no data left, the result is the simplest value
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(Double::arbitrary(&mut u).unwrap(), Double::ZERO);
+        assert_eq!(Quad::arbitrary(&mut u).unwrap(), Quad::ZERO);
+    }
+}
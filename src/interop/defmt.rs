@@ -0,0 +1,211 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// `defmt::Format` for `Double` and `Quad`, for logging from embedded targets.
+//
+// `defmt` leaves formatting to the host, which only knows about primitive types, so the
+// digits are worked out here. They're rendered in exponential notation with the number of
+// significant digits that the type can represent (31 for a `Double`, 62 for a `Quad`), with
+// trailing zeros dropped. The mantissa goes over the wire as a string and the exponent as
+// an integer, so `Double::PI` is logged as `3.14159265358979323846264338328e0`.
+//
+// This doesn't use `Display` or anything else from `core::fmt`, and so it works without
+// the `display` feature.
+
+use crate::double::Double;
+use crate::quad::Quad;
+use defmt::{Format, Formatter};
+
+// The digits of a finite, nonzero number, with its sign and a decimal point, and the
+// power of ten that they're multiplied by. `N` is at least the number of digits plus three.
+struct Mantissa<const N: usize> {
+    chars: [u8; N],
+    len: usize,
+    exp: i32,
+}
+
+impl<const N: usize> Mantissa<N> {
+    fn as_str(&self) -> &str {
+        // Everything that's written into `chars` is ASCII
+        core::str::from_utf8(&self.chars[..self.len]).unwrap()
+    }
+}
+
+macro_rules! impl_format {
+    ($($t:ident, $name:ident, $len:expr, [$($zeros:expr),*];)*) => {$(
+        // Extracts the first `$t::DIGITS` significant digits of `value`, rounded.
+        fn $name(value: $t) -> Mantissa<$len> {
+            let ten = $t::from(10);
+
+            // Scale the number into [1, 10). The exponent from the first component can be
+            // off by one, which the checks afterward correct. The smallest numbers are scaled
+            // in two steps so that the power of ten doesn't underflow, and the largest so
+            // that splitting the divisor doesn't overflow when there's no FMA.
+            let mut x = value.abs();
+            let mut exp = x.hi().log10().floor() as i32;
+            x = if exp < -300 {
+                x * ten.powi(300) / ten.powi((exp + 300).into())
+            } else if exp > 300 {
+                x / ten.powi(300) / ten.powi((exp - 300).into())
+            } else {
+                x / ten.powi(exp.into())
+            };
+            if x >= ten {
+                x /= ten;
+                exp += 1;
+            } else if x < $t::ONE {
+                x *= ten;
+                exp -= 1;
+            }
+
+            // Subnormal numbers have fewer digits. As with `Display`, nothing past the 323rd
+            // decimal place is kept.
+            let digits = ($t::DIGITS as i32).min(324 + exp).max(1) as usize;
+
+            // One more digit than is kept, for rounding. Each digit is the floor of the
+            // whole number, not just of its first component, which can round up to the next
+            // integer when the rest of the number is negative.
+            let mut ds = [0u8; $len];
+            for d in ds.iter_mut().take(digits + 1) {
+                let mut digit = x.hi().floor();
+                if x < $t::new(digit, $($zeros),*) {
+                    digit -= 1.0;
+                }
+                let digit = digit.clamp(0.0, 9.0);
+                x = (x - $t::new(digit, $($zeros),*)) * ten;
+                *d = digit as u8;
+            }
+
+            if ds[digits] >= 5 {
+                let mut i = digits;
+                loop {
+                    if i == 0 {
+                        // Every digit was a 9, so the number rounds up to the next power of
+                        // ten
+                        ds[0] = 1;
+                        exp += 1;
+                        break;
+                    }
+                    i -= 1;
+                    if ds[i] == 9 {
+                        ds[i] = 0;
+                    } else {
+                        ds[i] += 1;
+                        break;
+                    }
+                }
+            }
+            let mut end = digits;
+            while end > 1 && ds[end - 1] == 0 {
+                end -= 1;
+            }
+
+            let mut m = Mantissa { chars: [0; $len], len: 0, exp };
+            if value.is_sign_negative() {
+                m.chars[0] = b'-';
+                m.len = 1;
+            }
+            for (i, d) in ds[..end].iter().enumerate() {
+                if i == 1 {
+                    m.chars[m.len] = b'.';
+                    m.len += 1;
+                }
+                m.chars[m.len] = b'0' + d;
+                m.len += 1;
+            }
+            m
+        }
+
+        impl Format for $t {
+            fn format(&self, f: Formatter) {
+                if self.is_nan() {
+                    defmt::write!(f, "NaN");
+                } else if self.is_infinite() {
+                    if self.is_sign_negative() {
+                        defmt::write!(f, "-inf");
+                    } else {
+                        defmt::write!(f, "inf");
+                    }
+                } else if *self == $t::ZERO {
+                    if self.is_sign_negative() {
+                        defmt::write!(f, "-0e0");
+                    } else {
+                        defmt::write!(f, "0e0");
+                    }
+                } else {
+                    let m = $name(*self);
+                    defmt::write!(f, "{=str}e{=i32}", m.as_str(), m.exp);
+                }
+            }
+        }
+    )*};
+}
+
+impl_format! {
+    Double, double_mantissa, 34, [0.0];
+    Quad, quad_mantissa, 65, [0.0, 0.0, 0.0];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn format() {
+        assert_format::<Double>();
+        assert_format::<Quad>();
+    }
+
+    #[test]
+    fn double_digits() {
+        let m = double_mantissa(Double::PI);
+        assert_eq!(m.as_str(), "3.14159265358979323846264338328");
+        assert_eq!(m.exp, 0);
+
+        let m = double_mantissa(dd!(-1) / dd!(3));
+        assert_eq!(m.as_str(), "-3.333333333333333333333333333333");
+        assert_eq!(m.exp, -1);
+
+        let m = double_mantissa(dd!(2) / dd!(3));
+        assert_eq!(m.as_str(), "6.666666666666666666666666666667");
+
+        let m = double_mantissa(dd!(1500));
+        assert_eq!((m.as_str(), m.exp), ("1.5", 3));
+
+        let m = double_mantissa(Double::MAX);
+        assert_eq!(m.as_str(), "1.797693134862315807937289714053");
+        assert_eq!(m.exp, 308);
+
+        let m = double_mantissa(dd!(1e-310));
+        assert_eq!((m.as_str(), m.exp), ("1", -310));
+    }
+
+    #[test]
+    fn quad_digits() {
+        let m = quad_mantissa(Quad::E);
+        assert_eq!(
+            m.as_str(),
+            "2.7182818284590452353602874713526624977572470936999595749669676"
+        );
+        assert_eq!(m.exp, 0);
+
+        let m = quad_mantissa(qd!(-1) / qd!(7));
+        assert_eq!(
+            m.as_str(),
+            "-1.4285714285714285714285714285714285714285714285714285714285714"
+        );
+        assert_eq!(m.exp, -1);
+
+        // Rounding carries all the way to the first digit
+        let m = quad_mantissa(qd!(1) - Quad::EPSILON / qd!(4));
+        assert_eq!((m.as_str(), m.exp), ("1", 0));
+
+        let m = quad_mantissa(qd!("9.5e-200"));
+        assert_eq!((m.as_str(), m.exp), ("9.5", -200));
+    }
+}
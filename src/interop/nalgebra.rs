@@ -0,0 +1,529 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// The traits that let `Double` and `Quad` be the scalar type of `nalgebra` vectors and
+// matrices, including the decompositions, which need `ComplexField` and `RealField`.
+//
+// Those traits build on `simba`'s scalar traits (from the `simba` feature), `num-traits`'
+// numeric traits (from the `num-traits` feature), and `approx`'s comparisons, which are
+// implemented here. A `Double` or a `Quad` is a real number, so as a `ComplexField` it has
+// no imaginary part, just like an `f64`.
+
+use crate::double::Double;
+use crate::quad::Quad;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use nalgebra::{ComplexField, Field, RealField};
+
+macro_rules! impl_nalgebra {
+    ($($t:ident)*) => {$(
+        impl AbsDiffEq for $t {
+            type Epsilon = $t;
+
+            #[inline]
+            fn default_epsilon() -> $t {
+                $t::EPSILON
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$t, epsilon: $t) -> bool {
+                (*self - *other).abs() <= epsilon
+            }
+        }
+
+        impl RelativeEq for $t {
+            #[inline]
+            fn default_max_relative() -> $t {
+                $t::EPSILON
+            }
+
+            // This follows `approx`'s implementation for `f64`.
+            fn relative_eq(&self, other: &$t, epsilon: $t, max_relative: $t) -> bool {
+                if self == other {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+                let diff = (*self - *other).abs();
+                if diff <= epsilon {
+                    return true;
+                }
+                diff <= self.abs().max(other.abs()) * max_relative
+            }
+        }
+
+        impl UlpsEq for $t {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            fn ulps_eq(&self, other: &$t, epsilon: $t, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_sign_positive() != other.is_sign_positive() {
+                    return false;
+                }
+                $t::ulps_eq(*self, *other, max_ulps)
+            }
+        }
+
+        impl Field for $t {}
+
+        impl ComplexField for $t {
+            type RealField = $t;
+
+            #[inline]
+            fn from_real(re: $t) -> $t {
+                re
+            }
+
+            #[inline]
+            fn real(self) -> $t {
+                self
+            }
+
+            #[inline]
+            fn imaginary(self) -> $t {
+                $t::ZERO
+            }
+
+            #[inline]
+            fn modulus(self) -> $t {
+                $t::abs(self)
+            }
+
+            #[inline]
+            fn modulus_squared(self) -> $t {
+                $t::sqr(self)
+            }
+
+            #[inline]
+            fn argument(self) -> $t {
+                if self >= $t::ZERO {
+                    $t::ZERO
+                } else {
+                    $t::PI
+                }
+            }
+
+            #[inline]
+            fn norm1(self) -> $t {
+                $t::abs(self)
+            }
+
+            #[inline]
+            fn scale(self, factor: $t) -> $t {
+                self * factor
+            }
+
+            #[inline]
+            fn unscale(self, factor: $t) -> $t {
+                self / factor
+            }
+
+            #[inline]
+            fn to_exp(self) -> ($t, $t) {
+                if self >= $t::ZERO {
+                    (self, $t::ONE)
+                } else {
+                    (-self, $t::NEG_ONE)
+                }
+            }
+
+            #[inline]
+            fn signum(self) -> $t {
+                $t::signum(self)
+            }
+
+            #[inline]
+            fn floor(self) -> $t {
+                $t::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> $t {
+                $t::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> $t {
+                $t::round(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> $t {
+                $t::trunc(self)
+            }
+
+            #[inline]
+            fn fract(self) -> $t {
+                $t::fract(self)
+            }
+
+            #[inline]
+            fn mul_add(self, a: $t, b: $t) -> $t {
+                $t::mul_add(self, a, b)
+            }
+
+            #[inline]
+            fn abs(self) -> $t {
+                $t::abs(self)
+            }
+
+            #[inline]
+            fn hypot(self, other: $t) -> $t {
+                $t::hypot(self, other)
+            }
+
+            #[inline]
+            fn recip(self) -> $t {
+                $t::recip(self)
+            }
+
+            #[inline]
+            fn conjugate(self) -> $t {
+                self
+            }
+
+            #[inline]
+            fn sin(self) -> $t {
+                $t::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> $t {
+                $t::cos(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> ($t, $t) {
+                $t::sin_cos(self)
+            }
+
+            #[inline]
+            fn sinh_cosh(self) -> ($t, $t) {
+                $t::sinh_cosh(self)
+            }
+
+            #[inline]
+            fn tan(self) -> $t {
+                $t::tan(self)
+            }
+
+            #[inline]
+            fn asin(self) -> $t {
+                $t::asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> $t {
+                $t::acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> $t {
+                $t::atan(self)
+            }
+
+            #[inline]
+            fn sinh(self) -> $t {
+                $t::sinh(self)
+            }
+
+            #[inline]
+            fn cosh(self) -> $t {
+                $t::cosh(self)
+            }
+
+            #[inline]
+            fn tanh(self) -> $t {
+                $t::tanh(self)
+            }
+
+            #[inline]
+            fn asinh(self) -> $t {
+                $t::asinh(self)
+            }
+
+            #[inline]
+            fn acosh(self) -> $t {
+                $t::acosh(self)
+            }
+
+            #[inline]
+            fn atanh(self) -> $t {
+                $t::atanh(self)
+            }
+
+            #[inline]
+            fn log(self, base: $t) -> $t {
+                $t::log(self, base)
+            }
+
+            #[inline]
+            fn log2(self) -> $t {
+                $t::log2(self)
+            }
+
+            #[inline]
+            fn log10(self) -> $t {
+                $t::log10(self)
+            }
+
+            #[inline]
+            fn ln(self) -> $t {
+                $t::ln(self)
+            }
+
+            #[inline]
+            fn ln_1p(self) -> $t {
+                $t::ln_1p(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> $t {
+                $t::sqrt(self)
+            }
+
+            #[inline]
+            fn exp(self) -> $t {
+                $t::exp(self)
+            }
+
+            #[inline]
+            fn exp2(self) -> $t {
+                $t::exp2(self)
+            }
+
+            #[inline]
+            fn exp_m1(self) -> $t {
+                $t::exp_m1(self)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> $t {
+                $t::powi(self, n.into())
+            }
+
+            #[inline]
+            fn powf(self, n: $t) -> $t {
+                $t::powf(self, n)
+            }
+
+            #[inline]
+            fn powc(self, n: $t) -> $t {
+                $t::powf(self, n)
+            }
+
+            #[inline]
+            fn cbrt(self) -> $t {
+                $t::cbrt(self)
+            }
+
+            #[inline]
+            fn is_finite(&self) -> bool {
+                $t::is_finite(*self)
+            }
+
+            #[inline]
+            fn try_sqrt(self) -> Option<$t> {
+                if self >= $t::ZERO {
+                    Some($t::sqrt(self))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl RealField for $t {
+            #[inline]
+            fn is_sign_positive(&self) -> bool {
+                $t::is_sign_positive(*self)
+            }
+
+            #[inline]
+            fn is_sign_negative(&self) -> bool {
+                $t::is_sign_negative(*self)
+            }
+
+            #[inline]
+            fn copysign(self, sign: $t) -> $t {
+                $t::copysign(self, sign)
+            }
+
+            #[inline]
+            fn max(self, other: $t) -> $t {
+                $t::max(self, other)
+            }
+
+            #[inline]
+            fn min(self, other: $t) -> $t {
+                $t::min(self, other)
+            }
+
+            #[inline]
+            fn clamp(self, min: $t, max: $t) -> $t {
+                $t::clamp(self, min, max)
+            }
+
+            #[inline]
+            fn atan2(self, other: $t) -> $t {
+                $t::atan2(self, other)
+            }
+
+            #[inline]
+            fn min_value() -> Option<$t> {
+                Some($t::MIN)
+            }
+
+            #[inline]
+            fn max_value() -> Option<$t> {
+                Some($t::MAX)
+            }
+
+            #[inline]
+            fn pi() -> $t {
+                $t::PI
+            }
+
+            #[inline]
+            fn two_pi() -> $t {
+                $t::TAU
+            }
+
+            #[inline]
+            fn frac_pi_2() -> $t {
+                $t::FRAC_PI_2
+            }
+
+            #[inline]
+            fn frac_pi_3() -> $t {
+                $t::FRAC_PI_3
+            }
+
+            #[inline]
+            fn frac_pi_4() -> $t {
+                $t::FRAC_PI_4
+            }
+
+            #[inline]
+            fn frac_pi_6() -> $t {
+                $t::FRAC_PI_6
+            }
+
+            #[inline]
+            fn frac_pi_8() -> $t {
+                $t::FRAC_PI_8
+            }
+
+            #[inline]
+            fn frac_1_pi() -> $t {
+                $t::FRAC_1_PI
+            }
+
+            #[inline]
+            fn frac_2_pi() -> $t {
+                $t::FRAC_2_PI
+            }
+
+            #[inline]
+            fn frac_2_sqrt_pi() -> $t {
+                $t::FRAC_2_SQRT_PI
+            }
+
+            #[inline]
+            fn e() -> $t {
+                $t::E
+            }
+
+            #[inline]
+            fn log2_e() -> $t {
+                $t::LOG2_E
+            }
+
+            #[inline]
+            fn log10_e() -> $t {
+                $t::LOG10_E
+            }
+
+            #[inline]
+            fn ln_2() -> $t {
+                $t::LN_2
+            }
+
+            #[inline]
+            fn ln_10() -> $t {
+                $t::LN_10
+            }
+        }
+    )*};
+}
+
+impl_nalgebra!(Double Quad);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+    use nalgebra::{Matrix3, Vector3};
+
+    // The 3x3 Hilbert matrix, whose condition number is about 524
+    fn hilbert<T: RealField + Copy>() -> Matrix3<T> {
+        Matrix3::from_fn(|i, j| T::one() / nalgebra::convert((i + j + 1) as f64))
+    }
+
+    #[test]
+    fn approx() {
+        assert!(Double::ONE.abs_diff_eq(&(Double::ONE + dd!(1e-33)), Double::EPSILON));
+        assert!(!Quad::ONE.abs_diff_eq(&(Quad::ONE + qd!(1e-60)), Quad::EPSILON));
+        assert!(Double::PI.relative_eq(&Double::PI.next_up(), dd!(0), dd!(1e-31)));
+        assert!(UlpsEq::ulps_eq(
+            &Quad::E,
+            &Quad::E.next_down().next_down(),
+            qd!(0),
+            2
+        ));
+        assert!(!Double::INFINITY.relative_eq(&Double::MAX, Double::EPSILON, dd!(1)));
+    }
+
+    #[test]
+    fn complex_field() {
+        assert_eq!(ComplexField::modulus(dd!(-3)), dd!(3));
+        assert_eq!(ComplexField::argument(qd!(-1)), Quad::PI);
+        assert_eq!(ComplexField::imaginary(Double::PI), Double::ZERO);
+        assert_eq!(ComplexField::try_sqrt(qd!(-4)), None);
+        assert_eq!(ComplexField::try_sqrt(dd!(4)), Some(dd!(2)));
+        assert_eq!(<Quad as RealField>::two_pi(), Quad::TAU);
+    }
+
+    #[test]
+    fn matrix_inverse() {
+        let h = hilbert::<Double>();
+        let inv = h.try_inverse().unwrap();
+        let entries = [9, -36, 30, -36, 192, -180, 30, -180, 180];
+        let expected = Matrix3::from_row_slice(&entries.map(Double::from));
+        assert!((inv - expected).abs().max() < dd!(1e-27));
+    }
+
+    #[test]
+    fn decompositions() {
+        let h = hilbert::<Quad>();
+        let b = Vector3::new(qd!(1), qd!(2), qd!(3));
+
+        let x = h.lu().solve(&b).unwrap();
+        assert!((h * x - b).norm() < qd!(1e-60));
+
+        let x = h.cholesky().unwrap().solve(&b);
+        assert!((h * x - b).norm() < qd!(1e-60));
+
+        // The sum of the eigenvalues is the trace, and their product is the determinant
+        let eigen = h.symmetric_eigen();
+        assert!((eigen.eigenvalues.sum() - h.trace()).abs() < qd!(1e-60));
+        let det = qd!(1) / qd!(2160);
+        assert!((eigen.eigenvalues.product() - det).abs() < qd!(1e-63));
+    }
+}
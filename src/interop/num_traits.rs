@@ -0,0 +1,927 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// The `num-traits` traits for `Double` and `Quad`, so that they can be used with numeric
+// code written against `T: Float` and the like.
+//
+// Almost everything here forwards to the inherent method of the same name. The exceptions
+// are the methods that have no inherent counterpart (`integer_decode` and the primitive
+// conversions), and `Float`, which needs the `transcendental` feature because most of its
+// methods are transcendental functions. `FloatCore` has no such requirement.
+
+use crate::double::Double;
+use crate::error::{ErrorKind, ParseDoubleError, ParseQuadError};
+use crate::quad::Quad;
+use num_traits::float::FloatCore;
+#[cfg(feature = "transcendental")]
+use num_traits::Float;
+use num_traits::{Bounded, FloatConst, FromPrimitive, Num, NumCast, One, Signed};
+use num_traits::{ToPrimitive, Zero};
+use std::convert::TryFrom;
+use std::num::FpCategory;
+
+// 2^64, the scale of the high half of a 128-bit integer.
+const TWO_64: f64 = 18446744073709551616.0;
+
+// `Quad` converts 128-bit integers itself, but `Double` doesn't, so these split them into
+// 64-bit halves. Scaling the high half by 2^64 is exact, so the only rounding is in the
+// final sum.
+fn double_from_i128(n: i128) -> Double {
+    <Double as From<_>>::from((n >> 64) as i64).mul_pwr2(TWO_64)
+        + <Double as From<_>>::from(n as u64)
+}
+
+fn double_from_u128(n: u128) -> Double {
+    <Double as From<_>>::from((n >> 64) as u64).mul_pwr2(TWO_64)
+        + <Double as From<_>>::from(n as u64)
+}
+
+macro_rules! impl_num_traits {
+    ($($t:ident $err:ident $from_i128:expr, $from_u128:expr;)*) => {$(
+        impl Zero for $t {
+            #[inline]
+            fn zero() -> $t {
+                $t::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                $t::is_zero(*self)
+            }
+        }
+
+        impl One for $t {
+            #[inline]
+            fn one() -> $t {
+                $t::ONE
+            }
+
+            #[inline]
+            fn is_one(&self) -> bool {
+                *self == $t::ONE
+            }
+        }
+
+        impl Num for $t {
+            type FromStrRadixErr = $err;
+
+            /// Parses a string into a number. Only decimal strings can be parsed, so any
+            /// radix other than 10 produces an error.
+            fn from_str_radix(s: &str, radix: u32) -> Result<$t, $err> {
+                if radix == 10 {
+                    s.parse()
+                } else {
+                    Err($err {
+                        kind: ErrorKind::Invalid,
+                    })
+                }
+            }
+        }
+
+        impl Signed for $t {
+            #[inline]
+            fn abs(&self) -> $t {
+                $t::abs(*self)
+            }
+
+            #[inline]
+            fn abs_sub(&self, other: &$t) -> $t {
+                $t::abs_sub(*self, *other)
+            }
+
+            #[inline]
+            fn signum(&self) -> $t {
+                $t::signum(*self)
+            }
+
+            #[inline]
+            fn is_positive(&self) -> bool {
+                $t::is_sign_positive(*self)
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                $t::is_sign_negative(*self)
+            }
+        }
+
+        impl Bounded for $t {
+            #[inline]
+            fn min_value() -> $t {
+                $t::MIN
+            }
+
+            #[inline]
+            fn max_value() -> $t {
+                $t::MAX
+            }
+        }
+
+        impl ToPrimitive for $t {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                $t::trunc_i64(*self)
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                $t::trunc_i128(*self).and_then(|n| u64::try_from(n).ok())
+            }
+
+            #[inline]
+            fn to_i128(&self) -> Option<i128> {
+                $t::trunc_i128(*self)
+            }
+
+            #[inline]
+            fn to_u128(&self) -> Option<u128> {
+                // `trunc_i128` stops at 2^127, so the upper half of the range is shifted down
+                // into its range first. The subtraction is exact for any number that can be
+                // converted.
+                let half = <$t as From<_>>::from(TWO_64).mul_pwr2(TWO_64 / 2.0);
+                if *self >= half {
+                    $t::trunc_i128(*self - half).map(|n| n as u128 + (1 << 127))
+                } else {
+                    $t::trunc_i128(*self).and_then(|n| u128::try_from(n).ok())
+                }
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some((*self).into())
+            }
+        }
+
+        impl FromPrimitive for $t {
+            #[inline]
+            fn from_i64(n: i64) -> Option<$t> {
+                Some(<$t as From<_>>::from(n))
+            }
+
+            #[inline]
+            fn from_u64(n: u64) -> Option<$t> {
+                Some(<$t as From<_>>::from(n))
+            }
+
+            #[inline]
+            fn from_i128(n: i128) -> Option<$t> {
+                Some($from_i128(n))
+            }
+
+            #[inline]
+            fn from_u128(n: u128) -> Option<$t> {
+                Some($from_u128(n))
+            }
+
+            #[inline]
+            fn from_f32(n: f32) -> Option<$t> {
+                Some(<$t as From<_>>::from(n))
+            }
+
+            #[inline]
+            fn from_f64(n: f64) -> Option<$t> {
+                Some(<$t as From<_>>::from(n))
+            }
+        }
+
+        impl NumCast for $t {
+            /// Converts any primitive number into a `Double` or a `Quad`.
+            ///
+            /// Integers are converted exactly (or, for a `Double`, as closely as it can
+            /// represent them), even those too large to be exactly converted to an `f64`.
+            /// Everything else goes through an `f64`, converted the same way as with
+            /// `From<f64>`.
+            fn from<T: ToPrimitive>(n: T) -> Option<$t> {
+                let f = n.to_f64()?;
+                if f.fract() == 0.0 {
+                    // `fract` is only zero for finite integers, and those are the only
+                    // numbers whose integer conversions aren't truncations
+                    if let Some(i) = n.to_i128() {
+                        return Some($from_i128(i));
+                    }
+                    if let Some(u) = n.to_u128() {
+                        return Some($from_u128(u));
+                    }
+                }
+                Some(<$t as From<_>>::from(f))
+            }
+        }
+
+        impl FloatConst for $t {
+            #[inline]
+            fn E() -> $t {
+                $t::E
+            }
+
+            #[inline]
+            fn FRAC_1_PI() -> $t {
+                $t::FRAC_1_PI
+            }
+
+            #[inline]
+            fn FRAC_1_SQRT_2() -> $t {
+                $t::FRAC_1_SQRT_2
+            }
+
+            #[inline]
+            fn FRAC_2_PI() -> $t {
+                $t::FRAC_2_PI
+            }
+
+            #[inline]
+            fn FRAC_2_SQRT_PI() -> $t {
+                $t::FRAC_2_SQRT_PI
+            }
+
+            #[inline]
+            fn FRAC_PI_2() -> $t {
+                $t::FRAC_PI_2
+            }
+
+            #[inline]
+            fn FRAC_PI_3() -> $t {
+                $t::FRAC_PI_3
+            }
+
+            #[inline]
+            fn FRAC_PI_4() -> $t {
+                $t::FRAC_PI_4
+            }
+
+            #[inline]
+            fn FRAC_PI_6() -> $t {
+                $t::FRAC_PI_6
+            }
+
+            #[inline]
+            fn FRAC_PI_8() -> $t {
+                $t::FRAC_PI_8
+            }
+
+            #[inline]
+            fn LN_10() -> $t {
+                $t::LN_10
+            }
+
+            #[inline]
+            fn LN_2() -> $t {
+                $t::LN_2
+            }
+
+            #[inline]
+            fn LOG10_E() -> $t {
+                $t::LOG10_E
+            }
+
+            #[inline]
+            fn LOG2_E() -> $t {
+                $t::LOG2_E
+            }
+
+            #[inline]
+            fn PI() -> $t {
+                $t::PI
+            }
+
+            #[inline]
+            fn SQRT_2() -> $t {
+                $t::SQRT_2
+            }
+
+            #[inline]
+            fn TAU() -> $t {
+                $t::TAU
+            }
+
+            #[inline]
+            fn LOG10_2() -> $t {
+                $t::LOG10_2
+            }
+
+            #[inline]
+            fn LOG2_10() -> $t {
+                $t::LOG2_10
+            }
+        }
+
+        impl FloatCore for $t {
+            #[inline]
+            fn infinity() -> $t {
+                $t::INFINITY
+            }
+
+            #[inline]
+            fn neg_infinity() -> $t {
+                $t::NEG_INFINITY
+            }
+
+            #[inline]
+            fn nan() -> $t {
+                $t::NAN
+            }
+
+            #[inline]
+            fn neg_zero() -> $t {
+                $t::NEG_ZERO
+            }
+
+            #[inline]
+            fn min_value() -> $t {
+                $t::MIN
+            }
+
+            #[inline]
+            fn min_positive_value() -> $t {
+                $t::MIN_POSITIVE
+            }
+
+            #[inline]
+            fn epsilon() -> $t {
+                $t::EPSILON
+            }
+
+            #[inline]
+            fn max_value() -> $t {
+                $t::MAX
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                $t::is_infinite(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            #[inline]
+            fn is_normal(self) -> bool {
+                $t::is_normal(self)
+            }
+
+            #[inline]
+            fn is_subnormal(self) -> bool {
+                $t::is_subnormal(self)
+            }
+
+            #[inline]
+            fn classify(self) -> FpCategory {
+                $t::classify(self)
+            }
+
+            #[inline]
+            fn floor(self) -> $t {
+                $t::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> $t {
+                $t::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> $t {
+                $t::round(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> $t {
+                $t::trunc(self)
+            }
+
+            #[inline]
+            fn fract(self) -> $t {
+                $t::fract(self)
+            }
+
+            #[inline]
+            fn abs(self) -> $t {
+                $t::abs(self)
+            }
+
+            #[inline]
+            fn signum(self) -> $t {
+                $t::signum(self)
+            }
+
+            #[inline]
+            fn is_sign_positive(self) -> bool {
+                $t::is_sign_positive(self)
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                $t::is_sign_negative(self)
+            }
+
+            #[inline]
+            fn min(self, other: $t) -> $t {
+                $t::min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: $t) -> $t {
+                $t::max(self, other)
+            }
+
+            #[inline]
+            fn recip(self) -> $t {
+                $t::recip(self)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> $t {
+                $t::powi(self, n.into())
+            }
+
+            #[inline]
+            fn to_degrees(self) -> $t {
+                self * <$t as From<_>>::from(180) / $t::PI
+            }
+
+            #[inline]
+            fn to_radians(self) -> $t {
+                self * $t::PI / <$t as From<_>>::from(180)
+            }
+
+            /// Returns the mantissa, base-2 exponent, and sign of the number.
+            ///
+            /// A `u64` mantissa can't hold all of the bits of a `Double` or a `Quad`, so the
+            /// mantissa is the leading 64 bits of the number, truncated. Multiplying it by 2
+            /// to the power of the exponent gives the number back only if it has no more
+            /// than 64 significant bits. Zeros, infinities, and `NaN` are decoded the same
+            /// way as an `f64`.
+            fn integer_decode(self) -> (u64, i16, i8) {
+                if !$t::is_finite(self) || $t::is_zero(self) {
+                    return FloatCore::integer_decode(self.hi());
+                }
+                let sign = if $t::is_sign_negative(self) { -1 } else { 1 };
+                let a = $t::abs(self);
+                // The estimate of the exponent from the leading component can be off by one
+                // in either direction, since the components after it can carry the number
+                // across a power of two, so it's corrected if the mantissa comes out with
+                // the wrong number of bits
+                let mut exp = a.hi().log2().floor() as i32 - 63;
+                let mut m = a.scale_b(-exp).trunc();
+                if m >= <$t as From<_>>::from(TWO_64) {
+                    exp += 1;
+                    m = a.scale_b(-exp).trunc();
+                } else if m < <$t as From<_>>::from(TWO_64 / 2.0) {
+                    exp -= 1;
+                    m = a.scale_b(-exp).trunc();
+                }
+                // `unwrap` is safe because the mantissa is an integer less than 2^64
+                (m.trunc_i128().unwrap() as u64, exp as i16, sign)
+            }
+        }
+
+        #[cfg(feature = "transcendental")]
+        impl Float for $t {
+            #[inline]
+            fn nan() -> $t {
+                $t::NAN
+            }
+
+            #[inline]
+            fn infinity() -> $t {
+                $t::INFINITY
+            }
+
+            #[inline]
+            fn neg_infinity() -> $t {
+                $t::NEG_INFINITY
+            }
+
+            #[inline]
+            fn neg_zero() -> $t {
+                $t::NEG_ZERO
+            }
+
+            #[inline]
+            fn min_value() -> $t {
+                $t::MIN
+            }
+
+            #[inline]
+            fn min_positive_value() -> $t {
+                $t::MIN_POSITIVE
+            }
+
+            #[inline]
+            fn epsilon() -> $t {
+                $t::EPSILON
+            }
+
+            #[inline]
+            fn max_value() -> $t {
+                $t::MAX
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                $t::is_infinite(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            #[inline]
+            fn is_normal(self) -> bool {
+                $t::is_normal(self)
+            }
+
+            #[inline]
+            fn is_subnormal(self) -> bool {
+                $t::is_subnormal(self)
+            }
+
+            #[inline]
+            fn classify(self) -> FpCategory {
+                $t::classify(self)
+            }
+
+            #[inline]
+            fn floor(self) -> $t {
+                $t::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> $t {
+                $t::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> $t {
+                $t::round(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> $t {
+                $t::trunc(self)
+            }
+
+            #[inline]
+            fn fract(self) -> $t {
+                $t::fract(self)
+            }
+
+            #[inline]
+            fn abs(self) -> $t {
+                $t::abs(self)
+            }
+
+            #[inline]
+            fn signum(self) -> $t {
+                $t::signum(self)
+            }
+
+            #[inline]
+            fn is_sign_positive(self) -> bool {
+                $t::is_sign_positive(self)
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                $t::is_sign_negative(self)
+            }
+
+            #[inline]
+            fn mul_add(self, a: $t, b: $t) -> $t {
+                $t::mul_add(self, a, b)
+            }
+
+            #[inline]
+            fn recip(self) -> $t {
+                $t::recip(self)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> $t {
+                $t::powi(self, n.into())
+            }
+
+            #[inline]
+            fn powf(self, n: $t) -> $t {
+                $t::powf(self, n)
+            }
+
+            #[inline]
+            fn sqrt(self) -> $t {
+                $t::sqrt(self)
+            }
+
+            #[inline]
+            fn exp(self) -> $t {
+                $t::exp(self)
+            }
+
+            #[inline]
+            fn exp2(self) -> $t {
+                $t::exp2(self)
+            }
+
+            #[inline]
+            fn ln(self) -> $t {
+                $t::ln(self)
+            }
+
+            #[inline]
+            fn log(self, base: $t) -> $t {
+                $t::log(self, base)
+            }
+
+            #[inline]
+            fn log2(self) -> $t {
+                $t::log2(self)
+            }
+
+            #[inline]
+            fn log10(self) -> $t {
+                $t::log10(self)
+            }
+
+            #[inline]
+            fn to_degrees(self) -> $t {
+                FloatCore::to_degrees(self)
+            }
+
+            #[inline]
+            fn to_radians(self) -> $t {
+                FloatCore::to_radians(self)
+            }
+
+            #[inline]
+            fn max(self, other: $t) -> $t {
+                $t::max(self, other)
+            }
+
+            #[inline]
+            fn min(self, other: $t) -> $t {
+                $t::min(self, other)
+            }
+
+            #[inline]
+            fn abs_sub(self, other: $t) -> $t {
+                $t::abs_sub(self, other)
+            }
+
+            #[inline]
+            fn cbrt(self) -> $t {
+                $t::cbrt(self)
+            }
+
+            #[inline]
+            fn hypot(self, other: $t) -> $t {
+                $t::hypot(self, other)
+            }
+
+            #[inline]
+            fn sin(self) -> $t {
+                $t::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> $t {
+                $t::cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> $t {
+                $t::tan(self)
+            }
+
+            #[inline]
+            fn asin(self) -> $t {
+                $t::asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> $t {
+                $t::acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> $t {
+                $t::atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: $t) -> $t {
+                $t::atan2(self, other)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> ($t, $t) {
+                $t::sin_cos(self)
+            }
+
+            #[inline]
+            fn exp_m1(self) -> $t {
+                $t::exp_m1(self)
+            }
+
+            #[inline]
+            fn ln_1p(self) -> $t {
+                $t::ln_1p(self)
+            }
+
+            #[inline]
+            fn sinh(self) -> $t {
+                $t::sinh(self)
+            }
+
+            #[inline]
+            fn cosh(self) -> $t {
+                $t::cosh(self)
+            }
+
+            #[inline]
+            fn tanh(self) -> $t {
+                $t::tanh(self)
+            }
+
+            #[inline]
+            fn asinh(self) -> $t {
+                $t::asinh(self)
+            }
+
+            #[inline]
+            fn acosh(self) -> $t {
+                $t::acosh(self)
+            }
+
+            #[inline]
+            fn atanh(self) -> $t {
+                $t::atanh(self)
+            }
+
+            #[inline]
+            fn integer_decode(self) -> (u64, i16, i8) {
+                FloatCore::integer_decode(self)
+            }
+
+            #[inline]
+            fn copysign(self, sign: $t) -> $t {
+                $t::copysign(self, sign)
+            }
+        }
+    )*};
+}
+
+impl_num_traits! {
+    Double ParseDoubleError double_from_i128, double_from_u128;
+    Quad ParseQuadError <Quad as From<_>>::from, <Quad as From<_>>::from;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+
+    // Generic functions, to make sure that the trait methods are the ones being tested
+    fn zero_one<T: Zero + One>() -> (T, T) {
+        (T::zero(), T::one())
+    }
+
+    fn cast<T: NumCast, U: ToPrimitive>(n: U) -> Option<T> {
+        T::from(n)
+    }
+
+    #[cfg(feature = "transcendental")]
+    fn hypot<T: Float>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+
+    #[test]
+    fn zero_and_one() {
+        assert_eq!(zero_one::<Double>(), (Double::ZERO, Double::ONE));
+        assert_eq!(zero_one::<Quad>(), (Quad::ZERO, Quad::ONE));
+        assert!(Zero::is_zero(&Double::NEG_ZERO));
+        assert!(One::is_one(&qd!(1)));
+        assert!(!One::is_one(&Quad::PI));
+    }
+
+    #[test]
+    fn from_str_radix() {
+        assert_eq!(Double::from_str_radix("1.5", 10), Ok(dd!(1.5)));
+        assert_eq!(Quad::from_str_radix("-2e3", 10), Ok(qd!(-2000)));
+        assert!(Double::from_str_radix("1.5", 16).is_err());
+        assert!(Quad::from_str_radix("", 10).is_err());
+    }
+
+    #[test]
+    fn signed() {
+        assert_eq!(Signed::abs(&dd!(-3)), dd!(3));
+        assert_eq!(Signed::abs_sub(&qd!(2), &qd!(5)), Quad::ZERO);
+        assert_eq!(Signed::signum(&dd!(-0.5)), Double::NEG_ONE);
+        assert!(Signed::is_positive(&Quad::PI));
+        assert!(Signed::is_negative(&Double::NEG_ZERO));
+    }
+
+    #[test]
+    fn primitive_conversions() {
+        assert_eq!(dd!(-2.7).to_i64(), Some(-2));
+        assert_eq!(dd!(-2.7).to_u64(), None);
+        assert_eq!(
+            qd!("1e30").to_i128(),
+            Some(1_000_000_000_000_000_000_000_000_000_000)
+        );
+        assert_eq!(Double::NAN.to_i32(), None);
+        assert_eq!(Quad::PI.to_f64(), Some(std::f64::consts::PI));
+
+        assert_eq!(
+            Double::from_i128(i128::MAX).unwrap().to_i128(),
+            Some(i128::MAX)
+        );
+        assert_eq!(
+            Quad::from_u128(u128::MAX).unwrap().to_u128(),
+            Some(u128::MAX)
+        );
+        assert_eq!(Double::from_f64(0.1), Some(dd!(0.1)));
+    }
+
+    #[test]
+    fn num_cast() {
+        // Integers too large for an `f64` are still converted exactly
+        let big = (1u64 << 60) + 1;
+        assert_eq!(cast::<Double, _>(big).unwrap().to_u64(), Some(big));
+        assert_eq!(
+            cast::<Quad, _>(-(1i128 << 100) - 1).unwrap().to_i128(),
+            Some(-(1 << 100) - 1)
+        );
+        assert_eq!(cast::<Double, _>(2.5f32), Some(dd!(2.5)));
+        assert_eq!(cast::<Quad, _>(0.1), Some(qd!(0.1)));
+        assert!(cast::<Double, _>(f64::NAN).unwrap().is_nan());
+        assert_eq!(
+            cast::<Quad, _>(Double::PI),
+            Some(<Quad as From<_>>::from(std::f64::consts::PI))
+        );
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(<Double as FloatConst>::PI(), Double::PI);
+        assert_eq!(<Quad as FloatConst>::LOG2_10(), Quad::LOG2_10);
+        assert_eq!(<Double as FloatCore>::epsilon(), Double::EPSILON);
+        assert_eq!(<Quad as Bounded>::max_value(), Quad::MAX);
+    }
+
+    #[test]
+    fn float_core() {
+        assert_eq!(FloatCore::floor(dd!(-1.5)), dd!(-2));
+        assert_eq!(FloatCore::powi(qd!(2), -2), qd!(0.25));
+        assert_eq!(FloatCore::to_degrees(Double::PI), dd!(180));
+        assert!((FloatCore::to_radians(qd!(90)) - Quad::FRAC_PI_2).abs() < qd!(1e-63));
+        assert_eq!(FloatCore::classify(Quad::NAN), FpCategory::Nan);
+    }
+
+    #[test]
+    fn integer_decode() {
+        // Bits past the first 64 are truncated
+        let x = dd!(2).powi(80) + dd!(1);
+        let (m, e, s) = FloatCore::integer_decode(-x);
+        assert_eq!((m, e, s), (1 << 63, 17, -1));
+        let (m, e, s) = FloatCore::integer_decode(qd!(2).powi(70) - qd!(1));
+        assert_eq!((m, e, s), (u64::MAX, 6, 1));
+        let (m, e, s) = FloatCore::integer_decode(qd!(3).powi(-500));
+        assert_eq!(m >> 63, 1);
+        assert!(
+            (<Quad as From<_>>::from(m) * qd!(2).powi(e.into()) - qd!(3).powi(-500)).abs()
+                < qd!(1e-257)
+        );
+        assert_eq!(s, 1);
+
+        let inf = FloatCore::integer_decode(f64::INFINITY);
+        assert_eq!(FloatCore::integer_decode(Double::INFINITY), inf);
+        assert_eq!(
+            FloatCore::integer_decode(Quad::ZERO),
+            FloatCore::integer_decode(0f64)
+        );
+    }
+
+    #[cfg(feature = "transcendental")]
+    #[test]
+    fn float() {
+        assert_eq!(hypot(dd!(3), dd!(4)), dd!(5));
+        assert!((hypot(qd!(1), qd!(1)) - Quad::SQRT_2).abs() < qd!(1e-63));
+        assert!((Float::exp(Double::ONE) - Double::E).abs() < dd!(1e-31));
+        assert!((Float::ln(Quad::E) - qd!(1)).abs() < qd!(1e-63));
+        assert_eq!(Float::powi(dd!(3), 3), dd!(27));
+        assert_eq!(Float::max(qd!(1), Quad::NAN), qd!(1));
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// `quickcheck::Arbitrary` for `Double` and `Quad`, for property testing.
+//
+// The first component is an arbitrary `f64`, so these get the same mix of values that `f64`
+// does, including its zeros, infinities, NaN, and extremes. When that component is finite
+// and nonzero, each of the other components is a random fraction of half an ulp of the one
+// before it, which keeps the number normalized and fills all of its precision.
+//
+// A number shrinks first by dropping its lower components, from all of them at once to just
+// the last one, and then the way that its first component shrinks as an `f64`.
+
+use crate::double::Double;
+use crate::quad::Quad;
+use quickcheck::{Arbitrary, Gen};
+
+// A random number whose magnitude is less than half an ulp of `prev`, which must be finite.
+// The lowest 53 bits of a random `u64` make a fraction in [0, 1) and the highest one is the
+// sign.
+fn next_component(g: &mut Gen, prev: f64) -> f64 {
+    let bits = u64::arbitrary(g);
+    if prev == 0.0 {
+        return 0.0;
+    }
+    let prev = prev.abs();
+    let half_ulp = (prev - f64::from_bits(prev.to_bits() - 1)) * 0.5;
+    let x = (bits & ((1 << 53) - 1)) as f64 / (1u64 << 53) as f64 * half_ulp;
+    if bits >> 63 == 1 {
+        -x
+    } else {
+        x
+    }
+}
+
+impl Arbitrary for Double {
+    fn arbitrary(g: &mut Gen) -> Double {
+        let a = f64::arbitrary(g);
+        if !a.is_finite() || a == 0.0 {
+            return Double::new(a, 0.0);
+        }
+        let b = next_component(g, a);
+        Double::new(a, b)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Double>> {
+        let (a, b) = (self.hi(), self.lo());
+        let truncated = if b != 0.0 {
+            Some(Double::new(a, 0.0))
+        } else {
+            None
+        };
+        Box::new(
+            truncated
+                .into_iter()
+                .chain(a.shrink().map(|x| Double::new(x, 0.0))),
+        )
+    }
+}
+
+impl Arbitrary for Quad {
+    fn arbitrary(g: &mut Gen) -> Quad {
+        let a = f64::arbitrary(g);
+        if !a.is_finite() || a == 0.0 {
+            return Quad::new(a, 0.0, 0.0, 0.0);
+        }
+        let b = next_component(g, a);
+        let c = next_component(g, b);
+        let d = next_component(g, c);
+        Quad::new(a, b, c, d)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Quad>> {
+        let c = [self[0], self[1], self[2], self[3]];
+        let len = c.iter().rposition(|&x| x != 0.0).map_or(0, |i| i + 1);
+        let truncated = (1..len).map(move |n| {
+            let mut t = [0.0; 4];
+            t[..n].copy_from_slice(&c[..n]);
+            Quad::new(t[0], t[1], t[2], t[3])
+        });
+        Box::new(truncated.chain(c[0].shrink().map(|x| Quad::new(x, 0.0, 0.0, 0.0))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+    use quickcheck::{QuickCheck, TestResult};
+
+    // Whether each component is no more than half an ulp of the one before it, which is
+    // when adding it to the one before it doesn't change that one
+    fn normalized(components: &[f64]) -> bool {
+        components.windows(2).all(|w| w[0] + w[1] == w[0])
+    }
+
+    #[test]
+    fn arbitrary() {
+        let mut g = Gen::new(100);
+        let (mut special, mut full) = (0, 0);
+        for _ in 0..1000 {
+            let x = Double::arbitrary(&mut g);
+            let y = Quad::arbitrary(&mut g);
+            if x.is_finite() {
+                assert!(normalized(&[x.hi(), x.lo()]), "{:?}", x);
+            }
+            if !y.is_finite() {
+                special += 1;
+            } else {
+                assert!(normalized(&[y[0], y[1], y[2], y[3]]), "{:?}", y);
+                if y[3] != 0.0 {
+                    full += 1;
+                }
+            }
+        }
+        assert!(special > 0 && full > 0);
+    }
+
+    #[test]
+    fn shrink() {
+        let x = dd!(10) / dd!(3);
+        let shrunk: Vec<Double> = x.shrink().collect();
+        assert_eq!(shrunk[0], Double::new(x.hi(), 0.0));
+        assert_eq!(shrunk[1], dd!(0));
+
+        let x = qd!(10) / qd!(3);
+        let shrunk: Vec<Quad> = x.shrink().take(4).collect();
+        assert_eq!(shrunk[0], Quad::new(x[0], 0.0, 0.0, 0.0));
+        assert_eq!(shrunk[1], Quad::new(x[0], x[1], 0.0, 0.0));
+        assert_eq!(shrunk[2], Quad::new(x[0], x[1], x[2], 0.0));
+        assert_eq!(shrunk[3], qd!(0));
+
+        assert_eq!(Double::ZERO.shrink().count(), 0);
+        assert!(Quad::NAN.shrink().all(|x| !x.is_nan()));
+    }
+
+    #[test]
+    fn property() {
+        fn abs_is_not_negative(x: Quad) -> TestResult {
+            if x.is_nan() {
+                return TestResult::discard();
+            }
+            TestResult::from_bool(x.abs() >= qd!(0))
+        }
+        QuickCheck::new().quickcheck(abs_is_not_negative as fn(Quad) -> TestResult);
+
+        // A failing property shrinks to its simplest counterexample
+        fn less_than_1000(x: Double) -> bool {
+            x.is_nan() || x < dd!(1000)
+        }
+        let result = QuickCheck::new().quicktest(less_than_1000 as fn(Double) -> bool);
+        let failure = result.unwrap_err();
+        assert!(failure.is_failure());
+        assert!(format!("{:?}", failure).contains("Double(1e3, 0e0)"));
+    }
+}
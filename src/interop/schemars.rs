@@ -0,0 +1,121 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// `schemars::JsonSchema` for `Double` and `Quad`, for services that publish JSON schemas or
+// OpenAPI documents.
+//
+// The schemas describe what the `serde` implementations write to human-readable formats: a
+// string holding a decimal number, or `NaN`, `inf`, or `-inf`. The pattern is the form that
+// the parser accepts, less the underscores and the other spellings of the special values
+// that it also takes. A description notes how many digits the type carries, since a client
+// that reads the string into an `f64` keeps only about 16 of them.
+
+use crate::double::Double;
+use crate::quad::Quad;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+
+const PATTERN: &str = r"^(?:[+-]?(?:[0-9]+\.?[0-9]*|\.[0-9]+)(?:[eE][+-]?[0-9]+)?|NaN|-?inf)$";
+
+impl JsonSchema for Double {
+    fn schema_name() -> Cow<'static, str> {
+        "Double".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "qd::Double".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": PATTERN,
+            "description": "A double-double precision number, with about 31 significant \
+                decimal digits, as a decimal string.",
+            "examples": ["3.1415926535897932384626433832795e0"],
+        })
+    }
+}
+
+impl JsonSchema for Quad {
+    fn schema_name() -> Cow<'static, str> {
+        "Quad".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "qd::Quad".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": PATTERN,
+            "description": "A quad-double precision number, with about 62 significant \
+                decimal digits, as a decimal string.",
+            "examples": ["3.1415926535897932384626433832795028841971693993751058209749445923e0"],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+    use regex::Regex;
+    use schemars::schema_for;
+
+    #[test]
+    fn schema() {
+        let schema = schema_for!(Double);
+        assert_eq!(schema.get("type").unwrap(), "string");
+        assert_eq!(schema.get("title").unwrap(), "Double");
+        assert!(schema
+            .get("description")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .contains("31"));
+
+        let schema = schema_for!(Vec<Quad>);
+        assert_eq!(schema.get("type").unwrap(), "array");
+        assert_eq!(
+            schema.get("items").unwrap().get("$ref").unwrap(),
+            "#/$defs/Quad"
+        );
+        let quad = schema.get("$defs").unwrap().get("Quad").unwrap();
+        assert_eq!(quad.get("pattern").unwrap(), PATTERN);
+    }
+
+    #[test]
+    fn pattern_matches_serialized() {
+        let pattern = Regex::new(PATTERN).unwrap();
+        let matches = |json: String| pattern.is_match(json.trim_matches('"'));
+
+        for x in [
+            Double::PI,
+            dd!(-1.5e-300),
+            dd!(0),
+            Double::NEG_ZERO,
+            Double::MAX,
+        ] {
+            assert!(matches(serde_json::to_string(&x).unwrap()), "{}", x);
+        }
+        for x in [
+            Quad::E,
+            qd!(7),
+            Quad::MIN_POSITIVE,
+            Quad::NAN,
+            Quad::NEG_INFINITY,
+        ] {
+            assert!(matches(serde_json::to_string(&x).unwrap()), "{}", x);
+        }
+        for s in ["1.5", "-.5", "2.", "+1e10", "6.02E23", "inf"] {
+            assert!(pattern.is_match(s), "{}", s);
+        }
+        for s in ["", "e5", "1.5.2", "--1", "Infinity", "1e"] {
+            assert!(!pattern.is_match(s), "{}", s);
+        }
+    }
+}
@@ -0,0 +1,538 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// `Serialize` and `Deserialize` for `Double` and `Quad`.
+//
+// Human-readable formats like JSON get a string with the shortest decimal that reads back
+// as exactly the same components (in exponential notation, so that very large and very
+// small numbers stay short). `Display` and `FromStr` can't be used for this, since they
+// round to a fixed number of digits and calculate in the type's own precision. Instead,
+// the exact value of the components is found with `BigNat`, and a decimal read from a
+// string is rounded into components from its exact value in the same way. A JSON number
+// would go through an `f64` and lose everything past its first 17 digits. A string read
+// back from a human-readable format can also be a plain number, which is taken to be the
+// decimal that it's written as, just as `From<f64>` does.
+//
+// Binary formats get the components as a tuple of `f64`s, which round-trips exactly.
+
+use crate::common::bigint::BigNat;
+use crate::double::Double;
+use crate::quad::Quad;
+use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+
+impl Serialize for Double {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            (self.hi(), self.lo()).serialize(serializer)
+        } else if self.is_finite() {
+            serializer.collect_str(&Exact(&[self.hi(), self.lo()]))
+        } else {
+            serializer.collect_str(&format_args!("{:e}", self))
+        }
+    }
+}
+
+impl Serialize for Quad {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            (self.c0(), self.c1(), self.c2(), self.c3()).serialize(serializer)
+        } else if self.is_finite() {
+            serializer.collect_str(&Exact(&[self.c0(), self.c1(), self.c2(), self.c3()]))
+        } else {
+            serializer.collect_str(&format_args!("{:e}", self))
+        }
+    }
+}
+
+// The largest power of ten that fits into a `u64`, which lets numbers be scaled by powers
+// of ten a chunk at a time.
+const TEN_19: u64 = 10_000_000_000_000_000_000;
+
+// The most significant digits that a decimal can need to be rounded correctly. A decimal
+// that lies exactly halfway between two `Quad`s is a multiple of 2^-1078 less than 2^1025,
+// which has fewer digits than this. Any digits past these only matter for whether they're
+// all zero.
+const MAX_DIGITS: usize = 1500;
+
+// Displays finite components as the shortest decimal that reads back as exactly the same
+// components.
+struct Exact<'a>(&'a [f64]);
+
+impl fmt::Display for Exact<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (negative, exact, exp) = to_decimal(self.0);
+        let sign = if negative { "-" } else { "" };
+        if exact.is_empty() {
+            return write!(f, "{}0e0", sign);
+        }
+
+        // The components of the absolute value, to compare against what each rounding of
+        // the exact digits reads back as
+        let target: Vec<f64> = self
+            .0
+            .iter()
+            .map(|&c| if negative { -c } else { c })
+            .collect();
+        let mut parsed = vec![0.0; target.len()];
+        let (digits, exp) = (1..exact.len())
+            .map(|k| round(&exact, exp, k))
+            .find(|(digits, exp)| {
+                from_decimal(digits, *exp as i64, &mut parsed);
+                parsed == target
+            })
+            .unwrap_or((exact, exp));
+
+        let (first, rest) = digits.split_at(1);
+        let exp = exp + rest.len() as i32;
+        if rest.is_empty() {
+            write!(f, "{}{}e{}", sign, first, exp)
+        } else {
+            write!(f, "{}{}.{}e{}", sign, first, rest, exp)
+        }
+    }
+}
+
+// Returns the exact value of finite components as `(negative, digits, exp)`, where the
+// value is `digits` times 10^`exp` and `digits` has no leading or trailing zeros (and so
+// is empty for zero).
+fn to_decimal(components: &[f64]) -> (bool, String, i32) {
+    // Every component is an integer multiple of the smallest subnormal, 2^-1074, so the
+    // positive and negative components are summed separately as those multiples
+    let mut pos = BigNat::from_u64(0);
+    let mut neg = BigNat::from_u64(0);
+    for &c in components.iter().filter(|&&c| c != 0.0) {
+        let bits = c.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as u32;
+        let frac = bits & ((1 << 52) - 1);
+        let (m, shift) = if biased == 0 {
+            (frac, 0)
+        } else {
+            (frac | 1 << 52, biased - 1)
+        };
+        let mut n = BigNat::from_u64(m);
+        n.shl(shift);
+        if c < 0.0 {
+            neg.add(&n);
+        } else {
+            pos.add(&n);
+        }
+    }
+
+    let negative = match pos.cmp(&neg) {
+        Ordering::Less => true,
+        Ordering::Greater => false,
+        Ordering::Equal => components[0].is_sign_negative(),
+    };
+    let mut n = if negative {
+        neg.sub(&pos);
+        neg
+    } else {
+        pos.sub(&neg);
+        pos
+    };
+    if n.is_zero() {
+        return (negative, String::new(), 0);
+    }
+
+    // n * 2^-k is n * 5^k * 10^-k, which is how a binary fraction becomes a decimal one
+    let tz = n.trailing_zeros();
+    n.shr(tz);
+    let exp2 = tz as i32 - 1074;
+    let mut exp = 0;
+    if exp2 >= 0 {
+        n.shl(exp2 as u32);
+    } else {
+        exp = exp2;
+        let mut k = -exp2 as u32;
+        while k >= 27 {
+            n.mul_small(5u64.pow(27));
+            k -= 27;
+        }
+        n.mul_small(5u64.pow(k));
+    }
+
+    let mut digits = n.to_decimal();
+    let len = digits.trim_end_matches('0').len();
+    exp += (digits.len() - len) as i32;
+    digits.truncate(len);
+    (negative, digits, exp)
+}
+
+// Rounds `digits` times 10^`exp` to `k` significant digits (less than the number of digits,
+// the last of which isn't zero), ties to even. The result is in the same form as the
+// input.
+fn round(digits: &str, exp: i32, k: usize) -> (String, i32) {
+    let bytes = digits.as_bytes();
+    let up = match bytes[k] {
+        b'5' => bytes[k + 1..].iter().any(|&b| b != b'0') || (bytes[k - 1] - b'0') % 2 == 1,
+        b => b > b'5',
+    };
+
+    let mut rounded = bytes[..k].to_vec();
+    let mut exp = exp + (bytes.len() - k) as i32;
+    if up {
+        match rounded.iter().rposition(|&b| b != b'9') {
+            Some(i) => {
+                rounded[i] += 1;
+                rounded.truncate(i + 1);
+            }
+            None => {
+                rounded = vec![b'1'];
+                exp += 1;
+            }
+        }
+        exp += (k - rounded.len()) as i32;
+    }
+    let len = rounded.iter().rposition(|&b| b != b'0').unwrap() + 1;
+    exp += (rounded.len() - len) as i32;
+    rounded.truncate(len);
+    (String::from_utf8(rounded).unwrap(), exp)
+}
+
+// Rounds `digits` (ASCII decimal digits) times 10^`exp` into components, filling the output
+// slice. Every component is rounded from the exact value, just as `BigNat::to_components`
+// does.
+fn from_decimal(digits: &str, exp: i64, out: &mut [f64]) {
+    for c in out.iter_mut() {
+        *c = 0.0;
+    }
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return;
+    }
+    let len = digits.len() as i64;
+    if len + exp > 310 {
+        out[0] = f64::INFINITY;
+        return;
+    }
+    if len + exp < -330 {
+        return;
+    }
+
+    let mut n = BigNat::from_u64(0);
+    for chunk in digits.as_bytes()[..digits.len().min(MAX_DIGITS)].chunks(19) {
+        let value = chunk.iter().fold(0, |v, &b| v * 10 + (b - b'0') as u64);
+        n.mul_small(10u64.pow(chunk.len() as u32));
+        n.add(&BigNat::from_u64(value));
+    }
+    let mut exp = exp;
+    if digits.len() > MAX_DIGITS {
+        // The extra digits are replaced by a single one that's 1 if any of them aren't
+        // zero, which rounds the same way
+        let rest = &digits[MAX_DIGITS..];
+        n.mul_small(10);
+        n.add(&BigNat::from_u64(rest.bytes().any(|b| b != b'0') as u64));
+        exp += rest.len() as i64 - 1;
+    }
+
+    if exp >= 0 {
+        let mut k = exp;
+        while k >= 19 {
+            n.mul_small(TEN_19);
+            k -= 19;
+        }
+        n.mul_small(10u64.pow(k as u32));
+        n.to_components(out);
+    } else {
+        // The quotient is kept to 1077 fraction bits, with one more bit that's set if it
+        // isn't exact. That's enough to round even the smallest subnormal correctly.
+        n.shl(1077);
+        let mut k = -exp;
+        let mut inexact = false;
+        while k >= 19 {
+            inexact |= n.div_rem_small(TEN_19) != 0;
+            k -= 19;
+        }
+        inexact |= n.div_rem_small(10u64.pow(k as u32)) != 0;
+        n.shl(1);
+        n.add(&BigNat::from_u64(inexact as u64));
+        n.to_scaled_components(-1078, out);
+    }
+
+    if out[0].is_infinite() {
+        for c in out[1..].iter_mut() {
+            *c = 0.0;
+        }
+    }
+}
+
+// Reads a plain decimal number (an optional sign, digits with an optional decimal point,
+// and an optional exponent) exactly, filling the output slice with its components. Anything
+// else, including the special values, is left to `FromStr`.
+fn parse(s: &str, out: &mut [f64]) -> Option<()> {
+    let s = s.trim();
+    let (negative, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+
+    let mut digits = String::new();
+    let mut point = None;
+    for c in mantissa.chars() {
+        match c {
+            '0'..='9' => digits.push(c),
+            '.' if point.is_none() => point = Some(digits.len()),
+            '_' => {}
+            _ => return None,
+        }
+    }
+    if digits.is_empty() {
+        return None;
+    }
+
+    let mut exp = match exponent {
+        Some(e) => parse_exponent(e)?,
+        None => 0,
+    };
+    if let Some(p) = point {
+        exp -= (digits.len() - p) as i64;
+    }
+    from_decimal(&digits, exp, out);
+    if negative {
+        for c in out.iter_mut() {
+            *c = -*c;
+        }
+    }
+    Some(())
+}
+
+// Reads an exponent, which is clamped to a size well past where every number is zero or
+// infinite.
+fn parse_exponent(s: &str) -> Option<i64> {
+    let (negative, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let exp = s
+        .bytes()
+        .fold(0i64, |e, b| (e * 10 + (b - b'0') as i64).min(1 << 40));
+    Some(if negative { -exp } else { exp })
+}
+
+// Builds a `Double` or a `Quad` from a decimal string read with `parse`.
+trait FromDecimal: Sized {
+    fn from_decimal(s: &str) -> Option<Self>;
+}
+
+impl FromDecimal for Double {
+    fn from_decimal(s: &str) -> Option<Double> {
+        let mut c = [0.0; 2];
+        parse(s, &mut c)?;
+        Some(Double::new(c[0], c[1]))
+    }
+}
+
+impl FromDecimal for Quad {
+    fn from_decimal(s: &str) -> Option<Quad> {
+        let mut c = [0.0; 4];
+        parse(s, &mut c)?;
+        Some(Quad::new(c[0], c[1], c[2], c[3]))
+    }
+}
+
+// Reads a `Double` or a `Quad` from the string or the number of a human-readable format.
+struct DecimalVisitor<T>(&'static str, std::marker::PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for DecimalVisitor<T>
+where
+    T: FromDecimal + std::str::FromStr + From<f64> + From<i64> + From<u64>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a {} as a decimal string or a number", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        T::from_decimal(v)
+            .or_else(|| v.parse().ok())
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<T, E> {
+        Ok(T::from(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        Ok(T::from(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        Ok(T::from(v))
+    }
+}
+
+impl<'de> Deserialize<'de> for Double {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Double, D::Error> {
+        if deserializer.is_human_readable() {
+            let visitor = DecimalVisitor("double-double", std::marker::PhantomData);
+            deserializer.deserialize_any(visitor)
+        } else {
+            let (a, b) = <(f64, f64)>::deserialize(deserializer)?;
+            Ok(Double::new(a, b))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Quad {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Quad, D::Error> {
+        if deserializer.is_human_readable() {
+            let visitor = DecimalVisitor("quad-double", std::marker::PhantomData);
+            deserializer.deserialize_any(visitor)
+        } else {
+            let (a, b, c, d) = <(f64, f64, f64, f64)>::deserialize(deserializer)?;
+            Ok(Quad::new(a, b, c, d))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+
+    #[test]
+    fn json() {
+        let json = serde_json::to_string(&Double::PI).unwrap();
+        assert_eq!(json, "\"3.1415926535897932384626433832795e0\"");
+        let x: Double = serde_json::from_str(&json).unwrap();
+        assert_eq!((x.hi(), x.lo()), (Double::PI.hi(), Double::PI.lo()));
+
+        let json = serde_json::to_string(&[Quad::E, qd!(-1.5e-200)]).unwrap();
+        let v: Vec<Quad> = serde_json::from_str(&json).unwrap();
+        assert_eq!(v, [Quad::E, qd!(-1.5e-200)]);
+
+        let json = serde_json::to_string(&Double::NEG_INFINITY).unwrap();
+        assert_eq!(json, "\"-inf\"");
+        let x: Double = serde_json::from_str(&json).unwrap();
+        assert_eq!(x, Double::NEG_INFINITY);
+    }
+
+    #[test]
+    fn json_round_trip_double() {
+        for x in [
+            Double::PI,
+            -Double::E,
+            Double::new(1.0, -1e-20),
+            Double::new(-1.0, 1e-20),
+            dd!(2).sqrt(),
+            dd!(1) / dd!(3),
+            dd!(0.1),
+            dd!(1e300) / dd!(7),
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double::MIN_POSITIVE * dd!(1e10) / dd!(3),
+            Double::new(5e-324, 0.0),
+            dd!(12345),
+        ] {
+            let json = serde_json::to_string(&x).unwrap();
+            let y: Double = serde_json::from_str(&json).unwrap();
+            assert_eq!((y.hi(), y.lo()), (x.hi(), x.lo()), "{}", json);
+        }
+
+        assert_eq!(serde_json::to_string(&dd!(12345)).unwrap(), "\"1.2345e4\"");
+        assert_eq!(
+            serde_json::to_string(&Double::NEG_ZERO).unwrap(),
+            "\"-0e0\""
+        );
+        let x: Double = serde_json::from_str("\"-0e0\"").unwrap();
+        assert!(x.is_sign_negative() && x.is_zero());
+    }
+
+    #[test]
+    fn json_round_trip_quad() {
+        for x in [
+            Quad::PI,
+            -Quad::LN_2,
+            Quad::new(1.0, -1e-20, 0.0, 0.0),
+            Quad::new(1.0, -1e-40, 1e-60, -1e-80),
+            Quad::new(-1.0, 1e-40, -1e-60, 1e-80),
+            qd!(2).sqrt(),
+            qd!(1) / qd!(3),
+            qd!(1e-300) / qd!(7),
+            Quad::new(1e300, 1e-300, 0.0, 0.0),
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+        ] {
+            let json = serde_json::to_string(&x).unwrap();
+            let y: Quad = serde_json::from_str(&json).unwrap();
+            assert_eq!(
+                (y.c0(), y.c1(), y.c2(), y.c3()),
+                (x.c0(), x.c1(), x.c2(), x.c3()),
+                "{}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn json_exact_strings() {
+        // 1 + 2^-60 + 2^-113, where the last term is half of the low component's ulp, so
+        // it rounds to even, and then a hair more than that, which rounds up
+        let half = "1.00000000000000000086736173798840364350245946005774602193952212924636592690508241076940976199693977832794189453125";
+        let x: Double = serde_json::from_str(&format!("\"{}\"", half)).unwrap();
+        assert_eq!((x.hi(), x.lo()), (1.0, 2f64.powi(-60)));
+        let x: Double = serde_json::from_str(&format!("\"{}{}1\"", half, "0".repeat(40))).unwrap();
+        assert_eq!((x.hi(), x.lo()), (1.0, 2f64.powi(-60) + 2f64.powi(-112)));
+        let x: Double = serde_json::from_str("\"1_000.5E-3\"").unwrap();
+        assert_eq!(x, dd!(1.0005));
+        let x: Quad = serde_json::from_str("\"1e400\"").unwrap();
+        assert_eq!(x, Quad::INFINITY);
+        let x: Quad = serde_json::from_str("\"-1e-400\"").unwrap();
+        assert!(x.is_zero());
+    }
+
+    #[test]
+    fn json_numbers() {
+        let x: Double = serde_json::from_str("0.1").unwrap();
+        assert_eq!(x, dd!(0.1));
+        let x: Quad = serde_json::from_str("-7").unwrap();
+        assert_eq!(x, qd!(-7));
+        let x: Quad = serde_json::from_str("18446744073709551615").unwrap();
+        assert_eq!(x, Quad::from(u64::MAX));
+    }
+
+    #[test]
+    fn json_errors() {
+        let err = serde_json::from_str::<Double>("\"1.5x\"").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("a double-double as a decimal string"));
+        assert!(serde_json::from_str::<Quad>("[1, 0]").is_err());
+        assert!(serde_json::from_str::<Quad>("\"\"").is_err());
+    }
+
+    #[test]
+    fn bincode() {
+        let x = Double::PI / dd!(7);
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bincode::deserialize::<Double>(&bytes).unwrap(), x);
+
+        let x = Quad::LN_2.sqrt();
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(bytes.len(), 32);
+        let y: Quad = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(
+            (y.c0(), y.c1(), y.c2(), y.c3()),
+            (x.c0(), x.c1(), x.c2(), x.c3())
+        );
+
+        let bytes = bincode::serialize(&Quad::NAN).unwrap();
+        assert!(bincode::deserialize::<Quad>(&bytes).unwrap().is_nan());
+    }
+}
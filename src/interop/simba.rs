@@ -0,0 +1,176 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// The `simba` traits that describe a scalar type, for generic geometry and physics code
+// that's parameterized over `simba` scalars.
+//
+// `SimdValue` treats a `Double` or a `Quad` as a single lane, just as `simba` treats `f64`.
+// The subset relations follow `simba`'s lead for `f32` and `f64`: every conversion is
+// allowed, and a conversion to a less precise type rounds. A `Double` or a `Quad` made from
+// an `f32` or an `f64` is exact, which `From` isn't for numbers that aren't dyadic, since
+// `From` takes the number to be the decimal that the float was written as.
+
+use crate::double::Double;
+use crate::quad::Quad;
+use simba::scalar::SubsetOf;
+use simba::simd::{PrimitiveSimdValue, SimdValue};
+
+macro_rules! impl_simd_value {
+    ($($t:ident)*) => {$(
+        impl PrimitiveSimdValue for $t {}
+
+        impl SimdValue for $t {
+            const LANES: usize = 1;
+            type Element = $t;
+            type SimdBool = bool;
+
+            #[inline(always)]
+            fn splat(val: $t) -> $t {
+                val
+            }
+
+            #[inline(always)]
+            fn extract(&self, _: usize) -> $t {
+                *self
+            }
+
+            #[inline(always)]
+            unsafe fn extract_unchecked(&self, _: usize) -> $t {
+                *self
+            }
+
+            #[inline(always)]
+            fn replace(&mut self, _: usize, val: $t) {
+                *self = val
+            }
+
+            #[inline(always)]
+            unsafe fn replace_unchecked(&mut self, _: usize, val: $t) {
+                *self = val
+            }
+
+            #[inline(always)]
+            fn select(self, cond: bool, other: $t) -> $t {
+                if cond {
+                    self
+                } else {
+                    other
+                }
+            }
+        }
+    )*};
+}
+
+impl_simd_value!(Double Quad);
+
+// Implements `SubsetOf<$sup> for $sub` with conversion functions for each direction.
+macro_rules! impl_subset {
+    ($($sub:ty as $sup:ty: $to:expr, $from:expr;)*) => {$(
+        impl SubsetOf<$sup> for $sub {
+            #[inline]
+            fn to_superset(&self) -> $sup {
+                $to(*self)
+            }
+
+            #[inline]
+            fn from_superset_unchecked(element: &$sup) -> $sub {
+                $from(*element)
+            }
+
+            #[inline]
+            fn is_in_subset(_: &$sup) -> bool {
+                true
+            }
+        }
+    )*};
+}
+
+fn identity<T>(x: T) -> T {
+    x
+}
+
+fn double_from_f64(x: f64) -> Double {
+    Double::new(x, 0.0)
+}
+
+fn quad_from_f64(x: f64) -> Quad {
+    Quad::new(x, 0.0, 0.0, 0.0)
+}
+
+fn quad_from_double(x: Double) -> Quad {
+    Quad::new(x.hi(), x.lo(), 0.0, 0.0)
+}
+
+// Adding the third component rounds the first two. The fourth is too small to change that
+// except in an exact tie.
+fn double_from_quad(x: Quad) -> Double {
+    Double::new(x.c0(), x.c1()) + Double::new(x.c2(), 0.0)
+}
+
+impl_subset! {
+    Double as Double: identity, identity;
+    Quad as Quad: identity, identity;
+
+    f32 as Double: |x: f32| double_from_f64(x.into()), |x: Double| f64::from(x) as f32;
+    f64 as Double: double_from_f64, f64::from;
+    Double as f32: |x: Double| f64::from(x) as f32, |x: f32| double_from_f64(x.into());
+    Double as f64: f64::from, double_from_f64;
+
+    f32 as Quad: |x: f32| quad_from_f64(x.into()), |x: Quad| f64::from(x) as f32;
+    f64 as Quad: quad_from_f64, f64::from;
+    Quad as f32: |x: Quad| f64::from(x) as f32, |x: f32| quad_from_f64(x.into());
+    Quad as f64: f64::from, quad_from_f64;
+
+    Double as Quad: quad_from_double, double_from_quad;
+    Quad as Double: double_from_quad, quad_from_double;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+    use simba::scalar::SupersetOf;
+
+    #[test]
+    fn simd_value() {
+        assert_eq!(Double::LANES, 1);
+        assert_eq!(<Quad as SimdValue>::splat(Quad::PI), Quad::PI);
+
+        let mut x = dd!(1);
+        x.replace(0, Double::E);
+        assert_eq!(x.extract(0), Double::E);
+        assert_eq!(x.select(false, Double::PI), Double::PI);
+        assert_eq!(qd!(2).map_lanes(|x| x * x), qd!(4));
+    }
+
+    #[test]
+    fn f64_subset() {
+        // Converting from `f64` is exact
+        let x: Double = 0.1f64.to_superset();
+        assert_eq!(x, Double::new(0.1, 0.0));
+        let y = <Quad as SupersetOf<f64>>::from_subset(&0.1);
+        assert_eq!(y, Quad::new(0.1, 0.0, 0.0, 0.0));
+
+        // Converting to `f64` rounds
+        assert_eq!(
+            <Double as SupersetOf<f64>>::to_subset(&Double::PI),
+            Some(std::f64::consts::PI)
+        );
+        assert_eq!(f64::from_superset(&Quad::E), Some(std::f64::consts::E));
+        assert_eq!(f32::from_superset(&Double::PI), Some(std::f32::consts::PI));
+    }
+
+    #[test]
+    fn double_quad_subset() {
+        let x: Quad = Double::PI.to_superset();
+        assert_eq!(x, Quad::new(Double::PI.hi(), Double::PI.lo(), 0.0, 0.0));
+        assert_eq!(Double::from_superset(&Quad::PI), Some(Double::PI));
+        assert_eq!(
+            Quad::from_superset(&Double::E).unwrap().c1(),
+            Double::E.lo()
+        );
+        assert_eq!(Double::from_superset(&Double::LN_2), Some(Double::LN_2));
+    }
+}
@@ -0,0 +1,2291 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Interval arithmetic with [`Double`] and [`Quad`] endpoints.
+//!
+//! An interval stands for an unknown real number that's somewhere between its endpoints.
+//! Calculating with [`DoubleInterval`]s and [`QuadInterval`]s instead of plain numbers
+//! produces a result that's guaranteed to contain the exact answer, accounting both for the
+//! uncertainty of the inputs and for every rounding error made along the way, and the
+//! width of the result shows how much precision is left.
+//!
+//! The floating-point hardware only rounds to nearest when it works with double-doubles
+//! and quad-doubles, so the endpoints are rounded outward by widening each rounded result
+//! by a bound on its error. For the basic arithmetic operations, that's a few ulps of the
+//! result, comfortably more than the known error bounds of the algorithms. The square root
+//! and the transcendental functions have no such proofs, so their results are widened by
+//! several times the largest error that has been measured for them, taking into account
+//! how much each function amplifies the rounding error of its argument. Their enclosures
+//! are therefore only as reliable as those measurements, though in practice they're safe by
+//! a wide margin.
+//!
+//! # Examples
+//! ```
+//! use qd::interval::DoubleInterval;
+//! use qd::{dd, Double};
+//!
+//! // sin²(x) + cos²(x) = 1, evaluated over a whole interval of x
+//! let x = DoubleInterval::new(dd!(0.5), dd!(0.5).next_up());
+//! let one = x.sin().sqr() + x.cos().sqr();
+//! assert!(one.contains(dd!(1)));
+//! assert!(one.width() < dd!(1e-29));
+//!
+//! // A decimal constant that isn't exactly representable is enclosed first
+//! let tenth = DoubleInterval::enclose(dd!("0.1"));
+//! let sum = (0..10).fold(DoubleInterval::ZERO, |acc, _| acc + tenth);
+//! assert!(sum.contains(dd!(1)));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`DoubleInterval`]: crate::interval::DoubleInterval
+//! [`QuadInterval`]: crate::interval::QuadInterval
+
+use crate::double::Double;
+use crate::quad::Quad;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A closed interval of real numbers whose endpoints are [`Double`]s.
+///
+/// The result of every operation on a `DoubleInterval` contains the exact result of the
+/// operation applied to every real number in the operands. See the [module documentation]
+/// for how this is guaranteed and for its limits.
+///
+/// Endpoints can be infinite, in which case the interval is unbounded on that side. The
+/// [`EMPTY`] interval contains no numbers at all, and it's what operations on other empty
+/// intervals and operations entirely outside of a function's domain return.
+///
+/// # Examples
+/// ```
+/// use qd::interval::DoubleInterval;
+/// use qd::{dd, Double};
+///
+/// let x = DoubleInterval::new(dd!(-1), dd!(2));
+/// let sq = x.sqr();
+/// assert!(sq.lo() == dd!(0) && sq.contains(dd!(4)));
+///
+/// // Multiplication can't know that both operands are the same number
+/// let prod = x * x;
+/// assert!(prod.contains(dd!(-2)) && prod.contains(dd!(4)));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [module documentation]: crate::interval
+/// [`EMPTY`]: #associatedconstant.EMPTY
+#[derive(Clone, Copy, Debug)]
+pub struct DoubleInterval {
+    lo: Double,
+    hi: Double,
+}
+
+impl DoubleInterval {
+    /// The interval that contains no numbers.
+    pub const EMPTY: DoubleInterval = DoubleInterval {
+        lo: Double::NAN,
+        hi: Double::NAN,
+    };
+
+    /// The interval that contains every number, [-∞, ∞].
+    pub const ENTIRE: DoubleInterval = DoubleInterval {
+        lo: Double::NEG_INFINITY,
+        hi: Double::INFINITY,
+    };
+
+    /// The interval that contains only zero, [0, 0].
+    pub const ZERO: DoubleInterval = DoubleInterval {
+        lo: Double::ZERO,
+        hi: Double::ZERO,
+    };
+
+    /// The interval that contains only one, [1, 1].
+    pub const ONE: DoubleInterval = DoubleInterval {
+        lo: Double::ONE,
+        hi: Double::ONE,
+    };
+
+    /// Creates a new `DoubleInterval` with the given endpoints.
+    ///
+    /// If `lo` is greater than `hi` or either of them is `NaN`, the result is [`EMPTY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// let x = DoubleInterval::new(dd!(1), dd!(2));
+    /// assert!(x.lo() == dd!(1));
+    /// assert!(x.hi() == dd!(2));
+    ///
+    /// assert!(DoubleInterval::new(dd!(2), dd!(1)).is_empty());
+    /// ```
+    ///
+    /// [`EMPTY`]: #associatedconstant.EMPTY
+    pub fn new(lo: Double, hi: Double) -> DoubleInterval {
+        if lo <= hi {
+            DoubleInterval { lo, hi }
+        } else {
+            DoubleInterval::EMPTY
+        }
+    }
+
+    /// Creates a new `DoubleInterval` that contains only the given number.
+    ///
+    /// This is the interval for a number that is exactly represented by the `Double`. For
+    /// a `Double` that only approximates the number that's meant, like a constant or a
+    /// parsed decimal, use [`enclose`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// let x = DoubleInterval::point(dd!(0.5));
+    /// assert!(x == DoubleInterval::new(dd!(0.5), dd!(0.5)));
+    /// ```
+    ///
+    /// [`enclose`]: #method.enclose
+    #[inline]
+    pub fn point(x: Double) -> DoubleInterval {
+        DoubleInterval::new(x, x)
+    }
+
+    /// Creates a new `DoubleInterval` that contains every number within one
+    /// [`precision_ulp`] of the given number.
+    ///
+    /// The constants like [`PI`] are the nearest `Double`s to their exact values, and
+    /// parsing a decimal produces a `Double` within an ulp of it, so this creates
+    /// intervals that are guaranteed to contain those exact values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::{dd, Double};
+    /// let pi = DoubleInterval::enclose(Double::PI);
+    /// assert!(pi.lo() < Double::PI && pi.hi() > Double::PI);
+    ///
+    /// let tenth = DoubleInterval::enclose(dd!("0.1"));
+    /// assert!(tenth.contains(dd!("0.1")));
+    /// ```
+    ///
+    /// [`precision_ulp`]: crate::Double::precision_ulp
+    /// [`PI`]: crate::Double::PI
+    #[inline]
+    pub fn enclose(x: Double) -> DoubleInterval {
+        let ulp = x.precision_ulp();
+        DoubleInterval::new(DoubleInterval::down(x, ulp), DoubleInterval::up(x, ulp))
+    }
+
+    /// Returns the lower endpoint of the `DoubleInterval`. This is `NaN` if the interval
+    /// is empty.
+    #[inline]
+    pub fn lo(self) -> Double {
+        self.lo
+    }
+
+    /// Returns the upper endpoint of the `DoubleInterval`. This is `NaN` if the interval
+    /// is empty.
+    #[inline]
+    pub fn hi(self) -> Double {
+        self.hi
+    }
+
+    /// Determines whether the `DoubleInterval` is empty.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.lo.is_nan()
+    }
+
+    /// Determines whether the `DoubleInterval` contains the given number.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// let x = DoubleInterval::new(dd!(1), dd!(2));
+    /// assert!(x.contains(dd!(1)));
+    /// assert!(!x.contains(dd!(3)));
+    /// ```
+    #[inline]
+    pub fn contains(self, x: Double) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Determines whether every number in the `DoubleInterval` is also in `other`.
+    ///
+    /// The empty interval is a subset of every interval.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// let x = DoubleInterval::new(dd!(1), dd!(2));
+    /// assert!(x.is_subset(DoubleInterval::new(dd!(0), dd!(2))));
+    /// assert!(!x.is_subset(DoubleInterval::new(dd!(1.5), dd!(3))));
+    /// ```
+    pub fn is_subset(self, other: DoubleInterval) -> bool {
+        self.is_empty() || (other.lo <= self.lo && self.hi <= other.hi)
+    }
+
+    /// Calculates the midpoint of the `DoubleInterval`.
+    ///
+    /// This is rounded to the nearest `Double`, so it isn't necessarily exact. The
+    /// midpoint of [`ENTIRE`] is zero, and the midpoint of an interval with only one
+    /// infinite endpoint is the finite `Double` closest to that endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// assert!(DoubleInterval::new(dd!(1), dd!(2)).mid() == dd!(1.5));
+    /// ```
+    ///
+    /// [`ENTIRE`]: #associatedconstant.ENTIRE
+    pub fn mid(self) -> Double {
+        match (self.lo.is_infinite(), self.hi.is_infinite()) {
+            (true, true) => Double::ZERO,
+            (true, false) => Double::MIN,
+            (false, true) => Double::MAX,
+            // Halving first so that the sum can't overflow
+            (false, false) => self.lo.mul_pwr2(0.5) + self.hi.mul_pwr2(0.5),
+        }
+    }
+
+    /// Calculates the width of the `DoubleInterval`, rounded up.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// assert!(DoubleInterval::new(dd!(-1), dd!(2)).width() >= dd!(3));
+    /// ```
+    pub fn width(self) -> Double {
+        let w = self.hi.add_accurate(-self.lo);
+        DoubleInterval::up(w, DoubleInterval::ARITH * w.precision_ulp())
+    }
+
+    /// Calculates the intersection of the `DoubleInterval` and another, the interval of
+    /// numbers that are in both. This is [`EMPTY`] if they don't overlap.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// let x = DoubleInterval::new(dd!(0), dd!(2));
+    /// let y = DoubleInterval::new(dd!(1), dd!(3));
+    /// assert!(x.intersection(y) == DoubleInterval::new(dd!(1), dd!(2)));
+    /// ```
+    ///
+    /// [`EMPTY`]: #associatedconstant.EMPTY
+    pub fn intersection(self, other: DoubleInterval) -> DoubleInterval {
+        DoubleInterval::new(self.lo.max(other.lo), self.hi.min(other.hi))
+    }
+
+    /// Calculates the hull of the `DoubleInterval` and another, the smallest interval that
+    /// contains both.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// let x = DoubleInterval::new(dd!(0), dd!(1));
+    /// let y = DoubleInterval::new(dd!(2), dd!(3));
+    /// assert!(x.hull(y) == DoubleInterval::new(dd!(0), dd!(3)));
+    /// ```
+    pub fn hull(self, other: DoubleInterval) -> DoubleInterval {
+        if self.is_empty() {
+            other
+        } else if other.is_empty() {
+            self
+        } else {
+            DoubleInterval::new(self.lo.min(other.lo), self.hi.max(other.hi))
+        }
+    }
+
+    /// Calculates the absolute value of the `DoubleInterval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// let x = DoubleInterval::new(dd!(-3), dd!(2)).abs();
+    /// assert!(x == DoubleInterval::new(dd!(0), dd!(3)));
+    /// ```
+    pub fn abs(self) -> DoubleInterval {
+        if self.lo >= Double::ZERO {
+            self
+        } else if self.hi <= Double::ZERO {
+            -self
+        } else {
+            DoubleInterval::new(Double::ZERO, (-self.lo).max(self.hi))
+        }
+    }
+
+    /// Calculates the square of the `DoubleInterval`.
+    ///
+    /// This is narrower than multiplying the interval by itself when it contains zero,
+    /// because the square of a number is never negative.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// let x = DoubleInterval::new(dd!(-3), dd!(2)).sqr();
+    /// assert!(x.lo() == dd!(0) && x.contains(dd!(9)));
+    /// ```
+    pub fn sqr(self) -> DoubleInterval {
+        let a = self.abs();
+        DoubleInterval::new(
+            DoubleInterval::mul_down(a.lo, a.lo),
+            DoubleInterval::mul_up(a.hi, a.hi),
+        )
+    }
+
+    /// Calculates the square root of the `DoubleInterval`.
+    ///
+    /// Only the non-negative part of the interval is in the domain of the square root, so
+    /// negative numbers are ignored, and the result is [`EMPTY`] if there are only
+    /// negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::{dd, Double};
+    /// let x = DoubleInterval::new(dd!(-1), dd!(2)).sqrt();
+    /// assert!(x.lo() == dd!(0));
+    /// assert!(x.contains(Double::SQRT_2));
+    /// ```
+    ///
+    /// [`EMPTY`]: #associatedconstant.EMPTY
+    pub fn sqrt(self) -> DoubleInterval {
+        if self.is_empty() || self.hi < Double::ZERO {
+            return DoubleInterval::EMPTY;
+        }
+        let r = |x: Double| {
+            let r = x.sqrt();
+            (r, DoubleInterval::FUNC * r.precision_ulp())
+        };
+        let (lo, e) = r(self.lo.max(Double::ZERO));
+        let (hi, f) = r(self.hi);
+        DoubleInterval::new(
+            DoubleInterval::down(lo, e).max(Double::ZERO),
+            DoubleInterval::up(hi, f),
+        )
+    }
+
+    /// Calculates *e* raised to the power of the `DoubleInterval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::{dd, Double};
+    /// let x = DoubleInterval::new(dd!(0), dd!(1)).exp();
+    /// assert!(x.contains(dd!(1)) && x.contains(Double::E));
+    /// ```
+    pub fn exp(self) -> DoubleInterval {
+        if self.is_empty() {
+            return DoubleInterval::EMPTY;
+        }
+        // The error grows with the argument, whose rounding error is amplified by the
+        // argument reduction
+        let r = |x: Double| {
+            let r = x.exp();
+            (
+                r,
+                DoubleInterval::FUNC * r.precision_ulp() * (Double::ONE + x.abs()),
+            )
+        };
+        let (lo, e) = r(self.lo);
+        let (hi, f) = r(self.hi);
+        DoubleInterval::new(
+            DoubleInterval::down(lo, e).max(Double::ZERO),
+            DoubleInterval::up(hi, f),
+        )
+    }
+
+    /// Calculates the natural logarithm of the `DoubleInterval`.
+    ///
+    /// Only the positive part of the interval is in the domain of the logarithm, so
+    /// other numbers are ignored. If the interval contains zero, the result has a lower
+    /// endpoint of -∞, and if it contains no positive numbers, it's [`EMPTY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::{dd, Double};
+    /// let x = DoubleInterval::new(dd!(1), dd!(2)).ln();
+    /// assert!(x.contains(dd!(0)) && x.contains(Double::LN_2));
+    ///
+    /// let y = DoubleInterval::new(dd!(-1), dd!(1)).ln();
+    /// assert!(y.lo() == Double::NEG_INFINITY);
+    /// ```
+    ///
+    /// [`EMPTY`]: #associatedconstant.EMPTY
+    pub fn ln(self) -> DoubleInterval {
+        if self.is_empty() || self.hi <= Double::ZERO {
+            return DoubleInterval::EMPTY;
+        }
+        // The argument's relative rounding error becomes an absolute error in the result
+        let r = |x: Double| {
+            let r = x.ln();
+            (
+                r,
+                DoubleInterval::FUNC * (r.precision_ulp() + Double::ONE.precision_ulp()),
+            )
+        };
+        let lo = if self.lo <= Double::ZERO {
+            Double::NEG_INFINITY
+        } else {
+            let (lo, e) = r(self.lo);
+            DoubleInterval::down(lo, e)
+        };
+        let (hi, f) = r(self.hi);
+        DoubleInterval::new(lo, DoubleInterval::up(hi, f))
+    }
+
+    /// Calculates the sine of the `DoubleInterval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// // The interval contains π/2, where the sine is at its maximum
+    /// let x = DoubleInterval::new(dd!(1), dd!(2)).sin();
+    /// assert!(x.hi() == dd!(1));
+    /// assert!(x.contains(dd!(1).sin()));
+    /// ```
+    pub fn sin(self) -> DoubleInterval {
+        // The sine has its maxima at π/2 + 2kπ and its minima at 3π/2 + 2kπ
+        self.periodic(|x| x.sin(), 1, 3)
+    }
+
+    /// Calculates the cosine of the `DoubleInterval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// // The interval contains π, where the cosine is at its minimum
+    /// let x = DoubleInterval::new(dd!(3), dd!(4)).cos();
+    /// assert!(x.lo() == dd!(-1));
+    /// assert!(x.contains(dd!(4).cos()));
+    /// ```
+    pub fn cos(self) -> DoubleInterval {
+        // The cosine has its maxima at 2kπ and its minima at π + 2kπ
+        self.periodic(|x| x.cos(), 0, 2)
+    }
+
+    /// Calculates the tangent of the `DoubleInterval`.
+    ///
+    /// If the interval contains a pole of the tangent (π/2 plus a multiple of π), the
+    /// result is [`ENTIRE`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::{dd, Double};
+    /// let x = DoubleInterval::new(dd!(0), dd!(1)).tan();
+    /// assert!(x.contains(dd!(0)) && x.contains(dd!(1).tan()));
+    ///
+    /// let y = DoubleInterval::new(dd!(1), dd!(2)).tan();
+    /// assert!(y == DoubleInterval::ENTIRE);
+    /// ```
+    ///
+    /// [`ENTIRE`]: #associatedconstant.ENTIRE
+    pub fn tan(self) -> DoubleInterval {
+        if self.is_empty() {
+            return DoubleInterval::EMPTY;
+        }
+        if !self.lo.is_finite() || !self.hi.is_finite() || self.quarter_turns(1, 2) {
+            return DoubleInterval::ENTIRE;
+        }
+        // Near the poles the argument's rounding error is amplified by the derivative,
+        // 1 + tan²(x)
+        let r = |x: Double| {
+            let r = x.tan();
+            (
+                r,
+                DoubleInterval::FUNC
+                    * (r.precision_ulp() + x.precision_ulp() * (Double::ONE + r.sqr())),
+            )
+        };
+        let (lo, e) = r(self.lo);
+        let (hi, f) = r(self.hi);
+        DoubleInterval::new(DoubleInterval::down(lo, e), DoubleInterval::up(hi, f))
+    }
+
+    /// Calculates the arctangent of the `DoubleInterval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::{dd, Double};
+    /// let x = DoubleInterval::new(dd!(0), dd!(1)).atan();
+    /// assert!(x.contains(dd!(0)) && x.contains(Double::FRAC_PI_4));
+    /// ```
+    pub fn atan(self) -> DoubleInterval {
+        if self.is_empty() {
+            return DoubleInterval::EMPTY;
+        }
+        let r = |x: Double| {
+            let r = x.atan();
+            (r, DoubleInterval::FUNC * r.precision_ulp())
+        };
+        let (lo, e) = r(self.lo);
+        let (hi, f) = r(self.hi);
+        DoubleInterval::new(DoubleInterval::down(lo, e), DoubleInterval::up(hi, f))
+    }
+}
+
+impl DoubleInterval {
+    // The bound on the error of the basic arithmetic operations, in ulps of the result.
+    const ARITH: Double = Double::new(4.0, 0.0);
+
+    // The bound on the error of the square root and the transcendental functions, in ulps
+    // of the result and of the argument as described by each function's error model. These
+    // are several times the largest errors that have been measured.
+    const FUNC: Double = Double::new(16.0, 0.0);
+
+    // Rounds `x`, which is within `err` of an exact result, down to a lower bound for it.
+    // An infinite `x` is exact unless it's the result of overflow.
+    fn down(x: Double, err: Double) -> Double {
+        if x == Double::INFINITY {
+            Double::MAX
+        } else if x.is_finite() {
+            x.add_accurate(-err)
+        } else {
+            x
+        }
+    }
+
+    // Rounds `x`, which is within `err` of an exact result, up to an upper bound for it.
+    fn up(x: Double, err: Double) -> Double {
+        -DoubleInterval::down(-x, err)
+    }
+
+    // `Double` arithmetic returns `NaN` when the result overflows. Given the sign of the
+    // exact result, this turns that back into the infinity that it rounds to.
+    fn overflow(r: Double, a: Double, b: Double, negative: bool) -> Double {
+        if r.is_nan() && !a.is_nan() && !b.is_nan() {
+            if negative {
+                Double::NEG_INFINITY
+            } else {
+                Double::INFINITY
+            }
+        } else {
+            r
+        }
+    }
+
+    // A sum or difference is only zero when it's exact, so zeros aren't widened.
+    fn add_down(a: Double, b: Double) -> Double {
+        let r = DoubleInterval::overflow(a.add_accurate(b), a, b, a.is_sign_negative());
+        if r.is_zero() {
+            r
+        } else {
+            DoubleInterval::down(r, DoubleInterval::ARITH * r.precision_ulp())
+        }
+    }
+
+    fn add_up(a: Double, b: Double) -> Double {
+        -DoubleInterval::add_down(-a, -b)
+    }
+
+    // A product with a zero factor is exactly zero, even if the other factor is infinite.
+    fn mul_down(a: Double, b: Double) -> Double {
+        if a.is_zero() || b.is_zero() {
+            Double::ZERO
+        } else {
+            let r = DoubleInterval::overflow(
+                a.mul_accurate(b),
+                a,
+                b,
+                a.is_sign_negative() != b.is_sign_negative(),
+            );
+            DoubleInterval::down(r, DoubleInterval::ARITH * r.precision_ulp())
+        }
+    }
+
+    fn mul_up(a: Double, b: Double) -> Double {
+        -DoubleInterval::mul_down(-a, b)
+    }
+
+    fn div_down(a: Double, b: Double) -> Double {
+        if a.is_zero() {
+            Double::ZERO
+        } else {
+            let r = DoubleInterval::overflow(
+                a.div_accurate(b),
+                a,
+                b,
+                a.is_sign_negative() != b.is_sign_negative(),
+            );
+            DoubleInterval::down(r, DoubleInterval::ARITH * r.precision_ulp())
+        }
+    }
+
+    fn div_up(a: Double, b: Double) -> Double {
+        -DoubleInterval::div_down(-a, b)
+    }
+
+    // Determines whether the interval might contain a number (r + k · period) · π/2 for
+    // some integer k, where `r` and `period` are numbers of quarter turns. This errs on the
+    // side of finding one, so a point near an endpoint is treated as though it's inside.
+    fn quarter_turns(self, r: i32, period: i32) -> bool {
+        let q = |x: Double| x / Double::FRAC_PI_2;
+        let (a, b) = (q(self.lo), q(self.hi));
+        let slack = |x: Double| (Double::ONE + x.abs()) * Double::EPSILON * Double::new(256.0, 0.0);
+        let r = Double::from(r);
+        let p = Double::from(period);
+        ((a - slack(a) - r) / p).ceil() <= ((b + slack(b) - r) / p).floor()
+    }
+
+    // Calculates a periodic function with a range of [-1, 1] over an interval, where the
+    // function has its maxima at `max` quarter turns and its minima at `min` quarter turns.
+    fn periodic(self, f: impl Fn(Double) -> Double, max: i32, min: i32) -> DoubleInterval {
+        if self.is_empty() {
+            return DoubleInterval::EMPTY;
+        }
+        let full = DoubleInterval::new(-Double::ONE, Double::ONE);
+        if !self.lo.is_finite() || !self.hi.is_finite() || self.hi - self.lo > Double::TAU {
+            return full;
+        }
+        // The rounding error of the argument reduction grows with the argument
+        let r = |x: Double| {
+            let r = f(x);
+            (
+                r,
+                DoubleInterval::FUNC * (r.precision_ulp() + x.precision_ulp()),
+            )
+        };
+        let (a, ea) = r(self.lo);
+        let (b, eb) = r(self.hi);
+        let lo = if self.quarter_turns(min, 4) {
+            -Double::ONE
+        } else {
+            DoubleInterval::down(a, ea)
+                .min(DoubleInterval::down(b, eb))
+                .max(-Double::ONE)
+        };
+        let hi = if self.quarter_turns(max, 4) {
+            Double::ONE
+        } else {
+            DoubleInterval::up(a, ea)
+                .max(DoubleInterval::up(b, eb))
+                .min(Double::ONE)
+        };
+        full.intersection(DoubleInterval::new(lo, hi))
+    }
+}
+
+impl PartialEq for DoubleInterval {
+    /// Two `DoubleInterval`s are equal if they have the same endpoints or if they're both
+    /// empty.
+    fn eq(&self, other: &DoubleInterval) -> bool {
+        (self.is_empty() && other.is_empty()) || (self.lo == other.lo && self.hi == other.hi)
+    }
+}
+
+impl From<Double> for DoubleInterval {
+    /// Converts a `Double` into a `DoubleInterval` that contains only that number. This is
+    /// the same as [`point`].
+    ///
+    /// [`point`]: #method.point
+    #[inline]
+    fn from(x: Double) -> DoubleInterval {
+        DoubleInterval::point(x)
+    }
+}
+
+impl Display for DoubleInterval {
+    /// Formats the `DoubleInterval` as [*lo*, *hi*]. Any precision given in the format
+    /// string is applied to both endpoints.
+    ///
+    /// The endpoints are rounded to the nearest decimal like any other `Double`, so the
+    /// displayed interval might not contain every number that the `DoubleInterval` does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::DoubleInterval;
+    /// # use qd::dd;
+    /// let x = DoubleInterval::new(dd!(1), dd!(2.5));
+    /// assert!(format!("{}", x) == "[1, 2.5]");
+    /// assert!(format!("{:.2}", x) == "[1.00, 2.50]");
+    /// assert!(format!("{}", DoubleInterval::EMPTY) == "[]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_empty() {
+            write!(f, "[]")
+        } else {
+            match f.precision() {
+                Some(p) => write!(f, "[{:.*}, {:.*}]", p, self.lo, p, self.hi),
+                None => write!(f, "[{}, {}]", self.lo, self.hi),
+            }
+        }
+    }
+}
+
+impl Add for DoubleInterval {
+    type Output = DoubleInterval;
+
+    fn add(self, other: DoubleInterval) -> DoubleInterval {
+        DoubleInterval::new(
+            DoubleInterval::add_down(self.lo, other.lo),
+            DoubleInterval::add_up(self.hi, other.hi),
+        )
+    }
+}
+
+impl Add<Double> for DoubleInterval {
+    type Output = DoubleInterval;
+
+    #[inline]
+    fn add(self, other: Double) -> DoubleInterval {
+        self + DoubleInterval::point(other)
+    }
+}
+
+impl Add<DoubleInterval> for Double {
+    type Output = DoubleInterval;
+
+    #[inline]
+    fn add(self, other: DoubleInterval) -> DoubleInterval {
+        DoubleInterval::point(self) + other
+    }
+}
+
+impl Sub for DoubleInterval {
+    type Output = DoubleInterval;
+
+    fn sub(self, other: DoubleInterval) -> DoubleInterval {
+        DoubleInterval::new(
+            DoubleInterval::add_down(self.lo, -other.hi),
+            DoubleInterval::add_up(self.hi, -other.lo),
+        )
+    }
+}
+
+impl Sub<Double> for DoubleInterval {
+    type Output = DoubleInterval;
+
+    #[inline]
+    fn sub(self, other: Double) -> DoubleInterval {
+        self - DoubleInterval::point(other)
+    }
+}
+
+impl Sub<DoubleInterval> for Double {
+    type Output = DoubleInterval;
+
+    #[inline]
+    fn sub(self, other: DoubleInterval) -> DoubleInterval {
+        DoubleInterval::point(self) - other
+    }
+}
+
+impl Mul for DoubleInterval {
+    type Output = DoubleInterval;
+
+    fn mul(self, other: DoubleInterval) -> DoubleInterval {
+        if self.is_empty() || other.is_empty() {
+            return DoubleInterval::EMPTY;
+        }
+        let ends = [
+            (self.lo, other.lo),
+            (self.lo, other.hi),
+            (self.hi, other.lo),
+            (self.hi, other.hi),
+        ];
+        let lo = ends
+            .iter()
+            .map(|&(a, b)| DoubleInterval::mul_down(a, b))
+            .fold(Double::INFINITY, Double::min);
+        let hi = ends
+            .iter()
+            .map(|&(a, b)| DoubleInterval::mul_up(a, b))
+            .fold(Double::NEG_INFINITY, Double::max);
+        DoubleInterval::new(lo, hi)
+    }
+}
+
+impl Mul<Double> for DoubleInterval {
+    type Output = DoubleInterval;
+
+    #[inline]
+    fn mul(self, other: Double) -> DoubleInterval {
+        self * DoubleInterval::point(other)
+    }
+}
+
+impl Mul<DoubleInterval> for Double {
+    type Output = DoubleInterval;
+
+    #[inline]
+    fn mul(self, other: DoubleInterval) -> DoubleInterval {
+        DoubleInterval::point(self) * other
+    }
+}
+
+impl Div for DoubleInterval {
+    type Output = DoubleInterval;
+
+    /// Divides one `DoubleInterval` by another.
+    ///
+    /// If the divisor contains zero, the result is [`ENTIRE`], unless the divisor is
+    /// exactly zero, in which case there is nothing to divide by and the result is
+    /// [`EMPTY`].
+    ///
+    /// [`ENTIRE`]: #associatedconstant.ENTIRE
+    /// [`EMPTY`]: #associatedconstant.EMPTY
+    fn div(self, other: DoubleInterval) -> DoubleInterval {
+        if self.is_empty() || other.is_empty() || other == DoubleInterval::ZERO {
+            return DoubleInterval::EMPTY;
+        }
+        if other.contains(Double::ZERO) {
+            return DoubleInterval::ENTIRE;
+        }
+        let ends = [
+            (self.lo, other.lo),
+            (self.lo, other.hi),
+            (self.hi, other.lo),
+            (self.hi, other.hi),
+        ];
+        // An infinite numerator over an infinite denominator is taken to be an infinity.
+        // The quotient is unbounded in that direction anyway, so it's never too narrow.
+        let lo = ends
+            .iter()
+            .map(|&(a, b)| DoubleInterval::div_down(a, b))
+            .fold(Double::INFINITY, Double::min);
+        let hi = ends
+            .iter()
+            .map(|&(a, b)| DoubleInterval::div_up(a, b))
+            .fold(Double::NEG_INFINITY, Double::max);
+        DoubleInterval::new(lo, hi)
+    }
+}
+
+impl Div<Double> for DoubleInterval {
+    type Output = DoubleInterval;
+
+    #[inline]
+    fn div(self, other: Double) -> DoubleInterval {
+        self / DoubleInterval::point(other)
+    }
+}
+
+impl Div<DoubleInterval> for Double {
+    type Output = DoubleInterval;
+
+    #[inline]
+    fn div(self, other: DoubleInterval) -> DoubleInterval {
+        DoubleInterval::point(self) / other
+    }
+}
+
+impl Neg for DoubleInterval {
+    type Output = DoubleInterval;
+
+    #[inline]
+    fn neg(self) -> DoubleInterval {
+        DoubleInterval::new(-self.hi, -self.lo)
+    }
+}
+
+impl AddAssign for DoubleInterval {
+    #[inline]
+    fn add_assign(&mut self, other: DoubleInterval) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Double> for DoubleInterval {
+    #[inline]
+    fn add_assign(&mut self, other: Double) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for DoubleInterval {
+    #[inline]
+    fn sub_assign(&mut self, other: DoubleInterval) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<Double> for DoubleInterval {
+    #[inline]
+    fn sub_assign(&mut self, other: Double) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for DoubleInterval {
+    #[inline]
+    fn mul_assign(&mut self, other: DoubleInterval) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<Double> for DoubleInterval {
+    #[inline]
+    fn mul_assign(&mut self, other: Double) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for DoubleInterval {
+    #[inline]
+    fn div_assign(&mut self, other: DoubleInterval) {
+        *self = *self / other;
+    }
+}
+
+impl DivAssign<Double> for DoubleInterval {
+    #[inline]
+    fn div_assign(&mut self, other: Double) {
+        *self = *self / other;
+    }
+}
+
+/// A closed interval of real numbers whose endpoints are [`Quad`]s.
+///
+/// The result of every operation on a `QuadInterval` contains the exact result of the
+/// operation applied to every real number in the operands. See the [module documentation]
+/// for how this is guaranteed and for its limits.
+///
+/// Endpoints can be infinite, in which case the interval is unbounded on that side. The
+/// [`EMPTY`] interval contains no numbers at all, and it's what operations on other empty
+/// intervals and operations entirely outside of a function's domain return.
+///
+/// # Examples
+/// ```
+/// use qd::interval::QuadInterval;
+/// use qd::{qd, Quad};
+///
+/// let x = QuadInterval::new(qd!(-1), qd!(2));
+/// let sq = x.sqr();
+/// assert!(sq.lo() == qd!(0) && sq.contains(qd!(4)));
+///
+/// // Multiplication can't know that both operands are the same number
+/// let prod = x * x;
+/// assert!(prod.contains(qd!(-2)) && prod.contains(qd!(4)));
+/// ```
+///
+/// [`Quad`]: crate::Quad
+/// [module documentation]: crate::interval
+/// [`EMPTY`]: #associatedconstant.EMPTY
+#[derive(Clone, Copy, Debug)]
+pub struct QuadInterval {
+    lo: Quad,
+    hi: Quad,
+}
+
+impl QuadInterval {
+    /// The interval that contains no numbers.
+    pub const EMPTY: QuadInterval = QuadInterval {
+        lo: Quad::NAN,
+        hi: Quad::NAN,
+    };
+
+    /// The interval that contains every number, [-∞, ∞].
+    pub const ENTIRE: QuadInterval = QuadInterval {
+        lo: Quad::NEG_INFINITY,
+        hi: Quad::INFINITY,
+    };
+
+    /// The interval that contains only zero, [0, 0].
+    pub const ZERO: QuadInterval = QuadInterval {
+        lo: Quad::ZERO,
+        hi: Quad::ZERO,
+    };
+
+    /// The interval that contains only one, [1, 1].
+    pub const ONE: QuadInterval = QuadInterval {
+        lo: Quad::ONE,
+        hi: Quad::ONE,
+    };
+
+    /// Creates a new `QuadInterval` with the given endpoints.
+    ///
+    /// If `lo` is greater than `hi` or either of them is `NaN`, the result is [`EMPTY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// let x = QuadInterval::new(qd!(1), qd!(2));
+    /// assert!(x.lo() == qd!(1));
+    /// assert!(x.hi() == qd!(2));
+    ///
+    /// assert!(QuadInterval::new(qd!(2), qd!(1)).is_empty());
+    /// ```
+    ///
+    /// [`EMPTY`]: #associatedconstant.EMPTY
+    pub fn new(lo: Quad, hi: Quad) -> QuadInterval {
+        if lo <= hi {
+            QuadInterval { lo, hi }
+        } else {
+            QuadInterval::EMPTY
+        }
+    }
+
+    /// Creates a new `QuadInterval` that contains only the given number.
+    ///
+    /// This is the interval for a number that is exactly represented by the `Quad`. For
+    /// a `Quad` that only approximates the number that's meant, like a constant or a
+    /// parsed decimal, use [`enclose`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// let x = QuadInterval::point(qd!(0.5));
+    /// assert!(x == QuadInterval::new(qd!(0.5), qd!(0.5)));
+    /// ```
+    ///
+    /// [`enclose`]: #method.enclose
+    #[inline]
+    pub fn point(x: Quad) -> QuadInterval {
+        QuadInterval::new(x, x)
+    }
+
+    /// Creates a new `QuadInterval` that contains every number within one
+    /// [`precision_ulp`] of the given number.
+    ///
+    /// The constants like [`PI`] are the nearest `Quad`s to their exact values, and
+    /// parsing a decimal produces a `Quad` within an ulp of it, so this creates
+    /// intervals that are guaranteed to contain those exact values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::{qd, Quad};
+    /// let pi = QuadInterval::enclose(Quad::PI);
+    /// assert!(pi.lo() < Quad::PI && pi.hi() > Quad::PI);
+    ///
+    /// let tenth = QuadInterval::enclose(qd!("0.1"));
+    /// assert!(tenth.contains(qd!("0.1")));
+    /// ```
+    ///
+    /// [`precision_ulp`]: crate::Quad::precision_ulp
+    /// [`PI`]: crate::Quad::PI
+    #[inline]
+    pub fn enclose(x: Quad) -> QuadInterval {
+        let ulp = x.precision_ulp();
+        QuadInterval::new(QuadInterval::down(x, ulp), QuadInterval::up(x, ulp))
+    }
+
+    /// Returns the lower endpoint of the `QuadInterval`. This is `NaN` if the interval
+    /// is empty.
+    #[inline]
+    pub fn lo(self) -> Quad {
+        self.lo
+    }
+
+    /// Returns the upper endpoint of the `QuadInterval`. This is `NaN` if the interval
+    /// is empty.
+    #[inline]
+    pub fn hi(self) -> Quad {
+        self.hi
+    }
+
+    /// Determines whether the `QuadInterval` is empty.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.lo.is_nan()
+    }
+
+    /// Determines whether the `QuadInterval` contains the given number.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// let x = QuadInterval::new(qd!(1), qd!(2));
+    /// assert!(x.contains(qd!(1)));
+    /// assert!(!x.contains(qd!(3)));
+    /// ```
+    #[inline]
+    pub fn contains(self, x: Quad) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Determines whether every number in the `QuadInterval` is also in `other`.
+    ///
+    /// The empty interval is a subset of every interval.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// let x = QuadInterval::new(qd!(1), qd!(2));
+    /// assert!(x.is_subset(QuadInterval::new(qd!(0), qd!(2))));
+    /// assert!(!x.is_subset(QuadInterval::new(qd!(1.5), qd!(3))));
+    /// ```
+    pub fn is_subset(self, other: QuadInterval) -> bool {
+        self.is_empty() || (other.lo <= self.lo && self.hi <= other.hi)
+    }
+
+    /// Calculates the midpoint of the `QuadInterval`.
+    ///
+    /// This is rounded to the nearest `Quad`, so it isn't necessarily exact. The
+    /// midpoint of [`ENTIRE`] is zero, and the midpoint of an interval with only one
+    /// infinite endpoint is the finite `Quad` closest to that endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// assert!(QuadInterval::new(qd!(1), qd!(2)).mid() == qd!(1.5));
+    /// ```
+    ///
+    /// [`ENTIRE`]: #associatedconstant.ENTIRE
+    pub fn mid(self) -> Quad {
+        match (self.lo.is_infinite(), self.hi.is_infinite()) {
+            (true, true) => Quad::ZERO,
+            (true, false) => Quad::MIN,
+            (false, true) => Quad::MAX,
+            // Halving first so that the sum can't overflow
+            (false, false) => self.lo.mul_pwr2(0.5) + self.hi.mul_pwr2(0.5),
+        }
+    }
+
+    /// Calculates the width of the `QuadInterval`, rounded up.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// assert!(QuadInterval::new(qd!(-1), qd!(2)).width() >= qd!(3));
+    /// ```
+    pub fn width(self) -> Quad {
+        let w = self.hi.add_accurate(-self.lo);
+        QuadInterval::up(w, QuadInterval::ARITH * w.precision_ulp())
+    }
+
+    /// Calculates the intersection of the `QuadInterval` and another, the interval of
+    /// numbers that are in both. This is [`EMPTY`] if they don't overlap.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// let x = QuadInterval::new(qd!(0), qd!(2));
+    /// let y = QuadInterval::new(qd!(1), qd!(3));
+    /// assert!(x.intersection(y) == QuadInterval::new(qd!(1), qd!(2)));
+    /// ```
+    ///
+    /// [`EMPTY`]: #associatedconstant.EMPTY
+    pub fn intersection(self, other: QuadInterval) -> QuadInterval {
+        QuadInterval::new(self.lo.max(other.lo), self.hi.min(other.hi))
+    }
+
+    /// Calculates the hull of the `QuadInterval` and another, the smallest interval that
+    /// contains both.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// let x = QuadInterval::new(qd!(0), qd!(1));
+    /// let y = QuadInterval::new(qd!(2), qd!(3));
+    /// assert!(x.hull(y) == QuadInterval::new(qd!(0), qd!(3)));
+    /// ```
+    pub fn hull(self, other: QuadInterval) -> QuadInterval {
+        if self.is_empty() {
+            other
+        } else if other.is_empty() {
+            self
+        } else {
+            QuadInterval::new(self.lo.min(other.lo), self.hi.max(other.hi))
+        }
+    }
+
+    /// Calculates the absolute value of the `QuadInterval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// let x = QuadInterval::new(qd!(-3), qd!(2)).abs();
+    /// assert!(x == QuadInterval::new(qd!(0), qd!(3)));
+    /// ```
+    pub fn abs(self) -> QuadInterval {
+        if self.lo >= Quad::ZERO {
+            self
+        } else if self.hi <= Quad::ZERO {
+            -self
+        } else {
+            QuadInterval::new(Quad::ZERO, (-self.lo).max(self.hi))
+        }
+    }
+
+    /// Calculates the square of the `QuadInterval`.
+    ///
+    /// This is narrower than multiplying the interval by itself when it contains zero,
+    /// because the square of a number is never negative.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// let x = QuadInterval::new(qd!(-3), qd!(2)).sqr();
+    /// assert!(x.lo() == qd!(0) && x.contains(qd!(9)));
+    /// ```
+    pub fn sqr(self) -> QuadInterval {
+        let a = self.abs();
+        QuadInterval::new(
+            QuadInterval::mul_down(a.lo, a.lo),
+            QuadInterval::mul_up(a.hi, a.hi),
+        )
+    }
+
+    /// Calculates the square root of the `QuadInterval`.
+    ///
+    /// Only the non-negative part of the interval is in the domain of the square root, so
+    /// negative numbers are ignored, and the result is [`EMPTY`] if there are only
+    /// negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::{qd, Quad};
+    /// let x = QuadInterval::new(qd!(-1), qd!(2)).sqrt();
+    /// assert!(x.lo() == qd!(0));
+    /// assert!(x.contains(Quad::SQRT_2));
+    /// ```
+    ///
+    /// [`EMPTY`]: #associatedconstant.EMPTY
+    pub fn sqrt(self) -> QuadInterval {
+        if self.is_empty() || self.hi < Quad::ZERO {
+            return QuadInterval::EMPTY;
+        }
+        let r = |x: Quad| {
+            let r = x.sqrt();
+            (r, QuadInterval::FUNC * r.precision_ulp())
+        };
+        let (lo, e) = r(self.lo.max(Quad::ZERO));
+        let (hi, f) = r(self.hi);
+        QuadInterval::new(
+            QuadInterval::down(lo, e).max(Quad::ZERO),
+            QuadInterval::up(hi, f),
+        )
+    }
+
+    /// Calculates *e* raised to the power of the `QuadInterval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::{qd, Quad};
+    /// let x = QuadInterval::new(qd!(0), qd!(1)).exp();
+    /// assert!(x.contains(qd!(1)) && x.contains(Quad::E));
+    /// ```
+    pub fn exp(self) -> QuadInterval {
+        if self.is_empty() {
+            return QuadInterval::EMPTY;
+        }
+        // The error grows with the argument, whose rounding error is amplified by the
+        // argument reduction
+        let r = |x: Quad| {
+            let r = x.exp();
+            (
+                r,
+                QuadInterval::FUNC * r.precision_ulp() * (Quad::ONE + x.abs()),
+            )
+        };
+        let (lo, e) = r(self.lo);
+        let (hi, f) = r(self.hi);
+        QuadInterval::new(
+            QuadInterval::down(lo, e).max(Quad::ZERO),
+            QuadInterval::up(hi, f),
+        )
+    }
+
+    /// Calculates the natural logarithm of the `QuadInterval`.
+    ///
+    /// Only the positive part of the interval is in the domain of the logarithm, so
+    /// other numbers are ignored. If the interval contains zero, the result has a lower
+    /// endpoint of -∞, and if it contains no positive numbers, it's [`EMPTY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::{qd, Quad};
+    /// let x = QuadInterval::new(qd!(1), qd!(2)).ln();
+    /// assert!(x.contains(qd!(0)) && x.contains(Quad::LN_2));
+    ///
+    /// let y = QuadInterval::new(qd!(-1), qd!(1)).ln();
+    /// assert!(y.lo() == Quad::NEG_INFINITY);
+    /// ```
+    ///
+    /// [`EMPTY`]: #associatedconstant.EMPTY
+    pub fn ln(self) -> QuadInterval {
+        if self.is_empty() || self.hi <= Quad::ZERO {
+            return QuadInterval::EMPTY;
+        }
+        // The argument's relative rounding error becomes an absolute error in the result
+        let r = |x: Quad| {
+            let r = x.ln();
+            (
+                r,
+                QuadInterval::FUNC * (r.precision_ulp() + Quad::ONE.precision_ulp()),
+            )
+        };
+        let lo = if self.lo <= Quad::ZERO {
+            Quad::NEG_INFINITY
+        } else {
+            let (lo, e) = r(self.lo);
+            QuadInterval::down(lo, e)
+        };
+        let (hi, f) = r(self.hi);
+        QuadInterval::new(lo, QuadInterval::up(hi, f))
+    }
+
+    /// Calculates the sine of the `QuadInterval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// // The interval contains π/2, where the sine is at its maximum
+    /// let x = QuadInterval::new(qd!(1), qd!(2)).sin();
+    /// assert!(x.hi() == qd!(1));
+    /// assert!(x.contains(qd!(1).sin()));
+    /// ```
+    pub fn sin(self) -> QuadInterval {
+        // The sine has its maxima at π/2 + 2kπ and its minima at 3π/2 + 2kπ
+        self.periodic(|x| x.sin(), 1, 3)
+    }
+
+    /// Calculates the cosine of the `QuadInterval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// // The interval contains π, where the cosine is at its minimum
+    /// let x = QuadInterval::new(qd!(3), qd!(4)).cos();
+    /// assert!(x.lo() == qd!(-1));
+    /// assert!(x.contains(qd!(4).cos()));
+    /// ```
+    pub fn cos(self) -> QuadInterval {
+        // The cosine has its maxima at 2kπ and its minima at π + 2kπ
+        self.periodic(|x| x.cos(), 0, 2)
+    }
+
+    /// Calculates the tangent of the `QuadInterval`.
+    ///
+    /// If the interval contains a pole of the tangent (π/2 plus a multiple of π), the
+    /// result is [`ENTIRE`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::{qd, Quad};
+    /// let x = QuadInterval::new(qd!(0), qd!(1)).tan();
+    /// assert!(x.contains(qd!(0)) && x.contains(qd!(1).tan()));
+    ///
+    /// let y = QuadInterval::new(qd!(1), qd!(2)).tan();
+    /// assert!(y == QuadInterval::ENTIRE);
+    /// ```
+    ///
+    /// [`ENTIRE`]: #associatedconstant.ENTIRE
+    pub fn tan(self) -> QuadInterval {
+        if self.is_empty() {
+            return QuadInterval::EMPTY;
+        }
+        if !self.lo.is_finite() || !self.hi.is_finite() || self.quarter_turns(1, 2) {
+            return QuadInterval::ENTIRE;
+        }
+        // Near the poles the argument's rounding error is amplified by the derivative,
+        // 1 + tan²(x)
+        let r = |x: Quad| {
+            let r = x.tan();
+            (
+                r,
+                QuadInterval::FUNC
+                    * (r.precision_ulp() + x.precision_ulp() * (Quad::ONE + r.sqr())),
+            )
+        };
+        let (lo, e) = r(self.lo);
+        let (hi, f) = r(self.hi);
+        QuadInterval::new(QuadInterval::down(lo, e), QuadInterval::up(hi, f))
+    }
+
+    /// Calculates the arctangent of the `QuadInterval`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::{qd, Quad};
+    /// let x = QuadInterval::new(qd!(0), qd!(1)).atan();
+    /// assert!(x.contains(qd!(0)) && x.contains(Quad::FRAC_PI_4));
+    /// ```
+    pub fn atan(self) -> QuadInterval {
+        if self.is_empty() {
+            return QuadInterval::EMPTY;
+        }
+        let r = |x: Quad| {
+            let r = x.atan();
+            (r, QuadInterval::FUNC * r.precision_ulp())
+        };
+        let (lo, e) = r(self.lo);
+        let (hi, f) = r(self.hi);
+        QuadInterval::new(QuadInterval::down(lo, e), QuadInterval::up(hi, f))
+    }
+}
+
+impl QuadInterval {
+    // The bound on the error of the basic arithmetic operations, in ulps of the result.
+    const ARITH: Quad = Quad::new(4.0, 0.0, 0.0, 0.0);
+
+    // The bound on the error of the square root and the transcendental functions, in ulps
+    // of the result and of the argument as described by each function's error model. These
+    // are several times the largest errors that have been measured.
+    const FUNC: Quad = Quad::new(16.0, 0.0, 0.0, 0.0);
+
+    // Rounds `x`, which is within `err` of an exact result, down to a lower bound for it.
+    // An infinite `x` is exact unless it's the result of overflow.
+    fn down(x: Quad, err: Quad) -> Quad {
+        if x == Quad::INFINITY {
+            Quad::MAX
+        } else if x.is_finite() {
+            x.add_accurate(-err)
+        } else {
+            x
+        }
+    }
+
+    // Rounds `x`, which is within `err` of an exact result, up to an upper bound for it.
+    fn up(x: Quad, err: Quad) -> Quad {
+        -QuadInterval::down(-x, err)
+    }
+
+    // `Quad` arithmetic returns `NaN` when the result overflows. Given the sign of the
+    // exact result, this turns that back into the infinity that it rounds to.
+    fn overflow(r: Quad, a: Quad, b: Quad, negative: bool) -> Quad {
+        if r.is_nan() && !a.is_nan() && !b.is_nan() {
+            if negative {
+                Quad::NEG_INFINITY
+            } else {
+                Quad::INFINITY
+            }
+        } else {
+            r
+        }
+    }
+
+    // A sum or difference is only zero when it's exact, so zeros aren't widened.
+    fn add_down(a: Quad, b: Quad) -> Quad {
+        let r = QuadInterval::overflow(a.add_accurate(b), a, b, a.is_sign_negative());
+        if r.is_zero() {
+            r
+        } else {
+            QuadInterval::down(r, QuadInterval::ARITH * r.precision_ulp())
+        }
+    }
+
+    fn add_up(a: Quad, b: Quad) -> Quad {
+        -QuadInterval::add_down(-a, -b)
+    }
+
+    // A product with a zero factor is exactly zero, even if the other factor is infinite.
+    fn mul_down(a: Quad, b: Quad) -> Quad {
+        if a.is_zero() || b.is_zero() {
+            Quad::ZERO
+        } else {
+            let r = QuadInterval::overflow(
+                a.mul_accurate(b),
+                a,
+                b,
+                a.is_sign_negative() != b.is_sign_negative(),
+            );
+            QuadInterval::down(r, QuadInterval::ARITH * r.precision_ulp())
+        }
+    }
+
+    fn mul_up(a: Quad, b: Quad) -> Quad {
+        -QuadInterval::mul_down(-a, b)
+    }
+
+    fn div_down(a: Quad, b: Quad) -> Quad {
+        if a.is_zero() {
+            Quad::ZERO
+        } else {
+            let r = QuadInterval::overflow(
+                a.div_accurate(b),
+                a,
+                b,
+                a.is_sign_negative() != b.is_sign_negative(),
+            );
+            QuadInterval::down(r, QuadInterval::ARITH * r.precision_ulp())
+        }
+    }
+
+    fn div_up(a: Quad, b: Quad) -> Quad {
+        -QuadInterval::div_down(-a, b)
+    }
+
+    // Determines whether the interval might contain a number (r + k · period) · π/2 for
+    // some integer k, where `r` and `period` are numbers of quarter turns. This errs on the
+    // side of finding one, so a point near an endpoint is treated as though it's inside.
+    fn quarter_turns(self, r: i32, period: i32) -> bool {
+        let q = |x: Quad| x / Quad::FRAC_PI_2;
+        let (a, b) = (q(self.lo), q(self.hi));
+        let slack =
+            |x: Quad| (Quad::ONE + x.abs()) * Quad::EPSILON * Quad::new(256.0, 0.0, 0.0, 0.0);
+        let r = Quad::from(r);
+        let p = Quad::from(period);
+        ((a - slack(a) - r) / p).ceil() <= ((b + slack(b) - r) / p).floor()
+    }
+
+    // Calculates a periodic function with a range of [-1, 1] over an interval, where the
+    // function has its maxima at `max` quarter turns and its minima at `min` quarter turns.
+    fn periodic(self, f: impl Fn(Quad) -> Quad, max: i32, min: i32) -> QuadInterval {
+        if self.is_empty() {
+            return QuadInterval::EMPTY;
+        }
+        let full = QuadInterval::new(-Quad::ONE, Quad::ONE);
+        if !self.lo.is_finite() || !self.hi.is_finite() || self.hi - self.lo > Quad::TAU {
+            return full;
+        }
+        // The rounding error of the argument reduction grows with the argument
+        let r = |x: Quad| {
+            let r = f(x);
+            (
+                r,
+                QuadInterval::FUNC * (r.precision_ulp() + x.precision_ulp()),
+            )
+        };
+        let (a, ea) = r(self.lo);
+        let (b, eb) = r(self.hi);
+        let lo = if self.quarter_turns(min, 4) {
+            -Quad::ONE
+        } else {
+            QuadInterval::down(a, ea)
+                .min(QuadInterval::down(b, eb))
+                .max(-Quad::ONE)
+        };
+        let hi = if self.quarter_turns(max, 4) {
+            Quad::ONE
+        } else {
+            QuadInterval::up(a, ea)
+                .max(QuadInterval::up(b, eb))
+                .min(Quad::ONE)
+        };
+        full.intersection(QuadInterval::new(lo, hi))
+    }
+}
+
+impl PartialEq for QuadInterval {
+    /// Two `QuadInterval`s are equal if they have the same endpoints or if they're both
+    /// empty.
+    fn eq(&self, other: &QuadInterval) -> bool {
+        (self.is_empty() && other.is_empty()) || (self.lo == other.lo && self.hi == other.hi)
+    }
+}
+
+impl From<Quad> for QuadInterval {
+    /// Converts a `Quad` into a `QuadInterval` that contains only that number. This is
+    /// the same as [`point`].
+    ///
+    /// [`point`]: #method.point
+    #[inline]
+    fn from(x: Quad) -> QuadInterval {
+        QuadInterval::point(x)
+    }
+}
+
+impl Display for QuadInterval {
+    /// Formats the `QuadInterval` as [*lo*, *hi*]. Any precision given in the format
+    /// string is applied to both endpoints.
+    ///
+    /// The endpoints are rounded to the nearest decimal like any other `Quad`, so the
+    /// displayed interval might not contain every number that the `QuadInterval` does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::interval::QuadInterval;
+    /// # use qd::qd;
+    /// let x = QuadInterval::new(qd!(1), qd!(2.5));
+    /// assert!(format!("{}", x) == "[1, 2.5]");
+    /// assert!(format!("{:.2}", x) == "[1.00, 2.50]");
+    /// assert!(format!("{}", QuadInterval::EMPTY) == "[]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_empty() {
+            write!(f, "[]")
+        } else {
+            match f.precision() {
+                Some(p) => write!(f, "[{:.*}, {:.*}]", p, self.lo, p, self.hi),
+                None => write!(f, "[{}, {}]", self.lo, self.hi),
+            }
+        }
+    }
+}
+
+impl Add for QuadInterval {
+    type Output = QuadInterval;
+
+    fn add(self, other: QuadInterval) -> QuadInterval {
+        QuadInterval::new(
+            QuadInterval::add_down(self.lo, other.lo),
+            QuadInterval::add_up(self.hi, other.hi),
+        )
+    }
+}
+
+impl Add<Quad> for QuadInterval {
+    type Output = QuadInterval;
+
+    #[inline]
+    fn add(self, other: Quad) -> QuadInterval {
+        self + QuadInterval::point(other)
+    }
+}
+
+impl Add<QuadInterval> for Quad {
+    type Output = QuadInterval;
+
+    #[inline]
+    fn add(self, other: QuadInterval) -> QuadInterval {
+        QuadInterval::point(self) + other
+    }
+}
+
+impl Sub for QuadInterval {
+    type Output = QuadInterval;
+
+    fn sub(self, other: QuadInterval) -> QuadInterval {
+        QuadInterval::new(
+            QuadInterval::add_down(self.lo, -other.hi),
+            QuadInterval::add_up(self.hi, -other.lo),
+        )
+    }
+}
+
+impl Sub<Quad> for QuadInterval {
+    type Output = QuadInterval;
+
+    #[inline]
+    fn sub(self, other: Quad) -> QuadInterval {
+        self - QuadInterval::point(other)
+    }
+}
+
+impl Sub<QuadInterval> for Quad {
+    type Output = QuadInterval;
+
+    #[inline]
+    fn sub(self, other: QuadInterval) -> QuadInterval {
+        QuadInterval::point(self) - other
+    }
+}
+
+impl Mul for QuadInterval {
+    type Output = QuadInterval;
+
+    fn mul(self, other: QuadInterval) -> QuadInterval {
+        if self.is_empty() || other.is_empty() {
+            return QuadInterval::EMPTY;
+        }
+        let ends = [
+            (self.lo, other.lo),
+            (self.lo, other.hi),
+            (self.hi, other.lo),
+            (self.hi, other.hi),
+        ];
+        let lo = ends
+            .iter()
+            .map(|&(a, b)| QuadInterval::mul_down(a, b))
+            .fold(Quad::INFINITY, Quad::min);
+        let hi = ends
+            .iter()
+            .map(|&(a, b)| QuadInterval::mul_up(a, b))
+            .fold(Quad::NEG_INFINITY, Quad::max);
+        QuadInterval::new(lo, hi)
+    }
+}
+
+impl Mul<Quad> for QuadInterval {
+    type Output = QuadInterval;
+
+    #[inline]
+    fn mul(self, other: Quad) -> QuadInterval {
+        self * QuadInterval::point(other)
+    }
+}
+
+impl Mul<QuadInterval> for Quad {
+    type Output = QuadInterval;
+
+    #[inline]
+    fn mul(self, other: QuadInterval) -> QuadInterval {
+        QuadInterval::point(self) * other
+    }
+}
+
+impl Div for QuadInterval {
+    type Output = QuadInterval;
+
+    /// Divides one `QuadInterval` by another.
+    ///
+    /// If the divisor contains zero, the result is [`ENTIRE`], unless the divisor is
+    /// exactly zero, in which case there is nothing to divide by and the result is
+    /// [`EMPTY`].
+    ///
+    /// [`ENTIRE`]: #associatedconstant.ENTIRE
+    /// [`EMPTY`]: #associatedconstant.EMPTY
+    fn div(self, other: QuadInterval) -> QuadInterval {
+        if self.is_empty() || other.is_empty() || other == QuadInterval::ZERO {
+            return QuadInterval::EMPTY;
+        }
+        if other.contains(Quad::ZERO) {
+            return QuadInterval::ENTIRE;
+        }
+        let ends = [
+            (self.lo, other.lo),
+            (self.lo, other.hi),
+            (self.hi, other.lo),
+            (self.hi, other.hi),
+        ];
+        // An infinite numerator over an infinite denominator is taken to be an infinity.
+        // The quotient is unbounded in that direction anyway, so it's never too narrow.
+        let lo = ends
+            .iter()
+            .map(|&(a, b)| QuadInterval::div_down(a, b))
+            .fold(Quad::INFINITY, Quad::min);
+        let hi = ends
+            .iter()
+            .map(|&(a, b)| QuadInterval::div_up(a, b))
+            .fold(Quad::NEG_INFINITY, Quad::max);
+        QuadInterval::new(lo, hi)
+    }
+}
+
+impl Div<Quad> for QuadInterval {
+    type Output = QuadInterval;
+
+    #[inline]
+    fn div(self, other: Quad) -> QuadInterval {
+        self / QuadInterval::point(other)
+    }
+}
+
+impl Div<QuadInterval> for Quad {
+    type Output = QuadInterval;
+
+    #[inline]
+    fn div(self, other: QuadInterval) -> QuadInterval {
+        QuadInterval::point(self) / other
+    }
+}
+
+impl Neg for QuadInterval {
+    type Output = QuadInterval;
+
+    #[inline]
+    fn neg(self) -> QuadInterval {
+        QuadInterval::new(-self.hi, -self.lo)
+    }
+}
+
+impl AddAssign for QuadInterval {
+    #[inline]
+    fn add_assign(&mut self, other: QuadInterval) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Quad> for QuadInterval {
+    #[inline]
+    fn add_assign(&mut self, other: Quad) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for QuadInterval {
+    #[inline]
+    fn sub_assign(&mut self, other: QuadInterval) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<Quad> for QuadInterval {
+    #[inline]
+    fn sub_assign(&mut self, other: Quad) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for QuadInterval {
+    #[inline]
+    fn mul_assign(&mut self, other: QuadInterval) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<Quad> for QuadInterval {
+    #[inline]
+    fn mul_assign(&mut self, other: Quad) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for QuadInterval {
+    #[inline]
+    fn div_assign(&mut self, other: QuadInterval) {
+        *self = *self / other;
+    }
+}
+
+impl DivAssign<Quad> for QuadInterval {
+    #[inline]
+    fn div_assign(&mut self, other: Quad) {
+        *self = *self / other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+
+    // Reference values, calculated with mpmath
+    const EXP: &str = "4.48168907033806482260205546011927581900574986836966705677265008278593667";
+    const LN: &str = "1.09861228866810969139524523692252570464749055782274945173469433363749429";
+    const SQRT: &str = "1.73205080756887729352744634150587236694280525381038062805580697945193302";
+    const SIN: &str = "0.479425538604203000273287935215571388081803367940600675188616613125535";
+    const COS: &str = "0.877582561890372716116281582603829651991645197109744052997610868315950763";
+    const TAN: &str = "0.546302489843790513255179465780285383297551720179791246164091385932907511";
+    const ATAN: &str = "0.463647609000806116214256231461214402028537054286120263810933088720197864";
+    const SIN_BIG: &str =
+        "0.0357487979720165093164705006958088290090456925781088968546167365009480743";
+    const EXP_BIG: &str =
+        "7.22597376812574925817747704218930569735687442852731928403269789123221909e+86";
+
+    fn double_encloses(x: DoubleInterval, exact: &str, tol: Double) {
+        let exact = Double::from(exact);
+        assert!(x.contains(exact), "{} doesn't contain {}", x, exact);
+        assert!(x.width() <= exact.abs() * tol, "{} is too wide", x);
+    }
+
+    #[test]
+    fn double_new() {
+        let x = DoubleInterval::new(dd!(1), dd!(2));
+        assert!(x.lo() == dd!(1) && x.hi() == dd!(2));
+        assert!(DoubleInterval::new(dd!(2), dd!(1)).is_empty());
+        assert!(DoubleInterval::new(Double::NAN, dd!(1)).is_empty());
+        assert!(DoubleInterval::point(Double::NAN).is_empty());
+        assert!(DoubleInterval::from(dd!(3)) == DoubleInterval::new(dd!(3), dd!(3)));
+        assert!(DoubleInterval::EMPTY == DoubleInterval::EMPTY);
+        assert!(DoubleInterval::EMPTY != DoubleInterval::ZERO);
+    }
+
+    #[test]
+    fn double_set_operations() {
+        let x = DoubleInterval::new(dd!(0), dd!(2));
+        let y = DoubleInterval::new(dd!(1), dd!(3));
+        let z = DoubleInterval::new(dd!(5), dd!(6));
+        assert!(x.intersection(y) == DoubleInterval::new(dd!(1), dd!(2)));
+        assert!(x.intersection(z).is_empty());
+        assert!(x.hull(z) == DoubleInterval::new(dd!(0), dd!(6)));
+        assert!(x.hull(DoubleInterval::EMPTY) == x);
+        assert!(DoubleInterval::EMPTY.is_subset(x));
+        assert!(x.is_subset(DoubleInterval::ENTIRE));
+        assert!(!DoubleInterval::EMPTY.contains(dd!(0)));
+        assert!(DoubleInterval::ENTIRE.contains(Double::MAX));
+    }
+
+    #[test]
+    fn double_mid_width() {
+        assert!(DoubleInterval::new(dd!(-1), dd!(3)).mid() == dd!(1));
+        assert!(DoubleInterval::new(-Double::MAX, Double::MAX).mid() == dd!(0));
+        assert!(DoubleInterval::ENTIRE.mid() == dd!(0));
+        assert!(DoubleInterval::new(dd!(0), Double::INFINITY).mid() == Double::MAX);
+        let w = DoubleInterval::new(dd!(1), dd!(2)).width();
+        assert!(w >= dd!(1) && w < dd!(1) + dd!(1e-30));
+        assert!(DoubleInterval::ENTIRE.width() == Double::INFINITY);
+    }
+
+    #[test]
+    fn double_add_sub() {
+        let x = DoubleInterval::new(dd!(1), dd!(2));
+        let y = DoubleInterval::new(dd!(3), dd!(4));
+        let s = x + y;
+        assert!(s.is_subset(DoubleInterval::new(dd!(3.999), dd!(6.001))));
+        assert!(DoubleInterval::new(dd!(4), dd!(6)).is_subset(s));
+        let d = x - y;
+        assert!(DoubleInterval::new(dd!(-3), dd!(-1)).is_subset(d));
+        assert!(d.is_subset(DoubleInterval::new(dd!(-3.001), dd!(-0.999))));
+
+        // Exact zeros aren't widened
+        let one = DoubleInterval::ONE;
+        assert!(one - one == DoubleInterval::ZERO);
+        assert!(DoubleInterval::ZERO + DoubleInterval::ZERO == DoubleInterval::ZERO);
+
+        let tenth = DoubleInterval::enclose(dd!("0.1"));
+        let mut sum = DoubleInterval::ZERO;
+        for _ in 0..10 {
+            sum += tenth;
+        }
+        assert!(sum.contains(dd!(1)));
+        assert!(sum.width() < dd!(1e-29));
+
+        assert!((x + DoubleInterval::EMPTY).is_empty());
+        assert!((x + DoubleInterval::ENTIRE) == DoubleInterval::ENTIRE);
+        assert!((dd!(1) + x).contains(dd!(3)));
+        assert!((x - dd!(1)).contains(dd!(0)));
+    }
+
+    #[test]
+    fn double_mul() {
+        let x = DoubleInterval::new(dd!(-1), dd!(2));
+        let y = DoubleInterval::new(dd!(-3), dd!(4));
+        let p = x * y;
+        assert!(DoubleInterval::new(dd!(-6), dd!(8)).is_subset(p));
+        assert!(p.is_subset(DoubleInterval::new(dd!(-6.001), dd!(8.001))));
+        assert!(DoubleInterval::ZERO * DoubleInterval::ENTIRE == DoubleInterval::ZERO);
+        assert!((x * DoubleInterval::EMPTY).is_empty());
+
+        let third = DoubleInterval::ONE / dd!(3);
+        assert!((third * dd!(3)).contains(dd!(1)));
+
+        // The exact product underflows, but the interval still contains it
+        let tiny = DoubleInterval::point(dd!(1e-200));
+        let p = tiny * tiny;
+        assert!(p.lo() <= dd!(0) && p.hi() > dd!(0));
+
+        // The exact product overflows
+        let p = DoubleInterval::point(Double::MAX) * dd!(2);
+        assert!(p.lo() == Double::MAX && p.hi() == Double::INFINITY);
+    }
+
+    #[test]
+    fn double_div() {
+        let x = DoubleInterval::new(dd!(1), dd!(2));
+        let q = x / DoubleInterval::new(dd!(4), dd!(8));
+        assert!(DoubleInterval::new(dd!(0.125), dd!(0.5)).is_subset(q));
+        assert!(q.is_subset(DoubleInterval::new(dd!(0.1249), dd!(0.5001))));
+        assert!(x / DoubleInterval::new(dd!(-1), dd!(1)) == DoubleInterval::ENTIRE);
+        assert!((x / DoubleInterval::ZERO).is_empty());
+        assert!(DoubleInterval::ZERO / x == DoubleInterval::ZERO);
+
+        let q = DoubleInterval::new(dd!(1), Double::INFINITY) / x;
+        assert!(q.lo() <= dd!(0.5) && q.hi() == Double::INFINITY);
+        let q = DoubleInterval::new(dd!(1), Double::INFINITY)
+            / DoubleInterval::new(dd!(1), Double::INFINITY);
+        assert!(q.lo() <= dd!(0) && q.hi() == Double::INFINITY);
+        assert!((dd!(1) / x).contains(dd!(0.5)));
+    }
+
+    #[test]
+    fn double_neg_abs_sqr() {
+        let x = DoubleInterval::new(dd!(-3), dd!(2));
+        assert!(-x == DoubleInterval::new(dd!(-2), dd!(3)));
+        assert!(x.abs() == DoubleInterval::new(dd!(0), dd!(3)));
+        assert!((-x).abs() == DoubleInterval::new(dd!(0), dd!(3)));
+        assert!(DoubleInterval::new(dd!(-3), dd!(-2)).abs() == DoubleInterval::new(dd!(2), dd!(3)));
+        let sq = x.sqr();
+        assert!(sq.lo() == dd!(0) && sq.contains(dd!(9)) && sq.hi() < dd!(9.001));
+        let sq = DoubleInterval::new(dd!(-3), dd!(-2)).sqr();
+        assert!(sq.contains(dd!(4)) && sq.contains(dd!(9)) && sq.width() < dd!(5.001));
+        assert!(DoubleInterval::EMPTY.abs().is_empty());
+        assert!(DoubleInterval::EMPTY.sqr().is_empty());
+    }
+
+    #[test]
+    fn double_sqrt() {
+        let tol = dd!(1e-29);
+        double_encloses(DoubleInterval::point(dd!(3)).sqrt(), SQRT, tol);
+        let x = DoubleInterval::new(dd!(-1), dd!(4)).sqrt();
+        assert!(x.lo() == dd!(0) && x.contains(dd!(2)));
+        assert!(DoubleInterval::new(dd!(-2), dd!(-1)).sqrt().is_empty());
+        let x = DoubleInterval::ZERO.sqrt();
+        assert!(x.lo() == dd!(0) && x.hi() < dd!(1e-300));
+    }
+
+    #[test]
+    fn double_exp_ln() {
+        let tol = dd!(1e-29);
+        double_encloses(DoubleInterval::point(dd!(1.5)).exp(), EXP, tol);
+        double_encloses(DoubleInterval::point(dd!(200)).exp(), EXP_BIG, dd!(1e-27));
+        double_encloses(DoubleInterval::point(dd!(3)).ln(), LN, tol);
+
+        let x = DoubleInterval::new(Double::NEG_INFINITY, dd!(0)).exp();
+        assert!(x.lo() == dd!(0) && x.contains(dd!(1)));
+        let x = DoubleInterval::new(dd!(1000), Double::INFINITY).exp();
+        assert!(x.lo() == Double::MAX && x.hi() == Double::INFINITY);
+
+        let x = DoubleInterval::new(dd!(0), dd!(1)).ln();
+        assert!(x.lo() == Double::NEG_INFINITY && x.contains(dd!(0)));
+        assert!(DoubleInterval::new(dd!(-2), dd!(0)).ln().is_empty());
+        assert!(DoubleInterval::EMPTY.exp().is_empty());
+    }
+
+    #[test]
+    fn double_sin_cos() {
+        let tol = dd!(1e-29);
+        let half = DoubleInterval::point(dd!(0.5));
+        double_encloses(half.sin(), SIN, tol);
+        double_encloses(half.cos(), COS, tol);
+        double_encloses(
+            DoubleInterval::point(dd!(100000)).sin(),
+            SIN_BIG,
+            dd!(1e-23),
+        );
+
+        // Extrema inside the interval
+        let x = DoubleInterval::new(dd!(1), dd!(2));
+        assert!(x.sin().hi() == dd!(1) && x.sin().contains(dd!(1).sin()));
+        let c = x.cos();
+        assert!(c.contains(dd!(2).cos()) && c.contains(dd!(1).cos()));
+        assert!(c.width() < dd!(1).cos() - dd!(2).cos() + dd!(1e-25));
+        let x = DoubleInterval::new(dd!(3), dd!(5));
+        assert!(x.cos().lo() == dd!(-1) && x.sin().lo() == dd!(-1));
+        let x = DoubleInterval::new(dd!(-100), dd!(-93));
+        assert!(x.sin() == DoubleInterval::new(dd!(-1), dd!(1)));
+        assert!(DoubleInterval::ENTIRE.cos() == DoubleInterval::new(dd!(-1), dd!(1)));
+
+        // Close enough to π/2 that the maximum can't be ruled out
+        let x = DoubleInterval::point(Double::FRAC_PI_2).sin();
+        assert!(x.hi() == dd!(1));
+
+        let x = DoubleInterval::new(dd!(0.5), dd!(0.5).next_up());
+        assert!((x.sin().sqr() + x.cos().sqr()).contains(dd!(1)));
+        assert!(DoubleInterval::EMPTY.sin().is_empty());
+    }
+
+    #[test]
+    fn double_tan_atan() {
+        let tol = dd!(1e-29);
+        double_encloses(DoubleInterval::point(dd!(0.5)).tan(), TAN, tol);
+        double_encloses(DoubleInterval::point(dd!(0.5)).atan(), ATAN, tol);
+        assert!(DoubleInterval::new(dd!(1), dd!(2)).tan() == DoubleInterval::ENTIRE);
+        assert!(DoubleInterval::new(dd!(-2), dd!(-1)).tan() == DoubleInterval::ENTIRE);
+        let x = DoubleInterval::new(dd!(2), dd!(4)).tan();
+        assert!(x.contains(dd!(3).tan()) && x.hi() < dd!(1.2));
+
+        let x = DoubleInterval::ENTIRE.atan();
+        assert!(x.contains(Double::FRAC_PI_2) && x.contains(-Double::FRAC_PI_2));
+    }
+
+    #[test]
+    fn double_display() {
+        let x = DoubleInterval::new(dd!(-1), dd!(2.5));
+        assert!(format!("{}", x) == "[-1, 2.5]");
+        assert!(format!("{:.1}", x) == "[-1.0, 2.5]");
+        assert!(format!("{}", DoubleInterval::EMPTY) == "[]");
+    }
+
+    fn quad_encloses(x: QuadInterval, exact: &str, tol: Quad) {
+        let exact = Quad::from(exact);
+        assert!(x.contains(exact), "{} doesn't contain {}", x, exact);
+        assert!(x.width() <= exact.abs() * tol, "{} is too wide", x);
+    }
+
+    #[test]
+    fn quad_new() {
+        let x = QuadInterval::new(qd!(1), qd!(2));
+        assert!(x.lo() == qd!(1) && x.hi() == qd!(2));
+        assert!(QuadInterval::new(qd!(2), qd!(1)).is_empty());
+        assert!(QuadInterval::new(Quad::NAN, qd!(1)).is_empty());
+        assert!(QuadInterval::point(Quad::NAN).is_empty());
+        assert!(QuadInterval::from(qd!(3)) == QuadInterval::new(qd!(3), qd!(3)));
+        assert!(QuadInterval::EMPTY == QuadInterval::EMPTY);
+        assert!(QuadInterval::EMPTY != QuadInterval::ZERO);
+    }
+
+    #[test]
+    fn quad_set_operations() {
+        let x = QuadInterval::new(qd!(0), qd!(2));
+        let y = QuadInterval::new(qd!(1), qd!(3));
+        let z = QuadInterval::new(qd!(5), qd!(6));
+        assert!(x.intersection(y) == QuadInterval::new(qd!(1), qd!(2)));
+        assert!(x.intersection(z).is_empty());
+        assert!(x.hull(z) == QuadInterval::new(qd!(0), qd!(6)));
+        assert!(x.hull(QuadInterval::EMPTY) == x);
+        assert!(QuadInterval::EMPTY.is_subset(x));
+        assert!(x.is_subset(QuadInterval::ENTIRE));
+        assert!(!QuadInterval::EMPTY.contains(qd!(0)));
+        assert!(QuadInterval::ENTIRE.contains(Quad::MAX));
+    }
+
+    #[test]
+    fn quad_mid_width() {
+        assert!(QuadInterval::new(qd!(-1), qd!(3)).mid() == qd!(1));
+        assert!(QuadInterval::new(-Quad::MAX, Quad::MAX).mid() == qd!(0));
+        assert!(QuadInterval::ENTIRE.mid() == qd!(0));
+        assert!(QuadInterval::new(qd!(0), Quad::INFINITY).mid() == Quad::MAX);
+        let w = QuadInterval::new(qd!(1), qd!(2)).width();
+        assert!(w >= qd!(1) && w < qd!(1) + qd!(1e-60));
+        assert!(QuadInterval::ENTIRE.width() == Quad::INFINITY);
+    }
+
+    #[test]
+    fn quad_add_sub() {
+        let x = QuadInterval::new(qd!(1), qd!(2));
+        let y = QuadInterval::new(qd!(3), qd!(4));
+        let s = x + y;
+        assert!(s.is_subset(QuadInterval::new(qd!(3.999), qd!(6.001))));
+        assert!(QuadInterval::new(qd!(4), qd!(6)).is_subset(s));
+        let d = x - y;
+        assert!(QuadInterval::new(qd!(-3), qd!(-1)).is_subset(d));
+        assert!(d.is_subset(QuadInterval::new(qd!(-3.001), qd!(-0.999))));
+
+        // Exact zeros aren't widened
+        let one = QuadInterval::ONE;
+        assert!(one - one == QuadInterval::ZERO);
+        assert!(QuadInterval::ZERO + QuadInterval::ZERO == QuadInterval::ZERO);
+
+        let tenth = QuadInterval::enclose(qd!("0.1"));
+        let mut sum = QuadInterval::ZERO;
+        for _ in 0..10 {
+            sum += tenth;
+        }
+        assert!(sum.contains(qd!(1)));
+        assert!(sum.width() < qd!(1e-59));
+
+        assert!((x + QuadInterval::EMPTY).is_empty());
+        assert!((x + QuadInterval::ENTIRE) == QuadInterval::ENTIRE);
+        assert!((qd!(1) + x).contains(qd!(3)));
+        assert!((x - qd!(1)).contains(qd!(0)));
+    }
+
+    #[test]
+    fn quad_mul() {
+        let x = QuadInterval::new(qd!(-1), qd!(2));
+        let y = QuadInterval::new(qd!(-3), qd!(4));
+        let p = x * y;
+        assert!(QuadInterval::new(qd!(-6), qd!(8)).is_subset(p));
+        assert!(p.is_subset(QuadInterval::new(qd!(-6.001), qd!(8.001))));
+        assert!(QuadInterval::ZERO * QuadInterval::ENTIRE == QuadInterval::ZERO);
+        assert!((x * QuadInterval::EMPTY).is_empty());
+
+        let third = QuadInterval::ONE / qd!(3);
+        assert!((third * qd!(3)).contains(qd!(1)));
+
+        // The exact product underflows, but the interval still contains it
+        let tiny = QuadInterval::point(qd!(1e-200));
+        let p = tiny * tiny;
+        assert!(p.lo() <= qd!(0) && p.hi() > qd!(0));
+
+        // The exact product overflows
+        let p = QuadInterval::point(Quad::MAX) * qd!(2);
+        assert!(p.lo() == Quad::MAX && p.hi() == Quad::INFINITY);
+    }
+
+    #[test]
+    fn quad_div() {
+        let x = QuadInterval::new(qd!(1), qd!(2));
+        let q = x / QuadInterval::new(qd!(4), qd!(8));
+        assert!(QuadInterval::new(qd!(0.125), qd!(0.5)).is_subset(q));
+        assert!(q.is_subset(QuadInterval::new(qd!(0.1249), qd!(0.5001))));
+        assert!(x / QuadInterval::new(qd!(-1), qd!(1)) == QuadInterval::ENTIRE);
+        assert!((x / QuadInterval::ZERO).is_empty());
+        assert!(QuadInterval::ZERO / x == QuadInterval::ZERO);
+
+        let q = QuadInterval::new(qd!(1), Quad::INFINITY) / x;
+        assert!(q.lo() <= qd!(0.5) && q.hi() == Quad::INFINITY);
+        let q =
+            QuadInterval::new(qd!(1), Quad::INFINITY) / QuadInterval::new(qd!(1), Quad::INFINITY);
+        assert!(q.lo() <= qd!(0) && q.hi() == Quad::INFINITY);
+        assert!((qd!(1) / x).contains(qd!(0.5)));
+    }
+
+    #[test]
+    fn quad_neg_abs_sqr() {
+        let x = QuadInterval::new(qd!(-3), qd!(2));
+        assert!(-x == QuadInterval::new(qd!(-2), qd!(3)));
+        assert!(x.abs() == QuadInterval::new(qd!(0), qd!(3)));
+        assert!((-x).abs() == QuadInterval::new(qd!(0), qd!(3)));
+        assert!(QuadInterval::new(qd!(-3), qd!(-2)).abs() == QuadInterval::new(qd!(2), qd!(3)));
+        let sq = x.sqr();
+        assert!(sq.lo() == qd!(0) && sq.contains(qd!(9)) && sq.hi() < qd!(9.001));
+        let sq = QuadInterval::new(qd!(-3), qd!(-2)).sqr();
+        assert!(sq.contains(qd!(4)) && sq.contains(qd!(9)) && sq.width() < qd!(5.001));
+        assert!(QuadInterval::EMPTY.abs().is_empty());
+        assert!(QuadInterval::EMPTY.sqr().is_empty());
+    }
+
+    #[test]
+    fn quad_sqrt() {
+        let tol = qd!(1e-59);
+        quad_encloses(QuadInterval::point(qd!(3)).sqrt(), SQRT, tol);
+        let x = QuadInterval::new(qd!(-1), qd!(4)).sqrt();
+        assert!(x.lo() == qd!(0) && x.contains(qd!(2)));
+        assert!(QuadInterval::new(qd!(-2), qd!(-1)).sqrt().is_empty());
+        let x = QuadInterval::ZERO.sqrt();
+        assert!(x.lo() == qd!(0) && x.hi() < qd!(1e-300));
+    }
+
+    #[test]
+    fn quad_exp_ln() {
+        let tol = qd!(1e-59);
+        quad_encloses(QuadInterval::point(qd!(1.5)).exp(), EXP, tol);
+        quad_encloses(QuadInterval::point(qd!(200)).exp(), EXP_BIG, qd!(1e-57));
+        quad_encloses(QuadInterval::point(qd!(3)).ln(), LN, tol);
+
+        let x = QuadInterval::new(Quad::NEG_INFINITY, qd!(0)).exp();
+        assert!(x.lo() == qd!(0) && x.contains(qd!(1)));
+        let x = QuadInterval::new(qd!(1000), Quad::INFINITY).exp();
+        assert!(x.lo() == Quad::MAX && x.hi() == Quad::INFINITY);
+
+        let x = QuadInterval::new(qd!(0), qd!(1)).ln();
+        assert!(x.lo() == Quad::NEG_INFINITY && x.contains(qd!(0)));
+        assert!(QuadInterval::new(qd!(-2), qd!(0)).ln().is_empty());
+        assert!(QuadInterval::EMPTY.exp().is_empty());
+    }
+
+    #[test]
+    fn quad_sin_cos() {
+        let tol = qd!(1e-59);
+        let half = QuadInterval::point(qd!(0.5));
+        quad_encloses(half.sin(), SIN, tol);
+        quad_encloses(half.cos(), COS, tol);
+        quad_encloses(QuadInterval::point(qd!(100000)).sin(), SIN_BIG, qd!(1e-53));
+
+        // Extrema inside the interval
+        let x = QuadInterval::new(qd!(1), qd!(2));
+        assert!(x.sin().hi() == qd!(1) && x.sin().contains(qd!(1).sin()));
+        let c = x.cos();
+        assert!(c.contains(qd!(2).cos()) && c.contains(qd!(1).cos()));
+        assert!(c.width() < qd!(1).cos() - qd!(2).cos() + qd!(1e-55));
+        let x = QuadInterval::new(qd!(3), qd!(5));
+        assert!(x.cos().lo() == qd!(-1) && x.sin().lo() == qd!(-1));
+        let x = QuadInterval::new(qd!(-100), qd!(-93));
+        assert!(x.sin() == QuadInterval::new(qd!(-1), qd!(1)));
+        assert!(QuadInterval::ENTIRE.cos() == QuadInterval::new(qd!(-1), qd!(1)));
+
+        // Close enough to π/2 that the maximum can't be ruled out
+        let x = QuadInterval::point(Quad::FRAC_PI_2).sin();
+        assert!(x.hi() == qd!(1));
+
+        let x = QuadInterval::new(qd!(0.5), qd!(0.5).next_up());
+        assert!((x.sin().sqr() + x.cos().sqr()).contains(qd!(1)));
+        assert!(QuadInterval::EMPTY.sin().is_empty());
+    }
+
+    #[test]
+    fn quad_tan_atan() {
+        let tol = qd!(1e-59);
+        quad_encloses(QuadInterval::point(qd!(0.5)).tan(), TAN, tol);
+        quad_encloses(QuadInterval::point(qd!(0.5)).atan(), ATAN, tol);
+        assert!(QuadInterval::new(qd!(1), qd!(2)).tan() == QuadInterval::ENTIRE);
+        assert!(QuadInterval::new(qd!(-2), qd!(-1)).tan() == QuadInterval::ENTIRE);
+        let x = QuadInterval::new(qd!(2), qd!(4)).tan();
+        assert!(x.contains(qd!(3).tan()) && x.hi() < qd!(1.2));
+
+        let x = QuadInterval::ENTIRE.atan();
+        assert!(x.contains(Quad::FRAC_PI_2) && x.contains(-Quad::FRAC_PI_2));
+    }
+
+    #[test]
+    fn quad_display() {
+        let x = QuadInterval::new(qd!(-1), qd!(2.5));
+        assert!(format!("{}", x) == "[-1, 2.5]");
+        assert!(format!("{:.1}", x) == "[-1.0, 2.5]");
+        assert!(format!("{}", QuadInterval::EMPTY) == "[]");
+    }
+}
@@ -111,9 +111,58 @@
 
 mod common;
 mod double;
+mod interop;
+mod precision;
 mod quad;
 
+#[cfg(feature = "extras")]
+pub mod accumulator;
+#[cfg(feature = "extras")]
+pub mod angle;
+#[cfg(feature = "extras")]
+pub mod approx;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "extras")]
+pub mod cf;
+#[cfg(feature = "extras")]
+pub mod complex;
+pub mod consts;
+pub mod eft;
 pub mod error;
+pub mod expansion;
+pub mod ext;
+#[cfg(feature = "extras")]
+pub mod integrate;
+#[cfg(feature = "extras")]
+pub mod interval;
+#[cfg(feature = "extras")]
+pub mod linalg;
+#[cfg(feature = "extras")]
+pub mod multi;
+#[cfg(feature = "extras")]
+pub mod ordered;
+#[cfg(feature = "extras")]
+pub mod poly;
+pub mod prelude;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "extras")]
+pub mod roots;
+#[cfg(feature = "simd")]
+pub mod simd;
+#[cfg(feature = "extras")]
+pub mod stats;
+#[cfg(feature = "extras")]
+pub mod testing;
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use qd_macros as __macros;
 
 pub use self::double::Double;
+pub use self::precision::AbsRel;
+#[cfg(all(feature = "transcendental", feature = "display"))]
+pub use self::precision::MultiPrecision;
+pub use self::precision::MultiPrecisionCore;
 pub use self::quad::Quad;
@@ -0,0 +1,1331 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Small dense vectors and matrices of [`Double`]s and [`Quad`]s.
+//!
+//! [`Vector`] and [`Matrix`] work with either [`Double`]s or [`Quad`]s through the
+//! [`MultiPrecision`] trait. Matrices can be factored into [`Lu`] (with partial pivoting)
+//! and [`Qr`] (with Householder reflections) factorizations, which are used to solve
+//! linear systems, to solve least-squares problems, and to find determinants and inverses.
+//! For larger systems, [`refine`] finds a solution in fast `f64` arithmetic and then
+//! improves it to full precision.
+//!
+//! The algorithms are the straightforward dense ones, which take time proportional to the
+//! cube of the size of the matrix, so they're meant for matrices of modest size. That's
+//! where the extra precision matters most anyway: the classic example is a least-squares
+//! fit through the normal equations, whose matrix has the square of the condition number
+//! of the original problem and can lose every digit of an `f64` solution.
+//!
+//! # Examples
+//! ```
+//! use qd::linalg::{Matrix, Vector};
+//! use qd::{dd, Double};
+//!
+//! // The 8 × 8 Hilbert matrix has a condition number of about 1.5 × 10¹⁰
+//! let n = 8;
+//! let h = Matrix::from_fn(n, n, |i, j| dd!(1) / Double::from((i + j + 1) as f64));
+//! let x = Vector::new(vec![dd!(1); n]);
+//! let b = &h * &x;
+//!
+//! let solved = h.solve(&b).unwrap();
+//! assert!((&solved - &x).norm() < dd!(1e-20));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`MultiPrecision`]: crate::MultiPrecision
+//! [`Vector`]: crate::linalg::Vector
+//! [`Matrix`]: crate::linalg::Matrix
+//! [`Lu`]: crate::linalg::Lu
+//! [`Qr`]: crate::linalg::Qr
+//! [`refine`]: crate::linalg::refine
+
+use crate::precision::MultiPrecision;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/// A vector of [`Double`]s or [`Quad`]s.
+///
+/// The arithmetic operators are implemented for both vectors and references to vectors,
+/// so that the operands don't have to be cloned to be reused. Adding or subtracting
+/// vectors of different lengths panics.
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vector<T: MultiPrecision> {
+    data: Vec<T>,
+}
+
+impl<T: MultiPrecision> Vector<T> {
+    /// Creates a new vector from its elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Vector;
+    /// # use qd::{dd, Double};
+    /// let v = Vector::new(vec![dd!(1), dd!(2)]);
+    /// assert_eq!(v[1], dd!(2));
+    /// ```
+    pub fn new(data: Vec<T>) -> Vector<T> {
+        Vector { data }
+    }
+
+    /// Creates a new vector of zeros.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Vector;
+    /// # use qd::{qd, Quad};
+    /// let v = Vector::<Quad>::zeros(3);
+    /// assert_eq!(v.as_slice(), &[qd!(0); 3]);
+    /// ```
+    pub fn zeros(len: usize) -> Vector<T> {
+        Vector::new(vec![T::ZERO; len])
+    }
+
+    /// Returns the number of elements in the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Vector;
+    /// # use qd::Double;
+    /// assert_eq!(Vector::<Double>::zeros(3).len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the vector has no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Vector;
+    /// # use qd::Double;
+    /// assert!(Vector::<Double>::zeros(0).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the elements of the vector as a slice.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Vector;
+    /// # use qd::{dd, Double};
+    /// let v = Vector::new(vec![dd!(1), dd!(2)]);
+    /// assert_eq!(v.as_slice(), &[dd!(1), dd!(2)]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Calculates the dot product of two vectors.
+    ///
+    /// # Panics
+    /// Panics if the vectors have different lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Vector;
+    /// # use qd::{dd, Double};
+    /// let a = Vector::new(vec![dd!(1), dd!(2)]);
+    /// let b = Vector::new(vec![dd!(3), dd!(4)]);
+    /// assert_eq!(a.dot(&b), dd!(11));
+    /// ```
+    pub fn dot(&self, other: &Vector<T>) -> T {
+        assert_eq!(self.len(), other.len(), "vectors have different lengths");
+        dot(&self.data, &other.data)
+    }
+
+    /// Calculates the Euclidean norm of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Vector;
+    /// # use qd::{dd, Double};
+    /// let v = Vector::new(vec![dd!(3), dd!(4)]);
+    /// assert_eq!(v.norm(), dd!(5));
+    /// ```
+    pub fn norm(&self) -> T {
+        dot(&self.data, &self.data).sqrt()
+    }
+}
+
+impl<T: MultiPrecision> Index<usize> for Vector<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        &self.data[idx]
+    }
+}
+
+impl<T: MultiPrecision> IndexMut<usize> for Vector<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        &mut self.data[idx]
+    }
+}
+
+impl<T: MultiPrecision> From<Vec<T>> for Vector<T> {
+    fn from(data: Vec<T>) -> Vector<T> {
+        Vector::new(data)
+    }
+}
+
+impl<T: MultiPrecision> Neg for &Vector<T> {
+    type Output = Vector<T>;
+
+    /// Negates every element of the vector.
+    fn neg(self) -> Vector<T> {
+        Vector::new(self.data.iter().map(|&x| -x).collect())
+    }
+}
+
+impl<T: MultiPrecision> Add for &Vector<T> {
+    type Output = Vector<T>;
+
+    /// Adds two vectors element by element.
+    fn add(self, other: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.len(), other.len(), "vectors have different lengths");
+        let data = self.data.iter().zip(other.data.iter());
+        Vector::new(data.map(|(&a, &b)| a + b).collect())
+    }
+}
+
+impl<T: MultiPrecision> Sub for &Vector<T> {
+    type Output = Vector<T>;
+
+    /// Subtracts one vector from another element by element.
+    fn sub(self, other: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.len(), other.len(), "vectors have different lengths");
+        let data = self.data.iter().zip(other.data.iter());
+        Vector::new(data.map(|(&a, &b)| a - b).collect())
+    }
+}
+
+impl<T: MultiPrecision> Mul<T> for &Vector<T> {
+    type Output = Vector<T>;
+
+    /// Multiplies every element of the vector by a number.
+    fn mul(self, k: T) -> Vector<T> {
+        Vector::new(self.data.iter().map(|&x| x * k).collect())
+    }
+}
+
+/// A matrix of [`Double`]s or [`Quad`]s.
+///
+/// The elements are stored in row-major order and are indexed by `(row, column)` pairs.
+/// The arithmetic operators are implemented for both matrices and references to matrices.
+/// Operations on matrices whose dimensions don't match panic.
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix<T: MultiPrecision> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: MultiPrecision> Matrix<T> {
+    /// Creates a new matrix from its elements, given in row-major order.
+    ///
+    /// # Panics
+    /// Panics if the number of elements isn't `rows * cols`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{dd, Double};
+    /// let m = Matrix::new(2, 2, vec![dd!(1), dd!(2), dd!(3), dd!(4)]);
+    /// assert_eq!(m[(1, 0)], dd!(3));
+    /// ```
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Matrix<T> {
+        assert_eq!(data.len(), rows * cols, "wrong number of matrix elements");
+        Matrix { rows, cols, data }
+    }
+
+    /// Creates a new matrix whose elements are given by a function of their row and column.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{dd, Double};
+    /// let m = Matrix::from_fn(2, 3, |i, j| Double::from((i * 3 + j) as f64));
+    /// assert_eq!(m[(1, 2)], dd!(5));
+    /// ```
+    pub fn from_fn<F>(rows: usize, cols: usize, f: F) -> Matrix<T>
+    where
+        F: Fn(usize, usize) -> T,
+    {
+        let data = (0..rows * cols).map(|k| f(k / cols, k % cols)).collect();
+        Matrix::new(rows, cols, data)
+    }
+
+    /// Creates a new matrix of zeros.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{qd, Quad};
+    /// let m = Matrix::<Quad>::zeros(2, 3);
+    /// assert_eq!(m[(1, 2)], qd!(0));
+    /// ```
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix::new(rows, cols, vec![T::ZERO; rows * cols])
+    }
+
+    /// Creates a new identity matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{qd, Quad};
+    /// let m = Matrix::<Quad>::identity(3);
+    /// assert_eq!(m[(1, 1)], qd!(1));
+    /// assert_eq!(m[(1, 2)], qd!(0));
+    /// ```
+    pub fn identity(n: usize) -> Matrix<T> {
+        Matrix::from_fn(n, n, |i, j| if i == j { T::ONE } else { T::ZERO })
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns one row of the matrix as a slice.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{dd, Double};
+    /// let m = Matrix::new(2, 2, vec![dd!(1), dd!(2), dd!(3), dd!(4)]);
+    /// assert_eq!(m.row(1), &[dd!(3), dd!(4)]);
+    /// ```
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns the transpose of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{dd, Double};
+    /// let m = Matrix::new(1, 2, vec![dd!(1), dd!(2)]);
+    /// assert_eq!(m.transpose(), Matrix::new(2, 1, vec![dd!(1), dd!(2)]));
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        Matrix::from_fn(self.cols, self.rows, |i, j| self[(j, i)])
+    }
+
+    /// Factors the matrix into a lower and an upper triangular matrix, with partial
+    /// pivoting.
+    ///
+    /// # Panics
+    /// Panics if the matrix isn't square.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{dd, Double};
+    /// let m = Matrix::new(2, 2, vec![dd!(1), dd!(2), dd!(3), dd!(4)]);
+    /// let lu = m.lu();
+    /// assert_eq!(lu.det(), dd!(-2));
+    /// ```
+    pub fn lu(&self) -> Lu<T> {
+        assert_eq!(
+            self.rows, self.cols,
+            "LU factorization of a non-square matrix"
+        );
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut negative = false;
+        let mut singular = false;
+
+        for k in 0..n {
+            // The largest remaining element in the column becomes the pivot
+            let p = (k..n)
+                .max_by(|&i, &j| a[(i, k)].abs().partial_cmp(&a[(j, k)].abs()).unwrap())
+                .unwrap();
+            if a[(p, k)] == T::ZERO {
+                singular = true;
+                continue;
+            }
+            if p != k {
+                a.swap_rows(p, k);
+                perm.swap(p, k);
+                negative = !negative;
+            }
+            let pivot = a[(k, k)];
+            for i in k + 1..n {
+                let factor = a[(i, k)] / pivot;
+                a[(i, k)] = factor;
+                for j in k + 1..n {
+                    let x = a[(k, j)];
+                    a[(i, j)] = a[(i, j)] - factor * x;
+                }
+            }
+        }
+        Lu {
+            lu: a,
+            perm,
+            negative,
+            singular,
+        }
+    }
+
+    /// Factors the matrix into an orthogonal and an upper triangular matrix with
+    /// Householder reflections.
+    ///
+    /// # Panics
+    /// Panics if the matrix has more columns than rows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{dd, Double};
+    /// let m = Matrix::new(3, 2, vec![dd!(1), dd!(0), dd!(1), dd!(1), dd!(1), dd!(2)]);
+    /// let qr = m.qr();
+    /// let diff = &(&qr.q() * &qr.r()) - &m;
+    /// assert!(diff.row(2)[1].abs() < dd!(1e-30));
+    /// ```
+    pub fn qr(&self) -> Qr<T> {
+        assert!(self.rows >= self.cols, "QR factorization of a wide matrix");
+        let (m, n) = (self.rows, self.cols);
+        let mut a = self.clone();
+        let mut vs = Vec::with_capacity(n);
+        let mut singular = false;
+
+        for k in 0..n {
+            // The reflection that takes column k below the diagonal to a multiple of the
+            // first unit vector, choosing the sign of that multiple to avoid cancellation
+            let mut v: Vec<T> = (k..m).map(|i| a[(i, k)]).collect();
+            let norm = dot(&v, &v).sqrt();
+            if norm == T::ZERO {
+                singular = true;
+                vs.push(v);
+                continue;
+            }
+            let alpha = if v[0].is_sign_negative() { norm } else { -norm };
+            v[0] = v[0] - alpha;
+            let vv = dot(&v, &v);
+            for j in k..n {
+                let s = (k..m).fold(T::ZERO, |acc, i| acc + v[i - k] * a[(i, j)]);
+                let f = (s / vv).mul_pwr2(2.0);
+                for i in k..m {
+                    a[(i, j)] = a[(i, j)] - f * v[i - k];
+                }
+            }
+            vs.push(v);
+        }
+        Qr { r: a, vs, singular }
+    }
+
+    /// Solves the linear system `self * x = b` for `x`, returning `None` if the matrix is
+    /// singular.
+    ///
+    /// This uses the [`lu`] factorization. If more than one system with the same matrix
+    /// needs to be solved, it's faster to factor it once and use [`Lu::solve`].
+    ///
+    /// # Panics
+    /// Panics if the matrix isn't square or if the length of `b` doesn't match it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::{Matrix, Vector};
+    /// # use qd::{dd, Double};
+    /// let m = Matrix::new(2, 2, vec![dd!(2), dd!(1), dd!(1), dd!(3)]);
+    /// let b = Vector::new(vec![dd!(3), dd!(5)]);
+    /// let x = m.solve(&b).unwrap();
+    /// assert!((x[0] - dd!(0.8)).abs() < dd!(1e-31));
+    /// assert!((x[1] - dd!(1.4)).abs() < dd!(1e-31));
+    /// ```
+    ///
+    /// [`lu`]: #method.lu
+    /// [`Lu::solve`]: struct.Lu.html#method.solve
+    pub fn solve(&self, b: &Vector<T>) -> Option<Vector<T>> {
+        self.lu().solve(b)
+    }
+
+    /// Calculates the determinant of the matrix.
+    ///
+    /// # Panics
+    /// Panics if the matrix isn't square.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{qd, Quad};
+    /// let m = Matrix::new(2, 2, vec![qd!(1), qd!(2), qd!(3), qd!(4)]);
+    /// assert!((m.det() - qd!(-2)).abs() < qd!(1e-62));
+    /// ```
+    pub fn det(&self) -> T {
+        self.lu().det()
+    }
+
+    /// Calculates the inverse of the matrix, returning `None` if the matrix is singular.
+    ///
+    /// # Panics
+    /// Panics if the matrix isn't square.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{qd, Quad};
+    /// let m = Matrix::new(2, 2, vec![qd!(4), qd!(7), qd!(2), qd!(6)]);
+    /// let inv = m.inverse().unwrap();
+    /// assert!((inv[(0, 0)] - qd!(0.6)).abs() < qd!(1e-62));
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix<T>> {
+        self.lu().inverse()
+    }
+
+    /// Solves the linear system `self * x = b` with an `f64` LU factorization, then
+    /// improves the solution with mixed-precision [`refine`]ment.
+    ///
+    /// This is much faster than [`solve`] for larger matrices, since the factorization is
+    /// done in hardware arithmetic, and it reaches the same accuracy as long as the matrix
+    /// isn't so badly conditioned that the `f64` solution has no correct digits at all.
+    /// It returns `None` if the `f64` factorization of the matrix is singular.
+    ///
+    /// # Panics
+    /// Panics if the matrix isn't square or if the length of `b` doesn't match it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::{Matrix, Vector};
+    /// # use qd::{qd, Quad};
+    /// let m = Matrix::from_fn(6, 6, |i, j| qd!(1) / Quad::from((i + j + 1) as f64));
+    /// let x = Vector::new(vec![qd!(1); 6]);
+    /// let refined = m.refine(&(&m * &x), 50).unwrap();
+    /// assert!(refined.converged);
+    /// assert!((&refined.solution - &x).norm() < qd!(1e-55));
+    /// ```
+    ///
+    /// [`refine`]: fn.refine.html
+    /// [`solve`]: #method.solve
+    pub fn refine(&self, b: &Vector<T>, max_iterations: usize) -> Option<Refinement<T>> {
+        assert_eq!(self.rows, self.cols, "refinement of a non-square matrix");
+        let lu = LuF64::new(self);
+        refine(self, b, |r| lu.solve(r), max_iterations)
+    }
+
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        for k in 0..self.cols {
+            self.data.swap(i * self.cols + k, j * self.cols + k);
+        }
+    }
+}
+
+impl<T: MultiPrecision> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(j < self.cols, "column index out of bounds");
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T: MultiPrecision> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(j < self.cols, "column index out of bounds");
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl<T: MultiPrecision> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Negates every element of the matrix.
+    fn neg(self) -> Matrix<T> {
+        Matrix::new(
+            self.rows,
+            self.cols,
+            self.data.iter().map(|&x| -x).collect(),
+        )
+    }
+}
+
+impl<T: MultiPrecision> Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Adds two matrices element by element.
+    fn add(self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(
+            (self.rows, self.cols),
+            (other.rows, other.cols),
+            "matrix dimensions"
+        );
+        let data = self.data.iter().zip(other.data.iter());
+        Matrix::new(self.rows, self.cols, data.map(|(&a, &b)| a + b).collect())
+    }
+}
+
+impl<T: MultiPrecision> Sub for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Subtracts one matrix from another element by element.
+    fn sub(self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(
+            (self.rows, self.cols),
+            (other.rows, other.cols),
+            "matrix dimensions"
+        );
+        let data = self.data.iter().zip(other.data.iter());
+        Matrix::new(self.rows, self.cols, data.map(|(&a, &b)| a - b).collect())
+    }
+}
+
+impl<T: MultiPrecision> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Multiplies two matrices.
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.cols, other.rows, "matrix dimensions");
+        Matrix::from_fn(self.rows, other.cols, |i, j| {
+            (0..self.cols).fold(T::ZERO, |acc, k| acc + self[(i, k)] * other[(k, j)])
+        })
+    }
+}
+
+impl<T: MultiPrecision> Mul<&Vector<T>> for &Matrix<T> {
+    type Output = Vector<T>;
+
+    /// Multiplies a matrix by a column vector.
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.cols, v.len(), "matrix and vector dimensions");
+        Vector::new((0..self.rows).map(|i| dot(self.row(i), &v.data)).collect())
+    }
+}
+
+impl<T: MultiPrecision> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Multiplies every element of the matrix by a number.
+    fn mul(self, k: T) -> Matrix<T> {
+        Matrix::new(
+            self.rows,
+            self.cols,
+            self.data.iter().map(|&x| x * k).collect(),
+        )
+    }
+}
+
+/// The LU factorization of a square matrix, with partial pivoting.
+///
+/// The factorization is `P * A = L * U`, where `P` is a permutation matrix, `L` is lower
+/// triangular with ones on its diagonal, and `U` is upper triangular. It's created by
+/// [`Matrix::lu`].
+///
+/// [`Matrix::lu`]: struct.Matrix.html#method.lu
+#[derive(Clone, Debug)]
+pub struct Lu<T: MultiPrecision> {
+    // L below the diagonal and U on and above it
+    lu: Matrix<T>,
+    perm: Vec<usize>,
+    negative: bool,
+    singular: bool,
+}
+
+impl<T: MultiPrecision> Lu<T> {
+    /// Returns the lower triangular factor `L`.
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.lu.rows;
+        Matrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => self.lu[(i, j)],
+            std::cmp::Ordering::Equal => T::ONE,
+            std::cmp::Ordering::Less => T::ZERO,
+        })
+    }
+
+    /// Returns the upper triangular factor `U`.
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.lu.rows;
+        Matrix::from_fn(n, n, |i, j| if i <= j { self.lu[(i, j)] } else { T::ZERO })
+    }
+
+    /// Returns the permutation, as the row of the original matrix that each row of `L * U`
+    /// came from.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::Matrix;
+    /// # use qd::{dd, Double};
+    /// let m = Matrix::new(2, 2, vec![dd!(1), dd!(2), dd!(3), dd!(4)]);
+    /// let lu = m.lu();
+    /// assert_eq!(lu.permutation(), &[1, 0]);
+    /// let swapped = Matrix::new(2, 2, vec![dd!(3), dd!(4), dd!(1), dd!(2)]);
+    /// assert_eq!(&lu.l() * &lu.u(), swapped);
+    /// ```
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Returns `true` if the matrix is singular, meaning that a pivot was exactly zero.
+    ///
+    /// A matrix that's singular in exact arithmetic may not be found to be singular here
+    /// because of rounding, in which case its factors have elements that are huge compared
+    /// to those of the matrix.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Solves the linear system `A * x = b` for `x`, returning `None` if the matrix is
+    /// singular.
+    ///
+    /// # Panics
+    /// Panics if the length of `b` doesn't match the matrix.
+    pub fn solve(&self, b: &Vector<T>) -> Option<Vector<T>> {
+        let n = self.lu.rows;
+        assert_eq!(b.len(), n, "matrix and vector dimensions");
+        if self.singular {
+            return None;
+        }
+        let mut x: Vec<T> = self.perm.iter().map(|&p| b[p]).collect();
+        for i in 0..n {
+            x[i] = x[i] - dot(&self.lu.row(i)[..i], &x[..i]);
+        }
+        for i in (0..n).rev() {
+            x[i] = (x[i] - dot(&self.lu.row(i)[i + 1..], &x[i + 1..])) / self.lu[(i, i)];
+        }
+        Some(Vector::new(x))
+    }
+
+    /// Calculates the determinant of the matrix.
+    pub fn det(&self) -> T {
+        if self.singular {
+            return T::ZERO;
+        }
+        let d = (0..self.lu.rows).fold(T::ONE, |acc, i| acc * self.lu[(i, i)]);
+        if self.negative {
+            -d
+        } else {
+            d
+        }
+    }
+
+    /// Calculates the inverse of the matrix, returning `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix<T>> {
+        let n = self.lu.rows;
+        let mut inv = Matrix::zeros(n, n);
+        for j in 0..n {
+            let mut e = Vector::zeros(n);
+            e[j] = T::ONE;
+            let col = self.solve(&e)?;
+            for i in 0..n {
+                inv[(i, j)] = col[i];
+            }
+        }
+        Some(inv)
+    }
+}
+
+/// The QR factorization of a matrix with at least as many rows as columns.
+///
+/// The factorization is `A = Q * R`, where `Q` has orthonormal columns and `R` is upper
+/// triangular. It's created by [`Matrix::qr`] and is computed with Householder
+/// reflections, which keep `Q` orthogonal to full precision.
+///
+/// [`Matrix::qr`]: struct.Matrix.html#method.qr
+#[derive(Clone, Debug)]
+pub struct Qr<T: MultiPrecision> {
+    // R on and above the diagonal, with what's below the diagonal unused
+    r: Matrix<T>,
+    // The Householder vectors, one per column
+    vs: Vec<Vec<T>>,
+    singular: bool,
+}
+
+impl<T: MultiPrecision> Qr<T> {
+    /// Returns the factor `Q`, which has as many rows and columns as the original matrix.
+    pub fn q(&self) -> Matrix<T> {
+        let (m, n) = (self.r.rows, self.r.cols);
+        let mut q = Matrix::zeros(m, n);
+        for j in 0..n {
+            let mut e = vec![T::ZERO; m];
+            e[j] = T::ONE;
+            // Q is the product of the reflections, so its columns are the reflections of
+            // the unit vectors, applied in reverse order
+            for k in (0..n).rev() {
+                self.reflect(k, &mut e);
+            }
+            for i in 0..m {
+                q[(i, j)] = e[i];
+            }
+        }
+        q
+    }
+
+    /// Returns the factor `R`, which is square with as many rows as the original matrix
+    /// has columns.
+    pub fn r(&self) -> Matrix<T> {
+        let n = self.r.cols;
+        Matrix::from_fn(n, n, |i, j| if i <= j { self.r[(i, j)] } else { T::ZERO })
+    }
+
+    /// Returns `true` if the columns of the matrix are linearly dependent, meaning that a
+    /// diagonal element of `R` is exactly zero.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Finds the `x` that minimizes the norm of `A * x - b`, returning `None` if the
+    /// columns of the matrix are linearly dependent.
+    ///
+    /// If the matrix is square, this solves the linear system `A * x = b`.
+    ///
+    /// # Panics
+    /// Panics if the length of `b` doesn't match the number of rows of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::linalg::{Matrix, Vector};
+    /// # use qd::{dd, Double};
+    /// // The line through (0, 1), (1, 3), and (2, 5) is y = 1 + 2x
+    /// let m = Matrix::new(3, 2, vec![dd!(1), dd!(0), dd!(1), dd!(1), dd!(1), dd!(2)]);
+    /// let b = Vector::new(vec![dd!(1), dd!(3), dd!(5)]);
+    /// let x = m.qr().solve(&b).unwrap();
+    /// assert!((x[0] - dd!(1)).abs() < dd!(1e-30));
+    /// assert!((x[1] - dd!(2)).abs() < dd!(1e-30));
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Option<Vector<T>> {
+        let (m, n) = (self.r.rows, self.r.cols);
+        assert_eq!(b.len(), m, "matrix and vector dimensions");
+        if self.singular {
+            return None;
+        }
+        let mut y = b.data.clone();
+        for k in 0..n {
+            self.reflect(k, &mut y);
+        }
+        let mut x = vec![T::ZERO; n];
+        for i in (0..n).rev() {
+            x[i] = (y[i] - dot(&self.r.row(i)[i + 1..], &x[i + 1..])) / self.r[(i, i)];
+        }
+        Some(Vector::new(x))
+    }
+
+    // Applies the kth Householder reflection to a vector.
+    fn reflect(&self, k: usize, x: &mut [T]) {
+        let v = &self.vs[k];
+        let vv = dot(v, v);
+        if vv == T::ZERO {
+            return;
+        }
+        let f = (dot(v, &x[k..]) / vv).mul_pwr2(2.0);
+        for (xi, &vi) in x[k..].iter_mut().zip(v.iter()) {
+            *xi = *xi - f * vi;
+        }
+    }
+}
+
+/// The result of a mixed-precision [`refine`]ment.
+///
+/// [`refine`]: fn.refine.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct Refinement<T: MultiPrecision> {
+    /// The refined solution.
+    pub solution: Vector<T>,
+    /// An estimate of the relative error of the solution, which is the size of the last
+    /// correction compared to the size of the solution (using the largest elements of
+    /// each).
+    pub error: T,
+    /// The number of corrections that were calculated after the initial `f64` solution,
+    /// including a final one that may have been discarded.
+    pub iterations: usize,
+    /// Whether the corrections got smaller and then stopped at the limit of the working
+    /// precision. This is `false` if `max_iterations` was reached first or if the
+    /// corrections never got smaller at all, which means that the matrix is too badly
+    /// conditioned for the `f64` solver to make progress.
+    pub converged: bool,
+}
+
+/// Solves the linear system `a * x = b` by mixed-precision iterative refinement.
+///
+/// `solve` is a solver for the system in `f64` arithmetic, typically one that reuses a
+/// factorization of the matrix rounded to `f64`. It's called once to find an initial
+/// solution, and then once per iteration on the residual `b - a * x`, which is calculated
+/// in the full precision of `a` and `b`. Each of these corrections gains roughly as many
+/// correct digits as the `f64` solution had, so the expensive work is all done in `f64`
+/// and only the residuals (which take time proportional to the square of the size of the
+/// matrix) are done in extended precision.
+///
+/// Refinement stops when a correction is smaller than [`EPSILON`] relative to the
+/// solution, when a correction is no smaller than half of the previous one (which is then
+/// discarded), or after `max_iterations` corrections. The second usually happens first for
+/// ill-conditioned matrices, since rounding in the residual keeps the corrections from
+/// shrinking any further once the solution is as accurate as the working precision allows.
+///
+/// This returns `None` if `solve` returns `None` or if the solution isn't finite.
+///
+/// # Panics
+/// Panics if the matrix isn't square, if the length of `b` doesn't match it, or if `solve`
+/// returns a vector of the wrong length.
+///
+/// # Examples
+/// ```
+/// # use qd::linalg::{refine, Matrix, Vector};
+/// # use qd::{dd, Double};
+/// let m = Matrix::new(2, 2, vec![dd!(4), dd!(1), dd!(1), dd!(3)]);
+/// let b = Vector::new(vec![dd!(1), dd!(2)]);
+///
+/// // Cramer's rule in f64
+/// let solve = |r: &[f64]| {
+///     Some(vec![(3.0 * r[0] - r[1]) / 11.0, (4.0 * r[1] - r[0]) / 11.0])
+/// };
+/// let refined = refine(&m, &b, solve, 10).unwrap();
+/// assert!((refined.solution[0] - dd!(1) / dd!(11)).abs() < dd!(1e-31));
+/// assert!((refined.solution[1] - dd!(7) / dd!(11)).abs() < dd!(1e-31));
+/// ```
+///
+/// [`EPSILON`]: crate::MultiPrecision::EPSILON
+pub fn refine<T, F>(
+    a: &Matrix<T>,
+    b: &Vector<T>,
+    mut solve: F,
+    max_iterations: usize,
+) -> Option<Refinement<T>>
+where
+    T: MultiPrecision,
+    F: FnMut(&[f64]) -> Option<Vec<f64>>,
+{
+    assert_eq!(a.rows, a.cols, "refinement of a non-square matrix");
+    assert_eq!(b.len(), a.rows, "matrix and vector dimensions");
+    let mut f64_solve = |v: &[T]| -> Option<Vec<T>> {
+        let rounded: Vec<f64> = v.iter().map(|&x| x.into()).collect();
+        let solved = solve(&rounded)?;
+        assert_eq!(
+            solved.len(),
+            v.len(),
+            "solver returned a vector of the wrong length"
+        );
+        Some(solved.into_iter().map(T::from).collect())
+    };
+
+    let mut x = f64_solve(&b.data)?;
+    let mut error = T::NAN;
+    let mut iterations = 0;
+    let mut converged = false;
+    let mut contracted = false;
+
+    while iterations < max_iterations {
+        let residual: Vec<T> = (0..a.rows).map(|i| b[i] - dot(a.row(i), &x)).collect();
+        let d = f64_solve(&residual)?;
+        iterations += 1;
+
+        let size = max_norm(&x);
+        let correction = max_norm(&d);
+        let e = if size == T::ZERO {
+            correction
+        } else {
+            correction / size
+        };
+        if !e.is_finite() {
+            return None;
+        }
+        // A correction that isn't much smaller than the last one means that the solver is
+        // only adding noise, so it's better not to apply it
+        if e > error.mul_pwr2(0.5) {
+            converged = contracted;
+            break;
+        }
+        for (xi, &di) in x.iter_mut().zip(d.iter()) {
+            *xi = *xi + di;
+        }
+        contracted = !error.is_nan();
+        error = e;
+        if e <= T::EPSILON {
+            converged = true;
+            break;
+        }
+    }
+
+    if x.iter().all(|xi| xi.is_finite()) {
+        Some(Refinement {
+            solution: Vector::new(x),
+            error,
+            iterations,
+            converged,
+        })
+    } else {
+        None
+    }
+}
+
+// An LU factorization in f64 arithmetic, for refining solutions in `Matrix::refine`.
+struct LuF64 {
+    n: usize,
+    lu: Vec<f64>,
+    perm: Vec<usize>,
+    singular: bool,
+}
+
+impl LuF64 {
+    fn new<T: MultiPrecision>(a: &Matrix<T>) -> LuF64 {
+        let n = a.rows;
+        let mut lu: Vec<f64> = a.data.iter().map(|&x| x.into()).collect();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut singular = false;
+
+        for k in 0..n {
+            let p = (k..n)
+                .max_by(|&i, &j| lu[i * n + k].abs().total_cmp(&lu[j * n + k].abs()))
+                .unwrap();
+            if lu[p * n + k] == 0.0 {
+                singular = true;
+                continue;
+            }
+            if p != k {
+                for j in 0..n {
+                    lu.swap(p * n + j, k * n + j);
+                }
+                perm.swap(p, k);
+            }
+            let pivot = lu[k * n + k];
+            for i in k + 1..n {
+                let factor = lu[i * n + k] / pivot;
+                lu[i * n + k] = factor;
+                for j in k + 1..n {
+                    lu[i * n + j] -= factor * lu[k * n + j];
+                }
+            }
+        }
+        LuF64 {
+            n,
+            lu,
+            perm,
+            singular,
+        }
+    }
+
+    fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        if self.singular {
+            return None;
+        }
+        let n = self.n;
+        let mut x: Vec<f64> = self.perm.iter().map(|&p| b[p]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= self.lu[i * n + j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= self.lu[i * n + j] * x[j];
+            }
+            x[i] /= self.lu[i * n + i];
+        }
+        Some(x)
+    }
+}
+
+// The largest absolute value in a slice.
+fn max_norm<T: MultiPrecision>(a: &[T]) -> T {
+    a.iter()
+        .fold(T::ZERO, |acc, &x| if x.abs() > acc { x.abs() } else { acc })
+}
+
+// The dot product of two slices of the same length.
+fn dot<T: MultiPrecision>(a: &[T], b: &[T]) -> T {
+    a.iter()
+        .zip(b.iter())
+        .fold(T::ZERO, |acc, (&x, &y)| acc + x * y)
+}
+
+macro_rules! owned_ops {
+    ($type:ident: $($trait:ident $method:ident),*) => {$(
+        impl<T: MultiPrecision> $trait for $type<T> {
+            type Output = $type<T>;
+
+            #[inline]
+            fn $method(self, other: $type<T>) -> $type<T> {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<T: MultiPrecision> $trait<&$type<T>> for $type<T> {
+            type Output = $type<T>;
+
+            #[inline]
+            fn $method(self, other: &$type<T>) -> $type<T> {
+                (&self).$method(other)
+            }
+        }
+
+        impl<T: MultiPrecision> $trait<$type<T>> for &$type<T> {
+            type Output = $type<T>;
+
+            #[inline]
+            fn $method(self, other: $type<T>) -> $type<T> {
+                self.$method(&other)
+            }
+        }
+    )*};
+}
+
+owned_ops!(Vector: Add add, Sub sub);
+owned_ops!(Matrix: Add add, Sub sub, Mul mul);
+
+impl<T: MultiPrecision> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline]
+    fn neg(self) -> Vector<T> {
+        -&self
+    }
+}
+
+impl<T: MultiPrecision> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline]
+    fn neg(self) -> Matrix<T> {
+        -&self
+    }
+}
+
+impl<T: MultiPrecision> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    #[inline]
+    fn mul(self, k: T) -> Vector<T> {
+        &self * k
+    }
+}
+
+impl<T: MultiPrecision> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    #[inline]
+    fn mul(self, k: T) -> Matrix<T> {
+        &self * k
+    }
+}
+
+impl<T: MultiPrecision> Mul<Vector<T>> for Matrix<T> {
+    type Output = Vector<T>;
+
+    #[inline]
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        &self * &v
+    }
+}
+
+impl<T: MultiPrecision> Mul<&Vector<T>> for Matrix<T> {
+    type Output = Vector<T>;
+
+    #[inline]
+    fn mul(self, v: &Vector<T>) -> Vector<T> {
+        &self * v
+    }
+}
+
+impl<T: MultiPrecision> Mul<Vector<T>> for &Matrix<T> {
+    type Output = Vector<T>;
+
+    #[inline]
+    fn mul(self, v: Vector<T>) -> Vector<T> {
+        self * &v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd, Double, Quad};
+
+    fn hilbert<T: MultiPrecision>(n: usize) -> Matrix<T> {
+        Matrix::from_fn(n, n, |i, j| T::ONE / T::from((i + j + 1) as f64))
+    }
+
+    #[test]
+    fn vector_ops() {
+        let a = Vector::new(vec![dd!(1), dd!(2), dd!(2)]);
+        let b = Vector::from(vec![dd!(3), dd!(0), dd!(-1)]);
+        assert!((a.norm() - dd!(3)).abs() < dd!(1e-30));
+        assert_eq!(a.dot(&b), dd!(1));
+        assert_eq!(&a + &b, Vector::new(vec![dd!(4), dd!(2), dd!(1)]));
+        assert_eq!(
+            a.clone() - b.clone(),
+            Vector::new(vec![dd!(-2), dd!(2), dd!(3)])
+        );
+        assert_eq!(
+            -a.clone() * dd!(2),
+            Vector::new(vec![dd!(-2), dd!(-4), dd!(-4)])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn vector_mismatch() {
+        let _ = Vector::<Quad>::zeros(2) + Vector::zeros(3);
+    }
+
+    #[test]
+    fn matrix_ops() {
+        let a = Matrix::new(2, 3, vec![dd!(1), dd!(2), dd!(3), dd!(4), dd!(5), dd!(6)]);
+        let b = a.transpose();
+        assert_eq!(
+            &a * &b,
+            Matrix::new(2, 2, vec![dd!(14), dd!(32), dd!(32), dd!(77)])
+        );
+        assert_eq!(&a - &a, Matrix::zeros(2, 3));
+        assert_eq!(a.clone() + a.clone(), &a * dd!(2));
+        assert_eq!(&Matrix::identity(2) * &a, a);
+        let v = Vector::new(vec![dd!(1), dd!(0), dd!(-1)]);
+        assert_eq!(&a * &v, Vector::new(vec![dd!(-2), dd!(-2)]));
+    }
+
+    #[test]
+    fn double_hilbert() {
+        let n = 8;
+        let h = hilbert::<Double>(n);
+        let x = Vector::new((1..=n).map(|i| Double::from(i as f64)).collect());
+        let b = &h * &x;
+        assert!((&h.solve(&b).unwrap() - &x).norm() < dd!(1e-20));
+        assert!((&h.qr().solve(&b).unwrap() - &x).norm() < dd!(1e-20));
+    }
+
+    #[test]
+    fn quad_hilbert() {
+        let n = 12;
+        let h = hilbert::<Quad>(n);
+        let x = Vector::new(vec![qd!(1); n]);
+        let b = &h * &x;
+        assert!((&h.solve(&b).unwrap() - &x).norm() < qd!(1e-45));
+        assert!((&h.qr().solve(&b).unwrap() - &x).norm() < qd!(1e-45));
+        // The inverse of a Hilbert matrix has integer elements
+        let inv = h.inverse().unwrap();
+        assert!((inv[(0, 0)] - qd!(144)).abs() < qd!(1e-40));
+        assert!(max_norm(&(&(&h * &inv) - &Matrix::identity(n)).data) < qd!(1e-45));
+    }
+
+    #[test]
+    fn determinants() {
+        // The determinant of the 4 × 4 Hilbert matrix is 1 / 6048000
+        let d = hilbert::<Quad>(4).det();
+        assert!((d * qd!(6048000) - qd!(1)).abs() < qd!(1e-60));
+        let m = Matrix::new(
+            3,
+            3,
+            vec![
+                dd!(0),
+                dd!(1),
+                dd!(2),
+                dd!(1),
+                dd!(0),
+                dd!(3),
+                dd!(4),
+                dd!(-3),
+                dd!(8),
+            ],
+        );
+        assert!((m.det() - dd!(-2)).abs() < dd!(1e-30));
+        assert_eq!(Matrix::<Double>::identity(5).det(), dd!(1));
+    }
+
+    #[test]
+    fn factors() {
+        let m = Matrix::new(
+            3,
+            3,
+            vec![
+                qd!(2),
+                qd!(-1),
+                qd!(0),
+                qd!(-1),
+                qd!(2),
+                qd!(-1),
+                qd!(0),
+                qd!(-1),
+                qd!(2),
+            ],
+        );
+        let lu = m.lu();
+        let pm = Matrix::from_fn(3, 3, |i, j| m[(lu.permutation()[i], j)]);
+        assert!(max_norm(&(&(&lu.l() * &lu.u()) - &pm).data) < qd!(1e-62));
+
+        let qr = m.qr();
+        let q = qr.q();
+        assert!(max_norm(&(&(&q * &qr.r()) - &m).data) < qd!(1e-62));
+        assert!(max_norm(&(&(&q.transpose() * &q) - &Matrix::identity(3)).data) < qd!(1e-62));
+    }
+
+    #[test]
+    fn least_squares() {
+        // Fitting a quadratic through points that are exactly on y = 1 - x + x²
+        let xs: Vec<Quad> = (0..6).map(|i| Quad::from(i as f64) / qd!(5)).collect();
+        let a = Matrix::from_fn(6, 3, |i, j| xs[i].powi(j as i64));
+        let b = Vector::new(xs.iter().map(|&x| qd!(1) - x + x * x).collect());
+        let c = a.qr().solve(&b).unwrap();
+        assert!((&c - &Vector::new(vec![qd!(1), qd!(-1), qd!(1)])).norm() < qd!(1e-60));
+    }
+
+    #[test]
+    fn singular() {
+        let m = Matrix::new(2, 2, vec![dd!(1), dd!(2), dd!(2), dd!(4)]);
+        let b = Vector::new(vec![dd!(1), dd!(1)]);
+        assert!(m.lu().is_singular());
+        assert_eq!(m.solve(&b), None);
+        assert_eq!(m.inverse(), None);
+        assert_eq!(m.det(), dd!(0));
+
+        // Householder reflections don't produce an exact zero for dependent columns unless
+        // one of them is zero to begin with
+        let m = Matrix::new(2, 2, vec![dd!(1), dd!(0), dd!(2), dd!(0)]);
+        assert!(m.qr().is_singular());
+        assert_eq!(m.qr().solve(&b), None);
+    }
+
+    #[test]
+    fn refinement() {
+        let n = 8;
+        let h = hilbert::<Quad>(n);
+        let x = Vector::new((1..=n).map(|i| Quad::from(i as f64)).collect());
+        let b = &h * &x;
+        let refined = h.refine(&b, 50).unwrap();
+        assert!(refined.converged);
+        assert!(refined.error < qd!(1e-50));
+        assert!((&refined.solution - &x).norm() < qd!(1e-50));
+
+        let h = hilbert::<Double>(n);
+        let b = &h * &Vector::new(vec![dd!(1); n]);
+        let refined = h.refine(&b, 50).unwrap();
+        assert!(refined.converged);
+        assert!((&refined.solution - &Vector::new(vec![dd!(1); n])).norm() < dd!(1e-20));
+    }
+
+    #[test]
+    fn refinement_stagnation() {
+        // An f64 solver that's only accurate to a few digits still converges
+        let m = Matrix::new(2, 2, vec![qd!(2), qd!(1), qd!(1), qd!(2)]);
+        let b = Vector::new(vec![qd!(1), qd!(1)]);
+        let sloppy = |r: &[f64]| {
+            let x = (2.0 * r[0] - r[1]) / 3.0;
+            let y = (2.0 * r[1] - r[0]) / 3.0;
+            Some(vec![x * (1.0 + 1e-4), y * (1.0 - 1e-4)])
+        };
+        let refined = refine(&m, &b, sloppy, 100).unwrap();
+        assert!(refined.converged);
+        assert!((refined.solution[0] - qd!(1) / qd!(3)).abs() < qd!(1e-62));
+
+        // One that's only noise makes no progress at all
+        let noisy = |r: &[f64]| Some(vec![r[0] + 1.0, r[1] - 1.0]);
+        let refined = refine(&m, &b, noisy, 100).unwrap();
+        assert!(!refined.converged);
+        assert!(refined.iterations < 100);
+
+        let singular = Matrix::new(2, 2, vec![qd!(1), qd!(2), qd!(2), qd!(4)]);
+        assert_eq!(singular.refine(&b, 10), None);
+    }
+}
@@ -0,0 +1,581 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Floating-point numbers with any number of `f64` components.
+//!
+//! [`MultiFloat<N>`] represents a number as the unevaluated sum of `N` non-overlapping
+//! `f64`s, just as [`Double`] does with two and [`Quad`] does with four, giving roughly
+//! `16 * N` decimal digits of precision. The number of components is a const generic
+//! parameter, so the precision can be chosen where the type is used, including
+//! precisions above that of a `Quad`.
+//!
+//! All precisions share the same algorithms. Sums and products are computed as exact
+//! expansions of `f64`s and then distilled into `N` components, and division and square
+//! roots are built on top of those. This is general but slower than the algorithms
+//! specialized for two and four components, so `Double` and `Quad` remain separate types
+//! and are still the better choice at their precisions. Values convert freely between
+//! `MultiFloat`s and the other types.
+//!
+//! Only the arithmetic operations, the square root, and comparisons are provided.
+//!
+//! # Examples
+//! ```
+//! use qd::multi::MultiFloat;
+//! use qd::{qd, Quad};
+//!
+//! // √2 to about 128 decimal digits
+//! let two = MultiFloat::<8>::from(2.0);
+//! let root = two.sqrt();
+//! assert!((root * root - two).abs() < MultiFloat::from(1e-125));
+//!
+//! // Four components agree with a Quad
+//! let third = MultiFloat::<4>::from(1.0) / MultiFloat::from(3.0);
+//! assert!((Quad::from(third) - qd!(1) / qd!(3)).abs() < qd!(1e-63));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`MultiFloat<N>`]: crate::multi::MultiFloat
+
+use crate::common::primitive as p;
+use crate::common::utils as u;
+use crate::double::Double;
+use crate::quad::Quad;
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A number represented by the unevaluated sum of `N` non-overlapping `f64` components.
+///
+/// `MultiFloat<2>` has the precision of a [`Double`] and `MultiFloat<4>` the precision of a
+/// [`Quad`], but any number of components can be used. `N` must be at least 1; operations
+/// on a `MultiFloat<0>` panic.
+///
+/// As with `Double` and `Quad`, the components are ordered from the largest to the
+/// smallest, and each of them is no more than half an ulp of the one before it.
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MultiFloat<const N: usize>([f64; N]);
+
+impl<const N: usize> MultiFloat<N> {
+    /// The number zero.
+    pub const ZERO: MultiFloat<N> = MultiFloat([0.0; N]);
+
+    /// The number one.
+    pub const ONE: MultiFloat<N> = MultiFloat::from_leading(1.0);
+
+    /// Not a number.
+    pub const NAN: MultiFloat<N> = MultiFloat::from_leading(f64::NAN);
+
+    /// Positive infinity.
+    pub const INFINITY: MultiFloat<N> = MultiFloat::from_leading(f64::INFINITY);
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: MultiFloat<N> = MultiFloat::from_leading(f64::NEG_INFINITY);
+
+    /// Creates a new `MultiFloat` from its components, without normalizing them.
+    ///
+    /// Like [`Double::new`] and [`Quad::new`], this should only be used when the
+    /// components are already known to be normalized. Use [`from_parts`] otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::multi::MultiFloat;
+    /// let x = MultiFloat::new([1.0, 1e-20, 0.0]);
+    /// assert_eq!(x[1], 1e-20);
+    /// ```
+    ///
+    /// [`Double::new`]: crate::Double::new
+    /// [`Quad::new`]: crate::Quad::new
+    /// [`from_parts`]: #method.from_parts
+    pub const fn new(components: [f64; N]) -> MultiFloat<N> {
+        MultiFloat(components)
+    }
+
+    /// Creates a new `MultiFloat` from the exact sum of any number of `f64`s.
+    ///
+    /// The sum is rounded to `N` components if it needs more than that.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::multi::MultiFloat;
+    /// let x = MultiFloat::<3>::from_parts(&[1e-40, 1.0, 1e-20]);
+    /// assert_eq!(x.components(), [1.0, 1e-20, 1e-40]);
+    /// ```
+    pub fn from_parts(parts: &[f64]) -> MultiFloat<N> {
+        if parts.iter().any(|x| !x.is_finite()) {
+            // Infinities of opposite signs sum to NaN, as they should
+            return MultiFloat::from_leading(parts.iter().filter(|x| !x.is_finite()).sum());
+        }
+        let mut xs = parts.to_vec();
+        xs.resize(parts.len().max(N), 0.0);
+        // Distillation works best when the terms are summed from the smallest up
+        xs.sort_by(|a, b| a.abs().partial_cmp(&b.abs()).unwrap());
+        let mut out = [0.0; N];
+        u::distill(&mut xs, &mut out);
+        MultiFloat(out)
+    }
+
+    /// Returns the components of the number, from the largest to the smallest.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::multi::MultiFloat;
+    /// let x = MultiFloat::<2>::from(0.5);
+    /// assert_eq!(x.components(), [0.5, 0.0]);
+    /// ```
+    pub const fn components(self) -> [f64; N] {
+        self.0
+    }
+
+    /// Returns the absolute value of the number.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::multi::MultiFloat;
+    /// let x = MultiFloat::<6>::from(-2.5);
+    /// assert_eq!(x.abs(), MultiFloat::from(2.5));
+    /// ```
+    pub fn abs(self) -> MultiFloat<N> {
+        if self.0[0].is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Calculates the square of the number.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::multi::MultiFloat;
+    /// let x = MultiFloat::<6>::from(3.0);
+    /// assert_eq!(x.sqr(), MultiFloat::from(9.0));
+    /// ```
+    pub fn sqr(self) -> MultiFloat<N> {
+        self * self
+    }
+
+    /// Calculates the reciprocal of the number.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::multi::MultiFloat;
+    /// let x = MultiFloat::<6>::from(4.0);
+    /// assert_eq!(x.recip(), MultiFloat::from(0.25));
+    /// ```
+    pub fn recip(self) -> MultiFloat<N> {
+        MultiFloat::ONE / self
+    }
+
+    /// Calculates the square root of the number.
+    ///
+    /// The square root of a negative number is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::multi::MultiFloat;
+    /// let x = MultiFloat::<6>::from(2.0).sqrt();
+    /// assert!((x * x - MultiFloat::from(2.0)).abs() < MultiFloat::from(1e-94));
+    /// ```
+    pub fn sqrt(self) -> MultiFloat<N> {
+        let a = self.0[0];
+        if a == 0.0 || a.is_nan() || a == f64::INFINITY {
+            self
+        } else if a < 0.0 {
+            MultiFloat::NAN
+        } else {
+            // Newton's iteration for 1/√x, which doubles the number of correct bits each
+            // time, and then one more step to correct the last bits of √x itself, using the
+            // exact residual
+            let mut x = MultiFloat::from(1.0 / a.sqrt());
+            let half = self.mul_f64(0.5);
+            let mut bits = 53;
+            while bits < 53 * N + 53 {
+                x += x * (MultiFloat::from(0.5) - half * x.sqr());
+                bits *= 2;
+            }
+            let r = self * x;
+            let mut xs = self.0.to_vec();
+            for &a in &r.0 {
+                for &b in &r.0 {
+                    let (p, e) = p::two_prod(-a, b);
+                    xs.push(p);
+                    xs.push(e);
+                }
+            }
+            r + x * MultiFloat::from_parts(&xs).mul_f64(0.5)
+        }
+    }
+
+    /// Returns `true` if the number is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::multi::MultiFloat;
+    /// assert!(MultiFloat::<3>::NAN.is_nan());
+    /// assert!(!MultiFloat::<3>::ONE.is_nan());
+    /// ```
+    pub fn is_nan(self) -> bool {
+        self.0[0].is_nan()
+    }
+
+    /// Returns `true` if the number is positive or negative infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::multi::MultiFloat;
+    /// assert!(MultiFloat::<3>::NEG_INFINITY.is_infinite());
+    /// assert!(!MultiFloat::<3>::ONE.is_infinite());
+    /// ```
+    pub fn is_infinite(self) -> bool {
+        self.0[0].is_infinite()
+    }
+
+    /// Returns `true` if the number is neither infinite nor NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::multi::MultiFloat;
+    /// assert!(MultiFloat::<3>::ONE.is_finite());
+    /// assert!(!MultiFloat::<3>::NAN.is_finite());
+    /// ```
+    pub fn is_finite(self) -> bool {
+        self.0[0].is_finite()
+    }
+
+    // Creates a number whose first component is `a` and whose others are zero.
+    const fn from_leading(a: f64) -> MultiFloat<N> {
+        let mut xs = [0.0; N];
+        if N > 0 {
+            xs[0] = a;
+        }
+        MultiFloat(xs)
+    }
+
+    // Multiplies the number by a single `f64`, exactly before the final rounding.
+    fn mul_f64(self, b: f64) -> MultiFloat<N> {
+        let mut xs = Vec::with_capacity(2 * N);
+        for &a in &self.0 {
+            let (p, e) = p::two_prod(a, b);
+            xs.push(p);
+            xs.push(e);
+        }
+        MultiFloat::from_parts(&xs)
+    }
+
+    // Returns the result of an operation if the same operation on the leading components
+    // alone doesn't give a finite number, in which case that's the result.
+    fn special(a: f64, b: f64, f: impl Fn(f64, f64) -> f64) -> Option<MultiFloat<N>> {
+        let r = f(a, b);
+        if r.is_finite() {
+            None
+        } else {
+            Some(MultiFloat::from_leading(r))
+        }
+    }
+}
+
+impl<const N: usize> Index<usize> for MultiFloat<N> {
+    type Output = f64;
+
+    /// Returns one of the components of the number.
+    ///
+    /// # Panics
+    /// The index must be less than `N`.
+    fn index(&self, idx: usize) -> &f64 {
+        &self.0[idx]
+    }
+}
+
+impl<const N: usize> Neg for MultiFloat<N> {
+    type Output = MultiFloat<N>;
+
+    /// Negates the number.
+    fn neg(self) -> MultiFloat<N> {
+        let mut xs = self.0;
+        for x in xs.iter_mut() {
+            *x = -*x;
+        }
+        MultiFloat(xs)
+    }
+}
+
+impl<const N: usize> Add for MultiFloat<N> {
+    type Output = MultiFloat<N>;
+
+    /// Adds two numbers.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: MultiFloat<N>) -> MultiFloat<N> {
+        if let Some(r) = MultiFloat::special(self.0[0], other.0[0], |a, b| a + b) {
+            return r;
+        }
+        let mut xs = Vec::with_capacity(2 * N);
+        xs.extend_from_slice(&self.0);
+        xs.extend_from_slice(&other.0);
+        MultiFloat::from_parts(&xs)
+    }
+}
+
+impl<const N: usize> Sub for MultiFloat<N> {
+    type Output = MultiFloat<N>;
+
+    /// Subtracts one number from another.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: MultiFloat<N>) -> MultiFloat<N> {
+        self + -other
+    }
+}
+
+impl<const N: usize> Mul for MultiFloat<N> {
+    type Output = MultiFloat<N>;
+
+    /// Multiplies two numbers.
+    ///
+    /// Products of components that are too small to affect the result are skipped, and
+    /// those that are just big enough to affect it are computed without their errors.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, other: MultiFloat<N>) -> MultiFloat<N> {
+        if let Some(r) = MultiFloat::special(self.0[0], other.0[0], |a, b| a * b) {
+            return r;
+        }
+        let mut xs = Vec::with_capacity(N * (N + 1));
+        for i in 0..N {
+            for j in 0..N - i {
+                let (p, e) = p::two_prod(self.0[i], other.0[j]);
+                xs.push(p);
+                xs.push(e);
+            }
+            if i > 0 {
+                xs.push(self.0[i] * other.0[N - i]);
+            }
+        }
+        MultiFloat::from_parts(&xs)
+    }
+}
+
+impl<const N: usize> Div for MultiFloat<N> {
+    type Output = MultiFloat<N>;
+
+    /// Divides one number by another.
+    ///
+    /// This is long division: each quotient term is the leading component of the remainder
+    /// divided by the leading component of the divisor, and one more term is computed than
+    /// there are components so that the last one can be rounded.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: MultiFloat<N>) -> MultiFloat<N> {
+        if let Some(r) = MultiFloat::special(self.0[0], other.0[0], |a, b| a / b) {
+            return r;
+        }
+        let mut q = Vec::with_capacity(N + 1);
+        let mut r = self;
+        for _ in 0..=N {
+            let t = r.0[0] / other.0[0];
+            q.push(t);
+            r -= other.mul_f64(t);
+        }
+        MultiFloat::from_parts(&q)
+    }
+}
+
+impl<const N: usize> AddAssign for MultiFloat<N> {
+    /// Adds another number to this one in place.
+    fn add_assign(&mut self, other: MultiFloat<N>) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> SubAssign for MultiFloat<N> {
+    /// Subtracts another number from this one in place.
+    fn sub_assign(&mut self, other: MultiFloat<N>) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: usize> MulAssign for MultiFloat<N> {
+    /// Multiplies this number by another in place.
+    fn mul_assign(&mut self, other: MultiFloat<N>) {
+        *self = *self * other;
+    }
+}
+
+impl<const N: usize> DivAssign for MultiFloat<N> {
+    /// Divides this number by another in place.
+    fn div_assign(&mut self, other: MultiFloat<N>) {
+        *self = *self / other;
+    }
+}
+
+impl<const N: usize> PartialOrd for MultiFloat<N> {
+    /// Compares two numbers.
+    ///
+    /// Normalized numbers compare in the same order as their components do, taken from
+    /// the largest to the smallest.
+    fn partial_cmp(&self, other: &MultiFloat<N>) -> Option<Ordering> {
+        for (a, b) in self.0.iter().zip(other.0.iter()) {
+            match a.partial_cmp(b)? {
+                Ordering::Equal => continue,
+                ord => return Some(ord),
+            }
+        }
+        Some(Ordering::Equal)
+    }
+}
+
+impl<const N: usize> From<f64> for MultiFloat<N> {
+    /// Converts an `f64` into a `MultiFloat` exactly.
+    ///
+    /// Unlike the conversion from `f64` into a [`Double`] or [`Quad`], the value isn't
+    /// reparsed from its decimal representation. Use [`from`] with the result of `dd!` or
+    /// `qd!` to convert a decimal value.
+    ///
+    /// [`Double`]: crate::Double
+    /// [`Quad`]: crate::Quad
+    /// [`from`]: #impl-From<Quad>
+    fn from(a: f64) -> MultiFloat<N> {
+        MultiFloat::from_leading(a)
+    }
+}
+
+impl<const N: usize> From<Double> for MultiFloat<N> {
+    /// Converts a `Double` into a `MultiFloat`, rounding it if `N` is less than 2.
+    fn from(a: Double) -> MultiFloat<N> {
+        MultiFloat::from_parts(&[a[0], a[1]])
+    }
+}
+
+impl<const N: usize> From<Quad> for MultiFloat<N> {
+    /// Converts a `Quad` into a `MultiFloat`, rounding it if `N` is less than 4.
+    fn from(a: Quad) -> MultiFloat<N> {
+        MultiFloat::from_parts(&[a[0], a[1], a[2], a[3]])
+    }
+}
+
+impl<const N: usize> From<MultiFloat<N>> for Double {
+    /// Converts a `MultiFloat` into a `Double`, rounding it if `N` is more than 2.
+    fn from(a: MultiFloat<N>) -> Double {
+        let b = MultiFloat::<2>::from_parts(&a.0);
+        Double::new(b[0], b[1])
+    }
+}
+
+impl<const N: usize> From<MultiFloat<N>> for Quad {
+    /// Converts a `MultiFloat` into a `Quad`, rounding it if `N` is more than 4.
+    fn from(a: MultiFloat<N>) -> Quad {
+        let b = MultiFloat::<4>::from_parts(&a.0);
+        Quad::new(b[0], b[1], b[2], b[3])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+
+    // √2 and 1/3 to 8 components, from mpmath
+    const SQRT_2: [f64; 8] = [
+        std::f64::consts::SQRT_2,
+        -9.667293313452913e-17,
+        4.1386753086994136e-33,
+        4.935546991468351e-50,
+        4.089404286119896e-67,
+        3.1055431288249333e-84,
+        -2.0011002671060812e-100,
+        3.0645041147508584e-117,
+    ];
+
+    fn near<const N: usize>(a: MultiFloat<N>, b: MultiFloat<N>, tol: f64) -> bool {
+        ((a - b).abs() / b.abs()).0[0] < tol
+    }
+
+    #[test]
+    fn double_agrees() {
+        let (a, b) = (dd!(2) / dd!(7), dd!(3) / dd!(11));
+        let (x, y) = (MultiFloat::<2>::from(a), MultiFloat::<2>::from(b));
+        let tol = dd!(1e-31);
+        assert!((Double::from(x + y) - (a + b)).abs() < tol);
+        assert!((Double::from(x - y) - (a - b)).abs() < tol);
+        assert!((Double::from(x * y) - a * b).abs() < tol);
+        assert!((Double::from(x / y) - a / b).abs() < tol);
+        assert!((Double::from(x.sqrt()) - a.sqrt()).abs() < tol);
+    }
+
+    #[test]
+    fn quad_agrees() {
+        let (a, b) = (qd!(2) / qd!(7), qd!(3) / qd!(11));
+        let (x, y) = (MultiFloat::<4>::from(a), MultiFloat::<4>::from(b));
+        let tol = qd!(1e-63);
+        assert!((Quad::from(x + y) - (a + b)).abs() < tol);
+        assert!((Quad::from(x - y) - (a - b)).abs() < tol);
+        assert!((Quad::from(x * y) - a * b).abs() < tol);
+        assert!((Quad::from(x / y) - a / b).abs() < tol);
+        assert!((Quad::from(x.sqrt()) - a.sqrt()).abs() < tol);
+    }
+
+    #[test]
+    fn eight_components() {
+        let two = MultiFloat::<8>::from(2.0);
+        let expected = MultiFloat::new(SQRT_2);
+        assert!(near(two.sqrt(), expected, 1e-125));
+        assert!(near(two / expected, expected, 1e-125));
+        assert!(near(expected * expected, two, 1e-125));
+
+        let third = MultiFloat::<8>::ONE / MultiFloat::from(3.0);
+        assert!(near(third * MultiFloat::from(3.0), MultiFloat::ONE, 1e-125));
+    }
+
+    #[test]
+    fn one_component() {
+        let third = MultiFloat::<1>::ONE / MultiFloat::from(3.0);
+        assert_eq!(third[0], 1.0 / 3.0);
+        assert_eq!(MultiFloat::<1>::from(2.0).sqrt()[0], 2f64.sqrt());
+    }
+
+    #[test]
+    fn exact_sums() {
+        let big = MultiFloat::<8>::from(1e300);
+        let tiny = MultiFloat::<8>::from(1e-300);
+        assert_eq!((big + tiny) - big, tiny);
+        assert_eq!((big + tiny)[1], 1e-300);
+        assert_eq!(
+            MultiFloat::<3>::from_parts(&[1.0, -1.0, 0.5]),
+            MultiFloat::from(0.5)
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let q = qd!(1) / qd!(3);
+        assert_eq!(Quad::from(MultiFloat::<4>::from(q)), q);
+        assert_eq!(Quad::from(MultiFloat::<6>::from(q)), q);
+        let d = dd!(1) / dd!(3);
+        assert_eq!(Double::from(MultiFloat::<4>::from(d)), d);
+        assert_eq!(Double::from(MultiFloat::<4>::from(q)), d);
+    }
+
+    #[test]
+    fn special() {
+        let inf = MultiFloat::<4>::INFINITY;
+        let one = MultiFloat::<4>::ONE;
+        assert_eq!(one / MultiFloat::ZERO, inf);
+        assert_eq!(inf + one, inf);
+        assert!((inf - inf).is_nan());
+        assert!((inf * MultiFloat::ZERO).is_nan());
+        assert!((-one).sqrt().is_nan());
+        assert!(MultiFloat::<4>::NAN.sqrt().is_nan());
+        assert_eq!(MultiFloat::<4>::ZERO.sqrt(), MultiFloat::ZERO);
+        assert!(MultiFloat::from_parts(&[f64::INFINITY, 1.0]) == inf);
+    }
+
+    #[test]
+    fn ordering() {
+        let a = MultiFloat::<4>::from_parts(&[1.0, 1e-40]);
+        let b = MultiFloat::<4>::from_parts(&[1.0, -1e-40]);
+        assert!(b < a);
+        assert!(-a < -b);
+        assert!(a.partial_cmp(&MultiFloat::NAN).is_none());
+    }
+}
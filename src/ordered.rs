@@ -0,0 +1,381 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Wrappers that give [`Double`]s and [`Quad`]s a total order.
+//!
+//! Like `f64`, `Double` and `Quad` are only `PartialEq` and `PartialOrd`, because NaN isn't
+//! equal to anything (including itself) and isn't ordered with respect to anything. That
+//! keeps them out of `BTreeMap`s, `HashSet`s, and `BinaryHeap`s, and out of `sort`. The
+//! wrappers in this module fix that in the same ways as the `ordered-float` crate does for
+//! primitive floating-point numbers:
+//!
+//! * [`OrderedDouble`] and [`OrderedQuad`] treat all NaNs as equal to each other and
+//!   greater than every other number, including infinity.
+//! * [`NotNan`] can't hold NaN at all; creating one from NaN fails.
+//!
+//! In both, negative zero is equal to positive zero, and all of them implement `Eq`, `Ord`,
+//! and `Hash`.
+//!
+//! # Examples
+//! ```
+//! use qd::ordered::{NotNan, OrderedDouble};
+//! use qd::{dd, Double};
+//! use std::collections::BTreeMap;
+//! use std::convert::TryFrom;
+//!
+//! let mut map = BTreeMap::new();
+//! map.insert(OrderedDouble(Double::NAN), "nan");
+//! map.insert(OrderedDouble(dd!(2)), "two");
+//! map.insert(OrderedDouble(dd!(1)), "one");
+//! let values: Vec<_> = map.values().copied().collect();
+//! assert_eq!(values, vec!["one", "two", "nan"]);
+//!
+//! assert!(NotNan::try_from(dd!(1)).is_ok());
+//! assert!(NotNan::try_from(Double::NAN).is_err());
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`OrderedDouble`]: crate::ordered::OrderedDouble
+//! [`OrderedQuad`]: crate::ordered::OrderedQuad
+//! [`NotNan`]: crate::ordered::NotNan
+
+use crate::double::Double;
+use crate::precision::MultiPrecision;
+use crate::quad::Quad;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// A [`Double`] with a total order, in which NaN is equal to itself and greater than
+/// everything else.
+///
+/// # Examples
+/// ```
+/// # use qd::ordered::OrderedDouble;
+/// # use qd::{dd, Double};
+/// let mut xs: Vec<OrderedDouble> = vec![Double::NAN, dd!(3), dd!(-1)]
+///     .into_iter()
+///     .map(OrderedDouble)
+///     .collect();
+/// xs.sort();
+/// assert_eq!(xs[0], OrderedDouble(dd!(-1)));
+/// assert_eq!(xs[2], OrderedDouble(Double::NAN));
+/// ```
+///
+/// [`Double`]: crate::Double
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedDouble(pub Double);
+
+/// A [`Quad`] with a total order, in which NaN is equal to itself and greater than
+/// everything else.
+///
+/// # Examples
+/// ```
+/// # use qd::ordered::OrderedQuad;
+/// # use qd::{qd, Quad};
+/// use std::collections::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::new();
+/// heap.push(OrderedQuad(qd!(1)));
+/// heap.push(OrderedQuad(Quad::PI));
+/// heap.push(OrderedQuad(qd!(-2)));
+/// assert_eq!(heap.pop().unwrap().0, Quad::PI);
+/// ```
+///
+/// [`Quad`]: crate::Quad
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedQuad(pub Quad);
+
+/// A [`Double`] or [`Quad`] that's guaranteed not to be NaN, which makes it totally
+/// ordered.
+///
+/// A `NotNan` is created with [`new`] or `TryFrom`, both of which fail if the number is
+/// NaN. It dereferences to the number that it wraps, so all of the number's methods are
+/// available on it, but any arithmetic has to be done on the wrapped number (which may
+/// produce NaN) and the result wrapped again.
+///
+/// # Examples
+/// ```
+/// # use qd::ordered::NotNan;
+/// # use qd::{dd, Double};
+/// use std::collections::HashSet;
+/// use std::convert::TryFrom;
+///
+/// let mut set = HashSet::new();
+/// set.insert(NotNan::try_from(dd!(0)).unwrap());
+/// set.insert(NotNan::try_from(dd!(-0.0)).unwrap());
+/// assert_eq!(set.len(), 1);
+///
+/// let x = NotNan::new(dd!(4)).unwrap();
+/// assert_eq!(x.sqrt(), dd!(2));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+/// [`new`]: #method.new
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NotNan<T: MultiPrecision>(T);
+
+/// The error returned when trying to create a [`NotNan`] from NaN.
+///
+/// [`NotNan`]: crate::ordered::NotNan
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FloatIsNan;
+
+impl Display for FloatIsNan {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        "number is NaN".fmt(f)
+    }
+}
+
+impl<T: MultiPrecision> NotNan<T> {
+    /// Wraps a number, failing if it's NaN.
+    ///
+    /// # Errors
+    /// Returns [`FloatIsNan`] if the number is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::ordered::{FloatIsNan, NotNan};
+    /// # use qd::{qd, Quad};
+    /// assert_eq!(NotNan::new(qd!(1)).unwrap().into_inner(), qd!(1));
+    /// assert_eq!(NotNan::new(Quad::NAN), Err(FloatIsNan));
+    /// ```
+    ///
+    /// [`FloatIsNan`]: crate::ordered::FloatIsNan
+    pub fn new(x: T) -> Result<NotNan<T>, FloatIsNan> {
+        if x.is_nan() {
+            Err(FloatIsNan)
+        } else {
+            Ok(NotNan(x))
+        }
+    }
+
+    /// Returns the wrapped number.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: MultiPrecision> Eq for NotNan<T> {}
+
+impl<T: MultiPrecision> PartialOrd for NotNan<T> {
+    fn partial_cmp(&self, other: &NotNan<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: MultiPrecision> Ord for NotNan<T> {
+    fn cmp(&self, other: &NotNan<T>) -> Ordering {
+        // Neither number is NaN, so they're always comparable
+        self.0.partial_cmp(&other.0).unwrap()
+    }
+}
+
+impl<T: MultiPrecision> Deref for NotNan<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: MultiPrecision> Display for NotNan<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+// Compares two numbers with NaN greater than everything and equal to itself.
+fn cmp_nan_last<T: MultiPrecision>(a: T, b: T) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+macro_rules! impl_ordered {
+    ($($ordered:ident $t:ident [$($i:literal)*]),*) => {$(
+        impl $ordered {
+            // Hashes the components of a number that isn't NaN. Adding zero turns negative
+            // zeros into positive ones, so that numbers that are equal hash the same.
+            fn hash_components<H: Hasher>(x: &$t, state: &mut H) {
+                $((x[$i] + 0.0).to_bits().hash(state);)*
+            }
+        }
+
+        impl PartialEq for $ordered {
+            fn eq(&self, other: &$ordered) -> bool {
+                cmp_nan_last(self.0, other.0) == Ordering::Equal
+            }
+        }
+
+        impl Eq for $ordered {}
+
+        impl PartialOrd for $ordered {
+            fn partial_cmp(&self, other: &$ordered) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $ordered {
+            fn cmp(&self, other: &$ordered) -> Ordering {
+                cmp_nan_last(self.0, other.0)
+            }
+        }
+
+        impl Hash for $ordered {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                if self.0.is_nan() {
+                    f64::NAN.to_bits().hash(state);
+                } else {
+                    $ordered::hash_components(&self.0, state);
+                }
+            }
+        }
+
+        impl Deref for $ordered {
+            type Target = $t;
+
+            fn deref(&self) -> &$t {
+                &self.0
+            }
+        }
+
+        impl Display for $ordered {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl From<$t> for $ordered {
+            fn from(x: $t) -> $ordered {
+                $ordered(x)
+            }
+        }
+
+        impl From<$ordered> for $t {
+            fn from(x: $ordered) -> $t {
+                x.0
+            }
+        }
+
+        impl TryFrom<$t> for NotNan<$t> {
+            type Error = FloatIsNan;
+
+            fn try_from(x: $t) -> Result<NotNan<$t>, FloatIsNan> {
+                NotNan::new(x)
+            }
+        }
+
+        impl Hash for NotNan<$t> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                $ordered::hash_components(&self.0, state);
+            }
+        }
+
+        impl From<NotNan<$t>> for $t {
+            fn from(x: NotNan<$t>) -> $t {
+                x.0
+            }
+        }
+
+        impl From<NotNan<$t>> for $ordered {
+            fn from(x: NotNan<$t>) -> $ordered {
+                $ordered(x.0)
+            }
+        }
+    )*};
+}
+
+impl_ordered!(OrderedDouble Double [0 1], OrderedQuad Quad [0 1 2 3]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashSet};
+
+    fn hash<H: Hash>(x: &H) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        x.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn double_order() {
+        let nan = OrderedDouble(Double::NAN);
+        let inf = OrderedDouble(Double::INFINITY);
+        let one = OrderedDouble(dd!(1));
+        let tiny = OrderedDouble(Double::new(1.0, 1e-20));
+        assert_eq!(nan, nan);
+        assert!(nan > inf);
+        assert!(inf > tiny);
+        assert!(tiny > one);
+        assert!(OrderedDouble(Double::NEG_INFINITY) < one);
+        assert_eq!(OrderedDouble(dd!(0)), OrderedDouble(dd!(-0.0)));
+        assert_eq!(
+            hash(&OrderedDouble(dd!(0))),
+            hash(&OrderedDouble(dd!(-0.0)))
+        );
+        assert_eq!(hash(&nan), hash(&OrderedDouble(-Double::NAN)));
+        assert_ne!(hash(&one), hash(&tiny));
+    }
+
+    #[test]
+    fn quad_collections() {
+        let xs = vec![
+            qd!(3),
+            Quad::NAN,
+            qd!(-1),
+            Quad::NAN,
+            qd!(3),
+            qd!(-0.0),
+            qd!(0),
+        ];
+        let set: BTreeSet<OrderedQuad> = xs.iter().map(|&x| OrderedQuad(x)).collect();
+        let sorted: Vec<OrderedQuad> = set.into_iter().collect();
+        assert_eq!(sorted.len(), 4);
+        assert_eq!(*sorted[0], qd!(-1));
+        assert_eq!(*sorted[1], qd!(0));
+        assert_eq!(Quad::from(sorted[2]), qd!(3));
+        assert!(sorted[3].is_nan());
+
+        let hashed: HashSet<OrderedQuad> = xs.into_iter().map(OrderedQuad::from).collect();
+        assert_eq!(hashed.len(), 4);
+    }
+
+    #[test]
+    fn not_nan() {
+        let a = NotNan::try_from(Quad::PI).unwrap();
+        let b = NotNan::new(Quad::E).unwrap();
+        assert!(a > b);
+        assert_eq!(a.max(b), a);
+        assert_eq!(NotNan::try_from(Quad::NAN), Err(FloatIsNan));
+        assert_eq!(NotNan::new(Double::NAN), Err(FloatIsNan));
+        assert_eq!(FloatIsNan.to_string(), "number is NaN");
+
+        let inf = NotNan::new(Double::INFINITY).unwrap();
+        assert!(inf > NotNan::new(dd!(1e300)).unwrap());
+        assert_eq!(Double::from(inf), Double::INFINITY);
+        assert_eq!(OrderedDouble::from(inf), OrderedDouble(Double::INFINITY));
+
+        let zero = NotNan::new(dd!(0)).unwrap();
+        let neg_zero = NotNan::new(dd!(-0.0)).unwrap();
+        assert_eq!(zero, neg_zero);
+        assert_eq!(hash(&zero), hash(&neg_zero));
+        assert_eq!(
+            format!("{}", NotNan::new(dd!(1.5)).unwrap()),
+            format!("{}", dd!(1.5))
+        );
+    }
+}
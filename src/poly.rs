@@ -0,0 +1,505 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Polynomials with [`Double`] or [`Quad`] coefficients.
+//!
+//! A [`Polynomial`] can be evaluated, differentiated, integrated, and combined with other
+//! polynomials by addition, subtraction, multiplication, and division with remainder.
+//!
+//! Evaluating a polynomial with [`eval`] uses Horner's rule, whose error can be far larger
+//! than the precision of the coefficients when the terms of the polynomial cancel each
+//! other out, as they do near its roots. [`eval_compensated`] uses compensated Horner's
+//! rule instead, which also tracks the rounding error of each step and adds it back in at
+//! the end. Its result is as accurate as if Horner's rule had been carried out in twice the
+//! precision and then rounded, at roughly three times the cost. This matters most for
+//! polynomials whose coefficients are already as accurate as they can be, such as minimax
+//! approximations.
+//!
+//! # Examples
+//! ```
+//! use qd::poly::Polynomial;
+//! use qd::{dd, Double};
+//!
+//! // (x - 1)⁵, expanded
+//! let p = Polynomial::new(vec![dd!(-1), dd!(5), dd!(-10), dd!(10), dd!(-5), dd!(1)]);
+//! let h = Double::new(1e-6, 0.0);
+//! let x = dd!(1) + h;
+//! let exact = h * h * h * h * h;
+//!
+//! // Horner's rule loses almost every digit, but the compensated version loses none
+//! assert!(((p.eval(x) - exact) / exact).abs() > dd!(0.01));
+//! assert!(((p.eval_compensated(x) - exact) / exact).abs() < dd!(1e-30));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`eval`]: crate::poly::Polynomial::eval
+//! [`eval_compensated`]: crate::poly::Polynomial::eval_compensated
+
+use crate::double::Double;
+use crate::multi::MultiFloat;
+use crate::precision::MultiPrecision;
+use crate::quad::Quad;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A type that can be the coefficient of a [`Polynomial`].
+///
+/// This is implemented for [`Double`] and [`Quad`], and like [`MultiPrecision`] it can't be
+/// implemented for anything else.
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+/// [`MultiPrecision`]: crate::MultiPrecision
+pub trait Coefficient: MultiPrecision {
+    /// Returns the sum of two numbers and the rounding error of that sum.
+    ///
+    /// The error is computed in a wider precision, so it's accurate to the full precision
+    /// of `Self` but isn't necessarily exact.
+    fn two_sum(a: Self, b: Self) -> (Self, Self);
+
+    /// Returns the product of two numbers and the rounding error of that product.
+    ///
+    /// The error is computed in a wider precision, so it's accurate to the full precision
+    /// of `Self` but isn't necessarily exact.
+    fn two_prod(a: Self, b: Self) -> (Self, Self);
+}
+
+impl Coefficient for Double {
+    fn two_sum(a: Double, b: Double) -> (Double, Double) {
+        let s = a + b;
+        let e = wide(a) + wide(b) - wide(s);
+        (s, Double::new(e[0], e[1]))
+    }
+
+    fn two_prod(a: Double, b: Double) -> (Double, Double) {
+        let p = a * b;
+        let e = wide(a) * wide(b) - wide(p);
+        (p, Double::new(e[0], e[1]))
+    }
+}
+
+// Converts a `Double` into a `Quad` exactly. (`Quad::from` goes through the decimal
+// representation, which is rounded.)
+fn wide(a: Double) -> Quad {
+    Quad::new(a[0], a[1], 0.0, 0.0)
+}
+
+impl Coefficient for Quad {
+    fn two_sum(a: Quad, b: Quad) -> (Quad, Quad) {
+        let s = a + b;
+        let e = MultiFloat::<8>::from(a) + MultiFloat::from(b) - MultiFloat::from(s);
+        (s, Quad::from(e))
+    }
+
+    fn two_prod(a: Quad, b: Quad) -> (Quad, Quad) {
+        let p = a * b;
+        let e = MultiFloat::<8>::from(a) * MultiFloat::from(b) - MultiFloat::from(p);
+        (p, Quad::from(e))
+    }
+}
+
+/// A polynomial in one variable with [`Double`] or [`Quad`] coefficients.
+///
+/// The coefficients are stored from the constant term up, so the coefficient at index `i`
+/// is the coefficient of *x*<sup>*i*</sup>. Zero coefficients at the end are removed, so
+/// the last coefficient is never zero and the zero polynomial has no coefficients at all.
+///
+/// The arithmetic operators are implemented for both polynomials and references to
+/// polynomials, so that the operands don't have to be cloned to be reused.
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial<T: Coefficient> {
+    coeffs: Vec<T>,
+}
+
+impl<T: Coefficient> Polynomial<T> {
+    /// Creates a new polynomial from its coefficients, starting with the constant term.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::poly::Polynomial;
+    /// # use qd::{dd, Double};
+    /// // 1 + 2x + 3x²
+    /// let p = Polynomial::new(vec![dd!(1), dd!(2), dd!(3), dd!(0)]);
+    /// assert_eq!(p.coeffs(), &[dd!(1), dd!(2), dd!(3)]);
+    /// ```
+    pub fn new(coeffs: Vec<T>) -> Polynomial<T> {
+        let mut p = Polynomial { coeffs };
+        p.trim();
+        p
+    }
+
+    /// Creates the polynomial equal to zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::poly::Polynomial;
+    /// # use qd::Double;
+    /// let p = Polynomial::<Double>::zero();
+    /// assert!(p.coeffs().is_empty());
+    /// ```
+    pub fn zero() -> Polynomial<T> {
+        Polynomial { coeffs: vec![] }
+    }
+
+    /// Returns the coefficients of the polynomial, starting with the constant term.
+    ///
+    /// The zero polynomial has no coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::poly::Polynomial;
+    /// # use qd::{qd, Quad};
+    /// let p = Polynomial::new(vec![qd!(0), qd!(1)]);
+    /// assert_eq!(p.coeffs(), &[qd!(0), qd!(1)]);
+    /// ```
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    /// Returns the degree of the polynomial, or `None` if it's the zero polynomial.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::poly::Polynomial;
+    /// # use qd::{dd, Double};
+    /// assert_eq!(Polynomial::new(vec![dd!(1), dd!(2)]).degree(), Some(1));
+    /// assert_eq!(Polynomial::new(vec![dd!(1)]).degree(), Some(0));
+    /// assert_eq!(Polynomial::<Double>::zero().degree(), None);
+    /// ```
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Evaluates the polynomial at `x` with Horner's rule.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::poly::Polynomial;
+    /// # use qd::{dd, Double};
+    /// // 1 + 2x + 3x²
+    /// let p = Polynomial::new(vec![dd!(1), dd!(2), dd!(3)]);
+    /// assert_eq!(p.eval(dd!(2)), dd!(17));
+    /// ```
+    pub fn eval(&self, x: T) -> T {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(T::ZERO, |acc, &c| acc * x + c)
+    }
+
+    /// Evaluates the polynomial at `x` with compensated Horner's rule.
+    ///
+    /// The result is as accurate as if it had been calculated with Horner's rule in twice
+    /// the precision of the coefficients, which makes a difference when the terms of the
+    /// polynomial nearly cancel each other out. See the [module documentation] for an
+    /// example.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::poly::Polynomial;
+    /// # use qd::{dd, Double};
+    /// let p = Polynomial::new(vec![dd!(1), dd!(2), dd!(3)]);
+    /// assert_eq!(p.eval_compensated(dd!(2)), dd!(17));
+    /// ```
+    ///
+    /// [module documentation]: index.html
+    pub fn eval_compensated(&self, x: T) -> T {
+        let mut coeffs = self.coeffs.iter().rev();
+        let mut s = match coeffs.next() {
+            Some(&c) => c,
+            None => return T::ZERO,
+        };
+        let mut r = T::ZERO;
+        for &c in coeffs {
+            let (p, pe) = T::two_prod(s, x);
+            let (t, se) = T::two_sum(p, c);
+            s = t;
+            r = r * x + (pe + se);
+        }
+        s + r
+    }
+
+    /// Calculates the derivative of the polynomial.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::poly::Polynomial;
+    /// # use qd::{dd, Double};
+    /// // 1 + 2x + 3x² ⇒ 2 + 6x
+    /// let p = Polynomial::new(vec![dd!(1), dd!(2), dd!(3)]);
+    /// assert_eq!(p.derivative().coeffs(), &[dd!(2), dd!(6)]);
+    /// ```
+    pub fn derivative(&self) -> Polynomial<T> {
+        Polynomial::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| c * T::from(i as f64))
+                .collect(),
+        )
+    }
+
+    /// Calculates the integral of the polynomial whose constant term is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::poly::Polynomial;
+    /// # use qd::{dd, Double};
+    /// // 2 + 6x ⇒ 2x + 3x²
+    /// let p = Polynomial::new(vec![dd!(2), dd!(6)]);
+    /// assert_eq!(p.integral().coeffs(), &[dd!(0), dd!(2), dd!(3)]);
+    /// ```
+    pub fn integral(&self) -> Polynomial<T> {
+        if self.coeffs.is_empty() {
+            return Polynomial::zero();
+        }
+        let mut coeffs = Vec::with_capacity(self.coeffs.len() + 1);
+        coeffs.push(T::ZERO);
+        coeffs.extend(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| c / T::from((i + 1) as f64)),
+        );
+        Polynomial::new(coeffs)
+    }
+
+    /// Divides the polynomial by another, returning the quotient and the remainder.
+    ///
+    /// The degree of the remainder is less than the degree of the divisor.
+    ///
+    /// # Panics
+    /// Panics if the divisor is the zero polynomial.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::poly::Polynomial;
+    /// # use qd::{dd, Double};
+    /// // x² + 1 = (x - 1)(x + 1) + 2
+    /// let p = Polynomial::new(vec![dd!(1), dd!(0), dd!(1)]);
+    /// let d = Polynomial::new(vec![dd!(-1), dd!(1)]);
+    /// let (q, r) = p.div_rem(&d);
+    /// assert_eq!(q.coeffs(), &[dd!(1), dd!(1)]);
+    /// assert_eq!(r.coeffs(), &[dd!(2)]);
+    /// ```
+    pub fn div_rem(&self, divisor: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>) {
+        let d = divisor.coeffs.len();
+        assert!(d > 0, "division by the zero polynomial");
+        if self.coeffs.len() < d {
+            return (Polynomial::zero(), self.clone());
+        }
+
+        let lead = divisor.coeffs[d - 1];
+        let mut rem = self.coeffs.clone();
+        let mut quot = vec![T::ZERO; rem.len() - d + 1];
+        for i in (0..quot.len()).rev() {
+            let c = rem[i + d - 1] / lead;
+            quot[i] = c;
+            for (j, &b) in divisor.coeffs.iter().enumerate() {
+                rem[i + j] = rem[i + j] - c * b;
+            }
+            // This term is eliminated by construction, even if rounding says otherwise
+            rem[i + d - 1] = T::ZERO;
+        }
+        rem.truncate(d - 1);
+        (Polynomial::new(quot), Polynomial::new(rem))
+    }
+
+    // Removes the zero coefficients from the end.
+    fn trim(&mut self) {
+        while self.coeffs.last() == Some(&T::ZERO) {
+            self.coeffs.pop();
+        }
+    }
+}
+
+impl<T: Coefficient> Neg for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    /// Negates the polynomial.
+    fn neg(self) -> Polynomial<T> {
+        Polynomial::new(self.coeffs.iter().map(|&c| -c).collect())
+    }
+}
+
+impl<T: Coefficient> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    /// Negates the polynomial.
+    fn neg(self) -> Polynomial<T> {
+        -&self
+    }
+}
+
+impl<T: Coefficient> Add for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    /// Adds two polynomials.
+    fn add(self, other: &Polynomial<T>) -> Polynomial<T> {
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let coeff = |p: &Polynomial<T>, i| p.coeffs.get(i).copied().unwrap_or(T::ZERO);
+        Polynomial::new((0..n).map(|i| coeff(self, i) + coeff(other, i)).collect())
+    }
+}
+
+impl<T: Coefficient> Sub for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    /// Subtracts one polynomial from another.
+    fn sub(self, other: &Polynomial<T>) -> Polynomial<T> {
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let coeff = |p: &Polynomial<T>, i| p.coeffs.get(i).copied().unwrap_or(T::ZERO);
+        Polynomial::new((0..n).map(|i| coeff(self, i) - coeff(other, i)).collect())
+    }
+}
+
+impl<T: Coefficient> Mul for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    /// Multiplies two polynomials.
+    fn mul(self, other: &Polynomial<T>) -> Polynomial<T> {
+        if self.coeffs.is_empty() || other.coeffs.is_empty() {
+            return Polynomial::zero();
+        }
+        let mut coeffs = vec![T::ZERO; self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, &a) in self.coeffs.iter().enumerate() {
+            for (j, &b) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j] + a * b;
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+macro_rules! owned_ops {
+    ($($trait:ident $method:ident),*) => {$(
+        impl<T: Coefficient> $trait for Polynomial<T> {
+            type Output = Polynomial<T>;
+
+            #[inline]
+            fn $method(self, other: Polynomial<T>) -> Polynomial<T> {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<T: Coefficient> $trait<&Polynomial<T>> for Polynomial<T> {
+            type Output = Polynomial<T>;
+
+            #[inline]
+            fn $method(self, other: &Polynomial<T>) -> Polynomial<T> {
+                (&self).$method(other)
+            }
+        }
+
+        impl<T: Coefficient> $trait<Polynomial<T>> for &Polynomial<T> {
+            type Output = Polynomial<T>;
+
+            #[inline]
+            fn $method(self, other: Polynomial<T>) -> Polynomial<T> {
+                self.$method(&other)
+            }
+        }
+    )*};
+}
+
+owned_ops!(Add add, Sub sub, Mul mul);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+
+    // (x - 1)⁵, expanded
+    fn fifth_power<T: Coefficient>() -> Polynomial<T> {
+        let cs = [-1.0, 5.0, -10.0, 10.0, -5.0, 1.0];
+        Polynomial::new(cs.iter().map(|&c| T::from(c)).collect())
+    }
+
+    #[test]
+    fn double_eval() {
+        let p = fifth_power::<Double>();
+        let h = Double::new(1e-6, 0.0);
+        let x = dd!(1) + h;
+        let exact = h * h * h * h * h;
+        assert!(((p.eval(x) - exact) / exact).abs() > dd!(0.01));
+        assert!(((p.eval_compensated(x) - exact) / exact).abs() < dd!(1e-30));
+        assert_eq!(
+            Polynomial::<Double>::zero().eval_compensated(dd!(3)),
+            dd!(0)
+        );
+    }
+
+    #[test]
+    fn quad_eval() {
+        let p = fifth_power::<Quad>();
+        let h = Quad::new(1e-13, 0.0, 0.0, 0.0);
+        let x = qd!(1) + h;
+        let exact = h * h * h * h * h;
+        assert!(((p.eval(x) - exact) / exact).abs() > qd!(0.1));
+        assert!(((p.eval_compensated(x) - exact) / exact).abs() < qd!(1e-60));
+        assert_eq!(
+            Polynomial::new(vec![qd!(7)]).eval_compensated(qd!(3)),
+            qd!(7)
+        );
+    }
+
+    #[test]
+    fn calculus() {
+        let p = fifth_power::<Quad>();
+        let dp = p.derivative();
+        assert_eq!(dp.coeffs(), &[qd!(5), qd!(-20), qd!(30), qd!(-20), qd!(5)]);
+        assert_eq!(dp.integral(), &p - &Polynomial::new(vec![qd!(-1)]));
+        assert_eq!(
+            Polynomial::new(vec![qd!(4)]).derivative(),
+            Polynomial::zero()
+        );
+        assert_eq!(Polynomial::<Quad>::zero().integral(), Polynomial::zero());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let p = fifth_power::<Double>();
+        let q = Polynomial::new(vec![dd!(-1), dd!(1)]);
+        assert_eq!(&p + &-&p, Polynomial::zero());
+        assert_eq!(p.clone() - p.clone(), Polynomial::zero());
+        let prod = &(&q * &q) * &q;
+        assert_eq!((&prod * &prod) * q.clone(), prod.clone() * (&q * &prod));
+        assert_eq!(
+            prod + Polynomial::new(vec![dd!(1), dd!(2)]),
+            Polynomial::new(vec![dd!(0), dd!(5), dd!(-3), dd!(1)])
+        );
+        assert_eq!(&p * &Polynomial::zero(), Polynomial::zero());
+    }
+
+    #[test]
+    fn division() {
+        let p = fifth_power::<Double>();
+        let q = Polynomial::new(vec![dd!(-1), dd!(1)]);
+        let (quot, rem) = p.div_rem(&q);
+        assert_eq!(quot.degree(), Some(4));
+        assert_eq!(rem, Polynomial::zero());
+        assert_eq!(&quot * &q, p);
+
+        let r = Polynomial::new(vec![dd!(3), dd!(0), dd!(2)]);
+        let (quot, rem) = (&p + &r).div_rem(&(&q * &q));
+        assert_eq!(rem, Polynomial::new(vec![dd!(1), dd!(4)]));
+        assert_eq!(&(&quot * &(&q * &q)) + &rem, &p + &r);
+
+        let (quot, rem) = q.div_rem(&p);
+        assert_eq!(quot, Polynomial::zero());
+        assert_eq!(rem, q);
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        let p = fifth_power::<Quad>();
+        p.div_rem(&Polynomial::zero());
+    }
+}
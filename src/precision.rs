@@ -0,0 +1,747 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use crate::quad::Quad;
+use std::fmt::Debug;
+#[cfg(all(feature = "transcendental", feature = "display"))]
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for crate::Double {}
+    impl Sealed for crate::Quad {}
+}
+
+/// An absolute and a relative tolerance, for comparing [`Double`]s and [`Quad`]s with
+/// [`Double::approx_eq`] and [`Quad::approx_eq`].
+///
+/// Two numbers are approximately equal if the difference between them is no more than
+/// `abs`, *or* no more than `rel` times the larger of their magnitudes. The absolute
+/// tolerance is what matters near zero, where no relative tolerance can be met by numbers
+/// that are merely close, and the relative tolerance is what matters everywhere else.
+/// Either can be zero to leave it out.
+///
+/// # Examples
+/// ```
+/// use qd::{dd, AbsRel};
+///
+/// let tol = AbsRel { abs: 1e-30, rel: 1e-25 };
+/// assert!(dd!(1e10).approx_eq(dd!(1e10) + dd!(1e-16), tol));
+/// assert!(dd!(0).approx_eq(dd!(1e-31), tol));
+/// assert!(!dd!(1).approx_eq(dd!(1) + dd!(1e-24), tol));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+/// [`Double::approx_eq`]: crate::Double::approx_eq
+/// [`Quad::approx_eq`]: crate::Quad::approx_eq
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AbsRel {
+    /// The largest difference allowed between any two numbers.
+    pub abs: f64,
+    /// The largest difference allowed between two numbers, as a fraction of the larger of
+    /// their magnitudes.
+    pub rel: f64,
+}
+
+/// The arithmetic shared by [`Double`] and [`Quad`], for writing code that works with
+/// either of them whatever features are enabled.
+///
+/// The trait covers the constants, the arithmetic operators, integer powers and roots,
+/// rounding, comparisons, and conversions to `f64` and (for integers) `i128`. All of it is
+/// available with the `display`, `transcendental`, and `extras` features turned off, so
+/// it's the bound to use for generic code that has to build for small targets.
+/// [`MultiPrecision`] adds real powers and the exponential, logarithmic, trigonometric,
+/// and hyperbolic functions to it.
+///
+/// This trait is sealed; it's implemented for `Double` and `Quad` and can't be implemented
+/// for anything else. Its methods do exactly what the inherent methods of the same names
+/// do.
+///
+/// # Examples
+/// ```
+/// use qd::{dd, qd, MultiPrecisionCore};
+///
+/// // Evaluates a polynomial, with its coefficients from the constant term up
+/// fn horner<T: MultiPrecisionCore>(coeffs: &[T], x: T) -> T {
+///     coeffs.iter().rev().fold(T::zero(), |acc, &c| acc.mul_add(x, c))
+/// }
+///
+/// assert_eq!(horner(&[dd!(1), dd!(2), dd!(3)], dd!(2)), dd!(17));
+/// assert_eq!(horner(&[qd!(1), qd!(0), qd!(1)], qd!(3)), qd!(10));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+pub trait MultiPrecisionCore:
+    sealed::Sealed
+    + Copy
+    + Default
+    + Debug
+    + PartialEq
+    + PartialOrd
+    + From<f64>
+    + Into<f64>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The approximate number of significant decimal digits.
+    const DIGITS: u32;
+
+    /// The number of significant binary digits.
+    const MANTISSA_DIGITS: u32;
+
+    /// The number zero.
+    const ZERO: Self;
+
+    /// The number negative zero.
+    const NEG_ZERO: Self;
+
+    /// The number one.
+    const ONE: Self;
+
+    /// The number negative one.
+    const NEG_ONE: Self;
+
+    /// The difference between 1 and the next larger representable number.
+    const EPSILON: Self;
+
+    /// The largest finite number.
+    const MAX: Self;
+
+    /// The smallest finite number.
+    const MIN: Self;
+
+    /// The smallest positive normal number.
+    const MIN_POSITIVE: Self;
+
+    /// Positive infinity.
+    const INFINITY: Self;
+
+    /// Negative infinity.
+    const NEG_INFINITY: Self;
+
+    /// Not a number.
+    const NAN: Self;
+
+    /// Archimedes' constant (π).
+    const PI: Self;
+
+    /// The full circle constant (τ = 2π).
+    const TAU: Self;
+
+    /// π/2.
+    const FRAC_PI_2: Self;
+
+    /// Euler's number (*e*).
+    const E: Self;
+
+    /// ln(2).
+    const LN_2: Self;
+
+    /// ln(10).
+    const LN_10: Self;
+
+    /// √2.
+    const SQRT_2: Self;
+
+    /// Returns the number zero.
+    ///
+    /// This is the same as [`ZERO`](MultiPrecisionCore::ZERO) and as `Default::default()`,
+    /// for generic code that's written in terms of functions rather than constants.
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    /// Returns the number one.
+    ///
+    /// This is the same as [`ONE`](MultiPrecisionCore::ONE).
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    /// Calculates the absolute value of the number.
+    fn abs(self) -> Self;
+
+    /// Returns 1 with the sign of the number, or NaN if the number is NaN.
+    fn signum(self) -> Self;
+
+    /// Returns the smaller of two numbers, ignoring NaN.
+    fn min(self, other: Self) -> Self;
+
+    /// Returns the larger of two numbers, ignoring NaN.
+    fn max(self, other: Self) -> Self;
+
+    /// Calculates the reciprocal of the number.
+    fn recip(self) -> Self;
+
+    /// Calculates the square of the number.
+    fn sqr(self) -> Self;
+
+    /// Calculates the square root of the number.
+    fn sqrt(self) -> Self;
+
+    /// Calculates the cube root of the number.
+    fn cbrt(self) -> Self;
+
+    /// Calculates the length of the hypotenuse of a right triangle with legs of the given
+    /// lengths.
+    fn hypot(self, other: Self) -> Self;
+
+    /// Raises the number to an integer power.
+    fn powi(self, n: i64) -> Self;
+
+    /// Calculates `self * a + b`.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Multiplies the number by a power of two, which must be an `f64` power of two.
+    fn mul_pwr2(self, n: f64) -> Self;
+
+    /// Returns the largest integer less than or equal to the number.
+    fn floor(self) -> Self;
+
+    /// Returns the smallest integer greater than or equal to the number.
+    fn ceil(self) -> Self;
+
+    /// Returns the nearest integer to the number, rounding half-way cases away from zero.
+    fn round(self) -> Self;
+
+    /// Returns the integer part of the number.
+    fn trunc(self) -> Self;
+
+    /// Returns the fractional part of the number.
+    fn fract(self) -> Self;
+
+    /// Returns the largest integer less than or equal to the number as an `i128`, or `None`
+    /// if it's out of range or not finite.
+    fn floor_i128(self) -> Option<i128>;
+
+    /// Returns the smallest integer greater than or equal to the number as an `i128`, or
+    /// `None` if it's out of range or not finite.
+    fn ceil_i128(self) -> Option<i128>;
+
+    /// Returns the nearest integer to the number as an `i128`, or `None` if it's out of
+    /// range or not finite.
+    fn round_i128(self) -> Option<i128>;
+
+    /// Returns the integer part of the number as an `i128`, or `None` if it's out of range
+    /// or not finite.
+    fn trunc_i128(self) -> Option<i128>;
+
+    /// Returns the unit in the last place of the number, the spacing of the representable
+    /// numbers at it.
+    fn ulp(self) -> Self;
+
+    /// Returns the unit in the last place of a number of this size with the full precision
+    /// of the type.
+    fn precision_ulp(self) -> Self;
+
+    /// Returns `true` if the number and another are equal to within an absolute or a
+    /// relative tolerance.
+    fn approx_eq(self, other: Self, tol: AbsRel) -> bool;
+
+    /// Returns `true` if the number and another are no more than `max_ulps` ULPs apart.
+    fn ulps_eq(self, other: Self, max_ulps: u32) -> bool;
+
+    /// Returns `true` if the number is NaN.
+    fn is_nan(self) -> bool;
+
+    /// Returns `true` if the number is positive or negative infinity.
+    fn is_infinite(self) -> bool;
+
+    /// Returns `true` if the number is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+
+    /// Returns `true` if the number is zero, including `-0.0`.
+    fn is_zero(self) -> bool;
+
+    /// Returns `true` if the number has a negative sign, including `-0.0`.
+    fn is_sign_negative(self) -> bool;
+
+    /// Returns `true` if the number has a positive sign, including `+0.0`.
+    fn is_sign_positive(self) -> bool;
+
+    /// Returns `true` if the number is an integer, taking all of its components into account.
+    fn is_integer(self) -> bool;
+
+    /// Returns `true` if the number is an even integer.
+    fn is_even(self) -> bool;
+
+    /// Returns `true` if the number is an odd integer.
+    fn is_odd(self) -> bool;
+}
+
+/// The operations shared by [`Double`] and [`Quad`], for writing code that works with
+/// either of them.
+///
+/// The trait adds real powers, the exponential, logarithmic, trigonometric, and hyperbolic
+/// functions, and formatting with `Display` to the constants, arithmetic, rounding, and
+/// conversions of [`MultiPrecisionCore`]. This is enough to write most numerical
+/// algorithms once and run them in either precision, without pulling in a general numeric
+/// traits crate. It needs the `transcendental` and `display` features.
+///
+/// This trait is sealed; it's implemented for `Double` and `Quad` and can't be implemented
+/// for anything else. Its methods do exactly what the inherent methods of the same names
+/// do.
+///
+/// # Examples
+/// ```
+/// use qd::{dd, qd, Double, MultiPrecision, Quad};
+///
+/// fn hypot<T: MultiPrecision>(a: T, b: T) -> T {
+///     (a * a + b * b).sqrt()
+/// }
+///
+/// assert_eq!(hypot(dd!(3), dd!(4)), dd!(5));
+/// assert!((hypot(qd!(3), qd!(4)) - qd!(5)).abs() < qd!(1e-60));
+///
+/// // The logistic function, σ(x) = 1 / (1 + e⁻ˣ)
+/// fn sigmoid<T: MultiPrecision>(x: T) -> T {
+///     (T::ONE + (-x).exp()).recip()
+/// }
+///
+/// assert!((sigmoid(dd!(0)) - dd!(0.5)).abs() < dd!(1e-30));
+/// let y = sigmoid(Quad::LN_2);
+/// assert!((y - qd!(2) / qd!(3)).abs() < qd!(1e-62));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+#[cfg(all(feature = "transcendental", feature = "display"))]
+pub trait MultiPrecision: MultiPrecisionCore + Display {
+    /// Raises the number to a power of the same type.
+    fn powf(self, n: Self) -> Self;
+
+    /// Calculates *e* raised to the power of the number.
+    fn exp(self) -> Self;
+
+    /// Calculates *e* raised to the power of the number, minus 1.
+    fn exp_m1(self) -> Self;
+
+    /// Calculates the natural logarithm of the number.
+    fn ln(self) -> Self;
+
+    /// Calculates the natural logarithm of 1 plus the number.
+    fn ln_1p(self) -> Self;
+
+    /// Calculates the base-2 logarithm of the number.
+    fn log2(self) -> Self;
+
+    /// Calculates the base-10 logarithm of the number.
+    fn log10(self) -> Self;
+
+    /// Calculates the sine of the number (in radians).
+    fn sin(self) -> Self;
+
+    /// Calculates the cosine of the number (in radians).
+    fn cos(self) -> Self;
+
+    /// Calculates the tangent of the number (in radians).
+    fn tan(self) -> Self;
+
+    /// Calculates the sine and the cosine of the number (in radians) together.
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Calculates the arcsine of the number.
+    fn asin(self) -> Self;
+
+    /// Calculates the arccosine of the number.
+    fn acos(self) -> Self;
+
+    /// Calculates the arctangent of the number.
+    fn atan(self) -> Self;
+
+    /// Calculates the four-quadrant arctangent of `self` (the *y* coordinate) and `other`
+    /// (the *x* coordinate).
+    fn atan2(self, other: Self) -> Self;
+
+    /// Calculates the hyperbolic sine of the number.
+    fn sinh(self) -> Self;
+
+    /// Calculates the hyperbolic cosine of the number.
+    fn cosh(self) -> Self;
+
+    /// Calculates the hyperbolic tangent of the number.
+    fn tanh(self) -> Self;
+}
+
+macro_rules! impl_multi_precision_core {
+    ($($t:ident)*) => {$(
+        impl MultiPrecisionCore for $t {
+            const DIGITS: u32 = $t::DIGITS;
+            const MANTISSA_DIGITS: u32 = $t::MANTISSA_DIGITS;
+            const ZERO: $t = $t::ZERO;
+            const NEG_ZERO: $t = $t::NEG_ZERO;
+            const ONE: $t = $t::ONE;
+            const NEG_ONE: $t = $t::NEG_ONE;
+            const EPSILON: $t = $t::EPSILON;
+            const MAX: $t = $t::MAX;
+            const MIN: $t = $t::MIN;
+            const MIN_POSITIVE: $t = $t::MIN_POSITIVE;
+            const INFINITY: $t = $t::INFINITY;
+            const NEG_INFINITY: $t = $t::NEG_INFINITY;
+            const NAN: $t = $t::NAN;
+            const PI: $t = $t::PI;
+            const TAU: $t = $t::TAU;
+            const FRAC_PI_2: $t = $t::FRAC_PI_2;
+            const E: $t = $t::E;
+            const LN_2: $t = $t::LN_2;
+            const LN_10: $t = $t::LN_10;
+            const SQRT_2: $t = $t::SQRT_2;
+
+            #[inline]
+            fn abs(self) -> $t {
+                $t::abs(self)
+            }
+
+            #[inline]
+            fn signum(self) -> $t {
+                $t::signum(self)
+            }
+
+            #[inline]
+            fn min(self, other: $t) -> $t {
+                $t::min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: $t) -> $t {
+                $t::max(self, other)
+            }
+
+            #[inline]
+            fn recip(self) -> $t {
+                $t::recip(self)
+            }
+
+            #[inline]
+            fn sqr(self) -> $t {
+                $t::sqr(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> $t {
+                $t::sqrt(self)
+            }
+
+            #[inline]
+            fn cbrt(self) -> $t {
+                $t::cbrt(self)
+            }
+
+            #[inline]
+            fn hypot(self, other: $t) -> $t {
+                $t::hypot(self, other)
+            }
+
+            #[inline]
+            fn powi(self, n: i64) -> $t {
+                $t::powi(self, n)
+            }
+
+            #[inline]
+            fn mul_add(self, a: $t, b: $t) -> $t {
+                $t::mul_add(self, a, b)
+            }
+
+            #[inline]
+            fn mul_pwr2(self, n: f64) -> $t {
+                $t::mul_pwr2(self, n)
+            }
+
+            #[inline]
+            fn floor(self) -> $t {
+                $t::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> $t {
+                $t::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> $t {
+                $t::round(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> $t {
+                $t::trunc(self)
+            }
+
+            #[inline]
+            fn fract(self) -> $t {
+                $t::fract(self)
+            }
+
+            #[inline]
+            fn floor_i128(self) -> Option<i128> {
+                $t::floor_i128(self)
+            }
+
+            #[inline]
+            fn ceil_i128(self) -> Option<i128> {
+                $t::ceil_i128(self)
+            }
+
+            #[inline]
+            fn round_i128(self) -> Option<i128> {
+                $t::round_i128(self)
+            }
+
+            #[inline]
+            fn trunc_i128(self) -> Option<i128> {
+                $t::trunc_i128(self)
+            }
+
+            #[inline]
+            fn ulp(self) -> $t {
+                $t::ulp(self)
+            }
+
+            #[inline]
+            fn precision_ulp(self) -> $t {
+                $t::precision_ulp(self)
+            }
+
+            #[inline]
+            fn approx_eq(self, other: $t, tol: AbsRel) -> bool {
+                $t::approx_eq(self, other, tol)
+            }
+
+            #[inline]
+            fn ulps_eq(self, other: $t, max_ulps: u32) -> bool {
+                $t::ulps_eq(self, other, max_ulps)
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                $t::is_infinite(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            #[inline]
+            fn is_zero(self) -> bool {
+                $t::is_zero(self)
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                $t::is_sign_negative(self)
+            }
+
+            #[inline]
+            fn is_sign_positive(self) -> bool {
+                $t::is_sign_positive(self)
+            }
+
+            #[inline]
+            fn is_integer(self) -> bool {
+                $t::is_integer(self)
+            }
+
+            #[inline]
+            fn is_even(self) -> bool {
+                $t::is_even(self)
+            }
+
+            #[inline]
+            fn is_odd(self) -> bool {
+                $t::is_odd(self)
+            }
+        }
+    )*};
+}
+
+impl_multi_precision_core!(Double Quad);
+
+#[cfg(all(feature = "transcendental", feature = "display"))]
+macro_rules! impl_multi_precision {
+    ($($t:ident)*) => {$(
+        impl MultiPrecision for $t {
+            #[inline]
+            fn powf(self, n: $t) -> $t {
+                $t::powf(self, n)
+            }
+
+            #[inline]
+            fn exp(self) -> $t {
+                $t::exp(self)
+            }
+
+            #[inline]
+            fn exp_m1(self) -> $t {
+                $t::exp_m1(self)
+            }
+
+            #[inline]
+            fn ln(self) -> $t {
+                $t::ln(self)
+            }
+
+            #[inline]
+            fn ln_1p(self) -> $t {
+                $t::ln_1p(self)
+            }
+
+            #[inline]
+            fn log2(self) -> $t {
+                $t::log2(self)
+            }
+
+            #[inline]
+            fn log10(self) -> $t {
+                $t::log10(self)
+            }
+
+            #[inline]
+            fn sin(self) -> $t {
+                $t::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> $t {
+                $t::cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> $t {
+                $t::tan(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> ($t, $t) {
+                $t::sin_cos(self)
+            }
+
+            #[inline]
+            fn asin(self) -> $t {
+                $t::asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> $t {
+                $t::acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> $t {
+                $t::atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: $t) -> $t {
+                $t::atan2(self, other)
+            }
+
+            #[inline]
+            fn sinh(self) -> $t {
+                $t::sinh(self)
+            }
+
+            #[inline]
+            fn cosh(self) -> $t {
+                $t::cosh(self)
+            }
+
+            #[inline]
+            fn tanh(self) -> $t {
+                $t::tanh(self)
+            }
+        }
+    )*};
+}
+
+#[cfg(all(feature = "transcendental", feature = "display"))]
+impl_multi_precision!(Double Quad);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd};
+
+    // Evaluates tan x two ways, and the difference in units of epsilon
+    #[cfg(all(feature = "transcendental", feature = "display"))]
+    fn tan_error<T: MultiPrecision>(x: T) -> T {
+        let (s, c) = x.sin_cos();
+        ((s / c - x.tan()) / x.tan()).abs() / T::EPSILON
+    }
+
+    #[test]
+    #[cfg(all(feature = "transcendental", feature = "display"))]
+    fn generic_functions() {
+        assert!(tan_error(dd!(0.7)) < dd!(4));
+        assert!(tan_error(qd!(0.7)) < qd!(4));
+
+        fn round_trip<T: MultiPrecision>(x: T) -> T {
+            x.ln()
+                .exp()
+                .log2()
+                .powf(T::ONE + T::ONE)
+                .sqrt()
+                .mul_pwr2(0.5)
+        }
+        assert!((round_trip(dd!(8)) - dd!(1.5)).abs() < dd!(1e-30));
+        assert!((round_trip(qd!(8)) - qd!(1.5)).abs() < qd!(1e-60));
+    }
+
+    #[test]
+    fn rounding_and_constants() {
+        fn parts<T: MultiPrecisionCore>(x: T) -> (Option<i128>, Option<i128>, Option<i128>, T) {
+            (x.floor_i128(), x.ceil_i128(), x.round_i128(), x.fract())
+        }
+        assert_eq!(
+            parts(dd!(-2.25)),
+            (Some(-3), Some(-2), Some(-2), dd!(-0.25))
+        );
+        assert_eq!(parts(qd!(2.5)), (Some(2), Some(3), Some(3), qd!(0.5)));
+        assert_eq!(parts(Quad::INFINITY).0, None);
+
+        fn full_turn<T: MultiPrecisionCore>() -> T {
+            T::TAU / T::PI - (T::FRAC_PI_2 + T::FRAC_PI_2) / T::PI
+        }
+        assert_eq!(full_turn::<Double>(), Double::ONE);
+        assert_eq!(full_turn::<Quad>(), Quad::ONE);
+    }
+
+    #[test]
+    fn defaults_and_identities() {
+        #[derive(Default)]
+        struct Sums<T> {
+            double: Double,
+            quad: Quad,
+            generic: T,
+        }
+
+        fn product<T: MultiPrecisionCore>(xs: &[T]) -> T {
+            xs.iter().fold(T::one(), |acc, &x| acc * x)
+        }
+
+        let sums = Sums::<Quad>::default();
+        assert_eq!(sums.double, Double::ZERO);
+        assert_eq!(sums.quad, Quad::ZERO);
+        assert!(sums.generic.is_zero() && sums.generic.is_sign_positive());
+        assert_eq!(Double::zero(), Double::default());
+        assert_eq!(product(&[dd!(2), dd!(3)]), dd!(6));
+        assert_eq!(product::<Quad>(&[]), Quad::ONE);
+    }
+}
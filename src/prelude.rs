@@ -0,0 +1,43 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! The most commonly used types, macros, and traits, for importing all at once.
+//!
+//! This re-exports [`Double`] and [`Quad`], the [`dd!`] and [`qd!`] macros (and
+//! `dd_lit!` and `qd_lit!` with the `macros` feature), the [`MultiPrecisionCore`] and
+//! [`MultiPrecision`] traits for code that works with either type, and the [`F64Ext`]
+//! trait that adds extended-precision methods to `f64`.
+//!
+//! # Examples
+//! ```
+//! use qd::prelude::*;
+//!
+//! fn third<T: MultiPrecisionCore>() -> T {
+//!     T::ONE / (T::ONE + T::ONE + T::ONE)
+//! }
+//!
+//! let x: Double = third();
+//! let y: Quad = third();
+//! assert_eq!(x, dd!(1; 3));
+//! assert_eq!(y, qd!(1; 3));
+//! assert_eq!(0.5.wide_add(0.25), dd!(0.75));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`dd!`]: crate::dd
+//! [`qd!`]: crate::qd
+//! [`MultiPrecisionCore`]: crate::MultiPrecisionCore
+//! [`MultiPrecision`]: crate::MultiPrecision
+//! [`F64Ext`]: crate::ext::F64Ext
+
+pub use crate::ext::F64Ext;
+pub use crate::{dd, qd, Double, MultiPrecisionCore, Quad};
+
+#[cfg(all(feature = "transcendental", feature = "display"))]
+pub use crate::MultiPrecision;
+
+#[cfg(feature = "macros")]
+pub use crate::{dd_lit, qd_lit};
@@ -3,6 +3,8 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use std::array::IntoIter;
+use std::fmt::{self, Debug, Formatter};
 use std::ops::Index;
 
 #[macro_use]
@@ -13,6 +15,15 @@ mod macros {
     /// defines a `From` implementation for. This includes `&str`, `Double`, `Quad`, any
     /// primitive number, and 2-, 3-, and 4-tuples of any of those primitive number types.
     ///
+    /// There are two other forms:
+    ///
+    /// * `qd!(a, b, c, d)` takes the four components of the `Quad` as `f64`s. Like [`new`],
+    ///   it uses them as they are, so they must already be normalized.
+    /// * `qd!(n; d)` divides `n` by `d`, each of which can be anything that the
+    ///   single-argument form accepts. This is ordinary `Quad` division, which is accurate
+    ///   to within a few ulps but not correctly rounded, so `qd!(1; 3)` is 1/3 computed in
+    ///   `Quad` precision rather than necessarily the `Quad` closest to it.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
@@ -22,11 +33,55 @@ mod macros {
     /// let expected = qd!("0.7071067811865475244008443621048490392848359376884740365883398690");
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
+    ///
+    /// assert!(qd!(1.0, 1e-20, 1e-40, 1e-60) == Quad::new(1.0, 1e-20, 1e-40, 1e-60));
+    /// let third = qd!("0.3333333333333333333333333333333333333333333333333333333333333333");
+    /// assert!((qd!(1; 3) - third).abs() < qd!(1e-64));
     /// ```
+    ///
+    /// [`new`]: crate::Quad::new
     #[macro_export]
     macro_rules! qd {
+        ($n:expr; $d:expr) => {
+            <$crate::Quad as ::std::convert::From<_>>::from($n)
+                / <$crate::Quad as ::std::convert::From<_>>::from($d)
+        };
+        ($a:expr, $b:expr, $c:expr, $d:expr) => {
+            <$crate::Quad as ::std::convert::From<_>>::from(($a, $b, $c, $d))
+        };
         ($x:expr) => {
-            $crate::Quad::from($x)
+            <$crate::Quad as ::std::convert::From<_>>::from($x)
+        };
+    }
+
+    /// Creates a new quad-double from a literal that's checked at compile time.
+    ///
+    /// This works just like [`dd_lit!`], except that it creates a `Quad`, so the literal
+    /// can have up to 65 significant digits. This macro is only available with the `macros`
+    /// feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, qd_lit, Quad};
+    /// let x = qd_lit!("0.7071067811865475244008443621048490392848359376884740365883398690");
+    /// assert!((x - qd!(2).sqrt().recip()).abs() < qd!(1e-64));
+    /// assert!(qd_lit!(1_000) == qd!(1000));
+    /// ```
+    ///
+    /// This doesn't compile:
+    /// ```compile_fail
+    /// # use qd::qd_lit;
+    /// let x = qd_lit!(1e99999);
+    /// ```
+    ///
+    /// [`dd_lit!`]: crate::dd_lit
+    #[cfg(feature = "macros")]
+    #[macro_export]
+    macro_rules! qd_lit {
+        ($($arg:tt)+) => {
+            <$crate::Quad as ::std::convert::From<_>>::from(
+                $crate::__macros::quad_literal!($($arg)+)
+            )
         };
     }
 }
@@ -38,20 +93,33 @@ mod test_macros;
 mod aconsts; // "a" for "associated", or maybe just to make it appear first in docs
 mod add;
 mod alg;
+#[cfg(feature = "transcendental")]
+mod bessel;
+mod comb;
 mod common;
 mod comp;
+#[cfg(feature = "display")]
 mod display;
 mod div;
+#[cfg(feature = "transcendental")]
+mod expint;
 mod from;
 mod from_str;
+#[cfg(feature = "transcendental")]
 mod hyper;
 mod iter;
 mod misc;
 mod mul;
 mod neg;
+#[cfg(feature = "transcendental")]
+mod polylog;
 mod rem;
+#[cfg(feature = "transcendental")]
+mod special;
 mod sub;
+#[cfg(feature = "transcendental")]
 mod trans;
+#[cfg(feature = "transcendental")]
 mod trig;
 
 /// A 256-bit floating-point number implemented as the unevaluated sum of four 64-bit
@@ -91,7 +159,7 @@ mod trig;
 /// [`from_str`]: #method.from_str
 /// [`qd!`]: macro.qd.html
 /// [*dyadic*]: https://en.wikipedia.org/wiki/Dyadic_rational
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Default)]
 pub struct Quad(f64, f64, f64, f64);
 
 impl Quad {
@@ -116,6 +184,170 @@ impl Quad {
     pub const fn new(a: f64, b: f64, c: f64, d: f64) -> Quad {
         Quad(a, b, c, d)
     }
+
+    /// Creates a `Quad` from components that are already normalized, without checking or
+    /// renormalizing them.
+    ///
+    /// The components have to satisfy the invariant that [`is_normalized`] checks: each one
+    /// can be no more than half a unit in the last place of the one before it, so that
+    /// adding it to that one in `f64` arithmetic changes nothing. Components like that come
+    /// from tables of precomputed constants, from other quad-double libraries, or from the
+    /// components of an existing `Quad`. This does the same thing as [`new`], but its name
+    /// makes that assumption visible where it's called.
+    ///
+    /// Components that aren't normalized make a `Quad` that compares, classifies, and
+    /// computes incorrectly, so when in doubt, check the result with [`is_normalized`] or
+    /// repair it with [`canonicalize`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// // The components of Quad::PI
+    /// let x = Quad::from_parts_unchecked(
+    ///     3.141592653589793,
+    ///     1.2246467991473532e-16,
+    ///     -2.9947698097183397e-33,
+    ///     1.1124542208633652e-49,
+    /// );
+    /// assert!(x.is_normalized());
+    /// assert!(x == Quad::PI);
+    /// ```
+    ///
+    /// [`is_normalized`]: #method.is_normalized
+    /// [`canonicalize`]: #method.canonicalize
+    /// [`new`]: #method.new
+    #[inline]
+    pub const fn from_parts_unchecked(c0: f64, c1: f64, c2: f64, c3: f64) -> Quad {
+        Quad(c0, c1, c2, c3)
+    }
+
+    /// Returns the first component of the `Quad`.
+    ///
+    /// This is the `f64` closest to the value of the `Quad`, the same `f64` that converting
+    /// it with `into` produces.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.c0() == 3.141592653589793);
+    /// ```
+    #[inline]
+    pub const fn c0(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the second component of the `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.c1() == 1.2246467991473532e-16);
+    /// ```
+    #[inline]
+    pub const fn c1(self) -> f64 {
+        self.1
+    }
+
+    /// Returns the third component of the `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.c2() == -2.9947698097183397e-33);
+    /// ```
+    #[inline]
+    pub const fn c2(self) -> f64 {
+        self.2
+    }
+
+    /// Returns the fourth component of the `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.c3() == 1.1124542208633652e-49);
+    /// ```
+    #[inline]
+    pub const fn c3(self) -> f64 {
+        self.3
+    }
+
+    /// Returns the leading component of the `Quad`. This is the same as [`c0`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.hi() == std::f64::consts::PI);
+    /// ```
+    ///
+    /// [`c0`]: #method.c0
+    #[inline]
+    pub const fn hi(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the last, and smallest, component of the `Quad`. This is the same as
+    /// [`c3`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.lo() == 1.1124542208633652e-49);
+    /// assert!(Quad::ONE.lo() == 0.0);
+    /// ```
+    ///
+    /// [`c3`]: #method.c3
+    #[inline]
+    pub const fn lo(self) -> f64 {
+        self.3
+    }
+
+    /// Returns a `Quad` with the leading component replaced by `hi` and the other
+    /// components kept.
+    ///
+    /// The result is renormalized with [`canonicalize`] if the other components are too
+    /// large to go with the new leading component, so it's always a valid `Quad` equal to
+    /// the sum of `hi` and the other components, to within the precision of a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::PI.with_hi(3.0);
+    /// assert!(x.hi() == 3.0);
+    /// assert!(x.c1() == Quad::PI.c1());
+    ///
+    /// // The other components are too large to follow a leading 1e-20
+    /// let y = Quad::new(1.0, 1e-17, 0.0, 0.0).with_hi(1e-20);
+    /// assert!(y.is_normalized());
+    /// assert!(y == Quad::new(1e-20, 0.0, 0.0, 0.0) + Quad::new(1e-17, 0.0, 0.0, 0.0));
+    /// ```
+    ///
+    /// [`canonicalize`]: #method.canonicalize
+    #[inline]
+    pub fn with_hi(self, hi: f64) -> Quad {
+        Quad(hi, self.1, self.2, self.3).canonicalize()
+    }
+
+    /// Returns an iterator over the components of the `Quad`.
+    ///
+    /// The iterator yields the components in order, from the largest to the smallest.
+    /// Iterating over the `Quad` itself does the same thing. Like [indexing], this is meant
+    /// for code that needs the components themselves, such as code that passes them to
+    /// another language or prints them for debugging.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let components: Vec<f64> = Quad::PI.iter().collect();
+    /// assert_eq!(components.len(), 4);
+    /// assert_eq!(components[0], Quad::PI[0]);
+    /// ```
+    ///
+    /// [indexing]: #impl-Index<usize>
+    #[inline]
+    pub fn iter(&self) -> IntoIter<f64, 4> {
+        IntoIterator::into_iter([self.0, self.1, self.2, self.3])
+    }
 }
 
 impl Index<usize> for Quad {
@@ -158,6 +390,81 @@ impl Index<usize> for Quad {
     }
 }
 
+impl IntoIterator for Quad {
+    type Item = f64;
+    type IntoIter = IntoIter<f64, 4>;
+
+    /// Returns an iterator over the components of the `Quad`.
+    ///
+    /// This is the same as [`iter`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// for (i, component) in Quad::PI.into_iter().enumerate() {
+    ///     assert_eq!(component, Quad::PI[i]);
+    /// }
+    /// ```
+    ///
+    /// [`iter`]: #method.iter
+    #[inline]
+    fn into_iter(self) -> IntoIter<f64, 4> {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &Quad {
+    type Item = f64;
+    type IntoIter = IntoIter<f64, 4>;
+
+    /// Returns an iterator over the components of the referenced `Quad`.
+    ///
+    /// This is the same as [`iter`].
+    ///
+    /// [`iter`]: struct.Quad.html#method.iter
+    #[inline]
+    fn into_iter(self) -> IntoIter<f64, 4> {
+        self.iter()
+    }
+}
+
+impl Debug for Quad {
+    /// Formats a `Double` for display when the "`?`" formatting option is specified.
+    ///
+    /// See [`Display::fmt`](#method.fmt-1) for more information.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let alt = f.alternate();
+        let mut str = String::from("Quad(");
+        if alt {
+            str.push_str("\n    ");
+        }
+        str.push_str(format!("{:e},", self.0).as_str());
+        if alt {
+            str.push_str("\n    ");
+        } else {
+            str.push(' ');
+        }
+        str.push_str(format!("{:e},", self.1).as_str());
+        if alt {
+            str.push_str("\n    ");
+        } else {
+            str.push(' ');
+        }
+        str.push_str(format!("{:e},", self.2).as_str());
+        if alt {
+            str.push_str("\n    ");
+        } else {
+            str.push(' ');
+        }
+        str.push_str(format!("{:e}", self.3).as_str());
+        if alt {
+            str.push('\n');
+        }
+        str.push(')');
+        write!(f, "{}", str)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -176,4 +483,38 @@ mod tests {
         index_two: Quad::PI[2], Quad::PI.2;
         index_three: Quad::PI[3], Quad::PI.3;
     );
+
+    test_all_exact!(
+        macro_expr: qd!(2.0 * 3.0 + 1.0), qd!(7);
+        macro_components: qd!(Quad::PI[0], Quad::PI[1], Quad::PI[2], Quad::PI[3]), Quad::PI;
+        macro_ratio: qd!(1; 3), Quad::ONE / Quad::from(3);
+        macro_ratio_exprs: qd!(2 + 5; "4"), qd!(1.75);
+    );
+
+    test!(iter_components: {
+        let v: Vec<f64> = Quad::PI.iter().collect();
+        assert_eq!(v, vec![Quad::PI.0, Quad::PI.1, Quad::PI.2, Quad::PI.3]);
+        let w: Vec<f64> = Quad::PI.into_iter().collect();
+        assert_eq!(w, v);
+        let x: Vec<f64> = (&Quad::PI).into_iter().collect();
+        assert_eq!(x, v);
+    });
+
+    test!(accessors: {
+        let q = Quad::PI;
+        let x = Quad::from_parts_unchecked(q.c0(), q.c1(), q.c2(), q.c3());
+        assert_eq!(x, Quad::PI);
+        assert_eq!([x.c0(), x.c1(), x.c2(), x.c3()], [x[0], x[1], x[2], x[3]]);
+        assert_eq!((x.hi(), x.lo()), (x.c0(), x.c3()));
+    });
+    test!(with_hi: {
+        let x = Quad::PI.with_hi(-3.0);
+        assert_eq!(x.c0(), -3.0);
+        assert_eq!((x.c1(), x.c2(), x.c3()), (Quad::PI.c1(), Quad::PI.c2(), Quad::PI.c3()));
+        let y = Quad::new(1.0, 1e-17, 1e-34, 0.0).with_hi(-1e-20);
+        assert!(y.is_normalized());
+        assert_eq!(y, Quad::new(-1e-20, 0.0, 0.0, 0.0) + Quad::new(1e-17, 1e-34, 0.0, 0.0));
+        assert!(Quad::PI.with_hi(f64::NAN).is_nan());
+        assert_eq!(Quad::PI.with_hi(f64::INFINITY), Quad::INFINITY);
+    });
 }
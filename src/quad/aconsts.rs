@@ -3,6 +3,7 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::consts::compute::Constant;
 use crate::quad::Quad;
 use std::f64;
 
@@ -33,9 +34,33 @@ impl Quad {
     );
 
     /// Smallest positive normal `Quad` value.
+    ///
+    /// This is the smallest value for which all four components can be normal `f64`s, so it
+    /// is the same as [`MIN_FULL_PRECISION`]. Smaller `Quad`s (down to the smallest
+    /// positive `f64`) can be represented, but with less precision than
+    /// [`MANTISSA_DIGITS`].
+    ///
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    /// [`MIN_FULL_PRECISION`]: #associatedconstant.MIN_FULL_PRECISION
     pub const MIN_POSITIVE: Quad = Quad(1.6259745436952323e-260, 0e0, 0e0, 0e0);
 
+    /// Smallest positive `Quad` value that has the full precision of [`MANTISSA_DIGITS`],
+    /// 2<sup>-863</sup>.
+    ///
+    /// Below this, the trailing components would have to be smaller than the smallest
+    /// normal `f64`, so they lose bits and the effective precision of the `Quad` degrades
+    /// smoothly. By 10<sup>-290</sup> it's about half of full precision, and below
+    /// 2<sup>-1022</sup> it's no better than an `f64`. The precision of a particular value
+    /// can be found with [`effective_precision`].
+    ///
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    /// [`effective_precision`]: #method.effective_precision
+    pub const MIN_FULL_PRECISION: Quad = Quad(1.6259745436952323e-260, 0e0, 0e0, 0e0);
+
     /// Largest finite `Quad` value.
+    ///
+    /// The first component is the largest finite `f64`, and the others are the largest
+    /// values that can be added to it without the sum rounding up to infinity.
     pub const MAX: Quad = Quad(
         1.7976931348623157e308,
         9.979201547673598e291,
@@ -55,6 +80,34 @@ impl Quad {
     /// Maximum possible power of 10 exponent.
     pub const MAX_10_EXP: i32 = 308;
 
+    /// The largest argument for which [`exp`] doesn't overflow. Larger arguments make
+    /// [`exp`] return [`INFINITY`].
+    ///
+    /// This is the natural logarithm of the largest `f64`. [`MAX`] is a tiny bit larger
+    /// than that, but a result in between would have a first component that rounds up to
+    /// infinity.
+    ///
+    /// [`exp`]: #method.exp
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub const MAX_EXP_ARG: Quad = Quad(
+        709.782712893384,
+        2.3636017071323592e-14,
+        5.78306368271781e-31,
+        -3.7140257680418287e-47,
+    );
+
+    /// The smallest argument for which [`exp`] doesn't underflow, ln(2<sup>-1075</sup>).
+    /// Smaller arguments make [`exp`] return zero.
+    ///
+    /// [`exp`]: #method.exp
+    pub const MIN_EXP_ARG: Quad = Quad(
+        -745.1332191019412,
+        1.4483164125345337e-14,
+        -1.0480684525835031e-30,
+        -7.883391556791828e-47,
+    );
+
     /// Not a Number (NaN).
     pub const NAN: Quad = Quad(f64::NAN, 0.0, 0.0, 0.0);
 
@@ -77,194 +130,105 @@ impl Quad {
     pub const NEG_ONE: Quad = Quad(-1.0, 0.0, 0.0, 0.0);
 
     /// Archimedes' constant (π)
-    pub const PI: Quad = Quad(
-        3.141592653589793e0,
-        1.2246467991473532e-16,
-        -2.9947698097183397e-33,
-        1.1124542208633655e-49,
-    );
+    pub const PI: Quad = Constant::pi().to_quad();
 
     /// The full circle constant (τ), or 2π
-    pub const TAU: Quad = Quad(
-        6.283185307179586e0,
-        2.4492935982947064e-16,
-        -5.989539619436679e-33,
-        2.224908441726731e-49,
-    );
+    pub const TAU: Quad = Constant::pi().times(Constant::int(2)).to_quad();
 
     /// π/2
-    pub const FRAC_PI_2: Quad = Quad(
-        1.5707963267948966e0,
-        6.123233995736766e-17,
-        -1.4973849048591698e-33,
-        5.562271104316827e-50,
-    );
+    pub const FRAC_PI_2: Quad = Constant::pi().over(Constant::int(2)).to_quad();
 
     /// π/3
-    pub const FRAC_PI_3: Quad = Quad(
-        1.0471975511965979e0,
-        -1.072081766451091e-16,
-        -9.982566032394464e-34,
-        -7.69561536018215e-50,
-    );
+    pub const FRAC_PI_3: Quad = Constant::pi().over(Constant::int(3)).to_quad();
 
     /// π/4
-    pub const FRAC_PI_4: Quad = Quad(
-        7.853981633974483e-1,
-        3.061616997868383e-17,
-        -7.486924524295849e-34,
-        2.781135552158413e-50,
-    );
+    pub const FRAC_PI_4: Quad = Constant::pi().over(Constant::int(4)).to_quad();
 
     /// π/6
-    pub const FRAC_PI_6: Quad = Quad(
-        5.235987755982989e-1,
-        -5.360408832255455e-17,
-        -4.991283016197232e-34,
-        -3.847807680091076e-50,
-    );
+    pub const FRAC_PI_6: Quad = Constant::pi().over(Constant::int(6)).to_quad();
 
     /// π/8
-    pub const FRAC_PI_8: Quad = Quad(
-        3.9269908169872414e-1,
-        1.5308084989341915e-17,
-        -3.7434622621479246e-34,
-        1.3905677760792066e-50,
-    );
+    pub const FRAC_PI_8: Quad = Constant::pi().over(Constant::int(8)).to_quad();
 
     /// π/16
-    pub const FRAC_PI_16: Quad = Quad(
-        1.9634954084936207e-1,
-        7.654042494670958e-18,
-        -1.8717311310739623e-34,
-        6.952838880396033e-51,
-    );
+    pub const FRAC_PI_16: Quad = Constant::pi().over(Constant::int(16)).to_quad();
 
     /// 3π/2
-    pub const FRAC_3_PI_2: Quad = Quad(
-        4.71238898038469e0,
-        1.8369701987210297e-16,
-        7.8337969295008e-33,
-        -5.173596326540972e-49,
-    );
+    pub const FRAC_3_PI_2: Quad = Constant::pi().times(Constant::ratio(3, 2)).to_quad();
 
     /// 3π/4
-    pub const FRAC_3_PI_4: Quad = Quad(
-        2.356194490192345e0,
-        9.184850993605148e-17,
-        3.9168984647504e-33,
-        -2.586798163270486e-49,
-    );
+    pub const FRAC_3_PI_4: Quad = Constant::pi().times(Constant::ratio(3, 4)).to_quad();
 
     /// 5π/4
-    pub const FRAC_5_PI_4: Quad = Quad(
-        3.9269908169872414e0,
-        1.5308084989341916e-16,
-        -9.90643808418708e-33,
-        4.811706604997218e-49,
-    );
+    pub const FRAC_5_PI_4: Quad = Constant::pi().times(Constant::ratio(5, 4)).to_quad();
 
     /// 7π/4
-    pub const FRAC_7_PI_4: Quad = Quad(
-        5.497787143782138e0,
-        2.143131898507868e-16,
-        9.221286550320605e-34,
-        2.3622547205188384e-50,
-    );
+    pub const FRAC_7_PI_4: Quad = Constant::pi().times(Constant::ratio(7, 4)).to_quad();
 
     /// 1/π
-    pub const FRAC_1_PI: Quad = Quad(
-        3.183098861837907e-1,
-        -1.9678676675182486e-17,
-        -1.0721436282893004e-33,
-        8.053563926594112e-50,
-    );
+    pub const FRAC_1_PI: Quad = Constant::pi().recip().to_quad();
 
     /// 2/π
-    pub const FRAC_2_PI: Quad = Quad(
-        6.366197723675814e-1,
-        -3.935735335036497e-17,
-        -2.1442872565786008e-33,
-        1.6107127853188224e-49,
-    );
+    pub const FRAC_2_PI: Quad = Constant::int(2).over(Constant::pi()).to_quad();
 
     /// 2/√π
-    pub const FRAC_2_SQRT_PI: Quad = Quad(
-        1.1283791670955126e0,
-        1.533545961316588e-17,
-        -4.765684596693686e-34,
-        -2.0077946616552623e-50,
-    );
+    pub const FRAC_2_SQRT_PI: Quad = Constant::int(2).over(Constant::pi().sqrt()).to_quad();
+
+    /// 1/√π
+    pub const FRAC_1_SQRT_PI: Quad = Constant::pi().sqrt().recip().to_quad();
+
+    /// 1/√(2π)
+    pub const FRAC_1_SQRT_2PI: Quad = Constant::pi()
+        .times(Constant::int(2))
+        .sqrt()
+        .recip()
+        .to_quad();
 
     /// √2
-    pub const SQRT_2: Quad = Quad(
-        1.4142135623730951e0,
-        -9.667293313452913e-17,
-        4.1386753086994136e-33,
-        4.935546991468354e-50,
-    );
+    pub const SQRT_2: Quad = Constant::int(2).sqrt().to_quad();
 
     /// 1/√2
-    pub const FRAC_1_SQRT_2: Quad = Quad(
-        7.071067811865476e-1,
-        -4.833646656726457e-17,
-        2.0693376543497068e-33,
-        2.467773495734177e-50,
-    );
+    pub const FRAC_1_SQRT_2: Quad = Constant::int(2).sqrt().recip().to_quad();
+
+    /// √3
+    pub const SQRT_3: Quad = Constant::int(3).sqrt().to_quad();
+
+    /// 1/√3
+    pub const FRAC_1_SQRT_3: Quad = Constant::int(3).sqrt().recip().to_quad();
 
     /// Euler's number (*e*)
-    pub const E: Quad = Quad(
-        2.718281828459045e0,
-        1.4456468917292502e-16,
-        -2.1277171080381768e-33,
-        1.5156301598412193e-49,
+    pub const E: Quad = Constant::e().to_quad();
+
+    /// The golden ratio (φ)
+    pub const PHI: Quad = Constant::int(5)
+        .sqrt()
+        .plus(Constant::int(1))
+        .over(Constant::int(2))
+        .to_quad();
+
+    /// The Euler-Mascheroni constant (γ)
+    pub const EGAMMA: Quad = Quad(
+        5.772156649015329e-1,
+        -4.942915152430645e-18,
+        -2.322111740706957e-34,
+        1.7004947433810964e-50,
     );
 
     /// log<sub>2</sub> 10
-    pub const LOG2_10: Quad = Quad(
-        3.321928094887362e0,
-        1.661617516973592e-16,
-        1.2215512178458181e-32,
-        5.95511897027825e-49,
-    );
+    pub const LOG2_10: Quad = Constant::ln_10().over(Constant::ln_2()).to_quad();
 
     /// log<sub>2</sub> *e*
-    pub const LOG2_E: Quad = Quad(
-        1.4426950408889634e0,
-        2.0355273740931033e-17,
-        -1.0614659956117258e-33,
-        -1.3836716780181397e-50,
-    );
+    pub const LOG2_E: Quad = Constant::ln_2().recip().to_quad();
 
     /// log<sub>10</sub> 2
-    pub const LOG10_2: Quad = Quad(
-        3.010299956639812e-1,
-        -2.8037281277851704e-18,
-        5.471948402314639e-35,
-        5.105138983107093e-51,
-    );
+    pub const LOG10_2: Quad = Constant::ln_2().over(Constant::ln_10()).to_quad();
 
     /// log<sub>10</sub> *e*
-    pub const LOG10_E: Quad = Quad(
-        4.342944819032518e-1,
-        1.098319650216765e-17,
-        3.717181233110959e-34,
-        7.73448434650429e-51,
-    );
+    pub const LOG10_E: Quad = Constant::ln_10().recip().to_quad();
 
     /// log<sub>*e*</sub> 2
-    pub const LN_2: Quad = Quad(
-        6.931471805599453e-1,
-        2.3190468138462996e-17,
-        5.707708438416212e-34,
-        -3.582432210601812e-50,
-    );
+    pub const LN_2: Quad = Constant::ln_2().to_quad();
 
     /// log<sub>*e*</sub> 10
-    pub const LN_10: Quad = Quad(
-        2.302585092994046e0,
-        -2.1707562233822494e-16,
-        -9.984262454465777e-33,
-        -4.023357454450206e-49,
-    );
+    pub const LN_10: Quad = Constant::ln_10().to_quad();
 }
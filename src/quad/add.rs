@@ -3,7 +3,9 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::Double;
 use crate::quad::Quad;
 use std::ops::{Add, AddAssign};
 
@@ -36,70 +38,12 @@ impl Add for Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
-    #[allow(clippy::suspicious_arithmetic_impl, clippy::many_single_char_names)]
+    #[inline]
     fn add(self, other: Quad) -> Quad {
-        match self.pre_add(&other) {
-            Some(r) => r,
-            None => {
-                let mut i = 0;
-                let mut j = 0;
-                let mut k = 0;
-
-                let mut x = [0.0; 4];
-
-                // These two assignments, along with the reassignments of the same variables
-                // in the `accumulate` call below, act as a merge sort. The largest
-                // component between the two quads is operated on first, then the second
-                // largest, and so on.
-                let u = if self[i].abs() > other[j].abs() {
-                    index_and_inc(self, &mut i)
-                } else {
-                    index_and_inc(other, &mut j)
-                };
-                let v = if self[i].abs() > other[j].abs() {
-                    index_and_inc(self, &mut i)
-                } else {
-                    index_and_inc(other, &mut j)
-                };
-                let (mut u, mut v) = u::renorm2(u, v);
-
-                while k < 4 {
-                    if i >= 4 && j >= 4 {
-                        x[k] = u;
-                        if k < 3 {
-                            k += 1;
-                            x[k] = v;
-                        }
-                        break;
-                    }
-
-                    let t = if i >= 4 {
-                        index_and_inc(other, &mut j)
-                    } else if j >= 4 || self[i].abs() > other[j].abs() {
-                        index_and_inc(self, &mut i)
-                    } else {
-                        index_and_inc(other, &mut j)
-                    };
-
-                    let (s, y, z) = u::accumulate(u, v, t);
-                    u = y;
-                    v = z;
-
-                    if s != 0.0 {
-                        x[k] = s;
-                        k += 1;
-                    }
-                }
-
-                for k in i..4 {
-                    x[3] += self[k];
-                }
-                for k in j..4 {
-                    x[3] += other[k];
-                }
-                let (a, b, c, d) = u::renorm4(x[0], x[1], x[2], x[3]);
-                Quad(a, b, c, d)
-            }
+        if cfg!(feature = "fast_arith") {
+            self.add_fast(other)
+        } else {
+            self.add_accurate(other)
         }
     }
 }
@@ -222,7 +166,219 @@ impl AddAssign<&Quad> for Quad {
     }
 }
 
+impl AddAssign<f64> for Quad {
+    /// Adds an `f64` to this `Quad`, modifying this one to equal the result.
+    ///
+    /// This implements the `+=` operator between a `Quad` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x += 2.0;
+    /// let expected = qd!("5.141592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: f64) {
+        let r = self.add(Quad(other, 0.0, 0.0, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
+impl AddAssign<Double> for Quad {
+    /// Adds a `Double` to this `Quad`, modifying this one to equal the result.
+    ///
+    /// This implements the `+=` operator between a `Quad` and a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x += dd!(2);
+    /// let expected = qd!("5.141592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Double) {
+        let (a, b): (f64, f64) = other.into();
+        let r = self.add(Quad(a, b, 0.0, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
 impl Quad {
+    /// Adds this `Quad` to another using the accurate addition algorithm.
+    ///
+    /// This is the algorithm used by the `+` operator, unless the `fast_arith` feature is
+    /// enabled. The components of the two numbers are merged in order of magnitude and
+    /// accumulated one at a time, which keeps the relative error of the result within a
+    /// couple of ULPs even when the two numbers nearly cancel each other out.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::E.add_accurate(Quad::PI);
+    /// let expected = qd!("5.859874482048838473822930854632165381954416493075065395941912220");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[allow(clippy::many_single_char_names)]
+    #[inline]
+    pub fn add_accurate(self, other: Quad) -> Quad {
+        match self.pre_add(&other) {
+            Some(r) => r,
+            None => {
+                let mut i = 0;
+                let mut j = 0;
+                let mut k = 0;
+
+                let mut x = [0.0; 4];
+
+                // These two assignments, along with the reassignments of the same variables
+                // in the `accumulate` call below, act as a merge sort. The largest
+                // component between the two quads is operated on first, then the second
+                // largest, and so on.
+                let u = if self[i].abs() > other[j].abs() {
+                    index_and_inc(self, &mut i)
+                } else {
+                    index_and_inc(other, &mut j)
+                };
+                let v = if self[i].abs() > other[j].abs() {
+                    index_and_inc(self, &mut i)
+                } else {
+                    index_and_inc(other, &mut j)
+                };
+                let (mut u, mut v) = u::renorm2(u, v);
+
+                while k < 4 {
+                    if i >= 4 && j >= 4 {
+                        x[k] = u;
+                        if k < 3 {
+                            k += 1;
+                            x[k] = v;
+                        }
+                        break;
+                    }
+
+                    let t = if i >= 4 {
+                        index_and_inc(other, &mut j)
+                    } else if j >= 4 || self[i].abs() > other[j].abs() {
+                        index_and_inc(self, &mut i)
+                    } else {
+                        index_and_inc(other, &mut j)
+                    };
+
+                    let (s, y, z) = u::accumulate(u, v, t);
+                    u = y;
+                    v = z;
+
+                    if s != 0.0 {
+                        x[k] = s;
+                        k += 1;
+                    }
+                }
+
+                for k in i..4 {
+                    x[3] += self[k];
+                }
+                for k in j..4 {
+                    x[3] += other[k];
+                }
+                let (a, b, c, d) = u::renorm4(x[0], x[1], x[2], x[3]);
+                Quad(a, b, c, d)
+            }
+        }
+    }
+
+    /// Adds this `Quad` to another using the fast ("sloppy") addition algorithm.
+    ///
+    /// This adds the corresponding components of the two numbers pairwise and then folds
+    /// the errors of those additions back in, which avoids the data-dependent branching of
+    /// [`add_accurate`] and is considerably faster. The result is just as accurate when the
+    /// two numbers have the same sign, but when they have opposite signs and nearly cancel,
+    /// the relative error can grow well beyond a couple of ULPs.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::E.add_fast(Quad::PI);
+    /// let expected = qd!("5.859874482048838473822930854632165381954416493075065395941912220");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`add_accurate`]: #method.add_accurate
+    pub fn add_fast(self, other: Quad) -> Quad {
+        match self.pre_add(&other) {
+            Some(r) => r,
+            None => {
+                let (s0, t0) = p::two_sum(self.0, other.0);
+                let (s1, t1) = p::two_sum(self.1, other.1);
+                let (s2, t2) = p::two_sum(self.2, other.2);
+                let (s3, t3) = p::two_sum(self.3, other.3);
+
+                let (s1, t0) = p::two_sum(s1, t0);
+                let (s2, t0, t1) = u::three_three_sum(s2, t0, t1);
+                let (s3, t0) = u::three_two_sum(s3, t0, t2);
+                let t0 = t0 + t1 + t3;
+
+                let (a, b, c, d) = u::renorm5(s0, s1, s2, s3, t0);
+                Quad(a, b, c, d)
+            }
+        }
+    }
+
+    /// Adds this `Quad` to another, returning both the sum and the rounding error of the
+    /// addition.
+    ///
+    /// The sum is the same one that the `+` operator produces. The error is the difference
+    /// between the exact sum and the rounded one, itself rounded to a `Quad`, so that the
+    /// two together represent the exact sum to about twice the precision of a `Quad`. This
+    /// is the quad-double counterpart of the [`two_sum`] error-free transformation, and
+    /// it's what compensated algorithms (like compensated summation) are built from.
+    ///
+    /// If the sum is infinite or `NaN`, the error is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::PI;
+    /// let y = Quad::ONE.ldexp(-300);
+    /// let (s, e) = x.add_with_err(y);
+    ///
+    /// // The sum is too wide to fit, so `y` ends up entirely in the error
+    /// assert!(s == x);
+    /// assert!(e == y);
+    /// ```
+    ///
+    /// [`two_sum`]: crate::eft::two_sum
+    pub fn add_with_err(self, other: Quad) -> (Quad, Quad) {
+        let r = self + other;
+        if r.is_finite() {
+            let mut terms = [
+                self.3, other.3, -r.3, self.2, other.2, -r.2, self.1, other.1, -r.1, self.0,
+                other.0, -r.0,
+            ];
+            let (a, b, c, d) = u::distill4(&mut terms);
+            (r, Quad(a, b, c, d))
+        } else {
+            (r, Quad::ZERO)
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -266,6 +422,30 @@ impl Quad {
     }
 }
 
+impl Quad {
+    /// Adds another `Quad` to this one, returning `None` if the result is not finite.
+    ///
+    /// This mirrors the `checked_add` methods of the primitive integer types. The result is
+    /// `None` if the sum overflows to infinity or the result is `NaN`; this is always the
+    /// case when either argument is already infinite or `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.checked_add(Quad::E).is_some());
+    /// assert!(Quad::MAX.checked_add(Quad::MAX).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_add(self, other: Quad) -> Option<Quad> {
+        let r = self + other;
+        if r.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -360,6 +540,36 @@ mod tests {
             Quad::NAN + Quad::NAN;
     );
 
+    // add_accurate/add_fast tests
+    test_all_near!(
+        accurate_num_num:
+            qd!("5.8598744820488384738229308546321653819544164930750653959419122200308"),
+            Quad::PI.add_accurate(Quad::E);
+        accurate_num_neg_num:
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
+            Quad::PI.add_accurate(-Quad::E);
+        fast_num_num:
+            qd!("5.8598744820488384738229308546321653819544164930750653959419122200308"),
+            Quad::PI.add_fast(Quad::E);
+        fast_num_neg_num:
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
+            Quad::PI.add_fast(-Quad::E);
+        fast_three_nums:
+            Quad::PI + Quad::E + Quad::LN_2,
+            Quad::PI.add_fast(Quad::E).add_fast(Quad::LN_2);
+    );
+    test_all_exact!(
+        fast_inf_num:
+            Quad::INFINITY,
+            Quad::INFINITY.add_fast(Quad::ONE);
+        fast_inf_neg_inf:
+            Quad::NAN,
+            Quad::INFINITY.add_fast(Quad::NEG_INFINITY);
+        fast_nan_num:
+            Quad::NAN,
+            Quad::NAN.add_fast(Quad::ONE);
+    );
+
     // Assign tests. Assign code delegates to add code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -373,5 +583,65 @@ mod tests {
             b += &Quad::E;
             near!(qd!("5.8598744820488384738229308546321653819544164930750653959419122200308"), b);
         }
+        assign_f64: {
+            let mut c = Quad::PI;
+            c += 2.0;
+            near!(qd!("5.1415926535897932384626433832795028841971693993751058209749445923078"), c);
+        }
+        assign_double: {
+            let mut d = Quad::PI;
+            d += Double::from(2.0);
+            near!(qd!("5.1415926535897932384626433832795028841971693993751058209749445923078"), d);
+        }
+    );
+
+    // add_with_err tests
+    test_all!(
+        add_with_err_split: {
+            let x = Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), 2f64.powi(-180));
+            let y = Quad(2f64.powi(-240), 2f64.powi(-300), 0.0, 0.0);
+            let (s, e) = x.add_with_err(y);
+            exact!(x, s);
+            exact!(y, e);
+        }
+        add_with_err_no_err: {
+            let (s, e) = qd!(1).add_with_err(qd!(2));
+            exact!(qd!(3), s);
+            exact!(Quad::ZERO, e);
+        }
+        add_with_err_cancel: {
+            let (s, e) = Quad::PI.add_with_err(-Quad::PI);
+            exact!(Quad::ZERO, s);
+            exact!(Quad::ZERO, e);
+        }
+        add_with_err_pi_e: {
+            let (s, e) = Quad::PI.add_with_err(Quad::E);
+            exact!(Quad::PI + Quad::E, s);
+            assert!(e.abs() < s * Quad::EPSILON);
+            exact!(s, s + e);
+        }
+        add_with_err_inf: {
+            let (s, e) = Quad::INFINITY.add_with_err(Quad::ONE);
+            exact!(Quad::INFINITY, s);
+            exact!(Quad::ZERO, e);
+        }
+        add_with_err_overflow: {
+            let (s, e) = Quad::MAX.add_with_err(Quad::MAX);
+            exact!(Quad::MAX + Quad::MAX, s);
+            exact!(Quad::ZERO, e);
+        }
+        add_with_err_nan: {
+            let (s, e) = Quad::NAN.add_with_err(Quad::ONE);
+            exact!(Quad::NAN, s);
+            exact!(Quad::ZERO, e);
+        }
+    );
+
+    // checked_add tests
+    test_all_assert!(
+        checked_add_num: Quad::PI.checked_add(Quad::E) == Some(Quad::PI + Quad::E);
+        checked_add_nan: Quad::PI.checked_add(Quad::NAN).is_none();
+        checked_add_inf: Quad::INFINITY.checked_add(Quad::PI).is_none();
+        checked_add_overflow: Quad::MAX.checked_add(Quad::MAX).is_none();
     );
 }
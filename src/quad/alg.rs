@@ -35,6 +35,73 @@ impl Quad {
         )
     }
 
+    /// Multiplies the `Quad` by `k`, which must be a power of two.
+    ///
+    /// Multiplying by a power of two only changes the exponents of the components, so this
+    /// just multiplies each component by `k` instead of running the full multiplication
+    /// algorithm. The result is exact unless it overflows or some of its components become
+    /// subnormal. This is useful when the scale factor is already available as an `f64`;
+    /// [`scale_b`] takes an exponent instead.
+    ///
+    /// If `k` is not a power of two, the result is neither exact nor necessarily
+    /// normalized. Use ordinary multiplication for those values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(3).mul_pwr2(0.25) == qd!(0.75));
+    /// assert!(Quad::PI.mul_pwr2(2.0) == Quad::PI.ldexp(1));
+    /// ```
+    ///
+    /// [`scale_b`]: #method.scale_b
+    #[inline]
+    pub fn mul_pwr2(self, k: f64) -> Quad {
+        Quad(self.0 * k, self.1 * k, self.2 * k, self.3 * k)
+    }
+
+    /// Calculates x · 2<sup>n</sup>, where *x* is the `Quad` and *n* is an integer, exactly
+    /// and over the whole range of exponents.
+    ///
+    /// This is like [`ldexp`], except that [`ldexp`] computes 2<sup>n</sup> as an `f64`,
+    /// which overflows or underflows when *n* is outside of the range of normal `f64`
+    /// exponents (-1022 to 1023). This function applies larger scales in several steps,
+    /// so, for example, scaling by 2<sup>-2000</sup> works as expected. Like C's `scalbn`,
+    /// the result is only rounded if it ends up subnormal, and it saturates to zero or
+    /// infinity if it's out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(5).scale_b(3) == qd!(40));
+    ///
+    /// let x = qd!(3).scale_b(1000);
+    /// assert!(x.scale_b(-2000) == qd!(3).scale_b(-1000));
+    /// // `ldexp` can't represent 2^-2000, so its result underflows
+    /// assert!(x.ldexp(-2000) == Quad::ZERO);
+    ///
+    /// // The smallest subnormal number, 2^-1074
+    /// assert!(Quad::ONE.scale_b(-1074)[0] == f64::from_bits(1));
+    /// ```
+    ///
+    /// [`ldexp`]: #method.ldexp
+    pub fn scale_b(self, n: i32) -> Quad {
+        // Every finite nonzero `Quad` overflows or underflows by the time it's scaled by
+        // 2^2100 or 2^-2100, so there's no need to go any further than that. When scaling
+        // down, the partial step is applied first so that only the last step can produce
+        // subnormal (and therefore rounded) components.
+        let n = n.clamp(-2100, 2100);
+        let (step, count) = if n < 0 {
+            (-1022, n / -1022)
+        } else {
+            (1023, n / 1023)
+        };
+        let mut r = self.mul_pwr2(2f64.powi(n - step * count));
+        for _ in 0..count {
+            r = r.mul_pwr2(2f64.powi(step));
+        }
+        r
+    }
+
     /// Calculates the square of the `Quad`.
     ///
     /// This method takes advantage of optimizations in multiplication that are available
@@ -140,6 +207,13 @@ impl Quad {
 
     /// Calculates the *n*th root of the `Quad`.
     ///
+    /// Odd roots of negative numbers are defined and negative, while even roots of negative
+    /// numbers are [`NAN`]. A negative `n` produces the reciprocal of the |`n`|th root, so
+    /// `x.nroot(-3)` is 1 / ∛*x*. The 0th root of any number is [`NAN`].
+    ///
+    /// This is both faster and more accurate than raising the number to a fractional power
+    /// with [`powf`].
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -149,6 +223,9 @@ impl Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`powf`]: #method.powf
     pub fn nroot(self, n: i32) -> Quad {
         match self.pre_nroot(n) {
             Some(r) => r,
@@ -166,18 +243,29 @@ impl Quad {
                 // small number of iterations, we can then find a^(1/n) by taking the
                 // reciprocal.
 
-                let r = self.abs();
-                // a^(-1/n) = exp(-ln(a) / n)
-                let mut x = Quad::from((-(r.0.ln()) / n as f64).exp());
+                // The argument is first scaled by a power of two that is a multiple of n
+                // (chosen to bring it close to 1), so that x^n can neither overflow nor
+                // underflow and so that the `f64` seed from `powf` is as accurate as it can
+                // be. Since a^(1/n) = (a * 2^(-kn))^(1/n) * 2^k, the scaling is undone
+                // exactly at the end. Subnormal arguments take a second pass, because the
+                // exponent reported for them is that of the smallest normal number.
+                let mut r = self.abs();
+                let mut k = 0;
+                for _ in 0..2 {
+                    let j = u::exponent(r.0) / n;
+                    r = r.ldexp(-j * n);
+                    k += j;
+                }
+                let mut x = Quad::from(r.0.powf(-1.0 / n as f64));
 
                 let qd_n = Quad(n.into(), 0.0, 0.0, 0.0);
-                x += x * (Quad::ONE - r * x.powi(n)) / qd_n;
-                x += x * (Quad::ONE - r * x.powi(n)) / qd_n;
-                x += x * (Quad::ONE - r * x.powi(n)) / qd_n;
-                if self.0 < 0.0 {
-                    x = -x
+                x += x * (Quad::ONE - r * x.powi(n.into())) / qd_n;
+                x += x * (Quad::ONE - r * x.powi(n.into())) / qd_n;
+                x += x * (Quad::ONE - r * x.powi(n.into())) / qd_n;
+                if self.is_sign_negative() {
+                    x = -x;
                 }
-                x.recip()
+                x.recip().ldexp(k)
             }
         }
     }
@@ -208,36 +296,85 @@ impl Quad {
     /// * `x.powi(n)` is ±0 for `x` = ±0 and any odd positive `n`
     /// * `x.powi(n)` is +0 for `x` = ±0 and any even positive `n`
     ///
+    /// If the result is too large in magnitude to be represented, it is ±∞, and if it is
+    /// too small, it is ±0 (with the sign in both cases being negative only for negative
+    /// `x` and odd `n`). The computation stops as soon as this is known, so even very large
+    /// exponents are cheap in that case. A result in the subnormal range is rounded to a
+    /// subnormal number, as an `f64` operation would do, even when `x` is large and `n` is
+    /// negative.
+    ///
     /// # Examples
     /// ```
-    /// # use qd::qd;
+    /// # use qd::{qd, Quad};
     /// let x = qd!(3);
     /// assert!(x.powi(3) == qd!(27));
+    ///
+    /// assert!(qd!(10).powi(400) == Quad::INFINITY);
+    /// assert!(qd!(-10).powi(-401) == Quad::NEG_ZERO);
     /// ```
-    pub fn powi(self, n: i32) -> Quad {
+    pub fn powi(self, n: i64) -> Quad {
         match self.pre_powi(n) {
             Some(r) => r,
             None => {
-                let mut r = self;
-                let mut s = Quad::ONE;
-                let mut k = n.abs();
-
-                if k > 1 {
-                    while k > 0 {
-                        if k % 2 == 1 {
-                            s *= r;
-                        }
-                        k /= 2;
-                        if k > 0 {
-                            r = r.sqr();
-                        }
+                // Strategy: exponentiation by squaring on the absolute value, with the sign
+                // applied at the end. Every intermediate is a full (renormalized) product,
+                // so no precision is lost beyond the usual rounding of each operation.
+                //
+                // The running square and product are kept between 2^-300 and 2^300, with
+                // the powers of two that are taken out of them (which is exact) counted
+                // separately. That way no intermediate overflows or underflows, even when
+                // the result itself is close to the ends of the range or, with a negative
+                // exponent, is the reciprocal of a number that's out of range. The powers of
+                // two are put back at the end, and that's the only place where the result
+                // can round to a subnormal number, to zero, or to infinity.
+                //
+                // Once the square is further than 2^4200 from 1, the result is out of range
+                // no matter what's left to do, since every remaining step only moves it
+                // further away. The loop stops there, so even very large exponents are
+                // cheap in that case.
+                let (mut r, mut re) = rescale(self.abs(), 0);
+                let (mut s, mut se) = (Quad::ONE, 0);
+                let mut i = n.unsigned_abs();
+                let mut out_of_range = false;
+
+                loop {
+                    if i & 1 == 1 {
+                        let (m, e) = rescale(s * r, se + re);
+                        s = m;
+                        se = e;
+                    }
+                    i >>= 1;
+                    if i == 0 {
+                        break;
+                    }
+                    let (m, e) = rescale(r.sqr(), 2 * re);
+                    r = m;
+                    re = e;
+                    if re.abs() > 4200 {
+                        out_of_range = true;
+                        break;
                     }
-                } else {
-                    s = r;
                 }
 
-                if n < 0 {
-                    s.recip()
+                // A negative exponent swaps the roles of overflow and underflow
+                let s = if out_of_range {
+                    if (re > 0) == (n > 0) {
+                        Quad::INFINITY
+                    } else {
+                        Quad::ZERO
+                    }
+                } else {
+                    let (s, se) = if n < 0 { (s.recip(), -se) } else { (s, se) };
+                    let s = s.scale_b(se.clamp(-5000, 5000) as i32);
+                    if s.is_finite() {
+                        s
+                    } else {
+                        Quad::INFINITY
+                    }
+                };
+
+                if self.is_sign_negative() && n % 2 != 0 {
+                    -s
                 } else {
                     s
                 }
@@ -270,6 +407,7 @@ impl Quad {
     /// ```
     ///
     /// [`NAN`]: #associatedconstant.NAN
+    #[cfg(feature = "transcendental")]
     #[inline]
     pub fn powf(self, n: Quad) -> Quad {
         match self.pre_powf(&n) {
@@ -278,6 +416,75 @@ impl Quad {
         }
     }
 
+    /// Calculates 1 plus the `Quad`, raised to a `Quad` power.
+    ///
+    /// When x is tiny, forming 1 + x before raising it to a power rounds away most of the
+    /// digits of x, and those are exactly the digits that the result depends on. This
+    /// function calculates *e*<sup>n ln(1 + x)</sup> using [`ln_1p`] instead, so that x
+    /// keeps its full precision however small it is.
+    ///
+    /// The base 1 + x must not be negative, so any argument less than -1 produces
+    /// [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1e-20).pow1p(qd!(1e10));
+    /// let expected = qd!("1.000000000100000000004999999999666666666620833333334250000000376");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`NAN`]: #associatedconstant.NAN
+    #[cfg(feature = "transcendental")]
+    pub fn pow1p(self, n: Quad) -> Quad {
+        match self.pre_pow1p(&n) {
+            Some(r) => r,
+            None => (n * self.ln_1p()).exp(),
+        }
+    }
+
+    /// Calculates the `Quad` raised to a `Quad` power, minus 1.
+    ///
+    /// When x<sup>n</sup> is close to 1, subtracting 1 from the result of [`powf`] cancels
+    /// away most of its digits. This function calculates *e*<sup>n ln x</sup> - 1 with
+    /// [`exp_m1`] instead, taking the logarithm with [`ln_1p`] when x is close to 1, so the
+    /// result is accurate to full precision even when it's tiny.
+    ///
+    /// As with [`powf`], a negative `Quad` produces [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(2).powm1(qd!(0.5));
+    /// let expected = qd!("0.414213562373095048801688724209698078569671875376948073176679738");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`powf`]: #method.powf
+    /// [`exp_m1`]: #method.exp_m1
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`NAN`]: #associatedconstant.NAN
+    #[cfg(feature = "transcendental")]
+    pub fn powm1(self, n: Quad) -> Quad {
+        match self.pre_powm1(&n) {
+            Some(r) => r,
+            None => {
+                // Subtracting 1 is exact for anything between 1/2 and 2
+                let ln = if self.0 >= 0.5 && self.0 <= 2.0 {
+                    (self - Quad::ONE).ln_1p()
+                } else {
+                    self.ln()
+                };
+                (n * ln).exp_m1()
+            }
+        }
+    }
+
     /// Calculates the reciprocal of the `Quad`.
     ///
     /// # Examples
@@ -294,6 +501,165 @@ impl Quad {
         Quad::ONE / self
     }
 
+    /// Calculates the length of the hypotenuse of a right-angle triangle given legs of
+    /// length `self` and `other`, i.e., √(x<sup>2</sup> + y<sup>2</sup>).
+    ///
+    /// Both arguments are scaled by a power of two before they are squared, so the
+    /// intermediate squares can neither overflow nor underflow unless the result itself
+    /// does. As with `f64`'s `hypot`, the result is [`INFINITY`] if either argument is
+    /// infinite, even if the other is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::PI.hypot(Quad::E);
+    /// let expected = qd!("4.154354402313313572948121466679053097883112964956925650570131181");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// // Squaring 1e200 would overflow, but the hypotenuse is representable
+    /// let y = qd!("1e200").hypot(qd!("1e200"));
+    /// let expected = qd!("1.414213562373095048801688724209698078569671875376948073176679738e200");
+    /// assert!(((y - expected) / expected).abs() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn hypot(self, other: Quad) -> Quad {
+        match self.pre_hypot(&other) {
+            Some(r) => r,
+            None => {
+                let a = self.abs();
+                let b = other.abs();
+                let k = u::exponent(if a > b { a.0 } else { b.0 });
+                let r = (a.ldexp(-k).sqr() + b.ldexp(-k).sqr()).sqrt().ldexp(k);
+                if r.is_infinite() {
+                    Quad::INFINITY
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Calculates the arithmetic-geometric mean of the `Quad` and another `Quad`.
+    ///
+    /// The arithmetic and geometric means of the two numbers are repeatedly taken until
+    /// they agree to full precision. Convergence is quadratic, so only a handful of
+    /// iterations are needed unless the arguments differ by many orders of magnitude. The
+    /// geometric mean is taken as the product of square roots so that the calculation
+    /// can't overflow or underflow.
+    ///
+    /// The domain of this function is [0, ∞) for both arguments. If either argument is
+    /// negative, the result is [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// // The reciprocal of Gauss's constant
+    /// let x = Quad::ONE.agm(Quad::SQRT_2);
+    /// let expected = qd!("1.198140234735592207439922492280323878227212663215651558263674953");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn agm(self, other: Quad) -> Quad {
+        match self.pre_agm(&other) {
+            Some(r) => r,
+            None => {
+                let mut a = self;
+                let mut b = other;
+                for _ in 0..64 {
+                    if (a - b).abs() <= Quad::EPSILON * a {
+                        break;
+                    }
+                    let mean = c::mul_pwr2(a, 0.5) + c::mul_pwr2(b, 0.5);
+                    b = a.sqrt() * b.sqrt();
+                    a = mean;
+                }
+                c::mul_pwr2(a, 0.5) + c::mul_pwr2(b, 0.5)
+            }
+        }
+    }
+
+    /// Calculates the sum of the squares of a slice of `Quad`s.
+    ///
+    /// Like [`hypot`], this scales the values by a power of two before they are squared, so
+    /// the result only overflows (or underflows) if the sum itself is too large (or too
+    /// small) to represent. The result is [`INFINITY`] if any of the values are infinite,
+    /// [`NAN`] if any of them are `NaN` and none are infinite, and zero if the slice is
+    /// empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::sum_of_squares(&[qd!(1), qd!(2), qd!(3)]);
+    /// assert!(x == qd!(14));
+    ///
+    /// let y = Quad::sum_of_squares(&[qd!("1e150"), qd!("2e150")]);
+    /// assert!(((y - qd!("5e300")) / qd!("5e300")).abs() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`hypot`]: #method.hypot
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn sum_of_squares(values: &[Quad]) -> Quad {
+        if values.iter().any(|x| x.is_infinite()) {
+            Quad::INFINITY
+        } else if values.iter().any(|x| x.is_nan()) {
+            Quad::NAN
+        } else {
+            let max = values.iter().fold(0.0, |m: f64, x| m.max(x.0.abs()));
+            let k = u::exponent(max);
+            let sum: Quad = values.iter().map(|x| x.ldexp(-k).sqr()).sum();
+            // Two steps, since 2k may be outside the range of an f64 exponent
+            let r = sum.ldexp(k).ldexp(k);
+            if r.is_infinite() {
+                Quad::INFINITY
+            } else {
+                r
+            }
+        }
+    }
+
+    /// Evaluates a Chebyshev series at the given point with Clenshaw's algorithm.
+    ///
+    /// The result is c₀T₀(x) + c₁T₁(x) + c₂T₂(x) + ⋯, where the cₖ are the elements of
+    /// `coeffs` in order and Tₖ is the Chebyshev polynomial of the first kind of degree k.
+    /// Note that the first coefficient is used as is and not halved, as it is in some
+    /// presentations. An empty slice of coefficients results in zero.
+    ///
+    /// Chebyshev series are usually fitted over [-1, 1], and that's where the evaluation is
+    /// best behaved. An approximation fitted over some other interval [a, b] should be
+    /// evaluated at (2x - a - b) / (b - a). Clenshaw's recurrence is numerically stable
+    /// over [-1, 1], so the result is accurate to about the precision of the coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// // 1 + 2x + 3(2x² - 1) at x = 0.5
+    /// let x = Quad::chebyshev_eval(&[qd!(1), qd!(2), qd!(3)], qd!(0.5));
+    /// assert!(x == qd!(0.5));
+    /// ```
+    pub fn chebyshev_eval(coeffs: &[Quad], x: Quad) -> Quad {
+        let two_x = c::mul_pwr2(x, 2.0);
+        let mut b1 = Quad::ZERO;
+        let mut b2 = Quad::ZERO;
+        match coeffs.split_first() {
+            None => Quad::ZERO,
+            Some((c0, rest)) => {
+                for &ck in rest.iter().rev() {
+                    let b0 = ck + two_x * b1 - b2;
+                    b2 = b1;
+                    b1 = b0;
+                }
+                *c0 + x * b1 - b2
+            }
+        }
+    }
+
     // PRecalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -331,6 +697,8 @@ impl Quad {
             Some(Quad::NAN)
         } else if n == 1 {
             Some(*self)
+        } else if n == -1 {
+            Some(self.recip())
         } else if self.is_zero() {
             if n % 2 == 0 || self.is_sign_positive() {
                 if n > 0 {
@@ -367,7 +735,7 @@ impl Quad {
     }
 
     #[inline]
-    fn pre_powi(&self, n: i32) -> Option<Quad> {
+    fn pre_powi(&self, n: i64) -> Option<Quad> {
         if n == 0 {
             Some(Quad::ONE)
         } else if self.is_nan() {
@@ -401,6 +769,7 @@ impl Quad {
         }
     }
 
+    #[cfg(feature = "transcendental")]
     #[inline]
     fn pre_powf(&self, n: &Quad) -> Option<Quad> {
         if self.is_zero() {
@@ -423,6 +792,90 @@ impl Quad {
             None
         }
     }
+
+    #[cfg(feature = "transcendental")]
+    #[inline]
+    fn pre_pow1p(&self, n: &Quad) -> Option<Quad> {
+        if self.is_nan() || n.is_nan() || *self < -Quad::ONE {
+            Some(Quad::NAN)
+        } else if n.is_zero() || self.is_zero() {
+            Some(Quad::ONE)
+        } else if *self == -Quad::ONE {
+            if n.is_sign_positive() {
+                Some(Quad::ZERO)
+            } else {
+                Some(Quad::INFINITY)
+            }
+        } else if n.is_infinite() || self.is_infinite() {
+            if self.is_sign_positive() == n.is_sign_positive() {
+                Some(Quad::INFINITY)
+            } else {
+                Some(Quad::ZERO)
+            }
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "transcendental")]
+    #[inline]
+    fn pre_powm1(&self, n: &Quad) -> Option<Quad> {
+        if self.is_nan() || n.is_nan() || *self < Quad::ZERO {
+            Some(Quad::NAN)
+        } else if n.is_zero() || *self == Quad::ONE {
+            Some(Quad::ZERO)
+        } else if self.is_zero() || self.is_infinite() || n.is_infinite() {
+            if (*self > Quad::ONE) == n.is_sign_positive() {
+                Some(Quad::INFINITY)
+            } else {
+                Some(-Quad::ONE)
+            }
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn pre_hypot(&self, other: &Quad) -> Option<Quad> {
+        if self.is_infinite() || other.is_infinite() {
+            Some(Quad::INFINITY)
+        } else if self.is_nan() || other.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(other.abs())
+        } else if other.is_zero() {
+            Some(self.abs())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_agm(&self, other: &Quad) -> Option<Quad> {
+        if self.is_nan() || other.is_nan() || *self < Quad::ZERO || *other < Quad::ZERO {
+            Some(Quad::NAN)
+        } else if self.is_zero() || other.is_zero() {
+            Some(Quad::ZERO)
+        } else if self.is_infinite() || other.is_infinite() {
+            Some(Quad::INFINITY)
+        } else if self == other {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+}
+
+// Takes a power of two out of `x` (a finite, positive number) if it's outside of the range
+// 2^-300 to 2^300, adding its exponent to `exp`. This is exact, and a product or square of
+// two numbers in that range has all of its components well inside the range of normal
+// `f64`s.
+fn rescale(x: Quad, exp: i64) -> (Quad, i64) {
+    if x.0 > 2f64.powi(-300) && x.0 < 2f64.powi(300) {
+        (x, exp)
+    } else {
+        let e = x.0.log2().floor() as i32;
+        (x.scale_b(-e), exp + i64::from(e))
+    }
 }
 
 #[cfg(test)]
@@ -497,6 +950,103 @@ mod tests {
             Quad::NAN.ldexp(5);
     );
 
+    // mul_pwr2 tests
+    test_all_exact!(
+        mul_pwr2_pi:
+            Quad::PI.ldexp(1),
+            Quad::PI.mul_pwr2(2.0);
+        mul_pwr2_pi_half:
+            Quad::PI.ldexp(-1),
+            Quad::PI.mul_pwr2(0.5);
+        mul_pwr2_ldexp:
+            Quad::E.ldexp(-10),
+            Quad::E.mul_pwr2(2f64.powi(-10));
+        mul_pwr2_neg:
+            qd!(-12),
+            qd!(3).mul_pwr2(-4.0);
+
+        mul_pwr2_zero:
+            Quad::ZERO,
+            Quad::ZERO.mul_pwr2(8.0);
+        mul_pwr2_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.mul_pwr2(8.0);
+        mul_pwr2_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.mul_pwr2(0.5);
+        mul_pwr2_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.mul_pwr2(0.5);
+        mul_pwr2_nan:
+            Quad::NAN,
+            Quad::NAN.mul_pwr2(2.0);
+    );
+
+    // scale_b tests
+    test_all_exact!(
+        scale_b_pi:
+            Quad::PI.ldexp(2),
+            Quad::PI.scale_b(2);
+        scale_b_neg_e:
+            (-Quad::E).ldexp(-14),
+            (-Quad::E).scale_b(-14);
+        scale_b_zero_exp:
+            Quad::PI,
+            Quad::PI.scale_b(0);
+        scale_b_max_exp:
+            Quad(2f64.powi(1023), 0.0, 0.0, 0.0),
+            Quad::ONE.scale_b(1023);
+        scale_b_min_exp:
+            Quad(2f64.powi(-1022), 0.0, 0.0, 0.0),
+            Quad::ONE.scale_b(-1022);
+        scale_b_large_down:
+            Quad(3.0 * 2f64.powi(-500) * 2f64.powi(-500), 0.0, 0.0, 0.0),
+            qd!(3).scale_b(1000).scale_b(-2000);
+        scale_b_large_up:
+            Quad(2f64.powi(1023), 0.0, 0.0, 0.0),
+            Quad(5e-324, 0.0, 0.0, 0.0).scale_b(2097);
+        scale_b_min_subnormal:
+            Quad(5e-324, 0.0, 0.0, 0.0),
+            Quad::ONE.scale_b(-1074);
+        scale_b_subnormal_rounded:
+            Quad(1e-323, 0.0, 0.0, 0.0),
+            qd!(3).scale_b(-1075);
+        scale_b_subnormal_tie:
+            Quad::ZERO,
+            Quad::ONE.scale_b(-1075);
+        scale_b_overflow:
+            Quad::INFINITY,
+            Quad::ONE.scale_b(1024);
+        scale_b_neg_overflow:
+            Quad::NEG_INFINITY,
+            (-Quad::MIN_POSITIVE).scale_b(2100);
+        scale_b_i32_max:
+            Quad::INFINITY,
+            Quad(5e-324, 0.0, 0.0, 0.0).scale_b(i32::MAX);
+        scale_b_i32_min:
+            Quad::ZERO,
+            Quad::MAX.scale_b(i32::MIN);
+
+        scale_b_zero:
+            Quad::ZERO,
+            Quad::ZERO.scale_b(2000);
+        scale_b_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.scale_b(-2000);
+        scale_b_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.scale_b(-2000);
+        scale_b_nan:
+            Quad::NAN,
+            Quad::NAN.scale_b(4);
+    );
+    test_all_assert!(
+        scale_b_neg_zero:
+            Quad::NEG_ZERO.scale_b(2000).is_sign_negative();
+        scale_b_neg_underflow:
+            (-Quad::PI).scale_b(-2000).is_sign_negative();
+    );
+
     // sqr tests
     test_all_near!(
         sqr_pi:
@@ -723,6 +1273,23 @@ mod tests {
             Quad::NAN,
             Quad::NAN.nroot(4);
     );
+    test_all_near!(
+        nroot_huge:
+            qd!(2).cbrt().ldexp(333),
+            Quad(2f64.powi(1000), 0.0, 0.0, 0.0).nroot(3);
+        nroot_neg_huge:
+            -qd!(2).nroot(5).ldexp(204),
+            Quad(-2f64.powi(1021), 0.0, 0.0, 0.0).nroot(5);
+        nroot_tiny_neg:
+            Quad(2f64.powi(340), 0.0, 0.0, 0.0),
+            Quad(2f64.powi(-1020), 0.0, 0.0, 0.0).nroot(-3);
+        nroot_subnormal:
+            Quad(2f64.powi(-214), 0.0, 0.0, 0.0),
+            Quad(2f64.powi(-1000) * 2f64.powi(-70), 0.0, 0.0, 0.0).nroot(5);
+        nroot_neg_one:
+            qd!(0.25),
+            qd!(4).nroot(-1);
+    );
 
     // cbrt tests
     test_all_near!(
@@ -870,8 +1437,80 @@ mod tests {
             Quad::NAN,
             Quad::NAN.powi(2);
     );
+    test_all_prec!(
+        powi_large_exp:
+            qd!("2.7182818589778950338306474046576127618660387784694290599318239131704"),
+            (Quad::ONE + Quad(2f64.powi(-40), 0.0, 0.0, 0.0)).powi((1 << 40) + 12345),
+            48;
+        powi_3_neg_200:
+            qd!("3.7648619495990264198834218900111162409703509768819192559503485475330e-96"),
+            qd!(3).powi(-200),
+            58;
+    );
+    test_all_exact!(
+        powi_overflow:
+            Quad::INFINITY,
+            qd!(10).powi(400);
+        powi_neg_overflow:
+            Quad::NEG_INFINITY,
+            qd!(-10).powi(401);
+        powi_neg_overflow_even:
+            Quad::INFINITY,
+            qd!(-10).powi(402);
+        powi_underflow:
+            Quad::ZERO,
+            qd!(0.1).powi(400);
+        powi_neg_exp_overflow:
+            Quad::ZERO,
+            qd!(10).powi(-400);
+        powi_neg_exp_underflow:
+            Quad::INFINITY,
+            qd!(0.1).powi(-400);
+        powi_huge_exp_overflow:
+            Quad::INFINITY,
+            qd!(1.5).powi(i64::MAX);
+        powi_one_max:
+            Quad::ONE,
+            Quad::ONE.powi(i64::MAX);
+        powi_neg_one_min:
+            Quad::ONE,
+            (-Quad::ONE).powi(i64::MIN);
+        powi_2_1023:
+            Quad(2f64.powi(1023), 0.0, 0.0, 0.0),
+            qd!(2).powi(1023);
+        powi_2_neg_1000:
+            Quad(2f64.powi(-1000), 0.0, 0.0, 0.0),
+            qd!(2).powi(-1000);
+        powi_2_neg_1030:
+            Quad(f64::from_bits(1 << 44), 0.0, 0.0, 0.0),
+            qd!(2).powi(-1030);
+        powi_10_neg_310:
+            Quad(1e-310, 0.0, 0.0, 0.0),
+            qd!(10).powi(-310);
+        powi_max_neg_1:
+            Quad(f64::from_bits(1 << 50), 0.0, 0.0, 0.0),
+            Quad::MAX.powi(-1);
+        powi_2_neg_1074:
+            Quad(f64::from_bits(1), 0.0, 0.0, 0.0),
+            qd!(2).powi(-1074);
+        powi_half_1074:
+            Quad(f64::from_bits(1), 0.0, 0.0, 0.0),
+            qd!(0.5).powi(1074);
+        powi_2_neg_1075:
+            Quad::ZERO,
+            qd!(2).powi(-1075);
+    );
+    test_all_assert!(
+        powi_neg_underflow_sign:
+            qd!(-10).powi(-401).is_sign_negative() && qd!(-10).powi(-401).is_zero();
+        powi_neg_underflow_even_sign:
+            qd!(-0.1).powi(400).is_sign_positive() && qd!(-0.1).powi(400).is_zero();
+        powi_neg_subnormal_sign:
+            qd!(-2).powi(-1073)[0] == -f64::from_bits(2);
+    );
 
     // powf_tests
+    #[cfg(feature = "transcendental")]
     test_all_near!(
         powf_pi:
             qd!("93648.047476083020973716690184919345635998157275514694127052449392906"),
@@ -895,6 +1534,7 @@ mod tests {
             qd!("0.0000097580874511463571772513415019480387518681397873542312835791633473171"),
             qd!(150).powf(-Quad::LN_10);
     );
+    #[cfg(feature = "transcendental")]
     test_all_exact!(
         powf_zero:
             Quad::ZERO,
@@ -977,6 +1617,121 @@ mod tests {
             qd!(-1).powf(qd!(1));
     );
 
+    // pow1p tests
+    #[cfg(feature = "transcendental")]
+    test_all_near!(
+        pow1p_tiny:
+            qd!("1.00000000010000000000499999999966666666662083333333425000000037639"),
+            qd!(1e-20).pow1p(qd!(1e10));
+        pow1p_dyadic_tiny:
+            qd!("1.00000095367477115374544637465885616712912502716070278062434866652"),
+            Quad::ONE.scale_b(-60).pow1p(Quad::ONE.scale_b(40));
+        pow1p_half:
+            qd!("2.75567596063107536047194458404412781596169091573875389448677913816"),
+            qd!(0.5).pow1p(qd!(2.5));
+        pow1p_neg:
+            qd!("2.37037037037037037037037037037037037037037037037037037037037037037"),
+            qd!(-0.25).pow1p(qd!(-3));
+        pow1p_sqrt:
+            qd!(2),
+            qd!(3).pow1p(qd!(0.5));
+    );
+    #[cfg(feature = "transcendental")]
+    test_all_exact!(
+        pow1p_zero_exp:
+            Quad::ONE,
+            Quad::PI.pow1p(Quad::ZERO);
+        pow1p_zero:
+            Quad::ONE,
+            Quad::ZERO.pow1p(Quad::INFINITY);
+        pow1p_neg_one:
+            Quad::ZERO,
+            (-Quad::ONE).pow1p(Quad::PI);
+        pow1p_neg_one_neg_exp:
+            Quad::INFINITY,
+            (-Quad::ONE).pow1p(-Quad::PI);
+        pow1p_inf_exp:
+            Quad::INFINITY,
+            Quad::PI.pow1p(Quad::INFINITY);
+        pow1p_neg_inf_exp:
+            Quad::ZERO,
+            Quad::PI.pow1p(Quad::NEG_INFINITY);
+        pow1p_neg_base_inf_exp:
+            Quad::ZERO,
+            qd!(-0.5).pow1p(Quad::INFINITY);
+        pow1p_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.pow1p(Quad::E);
+        pow1p_inf_neg_exp:
+            Quad::ZERO,
+            Quad::INFINITY.pow1p(-Quad::E);
+        pow1p_too_small:
+            Quad::NAN,
+            qd!(-2).pow1p(qd!(2));
+        pow1p_nan:
+            Quad::NAN,
+            Quad::NAN.pow1p(Quad::ONE);
+        pow1p_nan_exp:
+            Quad::NAN,
+            Quad::ONE.pow1p(Quad::NAN);
+    );
+
+    // powm1 tests
+    #[cfg(feature = "transcendental")]
+    test_all_near!(
+        powm1_sqrt_2:
+            qd!("0.414213562373095048801688724209698078569671875376948073176679737991"),
+            qd!(2).powm1(qd!(0.5));
+        powm1_near_one:
+            qd!("2.54109884176290101720711989648012880517511426104376903980476632063e-21"),
+            Quad::new(1.0, 2f64.powi(-70), 0.0, 0.0).powm1(qd!(3));
+        powm1_tiny_exp:
+            qd!("4.05465108108164381978835125233814963720254715532609454613955303938e-21"),
+            qd!(1.5).powm1(qd!(1e-20));
+        powm1_below_one:
+            qd!("-2.55513008486279683038911422106143066664425514072184350832700982756e-16"),
+            qd!(0.75).powm1(Quad::ONE.scale_b(-50));
+        powm1_cube:
+            qd!(-0.875),
+            qd!(0.5).powm1(qd!(3));
+        powm1_large:
+            qd!(-0.99),
+            qd!(10).powm1(qd!(-2));
+    );
+    #[cfg(feature = "transcendental")]
+    test_all_exact!(
+        powm1_zero_exp:
+            Quad::ZERO,
+            Quad::PI.powm1(Quad::ZERO);
+        powm1_one:
+            Quad::ZERO,
+            Quad::ONE.powm1(Quad::INFINITY);
+        powm1_zero:
+            -Quad::ONE,
+            Quad::ZERO.powm1(Quad::PI);
+        powm1_zero_neg_exp:
+            Quad::INFINITY,
+            Quad::ZERO.powm1(-Quad::PI);
+        powm1_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.powm1(Quad::PI);
+        powm1_inf_neg_exp:
+            -Quad::ONE,
+            Quad::INFINITY.powm1(-Quad::PI);
+        powm1_inf_exp:
+            Quad::INFINITY,
+            Quad::PI.powm1(Quad::INFINITY);
+        powm1_small_inf_exp:
+            -Quad::ONE,
+            qd!(0.5).powm1(Quad::INFINITY);
+        powm1_neg:
+            Quad::NAN,
+            qd!(-2).powm1(qd!(2));
+        powm1_nan:
+            Quad::NAN,
+            Quad::NAN.powm1(Quad::ONE);
+    );
+
     // recip tests
     test_all_near!(
         recip_pi:
@@ -1027,4 +1782,197 @@ mod tests {
             Quad::NAN,
             Quad::NAN.recip();
     );
+
+    // hypot tests
+    test_all_near!(
+        hypot_pi_e:
+            qd!("4.154354402313313572948121466679053097883112964956925650570131181"),
+            Quad::PI.hypot(Quad::E);
+        hypot_neg:
+            qd!("4.154354402313313572948121466679053097883112964956925650570131181"),
+            (-Quad::PI).hypot(-Quad::E);
+        hypot_3_4:
+            qd!(5),
+            qd!(3).hypot(qd!(4));
+    );
+    test_all_prec!(
+        hypot_large:
+            qd!("1.414213562373095048801688724209698078569671875376948073176679738e200"),
+            qd!("1e200").hypot(qd!("1e200")),
+            60;
+        hypot_small:
+            qd!(10).sqrt(),
+            qd!("1e-200").hypot(qd!("3e-200")) / qd!("1e-200"),
+            60;
+    );
+    test_all_exact!(
+        hypot_zero:
+            qd!(3),
+            Quad::ZERO.hypot(qd!(-3));
+        hypot_inf:
+            Quad::INFINITY,
+            Quad::ONE.hypot(Quad::NEG_INFINITY);
+        hypot_inf_nan:
+            Quad::INFINITY,
+            Quad::NAN.hypot(Quad::INFINITY);
+        hypot_nan:
+            Quad::NAN,
+            Quad::NAN.hypot(Quad::ONE);
+        hypot_overflow:
+            Quad::INFINITY,
+            Quad::MAX.hypot(Quad::MAX);
+    );
+
+    // agm tests
+    test_all_near!(
+        agm_1_sqrt_2:
+            qd!("1.19814023473559220743992249228032387822721266321565155826367495295"),
+            Quad::ONE.agm(Quad::SQRT_2);
+        agm_24_6:
+            qd!("13.4581714817256154207668131569743992430538388544396598555129422083"),
+            qd!(24).agm(qd!(6));
+        agm_symmetric:
+            qd!(6).agm(qd!(24)),
+            qd!(24).agm(qd!(6));
+        agm_half_2:
+            qd!("1.12151429014380128506390109641453327025448657120330498795941185069"),
+            qd!(0.5).agm(qd!(2));
+    );
+    test_all_prec!(
+        agm_tiny:
+            qd!("2.28445571664677107340104987183299738415920436881239696783569382457e-3"),
+            Quad::ONE.agm(Quad::ONE.scale_b(-990)),
+            60;
+        agm_huge:
+            qd!("6.10034567499146435155893692296476607743085020729479743124812566422e150"),
+            Quad::ONE.scale_b(500).agm(qd!(3).scale_b(500)),
+            60;
+    );
+    test_all_exact!(
+        agm_equal:
+            Quad::PI,
+            Quad::PI.agm(Quad::PI);
+        agm_max:
+            Quad::MAX,
+            Quad::MAX.agm(Quad::MAX);
+        agm_zero:
+            Quad::ZERO,
+            Quad::ZERO.agm(Quad::PI);
+        agm_inf:
+            Quad::INFINITY,
+            Quad::PI.agm(Quad::INFINITY);
+        agm_neg:
+            Quad::NAN,
+            Quad::PI.agm(-Quad::E);
+        agm_nan:
+            Quad::NAN,
+            Quad::NAN.agm(Quad::ONE);
+    );
+
+    // sum_of_squares tests
+    test_all_near!(
+        sum_of_squares_consts:
+            qd!("21.25866050002000884606491846045115894849401497779263795050047719874262"),
+            Quad::sum_of_squares(&[Quad::PI, Quad::E, qd!(-2)]);
+    );
+    test_all_prec!(
+        sum_of_squares_large:
+            qd!("1.4e301"),
+            Quad::sum_of_squares(&[qd!("1e150"), qd!("2e150"), qd!("3e150")]),
+            60;
+    );
+    test_all_exact!(
+        sum_of_squares_empty:
+            Quad::ZERO,
+            Quad::sum_of_squares(&[]);
+        sum_of_squares_inf:
+            Quad::INFINITY,
+            Quad::sum_of_squares(&[Quad::ONE, Quad::NAN, Quad::NEG_INFINITY]);
+        sum_of_squares_nan:
+            Quad::NAN,
+            Quad::sum_of_squares(&[Quad::ONE, Quad::NAN]);
+        sum_of_squares_overflow:
+            Quad::INFINITY,
+            Quad::sum_of_squares(&[qd!("1e200"), qd!("1e200")]);
+    );
+
+    // chebyshev_eval tests
+    test_all_exact!(
+        chebyshev_eval_empty:
+            Quad::ZERO,
+            Quad::chebyshev_eval(&[], qd!(0.5));
+        chebyshev_eval_constant:
+            qd!(7),
+            Quad::chebyshev_eval(&[qd!(7)], qd!(0.5));
+        chebyshev_eval_quadratic:
+            qd!(0.5),
+            Quad::chebyshev_eval(&[qd!(1), qd!(2), qd!(3)], qd!(0.5));
+        chebyshev_eval_outside:
+            qd!(17),
+            Quad::chebyshev_eval(&[qd!(0), qd!(0), qd!(1)], qd!(3));
+        chebyshev_eval_t5:
+            qd!(0.5),
+            Quad::chebyshev_eval(&[Quad::ZERO, Quad::ZERO, Quad::ZERO, Quad::ZERO, Quad::ZERO, Quad::ONE], qd!(0.5));
+        chebyshev_eval_nan:
+            Quad::NAN,
+            Quad::chebyshev_eval(&[qd!(1), qd!(2)], Quad::NAN);
+    );
+    #[cfg(feature = "transcendental")]
+    test!(chebyshev_eval_exp: {
+        // The Chebyshev coefficients of eˣ are I₀(1), 2I₁(1), 2I₂(1), ...
+        let coeffs = [
+            qd!("1.26606587775200833559824462521471753760767031135496220680813533121"),
+            qd!("1.13031820798497005441539205521972661465779924324218401896058897896"),
+            qd!("0.27149533953407656236570513998998184589974213622555637569509270451"),
+            qd!("0.044336849848663804952571495259799231058830698339958516180218160918"),
+            qd!("5.47424044209373265027616843118645954675794618580527861378373900212e-3"),
+            qd!("5.42926311913943750362147810307554684767128853516287269948248901049e-4"),
+            qd!("4.49773229542951466546903281109126990866576506424059143012499916264e-5"),
+            qd!("3.19843646240199050586387297660229572723704580741629833324900153265e-6"),
+            qd!("1.99212480667279572596106438480558905339009338577737635763970169328e-7"),
+            qd!("1.10367717255173443261699609133532418128963901724961610254788234043e-8"),
+            qd!("5.50589607967374725047142040200552706874315472806737305351348049761e-10"),
+            qd!("2.49795661698498252271201093421876754100807163614149184518624091169e-11"),
+            qd!("1.03915223067857005049963467242384785253971285560909941037504918814e-12"),
+            qd!("3.99126335641440151288772040153269491276078267965326028612286014939e-14"),
+            qd!("1.42375801082565714882736802534717522190935889925173598310554930277e-15"),
+            qd!("4.74092610256149617108993056060429141457776174839953342732210164274e-17"),
+            qd!("1.48018005720829750038885716588779753603037473187595490891880994386e-18"),
+            qd!("4.34991949494416984558762976333929928056260639647771878190982237692e-20"),
+            qd!("1.20742892727975288906304635243578063908855707353052306947033571026e-21"),
+            qd!("3.17535673705944496066289457048897984380093176783573181661381997691e-23"),
+            qd!("7.93367197163804011146415649968298444203001752944979157084119037784e-25"),
+            qd!("1.88794840422891607723197061578606698892475605581518827734382577046e-26"),
+            qd!("4.28867387659258708987991338150308854604209502600080599712214190363e-28"),
+            qd!("9.31898528177757684808727924708028666234244374833638610083332864881e-30"),
+            qd!("1.94064697490173975976492784615668136457090176606839073881072517364e-31"),
+            qd!("3.87980224922600121562558552821611240211527120811055454184781532113e-33"),
+            qd!("7.45850288739151952135082048625163513266162013113467886817513079677e-35"),
+            qd!("1.38074778241106452315887536526213313122873992052153039674730680722e-36"),
+            qd!("2.46486237177109629289351383611622402642456031841472573967403777315e-38"),
+            qd!("4.24854219250599138507617037047676430986142209283982529845654256024e-40"),
+            qd!("7.0790011762128954933502123970072670493550456762706656924308821479e-42"),
+            qd!("1.14148677825408906604293227240408024839468707742588299801327149778e-43"),
+            qd!("1.78315103754328388403230810196950930798579623019110474859886164089e-45"),
+            qd!("2.70114226387380262255087143594291283777490103575958910000047614698e-47"),
+            qd!("3.97143386574153148732954247186835054361546589775942598547383879314e-49"),
+            qd!("5.67235169561211166782555072434468116384225283179429878265767652057e-51"),
+            qd!("7.87678813053319851656964827073728925888915503416837613465228736333e-53"),
+            qd!("1.06424162820873589540396941383289744206120719306796570802961897605e-54"),
+            qd!("1.40008178855289058027460837384818763622180546542989523310694057292e-56"),
+            qd!("1.79468908539054395267049708274838532635039341245330868344140628211e-58"),
+            qd!("2.24301948266297191620649304447081668498603716314460022643672872555e-60"),
+            qd!("2.73499260166419705302647171731978361563681937628502292023301674525e-62"),
+            qd!("3.25549298330332724786236268594120163845274590881431845654994443372e-64"),
+            qd!("3.78495689402164822087061129174239336512812880995416731063420925412e-66"),
+            qd!("4.30054444709777913636975042743344442555132253734569404524475172774e-68"),
+            qd!("4.77780575602580865230915600962270642964977089956550818827733716224e-70"),
+            qd!("5.192666745513492915100187730086388665287277367366757951482817212e-72"),
+            qd!("5.52350153395170416983297943228857585475721588090872913145327200948e-74"),
+        ];
+        for x in ["-0.5", "-0.3", "0", "0.5", "1"] {
+            let x = qd!(x);
+            near!(x.exp(), Quad::chebyshev_eval(&coeffs, x));
+        }
+    });
 }
@@ -0,0 +1,1082 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+// The argument below which the Bessel functions of the first and second kinds, and the
+// modified Bessel functions of the second kind, are calculated from their power series.
+// Past this point the series start to lose precision to cancellation.
+const SERIES_LIMIT: f64 = 2.0;
+
+// The argument past which the Bessel functions of the first and second kinds are calculated
+// from their asymptotic expansions. The smallest term in those expansions is around
+// e^(-2x), so this is far enough out that they reach full precision.
+const ASYMPTOTIC_LIMIT: f64 = 80.0;
+
+impl Quad {
+    /// Calculates the Bessel function of the first kind of order 0 (J<sub>0</sub>) of the
+    /// `Quad`.
+    ///
+    /// Small arguments are calculated from the power series, large ones from the
+    /// asymptotic expansion, and those in between with Steed's method, which evaluates a
+    /// pair of continued fractions.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).j0();
+    /// let expected = qd!("0.7651976865579665514497175261026632209092742897553252418615475491");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn j0(self) -> Quad {
+        match self.pre_j0() {
+            Some(r) => r,
+            None => j(self.abs()).0,
+        }
+    }
+
+    /// Calculates the Bessel function of the first kind of order 1 (J<sub>1</sub>) of the
+    /// `Quad`.
+    ///
+    /// This is calculated the same way as [`j0`].
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is about [-0.582, 0.582].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).j1();
+    /// let expected = qd!("0.4400505857449335159596822037189149131273723019927652511367581718");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`j0`]: #method.j0
+    pub fn j1(self) -> Quad {
+        match self.pre_j1() {
+            Some(r) => r,
+            None => {
+                let r = j(self.abs()).1;
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Calculates the Bessel function of the second kind of order 0 (Y<sub>0</sub>) of the
+    /// `Quad`.
+    ///
+    /// This function has a logarithmic singularity at 0. Small arguments are calculated
+    /// from the power series, with the logarithm split out so that the series itself has
+    /// no singularity, and larger ones the same way as [`j0`].
+    ///
+    /// The domain of this function is [0, ∞), and the range is [-∞, 0.521). Negative
+    /// arguments will result in [`NAN`], and 0 results in [`NEG_INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).y0();
+    /// let expected = qd!("0.08825696421567695798292676602351516282781752309067554671104384761");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`j0`]: #method.j0
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn y0(self) -> Quad {
+        match self.pre_y() {
+            Some(r) => r,
+            None => y(self).0,
+        }
+    }
+
+    /// Calculates the Bessel function of the second kind of order 1 (Y<sub>1</sub>) of the
+    /// `Quad`.
+    ///
+    /// This is calculated the same way as [`y0`]. Near 0 it behaves like -2 / (πx).
+    ///
+    /// The domain of this function is [0, ∞), and the range is [-∞, 0.417). Negative
+    /// arguments will result in [`NAN`], and 0 results in [`NEG_INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).y1();
+    /// let expected = qd!("-0.7812128213002887165471500000479648205499063907164446078438332461");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`y0`]: #method.y0
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn y1(self) -> Quad {
+        match self.pre_y() {
+            Some(r) => r,
+            None => y(self).1,
+        }
+    }
+
+    /// Calculates the Bessel function of the second kind of order `n` (Y<sub>n</sub>) of
+    /// the `Quad`.
+    ///
+    /// This starts from [`y0`] and [`y1`] and uses the recurrence Y<sub>n + 1</sub>(x) =
+    /// (2n / x) Y<sub>n</sub>(x) - Y<sub>n - 1</sub>(x), which is stable in the upward
+    /// direction for the functions of the second kind. Negative orders are handled with
+    /// Y<sub>-n</sub>(x) = (-1)<sup>n</sup> Y<sub>n</sub>(x).
+    ///
+    /// The domain of this function is [0, ∞). Negative arguments will result in [`NAN`],
+    /// and 0 results in an infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).yn(3);
+    /// let expected = qd!("-5.8215176059647288477617570644298143951606148273778144417510081133");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-59));
+    /// ```
+    ///
+    /// [`y0`]: #method.y0
+    /// [`y1`]: #method.y1
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn yn(self, n: i32) -> Quad {
+        match self.pre_yn(n) {
+            Some(r) => r,
+            None => {
+                let (mut a, mut b) = y(self);
+                let m = n.abs();
+                if m == 0 {
+                    return a;
+                }
+                let two_x = c::mul_pwr2(self.recip(), 2.0);
+                for k in 1..m {
+                    let next = Quad::from(k) * two_x * b - a;
+                    a = b;
+                    b = next;
+                }
+                if n < 0 && m % 2 == 1 {
+                    -b
+                } else {
+                    b
+                }
+            }
+        }
+    }
+
+    /// Calculates the modified Bessel function of the first kind of order 0
+    /// (I<sub>0</sub>) of the `Quad`.
+    ///
+    /// This is calculated from the power series, which has only positive terms and so
+    /// loses nothing to cancellation at any argument.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [1, ∞).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).i0();
+    /// let expected = qd!("1.266065877752008335598244625214717537607670311354962206808135331");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn i0(self) -> Quad {
+        match self.pre_i0() {
+            Some(r) => r,
+            None => i(self.abs()).0,
+        }
+    }
+
+    /// Calculates the modified Bessel function of the first kind of order 1
+    /// (I<sub>1</sub>) of the `Quad`.
+    ///
+    /// This is calculated the same way as [`i0`].
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is (-∞, ∞).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).i1();
+    /// let expected = qd!("0.5651591039924850272076960276098633073288996216210920094802944895");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`i0`]: #method.i0
+    pub fn i1(self) -> Quad {
+        match self.pre_i1() {
+            Some(r) => r,
+            None => {
+                let r = i(self.abs()).1;
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Calculates the modified Bessel function of the second kind of order 0
+    /// (K<sub>0</sub>) of the `Quad`.
+    ///
+    /// This function has a logarithmic singularity at 0. Small arguments are calculated
+    /// from the power series with the logarithm split out, and larger ones with Temme's
+    /// continued fraction, which gives the result as a multiple of e<sup>-x</sup> and so
+    /// keeps full precision as the function decays.
+    ///
+    /// The domain of this function is [0, ∞), and the range is (0, ∞]. Negative arguments
+    /// will result in [`NAN`], and 0 results in [`INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).k0();
+    /// let expected = qd!("0.4210244382407083333356273792126090361362197482266604722989695515");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn k0(self) -> Quad {
+        match self.pre_k() {
+            Some(r) => r,
+            None => k(self).0,
+        }
+    }
+
+    /// Calculates the modified Bessel function of the second kind of order 1
+    /// (K<sub>1</sub>) of the `Quad`.
+    ///
+    /// This is calculated the same way as [`k0`]. Near 0 it behaves like 1 / x.
+    ///
+    /// The domain of this function is [0, ∞), and the range is (0, ∞]. Negative arguments
+    /// will result in [`NAN`], and 0 results in [`INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).k1();
+    /// let expected = qd!("0.6019072301972345747375400015356173392615868899681064560177679592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`k0`]: #method.k0
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn k1(self) -> Quad {
+        match self.pre_k() {
+            Some(r) => r,
+            None => k(self).1,
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_j0(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(Quad::ZERO)
+        } else if self.is_zero() {
+            Some(Quad::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_j1(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(Quad::ZERO)
+        } else if self.is_zero() {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_y(&self) -> Option<Quad> {
+        if self.is_nan() || self.is_sign_negative() && !self.is_zero() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad::NEG_INFINITY)
+        } else if self.is_infinite() {
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_yn(&self, n: i32) -> Option<Quad> {
+        if self.is_zero() && n < 0 && n % 2 != 0 {
+            // Y₋ₙ = -Yₙ for odd n, so this pole goes the other way
+            Some(Quad::INFINITY)
+        } else {
+            self.pre_y()
+        }
+    }
+
+    #[inline]
+    fn pre_i0(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad::ONE)
+        } else if self.abs().0 > 714.0 {
+            // The result overflows past this point
+            Some(Quad::INFINITY)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_i1(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if self.abs().0 > 714.0 {
+            Some(if self.is_sign_negative() {
+                Quad::NEG_INFINITY
+            } else {
+                Quad::INFINITY
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_k(&self) -> Option<Quad> {
+        if self.is_nan() || self.is_sign_negative() && !self.is_zero() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad::INFINITY)
+        } else if self.0 > 746.0 {
+            // The result underflows past this point
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+}
+
+// Calculates J₀(x) and J₁(x) for positive x.
+fn j(x: Quad) -> (Quad, Quad) {
+    if x.0 < SERIES_LIMIT {
+        series_j(x)
+    } else {
+        let (j0, j1, _, _) = large_j_y(x);
+        (j0, j1)
+    }
+}
+
+// Calculates Y₀(x) and Y₁(x) for positive x.
+fn y(x: Quad) -> (Quad, Quad) {
+    if x.0 < SERIES_LIMIT {
+        let (j0, j1) = series_j(x);
+        series_y(x, j0, j1)
+    } else {
+        let (_, _, y0, y1) = large_j_y(x);
+        (y0, y1)
+    }
+}
+
+// Calculates J₀(x), J₁(x), Y₀(x), and Y₁(x) for x ≥ 2.
+fn large_j_y(x: Quad) -> (Quad, Quad, Quad, Quad) {
+    if x.0 < ASYMPTOTIC_LIMIT {
+        steed_j_y(x)
+    } else {
+        hankel_j_y(x)
+    }
+}
+
+// Calculates J₀(x) and J₁(x) from their power series,
+//
+//      J₀(x) = Σ (-x²/4)ᵏ / (k!)²
+//      J₁(x) = x/2 Σ (-x²/4)ᵏ / (k! (k + 1)!).
+fn series_j(x: Quad) -> (Quad, Quad) {
+    let q = -c::mul_pwr2(x * x, 0.25);
+    let mut t0 = Quad::ONE;
+    let mut t1 = Quad::ONE;
+    let mut s0 = Quad::ONE;
+    let mut s1 = Quad::ONE;
+    let mut k = 1;
+    loop {
+        t0 = t0 * q / Quad::from(k * k);
+        t1 = t1 * q / Quad::from(k * (k + 1));
+        s0 += t0;
+        s1 += t1;
+        if t0.abs() < Quad::EPSILON * s0.abs() && t1.abs() < Quad::EPSILON * s1.abs() {
+            break;
+        }
+        k += 1;
+    }
+    (s0, c::mul_pwr2(x, 0.5) * s1)
+}
+
+// Calculates Y₀(x) and Y₁(x) from their power series, given J₀(x) and J₁(x). With Hₖ as
+// the kth harmonic number,
+//
+//      Y₀(x) = 2/π [(ln(x/2) + γ) J₀(x) - Σ Hₖ (-x²/4)ᵏ / (k!)²]
+//      Y₁(x) = -2/(πx) + 2/π (ln(x/2) + γ) J₁(x)
+//              - x/(2π) Σ (Hₖ + Hₖ₊₁) (-x²/4)ᵏ / (k! (k + 1)!).
+fn series_y(x: Quad, j0: Quad, j1: Quad) -> (Quad, Quad) {
+    let q = -c::mul_pwr2(x * x, 0.25);
+    let mut t0 = Quad::ONE;
+    let mut t1 = Quad::ONE;
+    let mut h = Quad::ZERO;
+    let mut s0 = Quad::ZERO;
+    let mut s1 = Quad::ONE;
+    let mut k = 1;
+    loop {
+        let hk = h + Quad::from(k).recip();
+        t0 = t0 * q / Quad::from(k * k);
+        t1 = t1 * q / Quad::from(k * (k + 1));
+        let d0 = hk * t0;
+        let d1 = (hk + hk + Quad::from(k + 1).recip()) * t1;
+        s0 += d0;
+        s1 += d1;
+        h = hk;
+        if d0.abs() < Quad::EPSILON * s0.abs() && d1.abs() < Quad::EPSILON * s1.abs() {
+            break;
+        }
+        k += 1;
+    }
+
+    let lg = c::mul_pwr2(x, 0.5).ln() + c::EULER_GAMMA;
+    let y0 = Quad::FRAC_2_PI * (lg * j0 - s0);
+    let y1 = Quad::FRAC_2_PI * (lg * j1 - x.recip()) - c::mul_pwr2(x * Quad::FRAC_1_PI, 0.5) * s1;
+    (y0, y1)
+}
+
+// Calculates J₀(x), J₁(x), Y₀(x), and Y₁(x) with Steed's method, as described in Numerical
+// Recipes. The first continued fraction gives J₀'/J₀ (and the sign of J₀), the second gives
+// (J₀' + iY₀') / (J₀ + iY₀), and the Wronskian ties them together.
+fn steed_j_y(x: Quad) -> (Quad, Quad, Quad, Quad) {
+    let tiny = Quad::new(1e-300, 0.0, 0.0, 0.0);
+    let fix = |v: Quad| if v.abs() < tiny { tiny } else { v };
+    let xi = x.recip();
+    let xi2 = c::mul_pwr2(xi, 2.0);
+
+    // First continued fraction, by the modified Lentz method
+    let mut positive = true;
+    let mut h = tiny;
+    let mut b = Quad::ZERO;
+    let mut d = Quad::ZERO;
+    let mut cf = h;
+    for _ in 0..100_000 {
+        b += xi2;
+        d = fix(b - d).recip();
+        cf = fix(b - cf.recip());
+        let delta = cf * d;
+        h *= delta;
+        if d.is_sign_negative() {
+            positive = !positive;
+        }
+        if (delta - Quad::ONE).abs() < Quad::EPSILON {
+            break;
+        }
+    }
+    let f = h;
+
+    // Second continued fraction, in complex arithmetic with the real and imaginary parts
+    // kept separately
+    let mut a = Quad::from(0.25);
+    let mut p = -c::mul_pwr2(xi, 0.5);
+    let mut q = Quad::ONE;
+    let br = c::mul_pwr2(x, 2.0);
+    let mut bi = Quad::from(2);
+    let fact = a * xi / (p * p + q * q);
+    let mut cr = br + q * fact;
+    let mut ci = bi + p * fact;
+    let den = br * br + bi * bi;
+    let mut dr = br / den;
+    let mut di = -bi / den;
+    let dlr = cr * dr - ci * di;
+    let dli = cr * di + ci * dr;
+    let temp = p * dlr - q * dli;
+    q = p * dli + q * dlr;
+    p = temp;
+    for i in 2..100_000 {
+        a += Quad::from(2 * (i - 1));
+        bi += Quad::from(2);
+        dr = a * dr + br;
+        di = a * di + bi;
+        if dr.abs() + di.abs() < tiny {
+            dr = tiny;
+        }
+        let fact = a / (cr * cr + ci * ci);
+        cr = br + cr * fact;
+        ci = bi - ci * fact;
+        if cr.abs() + ci.abs() < tiny {
+            cr = tiny;
+        }
+        let den = dr * dr + di * di;
+        dr /= den;
+        di = -di / den;
+        let dlr = cr * dr - ci * di;
+        let dli = cr * di + ci * dr;
+        let temp = p * dlr - q * dli;
+        q = p * dli + q * dlr;
+        p = temp;
+        if (dlr - Quad::ONE).abs() + dli.abs() < Quad::EPSILON {
+            break;
+        }
+    }
+
+    let w = xi2 * Quad::FRAC_1_PI;
+    let gamma = (p - f) / q;
+    let j0 = (w / ((p - f) * gamma + q)).sqrt();
+    let j0 = if positive { j0 } else { -j0 };
+    let y0 = j0 * gamma;
+    (j0, -f * j0, y0, -y0 * (p + q / gamma))
+}
+
+// Calculates J₀(x), J₁(x), Y₀(x), and Y₁(x) from Hankel's asymptotic expansions,
+//
+//      J_ν(x) = √(2/(πx)) (P cos χ - Q sin χ)
+//      Y_ν(x) = √(2/(πx)) (P sin χ + Q cos χ),
+//
+// where χ = x - (ν/2 + 1/4)π. The sines and cosines of χ are taken from those of x itself
+// so that no precision is lost forming χ when x is large.
+fn hankel_j_y(x: Quad) -> (Quad, Quad, Quad, Quad) {
+    let (p0, q0) = hankel_p_q(x, 0.0);
+    let (p1, q1) = hankel_p_q(x, 4.0);
+    let (s, c) = x.sin_cos();
+    let k = (Quad::PI * x).sqrt().recip();
+    let (sum, diff) = (s + c, s - c);
+    (
+        k * (p0 * sum - q0 * diff),
+        k * (p1 * diff + q1 * sum),
+        k * (p0 * diff + q0 * sum),
+        k * (q1 * diff - p1 * sum),
+    )
+}
+
+// Sums the asymptotic series P and Q for the Bessel functions of order ν, where mu = 4ν².
+// The series are cut off once the terms get negligible or start to grow again.
+fn hankel_p_q(x: Quad, mu: f64) -> (Quad, Quad) {
+    let xi = x.recip();
+    let mu = Quad::from(mu);
+    let mut p = Quad::ONE;
+    let mut q = Quad::ZERO;
+    let mut term = Quad::ONE;
+    let mut k = 1;
+    loop {
+        let odd = Quad::from(2 * k - 1);
+        let next = term * (mu - odd * odd) * xi / Quad::from(8 * k);
+        if next.abs() > term.abs() {
+            break;
+        }
+        term = next;
+        // The terms alternate between P and Q, and each of them alternates in sign
+        match k % 4 {
+            0 => p += term,
+            1 => q += term,
+            2 => p -= term,
+            _ => q -= term,
+        }
+        if term.abs() < Quad::EPSILON * p.abs().max(q.abs()) {
+            break;
+        }
+        k += 1;
+    }
+    (p, q)
+}
+
+// Calculates I₀(x) and I₁(x) for non-negative x from their power series,
+//
+//      I₀(x) = Σ (x²/4)ᵏ / (k!)²
+//      I₁(x) = x/2 Σ (x²/4)ᵏ / (k! (k + 1)!).
+fn i(x: Quad) -> (Quad, Quad) {
+    let q = c::mul_pwr2(x * x, 0.25);
+    let mut t0 = Quad::ONE;
+    let mut t1 = Quad::ONE;
+    let mut s0 = Quad::ONE;
+    let mut s1 = Quad::ONE;
+    let mut k = 1;
+    loop {
+        t0 = t0 * q / Quad::from(k * k);
+        t1 = t1 * q / Quad::from(k * (k + 1));
+        s0 += t0;
+        s1 += t1;
+        if t0 < Quad::EPSILON * s0 && t1 < Quad::EPSILON * s1 {
+            break;
+        }
+        k += 1;
+    }
+    (s0, c::mul_pwr2(x, 0.5) * s1)
+}
+
+// Calculates K₀(x) and K₁(x) for positive x.
+fn k(x: Quad) -> (Quad, Quad) {
+    if x.0 < SERIES_LIMIT {
+        series_k(x)
+    } else {
+        temme_k(x)
+    }
+}
+
+// Calculates K₀(x) and K₁(x) from their power series. With Hₖ as the kth harmonic number,
+//
+//      K₀(x) = -(ln(x/2) + γ) I₀(x) + Σ Hₖ (x²/4)ᵏ / (k!)²
+//      K₁(x) = 1/x + (ln(x/2) + γ) I₁(x) - x/4 Σ (Hₖ + Hₖ₊₁) (x²/4)ᵏ / (k! (k + 1)!).
+fn series_k(x: Quad) -> (Quad, Quad) {
+    let q = c::mul_pwr2(x * x, 0.25);
+    let mut t0 = Quad::ONE;
+    let mut t1 = Quad::ONE;
+    let mut h = Quad::ZERO;
+    let mut s0 = Quad::ZERO;
+    let mut s1 = Quad::ONE;
+    let mut k = 1;
+    loop {
+        let hk = h + Quad::from(k).recip();
+        t0 = t0 * q / Quad::from(k * k);
+        t1 = t1 * q / Quad::from(k * (k + 1));
+        let d0 = hk * t0;
+        let d1 = (hk + hk + Quad::from(k + 1).recip()) * t1;
+        s0 += d0;
+        s1 += d1;
+        h = hk;
+        if d0 < Quad::EPSILON * s0 && d1 < Quad::EPSILON * s1 {
+            break;
+        }
+        k += 1;
+    }
+
+    let (i0, i1) = i(x);
+    let lg = c::mul_pwr2(x, 0.5).ln() + c::EULER_GAMMA;
+    (
+        s0 - lg * i0,
+        x.recip() + lg * i1 - c::mul_pwr2(x * s1, 0.25),
+    )
+}
+
+// Calculates K₀(x) and K₁(x) for x ≥ 2 with Temme's continued fraction (evaluated by
+// Steed's method), as described in Numerical Recipes.
+fn temme_k(x: Quad) -> (Quad, Quad) {
+    let a1 = Quad::from(0.25);
+    let mut b = c::mul_pwr2(Quad::ONE + x, 2.0);
+    let mut d = b.recip();
+    let mut h = d;
+    let mut delh = d;
+    let mut q1 = Quad::ZERO;
+    let mut q2 = Quad::ONE;
+    let mut q = a1;
+    let mut cf = a1;
+    let mut a = -a1;
+    let mut s = Quad::ONE + q * delh;
+    for i in 2..100_000 {
+        a -= Quad::from(2 * (i - 1));
+        cf = -a * cf / Quad::from(i);
+        let qnew = (q1 - b * q2) / a;
+        q1 = q2;
+        q2 = qnew;
+        q += cf * qnew;
+        if cf.abs().0 > 1e150 {
+            // cf grows like a factorial and q1 and q2 shrink like one. Only their products
+            // matter, so they're rescaled together to keep them in range.
+            cf = c::mul_pwr2(cf, 2f64.powi(-500));
+            q1 = c::mul_pwr2(q1, 2f64.powi(500));
+            q2 = c::mul_pwr2(q2, 2f64.powi(500));
+        }
+        b += Quad::from(2);
+        d = (b + a * d).recip();
+        delh = (b * d - Quad::ONE) * delh;
+        h += delh;
+        let dels = q * delh;
+        s += dels;
+        if (dels / s).abs() < Quad::EPSILON {
+            break;
+        }
+    }
+    let h = a1 * h;
+
+    let k0 = (c::mul_pwr2(Quad::PI / x, 0.5)).sqrt() * (-x).exp() / s;
+    let k1 = k0 * (x + Quad::from(0.5) - h) / x;
+    (k0, k1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // j0 tests
+    test_all_near!(
+        j0_0_5:
+            qd!("0.938469807240812904228404673599712625568926797096821576554705168024"),
+            qd!(0.5).j0();
+        j0_1:
+            qd!("0.765197686557966551449717526102663220909274289755325241861547549119"),
+            qd!(1).j0();
+        j0_3:
+            qd!("-0.260051954901933437624154695977331436819608653511293277055986885379"),
+            qd!(3).j0();
+        j0_10:
+            qd!("-0.245935764451348335197760862485328753829600072826566569699158393641"),
+            qd!(10).j0();
+        j0_50:
+            qd!("0.0558123276692518150047504785294339681765926710455781361966132531549"),
+            qd!(50).j0();
+        j0_100:
+            qd!("0.0199858503042231224242283909508489906806335788590279295586421144472"),
+            qd!(100).j0();
+        j0_neg_1:
+            qd!("0.765197686557966551449717526102663220909274289755325241861547549119"),
+            qd!(-1).j0();
+    );
+    test_all_exact!(
+        j0_zero:
+            Quad::ONE,
+            Quad::ZERO.j0();
+        j0_inf:
+            Quad::ZERO,
+            Quad::INFINITY.j0();
+        j0_nan:
+            Quad::NAN,
+            Quad::NAN.j0();
+    );
+
+    // j1 tests
+    test_all_near!(
+        j1_0_5:
+            qd!("0.242268457674873886383954576141531640800628654437959753506925305893"),
+            qd!(0.5).j1();
+        j1_1:
+            qd!("0.44005058574493351595968220371891491312737230199276525113675817178"),
+            qd!(1).j1();
+        j1_3:
+            qd!("0.33905895852593645892551459720647889697308041819800817548157077715"),
+            qd!(3).j1();
+        j1_10:
+            qd!("0.043472746168861436669748768025859288306272867118594208135914322601"),
+            qd!(10).j1();
+        j1_50:
+            qd!("-0.0975118281251751376614589538737016140404883189848785892795912875054"),
+            qd!(50).j1();
+        j1_100:
+            qd!("-0.0771453520141121580326854949272344702116116670992429716069733930415"),
+            qd!(100).j1();
+        j1_neg_1:
+            qd!("-0.44005058574493351595968220371891491312737230199276525113675817178"),
+            qd!(-1).j1();
+    );
+    test_all_exact!(
+        j1_zero:
+            Quad::ZERO,
+            Quad::ZERO.j1();
+        j1_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.j1();
+        j1_inf:
+            Quad::ZERO,
+            Quad::INFINITY.j1();
+        j1_nan:
+            Quad::NAN,
+            Quad::NAN.j1();
+    );
+
+    // y0 tests
+    test_all_near!(
+        y0_1eneg_5:
+            qd!("-7.40316028370197013259676050746759072070960287586102867247158738461"),
+            qd!(1e-5).y0();
+        y0_0_5:
+            qd!("-0.444518733506706557148398475068331910373565124401511020414891179388"),
+            qd!(0.5).y0();
+        y0_1:
+            qd!("0.088256964215676957982926766023515162827817523090675546711043847612"),
+            qd!(1).y0();
+        #[cfg(not(feature = "fast_arith"))]
+        y0_3:
+            qd!("0.376850010012790381967110192396616411216862135755948192207719966628"),
+            qd!(3).y0();
+        #[cfg(not(feature = "fast_arith"))]
+        y0_10:
+            qd!("0.0556711672835993914244598774101900481451281145169050440822979136219"),
+            qd!(10).y0();
+        y0_50:
+            qd!("-0.0980649954700770790292114534403704320079127760337839352637685983298"),
+            qd!(50).y0();
+        y0_100:
+            qd!("-0.0772443133650831522542282213671987705056989836566356547719427776077"),
+            qd!(100).y0();
+    );
+    test_all_exact!(
+        y0_zero:
+            Quad::NEG_INFINITY,
+            Quad::ZERO.y0();
+        y0_inf:
+            Quad::ZERO,
+            Quad::INFINITY.y0();
+        y0_neg:
+            Quad::NAN,
+            Quad::NEG_ONE.y0();
+        y0_nan:
+            Quad::NAN,
+            Quad::NAN.y0();
+    );
+
+    // y1 tests
+    test_all_prec!(
+        y1_3:
+            qd!("0.324674424791799978437012839287953239669275143372354956838717663186"),
+            qd!(3).y1(),
+            61;
+    );
+    test_all_near!(
+        #[cfg(not(feature = "fast_arith"))]
+        y1_1eneg_5:
+            qd!("-6.36619772753654851574748484392477251091502544786872405084716909807e4"),
+            qd!(1e-5).y1();
+        y1_0_5:
+            qd!("-1.47147239267024306918858463532329745324108805543574832295592238341"),
+            qd!(0.5).y1();
+        y1_1:
+            qd!("-0.781212821300288716547150000047964820549906390716444607843833246128"),
+            qd!(1).y1();
+        y1_10:
+            qd!("0.249015424206953883923283474663222803260416543069658461246943741024"),
+            qd!(10).y1();
+        y1_50:
+            qd!("-0.0567956685620147679418195492377633601344066253973803098964358094167"),
+            qd!(50).y1();
+        y1_100:
+            qd!("-0.0203723120027597933047039326664145597420186556890369904597992826316"),
+            qd!(100).y1();
+    );
+    test_all_exact!(
+        y1_zero:
+            Quad::NEG_INFINITY,
+            Quad::ZERO.y1();
+        y1_inf:
+            Quad::ZERO,
+            Quad::INFINITY.y1();
+        y1_neg:
+            Quad::NAN,
+            Quad::NEG_ONE.y1();
+        y1_nan:
+            Quad::NAN,
+            Quad::NAN.y1();
+    );
+
+    // yn tests
+    test_all_prec!(
+        yn_0_2:
+            qd!("0.510375672649745119596606592727157873268139227085846135571839271933"),
+            qd!(2).yn(0),
+            61;
+        yn_neg_2_3:
+            qd!("-0.16040039348492372967576829953798091810401204017437822098190819117"),
+            qd!(3).yn(-2),
+            61;
+    );
+    test_all_near!(
+        yn_3_1:
+            qd!("-5.82151760596472884776175706442981439516061482737781444175100811334"),
+            qd!(1).yn(3);
+        yn_5_10:
+            qd!("0.135403047689362303197029014762241709088405766746419538495983184073"),
+            qd!(10).yn(5);
+        yn_neg_3_2:
+            qd!("1.1277837768404277860815839577317923832237593524067461316694731011"),
+            qd!(2).yn(-3);
+        yn_2_100:
+            qd!("0.076836867125027956388134142713870479310858610542854914962746791955"),
+            qd!(100).yn(2);
+    );
+    test_all_exact!(
+        yn_zero:
+            Quad::NEG_INFINITY,
+            Quad::ZERO.yn(2);
+        yn_zero_neg_odd:
+            Quad::INFINITY,
+            Quad::ZERO.yn(-1);
+        yn_neg:
+            Quad::NAN,
+            Quad::NEG_ONE.yn(2);
+    );
+
+    // i0 tests
+    test_all_near!(
+        i0_0_5:
+            qd!("1.06348337074132351926318441544535652932952317482110498916957207469"),
+            qd!(0.5).i0();
+        i0_1:
+            qd!("1.26606587775200833559824462521471753760767031135496220680813533121"),
+            qd!(1).i0();
+        i0_10:
+            qd!("2.81571662846625447146981115342659009307845123839607778216714825993e3"),
+            qd!(10).i0();
+        i0_100:
+            qd!("1.07375170713107382351972085760349466128840319332527279540154006258e42"),
+            qd!(100).i0();
+        i0_neg_3:
+            qd!("4.88079258586502408561123554602131924942432885640599761605956381617"),
+            qd!(-3).i0();
+    );
+    test_all_exact!(
+        i0_zero:
+            Quad::ONE,
+            Quad::ZERO.i0();
+        i0_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.i0();
+        i0_neg_inf:
+            Quad::INFINITY,
+            Quad::NEG_INFINITY.i0();
+        i0_nan:
+            Quad::NAN,
+            Quad::NAN.i0();
+    );
+
+    // i1 tests
+    test_all_near!(
+        i1_0_5:
+            qd!("0.257894305390896316362479659523209634187743149640794572730945190871"),
+            qd!(0.5).i1();
+        i1_1:
+            qd!("0.565159103992485027207696027609863307328899621621092009480294489479"),
+            qd!(1).i1();
+        i1_10:
+            qd!("2.67098830370125465434103196677215254914574515378753771310848931619e3"),
+            qd!(10).i1();
+        i1_100:
+            qd!("1.0683693903381624812061457632242952654461228440562322696591802151e42"),
+            qd!(100).i1();
+        i1_neg_3:
+            qd!("-3.95337021740260939647863574058058128758422159509125917758584548952"),
+            qd!(-3).i1();
+    );
+    test_all_exact!(
+        i1_zero:
+            Quad::ZERO,
+            Quad::ZERO.i1();
+        i1_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.i1();
+        i1_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.i1();
+        i1_nan:
+            Quad::NAN,
+            Quad::NAN.i1();
+    );
+
+    // k0 tests
+    test_all_prec!(
+        k0_3:
+            qd!("0.0347395043862792480723495513510888316948459806190420292533206011345"),
+            qd!(3).k0(),
+            61;
+    );
+    test_all_near!(
+        k0_1eneg_5:
+            qd!("11.6288569809443622934184447104233411757590998515084085114255940872"),
+            qd!(1e-5).k0();
+        k0_0_5:
+            qd!("0.924419071227665861781924167530216989538768311953529684815019740633"),
+            qd!(0.5).k0();
+        k0_1:
+            qd!("0.421024438240708333335627379212609036136219748226660472298969551455"),
+            qd!(1).k0();
+        k0_10:
+            qd!("1.7780062316167651811301192799492792312873470160346436009253918399e-5"),
+            qd!(10).k0();
+        k0_100:
+            qd!("4.65662822917590201893900528948388635580753948544211387402671365207e-45"),
+            qd!(100).k0();
+    );
+    test_all_exact!(
+        k0_zero:
+            Quad::INFINITY,
+            Quad::ZERO.k0();
+        k0_inf:
+            Quad::ZERO,
+            Quad::INFINITY.k0();
+        k0_neg:
+            Quad::NAN,
+            Quad::NEG_ONE.k0();
+        k0_nan:
+            Quad::NAN,
+            Quad::NAN.k0();
+    );
+
+    // k1 tests
+    test_all_prec!(
+        k1_3:
+            qd!("0.0401564311281941843767057801526848149072439629643088890733821634491"),
+            qd!(3).k1(),
+            61;
+    );
+    test_all_near!(
+        #[cfg(not(feature = "fast_arith"))]
+        k1_1eneg_5:
+            qd!("9.99999999393557150960518670942038610457559079353959989893848450735e4"),
+            qd!(1e-5).k1();
+        k1_0_5:
+            qd!("1.65644112000330089369644540317409151153410075946407744605542781453"),
+            qd!(0.5).k1();
+        k1_1:
+            qd!("0.601907230197234574737540001535617339261586889968106456017767959169"),
+            qd!(1).k1();
+        k1_10:
+            qd!("1.86487734538255845968168581223716746816668801026340541215150986732e-5"),
+            qd!(10).k1();
+        k1_100:
+            qd!("4.6798537356369092865625442420243353079749435469433535293746466648e-45"),
+            qd!(100).k1();
+    );
+    test_all_exact!(
+        k1_zero:
+            Quad::INFINITY,
+            Quad::ZERO.k1();
+        k1_inf:
+            Quad::ZERO,
+            Quad::INFINITY.k1();
+        k1_neg:
+            Quad::NAN,
+            Quad::NEG_ONE.k1();
+        k1_nan:
+            Quad::NAN,
+            Quad::NAN.k1();
+    );
+}
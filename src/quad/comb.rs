@@ -0,0 +1,465 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bigint::BigNat;
+use crate::quad::Quad;
+
+impl Quad {
+    /// Calculates the factorial of `n` as a `Quad`.
+    ///
+    /// The factorial is calculated exactly as an integer and only then rounded, so the
+    /// result is exact for as long as it fits into a `Quad` (up to 58!) and correctly
+    /// rounded after that. If the result is too large to be represented (past 170!), it
+    /// overflows to [`INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::factorial(5) == qd!(120));
+    /// assert!(Quad::factorial(25) == qd!("15511210043330985984000000"));
+    /// assert!(Quad::factorial(171) == Quad::INFINITY);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn factorial(n: u32) -> Quad {
+        let mut p = BigNat::from_u64(1);
+        for k in 2..=n as u64 {
+            p.mul_small(k);
+            if p.bits() > MAX_BITS {
+                return Quad::INFINITY;
+            }
+        }
+        from_big_nat(&p)
+    }
+
+    /// Calculates the binomial coefficient "`n` choose `k`" as a `Quad`.
+    ///
+    /// This is the number of ways to choose `k` items from `n`, or n! / (k! (n - k)!),
+    /// which is zero if `k` is greater than `n`. Like [`factorial`], it's calculated
+    /// exactly as an integer and only then rounded, so the result is exact for as long as
+    /// it fits into a `Quad` and correctly rounded after that. If the result is too large
+    /// to be represented, it overflows to [`INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::binomial(10, 3) == qd!(120));
+    /// assert!(Quad::binomial(100, 50) == qd!("100891344545564193334812497256"));
+    /// assert!(Quad::binomial(3, 10) == Quad::ZERO);
+    /// ```
+    ///
+    /// [`factorial`]: #method.factorial
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn binomial(n: u32, k: u32) -> Quad {
+        if k > n {
+            return Quad::ZERO;
+        }
+        let k = k.min(n - k) as u64;
+        let n = n as u64;
+        let mut p = BigNat::from_u64(1);
+        for i in 1..=k {
+            // After this step, p is C(n - k + i, i), which is always an integer
+            p.mul_small(n - k + i);
+            p.div_small(i);
+            if p.bits() > MAX_BITS {
+                return Quad::INFINITY;
+            }
+        }
+        from_big_nat(&p)
+    }
+
+    /// Calculates the rising factorial of the `Quad` with `n` factors, x (x + 1) (x + 2) ⋯
+    /// (x + n - 1). This is also known as the Pochhammer symbol (x)ₙ.
+    ///
+    /// If the `Quad` is an integer, the product is calculated exactly as an integer and
+    /// only then rounded, so the result is exact for as long as it fits into a `Quad` and
+    /// correctly rounded after that. Otherwise, each factor is multiplied in turn and
+    /// rounded normally. If the result is too large to be represented, it overflows to
+    /// [`INFINITY`] or [`NEG_INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(5).rising_factorial(3) == qd!(210));
+    /// assert!(qd!(-3).rising_factorial(3) == qd!(-6));
+    /// assert!(qd!(-3).rising_factorial(5) == qd!(0));
+    ///
+    /// let x = qd!(0.5).rising_factorial(3);
+    /// let expected = qd!(1.875);
+    /// assert!((x - expected).abs() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn rising_factorial(self, n: u32) -> Quad {
+        match self.pre_rising_factorial(n) {
+            Some(r) => r,
+            None => product(self, n, 1),
+        }
+    }
+
+    /// Calculates the falling factorial of the `Quad` with `n` factors, x (x - 1) (x - 2) ⋯
+    /// (x - n + 1).
+    ///
+    /// If the `Quad` is an integer, the product is calculated exactly as an integer and
+    /// only then rounded, so the result is exact for as long as it fits into a `Quad` and
+    /// correctly rounded after that. Otherwise, each factor is multiplied in turn and
+    /// rounded normally. If the result is too large to be represented, it overflows to
+    /// [`INFINITY`] or [`NEG_INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(7).falling_factorial(3) == qd!(210));
+    /// assert!(qd!(3).falling_factorial(5) == qd!(0));
+    ///
+    /// let x = qd!(2.5).falling_factorial(2);
+    /// let expected = qd!(3.75);
+    /// assert!((x - expected).abs() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn falling_factorial(self, n: u32) -> Quad {
+        match self.pre_falling_factorial(n) {
+            Some(r) => r,
+            None => product(self, n, -1),
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_rising_factorial(&self, n: u32) -> Option<Quad> {
+        if n == 0 {
+            Some(Quad::ONE)
+        } else if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(self.powi(n as i64))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_falling_factorial(&self, n: u32) -> Option<Quad> {
+        self.pre_rising_factorial(n)
+    }
+}
+
+// The number of bits past which an integer is too large to be represented. Anything with
+// this many bits or fewer is less than 2^1024, which may still round up to infinity.
+const MAX_BITS: u32 = 1024;
+
+// Calculates the product x (x + s) (x + 2s) ⋯ (x + (n - 1)s) for finite x, where the step
+// s is 1 or -1.
+fn product(x: Quad, n: u32, step: i64) -> Quad {
+    if x.fract().is_zero() {
+        if let Some(m) = x.trunc_i64() {
+            return integer_product(m, n, step);
+        }
+    }
+
+    let mut p = x;
+    for j in 1..n {
+        p *= x + Quad::from(step * j as i64);
+        if p.is_zero() || p.is_infinite() {
+            break;
+        }
+    }
+    p
+}
+
+// Calculates the product m (m + s) (m + 2s) ⋯ (m + (n - 1)s) exactly, where the step s is 1
+// or -1, and then rounds it.
+fn integer_product(m: i64, n: u32, step: i64) -> Quad {
+    let first = m as i128;
+    let last = first + (step * (n as i64 - 1)) as i128;
+    let (lo, hi) = (first.min(last), first.max(last));
+    if lo <= 0 && hi >= 0 {
+        return Quad::ZERO;
+    }
+
+    // Either all of the factors are positive or all of them are negative
+    let negative = hi < 0 && n % 2 == 1;
+    let mut p = BigNat::from_u64(1);
+    for j in 0..n as i128 {
+        p.mul_small((lo + j).unsigned_abs() as u64);
+        if p.bits() > MAX_BITS {
+            return if negative {
+                Quad::NEG_INFINITY
+            } else {
+                Quad::INFINITY
+            };
+        }
+    }
+    let r = from_big_nat(&p);
+    if negative {
+        -r
+    } else {
+        r
+    }
+}
+
+// Rounds an exact integer into a `Quad`.
+fn from_big_nat(n: &BigNat) -> Quad {
+    if n.bits() > MAX_BITS {
+        return Quad::INFINITY;
+    }
+    let mut c = [0.0; 4];
+    n.to_components(&mut c);
+    if c[0].is_infinite() {
+        Quad::INFINITY
+    } else {
+        Quad(c[0], c[1], c[2], c[3])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // factorial tests
+    test_all_exact!(
+        factorial_0:
+            Quad::new(1.0, 0.0, 0.0, 0.0),
+            Quad::factorial(0);
+        factorial_1:
+            Quad::new(1.0, 0.0, 0.0, 0.0),
+            Quad::factorial(1);
+        factorial_2:
+            Quad::new(2.0, 0.0, 0.0, 0.0),
+            Quad::factorial(2);
+        factorial_5:
+            Quad::new(120.0, 0.0, 0.0, 0.0),
+            Quad::factorial(5);
+        factorial_20:
+            Quad::new(2.43290200817664e18, 0.0, 0.0, 0.0),
+            Quad::factorial(20);
+        factorial_36:
+            Quad::new(3.7199332678990125e41, -3.73956733043439e25, 0.0, 0.0),
+            Quad::factorial(36);
+        factorial_37:
+            Quad::new(1.3763753091226346e43, -5.325561352520254e26, 17179869184.0, 0.0),
+            Quad::factorial(37);
+        factorial_58:
+            Quad::new(
+                2.3505613312828785e78,
+                7.690781796036033e61,
+                4.359574377190208e45,
+                1.260093861364601e29,
+            ),
+            Quad::factorial(58);
+        factorial_59:
+            Quad::new(
+                1.3868311854568984e80,
+                -2.8672092482841673e63,
+                -5.398687203674357e45,
+                -1.7134981931823055e29,
+            ),
+            Quad::factorial(59);
+        factorial_100:
+            Quad::new(
+                9.332621544394415e157,
+                1.716052534060313e141,
+                -6.07833487716781e124,
+                -3.661171626525457e108,
+            ),
+            Quad::factorial(100);
+        factorial_170:
+            Quad::new(
+                7.257415615307999e306,
+                -6.760368134125985e289,
+                -3.8703610351801256e273,
+                2.2821734374749212e257,
+            ),
+            Quad::factorial(170);
+        factorial_171:
+            Quad::INFINITY,
+            Quad::factorial(171);
+        factorial_max:
+            Quad::INFINITY,
+            Quad::factorial(u32::MAX);
+    );
+
+    // binomial tests
+    test_all_exact!(
+        binomial_0_0:
+            Quad::new(1.0, 0.0, 0.0, 0.0),
+            Quad::binomial(0, 0);
+        binomial_5_0:
+            Quad::new(1.0, 0.0, 0.0, 0.0),
+            Quad::binomial(5, 0);
+        binomial_5_5:
+            Quad::new(1.0, 0.0, 0.0, 0.0),
+            Quad::binomial(5, 5);
+        binomial_10_3:
+            Quad::new(120.0, 0.0, 0.0, 0.0),
+            Quad::binomial(10, 3);
+        binomial_52_5:
+            Quad::new(2598960.0, 0.0, 0.0, 0.0),
+            Quad::binomial(52, 5);
+        binomial_100_50:
+            Quad::new(1.008913445455642e29, -8736902458008.0, 0.0, 0.0),
+            Quad::binomial(100, 50);
+        binomial_1000_500:
+            Quad::new(
+                2.7028824094543655e299,
+                1.8496308785162572e283,
+                2.060999405990349e266,
+                -1.2297976221458766e250,
+            ),
+            Quad::binomial(1000, 500);
+        binomial_1020_510:
+            Quad::new(
+                2.8062677682996225e305,
+                1.6093556670481862e289,
+                -5.886398368433073e271,
+                -2.396256116950004e255,
+            ),
+            Quad::binomial(1020, 510);
+        binomial_4000000000_3:
+            Quad::new(1.0666666658666666e28, 1016816973824.0, 0.0, 0.0),
+            Quad::binomial(4000000000, 3);
+        binomial_k_greater:
+            Quad::ZERO,
+            Quad::binomial(3, 4);
+        binomial_overflow:
+            Quad::INFINITY,
+            Quad::binomial(2000, 1000);
+        binomial_large_overflow:
+            Quad::INFINITY,
+            Quad::binomial(u32::MAX, u32::MAX / 2);
+    );
+
+    // rising_factorial tests
+    test_all_exact!(
+        rising_factorial_5_3:
+            Quad::new(210.0, 0.0, 0.0, 0.0),
+            qd!(5).rising_factorial(3);
+        rising_factorial_1_40:
+            Quad::new(8.159152832478977e47, 5.055006274829492e31, 1448331691687936.0, 0.0),
+            qd!(1).rising_factorial(40);
+        rising_factorial_neg_3_3:
+            Quad::new(-6.0, -0.0, -0.0, -0.0),
+            qd!(-3).rising_factorial(3);
+        rising_factorial_neg_3_4:
+            Quad::new(0.0, 0.0, 0.0, 0.0),
+            qd!(-3).rising_factorial(4);
+        rising_factorial_neg_50_20:
+            Quad::new(1.1466075511211338e32, -8993437468590080.0, 0.0, 0.0),
+            qd!(-50).rising_factorial(20);
+        rising_factorial_neg_50_51:
+            Quad::new(0.0, 0.0, 0.0, 0.0),
+            qd!(-50).rising_factorial(51);
+        rising_factorial_7_0:
+            Quad::new(1.0, 0.0, 0.0, 0.0),
+            qd!(7).rising_factorial(0);
+        rising_factorial_1000000_30:
+            Quad::new(
+                1.0004350903469223e180,
+                -2.9442416268507096e163,
+                7.8002657092661405e146,
+                2.8972012282210023e130,
+            ),
+            qd!(1000000).rising_factorial(30);
+        rising_factorial_neg_1000000_31:
+            Quad::new(
+                -9.995351033703702e185,
+                -7.4078835960991515e168,
+                3.174849773182393e152,
+                -1.0460543134657223e136,
+            ),
+            qd!(-1000000).rising_factorial(31);
+        rising_factorial_overflow:
+            Quad::INFINITY,
+            qd!(1).rising_factorial(200);
+        rising_factorial_neg_overflow:
+            Quad::NEG_INFINITY,
+            qd!(-300).rising_factorial(199);
+        rising_factorial_nan:
+            Quad::NAN,
+            Quad::NAN.rising_factorial(3);
+        rising_factorial_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.rising_factorial(3);
+        rising_factorial_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.rising_factorial(3);
+        rising_factorial_nan_zero:
+            Quad::ONE,
+            Quad::NAN.rising_factorial(0);
+    );
+    test_all_near!(
+        rising_factorial_0_5_3:
+            qd!("1.875"),
+            qd!(0.5).rising_factorial(3);
+        rising_factorial_2_25_10:
+            qd!("6.505852392303943634033203125e7"),
+            qd!(2.25).rising_factorial(10);
+        rising_factorial_neg_1_5_7:
+            qd!("22.1484375"),
+            qd!(-1.5).rising_factorial(7);
+        rising_factorial_0_1_50:
+            qd!("9.44646434431783523246956965447535793253754884256853990253478804198e61"),
+            qd!(0.1).rising_factorial(50);
+        falling_factorial_2_5_2:
+            qd!("3.75"),
+            qd!(2.5).falling_factorial(2);
+        falling_factorial_10_75_6:
+            qd!("2.75863634033203125e5"),
+            qd!(10.75).falling_factorial(6);
+        falling_factorial_neg_1_5_7:
+            qd!("-1.58361328125e4"),
+            qd!(-1.5).falling_factorial(7);
+    );
+
+    // falling_factorial tests
+    test_all_exact!(
+        falling_factorial_7_3:
+            Quad::new(210.0, 0.0, 0.0, 0.0),
+            qd!(7).falling_factorial(3);
+        falling_factorial_40_40:
+            Quad::new(8.159152832478977e47, 5.055006274829492e31, 1448331691687936.0, 0.0),
+            qd!(40).falling_factorial(40);
+        falling_factorial_3_5:
+            Quad::new(0.0, 0.0, 0.0, 0.0),
+            qd!(3).falling_factorial(5);
+        falling_factorial_neg_3_3:
+            Quad::new(-60.0, -0.0, -0.0, -0.0),
+            qd!(-3).falling_factorial(3);
+        falling_factorial_neg_4_6:
+            Quad::new(60480.0, 0.0, 0.0, 0.0),
+            qd!(-4).falling_factorial(6);
+        falling_factorial_100_0:
+            Quad::new(1.0, 0.0, 0.0, 0.0),
+            qd!(100).falling_factorial(0);
+        falling_factorial_1000000_30:
+            Quad::new(
+                9.9956509032308e179,
+                -3.026953437630666e163,
+                -2.4850044304326334e147,
+                -1.6692008572682706e131,
+            ),
+            qd!(1000000).falling_factorial(30);
+        falling_factorial_overflow:
+            Quad::INFINITY,
+            qd!(200).falling_factorial(200);
+        falling_factorial_nan:
+            Quad::NAN,
+            Quad::NAN.falling_factorial(3);
+        falling_factorial_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.falling_factorial(3);
+    );
+}
@@ -10,8 +10,26 @@ pub fn mul_pwr2(a: Quad, n: f64) -> Quad {
     Quad(a.0 * n, a.1 * n, a.2 * n, a.3 * n)
 }
 
+/// Multiplies two complex numbers, each represented by a tuple of its real and imaginary
+/// parts.
+#[cfg(feature = "transcendental")]
+#[inline]
+pub fn complex_mul(a: (Quad, Quad), b: (Quad, Quad)) -> (Quad, Quad) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+/// Calculates the reciprocal of a complex number represented by a tuple of its real and
+/// imaginary parts.
+#[cfg(feature = "transcendental")]
+#[inline]
+pub fn complex_recip(a: (Quad, Quad)) -> (Quad, Quad) {
+    let m = a.0 * a.0 + a.1 * a.1;
+    (a.0 / m, -a.1 / m)
+}
+
 /// Reciprocals of factorials, rendered as Quads. These are used in Taylor series
 /// calculations.
+#[cfg(feature = "transcendental")]
 pub const INV_FACTS: [Quad; 15] = [
     Quad(
         1.6666666666666666e-1,
@@ -106,6 +124,7 @@ pub const INV_FACTS: [Quad; 15] = [
 ];
 
 // Table of sin(kπ/1024), for k in [1, 256]
+#[cfg(feature = "transcendental")]
 pub const SINES: [Quad; 256] = [
     Quad(
         3.067956762965976e-3,
@@ -1646,6 +1665,7 @@ pub const SINES: [Quad; 256] = [
 ];
 
 // Table of cos(kπ/1024), for k in [1, 256]
+#[cfg(feature = "transcendental")]
 pub const COSINES: [Quad; 256] = [
     Quad(
         9.999952938095762e-1,
@@ -3184,3 +3204,283 @@ pub const COSINES: [Quad; 256] = [
         2.467773495734176e-50,
     ),
 ];
+
+/// The Euler-Mascheroni constant, γ.
+#[cfg(feature = "transcendental")]
+pub const EULER_GAMMA: Quad = Quad::EGAMMA;
+
+/// Even-indexed Bernoulli numbers, starting with B₂, rendered as Quads. These are used in
+/// the asymptotic expansions of the gamma function and its relatives.
+#[cfg(feature = "transcendental")]
+pub const BERNOULLI: [Quad; 45] = [
+    Quad(
+        1.6666666666666666e-1,
+        9.25185853854297e-18,
+        5.135813185032629e-34,
+        2.850949024098342e-50,
+    ),
+    Quad(
+        -3.333333333333333e-2,
+        -4.625929269271486e-19,
+        -6.419766481290786e-36,
+        -8.909215700307319e-53,
+    ),
+    Quad(
+        2.3809523809523808e-2,
+        1.32169407693471e-18,
+        7.336875978618041e-35,
+        4.0727843201404884e-51,
+    ),
+    Quad(
+        -3.333333333333333e-2,
+        -4.625929269271486e-19,
+        -6.419766481290786e-36,
+        -8.909215700307319e-53,
+    ),
+    Quad(
+        7.575757575757576e-2,
+        -2.10269512239613e-18,
+        5.836151346627988e-35,
+        -1.6198574000558762e-51,
+    ),
+    Quad(
+        -2.531135531135531e-1,
+        -1.1061562736192037e-17,
+        7.607776014751631e-34,
+        -3.40860718485604e-50,
+    ),
+    Quad(
+        1.1666666666666667e0,
+        -7.401486830834377e-17,
+        -4.108650548026103e-33,
+        -2.2807592192786735e-49,
+    ),
+    Quad(
+        -7.092156862745098e0,
+        -3.274069468698501e-16,
+        2.0108219152692458e-32,
+        2.7905759859409653e-49,
+    ),
+    Quad(
+        5.4971177944862156e1,
+        -1.9588897477095493e-16,
+        -1.0874022503046528e-32,
+        -6.036295076587166e-49,
+    ),
+    Quad(
+        -5.291242424242424e2,
+        6.890111377067638e-16,
+        5.976218978947059e-34,
+        5.183543680178804e-52,
+    ),
+    Quad(
+        6.192123188405797e3,
+        9.226757844073186e-14,
+        -5.853576572074754e-30,
+        7.108035688604145e-47,
+    ),
+    Quad(
+        -8.658025311355312e4,
+        3.5926706461242705e-12,
+        -1.609669232303282e-28,
+        1.1070771164306834e-44,
+    ),
+    Quad(
+        1.4255171666666667e6,
+        -7.761021455128987e-11,
+        -4.308232357047019e-27,
+        -2.3915493791143543e-43,
+    ),
+    Quad(
+        -2.7298231067816094e7,
+        1.610010519795034e-9,
+        2.234338436206454e-26,
+        3.1007674708517147e-43,
+    ),
+    Quad(
+        6.015808739006424e8,
+        -2.6635227381825164e-8,
+        -2.310237711361619e-26,
+        -2.0038117964929656e-44,
+    ),
+    Quad(
+        -1.5116315767092157e10,
+        5.011465035232843e-7,
+        -1.9514974765172607e-23,
+        1.1985432596619402e-39,
+    ),
+    Quad(
+        4.296146430611667e11,
+        -2.0345052083333332e-5,
+        -1.1293772630057337e-21,
+        -6.269303204385533e-38,
+    ),
+    Quad(
+        -1.3711655205088332e13,
+        -7.409090879485616e-4,
+        -1.4575906467399005e-20,
+        7.261631404270225e-37,
+    ),
+    Quad(
+        4.883323189735932e14,
+        -2.0833333333333332e-2,
+        -1.1564823173178713e-18,
+        -6.419766481290786e-35,
+    ),
+    Quad(
+        -1.9296579341940068e16,
+        -1.4863266814486326e-1,
+        -1.0970940014219026e-17,
+        -1.5396051942714223e-34,
+    ),
+    Quad(
+        8.416930475736826e17,
+        5.5000553709856035e1,
+        1.258990450959203e-16,
+        5.268900702784305e-33,
+    ),
+    Quad(
+        -4.0338071854059454e19,
+        -9.490768115942029e2,
+        2.405547580776223e-14,
+        1.0883993938701496e-30,
+    ),
+    Quad(
+        2.1150748638081993e21,
+        -1.0164785460992908e5,
+        5.366663772163661e-12,
+        3.322838786020307e-28,
+    ),
+    Quad(
+        -1.2086626522296526e23,
+        2.6786766880629174e6,
+        3.3552496116291315e-11,
+        2.8801382699793326e-27,
+    ),
+    Quad(
+        7.500866746076964e24,
+        2.0081412007575756e8,
+        1.2643409497810133e-8,
+        -3.5092510835582993e-25,
+    ),
+    Quad(
+        -5.038778101481069e26,
+        -6.426303095052201e9,
+        1.3195493686124214e-8,
+        -3.7873426788591457e-25,
+    ),
+    Quad(
+        3.6528776484818122e28,
+        1.0595445679309712e12,
+        -1.3461387844611528e-5,
+        -7.472571364248464e-22,
+    ),
+    Quad(
+        -2.849876930245088e30,
+        1.3495687303844932e13,
+        5.432830459770115e-4,
+        3.464462114378968e-20,
+    ),
+    Quad(
+        2.3865427499683627e32,
+        1.9668307468573442e15,
+        -1.0028248587570622e-1,
+        5.723607400963024e-18,
+    ),
+    Quad(
+        -2.1399949257225335e34,
+        1.1936757789605181e18,
+        7.060732584883827e1,
+        4.116591898827743e-15,
+    ),
+    Quad(
+        2.0500975723478097e36,
+        1.7982331506232715e19,
+        -9.748333333333334e2,
+        3.789561257387201e-14,
+    ),
+    Quad(
+        -2.093800591134638e38,
+        8.921695562739829e21,
+        -5.3591092156862745e4,
+        3.709311724877825e-13,
+    ),
+    Quad(
+        2.2752696488463515e40,
+        6.967910420880353e23,
+        -1.350160203459411e7,
+        -9.124722212156102e-10,
+    ),
+    Quad(
+        -2.6257710286239577e42,
+        1.1599947627916584e26,
+        6.069878267966666e9,
+        4.450480143229167e-7,
+    ),
+    Quad(
+        3.212508210271803e44,
+        7.753612571252424e27,
+        2.3614008622706168e11,
+        -2.956675302763551e-6,
+    ),
+    Quad(
+        -4.159827816679471e46,
+        1.0642832018302506e30,
+        9.718262369886536e11,
+        -3.5242349934863e-5,
+    ),
+    Quad(
+        5.692069548203528e48,
+        -3.1496744483983085e32,
+        1.0112049725094814e16,
+        -8.333333333333334e-1,
+    ),
+    Quad(
+        -8.218362941978458e50,
+        1.972697016250366e34,
+        3.14689060785986e17,
+        3.966666666666667e0,
+    ),
+    Quad(
+        1.2502904327166994e53,
+        -9.874858173094332e36,
+        -5.46261208331954e20,
+        -2.5432988848704037e4,
+    ),
+    Quad(
+        -2.001558323324837e55,
+        -2.2313276083262987e38,
+        1.202767238328886e22,
+        -4.1831520745619753e5,
+    ),
+    Quad(
+        3.3674982915364376e57,
+        -1.3228407897308492e41,
+        5.544510738580607e24,
+        -4.392947184538153e7,
+    ),
+    Quad(
+        -5.947097050313545e59,
+        2.486314552961313e43,
+        -9.034886899035218e25,
+        -4.624952963108521e8,
+    ),
+    Quad(
+        1.1011910323627977e62,
+        7.980022330933811e45,
+        -5.615570439926587e29,
+        -1.5119415539748333e12,
+    ),
+    Quad(
+        -2.1355259545253502e64,
+        8.901783616732954e47,
+        5.77525772357655e31,
+        -4.329407669821664e15,
+    ),
+    Quad(
+        4.3328896986641194e66,
+        -1.4270949710871423e50,
+        3.498142398307887e33,
+        5.75259086978816e16,
+    ),
+];
@@ -60,6 +60,151 @@ impl PartialOrd for Quad {
     }
 }
 
+// COMPARISONS WITH PRIMITIVES
+//
+// The primitive is converted exactly into a `Quad` and then compared with the usual
+// operators. Integers convert exactly through `From`, but `f64`s have to go straight into
+// the first component because `From<f64>` rounds to the closest decimal representation.
+
+#[inline]
+fn from_f64(x: f64) -> Quad {
+    Quad(x, 0.0, 0.0, 0.0)
+}
+
+macro_rules! prim_comp_impl {
+    ($(
+        $(#[$m:meta])*
+        $t:ty, $conv:path;
+    )*) => ($(
+        impl PartialEq<$t> for Quad {
+            $(#[$m])*
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                *self == $conv(*other)
+            }
+        }
+
+        impl PartialOrd<$t> for Quad {
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                self.partial_cmp(&$conv(*other))
+            }
+        }
+
+        impl PartialEq<Quad> for $t {
+            #[inline]
+            fn eq(&self, other: &Quad) -> bool {
+                $conv(*self) == *other
+            }
+        }
+
+        impl PartialOrd<Quad> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &Quad) -> Option<Ordering> {
+                $conv(*self).partial_cmp(other)
+            }
+        }
+    )*);
+}
+
+prim_comp_impl! {
+    /// Compares a `Quad` with an `f64`, in either order, with `==`, `!=`, `<`, `>`, `<=`,
+    /// and `>=`.
+    ///
+    /// The comparison uses the exact value of the `f64`, not the value closest to its
+    /// decimal representation that [`From`] would give. It otherwise works just like
+    /// comparing two `Quad`s, so `NaN` isn't equal to or ordered with anything.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::PI > 3.0);
+    /// assert!(3.0 < Quad::PI);
+    /// assert!(qd!(0.5) == 0.5);
+    /// assert!(qd!("0.1") != 0.1);
+    /// assert!(!(Quad::NAN <= f64::INFINITY));
+    /// ```
+    ///
+    /// [`From`]: struct.Quad.html#impl-From<f64>
+    f64, from_f64;
+    /// Compares a `Quad` with an `i32`, in either order, with `==`, `!=`, `<`, `>`, `<=`,
+    /// and `>=`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::PI > 3);
+    /// assert!(4 > Quad::PI);
+    /// assert!(qd!(-7) == -7);
+    /// ```
+    i32, Quad::from;
+    /// Compares a `Quad` with an `i64`, in either order, with `==`, `!=`, `<`, `>`, `<=`,
+    /// and `>=`.
+    ///
+    /// All `i64`s can be compared exactly, even those too large to fit into an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let n = i64::MAX;
+    /// assert!(qd!(n) == n);
+    /// assert!(qd!(n) > n - 1);
+    /// assert!(Quad::from(n as f64) > n);
+    /// ```
+    i64, Quad::from;
+}
+
+impl Quad {
+    /// Returns the ordering between the `Quad` and another according to the totalOrder
+    /// predicate defined in IEEE 754.
+    ///
+    /// Unlike [`partial_cmp`], this always produces an ordering, even when `NaN` is
+    /// involved. Values are ordered as follows:
+    ///
+    /// * negative `NaN`
+    /// * [`NEG_INFINITY`]
+    /// * negative numbers
+    /// * [`NEG_ZERO`]
+    /// * [`ZERO`]
+    /// * positive numbers
+    /// * [`INFINITY`]
+    /// * positive `NaN`
+    ///
+    /// Any two numbers that are equal according to `==` are also equal here, except for
+    /// zeros of opposite sign. The signs of zeros and `NaN`s are taken from their first
+    /// components.
+    ///
+    /// This makes `total_cmp` suitable for sorting slices that may contain `NaN`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut v = [Quad::NAN, qd!(1), Quad::ZERO, Quad::NEG_ZERO];
+    /// v.sort_by(|a, b| a.total_cmp(b));
+    ///
+    /// assert!(v[0].is_zero() && v[0].is_sign_negative());
+    /// assert!(v[1].is_zero() && v[1].is_sign_positive());
+    /// assert!(v[2] == qd!(1));
+    /// assert!(v[3].is_nan());
+    /// ```
+    ///
+    /// [`partial_cmp`]: #method.partial_cmp
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NEG_ZERO`]: #associatedconstant.NEG_ZERO
+    /// [`ZERO`]: #associatedconstant.ZERO
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn total_cmp(&self, other: &Quad) -> Ordering {
+        match self.0.total_cmp(&other.0) {
+            // The remaining components of a finite, non-zero number are never `NaN`, and
+            // the signs of any zeros among them don't mean anything
+            Ordering::Equal if self.0.is_finite() && self.0 != 0.0 => [self.1, self.2, self.3]
+                .partial_cmp(&[other.1, other.2, other.3])
+                .unwrap_or(Ordering::Equal),
+            x => x,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,4 +274,85 @@ mod tests {
         gte_nan_nan:
             !(Quad::NAN >= Quad::NAN);
     );
+
+    // total_cmp tests
+    test_all_assert!(
+        total_cmp_pi_e:
+            Quad::PI.total_cmp(&Quad::E) == Ordering::Greater;
+        total_cmp_e_pi:
+            Quad::E.total_cmp(&Quad::PI) == Ordering::Less;
+        total_cmp_pi_pi:
+            Quad::PI.total_cmp(&Quad::PI) == Ordering::Equal;
+        total_cmp_low_component:
+            Quad::PI.next_up().total_cmp(&Quad::PI) == Ordering::Greater;
+        total_cmp_neg_low_component:
+            (-Quad::PI).next_down().total_cmp(&-Quad::PI) == Ordering::Less;
+        total_cmp_zero_neg_zero:
+            Quad::ZERO.total_cmp(&Quad::NEG_ZERO) == Ordering::Greater;
+        total_cmp_neg_zero_zero:
+            Quad::NEG_ZERO.total_cmp(&Quad::ZERO) == Ordering::Less;
+        total_cmp_inf_max:
+            Quad::INFINITY.total_cmp(&Quad::MAX) == Ordering::Greater;
+        total_cmp_neg_inf_min:
+            Quad::NEG_INFINITY.total_cmp(&Quad::MIN) == Ordering::Less;
+        total_cmp_nan_inf:
+            Quad::NAN.total_cmp(&Quad::INFINITY) == Ordering::Greater;
+        total_cmp_neg_nan_neg_inf:
+            (-Quad::NAN).total_cmp(&Quad::NEG_INFINITY) == Ordering::Less;
+        total_cmp_nan_nan:
+            Quad::NAN.total_cmp(&Quad::NAN) == Ordering::Equal;
+        total_cmp_neg_nan_nan:
+            (-Quad::NAN).total_cmp(&Quad::NAN) == Ordering::Less;
+    );
+    test!(total_cmp_sort: {
+        let mut v = [
+            Quad::NAN,
+            Quad::PI,
+            -Quad::NAN,
+            Quad::ZERO,
+            Quad::NEG_INFINITY,
+            -Quad::E,
+            Quad::NEG_ZERO,
+            Quad::INFINITY,
+        ];
+        v.sort_by(|a, b| a.total_cmp(b));
+
+        assert!(v[0].is_nan() && v[0].is_sign_negative());
+        assert!(v[1] == Quad::NEG_INFINITY);
+        assert!(v[2] == -Quad::E);
+        assert!(v[3].is_zero() && v[3].is_sign_negative());
+        assert!(v[4].is_zero() && v[4].is_sign_positive());
+        assert!(v[5] == Quad::PI);
+        assert!(v[6] == Quad::INFINITY);
+        assert!(v[7].is_nan() && v[7].is_sign_positive());
+    });
+
+    // primitive comparison tests
+    test_all_assert!(
+        eq_f64:
+            qd!(1.5) == 1.5 && 1.5 == qd!(1.5);
+        eq_f64_exact:
+            qd!("0.1") != 0.1 && Quad::from(0.1) != 0.1;
+        eq_f64_zero:
+            Quad::NEG_ZERO == 0.0;
+        ord_f64:
+            Quad::PI > 3.0 && Quad::PI < 4.0 && 3.1 < Quad::PI && 3.2 >= Quad::PI;
+        ord_f64_low_component:
+            Quad::ONE.next_up() > 1.0 && Quad::ONE.next_down() < 1.0;
+        ord_f64_inf:
+            Quad::MAX < f64::INFINITY && f64::NEG_INFINITY <= Quad::NEG_INFINITY;
+        nan_f64:
+            Quad::NAN.is_nan()
+                && Quad::NAN.partial_cmp(&f64::NAN).is_none()
+                && Quad::NAN.partial_cmp(&1.0).is_none()
+                && 1f64.partial_cmp(&Quad::NAN).is_none();
+        eq_i32:
+            qd!(-3) == -3 && i32::MIN == qd!(i32::MIN);
+        ord_i32:
+            Quad::E > 2 && 3 > Quad::E && Quad::E >= 2i32;
+        eq_i64:
+            qd!(i64::MAX) == i64::MAX && i64::MIN == qd!(i64::MIN);
+        ord_i64:
+            qd!(i64::MAX) > i64::MAX - 1 && Quad::from(i64::MAX as f64) > i64::MAX;
+    );
 }
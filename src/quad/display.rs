@@ -6,7 +6,7 @@
 use crate::common::display as d;
 use crate::quad::Quad;
 use std::char;
-use std::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
+use std::fmt::{Display, Formatter, LowerExp, Result, UpperExp};
 
 const TEN: Quad = Quad(10.0, 0.0, 0.0, 0.0);
 const MAX_ACCURACY: usize = 62;
@@ -40,13 +40,13 @@ impl Display for Quad {
     ///
     /// // debug
     /// assert!(format!("{:?}", Quad::PI) ==
-    ///     "Quad(3.141592653589793e0, 1.2246467991473532e-16, -2.9947698097183397e-33, 1.1124542208633655e-49)");
+    ///     "Quad(3.141592653589793e0, 1.2246467991473532e-16, -2.9947698097183397e-33, 1.1124542208633653e-49)");
     /// assert!(format!("{:#?}", Quad::PI) ==
     /// "Quad(
     ///     3.141592653589793e0,
     ///     1.2246467991473532e-16,
     ///     -2.9947698097183397e-33,
-    ///     1.1124542208633655e-49
+    ///     1.1124542208633653e-49
     /// )");
     ///
     /// // precision and exponents
@@ -164,43 +164,6 @@ impl UpperExp for Quad {
     }
 }
 
-impl Debug for Quad {
-    /// Formats a `Double` for display when the "`?`" formatting option is specified.
-    ///
-    /// See [`Display::fmt`](#method.fmt-1) for more information.
-    fn fmt(&self, f: &mut Formatter) -> Result {
-        let alt = f.alternate();
-        let mut str = String::from("Quad(");
-        if alt {
-            str.push_str("\n    ");
-        }
-        str.push_str(format!("{:e},", self.0).as_str());
-        if alt {
-            str.push_str("\n    ");
-        } else {
-            str.push(' ');
-        }
-        str.push_str(format!("{:e},", self.1).as_str());
-        if alt {
-            str.push_str("\n    ");
-        } else {
-            str.push(' ');
-        }
-        str.push_str(format!("{:e},", self.2).as_str());
-        if alt {
-            str.push_str("\n    ");
-        } else {
-            str.push(' ');
-        }
-        str.push_str(format!("{:e}", self.3).as_str());
-        if alt {
-            str.push('\n');
-        }
-        str.push(')');
-        write!(f, "{}", str)
-    }
-}
-
 fn push_sign(chars: &mut Vec<char>, value: &Quad, f: &Formatter) -> bool {
     if value.is_sign_negative() {
         chars.push('-');
@@ -248,9 +211,14 @@ fn extract_digits(value: &Quad, exp: i32) -> Vec<u8> {
     // doing is parsing digits from the mantissa. This normalization makes the math involved
     // much faster. It also ensures that really large numbers don't overflow on
     // multiplication by ten.
-    let divisor = TEN.powi(exp);
-
-    let mut value = value / divisor;
+    //
+    // For the very smallest subnormal numbers, 10^exp is too small to be represented, so
+    // the normalization is done in two stages.
+    let mut value = if exp < -300 {
+        value * TEN.powi(300) / TEN.powi((exp + 300).into())
+    } else {
+        value / TEN.powi(exp.into())
+    };
     let mut digits = vec![];
 
     for _ in 0..(MAX_ACCURACY + 1) {
@@ -271,7 +239,13 @@ fn extract_digits(value: &Quad, exp: i32) -> Vec<u8> {
     //
     // If this isn't an issue, we still truncate by one because we produced an extra digit
     // for rounding.
-    d::round_and_trunc(&mut digits, (324 + exp).min(MAX_ACCURACY as i32) as usize);
+    //
+    // At least one digit is always kept, since the very smallest subnormal numbers have
+    // their first digit at position 324.
+    d::round_and_trunc(
+        &mut digits,
+        (324 + exp).clamp(1, MAX_ACCURACY as i32) as usize,
+    );
 
     digits
 }
@@ -291,7 +265,7 @@ mod tests {
                     "3.141592653589793e0, ",
                     "1.2246467991473532e-16, ",
                     "-2.9947698097183397e-33, ",
-                    "1.1124542208633655e-49",
+                    "1.1124542208633653e-49",
                 ")"
             ),
             format!("{:?}", Quad::PI);
@@ -304,7 +278,7 @@ mod tests {
                 "    3.141592653589793e0,\n",
                 "    1.2246467991473532e-16,\n",
                 "    -2.9947698097183397e-33,\n",
-                "    1.1124542208633655e-49\n",
+                "    1.1124542208633653e-49\n",
                 ")"
             ),
             format!("{:#?}", Quad::PI);
@@ -412,6 +386,21 @@ mod tests {
             format!("{:+}", qd!("1.234567890123456789e-308"));
     );
 
+    test_all_eq!(
+        small_subnormal_exp:
+            "3e-323",
+            format!("{:e}", Quad(3e-323, 0.0, 0.0, 0.0));
+        small_subnormal_fixed:
+            format!("0.{}3", "0".repeat(322)),
+            format!("{}", Quad(3e-323, 0.0, 0.0, 0.0));
+        min_subnormal_exp:
+            "5e-324",
+            format!("{:e}", Quad(5e-324, 0.0, 0.0, 0.0));
+        min_subnormal_fixed:
+            format!("0.{}5", "0".repeat(323)),
+            format!("{}", Quad(5e-324, 0.0, 0.0, 0.0));
+    );
+
     // precision tests
     test_all_eq!(
         prec_zero:
@@ -5,6 +5,7 @@
 
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::Double;
 use crate::quad::Quad;
 use std::ops::{Div, DivAssign};
 
@@ -49,55 +50,32 @@ impl Div for Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
-    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
     fn div(self, other: Quad) -> Quad {
-        match self.pre_div(&other) {
-            Some(r) => r,
-            None => {
-                // Strategy:
-                //
-                // Divide the first component of `self` by the first component of `other`.
-                // Then divide the first component of the remainder by the first component
-                // of `other`, then the first component of -that- remainder by the first
-                // component of `other`, and so on until we have five terms we can
-                // renormalize.
-                let q0 = self.0 / other.0;
-                let mut r = self - mul_f64(other, q0);
-
-                let q1 = r.0 / other.0;
-                r -= mul_f64(other, q1);
-
-                let q2 = r.0 / other.0;
-                r -= mul_f64(other, q2);
-
-                let q3 = r.0 / other.0;
-                r -= mul_f64(other, q3);
-
-                let q4 = r.0 / other.0;
-
-                let (a, b, c, d) = u::renorm5(q0, q1, q2, q3, q4);
-                Quad(a, b, c, d)
-            }
+        if cfg!(feature = "fast_arith") {
+            self.div_fast(other)
+        } else {
+            self.div_accurate(other)
         }
     }
 }
 
-/// Divides a reference to this `Quad` by another, producing a new `Quad` as a result.
-///
-/// This implements the `/` operator between two references to `Quad`s.
-///
-/// # Examples
-/// ```
-/// # use qd::{qd, Quad};
-/// let x = &Quad::E / &Quad::PI;
-/// let expected = qd!("0.8652559794322650872177747896460896174287446239085155394543302889");
-///
-/// let diff = (x - expected).abs();
-/// assert!(diff < qd!(1e-60));
-/// ```
 impl Div for &Quad {
     type Output = Quad;
 
+    /// Divides a reference to this `Quad` by another, producing a new `Quad` as a result.
+    ///
+    /// This implements the `/` operator between two references to `Quad`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = &Quad::E / &Quad::PI;
+    /// let expected = qd!("0.8652559794322650872177747896460896174287446239085155394543302889");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
     #[inline]
     fn div(self, other: &Quad) -> Quad {
         (*self).div(*other)
@@ -200,7 +178,166 @@ impl DivAssign<&Quad> for Quad {
     }
 }
 
+impl DivAssign<f64> for Quad {
+    /// Divides this `Quad` by an `f64`, modifying this one to equal the result.
+    ///
+    /// This implements the `/=` operator between a `Quad` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x /= 2.0;
+    /// let expected = qd!("1.570796326794896619231321691639751442098584699687552910487472296");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: f64) {
+        let r = self.div(Quad(other, 0.0, 0.0, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
+impl DivAssign<Double> for Quad {
+    /// Divides this `Quad` by a `Double`, modifying this one to equal the result.
+    ///
+    /// This implements the `/=` operator between a `Quad` and a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x /= dd!(2);
+    /// let expected = qd!("1.570796326794896619231321691639751442098584699687552910487472296");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Double) {
+        let (a, b): (f64, f64) = other.into();
+        let r = self.div(Quad(a, b, 0.0, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
 impl Quad {
+    /// Divides this `Quad` by another using the accurate division algorithm.
+    ///
+    /// This is the algorithm used by the `/` operator, unless the `fast_arith` feature is
+    /// enabled. It's long division with `f64` digits: each step divides the high component
+    /// of the remainder by the high component of the divisor and subtracts that multiple of
+    /// the divisor, and five such quotients are renormalized into the result.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::E.div_accurate(Quad::PI);
+    /// let expected = qd!("0.8652559794322650872177747896460896174287446239085155394543302889");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn div_accurate(self, other: Quad) -> Quad {
+        match self.pre_div(&other) {
+            Some(r) => r,
+            None => {
+                // Strategy:
+                //
+                // Divide the first component of `self` by the first component of `other`.
+                // Then divide the first component of the remainder by the first component
+                // of `other`, then the first component of -that- remainder by the first
+                // component of `other`, and so on until we have five terms we can
+                // renormalize.
+                let q0 = self.0 / other.0;
+                let mut r = self.add_accurate(-mul_f64(other, q0));
+
+                let q1 = r.0 / other.0;
+                r = r.add_accurate(-mul_f64(other, q1));
+
+                let q2 = r.0 / other.0;
+                r = r.add_accurate(-mul_f64(other, q2));
+
+                let q3 = r.0 / other.0;
+                r = r.add_accurate(-mul_f64(other, q3));
+
+                let q4 = r.0 / other.0;
+
+                let (a, b, c, d) = u::renorm5(q0, q1, q2, q3, q4);
+                Quad(a, b, c, d)
+            }
+        }
+    }
+
+    /// Divides this `Quad` by another using the fast division algorithm.
+    ///
+    /// This does the same long division as [`div_accurate`] but stops after four quotients
+    /// instead of five, and computes the remainders with the fast addition algorithm. The
+    /// last bit or two of the result can differ from the one `div_accurate` produces.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::E.div_fast(Quad::PI);
+    /// let expected = qd!("0.8652559794322650872177747896460896174287446239085155394543302889");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`div_accurate`]: #method.div_accurate
+    pub fn div_fast(self, other: Quad) -> Quad {
+        match self.pre_div(&other) {
+            Some(r) => r,
+            None => {
+                let q0 = self.0 / other.0;
+                let mut r = self.add_fast(-mul_f64(other, q0));
+
+                let q1 = r.0 / other.0;
+                r = r.add_fast(-mul_f64(other, q1));
+
+                let q2 = r.0 / other.0;
+                r = r.add_fast(-mul_f64(other, q2));
+
+                let q3 = r.0 / other.0;
+
+                let (a, b, c, d) = u::renorm4(q0, q1, q2, q3);
+                Quad(a, b, c, d)
+            }
+        }
+    }
+
+    /// Divides this `Quad` by another, returning `None` if the result is not finite.
+    ///
+    /// This mirrors the `checked_div` methods of the primitive integer types. The result is
+    /// `None` if the divisor is zero, the quotient overflows to infinity, or the result is
+    /// `NaN`; this is always the case when the dividend is infinite or either argument is
+    /// `NaN`. A finite number divided by infinity is zero, so it does return a result.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.checked_div(Quad::E).is_some());
+    /// assert!(Quad::ONE.checked_div(Quad::ZERO).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_div(self, other: Quad) -> Option<Quad> {
+        let r = self / other;
+        if r.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -369,5 +506,23 @@ mod tests {
             b /= &Quad::E;
             near!(qd!("1.1557273497909217179100931833126962991208510231644158204997065353273"), b);
         }
+        assign_f64: {
+            let mut c = Quad::PI;
+            c /= 2.0;
+            near!(qd!("1.5707963267948966192313216916397514420985846996875529104874722961539"), c);
+        }
+        assign_double: {
+            let mut d = Quad::PI;
+            d /= Double::from(2.0);
+            near!(qd!("1.5707963267948966192313216916397514420985846996875529104874722961539"), d);
+        }
+    );
+
+    // checked_div tests
+    test_all_assert!(
+        checked_div_num: Quad::PI.checked_div(Quad::E) == Some(Quad::PI / Quad::E);
+        checked_div_nan: Quad::PI.checked_div(Quad::NAN).is_none();
+        checked_div_inf: Quad::INFINITY.checked_div(Quad::PI).is_none();
+        checked_div_overflow: Quad::ONE.checked_div(Quad::ZERO).is_none();
     );
 }
@@ -0,0 +1,772 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+// The argument at or below which the generalized exponential integrals are calculated from
+// their power series. Past this point, the continued fraction converges quickly enough.
+const SERIES_LIMIT: f64 = 1.0;
+
+// The argument past which the exponential integral Ei, and the auxiliary functions for the
+// sine and cosine integrals, are calculated from their asymptotic expansions. The smallest
+// terms in those expansions are around e^(-x), so this is far enough out that they reach
+// full precision.
+const ASYMPTOTIC_LIMIT: f64 = 160.0;
+
+// The argument at or below which the sine and cosine integrals are calculated from their
+// power series. Past this point, cancellation between the terms starts to cost precision.
+const SI_CI_SERIES_LIMIT: f64 = 2.0;
+
+impl Quad {
+    /// Calculates the exponential integral (Ei) of the `Quad`.
+    ///
+    /// This is the Cauchy principal value of the integral of eᵗ/t from -∞ to the argument.
+    /// Positive arguments are calculated from the power series, except for large ones,
+    /// which are calculated from the asymptotic expansion. Negative arguments are
+    /// calculated with Ei(x) = -E<sub>1</sub>(-x) (see [`e1`]).
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is (-∞, ∞).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::ONE.ei();
+    /// let expected = qd!("1.895117816355936755466520934331634269017060581732707591646228432");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`e1`]: #method.e1
+    pub fn ei(self) -> Quad {
+        match self.pre_ei() {
+            Some(r) => r,
+            None => {
+                if self.is_sign_negative() {
+                    -expint(-self, 1)
+                } else if self.0 > ASYMPTOTIC_LIMIT {
+                    asymptotic_ei(self)
+                } else {
+                    series_ei(self)
+                }
+            }
+        }
+    }
+
+    /// Calculates the exponential integral E<sub>1</sub> of the `Quad`.
+    ///
+    /// This is the integral of e⁻ᵗ/t from the argument to ∞. It is the same as
+    /// [`expint`] with `n` equal to 1.
+    ///
+    /// E<sub>1</sub> is complex for negative arguments, so this function returns `NaN` for
+    /// them. The related real function for negative arguments is -Ei(-x) (see [`ei`]).
+    ///
+    /// The domain of this function is [0, ∞), and the range is (0, ∞].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::ONE.e1();
+    /// let expected = qd!("0.2193839343955202736771637754601216490310472934069082075779786131");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`expint`]: #method.expint
+    /// [`ei`]: #method.ei
+    pub fn e1(self) -> Quad {
+        self.expint(1)
+    }
+
+    /// Calculates the generalized exponential integral of order `n` (E<sub>n</sub>) of the
+    /// `Quad`.
+    ///
+    /// This is the integral of e<sup>-xt</sup>/tⁿ from 1 to ∞. Arguments up to 1 are
+    /// calculated from the power series, and larger ones from the continued fraction.
+    ///
+    /// Eₙ is complex for negative arguments, so this function returns `NaN` for them.
+    ///
+    /// The domain of this function is [0, ∞), and the range is [0, ∞].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(2.5).expint(3);
+    /// let expected = qd!("0.01629536937666882704669103250778525645242311146163306652797664367");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-61));
+    /// ```
+    pub fn expint(self, n: u32) -> Quad {
+        match self.pre_expint(n) {
+            Some(r) => r,
+            None => expint(self, n),
+        }
+    }
+
+    /// Calculates the sine integral (Si) of the `Quad`.
+    ///
+    /// This is the integral of sin(t)/t from 0 to the argument. Small arguments are
+    /// calculated from the power series. Larger ones are calculated from the auxiliary
+    /// functions f and g (Si(x) = π/2 - f(x) cos x - g(x) sin x), which come from a
+    /// continued fraction or, for large arguments, from their asymptotic expansions.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is about [-1.8519, 1.8519].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(2).si();
+    /// let expected = qd!("1.605412976802694848576720148198588940848583422328499660289006307");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn si(self) -> Quad {
+        match self.pre_si() {
+            Some(r) => r,
+            None => {
+                let x = self.abs();
+                let r = if x.0 <= SI_CI_SERIES_LIMIT {
+                    series_si_ci(x).0
+                } else {
+                    let (f, g) = aux_f_g(x);
+                    let (s, c) = x.sin_cos();
+                    Quad::FRAC_PI_2 - f * c - g * s
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Calculates the cosine integral (Ci) of the `Quad`.
+    ///
+    /// This is γ + ln x plus the integral of (cos(t) - 1)/t from 0 to the argument, or
+    /// equivalently the negative of the integral of cos(t)/t from the argument to ∞. Small
+    /// arguments are calculated from the power series. Larger ones are calculated from the
+    /// auxiliary functions f and g (Ci(x) = f(x) sin x - g(x) cos x), which come from a
+    /// continued fraction or, for large arguments, from their asymptotic expansions.
+    ///
+    /// Ci is complex for negative arguments, so this function returns `NaN` for them.
+    ///
+    /// The domain of this function is [0, ∞), and the range is about (-∞, 0.4721].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(2).ci();
+    /// let expected = qd!("0.4229808287748649956985651531982558941357377563061876881101624558");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn ci(self) -> Quad {
+        match self.pre_ci() {
+            Some(r) => r,
+            None => {
+                if self.0 <= SI_CI_SERIES_LIMIT {
+                    series_si_ci(self).1
+                } else {
+                    let (f, g) = aux_f_g(self);
+                    let (s, c) = self.sin_cos();
+                    f * s - g * c
+                }
+            }
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_ei(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad::NEG_INFINITY)
+        } else if self.0 > 717.0 {
+            // The result overflows past this point
+            Some(Quad::INFINITY)
+        } else if self.0 < -746.0 {
+            // The result underflows past this point
+            Some(-Quad::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_expint(&self, n: u32) -> Option<Quad> {
+        if self.is_nan() || self.is_sign_negative() && !self.is_zero() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(if n <= 1 {
+                Quad::INFINITY
+            } else {
+                Quad::from(n - 1).recip()
+            })
+        } else if self.0 > 746.0 {
+            // The result underflows past this point
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_si(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if self.is_infinite() {
+            Some(if self.is_sign_positive() {
+                Quad::FRAC_PI_2
+            } else {
+                -Quad::FRAC_PI_2
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_ci(&self) -> Option<Quad> {
+        if self.is_nan() || self.is_sign_negative() && !self.is_zero() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad::NEG_INFINITY)
+        } else if self.is_infinite() {
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+}
+
+// Calculates Eₙ(x) for positive x.
+fn expint(x: Quad, n: u32) -> Quad {
+    if n == 0 {
+        (-x).exp() / x
+    } else if x.0 <= SERIES_LIMIT {
+        series_expint(x, n)
+    } else {
+        cf_expint(x, n)
+    }
+}
+
+// Calculates Eₙ(x) for n ≥ 1 and x ≤ 1 from the power series
+//
+//      Eₙ(x) = (-x)ⁿ⁻¹ / (n - 1)! (ψ(n) - ln x) - Σ (-x)ᵏ / ((k - n + 1) k!),
+//
+// where the sum is over all k ≥ 0 except n - 1, and ψ(n) = -γ + Σ 1/k is the digamma
+// function.
+fn series_expint(x: Quad, n: u32) -> Quad {
+    let mut sum = if n == 1 {
+        -x.ln() - c::EULER_GAMMA
+    } else {
+        Quad::from(n - 1).recip()
+    };
+    let mut fact = Quad::ONE;
+    let mut k = 1;
+    loop {
+        fact = -fact * x / Quad::from(k);
+        let term = if k + 1 == n {
+            let mut psi = -c::EULER_GAMMA;
+            for j in 1..n {
+                psi += Quad::from(j).recip();
+            }
+            fact * (psi - x.ln())
+        } else {
+            -fact / (Quad::from(k) - Quad::from(n - 1))
+        };
+        sum += term;
+        if term.abs() < Quad::EPSILON * sum.abs() {
+            break;
+        }
+        k += 1;
+    }
+    sum
+}
+
+// Calculates Eₙ(x) for n ≥ 1 and x > 1 by evaluating its continued fraction with the
+// modified Lentz algorithm.
+fn cf_expint(x: Quad, n: u32) -> Quad {
+    let tiny = Quad::from(1e-300);
+    let mut b = x + Quad::from(n);
+    let mut cc = tiny.recip();
+    let mut d = b.recip();
+    let mut h = d;
+    let mut i = 1;
+    loop {
+        let a = -Quad::from(i) * Quad::from(n - 1 + i);
+        b += Quad::from(2);
+        d = (a * d + b).recip();
+        cc = b + a / cc;
+        let delta = cc * d;
+        h *= delta;
+        if (delta - Quad::ONE).abs() < Quad::EPSILON {
+            break;
+        }
+        i += 1;
+    }
+    h * (-x).exp()
+}
+
+// Calculates Ei(x) for positive x from the power series
+//
+//      Ei(x) = γ + ln x + Σ xᵏ / (k k!).
+//
+// All of the terms are positive, so there's no cancellation. The number of terms needed
+// grows with x.
+fn series_ei(x: Quad) -> Quad {
+    let mut sum = Quad::ZERO;
+    let mut p = Quad::ONE;
+    let mut k = 1;
+    loop {
+        p = p * x / Quad::from(k);
+        let term = p / Quad::from(k);
+        sum += term;
+        if term < Quad::EPSILON * sum {
+            break;
+        }
+        k += 1;
+    }
+    c::EULER_GAMMA + x.ln() + sum
+}
+
+// Calculates Ei(x) for large positive x from the asymptotic expansion
+//
+//      Ei(x) ~ eˣ / x Σ k! / xᵏ.
+//
+// eˣ / x is calculated as e^(x - ln x) so that it doesn't overflow before the result does.
+fn asymptotic_ei(x: Quad) -> Quad {
+    let mut sum = Quad::ONE;
+    let mut term = Quad::ONE;
+    let mut k = 1;
+    loop {
+        let next = term * Quad::from(k) / x;
+        if next >= term {
+            break;
+        }
+        term = next;
+        sum += term;
+        if term < Quad::EPSILON * sum {
+            break;
+        }
+        k += 1;
+    }
+    (x - x.ln()).exp() * sum
+}
+
+// Calculates Si(x) and Ci(x) for 0 < x ≤ 2 from the power series
+//
+//      Si(x) = Σ (-1)ᵏ x²ᵏ⁺¹ / ((2k + 1) (2k + 1)!)
+//      Ci(x) = γ + ln x + Σ (-1)ᵏ x²ᵏ / (2k (2k)!),
+//
+// where the first sum starts at k = 0 and the second at k = 1.
+fn series_si_ci(x: Quad) -> (Quad, Quad) {
+    let mut si = x;
+    let mut ci = Quad::ZERO;
+    let mut p = x;
+    let mut k = 2;
+    loop {
+        // p = ±xᵏ / k!, with the sign changing at every even k
+        p = p * x / Quad::from(k);
+        if k % 2 == 0 {
+            p = -p;
+        }
+        let term = p / Quad::from(k);
+        if k % 2 == 1 {
+            si += term;
+        } else {
+            ci += term;
+        }
+        if p.abs() < Quad::EPSILON * si {
+            break;
+        }
+        k += 1;
+    }
+    (si, c::EULER_GAMMA + x.ln() + ci)
+}
+
+// Calculates the auxiliary functions f(x) and g(x) for x > 2. These are defined by
+//
+//      f(x) = Ci(x) sin x - (Si(x) - π/2) cos x
+//      g(x) = -Ci(x) cos x - (Si(x) - π/2) sin x,
+//
+// and they're the real part and the negation of the imaginary part of e^(ix) E₁(ix). That
+// is calculated with the continued fraction for E₁ (see `cf_expint`), evaluated in complex
+// arithmetic with the modified Lentz algorithm. Large arguments use the asymptotic
+// expansions instead.
+fn aux_f_g(x: Quad) -> (Quad, Quad) {
+    if x.0 > ASYMPTOTIC_LIMIT {
+        return asymptotic_f_g(x);
+    }
+
+    // This is only as small as it is because its reciprocal still has to be squared
+    let tiny = Quad::from(1e-150);
+    let two = Quad::from(2);
+    let mut b = (Quad::ONE, x);
+    let mut cc = (tiny.recip(), Quad::ZERO);
+    let mut d = c::complex_recip(b);
+    let mut h = d;
+    let mut i = 1;
+    loop {
+        let a = -Quad::from(i * i);
+        b.0 += two;
+        let ad = (a * d.0 + b.0, a * d.1 + b.1);
+        d = c::complex_recip(ad);
+        let ac = c::complex_recip(cc);
+        cc = (b.0 + a * ac.0, b.1 + a * ac.1);
+        let delta = c::complex_mul(cc, d);
+        h = c::complex_mul(h, delta);
+        if (delta.0 - Quad::ONE).abs() + delta.1.abs() < Quad::EPSILON {
+            break;
+        }
+        i += 1;
+    }
+    (-h.1, h.0)
+}
+
+// Calculates the auxiliary functions f(x) and g(x) for large x from their asymptotic
+// expansions,
+//
+//      f(x) ~ 1/x Σ (-1)ᵏ (2k)! / x²ᵏ
+//      g(x) ~ 1/x² Σ (-1)ᵏ (2k + 1)! / x²ᵏ.
+fn asymptotic_f_g(x: Quad) -> (Quad, Quad) {
+    let r = x.recip();
+    let mut f = Quad::ONE;
+    let mut g = Quad::ZERO;
+    let mut term = Quad::ONE;
+    let mut k = 1;
+    loop {
+        // term = ±k! / xᵏ, with the sign changing at every even k
+        let mut next = term * Quad::from(k) * r;
+        if k % 2 == 0 {
+            next = -next;
+        }
+        if next.abs() >= term.abs() {
+            break;
+        }
+        term = next;
+        if k % 2 == 0 {
+            f += term;
+        } else {
+            g += term;
+        }
+        if term.abs() < Quad::EPSILON * g.abs() {
+            break;
+        }
+        k += 1;
+    }
+    (f * r, g * r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ei tests
+    test_all_near!(
+        ei_neg_50:
+            qd!("-3.7832640295504590186989678540212857803028931862511140475242885945e-24"),
+            qd!(-50).ei();
+        #[cfg(not(feature = "fast_arith"))]
+        ei_neg_3:
+            qd!("-0.0130483810941970374125007458286450229484776340802236011769990311409"),
+            qd!(-3).ei();
+        ei_neg_0_5:
+            qd!("-0.559773594776160811746795939315085235226846890316353515248293219107"),
+            qd!(-0.5).ei();
+        ei_0_001:
+            qd!("-6.32953936402503821755834187525254811494402197103369089618581213908"),
+            qd!(0.001).ei();
+        ei_0_3:
+            qd!("-0.302668539265825884468136901536939423600125672238641785158523810226"),
+            qd!(0.3).ei();
+        ei_1:
+            qd!("1.89511781635593675546652093433163426901706058173270759164622843188"),
+            qd!(1).ei();
+        ei_2_5:
+            qd!("7.07376589457860071192355196245101254699632010569037584623617164522"),
+            qd!(2.5).ei();
+        ei_10:
+            qd!("2.49222897624187775913844014399852484898964710143094234538818526714e3"),
+            qd!(10).ei();
+        ei_50:
+            qd!("1.05856368971316909630615414332299871950989197517087379780790879659e20"),
+            qd!(50).ei();
+        ei_79:
+            qd!("2.61362206325045575150640392249037304356517613562908622755536540761e32"),
+            qd!(79).ei();
+        ei_81:
+            qd!("1.88292359788351823132207389814534890960825251752447237991681039724e33"),
+            qd!(81).ei();
+        ei_200:
+            qd!("3.63123523315935685239671004384642504646130746687069058343608022794e84"),
+            qd!(200).ei();
+    );
+    test_all_exact!(
+        ei_zero:
+            Quad::NEG_INFINITY,
+            Quad::ZERO.ei();
+        ei_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.ei();
+        ei_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.ei();
+        ei_nan:
+            Quad::NAN,
+            Quad::NAN.ei();
+    );
+
+    // e1 tests
+    test_all_prec!(
+        e1_1_5:
+            qd!("0.100019582406632651901909339911666978261730006140350585050567060365"),
+            qd!(1.5).e1(),
+            61;
+        e1_2_5:
+            qd!("0.0249149178702697354956280122746096359458483847114273770119345444966"),
+            qd!(2.5).e1(),
+            61;
+    );
+    test_all_near!(
+        e1_1eneg_10:
+            qd!("22.4486352651389239795709024567612952005244100642366950670830673301"),
+            qd!(1e-10).e1();
+        e1_0_001:
+            qd!("6.33153936413614933200278637638633557540146052837006033653933343866"),
+            qd!(0.001).e1();
+        e1_0_3:
+            qd!("0.905676651675846712430327522141704361425379724333941125213280483067"),
+            qd!(0.3).e1();
+        e1_1:
+            qd!("0.219383934395520273677163775460121649031047293406908207577978613074"),
+            qd!(1).e1();
+        #[cfg(not(feature = "fast_arith"))]
+        e1_10:
+            qd!("4.15696892968532427740285981027818038434629008241953313262759569713e-6"),
+            qd!(10).e1();
+        e1_50:
+            qd!("3.7832640295504590186989678540212857803028931862511140475242885945e-24"),
+            qd!(50).e1();
+    );
+    test_all_exact!(
+        e1_zero:
+            Quad::INFINITY,
+            Quad::ZERO.e1();
+        e1_neg:
+            Quad::NAN,
+            qd!(-1).e1();
+        e1_inf:
+            Quad::ZERO,
+            Quad::INFINITY.e1();
+        e1_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.e1();
+        e1_nan:
+            Quad::NAN,
+            Quad::NAN.e1();
+    );
+    test!(e1_is_expint_1: {
+        for x in [0.2, 0.9, 1.1, 4.0, 30.0] {
+            exact!(Quad::from(x).e1(), Quad::from(x).expint(1));
+        }
+    });
+
+    // expint tests
+    test_all_prec!(
+        expint_2_3:
+            qd!("0.0106419250852728307418401781641267077862666899477524120366306341835"),
+            qd!(3).expint(2),
+            61;
+        expint_3_2_5:
+            qd!("0.0162953693766688270466910325077852564524231114616330665279766436654"),
+            qd!(2.5).expint(3),
+            61;
+    );
+    test_all_near!(
+        expint_0_0_3:
+            qd!("2.46939406893905955355624593105938957394083743999668782765103355603"),
+            qd!(0.3).expint(0);
+        expint_0_2:
+            qd!("0.067667641618306345946999747486242201703815772954787940734079436327"),
+            qd!(2).expint(0);
+        expint_2_0_001:
+            qd!("0.992668960469238842336052570791269639126954441831711998715489177757"),
+            qd!(0.001).expint(2);
+        expint_2_0_5:
+            qd!("0.326643862324553017730401565333637835828494690329010198058745549181"),
+            qd!(0.5).expint(2);
+        expint_5_1:
+            qd!("0.0704542374617203983358024523377485466172621590639158144003885758276"),
+            qd!(1).expint(5);
+        #[cfg(not(feature = "fast_arith"))]
+        expint_5_10:
+            qd!("3.08972891425368627074803668493942025024031667312289264519931692771e-6"),
+            qd!(10).expint(5);
+        expint_10_0_25:
+            qd!("0.0839219939386743007631612853162868916530565663863683258358379412929"),
+            qd!(0.25).expint(10);
+        #[cfg(not(feature = "fast_arith"))]
+        expint_20_7:
+            qd!("3.47068486248843709195463440273789678544295095198848857473052907772e-5"),
+            qd!(7).expint(20);
+    );
+    test_all_exact!(
+        expint_0_zero:
+            Quad::INFINITY,
+            Quad::ZERO.expint(0);
+        expint_1_zero:
+            Quad::INFINITY,
+            Quad::ZERO.expint(1);
+        expint_3_zero:
+            qd!(0.5),
+            Quad::ZERO.expint(3);
+        expint_5_zero:
+            qd!(0.25),
+            Quad::ZERO.expint(5);
+        expint_neg:
+            Quad::NAN,
+            qd!(-2).expint(3);
+        expint_inf:
+            Quad::ZERO,
+            Quad::INFINITY.expint(3);
+        expint_large:
+            Quad::ZERO,
+            qd!(800).expint(2);
+        expint_nan:
+            Quad::NAN,
+            Quad::NAN.expint(3);
+    );
+
+    // si tests
+    test_all_near!(
+        si_0_001:
+            qd!("9.99999944444446111111082766440215489485698796076709540509523320088e-4"),
+            qd!(0.001).si();
+        si_0_5:
+            qd!("0.49310741804306668916162670757276465364133713842872113166024261403"),
+            qd!(0.5).si();
+        si_1:
+            qd!("0.946083070367183014941353313823179657812337954738111790471454773567"),
+            qd!(1).si();
+        si_2:
+            qd!("1.60541297680269484857672014819858894084858342232849966028900630656"),
+            qd!(2).si();
+        si_2_5:
+            qd!("1.7785201734438266421003119817362294787095738494777819898806032831"),
+            qd!(2.5).si();
+        si_5:
+            qd!("1.54993124494467413727440840073063901218318489396637221047796971068"),
+            qd!(5).si();
+        si_10:
+            qd!("1.65834759421887404933097187938967248063025434830957984219572269466"),
+            qd!(10).si();
+        si_neg_3:
+            qd!("-1.84865252799946825639773025111197324516451273030946569497972307244"),
+            qd!(-3).si();
+        si_30:
+            qd!("1.56675654003035111098373130900679816652349501145615463736473190466"),
+            qd!(30).si();
+        si_100:
+            qd!("1.56222546688905629335234513880450267722782498054108345638431169271"),
+            qd!(100).si();
+        si_200:
+            qd!("1.56838233933946983335878557542354653152263068258847776287652869127"),
+            qd!(200).si();
+        si_1000:
+            qd!("1.57023312196877121814796277803633444100178687988931412260451765276"),
+            qd!(1000).si();
+    );
+    test_all_exact!(
+        si_zero:
+            Quad::ZERO,
+            Quad::ZERO.si();
+        si_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.si();
+        si_inf:
+            Quad::FRAC_PI_2,
+            Quad::INFINITY.si();
+        si_neg_inf:
+            -Quad::FRAC_PI_2,
+            Quad::NEG_INFINITY.si();
+        si_nan:
+            Quad::NAN,
+            Quad::NAN.si();
+    );
+
+    // ci tests
+    test_all_prec!(
+        ci_2_5:
+            qd!("0.285871196365383495389100647925236071969232876671079254445951385386"),
+            qd!(2.5).ci(),
+            61;
+        ci_1000:
+            qd!("8.26315511090682282001773882343207231780126228026933056700919647395e-4"),
+            qd!(1000).ci(),
+            61;
+    );
+    test_all_near!(
+        ci_0_001:
+            qd!("-6.33053986408059377478102708878240480819081885111281036226932833281"),
+            qd!(0.001).ci();
+        ci_0_5:
+            qd!("-0.177784078806612901335810271070569078090519474812621968666825357595"),
+            qd!(0.5).ci();
+        ci_1:
+            qd!("0.337403922900968134662646203889150769997578032585731894801318542436"),
+            qd!(1).ci();
+        ci_2:
+            qd!("0.422980828774864995698565153198255894135737756306187688110162455766"),
+            qd!(2).ci();
+        #[cfg(not(feature = "fast_arith"))]
+        ci_5:
+            qd!("-0.190029749656643878618458900116300806496739156101856628912812216256"),
+            qd!(5).ci();
+        ci_10:
+            qd!("-0.0454564330044553726345328299526278528876469579573168869305669878879"),
+            qd!(10).ci();
+        ci_30:
+            qd!("-0.0330324172820711437792264409630037141546821298720043444792090686314"),
+            qd!(30).ci();
+        ci_100:
+            qd!("-5.14882514261049214444355390534449785032633791932324580364417667955e-3"),
+            qd!(100).ci();
+        ci_200:
+            qd!("-4.37844609302782567916569771749325524128345091344187598851110680706e-3"),
+            qd!(200).ci();
+    );
+    test_all_exact!(
+        ci_zero:
+            Quad::NEG_INFINITY,
+            Quad::ZERO.ci();
+        ci_neg:
+            Quad::NAN,
+            qd!(-1).ci();
+        ci_inf:
+            Quad::ZERO,
+            Quad::INFINITY.ci();
+        ci_nan:
+            Quad::NAN,
+            Quad::NAN.ci();
+    );
+}
@@ -117,6 +117,7 @@ from_int_impl! {
     /// # use qd::Quad;
     /// let x = i8::MIN;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "-128");
     /// ```
     i8
@@ -127,6 +128,7 @@ from_int_impl! {
     /// # use qd::Quad;
     /// let x = u8::MAX;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "255");
     /// ```
     u8
@@ -137,6 +139,7 @@ from_int_impl! {
     /// # use qd::Quad;
     /// let x = i16::MIN;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "-32768");
     /// ```
     i16
@@ -147,6 +150,7 @@ from_int_impl! {
     /// # use qd::Quad;
     /// let x = u16::MAX;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "65535");
     /// ```
     u16
@@ -157,6 +161,7 @@ from_int_impl! {
     /// # use qd::Quad;
     /// let x = i32::MIN;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "-2147483648");
     /// ```
     i32
@@ -167,6 +172,7 @@ from_int_impl! {
     /// # use qd::Quad;
     /// let x = u32::MAX;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "4294967295");
     /// ```
     u32
@@ -198,6 +204,7 @@ from_long_int_impl! {
     /// # use qd::Quad;
     /// let x = i64::MIN;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "-9223372036854775808");
     /// ```
     i64 from_i64
@@ -208,6 +215,7 @@ from_long_int_impl! {
     /// # use qd::Quad;
     /// let x = u64::MAX;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "18446744073709551615");
     /// ```
     u64 from_u64
@@ -218,6 +226,7 @@ from_long_int_impl! {
     /// # use qd::Quad;
     /// let x = i128::MIN;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "-170141183460469231731687303715884105728");
     /// ```
     i128 from_i128
@@ -228,6 +237,7 @@ from_long_int_impl! {
     /// # use qd::Quad;
     /// let x = u128::MAX;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "340282366920938463463374607431768211455");
     /// ```
     u128 from_u128
@@ -300,11 +310,13 @@ from_float_impl! {
     /// // Exactly representable in binary
     /// let x = 0.9921875f32;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "0.9921875");
     ///
     /// // Xot exactly representable in binary
     /// let x = 0.9921876f32;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "0.9921876");
     /// ```
     f32
@@ -326,11 +338,13 @@ from_float_impl! {
     /// // Exactly representable in binary
     /// let x = 0.999969482421875f64;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "0.999969482421875");
     ///
     /// // Not exactly representable in binary
     /// let x = 0.999969482421876f64;
     /// let a = Quad::from(x);
+    /// # #[cfg(feature = "display")]
     /// assert!(a.to_string() == "0.999969482421876");
     /// ```
     f64
@@ -353,7 +367,7 @@ impl From<(f64, f64)> for Quad {
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
-    /// // These are the first two components used to define Quad::PI
+    /// // These are the first two components of Quad::PI
     /// let a = Quad::from((3.141592653589793e0, 1.2246467991473532e-16));
     /// let diff = (a - Quad::PI).abs();
     /// assert!(diff < qd!(1e-30));
@@ -383,7 +397,7 @@ impl From<(f64, f64, f64)> for Quad {
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
-    /// // These are the first three components used to define Quad::PI
+    /// // These are the first three components of Quad::PI
     /// let a = Quad::from((3.141592653589793e0, 1.2246467991473532e-16, -2.9947698097183397e-33));
     /// let diff = (a - Quad::PI).abs();
     /// assert!(diff < qd!(1e-45));
@@ -411,12 +425,12 @@ impl From<(f64, f64, f64, f64)> for Quad {
     /// # Examples
     /// ```
     /// # use qd::Quad;
-    /// // These are the components used to define Quad::PI
+    /// // These are the components of Quad::PI
     /// let a = Quad::from((
     ///     3.141592653589793e0,
     ///     1.2246467991473532e-16,
     ///     -2.9947698097183397e-33,
-    ///     1.1124542208633655e-49,
+    ///     1.1124542208633653e-49,
     /// ));
     /// assert!(a == Quad::PI);
     /// ```
@@ -429,24 +443,23 @@ impl From<(f64, f64, f64, f64)> for Quad {
 }
 
 impl From<Double> for Quad {
-    /// Generates a `Quad` from a `Double`.
+    /// Generates a `Quad` from a `Double`, exactly.
     ///
-    /// The new `Quad`'s third and fourth components will be used to account for
-    /// floating-point rounding error at the end of the `Double`, but it will of course
-    /// otherwise only have the precision of the `Double` used to make it.
+    /// The `Double`'s two components become the first two components of the `Quad`, and
+    /// the other two are zero. The new `Quad` has the same value as the `Double`, and so it
+    /// only has the precision of the `Double` used to make it.
     ///
     /// # Examples
     /// ```
-    /// # use qd::{dd, qd, Quad};
-    /// let expected = Quad::from("0.9999999303082806237436760862691");
+    /// # use qd::{dd, Double, Quad};
     /// let a = (dd!(3).powi(15) - dd!(1)) / dd!(3).powi(15);
     /// let x = Quad::from(a);
-    ///
-    /// let diff = (x - expected).abs();
-    /// assert!(diff < qd!(1e-60));
+    /// assert!(x == Quad::new(a.hi(), a.lo(), 0.0, 0.0));
+    /// assert!(Quad::from(Double::PI) != Quad::PI);
     /// ```
+    #[inline]
     fn from(a: Double) -> Quad {
-        a.to_string().parse().unwrap()
+        Quad(a.hi(), a.lo(), 0.0, 0.0)
     }
 }
 
@@ -568,7 +581,7 @@ impl From<Quad> for (f64, f64, f64, f64) {
     /// assert!(a == 3.141592653589793e0);
     /// assert!(b == 1.2246467991473532e-16);
     /// assert!(c == -2.9947698097183397e-33);
-    /// assert!(d == 1.1124542208633655e-49);
+    /// assert!(d == 1.1124542208633653e-49);
     /// ```
     #[inline]
     fn from(a: Quad) -> (f64, f64, f64, f64) {
@@ -640,6 +653,7 @@ mod tests {
     });
 
     // integer tests
+    #[cfg(feature = "display")]
     test_all_eq!(
         i8_min: i8::MIN.to_string(), qd!(i8::MIN).to_string();
         u8_max: u8::MAX.to_string(), qd!(u8::MAX).to_string();
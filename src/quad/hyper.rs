@@ -152,6 +152,9 @@ impl Quad {
     ///
     /// The domain and range of this function are both (-∞, ∞).
     ///
+    /// Arguments near zero are handled with [`ln_1p`] rather than by taking the logarithm
+    /// of a number near 1, so they keep their full precision.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -161,10 +164,32 @@ impl Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
+    ///
+    /// [`ln_1p`]: #method.ln_1p
     pub fn asinh(self) -> Quad {
         match self.pre_asinh() {
             Some(r) => r,
-            None => (self + (self.sqr() + Quad::ONE).sqrt()).ln(),
+            None => {
+                // Working with |x| keeps the function odd, and it avoids the cancellation
+                // that x + √(x² + 1) suffers when x is negative
+                let a = self.abs();
+                let r = if a.0 > 1e33 {
+                    // √(x² + 1) is x to full precision here (and x² might overflow), so
+                    // this is ln 2x, with the logarithm taken in base 2 because that's
+                    // accurate over the whole range of `Quad`s
+                    (a.log2() + Quad::ONE) * Quad::LN_2
+                } else if a.0 > 2.0 {
+                    (c::mul_pwr2(a, 2.0) + (a + (a.sqr() + Quad::ONE).sqrt()).recip()).ln()
+                } else {
+                    let a2 = a.sqr();
+                    (a + a2 / (Quad::ONE + (a2 + Quad::ONE).sqrt())).ln_1p()
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
         }
     }
 
@@ -173,6 +198,9 @@ impl Quad {
     /// The domain of the function is [1, ∞) and the range is [0, ∞). Any argument outside
     /// the range will result in [`NAN`].
     ///
+    /// Arguments near 1 are handled with [`ln_1p`], so the result keeps its full precision
+    /// even though it's close to zero.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -183,11 +211,24 @@ impl Quad {
     /// assert!(diff < qd!(1e-30));
     /// ```
     ///
+    /// [`ln_1p`]: #method.ln_1p
     /// [`NAN`]: #associatedconstant.NAN
     pub fn acosh(self) -> Quad {
         match self.pre_acosh() {
             Some(r) => r,
-            None => (self + (self.sqr() - Quad::ONE).sqrt()).ln(),
+            None => {
+                if self.0 > 1e33 {
+                    // As in asinh, √(x² - 1) is x to full precision here
+                    (self.log2() + Quad::ONE) * Quad::LN_2
+                } else if self.0 > 2.0 {
+                    let r = (self + (self.sqr() - Quad::ONE).sqrt()).recip();
+                    (c::mul_pwr2(self, 2.0) - r).ln()
+                } else {
+                    // Subtracting 1 is exact in this range, and x² - 1 = t(t + 2)
+                    let t = self - Quad::ONE;
+                    (t + (c::mul_pwr2(t, 2.0) + t.sqr()).sqrt()).ln_1p()
+                }
+            }
         }
     }
 
@@ -196,6 +237,9 @@ impl Quad {
     /// The domain of the function is (-1, 1) and the range is (-∞, ∞). Any argument whose
     /// absolute value is greater than or equal to 1 will result in [`NAN`].
     ///
+    /// The logarithm is calculated with [`ln_1p`] so that arguments near zero keep their
+    /// full precision.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -206,11 +250,76 @@ impl Quad {
     /// assert!(diff < qd!(1e-60));
     /// ```
     ///
+    /// [`ln_1p`]: #method.ln_1p
     /// [`NAN`]: #associatedconstant.NAN
     pub fn atanh(self) -> Quad {
         match self.pre_atanh() {
             Some(r) => r,
-            None => c::mul_pwr2(((Quad::ONE + self) / (Quad::ONE - self)).ln(), 0.5),
+            None => {
+                // (1 + x) / (1 - x) = 1 + 2x / (1 - x), and leaving out the 1 keeps all of
+                // the digits of small arguments
+                let a = self.abs();
+                let r = c::mul_pwr2((c::mul_pwr2(a, 2.0) / (Quad::ONE - a)).ln_1p(), 0.5);
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Calculates the Gudermannian function (gd) of the `Quad`.
+    ///
+    /// The Gudermannian function relates the hyperbolic functions to the circular ones
+    /// without using complex numbers. It's calculated as 2 tan<sup>-1</sup>(tanh(x / 2)),
+    /// which is accurate for small arguments and doesn't overflow for large ones.
+    ///
+    /// The domain of the function is (-∞, ∞) and the range is (-π/2, π/2).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).gd();
+    /// let expected = qd!("0.8657694832396586242896018461918444413796791992487600996118482297");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn gd(self) -> Quad {
+        match self.pre_gd() {
+            Some(r) => r,
+            None => c::mul_pwr2(c::mul_pwr2(self, 0.5).tanh().atan(), 2.0),
+        }
+    }
+
+    /// Calculates the inverse Gudermannian function (gd<sup>-1</sup>) of the `Quad`.
+    ///
+    /// This is calculated as 2 tanh<sup>-1</sup>(tan(x / 2)), with the inverse hyperbolic
+    /// tangent evaluated through [`ln_1p`] so that small arguments don't lose precision.
+    ///
+    /// The domain of the function is [-π/2, π/2] and the range is (-∞, ∞). Any argument
+    /// whose absolute value is greater than π/2 will result in [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(0.5).agd();
+    /// let expected = qd!("0.5222381032784403301898871449364482753992899262517072834324388857");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn agd(self) -> Quad {
+        match self.pre_agd() {
+            Some(r) => r,
+            None => {
+                let t = c::mul_pwr2(self, 0.5).tan();
+                (c::mul_pwr2(t, 2.0) / (Quad::ONE - t)).ln_1p()
+            }
         }
     }
 
@@ -301,6 +410,35 @@ impl Quad {
             None
         }
     }
+
+    #[inline]
+    fn pre_gd(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if self.abs().0 > 160.0 {
+            // Past this point the result rounds to ±π/2, and tanh would overflow anyway
+            Some(if self.is_sign_negative() {
+                -Quad::FRAC_PI_2
+            } else {
+                Quad::FRAC_PI_2
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_agd(&self) -> Option<Quad> {
+        if self.is_nan() || self.abs() > Quad::FRAC_PI_2 {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -623,7 +761,7 @@ mod tests {
         asinh_small:
             qd!("0.0000099999999998333333333408333333328869047619351438492041119904402889648"),
             qd!("0.00001").asinh(),
-            59;
+            60;
         asinh_neg_small:
             qd!("-0.000099999999833333334083333328869047649429563268341902889677582744003099"),
             qd!("-0.0001").asinh(),
@@ -646,6 +784,23 @@ mod tests {
             Quad::NAN,
             Quad::NAN.asinh();
     );
+    test_all_near!(
+        asinh_tiny:
+            qd!("9.99999999999999999999999999999999999999983333333333333333333333333e-21"),
+            qd!("1e-20").asinh();
+        asinh_2_neg_30:
+            qd!("9.31322574615478515490367738842280651928947017270585123601615767555e-10"),
+            Quad::ONE.scale_b(-30).asinh();
+        asinh_3:
+            qd!("1.81844645923206682348369896356070899378625394276812161745174416723"),
+            qd!(3).asinh();
+        asinh_2_60:
+            qd!("42.2819780141566638744511594089487706527935872921071365026364784251"),
+            Quad::ONE.scale_b(60).asinh();
+        asinh_neg_2_1000:
+            qd!("-693.840327740505254726649353579634744643575634494615509374800689503"),
+            (-Quad::ONE.scale_b(1000)).asinh();
+    );
 
     // acosh tests
     test_all_near!(
@@ -703,6 +858,20 @@ mod tests {
             Quad::NAN,
             Quad::NAN.acosh();
     );
+    test_all_near!(
+        acosh_near_one:
+            qd!("1.31708901596543849324758086642015086470826592095507955176675904932e-9"),
+            Quad::new(1.0, 2f64.powi(-60), 0.0, 0.0).acosh();
+        acosh_1_25:
+            qd!("0.693147180559945309417232121458176568075500134360255254120680009493"),
+            qd!(1.25).acosh();
+        acosh_2_60:
+            qd!("42.2819780141566638744511594089487706524174290998440045000864827331"),
+            Quad::ONE.scale_b(60).acosh();
+        acosh_2_1000:
+            qd!("693.840327740505254726649353579634744643575634494615509374800689503"),
+            Quad::ONE.scale_b(1000).acosh();
+    );
 
     // atanh tests
     test_all_near!(
@@ -754,4 +923,99 @@ mod tests {
             Quad::NAN,
             Quad::NAN.atanh();
     );
+    test_all_near!(
+        atanh_tiny:
+            qd!("1.00000000000000000000000000000000000000003333333333333333333333333e-20"),
+            qd!("1e-20").atanh();
+        atanh_neg_2_neg_40:
+            qd!("-9.09494701772928237915039313272128175421335033330585734384635157483e-13"),
+            (-Quad::ONE.scale_b(-40)).atanh();
+        atanh_near_one:
+            qd!("21.1409890070783319370087392699772844394542437637809054080632401938"),
+            Quad::new(1.0, -2f64.powi(-60), 0.0, 0.0).atanh();
+        atanh_half:
+            qd!("0.549306144334054845697622618461262852323745278911374725867347166819"),
+            qd!(0.5).atanh();
+    );
+
+    // gd tests
+    test_all_near!(
+        gd_one:
+            qd!("0.865769483239658624289601846191844441379679199248760099611848229742"),
+            qd!(1).gd();
+        gd_neg_two_and_a_half:
+            qd!("-1.40699356893615375337343081917136154516563210423769234994075512891"),
+            qd!(-2.5).gd();
+        gd_small:
+            qd!("9.76562344779607904844149783145849353952794402901433691196860510047e-4"),
+            qd!(0.0009765625).gd();
+        gd_forty:
+            qd!("1.57079632679489661073461318105657345144011513397023692584599501263"),
+            qd!(40).gd();
+        gd_agd_round_trip:
+            qd!("0.75"),
+            qd!(0.75).gd().agd();
+    );
+    test_all_exact!(
+        gd_zero:
+            Quad::ZERO,
+            Quad::ZERO.gd();
+        gd_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.gd();
+        gd_large:
+            Quad::FRAC_PI_2,
+            qd!(1000).gd();
+        gd_neg_large:
+            -Quad::FRAC_PI_2,
+            qd!(-1000).gd();
+        gd_inf:
+            Quad::FRAC_PI_2,
+            Quad::INFINITY.gd();
+        gd_neg_inf:
+            -Quad::FRAC_PI_2,
+            Quad::NEG_INFINITY.gd();
+        gd_nan:
+            Quad::NAN,
+            Quad::NAN.gd();
+    );
+
+    // agd tests
+    test_all_near!(
+        agd_half:
+            qd!("0.522238103278440330189887144936448275399289926251707283432438885734"),
+            qd!(0.5).agd();
+        agd_one_and_a_half:
+            qd!("3.34067754279831100332081266903768876035632219977707950293668182396"),
+            qd!(1.5).agd();
+        agd_neg_quarter:
+            qd!("-0.25264561035786753829411005567842654758447013504231964097018810803"),
+            qd!(-0.25).agd();
+        agd_small:
+            qd!("9.31322574615478515759632261157719348152795393148362538849160228164e-10"),
+            qd!(2).powi(-30).agd();
+    );
+    test_all_exact!(
+        agd_zero:
+            Quad::ZERO,
+            Quad::ZERO.agd();
+        agd_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.agd();
+        agd_two:
+            Quad::NAN,
+            qd!(2).agd();
+        agd_neg_two:
+            Quad::NAN,
+            qd!(-2).agd();
+        agd_inf:
+            Quad::NAN,
+            Quad::INFINITY.agd();
+        agd_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.agd();
+        agd_nan:
+            Quad::NAN,
+            Quad::NAN.agd();
+    );
 }
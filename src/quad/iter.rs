@@ -3,8 +3,9 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::expansion;
 use crate::quad::Quad;
-use std::iter::{Product, Sum};
+use std::iter::{FromIterator, Product, Sum};
 
 impl Sum for Quad {
     /// Sums all of the values in an iterator of `Quad`s.
@@ -86,6 +87,74 @@ impl<'a> Product<&'a Quad> for Quad {
     }
 }
 
+impl FromIterator<f64> for Quad {
+    /// Sums all of the values in an iterator of `f64`s, exactly.
+    ///
+    /// Unlike summing the `f64`s one at a time, which rounds after every addition, this
+    /// accumulates them in an [expansion] that holds their sum exactly and only rounds
+    /// once, at the end. The result is the correctly rounded sum no matter how many `f64`s
+    /// there are or how much they cancel each other out. A partial sum that's too large
+    /// for an `f64` doesn't matter as long as the whole sum isn't, and if the whole sum is,
+    /// the result is the infinity of its sign.
+    ///
+    /// If any of the `f64`s is infinite or `NaN`, the result is the same as it would be for
+    /// the `+` operator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let readings = vec![1e100, 1.0, 1e-100, -1e100];
+    /// let sum: Quad = readings.iter().copied().collect();
+    /// assert_eq!(sum, Quad::new(1.0, 1e-100, 0.0, 0.0));
+    /// ```
+    ///
+    /// [expansion]: crate::expansion
+    fn from_iter<I>(iter: I) -> Quad
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        // Non-finite values can't go into an expansion, so they're summed separately
+        let mut special = 0.0;
+        let e = expansion::sum_f64s(iter.into_iter().filter(|&x| {
+            let finite = x.is_finite();
+            if !finite {
+                special += x;
+            }
+            finite
+        }));
+        // A sum that overflows is an infinity like any other
+        let top = e[e.len() - 1];
+        if top.is_infinite() {
+            special += top;
+        }
+        if special.is_finite() {
+            expansion::to_quad(&e)
+        } else {
+            Quad(special, 0.0, 0.0, 0.0)
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a f64> for Quad {
+    /// Sums all of the referenced values in an iterator of `f64`s, exactly.
+    ///
+    /// This is the same as collecting the `f64`s themselves.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let readings = vec![1e100, 1.0, 1e-100, -1e100];
+    /// let sum: Quad = readings.iter().collect();
+    /// assert_eq!(sum, Quad::new(1.0, 1e-100, 0.0, 0.0));
+    /// ```
+    fn from_iter<I>(iter: I) -> Quad
+    where
+        I: IntoIterator<Item = &'a f64>,
+    {
+        iter.into_iter().copied().collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +232,46 @@ mod tests {
             Quad::NAN,
             vec![qd!(1), qd!(2), Quad::NAN].iter().product::<Quad>();
     );
+
+    // from_iter tests
+    test_all_near!(
+        from_iter_tenths:
+            qd!("1.000000000000000055511151231257827021181583404541015625"),
+            vec![0.1; 10].into_iter().collect::<Quad>();
+    );
+    test_all_exact!(
+        from_iter_cancel:
+            Quad::new(1.0, 1e-100, 0.0, 0.0),
+            vec![1e100, 1.0, 1e-100, -1e100].into_iter().collect::<Quad>();
+        from_iter_refs:
+            Quad::new(1.0, 1e-100, 0.0, 0.0),
+            [1e100, 1.0, 1e-100, -1e100].iter().collect::<Quad>();
+        from_iter_empty:
+            Quad::ZERO,
+            Vec::<f64>::new().into_iter().collect::<Quad>();
+        from_iter_inf:
+            Quad::INFINITY,
+            vec![1.0, f64::INFINITY, 3.0].into_iter().collect::<Quad>();
+        from_iter_both_inf:
+            Quad::NAN,
+            vec![f64::INFINITY, 1.0, f64::NEG_INFINITY].into_iter().collect::<Quad>();
+        from_iter_nan:
+            Quad::NAN,
+            vec![1.0, f64::NAN].into_iter().collect::<Quad>();
+        from_iter_partial_overflow:
+            Quad::new(1e308, 0.0, 0.0, 0.0),
+            vec![1e308, 1e308, -1e308].into_iter().collect::<Quad>();
+        from_iter_partial_overflow_tiny:
+            Quad::new(-1e308, 1e-300, 1e-320, 0.0),
+            vec![-1e308, 1e-320, -1e308, 1e-300, 1e308].into_iter().collect::<Quad>();
+        from_iter_overflow:
+            Quad::INFINITY,
+            vec![1e308, 1e308, 1.0].into_iter().collect::<Quad>();
+        from_iter_neg_overflow:
+            Quad::NEG_INFINITY,
+            vec![-1e308, 1e300, -1e308].into_iter().collect::<Quad>();
+        from_iter_overflow_inf:
+            Quad::NAN,
+            vec![1e308, 1e308, f64::NEG_INFINITY].into_iter().collect::<Quad>();
+    );
 }
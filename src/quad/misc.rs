@@ -3,11 +3,34 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::fraction;
 use crate::common::utils as u;
+use crate::precision::AbsRel;
 use crate::quad::Quad;
+use std::convert::TryFrom;
 use std::f64;
 use std::num::FpCategory;
 
+// The smallest positive subnormal `f64`, 2^-1074.
+const MIN_SUBNORMAL: f64 = 5e-324;
+
+const HALF: Quad = Quad(0.5, 0.0, 0.0, 0.0);
+
+// 2^127, the magnitude of the smallest `i128`.
+const I128_LIMIT: f64 = 1.7014118346046923e38;
+
+// Returns 10^n, correctly rounded. Up to 10^55, 5^n fits into a `u128` and is converted
+// into a `Quad` exactly before being scaled by 2^n. Larger powers fall back to `powi`;
+// they only come into play for numbers so small that their digits are already mostly
+// lost to the exponent.
+fn pow10(n: u32) -> Quad {
+    if n <= 55 {
+        Quad::from(5u128.pow(n)).ldexp(n as i32)
+    } else {
+        Quad(10.0, 0.0, 0.0, 0.0).powi(n.into())
+    }
+}
+
 impl Quad {
     /// Calculates the absolute value of the `Quad`.
     ///
@@ -44,11 +67,11 @@ impl Quad {
         let mut c = 0.0;
         let mut d = 0.0;
 
-        if (a - self.0).abs() < f64::EPSILON {
+        if a == self.0 && a.is_finite() {
             b = self.1.floor();
-            if (b - self.1).abs() < f64::EPSILON {
+            if b == self.1 {
                 c = self.2.floor();
-                if (c - self.2).abs() < f64::EPSILON {
+                if c == self.2 {
                     d = self.3.floor();
                 }
             }
@@ -77,11 +100,11 @@ impl Quad {
         let mut c = 0.0;
         let mut d = 0.0;
 
-        if (a - self.0).abs() < f64::EPSILON {
+        if a == self.0 && a.is_finite() {
             b = self.1.ceil();
-            if (b - self.1).abs() < f64::EPSILON {
+            if b == self.1 {
                 c = self.2.ceil();
-                if (c - self.2).abs() < f64::EPSILON {
+                if c == self.2 {
                     d = self.3.ceil();
                 }
             }
@@ -106,29 +129,51 @@ impl Quad {
     /// ```
     #[inline]
     pub fn round(self) -> Quad {
-        let a = self.0.round();
-        if (a - self.0).abs() < f64::EPSILON {
-            let b = self.1.round();
-            if (b - self.1).abs() < f64::EPSILON {
-                let c = self.2.round();
-                if (c - self.2).abs() < f64::EPSILON {
-                    let d = self.3.round();
-                    let (a, b, c, d) = u::renorm4(a, b, c, d);
-                    Quad(a, b, c, d)
-                } else if ((c - self.2).abs() - 0.5).abs() < f64::EPSILON && self.3 < 0.0 {
-                    Quad(a, b, c - 1.0, 0.0)
-                } else {
-                    Quad(a, b, c, 0.0)
-                }
-            } else if ((b - self.1).abs() - 0.5).abs() < f64::EPSILON && self.2 < 0.0 {
-                Quad(a, b - 1.0, 0.0, 0.0)
+        if !self.is_finite() {
+            self
+        } else {
+            // Working from the floor and the distance above it (which is calculated
+            // exactly) takes the lower components into account, which matters when the
+            // leading component alone is exactly halfway between two integers
+            let f = self.floor();
+            let d = self - f;
+            if d > HALF || (d == HALF && self.is_sign_positive()) {
+                (f + Quad::ONE).copysign(self)
             } else {
-                Quad(a, b, 0.0, 0.0)
+                f.copysign(self)
             }
-        } else if ((a - self.0).abs() - 0.5).abs() < f64::EPSILON && self.1 < 0.0 {
-            Quad(a - 1.0, 0.0, 0.0, 0.0)
+        }
+    }
+
+    /// Returns the nearest integer value to the `Quad`. Half-way cases are rounded to the
+    /// nearest even integer, per the behavior of `f64`'s `round_ties_even` method.
+    ///
+    /// This is the rounding mode used by default in IEEE 754 arithmetic, and unlike
+    /// [`round`] it doesn't introduce a bias when rounding a large number of half-way
+    /// values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(2.5).round_ties_even() == qd!(2));
+    /// assert!(qd!(3.5).round_ties_even() == qd!(4));
+    /// assert!(qd!(-2.5).round_ties_even() == qd!(-2));
+    /// assert!(qd!(2.6).round_ties_even() == qd!(3));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    pub fn round_ties_even(self) -> Quad {
+        if !self.is_finite() {
+            self
         } else {
-            Quad(a, 0.0, 0.0, 0.0)
+            let f = self.floor();
+            let d = self - f;
+            let half_f = f.ldexp(-1);
+            if d > HALF || (d == HALF && half_f.floor() != half_f) {
+                (f + Quad::ONE).copysign(self)
+            } else {
+                f.copysign(self)
+            }
         }
     }
 
@@ -171,6 +216,236 @@ impl Quad {
         self - self.trunc()
     }
 
+    /// Rounds the `Quad` to `n` decimal places. Half-way cases are rounded away from zero,
+    /// as with [`round`].
+    ///
+    /// The `Quad` is scaled by a correctly rounded power of ten in full quad-double
+    /// precision, so this is both faster and more accurate than formatting the number to a
+    /// string and parsing it back. A negative `n` rounds to the left of the decimal point,
+    /// so `round_to_places(-2)` rounds to the nearest hundred.
+    ///
+    /// Since most decimal fractions can't be represented exactly in binary, the result is
+    /// the closest `Quad` to the rounded decimal value (to within rounding error), and
+    /// whether a half-way case is recognized as one depends on the binary value that's
+    /// actually stored. Infinities and `NaN` are returned unchanged, as are numbers so
+    /// large that scaling them overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!("12.3456");
+    /// # #[cfg(feature = "display")]
+    /// assert!(x.round_to_places(2).to_string() == "12.35");
+    /// assert!(x.round_to_places(0) == qd!(12));
+    /// assert!(x.round_to_places(-1) == qd!(10));
+    /// # #[cfg(feature = "display")]
+    /// assert!((-x).round_to_places(3).to_string() == "-12.346");
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    pub fn round_to_places(self, n: i32) -> Quad {
+        self.to_places(n, Quad::round)
+    }
+
+    /// Truncates the `Quad` to `n` decimal places, discarding any further digits.
+    ///
+    /// This works like [`round_to_places`], except that the number is rounded toward zero
+    /// rather than to the nearest value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!("12.3456");
+    /// # #[cfg(feature = "display")]
+    /// assert!(x.trunc_to_places(2).to_string() == "12.34");
+    /// assert!(x.trunc_to_places(-1) == qd!(10));
+    /// # #[cfg(feature = "display")]
+    /// assert!((-x).trunc_to_places(3).to_string() == "-12.345");
+    /// ```
+    ///
+    /// [`round_to_places`]: #method.round_to_places
+    pub fn trunc_to_places(self, n: i32) -> Quad {
+        self.to_places(n, Quad::trunc)
+    }
+
+    /// Returns the largest integer less than or equal to the `Quad`, as an `i64`.
+    ///
+    /// All of the components of the `Quad` are taken into account, so the result is exact
+    /// even when the leading component alone would round to a different integer (as it
+    /// does for numbers near or above 2<sup>53</sup>). If the result doesn't fit into an
+    /// `i64`, or if the `Quad` is infinite or `NaN`, `None` is returned instead of a
+    /// saturated value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(999_999_999_999_999_999i64) + qd!(0.5);
+    /// assert!(x.floor_i64() == Some(999_999_999_999_999_999));
+    /// // The leading component alone is off by one
+    /// assert!(x[0] as i64 == 1_000_000_000_000_000_000);
+    ///
+    /// assert!(qd!(-2.5).floor_i64() == Some(-3));
+    /// assert!(qd!(1e19).floor_i64().is_none());
+    /// assert!(Quad::NAN.floor_i64().is_none());
+    /// ```
+    #[inline]
+    pub fn floor_i64(self) -> Option<i64> {
+        self.floor_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    /// Returns the smallest integer greater than or equal to the `Quad`, as an `i64`.
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(999_999_999_999_999_998i64) + qd!(0.5);
+    /// assert!(x.ceil_i64() == Some(999_999_999_999_999_999));
+    /// assert!(qd!(-2.5).ceil_i64() == Some(-2));
+    /// assert!(qd!(-1e19).ceil_i64().is_none());
+    /// ```
+    ///
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn ceil_i64(self) -> Option<i64> {
+        self.ceil_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    /// Returns the nearest integer to the `Quad`, as an `i64`. Half-way cases are rounded
+    /// away from zero, as with [`round`].
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(999_999_999_999_999_998i64) + qd!(0.5);
+    /// assert!(x.round_i64() == Some(999_999_999_999_999_999));
+    /// assert!(qd!(-2.5).round_i64() == Some(-3));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn round_i64(self) -> Option<i64> {
+        self.round_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    /// Returns the integer part of the `Quad`, as an `i64`.
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(999_999_999_999_999_998i64) + qd!(0.5);
+    /// assert!(x.trunc_i64() == Some(999_999_999_999_999_998));
+    /// assert!(qd!(-2.5).trunc_i64() == Some(-2));
+    /// ```
+    ///
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn trunc_i64(self) -> Option<i64> {
+        self.trunc_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    /// Returns the largest integer less than or equal to the `Quad`, as an `i128`.
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!("1e30") - qd!(0.5);
+    /// assert!(x.floor_i128() == Some(999_999_999_999_999_999_999_999_999_999));
+    /// assert!(qd!(1e39).floor_i128().is_none());
+    /// ```
+    ///
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn floor_i128(self) -> Option<i128> {
+        self.floor().int_to_i128()
+    }
+
+    /// Returns the smallest integer greater than or equal to the `Quad`, as an `i128`.
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!("1e30") + qd!(0.5);
+    /// assert!(x.ceil_i128() == Some(1_000_000_000_000_000_000_000_000_000_001));
+    /// ```
+    ///
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn ceil_i128(self) -> Option<i128> {
+        self.ceil().int_to_i128()
+    }
+
+    /// Returns the nearest integer to the `Quad`, as an `i128`. Half-way cases are rounded
+    /// away from zero, as with [`round`].
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!("1e30") + qd!(0.5);
+    /// assert!(x.round_i128() == Some(1_000_000_000_000_000_000_000_000_000_001));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn round_i128(self) -> Option<i128> {
+        self.round().int_to_i128()
+    }
+
+    /// Returns the integer part of the `Quad`, as an `i128`.
+    ///
+    /// See [`floor_i64`] for details on how this differs from casting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = -qd!("1e30") - qd!(0.5);
+    /// assert!(x.trunc_i128() == Some(-1_000_000_000_000_000_000_000_000_000_000));
+    /// ```
+    ///
+    /// [`floor_i64`]: #method.floor_i64
+    #[inline]
+    pub fn trunc_i128(self) -> Option<i128> {
+        self.trunc().int_to_i128()
+    }
+
+    /// Finds the fraction closest to the `Quad` whose denominator is no larger than
+    /// `max_denominator`, as a numerator and a denominator.
+    ///
+    /// The fraction is found from the continued fraction expansion of the number, whose
+    /// convergents (and the semiconvergents between them) are its best rational
+    /// approximations. A number that's exactly a fraction with a small enough denominator
+    /// gives that fraction in lowest terms. This is useful for recovering exact ratios from
+    /// calculated values, as long as the denominator is small enough that the precision of
+    /// the `Quad` can tell it apart from its neighbors.
+    ///
+    /// This returns `None` if the number isn't finite, if `max_denominator` is zero, or if
+    /// the numerator doesn't fit into an `i128`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert_eq!(Quad::PI.to_fraction(1000), Some((355, 113)));
+    /// assert_eq!(Quad::PI.to_fraction(100_000), Some((312_689, 99_532)));
+    /// assert_eq!((qd!(-7) / qd!(12)).to_fraction(100), Some((-7, 12)));
+    /// assert_eq!(Quad::NAN.to_fraction(100), None);
+    /// ```
+    pub fn to_fraction(self, max_denominator: u64) -> Option<(i128, u64)> {
+        fraction::best_fraction(self, max_denominator, Quad::from)
+    }
+
     /// Returns a number that represents the sign of the `Quad`.
     ///
     /// * `1.0` if the number is positive, including `+0.0` and [`INFINITY`]
@@ -182,6 +457,7 @@ impl Quad {
     /// # use qd::{qd, Quad};
     /// assert!(qd!(3.5).signum() == Quad::ONE);
     /// assert!(Quad::NEG_INFINITY.signum() == Quad::NEG_ONE);
+    /// assert!(Quad::NEG_ZERO.signum() == Quad::NEG_ONE);
     /// assert!(Quad::NAN.signum().is_nan());
     /// ```
     ///
@@ -199,6 +475,53 @@ impl Quad {
         }
     }
 
+    /// Returns a `Quad` with the magnitude of this one and the sign of `sign`.
+    ///
+    /// This works the same as `f64::copysign`. Only the sign bit of `sign` is used, so a
+    /// `sign` of -0 or of a `NaN` with its sign bit set produces a negative result, and a
+    /// `NaN` `Quad` keeps its `NaN`-ness while taking on the new sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(3.5).copysign(qd!(-1)) == qd!(-3.5));
+    /// assert!(qd!(-3.5).copysign(qd!(1)) == qd!(3.5));
+    /// assert!(qd!(3.5).copysign(Quad::NEG_ZERO) == qd!(-3.5));
+    /// assert!(Quad::NAN.copysign(qd!(-1)).is_sign_negative());
+    /// ```
+    #[inline]
+    pub fn copysign(self, sign: Quad) -> Quad {
+        if self.is_sign_negative() == sign.is_sign_negative() {
+            self
+        } else {
+            -self
+        }
+    }
+
+    /// Returns the positive difference between the `Quad` and another.
+    ///
+    /// This is `self - other` if `self` is greater than `other`, and 0 otherwise. If either
+    /// argument is `NaN`, [`NAN`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(3).abs_sub(qd!(1)) == qd!(2));
+    /// assert!(qd!(1).abs_sub(qd!(3)) == Quad::ZERO);
+    /// assert!(qd!(1).abs_sub(Quad::NAN).is_nan());
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn abs_sub(self, other: Quad) -> Quad {
+        if self.is_nan() || other.is_nan() {
+            Quad::NAN
+        } else if self <= other {
+            Quad::ZERO
+        } else {
+            self - other
+        }
+    }
+
     /// Returns the floating point category of the `Quad`.
     ///
     /// The possible return values are the members of [`FpCategory`], as follows:
@@ -356,14 +679,672 @@ impl Quad {
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
-    /// assert!(!Quad::PI.is_subnormal());
-    /// assert!(qd!(1e-308).is_subnormal());
+    /// assert!(!Quad::PI.is_subnormal());
+    /// assert!(qd!(1e-308).is_subnormal());
+    /// ```
+    ///
+    /// [`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+    #[inline]
+    pub fn is_subnormal(self) -> bool {
+        self.classify() == FpCategory::Subnormal
+    }
+
+    /// Returns `true` if the `Quad` is an integer.
+    ///
+    /// Every component is taken into account, not just the first. A `Quad` too large for its
+    /// first component to have a fractional part can still have one in a later component,
+    /// and then it isn't an integer. Infinities and `NaN` are not integers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(7).is_integer());
+    /// assert!(qd!(-1e30).is_integer());
+    /// assert!(!qd!(2.5).is_integer());
+    /// assert!(!Quad::INFINITY.is_integer());
+    ///
+    /// // 2^60 + 0.5 has an integer first component but isn't an integer
+    /// let x = qd!(2).powi(60) + qd!(0.5);
+    /// assert!(x.hi().fract() == 0.0);
+    /// assert!(!x.is_integer());
+    /// ```
+    pub fn is_integer(self) -> bool {
+        self.0.is_finite() && self.iter().all(|c| c.fract() == 0.0)
+    }
+
+    /// Returns `true` if the `Quad` is an even integer.
+    ///
+    /// Zero is even, while numbers that aren't integers (including infinities and `NaN`) are
+    /// neither even nor odd. As with [`is_integer`], every component is taken into account,
+    /// so a number like 2<sup>120</sup> + 1 is correctly found to be odd even though its first component
+    /// is even.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(4).is_even());
+    /// assert!(qd!(-2).is_even());
+    /// assert!(Quad::ZERO.is_even());
+    /// assert!(!qd!(3).is_even());
+    /// assert!(!qd!(2.5).is_even());
+    /// assert!(!(qd!(2).powi(120) + qd!(1)).is_even());
+    /// ```
+    ///
+    /// [`is_integer`]: #method.is_integer
+    pub fn is_even(self) -> bool {
+        self.is_integer() && self.odd_components().is_multiple_of(2)
+    }
+
+    /// Returns `true` if the `Quad` is an odd integer.
+    ///
+    /// Numbers that aren't integers (including infinities and `NaN`) are neither even nor
+    /// odd. As with [`is_integer`], every component is taken into account, so a number like
+    /// 2<sup>120</sup> + 1 is correctly found to be odd even though its first component is even.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(3).is_odd());
+    /// assert!(qd!(-1).is_odd());
+    /// assert!(!Quad::ZERO.is_odd());
+    /// assert!(!qd!(2.5).is_odd());
+    /// assert!((qd!(2).powi(120) + qd!(1)).is_odd());
+    /// ```
+    ///
+    /// [`is_integer`]: #method.is_integer
+    pub fn is_odd(self) -> bool {
+        self.is_integer() && !self.odd_components().is_multiple_of(2)
+    }
+
+    // Counts the components of an integer `Quad` that are odd. Each one is an integer that's
+    // exactly representable as an `f64`, so the remainder is exact, and the sum is odd if
+    // and only if an odd number of its components are.
+    #[inline]
+    fn odd_components(self) -> usize {
+        self.iter().filter(|c| c % 2.0 != 0.0).count()
+    }
+
+    /// Returns `true` if the components of the `Quad` satisfy the invariant that the rest
+    /// of the library relies on: each of its components is no larger than half of a unit in
+    /// the last place of the one before it, so that no two components overlap.
+    ///
+    /// Every `Quad` produced by this library's own functions is normalized. A `Quad` built
+    /// directly out of components (for instance with `From` on a tuple, or from raw bits)
+    /// might not be, and arithmetic on such a number can give wrong results. This function
+    /// makes it possible to check, and [`canonicalize`] can repair one that isn't.
+    ///
+    /// Infinities and `NaN` are considered normalized if all of their trailing components
+    /// are zero, which is how this library represents them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::PI.is_normalized());
+    /// assert!(qd!(1).is_normalized());
+    /// assert!(Quad::INFINITY.is_normalized());
+    ///
+    /// // The trailing component overlaps the leading one
+    /// assert!(!Quad::from((1.0, 1.0, 0.0, 0.0)).is_normalized());
+    /// ```
+    ///
+    /// [`canonicalize`]: #method.canonicalize
+    pub fn is_normalized(self) -> bool {
+        if self.0.is_finite() {
+            self.1.is_finite()
+                && self.2.is_finite()
+                && self.3.is_finite()
+                && self.0 + self.1 == self.0
+                && self.1 + self.2 == self.1
+                && self.2 + self.3 == self.2
+        } else {
+            self.1 == 0.0 && self.2 == 0.0 && self.3 == 0.0
+        }
+    }
+
+    /// Renormalizes the `Quad` so that its components satisfy the invariant checked by
+    /// [`is_normalized`].
+    ///
+    /// The result represents the same value as the exact sum of the components, as
+    /// accurately as a `Quad` can. A `Quad` that is already normalized is returned
+    /// unchanged. If the leading component is infinite or `NaN`, the trailing components
+    /// are discarded; otherwise, an infinite or `NaN` trailing component, or a sum that
+    /// overflows, results in an infinite or `NaN` `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::from((1.0, 1.0, 0.0, 0.0));
+    /// assert!(!x.is_normalized());
+    ///
+    /// let y = x.canonicalize();
+    /// assert!(y.is_normalized());
+    /// assert!(y == qd!(2));
+    /// assert!(Quad::PI.canonicalize() == Quad::PI);
+    /// ```
+    ///
+    /// [`is_normalized`]: #method.is_normalized
+    pub fn canonicalize(self) -> Quad {
+        if self.is_normalized() {
+            self
+        } else if !self.0.is_finite() {
+            Quad(self.0, 0.0, 0.0, 0.0)
+        } else if !(self.1.is_finite() && self.2.is_finite() && self.3.is_finite()) {
+            Quad(self.0 + self.1 + self.2 + self.3, 0.0, 0.0, 0.0)
+        } else {
+            let (a, b, c, d) = u::distill4(&mut [self.3, self.2, self.1, self.0]);
+            if a.is_finite() {
+                Quad(a, b, c, d)
+            } else {
+                Quad(self.0 + self.1 + self.2 + self.3, 0.0, 0.0, 0.0)
+            }
+        }
+    }
+
+    /// Returns the approximate number of significant decimal digits that a `Quad` can
+    /// represent. This is the same as [`DIGITS`].
+    ///
+    /// This is useful in code that works with both `Double` and `Quad`, where a tolerance
+    /// can be chosen from the precision of the type instead of being hard-coded.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::precision_digits() == 62);
+    /// let tol = qd!(10).powi(-(Quad::precision_digits() as i64) + 1);
+    /// assert!((qd!(2).sqrt().powi(2) - qd!(2)).abs() < tol);
+    /// ```
+    ///
+    /// [`DIGITS`]: #associatedconstant.DIGITS
+    pub const fn precision_digits() -> u32 {
+        Quad::DIGITS
+    }
+
+    /// Returns the number of significant bits that the `Quad` actually has.
+    ///
+    /// This is [`MANTISSA_DIGITS`] for every value with an absolute value of at least
+    /// [`MIN_FULL_PRECISION`]. Smaller numbers can only be represented with trailing
+    /// components that are subnormal, so they have fewer bits, down to 1 for the smallest
+    /// positive subnormal `f64`. Zero, infinities, and `NaN` have no significant bits and
+    /// return 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1).effective_precision() == Quad::MANTISSA_DIGITS);
+    /// let min = Quad::MIN_FULL_PRECISION;
+    /// assert!(min.effective_precision() == Quad::MANTISSA_DIGITS);
+    /// assert!(Quad::ONE.scale_b(-1042).effective_precision() == 33);
+    /// ```
+    ///
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    /// [`MIN_FULL_PRECISION`]: #associatedconstant.MIN_FULL_PRECISION
+    pub fn effective_precision(self) -> u32 {
+        if self.is_zero() || !self.is_finite() {
+            0
+        } else {
+            let a = self.0.abs();
+            let bits = if a < f64::MIN_POSITIVE {
+                // Subnormal, so the leading bit can't be found from the exponent
+                64 - a.to_bits().leading_zeros()
+            } else {
+                // There are 1075 bit positions from 2^0 down to the smallest subnormal
+                (u::exponent(a) + 1075) as u32
+            };
+            bits.min(Quad::MANTISSA_DIGITS)
+        }
+    }
+
+    /// Returns the unit in the last place (ULP) of the `Quad`.
+    ///
+    /// This is the spacing of the representable `Quad`s at this one: the ULP of its last
+    /// component as an `f64`, which is the size of the step taken by [`next_up`] and
+    /// [`next_down`]. Because the last component of a `Quad` can be much smaller than an
+    /// ulp of the one before it, or zero, this varies a great deal between numbers of about
+    /// the same size. Zero and any `Quad` with a zero last component have the smallest ULP,
+    /// 2<sup>-1074</sup>. For a tolerance that's proportional to the size of the number,
+    /// see [`precision_ulp`].
+    ///
+    /// The ULP is always positive. The ULP of an infinity is [`INFINITY`], and the ULP of
+    /// `NaN` is [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::ONE.ulp() == Quad::new(5e-324, 0.0, 0.0, 0.0));
+    /// assert!(Quad::PI.ulp() < Quad::PI.precision_ulp());
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`next_up`]: #method.next_up
+    /// [`next_down`]: #method.next_down
+    /// [`precision_ulp`]: #method.precision_ulp
+    pub fn ulp(self) -> Quad {
+        if self.is_nan() {
+            Quad::NAN
+        } else if self.is_infinite() {
+            Quad::INFINITY
+        } else {
+            Quad(u::ulp(self.3), 0.0, 0.0, 0.0)
+        }
+    }
+
+    /// Returns the unit in the last place (ULP) of a `Quad` with the full 212 bits of
+    /// precision.
+    ///
+    /// This is 2<sup>-209</sup>, roughly the size of [`EPSILON`], scaled to the binary
+    /// exponent of the `Quad`'s leading component. Unlike [`ulp`], it depends only on the
+    /// size of the number and not on how it happens to be split between its components,
+    /// which makes it the natural unit for error bounds and for tolerances in iterative
+    /// algorithms. For numbers so small that this would underflow, the result is instead
+    /// the smallest positive subnormal `f64`.
+    ///
+    /// The result is always positive. That of an infinity is [`INFINITY`], and that of
+    /// `NaN` is [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::ONE.precision_ulp() == Quad::ONE.ldexp(-209));
+    /// assert!(qd!(-8).precision_ulp() == Quad::ONE.precision_ulp() * qd!(8));
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`ulp`]: #method.ulp
+    pub fn precision_ulp(self) -> Quad {
+        if self.is_nan() {
+            Quad::NAN
+        } else if self.is_infinite() {
+            Quad::INFINITY
+        } else {
+            // 2^(e - 209), in two steps because `powi` can't produce subnormal results
+            let ulp = 2f64.powi(u::exponent(self.0)) * 2f64.powi(-209);
+            Quad(ulp.max(MIN_SUBNORMAL), 0.0, 0.0, 0.0)
+        }
+    }
+
+    /// Returns the next representable `Quad` greater than this one.
+    ///
+    /// This steps the last component up to the next `f64` and then renormalizes, both of
+    /// which are exact, so the result is greater by exactly one [`ulp`] unless the last
+    /// component is a negative power of two. Zero steps up to the smallest positive
+    /// subnormal number, [`NEG_INFINITY`] steps up to [`MIN`], and [`MAX`] steps up to
+    /// [`INFINITY`]. [`INFINITY`] and `NaN` are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::PI.next_up();
+    /// assert!(x > Quad::PI);
+    /// assert!(x - Quad::PI == Quad::PI.ulp());
+    /// assert!(Quad::MAX.next_up() == Quad::INFINITY);
+    /// ```
+    ///
+    /// [`ulp`]: #method.ulp
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    pub fn next_up(self) -> Quad {
+        if self.is_nan() || self == Quad::INFINITY {
+            self
+        } else if self == Quad::NEG_INFINITY {
+            Quad::MIN
+        } else {
+            let (a, b, c, d) = u::renorm4(self.0, self.1, self.2, u::next_up(self.3));
+            if a.is_finite() {
+                Quad(a, b, c, d)
+            } else {
+                Quad::INFINITY
+            }
+        }
+    }
+
+    /// Returns the next representable `Quad` less than this one.
+    ///
+    /// This is the mirror image of [`next_up`]: `x.next_down()` is always equal to
+    /// `-(-x).next_up()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::PI.next_down();
+    /// assert!(x < Quad::PI);
+    /// assert!(Quad::PI - x == Quad::PI.ulp());
+    /// assert!(Quad::MIN.next_down() == Quad::NEG_INFINITY);
+    /// ```
+    ///
+    /// [`next_up`]: #method.next_up
+    #[inline]
+    pub fn next_down(self) -> Quad {
+        -(-self).next_up()
+    }
+
+    /// Returns the next representable `Quad` after this one in the direction of `target`.
+    ///
+    /// If the two are equal, `target` is returned, and if either of them is `NaN`, [`NAN`]
+    /// is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.next_after(Quad::E) == Quad::PI.next_down());
+    /// assert!(Quad::PI.next_after(Quad::INFINITY) == Quad::PI.next_up());
+    /// assert!(Quad::PI.next_after(Quad::PI) == Quad::PI);
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn next_after(self, target: Quad) -> Quad {
+        if self.is_nan() || target.is_nan() {
+            Quad::NAN
+        } else if self == target {
+            target
+        } else if self < target {
+            self.next_up()
+        } else {
+            self.next_down()
+        }
+    }
+
+    /// Returns `true` if the `Quad` and another are equal to within an absolute or a
+    /// relative tolerance.
+    ///
+    /// The numbers are approximately equal if the difference between them is no more than
+    /// `tol.abs`, or no more than `tol.rel` times the larger of their magnitudes. Numbers
+    /// that are equal according to `==` are always approximately equal, which includes
+    /// infinities of the same sign, but any other infinity and any `NaN` is never
+    /// approximately equal to anything. See [`AbsRel`] for more about the tolerances.
+    ///
+    /// This is the comparison to use for checking whether an iteration has converged or
+    /// whether a computed result is right, where `==` would fail because of rounding.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad, AbsRel};
+    /// let x = Quad::ONE / qd!(3) * qd!(3);
+    /// let tol = AbsRel { abs: 0.0, rel: 1e-25 };
+    /// assert!(x.approx_eq(Quad::ONE, tol));
+    /// assert!(!qd!(1e-20).approx_eq(Quad::ZERO, tol));
+    /// assert!(qd!(1e-20).approx_eq(Quad::ZERO, AbsRel { abs: 1e-15, rel: 0.0 }));
+    /// assert!(!Quad::NAN.approx_eq(Quad::NAN, tol));
+    /// ```
+    ///
+    /// [`AbsRel`]: crate::AbsRel
+    pub fn approx_eq(self, other: Quad, tol: AbsRel) -> bool {
+        if self == other {
+            true
+        } else if !self.is_finite() || !other.is_finite() {
+            false
+        } else {
+            let diff = (self - other).abs();
+            let scale = self.abs().max(other.abs());
+            diff <= tol.abs || diff <= scale * Quad(tol.rel, 0.0, 0.0, 0.0)
+        }
+    }
+
+    /// Returns `true` if the `Quad` and another are no more than `max_ulps` ULPs apart.
+    ///
+    /// The ULP is the [`precision_ulp`] of the larger of the two numbers in magnitude,
+    /// 2<sup>-209</sup> relative to its leading component, rather than that of the leading
+    /// component alone. That makes this a much tighter comparison than counting the ULPs
+    /// between the leading `f64`s would be. Zero ULPs means the numbers have to be equal.
+    /// Infinities and `NaN`s compare as they do for [`approx_eq`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::PI;
+    /// let y = x + x.precision_ulp() * qd!(2);
+    /// assert!(x.ulps_eq(y, 2));
+    /// assert!(!x.ulps_eq(y, 1));
+    /// assert!(x.ulps_eq(x, 0));
+    /// ```
+    ///
+    /// [`precision_ulp`]: #method.precision_ulp
+    /// [`approx_eq`]: #method.approx_eq
+    pub fn ulps_eq(self, other: Quad, max_ulps: u32) -> bool {
+        if self == other {
+            true
+        } else if !self.is_finite() || !other.is_finite() {
+            false
+        } else {
+            let ulp = self.abs().max(other.abs()).precision_ulp();
+            (self - other).abs() <= ulp * Quad(max_ulps as f64, 0.0, 0.0, 0.0)
+        }
+    }
+
+    /// Returns the lesser of the `Quad` and another.
+    ///
+    /// This works the same as `f64::min`: if one of the arguments is `NaN`, the other is
+    /// returned, and [`NAN`] is only returned if both are `NaN`. If the arguments are zeros
+    /// of opposite sign, either may be returned. See [`minimum`] for a version that
+    /// propagates `NaN` and orders -0 below +0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.min(Quad::E) == Quad::E);
+    /// assert!(Quad::PI.min(Quad::NAN) == Quad::PI);
+    /// assert!(Quad::NAN.min(Quad::PI) == Quad::PI);
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`minimum`]: #method.minimum
+    pub fn min(self, other: Quad) -> Quad {
+        if self.is_nan() || other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the greater of the `Quad` and another.
+    ///
+    /// This works the same as `f64::max`: if one of the arguments is `NaN`, the other is
+    /// returned, and [`NAN`] is only returned if both are `NaN`. If the arguments are zeros
+    /// of opposite sign, either may be returned. See [`maximum`] for a version that
+    /// propagates `NaN` and orders -0 below +0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.max(Quad::E) == Quad::PI);
+    /// assert!(Quad::E.max(Quad::NAN) == Quad::E);
+    /// assert!(Quad::NAN.max(Quad::E) == Quad::E);
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`maximum`]: #method.maximum
+    pub fn max(self, other: Quad) -> Quad {
+        if self.is_nan() || other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the lesser of the `Quad` and another, following IEEE 754-2019 `minimum`.
+    ///
+    /// If either argument is `NaN`, [`NAN`] is returned. Negative zero is considered to be
+    /// less than positive zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.minimum(Quad::E) == Quad::E);
+    /// assert!(Quad::PI.minimum(Quad::NAN).is_nan());
+    /// assert!(Quad::ZERO.minimum(Quad::NEG_ZERO).is_sign_negative());
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn minimum(self, other: Quad) -> Quad {
+        if self.is_nan() || other.is_nan() {
+            Quad::NAN
+        } else if self < other || (self == other && self.is_sign_negative()) {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the greater of the `Quad` and another, following IEEE 754-2019 `maximum`.
+    ///
+    /// If either argument is `NaN`, [`NAN`] is returned. Positive zero is considered to be
+    /// greater than negative zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.maximum(Quad::E) == Quad::PI);
+    /// assert!(Quad::NAN.maximum(Quad::PI).is_nan());
+    /// assert!(Quad::NEG_ZERO.maximum(Quad::ZERO).is_sign_positive());
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn maximum(self, other: Quad) -> Quad {
+        if self.is_nan() || other.is_nan() {
+            Quad::NAN
+        } else if self > other || (self == other && self.is_sign_positive()) {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the lesser of the `Quad` and another, ignoring `NaN`.
+    ///
+    /// This follows the `minimumNumber` operation of IEEE 754-2019. It's like [`minimum`]
+    /// except that `NaN` is treated as missing data: if one of the arguments is `NaN`, the
+    /// other is returned. Negative zero is considered to be less than positive zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.minimum_number(Quad::NAN) == Quad::PI);
+    /// assert!(Quad::ZERO.minimum_number(Quad::NEG_ZERO).is_sign_negative());
+    /// ```
+    ///
+    /// [`minimum`]: #method.minimum
+    pub fn minimum_number(self, other: Quad) -> Quad {
+        if self.is_nan() {
+            other
+        } else if other.is_nan() {
+            self
+        } else {
+            self.minimum(other)
+        }
+    }
+
+    /// Returns the greater of the `Quad` and another, ignoring `NaN`.
+    ///
+    /// This follows the `maximumNumber` operation of IEEE 754-2019. It's like [`maximum`]
+    /// except that `NaN` is treated as missing data: if one of the arguments is `NaN`, the
+    /// other is returned. Positive zero is considered to be greater than negative zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::NAN.maximum_number(Quad::PI) == Quad::PI);
+    /// assert!(Quad::NEG_ZERO.maximum_number(Quad::ZERO).is_sign_positive());
+    /// ```
+    ///
+    /// [`maximum`]: #method.maximum
+    pub fn maximum_number(self, other: Quad) -> Quad {
+        if self.is_nan() {
+            other
+        } else if other.is_nan() {
+            self
+        } else {
+            self.maximum(other)
+        }
+    }
+
+    /// Restricts the `Quad` to a certain interval.
+    ///
+    /// Returns `max` if the `Quad` is greater than `max`, and `min` if it's less than
+    /// `min`. Otherwise it returns the `Quad` itself. If the `Quad` is `NaN`, [`NAN`] is
+    /// returned.
+    ///
+    /// # Panics
+    /// This works the same as `f64::clamp`, so it panics if `min` is greater than `max` or
+    /// if either of them is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(-3).clamp(qd!(-2), qd!(1)) == qd!(-2));
+    /// assert!(qd!(0).clamp(qd!(-2), qd!(1)) == qd!(0));
+    /// assert!(qd!(2).clamp(qd!(-2), qd!(1)) == qd!(1));
+    /// assert!(Quad::NAN.clamp(qd!(-2), qd!(1)).is_nan());
     /// ```
     ///
-    /// [`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
-    #[inline]
-    pub fn is_subnormal(self) -> bool {
-        self.classify() == FpCategory::Subnormal
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn clamp(self, min: Quad, max: Quad) -> Quad {
+        assert!(
+            min <= max,
+            "min > max, or either was NaN. min = {:?}, max = {:?}",
+            min,
+            max
+        );
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    // Applies an integer rounding function to the `Quad` after shifting its decimal point
+    // `n` places, then shifts it back. Scaling by a negative power is done by dividing by
+    // the positive power instead, since powers of ten with negative exponents can't be
+    // represented exactly. The sign is restored at the end because multiplying or dividing
+    // a zero doesn't preserve it, and a negative number that rounds to zero should produce
+    // -0.
+    fn to_places(self, n: i32, f: fn(Quad) -> Quad) -> Quad {
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        let p = pow10(n.unsigned_abs());
+        let r = if n >= 0 {
+            let y = self * p;
+            if y.is_finite() {
+                f(y) / p
+            } else {
+                self
+            }
+        } else if p.is_finite() {
+            f(self / p) * p
+        } else {
+            Quad::ZERO
+        };
+        r.copysign(self)
+    }
+
+    // Converts an integer-valued `Quad` into an `i128`, or `None` if it doesn't fit.
+    //
+    // Every component of an integer-valued `Quad` is itself an integer, and since the sum
+    // is known to be in range, adding the components with wrapping arithmetic gives the
+    // exact result. This even works when the leading component alone is out of range, as
+    // it is for 2^127 - 1 (which is stored with a leading component of 2^127).
+    fn int_to_i128(self) -> Option<i128> {
+        let limit = Quad(I128_LIMIT, 0.0, 0.0, 0.0);
+        if self >= -limit && self < limit {
+            let hi = if self.0 == I128_LIMIT {
+                i128::MIN
+            } else {
+                self.0 as i128
+            };
+            Some(
+                hi.wrapping_add(self.1 as i128)
+                    .wrapping_add(self.2 as i128)
+                    .wrapping_add(self.3 as i128),
+            )
+        } else {
+            None
+        }
     }
 }
 
@@ -414,6 +1395,9 @@ mod tests {
         floor_int:
             qd!(2),
             qd!(2).floor();
+        floor_small_parts:
+            qd!(2),
+            Quad(2.0, 6.4e-47, -2.2e-63, 0.0).floor();
 
         floor_zero:
             Quad::ZERO,
@@ -449,6 +1433,9 @@ mod tests {
         ceil_int:
             qd!(2),
             qd!(2).ceil();
+        ceil_small_parts:
+            qd!(2),
+            Quad(2.0, -6.4e-47, 2.2e-63, 0.0).ceil();
 
         ceil_zero:
             Quad::ZERO,
@@ -507,6 +1494,210 @@ mod tests {
             Quad::NAN,
             Quad::NAN.round();
     );
+    test_all_exact!(
+        round_low_tie_up:
+            qd!(3),
+            Quad(2.5, 1e-20, 0.0, 0.0).round();
+        round_low_tie_down:
+            qd!(2),
+            Quad(2.5, -1e-20, 0.0, 0.0).round();
+        round_neg_low_tie_up:
+            qd!(-2),
+            Quad(-2.5, 1e-20, 0.0, 0.0).round();
+        round_neg_low_tie_down:
+            qd!(-3),
+            Quad(-2.5, -1e-20, 0.0, 0.0).round();
+        round_large_low:
+            Quad(1e20, 1.0, 0.0, 0.0),
+            Quad(1e20, 0.5, 0.0, 0.0).round();
+        round_large_neg_low:
+            Quad(1e20, 0.0, 0.0, 0.0),
+            Quad(1e20, -0.5, 0.0, 0.0).round();
+        round_large_neg_low_down:
+            Quad(1e20, -1.0, 0.0, 0.0),
+            Quad(1e20, -0.75, 0.0, 0.0).round();
+    );
+    test_all_assert!(
+        round_small_neg_sign:
+            qd!(-0.3).round().is_sign_negative() && qd!(-0.3).round().is_zero();
+    );
+
+    // round_ties_even tests
+    test_all_exact!(
+        round_ties_even_pi:
+            qd!(3),
+            Quad::PI.round_ties_even();
+        round_ties_even_neg_pi:
+            qd!(-3),
+            (-Quad::PI).round_ties_even();
+        round_ties_even_half_even:
+            qd!(2),
+            qd!(2.5).round_ties_even();
+        round_ties_even_half_odd:
+            qd!(4),
+            qd!(3.5).round_ties_even();
+        round_ties_even_neg_half_even:
+            qd!(-2),
+            qd!(-2.5).round_ties_even();
+        round_ties_even_neg_half_odd:
+            qd!(-4),
+            qd!(-3.5).round_ties_even();
+        round_ties_even_half:
+            Quad::ZERO,
+            qd!(0.5).round_ties_even();
+        round_ties_even_one_half:
+            qd!(2),
+            qd!(1.5).round_ties_even();
+        round_ties_even_low_up:
+            qd!(3),
+            Quad(2.5, 1e-20, 0.0, 0.0).round_ties_even();
+        round_ties_even_low_down:
+            qd!(3),
+            Quad(3.5, -1e-20, 0.0, 0.0).round_ties_even();
+        round_ties_even_large_low:
+            Quad(1e20, 2.0, 0.0, 0.0),
+            Quad(1e20, 2.5, 0.0, 0.0).round_ties_even();
+        round_ties_even_int:
+            qd!(7),
+            qd!(7).round_ties_even();
+        round_ties_even_zero:
+            Quad::ZERO,
+            Quad::ZERO.round_ties_even();
+        round_ties_even_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.round_ties_even();
+        round_ties_even_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.round_ties_even();
+        round_ties_even_nan:
+            Quad::NAN,
+            Quad::NAN.round_ties_even();
+    );
+    test_all_assert!(
+        round_ties_even_neg_zero:
+            Quad::NEG_ZERO.round_ties_even().is_sign_negative();
+        round_ties_even_neg_half:
+            qd!(-0.5).round_ties_even().is_sign_negative();
+    );
+
+    // integer rounding tests
+    test_all_eq!(
+        floor_i64_pi:
+            Quad::PI.floor_i64(),
+            Some(3);
+        floor_i64_neg_pi:
+            (-Quad::PI).floor_i64(),
+            Some(-4);
+        floor_i64_low:
+            Quad(1e18, -0.5, 0.0, 0.0).floor_i64(),
+            Some(999_999_999_999_999_999);
+        floor_i64_max:
+            Quad::from(i64::MAX).floor_i64(),
+            Some(i64::MAX);
+        floor_i64_min:
+            Quad::from(i64::MIN).floor_i64(),
+            Some(i64::MIN);
+        floor_i64_over:
+            (Quad::from(i64::MAX) + Quad::ONE).floor_i64(),
+            None;
+        floor_i64_under:
+            (Quad::from(i64::MIN) - qd!(0.5)).floor_i64(),
+            None;
+        floor_i64_inf:
+            Quad::INFINITY.floor_i64(),
+            None;
+        floor_i64_nan:
+            Quad::NAN.floor_i64(),
+            None;
+
+        ceil_i64_pi:
+            Quad::PI.ceil_i64(),
+            Some(4);
+        ceil_i64_neg_pi:
+            (-Quad::PI).ceil_i64(),
+            Some(-3);
+        ceil_i64_low:
+            Quad(1e18, 0.5, 0.0, 0.0).ceil_i64(),
+            Some(1_000_000_000_000_000_001);
+        ceil_i64_over:
+            (Quad::from(i64::MAX) + qd!(0.5)).ceil_i64(),
+            None;
+        ceil_i64_neg_inf:
+            Quad::NEG_INFINITY.ceil_i64(),
+            None;
+
+        round_i64_pi:
+            Quad::PI.round_i64(),
+            Some(3);
+        round_i64_half:
+            qd!(-2.5).round_i64(),
+            Some(-3);
+        round_i64_low:
+            Quad(1e18, -0.5, 0.0, 0.0).round_i64(),
+            Some(1_000_000_000_000_000_000);
+        round_i64_low_down:
+            Quad(1e18, -0.75, 0.0, 0.0).round_i64(),
+            Some(999_999_999_999_999_999);
+        round_i64_nan:
+            Quad::NAN.round_i64(),
+            None;
+
+        trunc_i64_pi:
+            Quad::PI.trunc_i64(),
+            Some(3);
+        trunc_i64_neg_pi:
+            (-Quad::PI).trunc_i64(),
+            Some(-3);
+        trunc_i64_low:
+            Quad(1e18, -0.5, 0.0, 0.0).trunc_i64(),
+            Some(999_999_999_999_999_999);
+        trunc_i64_neg_low:
+            Quad(-1e18, 0.5, 0.0, 0.0).trunc_i64(),
+            Some(-999_999_999_999_999_999);
+
+        floor_i128_pi:
+            Quad::PI.floor_i128(),
+            Some(3);
+        floor_i128_low:
+            Quad(1e30, -0.5, 0.0, 0.0).floor_i128(),
+            Some(1_000_000_000_000_000_019_884_624_838_655);
+        floor_i128_max:
+            Quad(1.7014118346046923e38, -1.0, 0.0, 0.0).floor_i128(),
+            Some(i128::MAX);
+        floor_i128_min:
+            Quad(-1.7014118346046923e38, 0.0, 0.0, 0.0).floor_i128(),
+            Some(i128::MIN);
+        floor_i128_over:
+            Quad(1.7014118346046923e38, 0.0, 0.0, 0.0).floor_i128(),
+            None;
+        floor_i128_under:
+            Quad(-1.7014118346046923e38, -1.0, 0.0, 0.0).floor_i128(),
+            None;
+        floor_i128_nan:
+            Quad::NAN.floor_i128(),
+            None;
+
+        ceil_i128_neg_pi:
+            (-Quad::PI).ceil_i128(),
+            Some(-3);
+        ceil_i128_max:
+            Quad(1.7014118346046923e38, -1.5, 0.0, 0.0).ceil_i128(),
+            Some(i128::MAX);
+
+        round_i128_neg_half:
+            qd!(-0.5).round_i128(),
+            Some(-1);
+        round_i128_low:
+            Quad(-1e30, -0.5, 0.0, 0.0).round_i128(),
+            Some(-1_000_000_000_000_000_019_884_624_838_657);
+
+        trunc_i128_neg_pi:
+            (-Quad::PI).trunc_i128(),
+            Some(-3);
+        trunc_i128_inf:
+            Quad::INFINITY.trunc_i128(),
+            None;
+    );
 
     // trunc tests
     test_all_exact!(
@@ -592,6 +1783,136 @@ mod tests {
             Quad::NAN.fract();
     );
 
+    // round_to_places tests
+    test_all_near!(
+        round_to_places_pi_2:
+            qd!("3.14"),
+            Quad::PI.round_to_places(2);
+        round_to_places_pi_4:
+            qd!("3.1416"),
+            Quad::PI.round_to_places(4);
+        round_to_places_pi_long:
+            qd!("3.14159265358979323846264338327950288419716939937511"),
+            Quad::PI.round_to_places(50);
+        round_to_places_neg_e_5:
+            qd!("-2.71828"),
+            (-Quad::E).round_to_places(5);
+        round_to_places_small:
+            qd!("0.000123"),
+            qd!("0.00012345").round_to_places(6);
+        round_to_places_beyond_precision:
+            Quad::PI,
+            Quad::PI.round_to_places(70);
+    );
+    test_all_exact!(
+        round_to_places_zero_places:
+            qd!(3),
+            Quad::PI.round_to_places(0);
+        round_to_places_tie:
+            qd!(0.13),
+            qd!(0.125).round_to_places(2);
+        round_to_places_neg_tie:
+            qd!(-0.13),
+            qd!(-0.125).round_to_places(2);
+        round_to_places_neg_places:
+            qd!(1200),
+            qd!(1234.5).round_to_places(-2);
+        round_to_places_neg_places_up:
+            qd!(-1300),
+            qd!(-1250).round_to_places(-2);
+        round_to_places_neg_places_zero:
+            Quad::ZERO,
+            qd!(49).round_to_places(-2);
+        round_to_places_large_neg_places:
+            Quad::ZERO,
+            qd!(1e300).round_to_places(-400);
+        round_to_places_min_places:
+            Quad::ZERO,
+            Quad::MAX.round_to_places(i32::MIN);
+        round_to_places_overflow:
+            qd!(1e300),
+            qd!(1e300).round_to_places(20);
+        round_to_places_max_places:
+            Quad::PI,
+            Quad::PI.round_to_places(i32::MAX);
+
+        round_to_places_zero:
+            Quad::ZERO,
+            Quad::ZERO.round_to_places(2);
+        round_to_places_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.round_to_places(2);
+        round_to_places_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.round_to_places(2);
+        round_to_places_nan:
+            Quad::NAN,
+            Quad::NAN.round_to_places(2);
+    );
+    test_all_assert!(
+        round_to_places_neg_zero:
+            Quad::NEG_ZERO.round_to_places(2).is_sign_negative();
+        round_to_places_neg_to_zero:
+            qd!(-0.001).round_to_places(2).is_sign_negative();
+        round_to_places_neg_places_to_zero:
+            qd!(-49).round_to_places(-2).is_sign_negative();
+    );
+    #[cfg(feature = "display")]
+    test_all_assert!(
+        round_to_places_string:
+            qd!(2).sqrt().round_to_places(10).to_string() == "1.4142135624";
+    );
+
+    // trunc_to_places tests
+    test_all_near!(
+        trunc_to_places_pi_2:
+            qd!("3.14"),
+            Quad::PI.trunc_to_places(2);
+        trunc_to_places_pi_4:
+            qd!("3.1415"),
+            Quad::PI.trunc_to_places(4);
+        trunc_to_places_neg_e_5:
+            qd!("-2.71828"),
+            (-Quad::E).trunc_to_places(5);
+        trunc_to_places_neg_e_3:
+            qd!("-2.718"),
+            (-Quad::E).trunc_to_places(3);
+    );
+    test_all_exact!(
+        trunc_to_places_zero_places:
+            qd!(2),
+            Quad::E.trunc_to_places(0);
+        trunc_to_places_tie:
+            qd!(0.12),
+            qd!(0.125).trunc_to_places(2);
+        trunc_to_places_neg_places:
+            qd!(-1900),
+            qd!(-1999.9).trunc_to_places(-2);
+        trunc_to_places_large_neg_places:
+            Quad::ZERO,
+            qd!(1e300).trunc_to_places(-400);
+        trunc_to_places_overflow:
+            qd!(1e300),
+            qd!(1e300).trunc_to_places(20);
+
+        trunc_to_places_zero:
+            Quad::ZERO,
+            Quad::ZERO.trunc_to_places(2);
+        trunc_to_places_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.trunc_to_places(2);
+        trunc_to_places_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.trunc_to_places(2);
+        trunc_to_places_nan:
+            Quad::NAN,
+            Quad::NAN.trunc_to_places(2);
+    );
+    test_all_assert!(
+        trunc_to_places_neg_to_zero:
+            qd!(-0.009).trunc_to_places(2).is_sign_negative();
+    );
+
     // signum tests
     test_all_exact!(
         signum_pi:
@@ -633,6 +1954,71 @@ mod tests {
             Quad::NAN.signum();
     );
 
+    // copysign tests
+    test_all_exact!(
+        copysign_pos_pos:
+            Quad::PI,
+            Quad::PI.copysign(Quad::E);
+        copysign_pos_neg:
+            -Quad::PI,
+            Quad::PI.copysign(-Quad::E);
+        copysign_neg_pos:
+            Quad::PI,
+            (-Quad::PI).copysign(Quad::E);
+        copysign_neg_neg:
+            -Quad::PI,
+            (-Quad::PI).copysign(-Quad::E);
+        copysign_neg_zero:
+            -Quad::PI,
+            Quad::PI.copysign(Quad::NEG_ZERO);
+        copysign_zero:
+            Quad::PI,
+            (-Quad::PI).copysign(Quad::ZERO);
+        copysign_inf:
+            Quad::NEG_INFINITY,
+            Quad::INFINITY.copysign(-Quad::PI);
+        copysign_neg_nan:
+            -Quad::PI,
+            Quad::PI.copysign(-Quad::NAN);
+    );
+    test_all_assert!(
+        copysign_zero_neg:
+            Quad::ZERO.copysign(-Quad::PI).is_sign_negative();
+        copysign_neg_zero_pos:
+            Quad::NEG_ZERO.copysign(Quad::PI).is_sign_positive();
+        copysign_nan_neg:
+            Quad::NAN.copysign(-Quad::PI).is_nan();
+        copysign_nan_neg_sign:
+            Quad::NAN.copysign(-Quad::PI).is_sign_negative();
+        copysign_low_components:
+            (-Quad::PI).copysign(Quad::ONE) == Quad::PI;
+    );
+
+    // abs_sub tests
+    test_all_exact!(
+        abs_sub_pi_e:
+            Quad::PI - Quad::E,
+            Quad::PI.abs_sub(Quad::E);
+        abs_sub_e_pi:
+            Quad::ZERO,
+            Quad::E.abs_sub(Quad::PI);
+        abs_sub_equal:
+            Quad::ZERO,
+            Quad::PI.abs_sub(Quad::PI);
+        abs_sub_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.abs_sub(Quad::PI);
+        abs_sub_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.abs_sub(Quad::PI);
+        abs_sub_nan_left:
+            Quad::NAN,
+            Quad::NAN.abs_sub(Quad::PI);
+        abs_sub_nan_right:
+            Quad::NAN,
+            Quad::PI.abs_sub(Quad::NAN);
+    );
+
     // classify tests
     test_all_eq!(
         classify_pi:
@@ -817,4 +2203,637 @@ mod tests {
         is_subnormal_sub:
             qd!(1e-308).is_subnormal();
     );
+
+    // is_integer tests
+    test_all_assert!(
+        is_integer_zero:
+            Quad::ZERO.is_integer();
+        is_integer_neg_zero:
+            Quad::NEG_ZERO.is_integer();
+        is_integer_one:
+            qd!(1).is_integer();
+        is_integer_neg:
+            qd!(-42).is_integer();
+        is_integer_large:
+            qd!(2).powi(120).is_integer();
+        is_integer_max:
+            Quad::MAX.is_integer();
+        is_integer_trailing:
+            (qd!(2).powi(120) + qd!(1)).is_integer();
+
+        is_integer_half:
+            !qd!(0.5).is_integer();
+        is_integer_pi:
+            !Quad::PI.is_integer();
+        is_integer_tiny:
+            !Quad::MIN_POSITIVE.is_integer();
+        is_integer_trailing_fract:
+            !(qd!(2).powi(120) + qd!(0.5)).is_integer();
+        is_integer_trailing_neg_fract:
+            !(qd!(2).powi(120) - qd!(0.25)).is_integer();
+        is_integer_inf:
+            !Quad::INFINITY.is_integer();
+        is_integer_neg_inf:
+            !Quad::NEG_INFINITY.is_integer();
+        is_integer_nan:
+            !Quad::NAN.is_integer();
+    );
+
+    // is_even tests
+    test_all_assert!(
+        is_even_zero:
+            Quad::ZERO.is_even();
+        is_even_neg_zero:
+            Quad::NEG_ZERO.is_even();
+        is_even_two:
+            qd!(2).is_even();
+        is_even_neg:
+            qd!(-10).is_even();
+        is_even_large:
+            qd!(2).powi(120).is_even();
+        is_even_max:
+            Quad::MAX.is_even();
+        is_even_trailing:
+            (qd!(2).powi(120) + qd!(2)).is_even();
+        is_even_trailing_neg:
+            (qd!(2).powi(120) - qd!(1) - qd!(1)).is_even();
+
+        is_even_one:
+            !qd!(1).is_even();
+        is_even_neg_odd:
+            !qd!(-7).is_even();
+        is_even_trailing_odd:
+            !(qd!(2).powi(120) + qd!(1)).is_even();
+        is_even_trailing_neg_odd:
+            !(qd!(2).powi(120) - qd!(1)).is_even();
+        is_even_fract:
+            !qd!(2.5).is_even();
+        is_even_inf:
+            !Quad::INFINITY.is_even();
+        is_even_nan:
+            !Quad::NAN.is_even();
+    );
+
+    // is_odd tests
+    test_all_assert!(
+        is_odd_one:
+            qd!(1).is_odd();
+        is_odd_neg:
+            qd!(-7).is_odd();
+        is_odd_trailing:
+            (qd!(2).powi(120) + qd!(1)).is_odd();
+        is_odd_trailing_neg:
+            (qd!(2).powi(120) - qd!(1)).is_odd();
+        is_odd_trailing_three:
+            (qd!(2).powi(120) - qd!(3)).is_odd();
+
+        is_odd_zero:
+            !Quad::ZERO.is_odd();
+        is_odd_two:
+            !qd!(2).is_odd();
+        is_odd_large:
+            !qd!(2).powi(120).is_odd();
+        is_odd_max:
+            !Quad::MAX.is_odd();
+        is_odd_fract:
+            !qd!(1.5).is_odd();
+        is_odd_inf:
+            !Quad::NEG_INFINITY.is_odd();
+        is_odd_nan:
+            !Quad::NAN.is_odd();
+    );
+
+    // is_normalized tests
+    test_all_assert!(
+        is_normalized_pi:
+            Quad::PI.is_normalized();
+        is_normalized_e:
+            Quad::E.is_normalized();
+        is_normalized_one:
+            qd!(1).is_normalized();
+        is_normalized_max:
+            Quad::MAX.is_normalized();
+        is_normalized_min_positive:
+            Quad::MIN_POSITIVE.is_normalized();
+        is_normalized_product:
+            (Quad::PI * Quad::E).is_normalized();
+        is_normalized_quotient:
+            (qd!(1) / qd!(3)).is_normalized();
+        is_normalized_zero:
+            Quad::ZERO.is_normalized();
+        is_normalized_neg_zero:
+            Quad::NEG_ZERO.is_normalized();
+        is_normalized_inf:
+            Quad::INFINITY.is_normalized();
+        is_normalized_neg_inf:
+            Quad::NEG_INFINITY.is_normalized();
+        is_normalized_nan:
+            Quad::NAN.is_normalized();
+
+        is_normalized_overlap:
+            !Quad(1.0, 1.0, 0.0, 0.0).is_normalized();
+        is_normalized_reversed:
+            !Quad(1e-20, 1.0, 0.0, 0.0).is_normalized();
+        is_normalized_zero_leading:
+            !Quad(0.0, 1e-20, 0.0, 0.0).is_normalized();
+        is_normalized_nan_trailing:
+            !Quad(1.0, f64::NAN, 0.0, 0.0).is_normalized();
+        is_normalized_inf_trailing:
+            !Quad(1.0, f64::INFINITY, 0.0, 0.0).is_normalized();
+        is_normalized_inf_nonzero_trailing:
+            !Quad(f64::INFINITY, 1.0, 0.0, 0.0).is_normalized();
+        is_normalized_nan_nan:
+            !Quad(f64::NAN, f64::NAN, 0.0, 0.0).is_normalized();
+        is_normalized_quad_overlap:
+            !Quad(1.0, 1e-20, 1.0, 0.0).is_normalized();
+        is_normalized_quad_gap:
+            !Quad(1.0, 0.0, 1e-40, 0.0).is_normalized();
+        is_normalized_quad_last:
+            !Quad(1.0, 1e-20, 1e-40, 1e-40).is_normalized();
+    );
+
+    // canonicalize tests
+    test_all_exact!(
+        canonicalize_pi:
+            Quad::PI,
+            Quad::PI.canonicalize();
+        canonicalize_overlap:
+            qd!(2),
+            Quad(1.0, 1.0, 0.0, 0.0).canonicalize();
+        canonicalize_reversed:
+            Quad(1.0, 1e-20, 0.0, 0.0),
+            Quad(1e-20, 1.0, 0.0, 0.0).canonicalize();
+        canonicalize_tie:
+            Quad(1.0 + 2f64.powi(-51), -2f64.powi(-53), 0.0, 0.0),
+            Quad(1.0, 3.0 * 2f64.powi(-53), 0.0, 0.0).canonicalize();
+        canonicalize_cancel:
+            Quad::ZERO,
+            Quad(1.0, -1.0, 0.0, 0.0).canonicalize();
+        canonicalize_quad_reversed:
+            Quad(1.0, 1e-20, 1e-40, 0.0),
+            Quad(1e-40, 1e-20, 1.0, 0.0).canonicalize();
+        canonicalize_quad_cancel:
+            Quad(1e-30, 0.0, 0.0, 0.0),
+            Quad(1.0, -1.0, 1e-30, 0.0).canonicalize();
+
+        canonicalize_zero:
+            Quad::ZERO,
+            Quad::ZERO.canonicalize();
+        canonicalize_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.canonicalize();
+        canonicalize_inf_nan_trailing:
+            Quad::INFINITY,
+            Quad(f64::INFINITY, f64::NAN, 0.0, 0.0).canonicalize();
+        canonicalize_inf_trailing:
+            Quad::NEG_INFINITY,
+            Quad(1.0, f64::NEG_INFINITY, 0.0, 0.0).canonicalize();
+        canonicalize_overflow:
+            Quad::INFINITY,
+            Quad(f64::MAX, f64::MAX, 0.0, 0.0).canonicalize();
+        canonicalize_nan:
+            Quad::NAN,
+            Quad::NAN.canonicalize();
+        canonicalize_nan_trailing:
+            Quad::NAN,
+            Quad(1.0, f64::NAN, 0.0, 0.0).canonicalize();
+    );
+    test_all_assert!(
+        canonicalize_normalized:
+            Quad(1e-20, 1.0, 0.0, 0.0).canonicalize().is_normalized();
+        canonicalize_nan_normalized:
+            Quad(f64::NAN, f64::NAN, 0.0, 0.0).canonicalize().is_normalized();
+        canonicalize_neg_zero:
+            Quad::NEG_ZERO.canonicalize().is_sign_negative();
+        canonicalize_same_bits:
+            Quad::E.canonicalize()[1].to_bits() == Quad::E[1].to_bits();
+    );
+
+    // precision_digits tests
+    test!(precision_digits: {
+        assert_eq!(Quad::precision_digits(), Quad::DIGITS);
+        assert!(Quad::EPSILON < qd!(10).powi(-(Quad::precision_digits() as i64)));
+    });
+
+    // effective_precision tests
+    test!(effective_precision: {
+        assert_eq!(qd!(1).effective_precision(), 212);
+        assert_eq!(Quad::MAX.effective_precision(), 212);
+        assert_eq!(Quad::MIN_FULL_PRECISION.effective_precision(), 212);
+        assert_eq!((-Quad::MIN_FULL_PRECISION).effective_precision(), 212);
+        assert_eq!(Quad::MIN_FULL_PRECISION.ldexp(-1).effective_precision(), 211);
+        assert_eq!(Quad::ONE.scale_b(-1022).effective_precision(), 53);
+        assert_eq!(Quad::ONE.scale_b(-1023).effective_precision(), 52);
+        assert_eq!(Quad::ONE.scale_b(-1074).effective_precision(), 1);
+        assert_eq!(Quad::ZERO.effective_precision(), 0);
+        assert_eq!(Quad::INFINITY.effective_precision(), 0);
+        assert_eq!(Quad::NAN.effective_precision(), 0);
+    });
+
+    // ulp tests
+    test_all_exact!(
+        ulp_one:
+            Quad(MIN_SUBNORMAL, 0.0, 0.0, 0.0),
+            Quad::ONE.ulp();
+        ulp_pi:
+            Quad(u::ulp(Quad::PI.3), 0.0, 0.0, 0.0),
+            Quad::PI.ulp();
+        ulp_neg_pi:
+            Quad::PI.ulp(),
+            (-Quad::PI).ulp();
+        ulp_zero:
+            Quad(MIN_SUBNORMAL, 0.0, 0.0, 0.0),
+            Quad::ZERO.ulp();
+        ulp_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.ulp();
+        ulp_neg_inf:
+            Quad::INFINITY,
+            Quad::NEG_INFINITY.ulp();
+        ulp_nan:
+            Quad::NAN,
+            Quad::NAN.ulp();
+    );
+
+    // precision_ulp tests
+    test_all_exact!(
+        precision_ulp_one:
+            Quad(2f64.powi(-209), 0.0, 0.0, 0.0),
+            Quad::ONE.precision_ulp();
+        precision_ulp_pi:
+            Quad(2f64.powi(-208), 0.0, 0.0, 0.0),
+            Quad::PI.precision_ulp();
+        precision_ulp_neg_pi:
+            Quad(2f64.powi(-208), 0.0, 0.0, 0.0),
+            (-Quad::PI).precision_ulp();
+        precision_ulp_zero:
+            Quad(MIN_SUBNORMAL, 0.0, 0.0, 0.0),
+            Quad::ZERO.precision_ulp();
+        precision_ulp_sub:
+            Quad(MIN_SUBNORMAL, 0.0, 0.0, 0.0),
+            qd!(1e-308).precision_ulp();
+        precision_ulp_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.precision_ulp();
+        precision_ulp_nan:
+            Quad::NAN,
+            Quad::NAN.precision_ulp();
+    );
+
+    // next_up tests
+    test_all_exact!(
+        next_up_one:
+            Quad(1.0, MIN_SUBNORMAL, 0.0, 0.0),
+            Quad::ONE.next_up();
+        next_up_neg_one:
+            Quad(-1.0, MIN_SUBNORMAL, 0.0, 0.0),
+            Quad::NEG_ONE.next_up();
+        next_up_pi:
+            Quad(Quad::PI.0, Quad::PI.1, Quad::PI.2, Quad::PI.3 + Quad::PI.ulp().0),
+            Quad::PI.next_up();
+        next_up_zero:
+            Quad(MIN_SUBNORMAL, 0.0, 0.0, 0.0),
+            Quad::ZERO.next_up();
+        next_up_neg_zero:
+            Quad(MIN_SUBNORMAL, 0.0, 0.0, 0.0),
+            Quad::NEG_ZERO.next_up();
+        next_up_subnormal:
+            Quad(3.0 * MIN_SUBNORMAL, 0.0, 0.0, 0.0),
+            Quad(2.0 * MIN_SUBNORMAL, 0.0, 0.0, 0.0).next_up();
+        next_up_max:
+            Quad::INFINITY,
+            Quad::MAX.next_up();
+        next_up_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.next_up();
+        next_up_neg_inf:
+            Quad::MIN,
+            Quad::NEG_INFINITY.next_up();
+        next_up_nan:
+            Quad::NAN,
+            Quad::NAN.next_up();
+    );
+    test_all_assert!(
+        next_up_greater:
+            Quad::PI.next_up() > Quad::PI;
+        next_up_neg_pi:
+            (-Quad::PI).next_up() > -Quad::PI;
+        next_up_sub:
+            qd!(1e-308).next_up() > qd!(1e-308);
+        next_up_below_max:
+            Quad::MAX.next_down().next_up() == Quad::MAX;
+    );
+
+    // next_down tests
+    test_all_exact!(
+        next_down_one:
+            Quad(1.0, -MIN_SUBNORMAL, 0.0, 0.0),
+            Quad::ONE.next_down();
+        next_down_zero:
+            Quad(-MIN_SUBNORMAL, 0.0, 0.0, 0.0),
+            Quad::ZERO.next_down();
+        next_down_min:
+            Quad::NEG_INFINITY,
+            Quad::MIN.next_down();
+        next_down_inf:
+            Quad::MAX,
+            Quad::INFINITY.next_down();
+        next_down_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.next_down();
+        next_down_nan:
+            Quad::NAN,
+            Quad::NAN.next_down();
+    );
+    test_all_assert!(
+        next_down_pi:
+            Quad::PI.next_down() < Quad::PI;
+        next_down_round_trip:
+            Quad::PI.next_down().next_up() == Quad::PI;
+    );
+
+    // next_after tests
+    test_all_exact!(
+        next_after_up:
+            Quad::E.next_up(),
+            Quad::E.next_after(Quad::PI);
+        next_after_down:
+            Quad::PI.next_down(),
+            Quad::PI.next_after(Quad::E);
+        next_after_equal:
+            Quad::PI,
+            Quad::PI.next_after(Quad::PI);
+        next_after_zero:
+            Quad::NEG_ZERO,
+            Quad::ZERO.next_after(Quad::NEG_ZERO);
+        next_after_inf:
+            Quad::MAX,
+            Quad::INFINITY.next_after(Quad::ZERO);
+        next_after_nan:
+            Quad::NAN,
+            Quad::PI.next_after(Quad::NAN);
+        next_after_nan_self:
+            Quad::NAN,
+            Quad::NAN.next_after(Quad::PI);
+    );
+
+    // approx_eq tests
+    test_all_assert!(
+        approx_eq_rel:
+            (qd!(1) / qd!(7) * qd!(7)).approx_eq(qd!(1), AbsRel { abs: 0.0, rel: 1e-25 });
+        approx_eq_rel_large:
+            qd!(1e100).approx_eq(qd!(1.0000001e100), AbsRel { abs: 0.0, rel: 1e-6 });
+        approx_eq_rel_fail:
+            !qd!(1).approx_eq(qd!(1.001), AbsRel { abs: 1e-4, rel: 1e-4 });
+        approx_eq_abs:
+            qd!(1e-40).approx_eq(qd!(-1e-40), AbsRel { abs: 1e-30, rel: 0.0 });
+        approx_eq_abs_fail:
+            !qd!(1e-40).approx_eq(Quad::ZERO, AbsRel { abs: 0.0, rel: 0.5 });
+        approx_eq_zeros:
+            Quad::ZERO.approx_eq(Quad::NEG_ZERO, AbsRel::default());
+        approx_eq_inf:
+            Quad::INFINITY.approx_eq(Quad::INFINITY, AbsRel::default());
+        approx_eq_inf_max:
+            !Quad::INFINITY.approx_eq(Quad::MAX, AbsRel { abs: f64::INFINITY, rel: 1.0 });
+        approx_eq_nan:
+            !Quad::NAN.approx_eq(Quad::NAN, AbsRel { abs: f64::INFINITY, rel: 1.0 });
+    );
+
+    // ulps_eq tests
+    test_all_assert!(
+        ulps_eq_equal:
+            Quad::E.ulps_eq(Quad::E, 0);
+        ulps_eq_one:
+            Quad::E.ulps_eq(Quad::E.next_down(), 1);
+        ulps_eq_one_fail:
+            !Quad::E.ulps_eq(Quad::E.next_down(), 0);
+        ulps_eq_many:
+            Quad::PI.ulps_eq(Quad::PI + Quad::PI.precision_ulp() * qd!(100), 100);
+        ulps_eq_many_fail:
+            !Quad::PI.ulps_eq(Quad::PI + Quad::PI.precision_ulp() * qd!(101), 100);
+        ulps_eq_low_component:
+            !Quad::ONE.ulps_eq(Quad::ONE + qd!(1e-20), 1000);
+        ulps_eq_opposite_signs:
+            !Quad::ONE.ulps_eq(-Quad::ONE, u32::MAX);
+        ulps_eq_zeros:
+            Quad::ZERO.ulps_eq(Quad::NEG_ZERO, 0);
+        ulps_eq_inf:
+            Quad::NEG_INFINITY.ulps_eq(Quad::NEG_INFINITY, 0)
+                && !Quad::INFINITY.ulps_eq(Quad::MAX, u32::MAX);
+        ulps_eq_nan:
+            !Quad::NAN.ulps_eq(Quad::NAN, u32::MAX);
+    );
+
+    // min tests
+    test_all_exact!(
+        min_pi_e:
+            Quad::E,
+            Quad::PI.min(Quad::E);
+        min_e_pi:
+            Quad::E,
+            Quad::E.min(Quad::PI);
+        min_neg:
+            -Quad::PI,
+            Quad::E.min(-Quad::PI);
+        min_inf:
+            Quad::PI,
+            Quad::PI.min(Quad::INFINITY);
+        min_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::PI.min(Quad::NEG_INFINITY);
+        min_nan_right:
+            Quad::PI,
+            Quad::PI.min(Quad::NAN);
+        min_nan_left:
+            Quad::PI,
+            Quad::NAN.min(Quad::PI);
+        min_nan_nan:
+            Quad::NAN,
+            Quad::NAN.min(Quad::NAN);
+    );
+
+    // max tests
+    test_all_exact!(
+        max_pi_e:
+            Quad::PI,
+            Quad::PI.max(Quad::E);
+        max_e_pi:
+            Quad::PI,
+            Quad::E.max(Quad::PI);
+        max_neg:
+            Quad::E,
+            Quad::E.max(-Quad::PI);
+        max_inf:
+            Quad::INFINITY,
+            Quad::PI.max(Quad::INFINITY);
+        max_neg_inf:
+            Quad::PI,
+            Quad::PI.max(Quad::NEG_INFINITY);
+        max_nan_right:
+            Quad::PI,
+            Quad::PI.max(Quad::NAN);
+        max_nan_left:
+            Quad::PI,
+            Quad::NAN.max(Quad::PI);
+        max_nan_nan:
+            Quad::NAN,
+            Quad::NAN.max(Quad::NAN);
+    );
+
+    // minimum tests
+    test_all_exact!(
+        minimum_pi_e:
+            Quad::E,
+            Quad::PI.minimum(Quad::E);
+        minimum_low_component:
+            Quad::PI,
+            Quad::PI.next_up().minimum(Quad::PI);
+        minimum_nan_right:
+            Quad::NAN,
+            Quad::PI.minimum(Quad::NAN);
+        minimum_nan_left:
+            Quad::NAN,
+            Quad::NAN.minimum(Quad::PI);
+    );
+    test_all_assert!(
+        minimum_zero_neg_zero:
+            Quad::ZERO.minimum(Quad::NEG_ZERO).is_sign_negative();
+        minimum_neg_zero_zero:
+            Quad::NEG_ZERO.minimum(Quad::ZERO).is_sign_negative();
+    );
+
+    // maximum tests
+    test_all_exact!(
+        maximum_pi_e:
+            Quad::PI,
+            Quad::PI.maximum(Quad::E);
+        maximum_low_component:
+            Quad::PI.next_up(),
+            Quad::PI.next_up().maximum(Quad::PI);
+        maximum_nan_right:
+            Quad::NAN,
+            Quad::PI.maximum(Quad::NAN);
+        maximum_nan_left:
+            Quad::NAN,
+            Quad::NAN.maximum(Quad::PI);
+    );
+    test_all_assert!(
+        maximum_zero_neg_zero:
+            Quad::ZERO.maximum(Quad::NEG_ZERO).is_sign_positive();
+        maximum_neg_zero_zero:
+            Quad::NEG_ZERO.maximum(Quad::ZERO).is_sign_positive();
+    );
+
+    // minimum_number tests
+    test_all_exact!(
+        minimum_number_pi_e:
+            Quad::E,
+            Quad::PI.minimum_number(Quad::E);
+        minimum_number_nan_right:
+            Quad::PI,
+            Quad::PI.minimum_number(Quad::NAN);
+        minimum_number_nan_left:
+            Quad::PI,
+            Quad::NAN.minimum_number(Quad::PI);
+        minimum_number_nan_nan:
+            Quad::NAN,
+            Quad::NAN.minimum_number(Quad::NAN);
+    );
+    test_all_assert!(
+        minimum_number_zero_neg_zero:
+            Quad::ZERO.minimum_number(Quad::NEG_ZERO).is_sign_negative();
+    );
+
+    // maximum_number tests
+    test_all_exact!(
+        maximum_number_pi_e:
+            Quad::PI,
+            Quad::PI.maximum_number(Quad::E);
+        maximum_number_nan_right:
+            Quad::PI,
+            Quad::PI.maximum_number(Quad::NAN);
+        maximum_number_nan_left:
+            Quad::PI,
+            Quad::NAN.maximum_number(Quad::PI);
+        maximum_number_nan_nan:
+            Quad::NAN,
+            Quad::NAN.maximum_number(Quad::NAN);
+    );
+    test_all_assert!(
+        maximum_number_neg_zero_zero:
+            Quad::NEG_ZERO.maximum_number(Quad::ZERO).is_sign_positive();
+    );
+
+    // clamp tests
+    test_all_exact!(
+        clamp_below:
+            Quad::E,
+            qd!(1).clamp(Quad::E, Quad::PI);
+        clamp_above:
+            Quad::PI,
+            qd!(4).clamp(Quad::E, Quad::PI);
+        clamp_within:
+            qd!(3),
+            qd!(3).clamp(Quad::E, Quad::PI);
+        clamp_low_component:
+            Quad::PI,
+            Quad::PI.next_up().clamp(Quad::E, Quad::PI);
+        clamp_inf:
+            Quad::PI,
+            Quad::INFINITY.clamp(Quad::E, Quad::PI);
+        clamp_neg_inf:
+            Quad::E,
+            Quad::NEG_INFINITY.clamp(Quad::E, Quad::PI);
+        clamp_equal_bounds:
+            Quad::E,
+            Quad::PI.clamp(Quad::E, Quad::E);
+        clamp_nan:
+            Quad::NAN,
+            Quad::NAN.clamp(Quad::E, Quad::PI);
+    );
+
+    #[test]
+    #[should_panic]
+    fn clamp_min_gt_max() {
+        Quad::ONE.clamp(Quad::PI, Quad::E);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_min_nan() {
+        Quad::ONE.clamp(Quad::NAN, Quad::E);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_max_nan() {
+        Quad::ONE.clamp(Quad::E, Quad::NAN);
+    }
+
+    #[test]
+    fn to_fraction() {
+        assert_eq!(Quad::PI.to_fraction(1), Some((3, 1)));
+        assert_eq!(Quad::PI.to_fraction(7), Some((22, 7)));
+        assert_eq!(Quad::PI.to_fraction(106), Some((333, 106)));
+        assert_eq!(Quad::PI.to_fraction(1_000_000), Some((3_126_535, 995_207)));
+        assert_eq!((-Quad::E).to_fraction(1000), Some((-1_457, 536)));
+        assert_eq!(
+            (-Quad::E).to_fraction(1_000_000),
+            Some((-1_084_483, 398_959))
+        );
+        assert_eq!(qd!(0).to_fraction(10), Some((0, 1)));
+        assert_eq!(qd!(5).to_fraction(10), Some((5, 1)));
+        assert_eq!((qd!(1) / qd!(3)).to_fraction(u64::MAX), Some((1, 3)));
+        let x = qd!(1_234_567_891) / qd!(9_876_543_211u64);
+        assert_eq!(
+            x.to_fraction(u64::MAX),
+            Some((1_234_567_891, 9_876_543_211))
+        );
+        assert_eq!((qd!(-22) / qd!(7)).to_fraction(6), Some((-19, 6)));
+        // Ties go to the convergent
+        assert_eq!(qd!(0.5).to_fraction(1), Some((0, 1)));
+        assert_eq!(qd!(1e40).to_fraction(10), None);
+        assert_eq!(Quad::INFINITY.to_fraction(10), None);
+        assert_eq!(Quad::PI.to_fraction(0), None);
+    }
 }
@@ -5,38 +5,13 @@
 
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::Double;
 use crate::quad::Quad;
 use std::ops::{Mul, MulAssign};
 
 impl Mul for Quad {
     type Output = Quad;
 
-    // This is complicated.
-    //
-    // It closely follows the process described on pp. 11-16 of "Library for Double-Double
-    // and  Quad-Double Arithmetic" by Y. Hida, X.S. Li, and D.H. Bailey which can be found
-    // at http://web.mit.edu/tabbott/Public/quaddouble-debian/qd-2.3.4-old/docs/qd.pdf. You
-    // should be able to see the way the source code works from the diagrams there.
-    //
-    // TERMS (a = self, b = other):
-    // Order   Components   Group (hx, lx)
-    // O(1)    a0 * b0      0
-    // O(ε)    a0 * b1      1
-    //         a1 * b0      2
-    // O(ε²)   a0 * b2      3
-    //         a1 * b1      4
-    //         a2 * b0      5
-    // O(ε³)   a0 * b3      6
-    //         a1 * b2      7
-    //         a2 * b1      8
-    //         a3 * b0      9
-    // O(ε⁴)   a1 * b3      a  (high word only)
-    //         a2 * b2      b  (high word only)
-    //         a3 * b1      c  (high word only)
-    //
-    // Other terms, including the remaining O(ε⁴) terms and the low words of the O(ε⁴) that
-    // are calculated, are not necessary to provide 212 bits of accuracy.
-
     /// Multiplies this `Quad` by another, producing a new `Quad` as a result.
     ///
     /// This implements the `*` operator between two `Quad`s.
@@ -50,54 +25,9 @@ impl Mul for Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
-    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
     fn mul(self, other: Quad) -> Quad {
-        match self.pre_mul(&other) {
-            Some(r) => r,
-            None => {
-                // O(1) term
-                let (h0, l0) = p::two_prod(self.0, other.0);
-
-                // O(ε) terms
-                let (h1, l1) = p::two_prod(self.0, other.1);
-                let (h2, l2) = p::two_prod(self.1, other.0);
-
-                // O(ε²) terms
-                let (h3, l3) = p::two_prod(self.0, other.2);
-                let (h4, l4) = p::two_prod(self.1, other.1);
-                let (h5, l5) = p::two_prod(self.2, other.0);
-
-                // O(ε³) terms
-                let (h6, l6) = p::two_prod(self.0, other.3);
-                let (h7, l7) = p::two_prod(self.1, other.2);
-                let (h8, l8) = p::two_prod(self.2, other.1);
-                let (h9, l9) = p::two_prod(self.3, other.0);
-
-                // O(ε⁴) terms - the low words aren't necessary for the accuracy we need
-                let ha = self.1 * other.3;
-                let hb = self.2 * other.2;
-                let hc = self.3 * other.1;
-
-                // Each calculation takes all of the high words for the terms of that level,
-                // whatever intermediate words are specified by the algorithm, and whatever
-                // low words fit in the remaining input space.
-
-                // O(1) calculation (pass-through)
-                let r0 = h0;
-                // O(ε) calculation
-                let (r1, t0, t1) = u::three_three_sum(h1, h2, l0);
-                // O(ε²) calculation
-                let (r2, t2, t3) = u::six_three_sum(t0, h3, h4, h5, l1, l2);
-                // O(ε³) calculation
-                let (r3, t4) = u::nine_two_sum(t1, t2, h6, h7, h8, h9, l3, l4, l5);
-                // O(ε⁴) calculation (nine_one_sum)
-                let r4 = t3 + t4 + ha + hb + hc + l6 + l7 + l8 + l9;
-
-                // Results of the prior calculations are renormalized into four f64s.
-                let (a, b, c, d) = u::renorm5(r0, r1, r2, r3, r4);
-                Quad(a, b, c, d)
-            }
-        }
+        self.mul_fast(other)
     }
 }
 
@@ -221,7 +151,323 @@ impl MulAssign<&Quad> for Quad {
     }
 }
 
+impl MulAssign<f64> for Quad {
+    /// Multiplies this `Quad` by an `f64`, modifying this one to equal the result.
+    ///
+    /// This implements the `*=` operator between a `Quad` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x *= 2.0;
+    /// let expected = qd!("6.283185307179586476925286766559005768394338798750211641949889184");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: f64) {
+        let r = self.mul(Quad(other, 0.0, 0.0, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
+impl MulAssign<Double> for Quad {
+    /// Multiplies this `Quad` by a `Double`, modifying this one to equal the result.
+    ///
+    /// This implements the `*=` operator between a `Quad` and a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x *= dd!(2);
+    /// let expected = qd!("6.283185307179586476925286766559005768394338798750211641949889184");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Double) {
+        let (a, b): (f64, f64) = other.into();
+        let r = self.mul(Quad(a, b, 0.0, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
 impl Quad {
+    /// Multiplies this `Quad` by another using the accurate multiplication algorithm.
+    ///
+    /// Every product between the components of the two numbers is included. Those of order
+    /// ε<sup>4</sup> and larger are computed exactly, along with their error terms, and
+    /// the whole set is summed by distillation, which loses no accuracy to cancellation.
+    /// The only error of any consequence is the rounding of the last component of the
+    /// result, so the relative error is at most about 2<sup>-211</sup> (one ULP).
+    ///
+    /// This is the algorithm to use when results have to match a reference implementation
+    /// down to the last bit, but the difference from [`mul_fast`] is small. The terms that
+    /// [`mul_fast`] leaves out are around 2<sup>-260</sup> relative to the product, so they
+    /// only change the result in the rare cases where they tip the rounding of the last
+    /// component. This algorithm costs about twice as much.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::E.mul_accurate(Quad::PI);
+    /// let expected = qd!("8.539734222673567065463550869546574495034888535765114961879601130");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`mul_fast`]: #method.mul_fast
+    pub fn mul_accurate(self, other: Quad) -> Quad {
+        match self.pre_mul(&other) {
+            Some(r) => r,
+            None => {
+                // As in `mul_add`, the terms are collected by order of magnitude, from
+                // O(ε⁶) up to O(1). The three products smaller than O(ε⁴) are too small
+                // for their error terms to matter.
+                let mut terms = [0.0; 29];
+                let mut n = 0;
+                for order in (0..7).rev() {
+                    for i in 0..4 {
+                        if order < i || order - i > 3 {
+                            continue;
+                        }
+                        let j = order - i;
+                        if order > 4 {
+                            terms[n] = self[i] * other[j];
+                            n += 1;
+                        } else {
+                            let (h, l) = p::two_prod(self[i], other[j]);
+                            terms[n] = l;
+                            terms[n + 1] = h;
+                            n += 2;
+                        }
+                    }
+                }
+
+                let (a, b, c, d) = u::distill4(&mut terms);
+                Quad(a, b, c, d)
+            }
+        }
+    }
+
+    // This is complicated.
+    //
+    // It closely follows the process described on pp. 11-16 of "Library for Double-Double
+    // and  Quad-Double Arithmetic" by Y. Hida, X.S. Li, and D.H. Bailey which can be found
+    // at http://web.mit.edu/tabbott/Public/quaddouble-debian/qd-2.3.4-old/docs/qd.pdf. You
+    // should be able to see the way the source code works from the diagrams there.
+    //
+    // TERMS (a = self, b = other):
+    // Order   Components   Group (hx, lx)
+    // O(1)    a0 * b0      0
+    // O(ε)    a0 * b1      1
+    //         a1 * b0      2
+    // O(ε²)   a0 * b2      3
+    //         a1 * b1      4
+    //         a2 * b0      5
+    // O(ε³)   a0 * b3      6
+    //         a1 * b2      7
+    //         a2 * b1      8
+    //         a3 * b0      9
+    // O(ε⁴)   a1 * b3      a  (high word only)
+    //         a2 * b2      b  (high word only)
+    //         a3 * b1      c  (high word only)
+    //
+    // Other terms, including the remaining O(ε⁴) terms and the low words of the O(ε⁴) that
+    // are calculated, are not necessary to provide 212 bits of accuracy.
+
+    /// Multiplies this `Quad` by another using the fast multiplication algorithm.
+    ///
+    /// This is the algorithm used by the `*` operator. All of the products of order
+    /// ε<sup>3</sup> and larger are computed with their error terms, but only the high
+    /// words of three of the ε<sup>4</sup> products are included and the rest are dropped.
+    /// This is enough for the 212 bits of a `Quad`: the relative error is at most about
+    /// 2<sup>-211</sup> (one ULP), the same as for [`mul_accurate`], though in rare cases
+    /// the last bit of the result differs from the one [`mul_accurate`] produces.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::E.mul_fast(Quad::PI);
+    /// let expected = qd!("8.539734222673567065463550869546574495034888535765114961879601130");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`mul_accurate`]: #method.mul_accurate
+    #[inline]
+    pub fn mul_fast(self, other: Quad) -> Quad {
+        match self.pre_mul(&other) {
+            Some(r) => r,
+            None => {
+                // O(1) term
+                let (h0, l0) = p::two_prod(self.0, other.0);
+
+                // O(ε) terms
+                let (h1, l1) = p::two_prod(self.0, other.1);
+                let (h2, l2) = p::two_prod(self.1, other.0);
+
+                // O(ε²) terms
+                let (h3, l3) = p::two_prod(self.0, other.2);
+                let (h4, l4) = p::two_prod(self.1, other.1);
+                let (h5, l5) = p::two_prod(self.2, other.0);
+
+                // O(ε³) terms
+                let (h6, l6) = p::two_prod(self.0, other.3);
+                let (h7, l7) = p::two_prod(self.1, other.2);
+                let (h8, l8) = p::two_prod(self.2, other.1);
+                let (h9, l9) = p::two_prod(self.3, other.0);
+
+                // O(ε⁴) terms - the low words aren't necessary for the accuracy we need
+                let ha = self.1 * other.3;
+                let hb = self.2 * other.2;
+                let hc = self.3 * other.1;
+
+                // Each calculation takes all of the high words for the terms of that level,
+                // whatever intermediate words are specified by the algorithm, and whatever
+                // low words fit in the remaining input space.
+
+                // O(1) calculation (pass-through)
+                let r0 = h0;
+                // O(ε) calculation
+                let (r1, t0, t1) = u::three_three_sum(h1, h2, l0);
+                // O(ε²) calculation
+                let (r2, t2, t3) = u::six_three_sum(t0, h3, h4, h5, l1, l2);
+                // O(ε³) calculation
+                let (r3, t4) = u::nine_two_sum(t1, t2, h6, h7, h8, h9, l3, l4, l5);
+                // O(ε⁴) calculation (nine_one_sum)
+                let r4 = t3 + t4 + ha + hb + hc + l6 + l7 + l8 + l9;
+
+                // Results of the prior calculations are renormalized into four f64s.
+                let (a, b, c, d) = u::renorm5(r0, r1, r2, r3, r4);
+                Quad(a, b, c, d)
+            }
+        }
+    }
+
+    /// Computes `self * a + b` with only one rounding error, yielding a more accurate
+    /// result than a separate multiplication and addition.
+    ///
+    /// All of the products between the components of `self` and `a` that can affect the
+    /// result are computed, most of them exactly, and `b` is added to them before anything
+    /// is rounded. This avoids losing the part of the product that doesn't fit into a
+    /// `Quad`, which matters most when `b` nearly cancels the product out (as happens
+    /// regularly in polynomial evaluation and dot products).
+    ///
+    /// If any of the three values is infinite or `NaN`, or if either factor is zero, the
+    /// result is the same as `self * a + b`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::PI.mul_add(Quad::E, Quad::LN_2);
+    /// let expected = qd!("9.232881403233512374880782991004751063110388670125370216000281140");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn mul_add(self, a: Quad, b: Quad) -> Quad {
+        match self.pre_mul_add(&a, &b) {
+            Some(r) => r,
+            None => {
+                // The terms are collected by order of magnitude, from O(ε⁶) up to O(1), so
+                // that distillation converges more quickly. Terms up to O(ε⁴) are computed
+                // exactly; the three smaller ones are far below the precision of a `Quad`
+                // even when `b` cancels most of the product.
+                let mut terms = [0.0; 33];
+                let mut n = 0;
+                for order in (0..7).rev() {
+                    for i in 0..4 {
+                        if order < i || order - i > 3 {
+                            continue;
+                        }
+                        let j = order - i;
+                        if order > 4 {
+                            terms[n] = self[i] * a[j];
+                            n += 1;
+                        } else {
+                            let (h, l) = p::two_prod(self[i], a[j]);
+                            terms[n] = l;
+                            terms[n + 1] = h;
+                            n += 2;
+                        }
+                    }
+                    if order < 4 {
+                        terms[n] = b[order];
+                        n += 1;
+                    }
+                }
+
+                let (a, b, c, d) = u::distill4(&mut terms);
+                Quad(a, b, c, d)
+            }
+        }
+    }
+
+    /// Multiplies this `Quad` by another, returning both the product and the rounding error
+    /// of the multiplication.
+    ///
+    /// The product is the same one that the `*` operator produces. The error is the
+    /// difference between the exact product and the rounded one, itself rounded to a
+    /// `Quad`, so that the two together represent the exact product to about twice the
+    /// precision of a `Quad`. This is the quad-double counterpart of the [`two_prod`]
+    /// error-free transformation.
+    ///
+    /// If the product is infinite or `NaN`, the error is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let (p, e) = Quad::PI.mul_with_err(Quad::E);
+    ///
+    /// // The exact product needs more precision than a `Quad` has, so some of it ends up
+    /// // in the error, which is much smaller than the product
+    /// assert!(e != Quad::ZERO);
+    /// assert!(e.abs() < p * Quad::EPSILON);
+    /// ```
+    ///
+    /// [`two_prod`]: crate::eft::two_prod
+    pub fn mul_with_err(self, other: Quad) -> (Quad, Quad) {
+        let r = self * other;
+        if r.is_finite() {
+            // Unlike `mul_add`, every partial product is computed exactly here, since the
+            // error is several orders of magnitude smaller than the product
+            let mut terms = [0.0; 36];
+            let mut n = 0;
+            for order in (0..7).rev() {
+                for i in 0..4 {
+                    if order < i || order - i > 3 {
+                        continue;
+                    }
+                    let (h, l) = p::two_prod(self[i], other[order - i]);
+                    terms[n] = l;
+                    terms[n + 1] = h;
+                    n += 2;
+                }
+                if order < 4 {
+                    terms[n] = -r[order];
+                    n += 1;
+                }
+            }
+            let (a, b, c, d) = u::distill4(&mut terms);
+            (r, Quad(a, b, c, d))
+        } else {
+            (r, Quad::ZERO)
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -231,6 +477,15 @@ impl Quad {
     // This keeps the public functions from being mucked up with code that does validation
     // rather than calculation.
 
+    #[inline]
+    fn pre_mul_add(&self, a: &Quad, b: &Quad) -> Option<Quad> {
+        if !self.is_finite() || !a.is_finite() || !b.is_finite() || self.is_zero() || a.is_zero() {
+            Some(*self * *a + *b)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_mul(&self, other: &Quad) -> Option<Quad> {
         if self.is_nan() || other.is_nan() {
@@ -263,6 +518,30 @@ impl Quad {
     }
 }
 
+impl Quad {
+    /// Multiplies this `Quad` by another, returning `None` if the result is not finite.
+    ///
+    /// This mirrors the `checked_mul` methods of the primitive integer types. The result is
+    /// `None` if the product overflows to infinity or the result is `NaN`; this is always
+    /// the case when either argument is already infinite or `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::PI.checked_mul(Quad::E).is_some());
+    /// assert!(Quad::MAX.checked_mul(qd!(2)).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_mul(self, other: Quad) -> Option<Quad> {
+        let r = self * other;
+        if r.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -368,6 +647,90 @@ mod tests {
             Quad::ONE * Quad::NAN;
     );
 
+    // mul_accurate/mul_fast tests
+    test_all_near!(
+        accurate_num_num:
+            qd!("8.5397342226735670654635508695465744950348885357651149618796011301762"),
+            Quad::PI.mul_accurate(Quad::E);
+        accurate_neg_num:
+            qd!("-8.5397342226735670654635508695465744950348885357651149618796011301762"),
+            (-Quad::PI).mul_accurate(Quad::E);
+        accurate_small:
+            qd!("0.27219826128795026631258611227970174341732296254616078679072440664929"),
+            Quad::LN_2.mul_accurate(Quad::FRAC_PI_8);
+        fast_num_num:
+            qd!("8.5397342226735670654635508695465744950348885357651149618796011301762"),
+            Quad::PI.mul_fast(Quad::E);
+    );
+    test_all_exact!(
+        accurate_agrees:
+            Quad::PI * Quad::E,
+            Quad::PI.mul_accurate(Quad::E);
+        accurate_all_components:
+            Quad(
+                1.0,
+                2f64.powi(-60) + 2f64.powi(-70),
+                2f64.powi(-120) + 2f64.powi(-130),
+                2f64.powi(-180) + 2f64.powi(-190),
+            ),
+            Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), 2f64.powi(-180))
+                .mul_accurate(Quad(1.0, 2f64.powi(-70), 0.0, 0.0));
+        accurate_int:
+            qd!(21),
+            qd!(3).mul_accurate(qd!(7));
+        accurate_zero:
+            Quad::ZERO,
+            Quad::PI.mul_accurate(Quad::ZERO);
+        accurate_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.mul_accurate(Quad::PI);
+        accurate_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::INFINITY.mul_accurate(-Quad::PI);
+        accurate_inf_zero:
+            Quad::NAN,
+            Quad::INFINITY.mul_accurate(Quad::ZERO);
+        accurate_nan:
+            Quad::NAN,
+            Quad::NAN.mul_accurate(Quad::PI);
+    );
+
+    // mul_add tests
+    test_all_near!(
+        mul_add_num_num_num:
+            qd!("9.2328814032335123748807829910047510631103886701253702160002811396726"),
+            Quad::PI.mul_add(Quad::E, Quad::LN_2);
+        mul_add_neg:
+            qd!("-7.8465870421136217560463187480883979269593884014048597077589211206858"),
+            Quad::PI.mul_add(-Quad::E, Quad::LN_2);
+        mul_add_cancel:
+            Quad(
+                9.86076131526265e-32,
+                -5.473822126268814e-48,
+                2.698802673467014e-79,
+                2.2472046502522555e-95,
+            ),
+            Quad(1.0 + f64::EPSILON, 2f64.powi(-105), 2f64.powi(-158), 2f64.powi(-211))
+                .mul_add(
+                    Quad(1.0 + f64::EPSILON, 2f64.powi(-105), 2f64.powi(-158), 2f64.powi(-211)),
+                    Quad(-1.0 - 2.0 * f64::EPSILON, 0.0, 0.0, 0.0),
+                );
+    );
+    test_all_exact!(
+        mul_add_zero:
+            Quad::E,
+            Quad::ZERO.mul_add(Quad::PI, Quad::E);
+        mul_add_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.mul_add(Quad::PI, Quad::E);
+        mul_add_inf_neg_inf:
+            Quad::NAN,
+            Quad::INFINITY.mul_add(Quad::PI, Quad::NEG_INFINITY);
+        mul_add_nan:
+            Quad::NAN,
+            Quad::PI.mul_add(Quad::E, Quad::NAN);
+    );
+
     // Assign tests. Assign code delegates to mul code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -381,5 +744,62 @@ mod tests {
             b *= &Quad::E;
             near!(qd!("8.5397342226735670654635508695465744950348885357651149618796011301762"), b);
         }
+        assign_f64: {
+            let mut c = Quad::PI;
+            c *= 2.0;
+            near!(qd!("6.2831853071795864769252867665590057683943387987502116419498891846156"), c);
+        }
+        assign_double: {
+            let mut d = Quad::PI;
+            d *= Double::from(2.0);
+            near!(qd!("6.2831853071795864769252867665590057683943387987502116419498891846156"), d);
+        }
+    );
+
+    // mul_with_err tests
+    test_all!(
+        mul_with_err_split: {
+            let x = Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), 0.0);
+            let y = Quad(1.0, 2f64.powi(-200), 0.0, 0.0);
+            let (p, e) = x.mul_with_err(y);
+            exact!(Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), 2f64.powi(-200)), p);
+            exact!(Quad(2f64.powi(-260), 2f64.powi(-320), 0.0, 0.0), e);
+        }
+        mul_with_err_no_err: {
+            let (p, e) = qd!(3).mul_with_err(qd!(7));
+            exact!(qd!(21), p);
+            exact!(Quad::ZERO, e);
+        }
+        mul_with_err_pi_e: {
+            let (p, e) = Quad::PI.mul_with_err(Quad::E);
+            exact!(Quad::PI * Quad::E, p);
+            assert!(e != Quad::ZERO);
+            assert!(e.abs() < p * Quad::EPSILON);
+            // `mul_add` computes the same residual with less accuracy
+            prec!(e, Quad::PI.mul_add(Quad::E, -p), 12);
+        }
+        mul_with_err_inf: {
+            let (p, e) = Quad::INFINITY.mul_with_err(Quad::PI);
+            exact!(Quad::INFINITY, p);
+            exact!(Quad::ZERO, e);
+        }
+        mul_with_err_overflow: {
+            let (p, e) = Quad::MAX.mul_with_err(qd!(2));
+            exact!(Quad::MAX * qd!(2), p);
+            exact!(Quad::ZERO, e);
+        }
+        mul_with_err_nan: {
+            let (p, e) = Quad::NAN.mul_with_err(Quad::PI);
+            exact!(Quad::NAN, p);
+            exact!(Quad::ZERO, e);
+        }
+    );
+
+    // checked_mul tests
+    test_all_assert!(
+        checked_mul_num: Quad::PI.checked_mul(Quad::E) == Some(Quad::PI * Quad::E);
+        checked_mul_nan: Quad::PI.checked_mul(Quad::NAN).is_none();
+        checked_mul_inf: Quad::INFINITY.checked_mul(Quad::PI).is_none();
+        checked_mul_overflow: Quad::MAX.checked_mul(qd!(2)).is_none();
     );
 }
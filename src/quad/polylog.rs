@@ -0,0 +1,437 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+impl Quad {
+    /// Calculates the dilogarithm (Li<sub>2</sub>) of the `Quad`.
+    ///
+    /// The argument is first mapped into [-1, 1/2] with the standard reflection and
+    /// inversion identities, and there the dilogarithm is calculated from a series in
+    /// u = -ln(1 - x) with Bernoulli-number coefficients, which converges quickly over the
+    /// whole interval.
+    ///
+    /// The dilogarithm is complex for arguments greater than 1. For those arguments, this
+    /// function returns the real part, as is conventional.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is (-∞, π²/6].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(0.5).li2(); // π²/12 - ln²(2)/2
+    /// let expected = qd!("0.5822405264650125059026563201596801087441984748061264254343470479");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn li2(self) -> Quad {
+        match self.pre_li2() {
+            Some(r) => r,
+            None => li2(self),
+        }
+    }
+
+    /// Calculates the polylogarithm of order `n` (Li<sub>n</sub>) of the `Quad`.
+    ///
+    /// Orders 0 and 1 are elementary functions (Li<sub>0</sub>(x) = x / (1 - x) and
+    /// Li<sub>1</sub>(x) = -ln(1 - x)), and order 2 is the same as [`li2`]. Higher orders
+    /// are calculated from the defining power series for small arguments and from the
+    /// series in ln x (whose coefficients are values of the zeta function) for arguments
+    /// near 1. Arguments less than -1/2 are handled with the duplication formula, and those
+    /// whose absolute value is greater than 1 with the inversion formula.
+    ///
+    /// The polylogarithm is complex for arguments greater than 1 (other than for order 0).
+    /// For those arguments, this function returns the real part, as is conventional.
+    ///
+    /// This is intended for small orders; the cost grows with `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(0.5).polylog(3);
+    /// let expected = qd!("0.5372131936080402009406232255949658266704024993403781706897619307");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`li2`]: #method.li2
+    pub fn polylog(self, n: u32) -> Quad {
+        match self.pre_polylog(n) {
+            Some(r) => r,
+            None => match n {
+                0 => self / (Quad::ONE - self),
+                1 => {
+                    if self > Quad::ONE {
+                        -(self - Quad::ONE).ln()
+                    } else {
+                        -(-self).ln_1p()
+                    }
+                }
+                2 => li2(self),
+                _ => polylog(self, n),
+            },
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_li2(&self) -> Option<Quad> {
+        self.pre_polylog(2)
+    }
+
+    #[inline]
+    fn pre_polylog(&self, n: u32) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if self.is_infinite() {
+            Some(if n == 0 {
+                Quad::NEG_ONE
+            } else {
+                Quad::NEG_INFINITY
+            })
+        } else if *self == Quad::ONE {
+            // A pole for orders 0 and 1, ζ(n) for the rest
+            Some(match n {
+                0 => Quad::NAN,
+                1 => Quad::INFINITY,
+                _ => Quad::from(n).zeta(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+// π²/6, which is ζ(2) and Li₂(1).
+fn pi2_6() -> Quad {
+    Quad::PI * Quad::PI / Quad::from(6)
+}
+
+// Calculates the dilogarithm for any finite, non-zero x. The real part is returned for
+// x > 1.
+fn li2(x: Quad) -> Quad {
+    if x < Quad::NEG_ONE {
+        // Li₂(x) = -π²/6 - ln²(-x)/2 - Li₂(1/x)
+        let ln = (-x).ln();
+        -pi2_6() - c::mul_pwr2(ln * ln, 0.5) - li2(x.recip())
+    } else if x.0 <= 0.5 {
+        li2_series(x)
+    } else if x < Quad::ONE {
+        // Li₂(x) = π²/6 - ln(x) ln(1 - x) - Li₂(1 - x)
+        let y = Quad::ONE - x;
+        pi2_6() - x.ln() * y.ln() - li2_series(y)
+    } else if x == Quad::ONE {
+        pi2_6()
+    } else {
+        // Re Li₂(x) = π²/3 - ln²(x)/2 - Li₂(1/x)
+        let ln = x.ln();
+        c::mul_pwr2(pi2_6(), 2.0) - c::mul_pwr2(ln * ln, 0.5) - li2(x.recip())
+    }
+}
+
+// Calculates the dilogarithm for x in [-1, 1/2] from the series
+//
+//      Li₂(x) = Σ Bₖ uᵏ⁺¹ / (k + 1)!,
+//
+// where u = -ln(1 - x) and Bₖ is the kth Bernoulli number. All of the odd Bernoulli numbers
+// are zero except B₁ = -1/2, so this is u - u²/4 plus a sum over the even ones.
+fn li2_series(x: Quad) -> Quad {
+    let u = -(-x).ln_1p();
+    let u2 = u * u;
+    let mut sum = u - c::mul_pwr2(u2, 0.25);
+    let mut p = u;
+    for (k, b) in c::BERNOULLI.iter().enumerate() {
+        // p = u^(2k + 3) / (2k + 3)!
+        let j = 2 * k as u32 + 2;
+        p = p * u2 / Quad::from(j * (j + 1));
+        let term = *b * p;
+        sum += term;
+        if term.abs() < Quad::EPSILON * sum.abs() {
+            break;
+        }
+    }
+    sum
+}
+
+// Calculates the polylogarithm of order n ≥ 3 for any finite, non-zero x other than 1. The
+// real part is returned for x > 1.
+fn polylog(x: Quad, n: u32) -> Quad {
+    let sign = if n % 2 == 1 { -Quad::ONE } else { Quad::ONE };
+    if x > Quad::ONE {
+        // Re Liₙ(x) = -(-1)ⁿ Liₙ(1/x) - 1/n! Σ C(n, k) Bₖ lnⁿ⁻ᵏ(x) (2π)ᵏ (-1)^(k/2),
+        // where the sum is over the even k from 0 to n
+        let ln = x.ln();
+        let mut sum = ln.powi(n as i64);
+        let two_pi2 = Quad::TAU * Quad::TAU;
+        let mut binom = Quad::ONE;
+        let mut pk = Quad::ONE;
+        for k in (2..=n).step_by(2) {
+            binom = binom * Quad::from((n - k + 2) * (n - k + 1)) / Quad::from(k * (k - 1));
+            pk *= -two_pi2;
+            let b = c::BERNOULLI[k as usize / 2 - 1];
+            sum += binom * b * ln.powi((n - k) as i64) * pk;
+        }
+        -sign * polylog(x.recip(), n) - sum / Quad::factorial(n)
+    } else if x < Quad::NEG_ONE {
+        // Liₙ(-z) = -(-1)ⁿ Liₙ(-1/z) - lnⁿ(z)/n! - 2 Σ lnⁿ⁻²ᵏ(z) / (n - 2k)! η(2k),
+        // where η(2k) = (1 - 2^(1 - 2k)) ζ(2k) and the sum runs from k = 1 to n/2
+        let ln = (-x).ln();
+        let mut sum = ln.powi(n as i64) / Quad::factorial(n);
+        for k in 1..=n / 2 {
+            let eta = (Quad::ONE - Quad::from(2).powi(1 - 2 * k as i64)) * Quad::from(2 * k).zeta();
+            sum += c::mul_pwr2(
+                ln.powi((n - 2 * k) as i64) / Quad::factorial(n - 2 * k) * eta,
+                2.0,
+            );
+        }
+        -sign * polylog(x.recip(), n) - sum
+    } else if x.0 < -0.5 {
+        // Liₙ(x) = 2¹⁻ⁿ Liₙ(x²) - Liₙ(-x)
+        Quad::from(2).powi(1 - n as i64) * polylog(x * x, n) - polylog(-x, n)
+    } else if x.0 <= 0.5 {
+        polylog_series(x, n)
+    } else if x == Quad::ONE {
+        Quad::from(n).zeta()
+    } else {
+        polylog_log_series(x, n)
+    }
+}
+
+// Calculates the polylogarithm of order n for |x| ≤ 1/2 from its defining series,
+// Σ xᵏ / kⁿ.
+fn polylog_series(x: Quad, n: u32) -> Quad {
+    let mut sum = x;
+    let mut p = x;
+    let mut k = 2;
+    loop {
+        p *= x;
+        let term = p / Quad::from(k).powi(n as i64);
+        sum += term;
+        if term.abs() < Quad::EPSILON * sum.abs() {
+            break;
+        }
+        k += 1;
+    }
+    sum
+}
+
+// Calculates the polylogarithm of order n ≥ 3 for x in (1/2, 1) from the series in
+// μ = ln x,
+//
+//      Liₙ(eᵘ) = μⁿ⁻¹ / (n - 1)! (Hₙ₋₁ - ln(-μ)) + Σ ζ(n - k) μᵏ / k!,
+//
+// where Hₙ₋₁ is a harmonic number and the sum is over all k ≥ 0 except n - 1. Past k = n,
+// ζ(n - k) is zero for even n - k and comes straight from the Bernoulli numbers for odd.
+fn polylog_log_series(x: Quad, n: u32) -> Quad {
+    let mu = x.ln();
+    let mut harmonic = Quad::ZERO;
+    for k in 1..n {
+        harmonic += Quad::from(k).recip();
+    }
+
+    let mut sum = mu.powi(n as i64 - 1) / Quad::factorial(n - 1) * (harmonic - (-mu).ln());
+    let mut p = Quad::ONE;
+    for k in 0..n + 2 * c::BERNOULLI.len() as u32 {
+        if k > 0 {
+            p = p * mu / Quad::from(k);
+        }
+        let z = if k + 1 < n {
+            Quad::from(n - k).zeta()
+        } else if k + 1 == n {
+            continue;
+        } else if k == n {
+            Quad::from(-0.5)
+        } else if (k - n) % 2 == 1 {
+            // ζ(1 - 2j) = -B₂ⱼ / 2j
+            let j = (k - n) / 2 + 1;
+            -c::BERNOULLI[j as usize - 1] / Quad::from(2 * j)
+        } else {
+            continue;
+        };
+        let term = z * p;
+        sum += term;
+        if k > n && term.abs() < Quad::EPSILON * sum.abs() {
+            break;
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // li2 tests
+    test_all_near!(
+        li2_neg_50:
+            qd!("-9.27699518533262184010035834574384094954861378499301481774664358943"),
+            qd!(-50).li2();
+        li2_neg_3:
+            qd!("-1.93937542076670895307727171917789144122259017780857842583855746675"),
+            qd!(-3).li2();
+        li2_neg_1:
+            qd!("-0.822467033424113218236207583323012594609474950603399218867779114685"),
+            qd!(-1).li2();
+        li2_neg_0_75:
+            qd!("-0.642761268839978879105290401047091623324687320033291031122920746622"),
+            qd!(-0.75).li2();
+        li2_neg_0_3:
+            qd!("-0.280074333759582904230216972305425363692289652727659160243364705205"),
+            qd!(-0.3).li2();
+        li2_0_1:
+            qd!("0.102617791099391131113837369057232213705689939419268299531531222662"),
+            qd!(0.1).li2();
+        li2_0_5:
+            qd!("0.582240526465012505902656320159680108744198474806126425434347047873"),
+            qd!(0.5).li2();
+        li2_0_7:
+            qd!("0.88937762428603873860100627480736179353714742710846901258096507526"),
+            qd!(0.7).li2();
+        li2_0_99:
+            qd!("1.58862544807637532703122947398055246794495973114212389027817344947"),
+            qd!(0.99).li2();
+        li2_1_5:
+            qd!("2.37439527027248020067749976307163842396496851431381485722140489593"),
+            qd!(1.5).li2();
+        li2_2:
+            qd!("2.46740110027233965470862274996903778382842485181019765660333734406"),
+            qd!(2).li2();
+        li2_3:
+            qd!("2.32018042331309839640619447370310465782660471350930766255183772537"),
+            qd!(3).li2();
+        li2_10:
+            qd!("0.536301287357862736550159769937809318933484823427923705260885409016"),
+            qd!(10).li2();
+        li2_1000:
+            qd!("-20.5696736135675118257215777280705391160457222778051705636713043116"),
+            qd!(1000).li2();
+    );
+    test_all_exact!(
+        li2_zero:
+            Quad::ZERO,
+            Quad::ZERO.li2();
+        li2_inf:
+            Quad::NEG_INFINITY,
+            Quad::INFINITY.li2();
+        li2_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.li2();
+        li2_nan:
+            Quad::NAN,
+            Quad::NAN.li2();
+    );
+    test_all_near!(
+        li2_one:
+            qd!("1.64493406684822643647241516664602518921894990120679843773555822937"),
+            Quad::ONE.li2();
+    );
+
+    // polylog tests
+    test_all_near!(
+        polylog_0_0_5:
+            qd!("1.0"),
+            qd!(0.5).polylog(0);
+        polylog_0_3:
+            qd!("-1.5"),
+            qd!(3).polylog(0);
+        polylog_1_0_5:
+            qd!("0.693147180559945309417232121458176568075500134360255254120680009493"),
+            qd!(0.5).polylog(1);
+        polylog_1_neg_2:
+            qd!("-1.09861228866810969139524523692252570464749055782274945173469433364"),
+            qd!(-2).polylog(1);
+        polylog_1_3:
+            qd!("-0.693147180559945309417232121458176568075500134360255254120680009493"),
+            qd!(3).polylog(1);
+        polylog_3_neg_10:
+            qd!("-5.92106480375697349135192820931717378285816961658658988779975928944"),
+            qd!(-10).polylog(3);
+        polylog_3_neg_0_75:
+            qd!("-0.691703603690459451014144842662923096867407047353013222013052891812"),
+            qd!(-0.75).polylog(3);
+        polylog_3_0_25:
+            qd!("0.258461395796573305288000129873672612021625353527988047475840814151"),
+            qd!(0.25).polylog(3);
+        polylog_3_0_9:
+            qd!("1.04965895018643986964583249321010007043835542898355233615857273354"),
+            qd!(0.9).polylog(3);
+        polylog_3_2:
+            qd!("2.76207190622892413593664067981104275974430776784491490039927057305"),
+            qd!(2).polylog(3);
+        polylog_3_100:
+            qd!("-1.11701355602252896300480975515362668729412149594385857172456677682"),
+            qd!(100).polylog(3);
+        polylog_4_neg_3:
+            qd!("-2.62063470190976350339994139184746435479195495026406063680621680252"),
+            qd!(-3).polylog(4);
+        polylog_4_0_5:
+            qd!("0.517479061673899386330758161898862945622377475141379258244319347977"),
+            qd!(0.5).polylog(4);
+        polylog_4_0_8:
+            qd!("0.848821191281712587529879683813306651009031964001372110530118613792"),
+            qd!(0.8).polylog(4);
+        polylog_4_5:
+            qd!("5.94333094976815014135277417785071370552672708380018298602063335903"),
+            qd!(5).polylog(4);
+        polylog_6_neg_0_6:
+            qd!("-0.594643820611845447600083563958459191057986908292524900174092298404"),
+            qd!(-0.6).polylog(6);
+        polylog_6_0_75:
+            qd!("0.759465774303958334168448940266771817897544924250365551559961126965"),
+            qd!(0.75).polylog(6);
+        polylog_6_20:
+            qd!("21.7342313026248123397742727703561168194342982119294510106108133034"),
+            qd!(20).polylog(6);
+    );
+    test_all_near!(
+        polylog_3_one:
+            qd!("1.20205690315959428539973816151144999076498629234049888179227155534"),
+            Quad::ONE.polylog(3);
+        polylog_5_one:
+            qd!("1.0369277551433699263313654864570341680570809195019128119741926779"),
+            Quad::ONE.polylog(5);
+    );
+    test_all_exact!(
+        polylog_0_one:
+            Quad::NAN,
+            Quad::ONE.polylog(0);
+        polylog_1_one:
+            Quad::INFINITY,
+            Quad::ONE.polylog(1);
+        polylog_0_inf:
+            Quad::NEG_ONE,
+            Quad::INFINITY.polylog(0);
+        polylog_3_inf:
+            Quad::NEG_INFINITY,
+            Quad::INFINITY.polylog(3);
+        polylog_3_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.polylog(3);
+        polylog_3_zero:
+            Quad::ZERO,
+            Quad::ZERO.polylog(3);
+        polylog_3_nan:
+            Quad::NAN,
+            Quad::NAN.polylog(3);
+    );
+    test!(polylog_2_is_li2: {
+        for x in [-4.0, -0.6, 0.3, 0.8, 7.0] {
+            exact!(Quad::from(x).polylog(2), Quad::from(x).li2());
+        }
+    });
+}
@@ -189,6 +189,31 @@ impl RemAssign<&Quad> for Quad {
     }
 }
 
+impl Quad {
+    /// Calculates the remainder of dividing this `Quad` by another, returning `None` if the
+    /// result is not finite.
+    ///
+    /// This mirrors the `checked_rem` methods of the primitive integer types. The result is
+    /// `None` if the divisor is zero or the result is `NaN`; this is always the case when
+    /// either argument is already infinite or `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.checked_rem(Quad::E).is_some());
+    /// assert!(Quad::ONE.checked_rem(Quad::ZERO).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_rem(self, other: Quad) -> Option<Quad> {
+        let r = self % other;
+        if r.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -303,4 +328,12 @@ mod tests {
             near!(qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"), b);
         }
     );
+
+    // checked_rem tests
+    test_all_assert!(
+        checked_rem_num: Quad::PI.checked_rem(Quad::E) == Some(Quad::PI % Quad::E);
+        checked_rem_nan: Quad::PI.checked_rem(Quad::NAN).is_none();
+        checked_rem_inf: Quad::INFINITY.checked_rem(Quad::PI).is_none();
+        checked_rem_overflow: Quad::ONE.checked_rem(Quad::ZERO).is_none();
+    );
 }
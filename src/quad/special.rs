@@ -0,0 +1,1689 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+// The argument past which the asymptotic series for the digamma function is accurate to full
+// precision without needing any more terms than there are in the Bernoulli table.
+const DIGAMMA_CUTOFF: f64 = 60.0;
+
+// The argument below which erf is calculated directly from its power series. Above it, erf
+// is calculated from erfc, which is small enough by then that subtracting it from 1 costs
+// nothing.
+const ERF_SERIES_LIMIT: f64 = 2.0;
+
+// The argument below which erfc is calculated as 1 - erf, which loses less than a digit
+// there. Above it, erfc is calculated from a continued fraction, which converges too slowly
+// for smaller arguments.
+const ERFC_SERIES_LIMIT: f64 = 1.0;
+
+// The number of terms summed directly in the Euler-Maclaurin formula for the zeta function,
+// beyond the argument itself. This is enough for the correction terms to converge to full
+// precision within the Bernoulli table.
+const ZETA_TERMS: f64 = 40.0;
+
+// The argument at or below which the Fresnel integrals are calculated from their power
+// series. Past this point, cancellation between the terms starts to cost precision.
+const FRESNEL_SERIES_LIMIT: f64 = 1.0;
+
+// The argument past which the auxiliary functions for the Fresnel integrals are calculated
+// from their asymptotic expansions rather than a continued fraction. The smallest term in
+// those expansions is around e^(-πx²/2), so this is far enough out that they reach full
+// precision.
+const FRESNEL_ASYMPTOTIC_LIMIT: f64 = 11.0;
+
+impl Quad {
+    /// Calculates the digamma function (ψ) of the `Quad`.
+    ///
+    /// The digamma function is the logarithmic derivative of the gamma function, Γ'(x) /
+    /// Γ(x). Small arguments are shifted upward with the recurrence ψ(x) = ψ(x + 1) - 1 / x
+    /// until the asymptotic series in terms of the Bernoulli numbers converges to full
+    /// precision, and negative arguments are handled with the reflection formula ψ(x) = ψ(1
+    /// - x) - π cot(πx).
+    ///
+    /// The domain of this function is (-∞, ∞) except for zero and the negative integers,
+    /// where the function has poles. Any of those arguments will result in [`NAN`], as will
+    /// negative infinity. The range is (-∞, ∞).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(1).digamma(); // -γ, the Euler-Mascheroni constant
+    /// let expected = qd!("-0.5772156649015328606065120900824024310421593359399235988057672348848677");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn digamma(self) -> Quad {
+        match self.pre_digamma() {
+            Some(r) => r,
+            None => {
+                if self.is_sign_negative() {
+                    let (s, c) = self.sin_cos_pi();
+                    (Quad::ONE - self).digamma() - Quad::PI * c / s
+                } else {
+                    let mut x = self;
+                    let mut acc = Quad::ZERO;
+                    while x.0 < DIGAMMA_CUTOFF {
+                        acc -= x.recip();
+                        x += Quad::ONE;
+                    }
+
+                    let z = (x * x).recip();
+                    let mut zk = z;
+                    let mut sum = Quad::ZERO;
+                    for (k, b) in c::BERNOULLI.iter().enumerate() {
+                        let term = *b * zk / Quad::from(2 * k as u32 + 2);
+                        sum += term;
+                        if term.abs() < Quad::EPSILON * sum.abs() {
+                            break;
+                        }
+                        zk *= z;
+                    }
+                    acc + x.ln() - c::mul_pwr2(x.recip(), 0.5) - sum
+                }
+            }
+        }
+    }
+
+    /// Calculates the polygamma function of order `n` (ψ<sup>(n)</sup>) of the `Quad`.
+    ///
+    /// The polygamma function of order `n` is the `n`th derivative of the [`digamma`]
+    /// function, so `polygamma(0)` is the same as `digamma()`, `polygamma(1)` is the
+    /// trigamma function, and so on. Like the digamma function, it's calculated by
+    /// shifting the argument upward with a recurrence until an asymptotic series converges,
+    /// and negative arguments are handled with a reflection formula, which requires the
+    /// `n`th derivative of π cot(πx).
+    ///
+    /// This is meant for small orders. The factorials in the series grow quickly, so
+    /// precision drops off as `n` gets larger and the result overflows for very large `n`.
+    ///
+    /// The domain of this function is (-∞, ∞) except for zero and the negative integers,
+    /// where the function has poles. Any of those arguments will result in [`NAN`], as will
+    /// negative infinity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(1).polygamma(1); // π² / 6
+    /// let expected = qd!("1.6449340668482264364724151666460251892189499012067984377355582");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`digamma`]: #method.digamma
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn polygamma(self, n: u32) -> Quad {
+        match self.pre_polygamma(n) {
+            Some(r) => r,
+            None => {
+                if self.is_sign_negative() {
+                    // ψ⁽ⁿ⁾(x) = (-1)ⁿ ψ⁽ⁿ⁾(1 - x) - πⁿ⁺¹ Pₙ(cot πx), where Pₙ is the
+                    // polynomial with P₀(t) = t and Pₖ₊₁(t) = -(1 + t²) Pₖ'(t)
+                    let mut poly = vec![Quad::ZERO, Quad::ONE];
+                    for _ in 0..n {
+                        let mut next = vec![Quad::ZERO; poly.len() + 1];
+                        for i in 1..poly.len() {
+                            let d = poly[i] * Quad::from(i as u32);
+                            next[i - 1] -= d;
+                            next[i + 1] -= d;
+                        }
+                        poly = next;
+                    }
+                    let (s, c) = self.sin_cos_pi();
+                    let t = c / s;
+                    let p = poly.iter().rev().fold(Quad::ZERO, |acc, &k| acc * t + k);
+
+                    let r = (Quad::ONE - self).polygamma(n);
+                    let r = if n % 2 == 1 { -r } else { r };
+                    r - Quad::PI.powi(n as i64 + 1) * p
+                } else {
+                    let mut x = self;
+                    let mut acc = Quad::ZERO;
+                    while x.0 < DIGAMMA_CUTOFF + n as f64 {
+                        acc += x.recip().powi(n as i64 + 1);
+                        x += Quad::ONE;
+                    }
+
+                    // (n - 1)! and n!
+                    let mut fact = Quad::ONE;
+                    for i in 2..n {
+                        fact *= Quad::from(i);
+                    }
+                    let fact_n = fact * Quad::from(n);
+
+                    let xr = x.recip();
+                    let z = xr * xr;
+                    let xn = xr.powi(n as i64);
+                    let mut sum = fact * xn + c::mul_pwr2(fact_n * xn * xr, 0.5);
+
+                    // (2k + n - 1)! / (2k)!, starting at k = 1
+                    let mut coeff = c::mul_pwr2(fact_n * Quad::from(n + 1), 0.5);
+                    let mut zk = xn * z;
+                    for (k, b) in c::BERNOULLI.iter().enumerate() {
+                        let term = *b * coeff * zk;
+                        sum += term;
+                        if term.abs() < Quad::EPSILON * sum.abs() {
+                            break;
+                        }
+                        let j = 2 * k as u32 + 2;
+                        coeff *= Quad::from((j + n) * (j + n + 1)) / Quad::from((j + 1) * (j + 2));
+                        zk *= z;
+                    }
+
+                    let r = fact_n * acc + sum;
+                    if n % 2 == 1 {
+                        r
+                    } else {
+                        -r
+                    }
+                }
+            }
+        }
+    }
+
+    /// Calculates the error function (erf) of the `Quad`.
+    ///
+    /// Small arguments are calculated with a power series. For larger arguments, this is
+    /// calculated as 1 - [`erfc`], which loses nothing because erfc is small there.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).erf();
+    /// let expected = qd!("0.8427007929497148693412206350826092592960669979663029084599378978");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`erfc`]: #method.erfc
+    pub fn erf(self) -> Quad {
+        match self.pre_erf() {
+            Some(r) => r,
+            None => {
+                let a = self.abs();
+                let r = if a.0 < ERF_SERIES_LIMIT {
+                    erf_series(a)
+                } else {
+                    Quad::ONE - erfc_scaled(a) * exp_neg_square(a, 1.0)
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Calculates the complementary error function (erfc) of the `Quad`, which is
+    /// 1 - [`erf`].
+    ///
+    /// For all but small arguments, this is calculated directly from a continued fraction
+    /// rather than by subtracting from 1, so the result keeps its full precision far into
+    /// the tail, where erf is indistinguishable from 1. The factor e<sup>-x²</sup> is
+    /// computed by splitting x² into exact pieces, since rounding x² alone would cost
+    /// several digits when x is large.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [0, 2].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(5).erfc();
+    /// let expected = qd!("1.537459794428034850188343485383378890118050314723379930687914e-12");
+    ///
+    /// let diff = ((x - expected) / expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`erf`]: #method.erf
+    pub fn erfc(self) -> Quad {
+        match self.pre_erfc() {
+            Some(r) => r,
+            None => {
+                let a = self.abs();
+                if a.0 < ERFC_SERIES_LIMIT {
+                    Quad::ONE - self.erf()
+                } else {
+                    let r = erfc_scaled(a) * exp_neg_square(a, 1.0);
+                    if self.is_sign_negative() {
+                        Quad::from(2) - r
+                    } else {
+                        r
+                    }
+                }
+            }
+        }
+    }
+
+    /// Calculates the cumulative distribution function of the standard normal distribution
+    /// (Φ) at the `Quad`.
+    ///
+    /// This is the probability that a normally distributed random variable with mean 0 and
+    /// standard deviation 1 is less than the argument, Φ(x) = erfc(-x / √2) / 2. In the
+    /// lower tail, the continued fraction for [`erfc`] is used directly with
+    /// e<sup>-x²/2</sup> computed from `x` itself, so the result keeps its full relative
+    /// precision all the way down to the bottom of the floating-point range (around
+    /// 10<sup>-300</sup>, reached at x ≈ -37). Precision does drop off once the result gets
+    /// small enough that its lower components are subnormal.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [0, 1].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(-10).norm_cdf();
+    /// let expected = qd!("7.619853024160526065973343251599308363504033277956960578035355463e-24");
+    ///
+    /// let diff = ((x - expected) / expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`erfc`]: #method.erfc
+    pub fn norm_cdf(self) -> Quad {
+        match self.pre_norm_cdf() {
+            Some(r) => r,
+            None => {
+                let z = -self * Quad::FRAC_1_SQRT_2;
+                if z.0.abs() < ERFC_SERIES_LIMIT {
+                    c::mul_pwr2(z.erfc(), 0.5)
+                } else {
+                    let t = c::mul_pwr2(erfc_scaled(z.abs()) * exp_neg_square(self, 0.5), 0.5);
+                    if z.is_sign_negative() {
+                        Quad::ONE - t
+                    } else {
+                        t
+                    }
+                }
+            }
+        }
+    }
+
+    /// Calculates the quantile function of the standard normal distribution
+    /// (Φ<sup>-1</sup>) at the `Quad`, which is the inverse of [`norm_cdf`].
+    ///
+    /// This starts with a rational approximation good to about 9 digits and then refines
+    /// it with Halley's method against [`norm_cdf`], so it's accurate into the far tails.
+    /// Arguments greater than 1/2 are handled through the symmetry Φ<sup>-1</sup>(p) =
+    /// -Φ<sup>-1</sup>(1 - p), so the precision of results for arguments very near 1 is
+    /// limited by how well 1 - p is represented.
+    ///
+    /// The domain of this function is [0, 1], and the range is [-∞, ∞]. Any argument
+    /// outside of the domain will result in [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(0.975).norm_quantile();
+    /// let expected = qd!("1.959963984540054235524594430520551527955550077869548398476952646");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`norm_cdf`]: #method.norm_cdf
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn norm_quantile(self) -> Quad {
+        match self.pre_norm_quantile() {
+            Some(r) => r,
+            None => {
+                let (p, flip) = if self.0 > 0.5 {
+                    (Quad::ONE - self, true)
+                } else {
+                    (self, false)
+                };
+
+                let x0 = approx_norm_quantile(p.0);
+                let mut x = Quad::new(x0, 0.0, 0.0, 0.0);
+                // 1 / √(2π), the normalizing factor of the normal density
+                let k = c::mul_pwr2(Quad::FRAC_1_SQRT_2 * Quad::FRAC_2_SQRT_PI, 0.5);
+                for _ in 0..10 {
+                    // Near the center, Φ(x) - p is computed as erf(x / √2) / 2 - (p - 1/2) so
+                    // that both sides are small and nothing cancels
+                    let diff = if p.0 > 0.25 {
+                        c::mul_pwr2((x * Quad::FRAC_1_SQRT_2).erf(), 0.5) - (p - Quad::from(0.5))
+                    } else {
+                        x.norm_cdf() - p
+                    };
+                    let e = diff / (k * exp_neg_square(x, 0.5));
+                    let dx = e / (Quad::ONE + c::mul_pwr2(x * e, 0.5));
+                    x -= dx;
+                    if dx.abs() <= Quad::EPSILON * x.abs() {
+                        break;
+                    }
+                }
+
+                if flip {
+                    -x
+                } else {
+                    x
+                }
+            }
+        }
+    }
+
+    /// Calculates the beta function (B) of the `Quad` and another `Quad`.
+    ///
+    /// This is Γ(a)Γ(b) / Γ(a + b), calculated through the logarithms of the gamma
+    /// functions so that it doesn't overflow for large arguments. Since the logarithms are
+    /// exponentiated at the end, some precision is lost as the arguments (and therefore
+    /// their log-gamma values) get large.
+    ///
+    /// Both arguments must be positive. If either is zero, negative, or [`NAN`], the result
+    /// will be [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(2.5).beta(qd!(1.5));
+    /// let expected = qd!("0.196349540849362077403915211454968930262323087460944113810934037");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn beta(self, b: Quad) -> Quad {
+        match self.pre_beta(&b) {
+            Some(r) => r,
+            None => ln_beta(self, b).exp(),
+        }
+    }
+
+    /// Calculates the regularized incomplete beta function (I<sub>x</sub>(a, b)) of the
+    /// `Quad`, which is the upper limit of integration `x`.
+    ///
+    /// This is the integral of t<sup>a - 1</sup>(1 - t)<sup>b - 1</sup> from 0 to `x`,
+    /// divided by [`beta`]`(a, b)`. It's the cumulative distribution function of the beta
+    /// distribution, and the CDFs of the t- and F-distributions are calculated from it.
+    /// It's evaluated with a continued fraction, using the symmetry
+    /// I<sub>x</sub>(a, b) = 1 - I<sub>1 - x</sub>(b, a) to stay in the region where the
+    /// continued fraction converges quickly.
+    ///
+    /// `a` and `b` must be positive, and `x` must be in [0, 1]. Any other arguments will
+    /// result in [`NAN`]. The range is [0, 1].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(0.4).betainc(qd!(2), qd!(3));
+    /// let expected = qd!("0.5248");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`beta`]: #method.beta
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn betainc(self, a: Quad, b: Quad) -> Quad {
+        match self.pre_betainc(&a, &b) {
+            Some(r) => r,
+            None => {
+                // x^a (1 - x)^b / B(a, b), the factor in front of the continued fraction
+                let front = (a * self.ln() + b * (-self).ln_1p() - ln_beta(a, b)).exp();
+                if self < (a + Quad::ONE) / (a + b + Quad::from(2)) {
+                    front * betainc_cf(a, b, self) / a
+                } else {
+                    Quad::ONE - front * betainc_cf(b, a, Quad::ONE - self) / b
+                }
+            }
+        }
+    }
+
+    /// Calculates the Riemann zeta function (ζ) of the `Quad`.
+    ///
+    /// For arguments of at least 0, this uses the Euler-Maclaurin formula, directly summing
+    /// the first several terms of Σ k<sup>-s</sup> and correcting for the rest with
+    /// Bernoulli numbers. Negative arguments are handled with the reflection formula
+    /// ζ(s) = 2<sup>s</sup> π<sup>s - 1</sup> sin(πs/2) Γ(1 - s) ζ(1 - s).
+    ///
+    /// The domain of this function is (-∞, ∞) except for 1, where there is a pole. That
+    /// argument will result in [`NAN`], as will negative infinity. The result overflows
+    /// for negative arguments much past -170, other than the negative even integers (the
+    /// trivial zeros). At the negative odd integers, the result is taken directly from the
+    /// Bernoulli numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(3).zeta(); // Apéry's constant
+    /// let expected = qd!("1.202056903159594285399738161511449990764986292340498881792271555");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn zeta(self) -> Quad {
+        match self.pre_zeta() {
+            Some(r) => r,
+            None => {
+                if self.is_sign_negative() {
+                    let t = Quad::ONE - self;
+                    let (sin, _) = c::mul_pwr2(self, 0.5).sin_cos_pi();
+                    let mag =
+                        (self * Quad::LN_2 + (self - Quad::ONE) * Quad::PI.ln() + ln_gamma(t))
+                            .exp();
+                    mag * sin * t.zeta()
+                } else {
+                    zeta_em(self)
+                }
+            }
+        }
+    }
+
+    /// Calculates the Fresnel sine integral (S) of the `Quad`.
+    ///
+    /// This is the integral of sin(πt²/2) from 0 to the argument. Small arguments are
+    /// calculated from the power series. Larger ones are calculated from the auxiliary
+    /// functions f and g (S(x) = 1/2 - f(x) cos(πx²/2) - g(x) sin(πx²/2)), which come from
+    /// a continued fraction or, for large arguments, from their asymptotic expansions. The
+    /// argument πx²/2 is reduced without rounding x², so the result stays accurate for
+    /// large arguments.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is about [-0.7139, 0.7139].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1.5).fresnel_s();
+    /// let expected = qd!("0.6975049600820930130806551631872683329447691213792866001336007244");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn fresnel_s(self) -> Quad {
+        match self.pre_fresnel_s() {
+            Some(r) => r,
+            None => {
+                let (s, _) = fresnel(self.abs());
+                if self.is_sign_negative() {
+                    -s
+                } else {
+                    s
+                }
+            }
+        }
+    }
+
+    /// Calculates the Fresnel cosine integral (C) of the `Quad`.
+    ///
+    /// This is the integral of cos(πt²/2) from 0 to the argument. Small arguments are
+    /// calculated from the power series. Larger ones are calculated from the auxiliary
+    /// functions f and g (C(x) = 1/2 + f(x) sin(πx²/2) - g(x) cos(πx²/2)), which come from
+    /// a continued fraction or, for large arguments, from their asymptotic expansions. The
+    /// argument πx²/2 is reduced without rounding x², so the result stays accurate for
+    /// large arguments.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is about [-0.7799, 0.7799].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1.5).fresnel_c();
+    /// let expected = qd!("0.4452611760398215350645510097420897821594020577560995201341740322");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn fresnel_c(self) -> Quad {
+        match self.pre_fresnel_c() {
+            Some(r) => r,
+            None => {
+                let (_, c) = fresnel(self.abs());
+                if self.is_sign_negative() {
+                    -c
+                } else {
+                    c
+                }
+            }
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_digamma(&self) -> Option<Quad> {
+        if self.is_nan() || *self == Quad::NEG_INFINITY {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(Quad::INFINITY)
+        } else if self.is_sign_negative() && self.floor() == *self || self.is_zero() {
+            // Poles at zero and at every negative integer
+            Some(Quad::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_polygamma(&self, n: u32) -> Option<Quad> {
+        if n == 0 {
+            Some(self.digamma())
+        } else if self.is_nan() || *self == Quad::NEG_INFINITY {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(Quad::ZERO)
+        } else if self.is_sign_negative() && self.floor() == *self || self.is_zero() {
+            Some(Quad::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_erf(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if self.abs().0 > 27.0 {
+            // erfc underflows here, so erf is exactly ±1
+            Some(if self.is_sign_negative() {
+                Quad::NEG_ONE
+            } else {
+                Quad::ONE
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_erfc(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad::ONE)
+        } else if self.0 > 27.3 {
+            Some(Quad::ZERO)
+        } else if self.0 < -27.0 {
+            Some(Quad::from(2))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_norm_cdf(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad::from(0.5))
+        } else if self.0 < -38.5 {
+            // The result underflows past this point
+            Some(Quad::ZERO)
+        } else if self.0 > 38.5 {
+            Some(Quad::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_norm_quantile(&self) -> Option<Quad> {
+        if self.is_nan() || *self < Quad::ZERO || *self > Quad::ONE {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad::NEG_INFINITY)
+        } else if *self == Quad::ONE {
+            Some(Quad::INFINITY)
+        } else if self.0 == 0.5 && self[1] == 0.0 {
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_beta(&self, b: &Quad) -> Option<Quad> {
+        if self.is_nan() || b.is_nan() || *self <= Quad::ZERO || *b <= Quad::ZERO {
+            Some(Quad::NAN)
+        } else if self.is_infinite() || b.is_infinite() {
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_betainc(&self, a: &Quad, b: &Quad) -> Option<Quad> {
+        if self.is_nan()
+            || a.is_nan()
+            || b.is_nan()
+            || *a <= Quad::ZERO
+            || *b <= Quad::ZERO
+            || a.is_infinite()
+            || b.is_infinite()
+            || *self < Quad::ZERO
+            || *self > Quad::ONE
+        {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad::ZERO)
+        } else if *self == Quad::ONE {
+            Some(Quad::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_zeta(&self) -> Option<Quad> {
+        if self.is_nan() || *self == Quad::NEG_INFINITY || *self == Quad::ONE {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(Quad::ONE)
+        } else if self.is_zero() {
+            Some(Quad::from(-0.5))
+        } else if self.is_sign_negative()
+            && c::mul_pwr2(*self, 0.5).floor() == c::mul_pwr2(*self, 0.5)
+        {
+            // The trivial zeros at the negative even integers
+            Some(Quad::ZERO)
+        } else if self.is_sign_negative()
+            && self.floor() == *self
+            && -self.0 < 2.0 * c::BERNOULLI.len() as f64
+        {
+            // ζ(1 - 2k) = -B₂ₖ / 2k exactly at the negative odd integers
+            let k = (1.0 - self.0) as usize / 2;
+            Some(-c::BERNOULLI[k - 1] / Quad::from(2 * k as u32))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_fresnel_s(&self) -> Option<Quad> {
+        self.pre_fresnel_c()
+    }
+
+    #[inline]
+    fn pre_fresnel_c(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if self.is_infinite() {
+            Some(if self.is_sign_positive() {
+                Quad::from(0.5)
+            } else {
+                Quad::from(-0.5)
+            })
+        } else {
+            None
+        }
+    }
+}
+
+// Calculates erf(x) for small non-negative x from the power series
+//
+//      erf(x) = 2x / √π e^(-x²) Σ (2x²)ⁿ / (1 · 3 · 5 ⋯ (2n + 1)),
+//
+// which, unlike the Taylor series, has no alternating signs to cancel each other out.
+fn erf_series(x: Quad) -> Quad {
+    let x2 = c::mul_pwr2(x * x, 2.0);
+    let mut term = Quad::ONE;
+    let mut sum = Quad::ONE;
+    let mut k = 1;
+    loop {
+        term = term * x2 / Quad::from(2 * k + 1);
+        sum += term;
+        if term < Quad::EPSILON * sum {
+            break;
+        }
+        k += 1;
+    }
+    Quad::FRAC_2_SQRT_PI * x * exp_neg_square(x, 1.0) * sum
+}
+
+// Calculates e^(x²) erfc(x) for x ≥ 1 from the continued fraction
+//
+//      erfc(x) = e^(-x²) / √π · 2x / (2x² + 1 - 1·2 / (2x² + 5 - 3·4 / (2x² + 9 - ⋯))).
+//
+// This is evaluated from the back, which is faster and accumulates less rounding error than
+// evaluating from the front. The number of terms that it takes to converge to full
+// precision falls off with the square of x; the count here was found experimentally, with a
+// small margin added.
+fn erfc_scaled(x: Quad) -> Quad {
+    let b0 = c::mul_pwr2(x * x, 2.0) + Quad::ONE;
+    let n = (1500.0 / (x.0 * x.0)) as u32 + 30;
+    let mut t = Quad::ZERO;
+    for k in (1..=n).rev() {
+        t = Quad::from((2 * k - 1) * 2 * k) / (b0 + Quad::from(4 * k) - t);
+    }
+    Quad::FRAC_2_SQRT_PI * x / (b0 - t)
+}
+
+// Calculates e^(-sx²), where s is a power of two. x is split into a part with few enough
+// bits that its square is exact and a small remainder, so the exponent isn't rounded before
+// it's exponentiated; that rounding would be magnified by the size of x².
+fn exp_neg_square(x: Quad, s: f64) -> Quad {
+    let hi = c::mul_pwr2(c::mul_pwr2(x, 1048576.0).floor(), 1.0 / 1048576.0);
+    let lo = x - hi;
+    let e_hi = -c::mul_pwr2(hi * hi, s);
+    let e_lo = -c::mul_pwr2(lo * (x + hi), s);
+    e_hi.exp() * e_lo.exp()
+}
+
+// Approximates the quantile function of the standard normal distribution for 0 < p ≤ 1/2,
+// using Peter Acklam's rational approximations. The relative error is less than 1.15e-9,
+// which makes this a good starting point for refinement.
+fn approx_norm_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 6] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+        1.0,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 5] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+        1.0,
+    ];
+
+    let horner = |coeffs: &[f64], x: f64| coeffs.iter().fold(0.0, |acc, &k| acc * x + k);
+    if p < 0.02425 {
+        let q = (-2.0 * p.ln()).sqrt();
+        horner(&C, q) / horner(&D, q)
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        q * horner(&A, r) / horner(&B, r)
+    }
+}
+
+// Calculates the natural logarithm of the gamma function for positive x. The argument is
+// shifted upward with the recurrence Γ(x) = Γ(x + 1) / x until Stirling's series, in terms
+// of the Bernoulli numbers, converges to full precision.
+fn ln_gamma(x: Quad) -> Quad {
+    let mut x = x;
+    let mut prod = Quad::ONE;
+    while x.0 < DIGAMMA_CUTOFF {
+        prod *= x;
+        x += Quad::ONE;
+    }
+
+    let z = (x * x).recip();
+    let mut xk = x.recip();
+    let mut sum = Quad::ZERO;
+    for (k, b) in c::BERNOULLI.iter().enumerate() {
+        let n = 2 * k as u32 + 2;
+        let term = *b * xk / Quad::from(n * (n - 1));
+        sum += term;
+        if term.abs() < Quad::EPSILON * sum.abs() {
+            break;
+        }
+        xk *= z;
+    }
+
+    let half_ln_2pi = c::mul_pwr2(Quad::TAU.ln(), 0.5);
+    (x - Quad::from(0.5)) * x.ln() - x + half_ln_2pi + sum - prod.ln()
+}
+
+// Calculates the natural logarithm of the beta function for positive a and b.
+fn ln_beta(a: Quad, b: Quad) -> Quad {
+    ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b)
+}
+
+// Evaluates the continued fraction for the incomplete beta function with the modified Lentz
+// method. This converges quickly for x < (a + 1) / (a + b + 2).
+fn betainc_cf(a: Quad, b: Quad, x: Quad) -> Quad {
+    // Stands in for zero in denominators so that the method doesn't divide by zero
+    let tiny = Quad::new(1e-300, 0.0, 0.0, 0.0);
+    let fix = |v: Quad| if v.abs() < tiny { tiny } else { v };
+
+    let qab = a + b;
+    let qap = a + Quad::ONE;
+    let qam = a - Quad::ONE;
+    let mut cf = Quad::ONE;
+    let mut d = fix(Quad::ONE - qab * x / qap).recip();
+    let mut h = d;
+
+    for m in 1..10000 {
+        let m = Quad::from(m);
+        let m2 = c::mul_pwr2(m, 2.0);
+
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = fix(Quad::ONE + aa * d).recip();
+        cf = fix(Quad::ONE + aa / cf);
+        h *= d * cf;
+
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = fix(Quad::ONE + aa * d).recip();
+        cf = fix(Quad::ONE + aa / cf);
+        let delta = d * cf;
+        h *= delta;
+
+        if (delta - Quad::ONE).abs() < Quad::EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+// Calculates ζ(s) for s ≥ 0 with the Euler-Maclaurin formula,
+//
+//      ζ(s) = Σ k^(-s) + N^(1 - s) / (s - 1) + N^(-s) / 2
+//             + Σ B₂ⱼ / (2j)! s(s + 1)⋯(s + 2j - 2) N^(-s - 2j + 1),
+//
+// where the first sum runs from 1 to N - 1. N grows with s so that the second sum always
+// converges. For large s, the terms of the first sum fall off quickly enough that it can be
+// cut short, and then the rest of the formula is negligible.
+fn zeta_em(s: Quad) -> Quad {
+    // Integer powers are much more accurate than the exponential of a logarithm
+    let int = if s.floor() == s && s.0 < 1e9 {
+        Some(s.0 as i64)
+    } else {
+        None
+    };
+    let pow = |k: Quad, offset: i64| match int {
+        Some(n) => k.powi(offset - n),
+        None => ((-s + Quad::from(offset as f64)) * k.ln()).exp(),
+    };
+
+    let n = (ZETA_TERMS + s.0.ceil()) as u32;
+    let mut sum = Quad::ONE;
+    for k in 2..n {
+        let term = pow(Quad::from(k), 0);
+        sum += term;
+        if term < Quad::EPSILON * sum {
+            return sum;
+        }
+    }
+
+    let big_n = Quad::from(n);
+    let n_s = pow(big_n, 0);
+    sum += pow(big_n, 1) / (s - Quad::ONE) + c::mul_pwr2(n_s, 0.5);
+
+    let n2 = (big_n * big_n).recip();
+    // s(s + 1)⋯(s + 2j - 2) N^(-s - 2j + 1) / (2j)!, starting at j = 1
+    let mut fac = c::mul_pwr2(s * n_s / big_n, 0.5);
+    for (j, b) in c::BERNOULLI.iter().enumerate() {
+        let term = *b * fac;
+        sum += term;
+        if term.abs() < Quad::EPSILON * sum.abs() {
+            break;
+        }
+        let j2 = 2 * j as u32 + 2;
+        fac *=
+            (s + Quad::from(j2 - 1)) * (s + Quad::from(j2)) * n2 / Quad::from((j2 + 1) * (j2 + 2));
+    }
+    sum
+}
+
+// Calculates the Fresnel integrals S(x) and C(x) for positive x.
+fn fresnel(x: Quad) -> (Quad, Quad) {
+    if x.0 <= FRESNEL_SERIES_LIMIT {
+        return fresnel_series(x);
+    }
+    let (f, g) = if x.0 > FRESNEL_ASYMPTOTIC_LIMIT {
+        fresnel_asymptotic_f_g(x)
+    } else {
+        fresnel_cf_f_g(x)
+    };
+    let (sin, cos) = sin_cos_half_pi_square(x);
+    let half = Quad::from(0.5);
+    (half - f * cos - g * sin, half + f * sin - g * cos)
+}
+
+// Calculates S(x) and C(x) for 0 < x ≤ 1 from the power series. With t = πx²/2,
+//
+//      S(x) = x Σ (-1)ᵏ t²ᵏ⁺¹ / ((2k + 1)! (4k + 3))
+//      C(x) = x Σ (-1)ᵏ t²ᵏ / ((2k)! (4k + 1)).
+fn fresnel_series(x: Quad) -> (Quad, Quad) {
+    let t = c::mul_pwr2(Quad::PI * x * x, 0.5);
+    let mut s = Quad::ZERO;
+    let mut cc = x;
+    let mut p = Quad::ONE;
+    let mut j = 1;
+    loop {
+        // p = ±tʲ / j!, with the sign changing at every even j
+        p = p * t / Quad::from(j);
+        if j % 2 == 0 {
+            p = -p;
+        }
+        let term = p * x / Quad::from(2 * j + 1);
+        if j % 2 == 1 {
+            s += term;
+        } else {
+            cc += term;
+        }
+        if term.abs() < Quad::EPSILON * s.abs() {
+            break;
+        }
+        j += 1;
+    }
+    (s, cc)
+}
+
+// Calculates the auxiliary functions f(x) and g(x) for the Fresnel integrals, for x > 1.
+// These come from the continued fraction for erfc at the complex argument (1 - i)√π x/2,
+// evaluated with the modified Lentz algorithm. If h is the value of the continued fraction,
+// then f(x) and g(x) are the imaginary and real parts of xh.
+fn fresnel_cf_f_g(x: Quad) -> (Quad, Quad) {
+    // This is only as small as it is because its reciprocal still has to be squared
+    let tiny = Quad::from(1e-150);
+    let four = Quad::from(4);
+    let mut b = (Quad::ONE, -Quad::PI * x * x);
+    let mut cc = (tiny.recip(), Quad::ZERO);
+    let mut d = c::complex_recip(b);
+    let mut h = d;
+    let mut n = 1;
+    loop {
+        let a = -Quad::from(n * (n + 1));
+        b.0 += four;
+        let ad = (a * d.0 + b.0, a * d.1 + b.1);
+        d = c::complex_recip(ad);
+        let ac = c::complex_recip(cc);
+        cc = (b.0 + a * ac.0, b.1 + a * ac.1);
+        let delta = c::complex_mul(cc, d);
+        h = c::complex_mul(h, delta);
+        if (delta.0 - Quad::ONE).abs() + delta.1.abs() < Quad::EPSILON {
+            break;
+        }
+        n += 2;
+    }
+    (x * h.1, x * h.0)
+}
+
+// Calculates the auxiliary functions f(x) and g(x) for the Fresnel integrals, for large x,
+// from their asymptotic expansions. With y = πx²,
+//
+//      f(x) ~ 1/(πx) Σ (-1)ᵏ (4k - 1)!! / y²ᵏ
+//      g(x) ~ 1/(πx) Σ (-1)ᵏ (4k + 1)!! / y²ᵏ⁺¹.
+fn fresnel_asymptotic_f_g(x: Quad) -> (Quad, Quad) {
+    let r = (Quad::PI * x * x).recip();
+    let mut f = Quad::ONE;
+    let mut g = Quad::ZERO;
+    let mut term = Quad::ONE;
+    let mut m = 1;
+    loop {
+        // term = ±(2m - 1)!! / yᵐ, with the sign changing at every even m
+        let mut next = term * Quad::from(2 * m - 1) * r;
+        if m % 2 == 0 {
+            next = -next;
+        }
+        if next.abs() >= term.abs() {
+            break;
+        }
+        term = next;
+        if m % 2 == 0 {
+            f += term;
+        } else {
+            g += term;
+        }
+        if term.abs() < Quad::EPSILON * g.abs() {
+            break;
+        }
+        m += 1;
+    }
+    let s = (Quad::PI * x).recip();
+    (f * s, g * s)
+}
+
+// Calculates sin(πx²/2) and cos(πx²/2). x is split into a part with few enough bits that
+// its square is exact and a small remainder. The exact part of x²/2 is then reduced modulo
+// 2 before the remainder is added, so rounding x² doesn't throw the angle off by an amount
+// proportional to x².
+fn sin_cos_half_pi_square(x: Quad) -> (Quad, Quad) {
+    let hi = c::mul_pwr2(c::mul_pwr2(x, 1048576.0).floor(), 1.0 / 1048576.0);
+    let lo = x - hi;
+    let a = c::mul_pwr2(hi * hi, 0.5);
+    let r = a - c::mul_pwr2(c::mul_pwr2(a, 0.5).round(), 2.0);
+    (r + c::mul_pwr2(lo * (x + hi), 0.5)).sin_cos_pi()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // digamma tests
+    test_all_near!(
+        digamma_one:
+            qd!("-0.577215664901532860606512090082402431042159335939923598805767234885"),
+            qd!(1).digamma();
+        digamma_half:
+            qd!("-1.96351002602142347944097633299875556719315960466043410704712725387"),
+            qd!(0.5).digamma();
+        digamma_two_and_a_half:
+            qd!("0.703156640645243187225690333667911099473507062006232559619539412795"),
+            qd!(2.5).digamma();
+        digamma_ten:
+            qd!("2.25175258906672110764745616388585153721180891802833036944820101908"),
+            qd!(10).digamma();
+        digamma_hundred:
+            qd!("4.6001618527380874001986055855758507268668127907685280543707667217"),
+            qd!(100).digamma();
+        digamma_large:
+            qd!("46.051701859880913680354829093687284152022021439242126187333224686"),
+            qd!(1e20).digamma();
+        digamma_neg_half:
+            qd!("0.0364899739785765205590236670012444328068403953395658929528727461283"),
+            qd!(-0.5).digamma();
+        digamma_neg_two_and_a_half:
+            qd!("1.1031566406452431872256903336679110994735070620062325596195394128"),
+            qd!(-2.5).digamma();
+        digamma_neg_hundred_and_a_quarter:
+            qd!("7.75423895920864544841233684001450212218661269047909218794649009787"),
+            qd!(-100.25).digamma();
+        digamma_neg_large:
+            qd!("34.5387763949106852602698718202655047806831889960982613071665851739"),
+            qd!(-999999999999999.5).digamma();
+        digamma_small:
+            qd!("-1.07374182457721566336956863143933036397849437861847234105263473072e9"),
+            qd!(2).powi(-30).digamma();
+        digamma_near_three:
+            qd!("0.922784335098467139736038608529903598793167290603232320961540530584"),
+            Quad::new(3.0, 2f64.powi(-60), 0.0, 0.0).digamma();
+    );
+    test_all_exact!(
+        digamma_zero:
+            Quad::NAN,
+            Quad::ZERO.digamma();
+        digamma_neg_zero:
+            Quad::NAN,
+            Quad::NEG_ZERO.digamma();
+        digamma_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.digamma();
+        digamma_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.digamma();
+        digamma_nan:
+            Quad::NAN,
+            Quad::NAN.digamma();
+        digamma_neg_one:
+            Quad::NAN,
+            qd!(-1).digamma();
+        digamma_neg_three:
+            Quad::NAN,
+            qd!(-3).digamma();
+    );
+
+    // polygamma tests
+    test_all_near!(
+        polygamma_trigamma_one:
+            qd!("1.64493406684822643647241516664602518921894990120679843773555822937"),
+            qd!(1).polygamma(1);
+        polygamma_trigamma_half:
+            qd!("4.93480220054467930941724549993807556765684970362039531320667468811"),
+            qd!(0.5).polygamma(1);
+        polygamma_trigamma_neg_half:
+            qd!("8.93480220054467930941724549993807556765684970362039531320667468811"),
+            qd!(-0.5).polygamma(1);
+        polygamma_trigamma_hundred:
+            qd!("0.0100501666633335713952456684657014225356282011755346238824472488844"),
+            qd!(100).polygamma(1);
+        polygamma_trigamma_large:
+            qd!("1.00000000000000000000500000000000000000001666666666666666666666667e-20"),
+            qd!(1e20).polygamma(1);
+        polygamma_tetragamma_one:
+            qd!("-2.40411380631918857079947632302289998152997258468099776358454311068"),
+            qd!(1).polygamma(2);
+        polygamma_tetragamma_two_and_a_half:
+            qd!("-0.236204051641727403003741668567707278117215500174391752499209182193"),
+            qd!(2.5).polygamma(2);
+        polygamma_tetragamma_neg_two_and_a_half:
+            qd!("-0.108204051641727403003741668567707278117215500174391752499209182193"),
+            qd!(-2.5).polygamma(2);
+        polygamma_order_3_half:
+            qd!("97.40909103400243723644033268870511124972758567268542169146785939"),
+            qd!(0.5).polygamma(3);
+        polygamma_order_5_two_and_a_half:
+            qd!("0.578569178567183484553903128824211513871911891171281438074520448883"),
+            qd!(2.5).polygamma(5);
+        polygamma_order_10_one:
+            qd!("-3.63059331160662871299061884283205410545727903407938742980194044332e6"),
+            qd!(1).polygamma(10);
+        polygamma_trigamma_neg_hundred_and_a_quarter:
+            qd!("19.7292833253493633443093874655251647627312880714416460876917876934"),
+            qd!(-100.25).polygamma(1);
+        polygamma_trigamma_small:
+            qd!("1.15292150460684697764493406460922097951890788421104416394593231248e18"),
+            qd!(2).powi(-30).polygamma(1);
+        polygamma_zero_order:
+            qd!(2.5).digamma(),
+            qd!(2.5).polygamma(0);
+    );
+    test_all_exact!(
+        polygamma_zero:
+            Quad::NAN,
+            Quad::ZERO.polygamma(2);
+        polygamma_inf:
+            Quad::ZERO,
+            Quad::INFINITY.polygamma(2);
+        polygamma_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.polygamma(2);
+        polygamma_nan:
+            Quad::NAN,
+            Quad::NAN.polygamma(2);
+        polygamma_neg_two:
+            Quad::NAN,
+            qd!(-2).polygamma(1);
+    );
+
+    // erf tests
+    test_all_near!(
+        erf_tiny:
+            qd!("1.12837916709551257389239763923122679644179201277259685157495004062e-10"),
+            qd!(1e-10).erf();
+        erf_quarter:
+            qd!("0.276326390168236932985068267764815712065353977892311254082471931263"),
+            qd!(0.25).erf();
+        erf_half:
+            qd!("0.520499877813046537682746653891964528736451575757963700058805725647"),
+            qd!(0.5).erf();
+        erf_one:
+            qd!("0.842700792949714869341220635082609259296066997966302908459937897835"),
+            qd!(1).erf();
+        erf_two:
+            qd!("0.9953222650189527341620692563672529286108917970400600767383523262"),
+            qd!(2).erf();
+        erf_five:
+            qd!("0.999999999998462540205571965149811656514616621109881949685276620069"),
+            qd!(5).erf();
+        erf_neg_one_and_a_half:
+            qd!("-0.966105146475310727066976261645947858681410479257636780449967846442"),
+            qd!(-1.5).erf();
+        erf_ten:
+            qd!("0.999999999999999999999999999999999999999999997911512416237455242999"),
+            qd!(10).erf();
+    );
+    test_all_exact!(
+        erf_zero:
+            Quad::ZERO,
+            Quad::ZERO.erf();
+        erf_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.erf();
+        erf_inf:
+            Quad::ONE,
+            Quad::INFINITY.erf();
+        erf_neg_inf:
+            Quad::NEG_ONE,
+            Quad::NEG_INFINITY.erf();
+        erf_nan:
+            Quad::NAN,
+            Quad::NAN.erf();
+        erf_large:
+            Quad::ONE,
+            qd!(30).erf();
+        erf_neg_large:
+            Quad::NEG_ONE,
+            qd!(-30).erf();
+    );
+
+    // erfc tests
+    test_all_near!(
+        erfc_quarter:
+            qd!("0.723673609831763067014931732235184287934646022107688745917528068737"),
+            qd!(0.25).erfc();
+        erfc_half:
+            qd!("0.479500122186953462317253346108035471263548424242036299941194274353"),
+            qd!(0.5).erfc();
+        erfc_one:
+            qd!("0.157299207050285130658779364917390740703933002033697091540062102165"),
+            qd!(1).erfc();
+        erfc_two:
+            qd!("4.67773498104726583793074363274707138910820295993992326164767379956e-3"),
+            qd!(2).erfc();
+        erfc_five:
+            qd!("1.53745979442803485018834348538337889011805031472337993068791405592e-12"),
+            qd!(5).erfc();
+        erfc_ten:
+            qd!("2.08848758376254475700078629495778861156081811932116372701221371394e-45"),
+            qd!(10).erfc();
+        erfc_twenty:
+            qd!("5.39586561160790092893499916790534560408827267092360528347010378491e-176"),
+            qd!(20).erfc();
+        erfc_neg_half:
+            qd!("1.52049987781304653768274665389196452873645157575796370005880572565"),
+            qd!(-0.5).erfc();
+        erfc_neg_one_and_a_half:
+            qd!("1.96610514647531072706697626164594785868141047925763678044996784644"),
+            qd!(-1.5).erfc();
+        erfc_neg_five:
+            qd!("1.99999999999846254020557196514981165651461662110988194968527662007"),
+            qd!(-5).erfc();
+    );
+    test_all_exact!(
+        erfc_zero:
+            Quad::ONE,
+            Quad::ZERO.erfc();
+        erfc_neg_zero:
+            Quad::ONE,
+            Quad::NEG_ZERO.erfc();
+        erfc_inf:
+            Quad::ZERO,
+            Quad::INFINITY.erfc();
+        erfc_nan:
+            Quad::NAN,
+            Quad::NAN.erfc();
+        erfc_neg_inf:
+            qd!(2),
+            Quad::NEG_INFINITY.erfc();
+        erfc_large:
+            Quad::ZERO,
+            qd!(30).erfc();
+        erfc_neg_large:
+            qd!(2),
+            qd!(-30).erfc();
+    );
+
+    // norm_cdf tests
+    test_all_prec!(
+        // Like erfc, the error in the far tail comes mostly from exp
+        norm_cdf_neg_thirty:
+            qd!("4.90671392714818705953380925658019047199698494139251059006323411426e-198"),
+            qd!(-30).norm_cdf(),
+            59;
+    );
+    test_all_near!(
+        norm_cdf_one:
+            qd!("0.841344746068542948585232545632037922477912966726604390987394450243"),
+            qd!(1).norm_cdf();
+        norm_cdf_neg_one:
+            qd!("0.158655253931457051414767454367962077522087033273395609012605549757"),
+            qd!(-1).norm_cdf();
+        norm_cdf_half:
+            qd!("0.691462461274013103637704610608337739883602175554577936820776142679"),
+            qd!(0.5).norm_cdf();
+        norm_cdf_two:
+            qd!("0.97724986805182079279971736283346656252822377629832156601633399987"),
+            qd!(2).norm_cdf();
+        norm_cdf_neg_three:
+            qd!("1.34989803163009452665181476759497737782936815838064936422198535581e-3"),
+            qd!(-3).norm_cdf();
+        norm_cdf_neg_ten:
+            qd!("7.6198530241605260659733432515993083635040332779569605780353554629e-24"),
+            qd!(-10).norm_cdf();
+    );
+    test_all_exact!(
+        norm_cdf_zero:
+            qd!(0.5),
+            Quad::ZERO.norm_cdf();
+        norm_cdf_inf:
+            Quad::ONE,
+            Quad::INFINITY.norm_cdf();
+        norm_cdf_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.norm_cdf();
+        norm_cdf_nan:
+            Quad::NAN,
+            Quad::NAN.norm_cdf();
+        norm_cdf_large:
+            Quad::ONE,
+            qd!(40).norm_cdf();
+        norm_cdf_neg_large:
+            Quad::ZERO,
+            qd!(-40).norm_cdf();
+    );
+
+    // norm_quantile tests
+    test_all_near!(
+        norm_quantile_0_975:
+            qd!("1.95996398454005423552459443052055152795555007786954839847695264636"),
+            qd!("0.975").norm_quantile();
+        norm_quantile_0_3:
+            qd!("-0.52440051270804078403828932502512255432537803544997816890591667586"),
+            qd!("0.3").norm_quantile();
+        norm_quantile_0_01:
+            qd!("-2.32634787404084110088560616334691172335181714153201306906564024789"),
+            qd!("0.01").norm_quantile();
+        norm_quantile_1e_10:
+            qd!("-6.36134090240405620469537582826522167920393735091583613175786535028"),
+            qd!("1e-10").norm_quantile();
+        norm_quantile_1e_100:
+            qd!("-21.2734535609653242951172121886622264186487654862516779718523404476"),
+            qd!("1e-100").norm_quantile();
+        norm_quantile_0_999:
+            qd!("3.09023230616781354154039983010737920549100849186580885569717110854"),
+            qd!("0.999").norm_quantile();
+        norm_quantile_near_half:
+            qd!("2.33447949833329813991909237641026507920460192818980870000014810804e-9"),
+            (qd!(0.5) + qd!(2).powi(-30)).norm_quantile();
+        norm_quantile_round_trip:
+            qd!(-20),
+            qd!(-20).norm_cdf().norm_quantile();
+    );
+    test_all_exact!(
+        norm_quantile_zero:
+            Quad::NEG_INFINITY,
+            Quad::ZERO.norm_quantile();
+        norm_quantile_one:
+            Quad::INFINITY,
+            Quad::ONE.norm_quantile();
+        norm_quantile_half:
+            Quad::ZERO,
+            qd!(0.5).norm_quantile();
+        norm_quantile_nan:
+            Quad::NAN,
+            Quad::NAN.norm_quantile();
+        norm_quantile_neg:
+            Quad::NAN,
+            qd!(-0.1).norm_quantile();
+        norm_quantile_above_one:
+            Quad::NAN,
+            qd!(1.5).norm_quantile();
+    );
+
+    // beta tests
+    test_all_prec!(
+        // The logarithms and exponential cost a couple of digits
+        beta_two_and_a_half_one_and_a_half:
+            qd!("0.196349540849362077403915211454968930262323087460944113810934037019"),
+            qd!(2.5).beta(qd!(1.5)),
+            60;
+        beta_two_three:
+            qd!("0.0833333333333333333333333333333333333333333333333333333333333333333"),
+            qd!(2).beta(qd!(3)),
+            60;
+        beta_halves:
+            qd!("3.14159265358979323846264338327950288419716939937510582097494459231"),
+            qd!(0.5).beta(qd!(0.5)),
+            60;
+        beta_thirty_forty:
+            qd!("1.05394246037965456901667584464215654351047908616395087575869817651e-21"),
+            qd!(30).beta(qd!(40)),
+            60;
+        beta_hundred_quarter:
+            qd!("1.14759478002034372787648899707737850155455688556942174434799335549"),
+            qd!(100).beta(qd!(0.25)),
+            60;
+    );
+    test_all_exact!(
+        beta_nan:
+            Quad::NAN,
+            Quad::NAN.beta(qd!(2));
+        beta_zero:
+            Quad::NAN,
+            Quad::ZERO.beta(qd!(2));
+        beta_neg:
+            Quad::NAN,
+            qd!(-1.5).beta(qd!(2));
+        beta_inf:
+            Quad::ZERO,
+            Quad::INFINITY.beta(qd!(2));
+    );
+
+    // betainc tests
+    test_all_prec!(
+        // The logarithms and exponential cost a couple of digits
+        betainc_two_three:
+            qd!("0.5248"),
+            qd!(0.4).betainc(qd!(2), qd!(3)),
+            60;
+        betainc_halves:
+            qd!("0.204832764699133451649197847550518948097730952226164210800155374275"),
+            qd!(0.1).betainc(qd!(0.5), qd!(0.5)),
+            60;
+        betainc_ten_twenty:
+            qd!("0.3640040810719442776485398655"),
+            qd!(0.3).betainc(qd!(10), qd!(20)),
+            60;
+        betainc_hundred_fifty:
+            qd!("0.0444240972902437892822103467090142719566666931562091201712899590081"),
+            qd!(0.6).betainc(qd!(100), qd!(50)),
+            60;
+        betainc_small_x:
+            qd!("0.0141445439572011748046875"),
+            qd!(0.01).betainc(qd!(1.5), qd!(7)),
+            60;
+        betainc_large_x:
+            qd!("0.999999999996681483971955466355264614772076978338272969774532621007"),
+            qd!(0.999).betainc(qd!(0.25), qd!(3.5)),
+            60;
+    );
+    test_all_exact!(
+        betainc_zero:
+            Quad::ZERO,
+            Quad::ZERO.betainc(qd!(2), qd!(3));
+        betainc_one:
+            Quad::ONE,
+            Quad::ONE.betainc(qd!(2), qd!(3));
+        betainc_neg:
+            Quad::NAN,
+            qd!(-0.1).betainc(qd!(2), qd!(3));
+        betainc_above_one:
+            Quad::NAN,
+            qd!(1.5).betainc(qd!(2), qd!(3));
+        betainc_zero_a:
+            Quad::NAN,
+            qd!(0.5).betainc(Quad::ZERO, qd!(3));
+        betainc_nan:
+            Quad::NAN,
+            Quad::NAN.betainc(qd!(2), qd!(3));
+    );
+
+    // zeta tests
+    test_all_prec!(
+        zeta_neg_2_5:
+            qd!("8.51692877785033054235856702834448693627599022007447776588885495191e-3"),
+            qd!(-2.5).zeta(),
+            61;
+    );
+    test_all_near!(
+        zeta_2:
+            qd!("1.64493406684822643647241516664602518921894990120679843773555822937"),
+            qd!(2).zeta();
+        zeta_3:
+            qd!("1.20205690315959428539973816151144999076498629234049888179227155534"),
+            qd!(3).zeta();
+        zeta_0_5:
+            qd!("-1.46035450880958681288949915251529801246722933101258149054288608783"),
+            qd!(0.5).zeta();
+        zeta_1_5:
+            qd!("2.61237534868548834334856756792407163057080065240006340757332824881"),
+            qd!(1.5).zeta();
+        zeta_0_25:
+            qd!("-0.813278405261891656521447820073525574481570524529005842605066973442"),
+            qd!(0.25).zeta();
+        zeta_4_5:
+            qd!("1.05470751076145426402296728896028011727249383295625173068468450108"),
+            qd!(4.5).zeta();
+        zeta_10:
+            qd!("1.00099457512781808533714595890031901700601953156447751725778899464"),
+            qd!(10).zeta();
+        zeta_30:
+            qd!("1.00000000093132743241966818287176473502121981356795513681618500861"),
+            qd!(30).zeta();
+        zeta_100:
+            qd!("1.00000000000000000000000000000078886090522101180735205378276604137"),
+            qd!(100).zeta();
+        zeta_250:
+            qd!("1.0"),
+            qd!(250).zeta();
+        zeta_neg_1:
+            qd!("-0.0833333333333333333333333333333333333333333333333333333333333333333"),
+            qd!(-1).zeta();
+        zeta_neg_3:
+            qd!("8.33333333333333333333333333333333333333333333333333333333333333333e-3"),
+            qd!(-3).zeta();
+        zeta_neg_0_5:
+            qd!("-0.207886224977354566017306725397049302226268531287672537610113557106"),
+            qd!(-0.5).zeta();
+        zeta_neg_20_5:
+            qd!("-108.217475058776055404827141928857905977032711946819497384027374251"),
+            qd!(-20.5).zeta();
+    );
+    test_all_exact!(
+        zeta_zero:
+            qd!(-0.5),
+            Quad::ZERO.zeta();
+        zeta_one:
+            Quad::NAN,
+            Quad::ONE.zeta();
+        zeta_neg_two:
+            Quad::ZERO,
+            qd!(-2).zeta();
+        zeta_neg_hundred:
+            Quad::ZERO,
+            qd!(-100).zeta();
+        zeta_inf:
+            Quad::ONE,
+            Quad::INFINITY.zeta();
+        zeta_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.zeta();
+        zeta_nan:
+            Quad::NAN,
+            Quad::NAN.zeta();
+    );
+
+    // fresnel_s tests
+    test_all_prec!(
+        fresnel_s_1_5:
+            qd!("0.697504960082093013080655163187268332944769121379286600133600724439"),
+            qd!(1.5).fresnel_s(),
+            61;
+    );
+    test_all_near!(
+        fresnel_s_0_001:
+            qd!("5.23598775598206592491749202612274338720425273522392544050048803835e-10"),
+            qd!(0.001).fresnel_s();
+        fresnel_s_0_5:
+            qd!("0.0647324328599992776114805122306147676507259184935124927875889456483"),
+            qd!(0.5).fresnel_s();
+        fresnel_s_1:
+            qd!("0.438259147390354766076756696625152637493786572452416567334407326266"),
+            qd!(1).fresnel_s();
+        fresnel_s_2:
+            qd!("0.343415678363698242195300815958068456886541812202524767579268920449"),
+            qd!(2).fresnel_s();
+        fresnel_s_neg_2_5:
+            qd!("-0.619181755819592936113576239798555669397764585429247750994549697776"),
+            qd!(-2.5).fresnel_s();
+        fresnel_s_3:
+            qd!("0.496312998967375036097612265299112103856467034583241106086786479726"),
+            qd!(3).fresnel_s();
+        fresnel_s_5:
+            qd!("0.499191381917116886751928380465991655408431997072388153410141115176"),
+            qd!(5).fresnel_s();
+        fresnel_s_10:
+            qd!("0.468169978584882240403351110810446946053842724555830279927006227177"),
+            qd!(10).fresnel_s();
+        fresnel_s_15:
+            qd!("0.499969979809702743424116088250640964126550837421652761144481952761"),
+            qd!(15).fresnel_s();
+        fresnel_s_100:
+            qd!("0.49681690114783755327146702338445210837925320790448696625384201621"),
+            qd!(100).fresnel_s();
+        fresnel_s_1000:
+            qd!("0.499681690113816306083065531728607342024925390578996462219659397227"),
+            qd!(1000).fresnel_s();
+    );
+    test_all_exact!(
+        fresnel_s_zero:
+            Quad::ZERO,
+            Quad::ZERO.fresnel_s();
+        fresnel_s_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.fresnel_s();
+        fresnel_s_inf:
+            qd!(0.5),
+            Quad::INFINITY.fresnel_s();
+        fresnel_s_neg_inf:
+            qd!(-0.5),
+            Quad::NEG_INFINITY.fresnel_s();
+        fresnel_s_nan:
+            Quad::NAN,
+            Quad::NAN.fresnel_s();
+    );
+
+    // fresnel_c tests
+    test_all_near!(
+        fresnel_c_0_001:
+            qd!("9.99999999999753259889972794220030015617621950481904273563427059546e-4"),
+            qd!(0.001).fresnel_c();
+        fresnel_c_0_5:
+            qd!("0.492344225871446392878843665156681637766095145771501253294652619319"),
+            qd!(0.5).fresnel_c();
+        fresnel_c_1:
+            qd!("0.779893400376822829474206413652690136630625708136320960103133583178"),
+            qd!(1).fresnel_c();
+        #[cfg(not(feature = "fast_arith"))]
+        fresnel_c_1_5:
+            qd!("0.445261176039821535064551009742089782159402057756099520134174032225"),
+            qd!(1.5).fresnel_c();
+        fresnel_c_2:
+            qd!("0.488253406075340754500223503357261037688367154509215382947596442655"),
+            qd!(2).fresnel_c();
+        fresnel_c_neg_2_5:
+            qd!("-0.457413009641777045245656104956144438829075530295321239512596687361"),
+            qd!(-2.5).fresnel_c();
+        fresnel_c_3:
+            qd!("0.605720789297685629556161074287154697145224119938324238581846230165"),
+            qd!(3).fresnel_c();
+        fresnel_c_5:
+            qd!("0.56363118870401223110210740441301396412075376230999210786165934125"),
+            qd!(5).fresnel_c();
+        fresnel_c_10:
+            qd!("0.499898694205515723614151847735621114392346840226262657207467409347"),
+            qd!(10).fresnel_c();
+        fresnel_c_15:
+            qd!("0.521220531674373457934600045881396684721818467310531353254045473328"),
+            qd!(15).fresnel_c();
+        fresnel_c_100:
+            qd!("0.499999898678817897559468463525830632044293406504046774292803127208"),
+            qd!(100).fresnel_c();
+        fresnel_c_1000:
+            qd!("0.499999999898678816357816218289939818865517402506158776570215111864"),
+            qd!(1000).fresnel_c();
+    );
+    test_all_exact!(
+        fresnel_c_zero:
+            Quad::ZERO,
+            Quad::ZERO.fresnel_c();
+        fresnel_c_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.fresnel_c();
+        fresnel_c_inf:
+            qd!(0.5),
+            Quad::INFINITY.fresnel_c();
+        fresnel_c_neg_inf:
+            qd!(-0.5),
+            Quad::NEG_INFINITY.fresnel_c();
+        fresnel_c_nan:
+            Quad::NAN,
+            Quad::NAN.fresnel_c();
+    );
+}
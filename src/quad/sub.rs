@@ -3,6 +3,7 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::double::Double;
 use crate::quad::Quad;
 use std::ops::{Add, Sub, SubAssign};
 
@@ -150,6 +151,105 @@ impl SubAssign<&Quad> for Quad {
     }
 }
 
+impl SubAssign<f64> for Quad {
+    /// Subtracts an `f64` from this `Quad`, modifying this one to equal the result.
+    ///
+    /// This implements the `-=` operator between a `Quad` and an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x -= 2.0;
+    /// let expected = qd!("1.141592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: f64) {
+        let r = self.sub(Quad(other, 0.0, 0.0, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
+impl SubAssign<Double> for Quad {
+    /// Subtracts a `Double` from this `Quad`, modifying this one to equal the result.
+    ///
+    /// This implements the `-=` operator between a `Quad` and a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Quad};
+    /// let mut x = Quad::PI;
+    /// x -= dd!(2);
+    /// let expected = qd!("1.141592653589793238462643383279502884197169399375105820974944592");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: Double) {
+        let (a, b): (f64, f64) = other.into();
+        let r = self.sub(Quad(a, b, 0.0, 0.0));
+        self.0 = r.0;
+        self.1 = r.1;
+        self.2 = r.2;
+        self.3 = r.3;
+    }
+}
+
+impl Quad {
+    /// Subtracts another `Quad` from this one, returning `None` if the result is not
+    /// finite.
+    ///
+    /// This mirrors the `checked_sub` methods of the primitive integer types. The result is
+    /// `None` if the difference overflows to infinity or the result is `NaN`; this is
+    /// always the case when either argument is already infinite or `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::PI.checked_sub(Quad::E).is_some());
+    /// assert!(Quad::MIN.checked_sub(Quad::MAX).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_sub(self, other: Quad) -> Option<Quad> {
+        let r = self - other;
+        if r.is_finite() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Subtracts another `Quad` from this one, returning both the difference and the
+    /// rounding error of the subtraction.
+    ///
+    /// This works just like [`add_with_err`]: the difference is the one that the `-`
+    /// operator produces, and the error is the rest of the exact difference, rounded to a
+    /// `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::PI;
+    /// let y = Quad::ONE.ldexp(-300);
+    /// let (d, e) = x.sub_with_err(y);
+    ///
+    /// assert!(d == x);
+    /// assert!(e == -y);
+    /// ```
+    ///
+    /// [`add_with_err`]: #method.add_with_err
+    pub fn sub_with_err(self, other: Quad) -> (Quad, Quad) {
+        self.add_with_err(-other)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -255,5 +355,44 @@ mod tests {
             b -= &Quad::E;
             near!(qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"), b);
         }
+        assign_f64: {
+            let mut c = Quad::PI;
+            c -= 2.0;
+            near!(qd!("1.1415926535897932384626433832795028841971693993751058209749445923078"), c);
+        }
+        assign_double: {
+            let mut d = Quad::PI;
+            d -= Double::from(2.0);
+            near!(qd!("1.1415926535897932384626433832795028841971693993751058209749445923078"), d);
+        }
+    );
+
+    // sub_with_err tests
+    test_all!(
+        sub_with_err_split: {
+            let x = Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), 2f64.powi(-180));
+            let y = Quad(2f64.powi(-240), 2f64.powi(-300), 0.0, 0.0);
+            let (d, e) = x.sub_with_err(y);
+            exact!(x, d);
+            exact!(-y, e);
+        }
+        sub_with_err_pi_e: {
+            let (d, e) = Quad::PI.sub_with_err(Quad::E);
+            exact!(Quad::PI - Quad::E, d);
+            assert!(e.abs() < d * Quad::EPSILON);
+        }
+        sub_with_err_inf: {
+            let (d, e) = Quad::INFINITY.sub_with_err(Quad::INFINITY);
+            exact!(Quad::NAN, d);
+            exact!(Quad::ZERO, e);
+        }
+    );
+
+    // checked_sub tests
+    test_all_assert!(
+        checked_sub_num: Quad::PI.checked_sub(Quad::E) == Some(Quad::PI - Quad::E);
+        checked_sub_nan: Quad::PI.checked_sub(Quad::NAN).is_none();
+        checked_sub_inf: Quad::INFINITY.checked_sub(Quad::PI).is_none();
+        checked_sub_overflow: Quad::MIN.checked_sub(Quad::MAX).is_none();
     );
 }
@@ -3,6 +3,25 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+// Formats a number for a failure message, in decimal if the `display` feature is enabled
+// and as its components otherwise. With `e`, the decimal is in exponential notation.
+#[cfg(feature = "display")]
+macro_rules! show {
+    ($x:expr) => {
+        format!("{}", $x)
+    };
+    ($x:expr, e) => {
+        format!("{:e}", $x)
+    };
+}
+
+#[cfg(not(feature = "display"))]
+macro_rules! show {
+    ($x:expr $(, e)?) => {
+        format!("{:?}", $x)
+    };
+}
+
 macro_rules! prec {
     ($expected:expr, $actual:expr, $digits:expr $(,)?) => {
         let expected = Quad::from($expected);
@@ -12,7 +31,7 @@ macro_rules! prec {
         } else {
             expected.0.abs().log10().floor() as i32
         };
-        let epsilon = Quad(10.0, 0.0, 0.0, 0.0).powi(mag - $digits);
+        let epsilon = Quad(10.0, 0.0, 0.0, 0.0).powi((mag - $digits).into());
         let diff = (expected - actual).abs();
         let message = format!(
             concat!(
@@ -20,17 +39,17 @@ macro_rules! prec {
                 "Expected: {0}\n",
                 "Actual:   {1}\n",
                 "\n",
-                "Delta:    {2:e}\n",
-                "Epsilon:  {3:e}\n",
+                "Delta:    {2}\n",
+                "Epsilon:  {3}\n",
                 "\n",
                 "Components:\n",
                 "  Expected: {4:<22e} {5:<22e} {6:<22e} {7:e}\n",
                 "  Actual:   {8:<22e} {9:<22e} {10:<22e} {11:e}\n",
             ),
-            expected,
-            actual,
-            diff,
-            epsilon,
+            show!(expected),
+            show!(actual),
+            show!(diff, e),
+            show!(epsilon, e),
             expected[0],
             expected[1],
             expected[2],
@@ -64,8 +83,8 @@ macro_rules! exact {
                 "  Expected: {2:<22e} {3:<22e} {4:<22e} {5:e}\n",
                 "  Actual:   {6:<22e} {7:<22e} {8:<22e} {9:e}\n",
             ),
-            expected,
-            actual,
+            show!(expected),
+            show!(actual),
             expected[0],
             expected[1],
             expected[2],
@@ -99,8 +118,9 @@ macro_rules! test_prec {
 }
 
 macro_rules! test_near {
-    ($name:ident: $expected:expr, $actual:expr $(,)?) => {
+    ($(#[$attr:meta])* $name:ident: $expected:expr, $actual:expr $(,)?) => {
         #[test]
+        $(#[$attr])*
         fn $name() {
             near!($expected, $actual);
         }
@@ -140,9 +160,11 @@ macro_rules! test_all_prec {
     };
 }
 
+// Each test can be preceded by attributes, like `#[cfg(not(feature = "fast_arith"))]` for
+// a test whose precision depends on the accurate operators.
 macro_rules! test_all_near {
-    ($($name:ident: $expected:expr, $actual:expr);* $(;)?) => {
-        $(test_near!($name: $expected, $actual);)*
+    ($($(#[$attr:meta])* $name:ident: $expected:expr, $actual:expr);* $(;)?) => {
+        $(test_near!($(#[$attr])* $name: $expected, $actual);)*
     }
 }
 
@@ -8,15 +8,35 @@ use crate::quad::Quad;
 
 const INV_K: Quad = Quad(1.52587890625e-05, 0.0, 0.0, 0.0); //   1/65536, used for exp
 
+// Guards a logarithm `q` that was calculated as a quotient of natural logarithms. If `q` is
+// within rounding error of an integer `n` and `b` raised to the power of `n` is exactly
+// `x`, then the exact answer is `n`, and that's returned instead of the (possibly
+// slightly-off) quotient.
+fn exact_log(x: Quad, b: Quad, q: Quad) -> Quad {
+    let n = q.round();
+    if (q - n).abs() < Quad(1e-50, 0.0, 0.0, 0.0) && n.0.abs() <= 2100.0 {
+        let k = n.0 as i64;
+        let exact = if k >= 0 {
+            b.powi(k) == x
+        } else {
+            x * b.powi(-k) == Quad::ONE
+        };
+        if exact {
+            return n;
+        }
+    }
+    q
+}
+
 impl Quad {
     /// Computes the exponential function, *e*<sup>x</sup>, where *x* is this `Quad`.
     ///
-    /// The result of this function grows rapidly. Once *x* exceeds 708, the result is too
-    /// large to represent with a `Quad`; at that point the function begins to return
-    /// [`INFINITY`]. The limit on the low end is less due to the fact that the second,
-    /// third, and fourth components need to fit in an `f64` rather than the first, along
-    /// with extra bits used in argument reduction; this function begins to return 0 at
-    /// -460.
+    /// The result of this function grows rapidly. Once *x* exceeds [`MAX_EXP_ARG`]
+    /// (about 709.78), the result is too large to represent and the function returns
+    /// [`INFINITY`]. Once *x* is less than [`MIN_EXP_ARG`] (about -745.13), the result is
+    /// too small to represent even as a subnormal number and the function returns 0. In
+    /// between, results smaller than about 10<sup>-200</sup> lose precision gradually,
+    /// because the lower components of the `Quad` become subnormal.
     ///
     /// As *x* grows this function does lose a bit of precision. It's precise to at least 60
     /// digits up to values of -140 <= x <= 150, and from then until the limits, it's
@@ -32,94 +52,142 @@ impl Quad {
     /// assert!(diff < qd!(1e-59));
     /// ```
     ///
+    /// [`MAX_EXP_ARG`]: #associatedconstant.MAX_EXP_ARG
+    /// [`MIN_EXP_ARG`]: #associatedconstant.MIN_EXP_ARG
     /// [`INFINITY`]: #associatedconstant.INFINITY
-    #[allow(clippy::many_single_char_names)]
     pub fn exp(self) -> Quad {
         match self.pre_exp() {
             Some(r) => r,
             None => {
-                // Strategy:
-                //
-                // We first reduce the range of the argument to a convenient size to perform
-                // the calculation efficiently. This reduction takes advantage of the
-                // following identity.
-                //
-                //      exp(kx) = exp(x)^k
-                //
-                // We in fact go a little further because it makes the reduction easier.
-                //
-                //      exp(kx + m * ln(2)) = 2^m * exp(x)^k
-                //
-                // where m and k are arbitary integers. By choosing m appropriately we can
-                // make |kx| <= ln(2) / 2 = 0.347. Then exp(x) is evaluated using a Taylor
-                // series, which for exp(x) is pleasantly easy:
-                //
-                //      exp(x) = 1 + x + x^2/2! + x^3/3! + x^4/4! ...
-                //
-                // Reducing x substantially speeds up the convergence, so we have to use
-                // fewer terms to reach the required precision.
-                //
-                // Once we have executed the Taylor series to produce an intermediate
-                // answer, we expand it to compensate for the earlier reduction.
-
-                // k = 65536 is chosen; INV_K is defined above as that reciprocal
-                let threshold = c::mul_pwr2(Quad::EPSILON, INV_K.0);
-                // m doesn't need to be *that* accurate, so we calculate it with f64
-                // arithmetic instead of the more expensive Quad arithmetic
-                let m = (self.0 / Quad::LN_2.0 + 0.5).floor();
-
-                // solving for x in exp(kx + m * ln(2)). INV_K is a power of 2 so we could
-                // use mul_exp2, but on larger numbers that causes a loss of precision when
-                // used with negative powers of two because bits are being shifted to the
-                // right without accounting for the ones that are lost off the right.
-                let x = (self - Quad::LN_2 * Quad(m, 0.0, 0.0, 0.0)) * INV_K;
-
-                // This is the "x + x^2/2! + x^3/3!" part of the Taylor series.
-                let mut p = x.sqr();
-                let mut r = x + c::mul_pwr2(p, 0.5);
-                p *= x;
-                let mut t = p * c::INV_FACTS[0];
-                let mut i = 0;
+                let (r, m) = self.exp_reduced();
 
-                // This is the rest of the Taylor series. We perform it as many times as
-                // we need to reach our desired precision.
-                loop {
-                    r += t;
-                    p *= x;
-                    i += 1;
-                    t = p * c::INV_FACTS[i];
-                    if i >= 9 || t.abs() <= threshold {
-                        break;
-                    }
+                // Add the "1 +" part of the Taylor series, then do the final step of
+                // expansion, which is the "* 2^m" part
+                (r + Quad::ONE).scale_b(m)
+            }
+        }
+    }
+
+    /// Computes *e*<sup>x</sup> - 1, where *x* is this `Quad`.
+    ///
+    /// For *x* near zero, *e*<sup>x</sup> is near 1, and subtracting 1 from the result of
+    /// [`exp`] cancels away most of the precision of the answer. This function calculates
+    /// the difference directly and is accurate to full precision no matter how small *x*
+    /// is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1e-40).exp_m1();
+    /// let expected = qd!("1.00000000000000000000000000000000000000005e-40");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-100));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    pub fn exp_m1(self) -> Quad {
+        match self.pre_exp_m1() {
+            Some(r) => r,
+            None => {
+                let (r, m) = self.exp_reduced();
+                if m == 0 {
+                    r
+                } else if m > 1000 {
+                    // 2^m might overflow on its own here, but subtracting 1 can't cancel
+                    // anything either
+                    (r + Quad::ONE).scale_b(m) - Quad::ONE
+                } else {
+                    // exp(self) - 1 = 2^m * (1 + r) - 1 = 2^m * r + (2^m - 1), and the
+                    // subtraction in the last term is exact
+                    r.ldexp(m) + (Quad(2f64.powi(m), 0.0, 0.0, 0.0) - Quad::ONE)
                 }
+            }
+        }
+    }
 
-                // Add the Taylor series parts together, then expand by the same number of
-                // times that we reduced earlier.
-                r += t;
-
-                // mul_pwr2 can be used here because multiplication doesn't lose precision
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-
-                // Finally, add the "1 +" part of the Taylor series.
-                r += Quad::ONE;
-
-                // Final step of expansion, this is the "* 2^m" part
-                r.ldexp(m as i32)
+    /// Computes 2<sup>x</sup>, where *x* is this `Quad`.
+    ///
+    /// The argument is split into an integer and a fraction no larger than 1/2 in absolute
+    /// value. Only the fraction goes through [`exp`], and the integer is applied exactly
+    /// as a power of 2 afterwards. This is more accurate than calculating
+    /// *e*<sup>x ln 2</sup> for large arguments, and an integer argument produces an exact
+    /// result over the whole range of `Quad`s, including subnormal results.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(0.5).exp2();
+    /// let diff = (x - Quad::SQRT_2).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// assert!(qd!(-1074).exp2()[0] == f64::from_bits(1));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    pub fn exp2(self) -> Quad {
+        match self.pre_exp2() {
+            Some(r) => r,
+            None => {
+                let n = self.round();
+                let f = self - n;
+                let r = if f.is_zero() {
+                    Quad::ONE
+                } else {
+                    (f * Quad::LN_2).exp()
+                };
+                r.scale_b(n.0 as i32)
+            }
+        }
+    }
+
+    /// Computes 10<sup>x</sup>, where *x* is this `Quad`.
+    ///
+    /// As with [`exp2`], the argument is split into an integer and a fraction no larger
+    /// than 1/2 in absolute value. Only the fraction goes through [`exp`], and the integer
+    /// power of 10 is calculated separately with [`powi`]. This keeps large arguments
+    /// accurate, and it makes integer arguments produce exact results as long as the power
+    /// of 10 is representable.
+    ///
+    /// The function returns [`INFINITY`] for arguments greater than log<sub>10</sub> of
+    /// [`MAX`] (about 308.25) and 0 for arguments less than log<sub>10</sub>
+    /// 2<sup>-1075</sup> (about -323.61).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(0.5).exp10();
+    /// let diff = (x - qd!(10).sqrt()).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// assert!(qd!(20).exp10() == qd!(1e20));
+    /// ```
+    ///
+    /// [`exp2`]: #method.exp2
+    /// [`exp`]: #method.exp
+    /// [`powi`]: #method.powi
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn exp10(self) -> Quad {
+        match self.pre_exp10() {
+            Some(r) => r,
+            None => {
+                let n = self.round();
+                let f = self - n;
+                let mut r = if f.is_zero() {
+                    Quad::ONE
+                } else {
+                    (f * Quad::LN_10).exp()
+                };
+
+                // Powers of 10 below 10^-308 underflow when they're calculated on their
+                // own, so the smallest ones are applied in two steps
+                let mut n = n.0 as i64;
+                if n < -300 {
+                    r *= Quad(10.0, 0.0, 0.0, 0.0).powi(-300);
+                    n += 300;
+                }
+                r * Quad(10.0, 0.0, 0.0, 0.0).powi(n)
             }
         }
     }
@@ -130,12 +198,11 @@ impl Quad {
     /// large positive logarithm, for example, will require the calculation of a large
     /// negative exponential.
     ///
-    /// For the same reasons that negative values of [`exp`] are limited to -470, the
-    /// accurate results of this function are limited to the number whose logarithm is 460,
-    /// which is around 2 &times; 10<sup>200</sup>. Take care with this; unlike in
-    /// [`exp`], [`INFINITY`] is *not* returned. In that function, exceeding the maximum
-    /// refers to actually overflowing an `f64`, which is appropriate to call [`INFINITY`];
-    /// here, it means `470`.
+    /// Small results of [`exp`] lose precision once the lower components become subnormal,
+    /// and for the same reason the accurate results of this function are limited to numbers
+    /// whose logarithm is at most about 460, which is around 2 &times; 10<sup>200</sup>.
+    /// Beyond that, the result gradually loses precision. Take care with this; [`INFINITY`]
+    /// is *not* returned, since the logarithm itself is nowhere near overflowing.
     ///
     /// # Examples
     /// ```
@@ -189,8 +256,53 @@ impl Quad {
         }
     }
 
+    /// Computes ln(1 + *x*), where *x* is this `Quad`.
+    ///
+    /// For *x* near zero, adding 1 to *x* before taking the logarithm with [`ln`] loses
+    /// the digits of *x* that don't fit alongside the 1. This function works with *x*
+    /// directly and is accurate to full precision no matter how small *x* is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1e-40).ln_1p();
+    /// let expected = qd!("9.9999999999999999999999999999999999999995e-41");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-100));
+    /// ```
+    ///
+    /// [`ln`]: #method.ln
+    pub fn ln_1p(self) -> Quad {
+        match self.pre_ln_1p() {
+            Some(r) => r,
+            None => {
+                if self.0 >= 1.0 || self.0 < -0.5 {
+                    // There's no cancellation to worry about this far from zero, and near
+                    // -1 the addition is exact
+                    (self + Quad::ONE).ln()
+                } else {
+                    // Newton's iteration on f(y) = exp(y) - 1 - x, which is
+                    //
+                    //      y' = y - (exp(y) - 1 - x) / exp(y)
+                    //
+                    // Each iteration roughly doubles the number of correct digits, starting
+                    // from the f64 approximation.
+                    let mut y = Quad(self.0.ln_1p(), 0.0, 0.0, 0.0);
+                    for _ in 0..3 {
+                        let e = y.exp_m1();
+                        y -= (e - self) / (e + Quad::ONE);
+                    }
+                    y
+                }
+            }
+        }
+    }
+
     /// Calculates the base-10 logarithm, log<sub>10</sub>, of the `Quad`.
     ///
+    /// If the `Quad` is exactly an integer power of 10, the result is exactly that integer.
+    ///
     /// As with [`ln`], this has an upper usable range less than the size of the numbers
     /// themselves. In this case, that upper limit is around 10<sup>200</sup>. Over this
     /// number, the output is not reliable, but it does not return [`INFINITY`] because the
@@ -210,14 +322,16 @@ impl Quad {
     /// [`ln`]: #method.ln
     #[inline]
     pub fn log10(self) -> Quad {
-        self.ln() / Quad::LN_10
+        exact_log(self, Quad(10.0, 0.0, 0.0, 0.0), self.ln() / Quad::LN_10)
     }
 
     /// Calculates the base-2 logarithm, log<sub>2</sub>, of the `Quad`.
     ///
-    /// Since 2 is smaller than *e*, this function is constrained even more than [`ln`]. It
-    /// will start returning [`NEG_INFINITY`] at around 10<sup>-213</sup> and will start
-    /// to fail on the positive side at around 2.6 &times; 10<sup>180</sup>.
+    /// The binary exponent of the `Quad` is split off first, and only the remaining
+    /// mantissa (which is within a factor of √2 of 1) goes through a natural logarithm.
+    /// This means that the full range of `Quad`s, including subnormal numbers, can be
+    /// handled without any loss of accuracy, and if the `Quad` is exactly an integer power
+    /// of 2, the result is exactly that integer.
     ///
     /// # Examples
     /// ```
@@ -229,11 +343,17 @@ impl Quad {
     /// assert!(diff < qd!(1e-59));
     /// ```
     ///
-    /// [`ln`]: #method.ln
-    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
-    #[inline]
     pub fn log2(self) -> Quad {
-        self.ln() / Quad::LN_2
+        match self.pre_ln() {
+            Some(r) => r,
+            None => {
+                // f64's log2 is accurate enough that rounding it gives the exponent that
+                // puts the mantissa closest to 1, and scaling by a power of 2 is exact
+                let e = self.0.log2().round() as i32;
+                let m = self.scale_b(-e);
+                Quad(e as f64, 0.0, 0.0, 0.0) + (m - Quad::ONE).ln_1p() / Quad::LN_2
+            }
+        }
     }
 
     /// Calculates the base `b` logarithm (log<sub>`b`</sub>) of the `Quad`.
@@ -241,6 +361,9 @@ impl Quad {
     /// This function will have limits at extreme arguments like the other logarithm
     /// functions. The difference is that those limits will depend on the base argument.
     ///
+    /// If `self` is exactly an integer power of `b`, the result is exactly that integer
+    /// (so, for example, the base 2 logarithm of 8 is exactly 3).
+    ///
     /// If the goal is to calculate the base *e*, base 2, or base 10 logarithms of `self`,
     /// the specialized functions for those purposes([`ln`], [`log2`], and [`log10`]
     /// respectively) will be more efficient.
@@ -262,8 +385,188 @@ impl Quad {
     pub fn log(self, b: Quad) -> Quad {
         match self.pre_log(&b) {
             Some(r) => r,
-            None => self.ln() / b.ln(),
+            None => exact_log(self, b, self.ln() / b.ln()),
+        }
+    }
+
+    /// Calculates ln(*e*<sup>`self`</sup> + *e*<sup>`other`</sup>).
+    ///
+    /// This is the usual way to add two probabilities that are stored as their logarithms.
+    /// The exponentials are never calculated directly, so the result is accurate even when
+    /// they would overflow or underflow.
+    ///
+    /// [`NEG_INFINITY`] acts as the logarithm of zero: if one argument is [`NEG_INFINITY`],
+    /// the result is the other argument.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(2).ln().logaddexp(qd!(3).ln());
+    /// let expected = qd!(5).ln();
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// // exp(1000) overflows, but this doesn't
+    /// let y = qd!(1000).logaddexp(qd!(1000));
+    /// assert!((y - (qd!(1000) + Quad::LN_2)).abs() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn logaddexp(self, other: Quad) -> Quad {
+        match self.pre_logaddexp(&other) {
+            Some(r) => r,
+            None => {
+                // ln(e^a + e^b) = a + ln(1 + e^(b - a)), with a the larger of the two so
+                // that e^(b - a) can't overflow
+                let (a, b) = if self >= other {
+                    (self, other)
+                } else {
+                    (other, self)
+                };
+                a + (b - a).exp().ln_1p()
+            }
+        }
+    }
+
+    /// Calculates the logarithm of the sum of the exponentials of a slice of `Quad`s.
+    ///
+    /// This is [`logaddexp`] extended to any number of values. The largest value is
+    /// factored out before the exponentials are calculated, so the result is accurate even
+    /// when they would overflow or underflow. The result is [`NAN`] if any of the values
+    /// are `NaN`, [`INFINITY`] if any of them are positive infinity, and [`NEG_INFINITY`]
+    /// if the slice is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::logsumexp(&[qd!(1).ln(), qd!(2).ln(), qd!(3).ln(), qd!(4).ln()]);
+    /// let expected = Quad::LN_10;
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`logaddexp`]: #method.logaddexp
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn logsumexp(values: &[Quad]) -> Quad {
+        if values.iter().any(|x| x.is_nan()) {
+            return Quad::NAN;
+        }
+        let (index, max) =
+            values
+                .iter()
+                .enumerate()
+                .fold((0, Quad::NEG_INFINITY), |(i, m), (j, x)| {
+                    if *x > m {
+                        (j, *x)
+                    } else {
+                        (i, m)
+                    }
+                });
+        if max.is_infinite() {
+            max
+        } else {
+            // ln(sum(e^x)) = max + ln(1 + sum(e^(x - max))), where the sum inside the
+            // logarithm skips the maximum itself so that ln_1p can keep its precision
+            let sum: Quad = values
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .map(|(_, x)| (*x - max).exp())
+                .sum();
+            max + sum.ln_1p()
+        }
+    }
+
+    // Calculates exp(x) - 1 for the reduced argument x = self - m * ln(2), returning that
+    // value along with m. exp(self) is then 2^m * (1 + the returned value).
+    //
+    // This is split out of `exp` so that `exp_m1` can use it too; for small arguments m is
+    // 0, and the returned value is exp(self) - 1 without any of the cancellation that
+    // comes from subtracting 1 from exp(self).
+    #[allow(clippy::many_single_char_names)]
+    fn exp_reduced(self) -> (Quad, i32) {
+        // Strategy:
+        //
+        // We first reduce the range of the argument to a convenient size to perform
+        // the calculation efficiently. This reduction takes advantage of the
+        // following identity.
+        //
+        //      exp(kx) = exp(x)^k
+        //
+        // We in fact go a little further because it makes the reduction easier.
+        //
+        //      exp(kx + m * ln(2)) = 2^m * exp(x)^k
+        //
+        // where m and k are arbitary integers. By choosing m appropriately we can
+        // make |kx| <= ln(2) / 2 = 0.347. Then exp(x) is evaluated using a Taylor
+        // series, which for exp(x) is pleasantly easy:
+        //
+        //      exp(x) = 1 + x + x^2/2! + x^3/3! + x^4/4! ...
+        //
+        // Reducing x substantially speeds up the convergence, so we have to use
+        // fewer terms to reach the required precision.
+        //
+        // Once we have executed the Taylor series to produce an intermediate
+        // answer, we expand it to compensate for the earlier reduction.
+
+        // k = 65536 is chosen; INV_K is defined above as that reciprocal
+        let threshold = c::mul_pwr2(Quad::EPSILON, INV_K.0);
+        // m doesn't need to be *that* accurate, so we calculate it with f64
+        // arithmetic instead of the more expensive Quad arithmetic
+        let m = (self.0 / Quad::LN_2.0 + 0.5).floor();
+
+        // solving for x in exp(kx + m * ln(2)). INV_K is a power of 2 so we could
+        // use mul_exp2, but on larger numbers that causes a loss of precision when
+        // used with negative powers of two because bits are being shifted to the
+        // right without accounting for the ones that are lost off the right.
+        let x = (self - Quad::LN_2 * Quad(m, 0.0, 0.0, 0.0)) * INV_K;
+
+        // This is the "x + x^2/2! + x^3/3!" part of the Taylor series.
+        let mut p = x.sqr();
+        let mut r = x + c::mul_pwr2(p, 0.5);
+        p *= x;
+        let mut t = p * c::INV_FACTS[0];
+        let mut i = 0;
+
+        // This is the rest of the Taylor series. We perform it as many times as
+        // we need to reach our desired precision.
+        loop {
+            r += t;
+            p *= x;
+            i += 1;
+            t = p * c::INV_FACTS[i];
+            if i >= 9 || t.abs() <= threshold {
+                break;
+            }
         }
+
+        // Add the Taylor series parts together, then expand by the same number of
+        // times that we reduced earlier.
+        r += t;
+
+        // mul_pwr2 can be used here because multiplication doesn't lose precision
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+        r = c::mul_pwr2(r, 2.0) + r.sqr();
+
+        (r, m as i32)
     }
 
     // Precalc functions
@@ -277,9 +580,9 @@ impl Quad {
 
     #[inline]
     fn pre_exp(&self) -> Option<Quad> {
-        if self.0 < -470.0 {
+        if *self < Quad::MIN_EXP_ARG {
             Some(Quad::ZERO)
-        } else if self.0 >= 709.0 {
+        } else if *self > Quad::MAX_EXP_ARG {
             Some(Quad::INFINITY)
         } else if self.is_nan() {
             Some(Quad::NAN)
@@ -292,6 +595,67 @@ impl Quad {
         }
     }
 
+    #[inline]
+    fn pre_exp_m1(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.0.abs() < 1e-290 {
+            // This includes zero, which keeps its sign
+            Some(*self)
+        } else if self.0 < -600.0 {
+            Some(-Quad::ONE)
+        } else if *self > Quad::MAX_EXP_ARG {
+            Some(Quad::INFINITY)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp2(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.0 >= 1024.0 {
+            Some(Quad::INFINITY)
+        } else if self.0 < -1080.0 {
+            Some(Quad::ZERO)
+        } else if self.is_zero() {
+            Some(Quad::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp10(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.0 > 308.25471555991675 {
+            Some(Quad::INFINITY)
+        } else if self.0 < -323.60724533877976 {
+            Some(Quad::ZERO)
+        } else if self.is_zero() {
+            Some(Quad::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_ln_1p(&self) -> Option<Quad> {
+        if self.is_nan() || *self < -Quad::ONE {
+            Some(Quad::NAN)
+        } else if *self == -Quad::ONE {
+            Some(Quad::NEG_INFINITY)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if self.is_infinite() {
+            Some(Quad::INFINITY)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_ln(&self) -> Option<Quad> {
         if self.is_nan() {
@@ -319,6 +683,21 @@ impl Quad {
             None
         }
     }
+
+    #[inline]
+    fn pre_logaddexp(&self, other: &Quad) -> Option<Quad> {
+        if self.is_nan() || other.is_nan() {
+            Some(Quad::NAN)
+        } else if *self == Quad::NEG_INFINITY {
+            Some(*other)
+        } else if *other == Quad::NEG_INFINITY {
+            Some(*self)
+        } else if self.is_infinite() || other.is_infinite() {
+            Some(Quad::INFINITY)
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -422,7 +801,7 @@ mod tests {
     test_all_exact!(
         exp_below:
             Quad::ZERO,
-            qd!(-710).exp();
+            qd!(-746).exp();
         exp_above:
             Quad::INFINITY,
             qd!(710).exp();
@@ -443,6 +822,198 @@ mod tests {
             Quad::NAN.exp();
     );
 
+    test_all_prec!(
+        exp_near_max:
+            qd!("1.73983687326416055769825271167383039386476848748305484259749248373e308"),
+            qd!(709.75).exp(),
+            29;
+        exp_m1_near_max:
+            qd!("1.73983687326416055769825271167383039386476848748305484259749248373e308"),
+            qd!(709.75).exp_m1(),
+            29;
+    );
+    test!(exp_max_arg: {
+        assert!(Quad::MAX_EXP_ARG.exp().is_finite());
+    });
+    test_all_exact!(
+        exp_above_max_arg:
+            Quad::INFINITY,
+            (Quad::MAX_EXP_ARG + qd!(1e-10)).exp();
+        exp_m1_above_max_arg:
+            Quad::INFINITY,
+            (Quad::MAX_EXP_ARG + qd!(1e-10)).exp_m1();
+        exp_subnormal:
+            4.47628622567513e-309,
+            qd!(-710).exp()[0];
+        exp_min_subnormal:
+            f64::from_bits(1),
+            qd!(-745).exp()[0];
+        exp_below_min_arg:
+            Quad::ZERO,
+            (Quad::MIN_EXP_ARG - qd!(1e-10)).exp();
+    );
+
+    // exp_m1 tests
+    test_all_near!(
+        exp_m1_small:
+            qd!("1.00000000000000000000500000000000000000001666666666666666666670833e-20"),
+            qd!("1e-20").exp_m1();
+        exp_m1_neg_small:
+            qd!("-9.99999999999999999995000000000000000000016666666666666666666625e-21"),
+            qd!("-1e-20").exp_m1();
+        exp_m1_tiny:
+            qd!("1.0000050000166667083334166668055557539685019844025575947974286518e-5"),
+            qd!("1e-5").exp_m1();
+        exp_m1_tenth:
+            qd!("0.105170918075647624811707826490246668224547194737518718792863289441"),
+            qd!("0.1").exp_m1();
+        exp_m1_neg_0_3:
+            qd!("-0.259181779318282133933126220682183127817748768000993651704689933192"),
+            qd!("-0.3").exp_m1();
+        exp_m1_1:
+            qd!("1.71828182845904523536028747135266249775724709369995957496696762772"),
+            qd!("1").exp_m1();
+        exp_m1_neg_1:
+            qd!("-0.632120558828557678404476229838539132554188868968232165492163198303"),
+            qd!("-1").exp_m1();
+        exp_m1_10:
+            qd!("2.20254657948067165169579006452842443663535126185567810742354263552e4"),
+            qd!("10").exp_m1();
+        exp_m1_neg_50:
+            qd!("-0.999999999999999999999807125015203608221698265718347298742524716735"),
+            qd!("-50").exp_m1();
+    );
+    test_all_exact!(
+        exp_m1_zero:
+            Quad::ZERO,
+            Quad::ZERO.exp_m1();
+        exp_m1_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.exp_m1();
+        exp_m1_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.exp_m1();
+        exp_m1_neg_inf:
+            -Quad::ONE,
+            Quad::NEG_INFINITY.exp_m1();
+        exp_m1_nan:
+            Quad::NAN,
+            Quad::NAN.exp_m1();
+    );
+    test!(exp_m1_neg_zero_sign: {
+        assert!(Quad::NEG_ZERO.exp_m1().is_sign_negative());
+    });
+
+    // exp2 tests
+    test_all_near!(
+        exp2_half:
+            Quad::SQRT_2,
+            qd!(0.5).exp2();
+        exp2_neg_half:
+            Quad::FRAC_1_SQRT_2,
+            qd!(-0.5).exp2();
+        exp2_3_375:
+            qd!("10.374716437208077327470032942339609278682761195399724102462324181"),
+            qd!(3.375).exp2();
+        exp2_neg_3_25:
+            qd!("0.105112051906714317878890684529151861880004282794598063851653260747"),
+            qd!(-3.25).exp2();
+        exp2_1023_5:
+            qd!("1.27116100615364628366052028422228385109549339363277773668712774121e308"),
+            qd!(1023.5).exp2();
+        exp2_log2:
+            Quad::PI,
+            Quad::PI.log2().exp2();
+    );
+    test_all_exact!(
+        exp2_10:
+            qd!(1024),
+            qd!(10).exp2();
+        exp2_neg_10:
+            qd!(0.0009765625),
+            qd!(-10).exp2();
+        exp2_1023:
+            Quad::ONE.scale_b(1023),
+            qd!(1023).exp2();
+        exp2_neg_1074:
+            Quad::ONE.scale_b(-1074),
+            qd!(-1074).exp2();
+        exp2_1024:
+            Quad::INFINITY,
+            qd!(1024).exp2();
+        exp2_neg_1100:
+            Quad::ZERO,
+            qd!(-1100).exp2();
+        exp2_zero:
+            Quad::ONE,
+            Quad::ZERO.exp2();
+        exp2_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.exp2();
+        exp2_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.exp2();
+        exp2_nan:
+            Quad::NAN,
+            Quad::NAN.exp2();
+    );
+
+    // exp10 tests
+    test_all_near!(
+        exp10_half:
+            qd!("3.16227766016837933199889354443271853371955513932521682685750485279"),
+            qd!(0.5).exp10();
+        exp10_neg_2_25:
+            qd!("5.62341325190349080394951039776481231468251043098691664081689423736e-3"),
+            qd!(-2.25).exp10();
+        exp10_3_375:
+            qd!("2.37137370566165526165175275747888983856793665042552794627495175075e3"),
+            qd!(3.375).exp10();
+        exp10_308_25:
+            qd!("1.77827941003892280122542119519268484473579052640225535801183072278e308"),
+            qd!(308.25).exp10();
+    );
+    test_all_prec!(
+        // Parsing the expected value directly isn't accurate this close to the bottom of
+        // the range, so the result is scaled back up instead
+        exp10_neg_180_5:
+            qd!(10).sqrt(),
+            qd!(-180.5).exp10() * qd!(10).powi(181),
+            60;
+    );
+    test!(exp10_neg_310_5: {
+        // Too small for the lower components to be normal, so only the first one is
+        // checked
+        let x = qd!(-310.5).exp10();
+        assert!((x[0] / 3.162277660168e-311 - 1.0).abs() < 1e-12);
+    });
+    test_all_exact!(
+        exp10_20:
+            qd!(1e20),
+            qd!(20).exp10();
+        exp10_zero:
+            Quad::ONE,
+            Quad::ZERO.exp10();
+        exp10_subnormal:
+            3.16e-321,
+            qd!(-320.5).exp10()[0];
+        exp10_above:
+            Quad::INFINITY,
+            qd!(308.5).exp10();
+        exp10_below:
+            Quad::ZERO,
+            qd!(-324).exp10();
+        exp10_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.exp10();
+        exp10_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.exp10();
+        exp10_nan:
+            Quad::NAN,
+            Quad::NAN.exp10();
+    );
+
     // ln tests
     test_all_near!(
         ln_pi:
@@ -503,6 +1074,62 @@ mod tests {
             Quad::NAN.ln();
     );
 
+    // ln_1p tests
+    test_all_near!(
+        ln_1p_small:
+            qd!("9.99999999999999999995000000000000000000033333333333333333333083333e-21"),
+            qd!("1e-20").ln_1p();
+        ln_1p_neg_small:
+            qd!("-1.00000000000000000000500000000000000000003333333333333333333358333e-20"),
+            qd!("-1e-20").ln_1p();
+        ln_1p_tiny:
+            qd!("9.99995000033333083335333316666809522559534920534921544003210755133e-6"),
+            qd!("1e-5").ln_1p();
+        ln_1p_tenth:
+            qd!("0.095310179804324860043952123280765092220605365308644199185239808163"),
+            qd!("0.1").ln_1p();
+        ln_1p_neg_0_3:
+            qd!("-0.35667494393873237891263871124118447796401675904691178757393775103"),
+            qd!("-0.3").ln_1p();
+        ln_1p_1:
+            qd!("0.693147180559945309417232121458176568075500134360255254120680009493"),
+            qd!("1").ln_1p();
+        ln_1p_10:
+            qd!("2.39789527279837054406194357796512929982170685393741717521856770913"),
+            qd!("10").ln_1p();
+        ln_1p_1e10:
+            qd!("23.0258509300404568401749145468439754093443232196210650936666121763"),
+            qd!("1e10").ln_1p();
+        ln_1p_neg_0_9:
+            qd!("-2.30258509299404568401799145468436420760110148862877297603332790097"),
+            qd!("-0.9").ln_1p();
+    );
+    test_all_exact!(
+        ln_1p_zero:
+            Quad::ZERO,
+            Quad::ZERO.ln_1p();
+        ln_1p_neg_1:
+            Quad::NEG_INFINITY,
+            (-Quad::ONE).ln_1p();
+        ln_1p_neg_2:
+            Quad::NAN,
+            qd!(-2).ln_1p();
+        ln_1p_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.ln_1p();
+        ln_1p_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.ln_1p();
+        ln_1p_nan:
+            Quad::NAN,
+            Quad::NAN.ln_1p();
+    );
+    test_all_near!(
+        ln_1p_exp_m1:
+            qd!("1e-10"),
+            qd!("1e-10").exp_m1().ln_1p();
+    );
+
     // log10 tests
     test_all_near!(
         log10_pi:
@@ -622,6 +1249,22 @@ mod tests {
             Quad::NAN,
             Quad::NAN.log2();
     );
+    test_all_near!(
+        log2_300:
+            qd!("996.578428466208704361095828846817052759449417907374183616426918745"),
+            qd!(10).powi(300).log2();
+        log2_subnormal:
+            qd!("-1058.41503749927884381854626105605218349124018559230751893954424735"),
+            qd!(3).scale_b(-1060).log2();
+    );
+    test_all_exact!(
+        log2_exact_neg_1074:
+            qd!(-1074),
+            Quad::ONE.scale_b(-1074).log2();
+        log2_exact_1023:
+            qd!(1023),
+            Quad::ONE.scale_b(1023).log2();
+    );
 
     // log tests
     test_all_near!(
@@ -685,4 +1328,118 @@ mod tests {
             Quad::NAN,
             Quad::PI.log(Quad::NAN);
     );
+
+    // logaddexp tests
+    test_all_near!(
+        logaddexp_2_3:
+            qd!("1.609437912434100374600759333226187639525601354268517721912647891474179"),
+            qd!(2).ln().logaddexp(qd!(3).ln());
+        logaddexp_3_2:
+            qd!("1.609437912434100374600759333226187639525601354268517721912647891474179"),
+            qd!(3).ln().logaddexp(qd!(2).ln());
+        logaddexp_large:
+            qd!("1000.69314718055994530941723212145817656807550013436025525412068000949339"),
+            qd!(1000).logaddexp(qd!(1000));
+        logaddexp_neg_large:
+            qd!("-999.306852819440054690582767878541823431924499865639744745879319990506606"),
+            qd!(-1000).logaddexp(qd!(-1000));
+        logaddexp_small:
+            qd!("4.2483542552915889863049778436315821818777506798229745422054513756e-18"),
+            Quad::ZERO.logaddexp(qd!(-40));
+    );
+    test_all_exact!(
+        logaddexp_far_apart:
+            qd!(1000),
+            qd!(1000).logaddexp(qd!(-1000));
+        logaddexp_neg_inf:
+            qd!(3),
+            qd!(3).logaddexp(Quad::NEG_INFINITY);
+        logaddexp_neg_inf_self:
+            qd!(3),
+            Quad::NEG_INFINITY.logaddexp(qd!(3));
+        logaddexp_both_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.logaddexp(Quad::NEG_INFINITY);
+        logaddexp_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.logaddexp(qd!(3));
+        logaddexp_inf_neg_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.logaddexp(Quad::NEG_INFINITY);
+        logaddexp_nan:
+            Quad::NAN,
+            Quad::NAN.logaddexp(qd!(3));
+        logaddexp_other_nan:
+            Quad::NAN,
+            qd!(3).logaddexp(Quad::NAN);
+    );
+
+    // logsumexp tests
+    test_all_near!(
+        logsumexp_ln_1_to_4:
+            Quad::LN_10,
+            Quad::logsumexp(&[qd!(1).ln(), qd!(2).ln(), qd!(3).ln(), qd!(4).ln()]);
+        logsumexp_ln_1_to_3:
+            qd!("1.791759469228055000812477358380702272722990692183004705855374343130888"),
+            Quad::logsumexp(&[qd!(3).ln(), qd!(1).ln(), qd!(2).ln()]);
+        logsumexp_large:
+            qd!("1001.09861228866810969139524523692252570464749055782274945173469433363749"),
+            Quad::logsumexp(&[qd!(1000), qd!(1000), qd!(1000)]);
+    );
+    test_all_exact!(
+        logsumexp_one:
+            Quad::PI,
+            Quad::logsumexp(&[Quad::PI]);
+        logsumexp_empty:
+            Quad::NEG_INFINITY,
+            Quad::logsumexp(&[]);
+        logsumexp_neg_inf:
+            Quad::PI,
+            Quad::logsumexp(&[Quad::NEG_INFINITY, Quad::PI, Quad::NEG_INFINITY]);
+        logsumexp_all_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::logsumexp(&[Quad::NEG_INFINITY, Quad::NEG_INFINITY]);
+        logsumexp_inf:
+            Quad::INFINITY,
+            Quad::logsumexp(&[Quad::PI, Quad::INFINITY]);
+        logsumexp_nan:
+            Quad::NAN,
+            Quad::logsumexp(&[Quad::PI, Quad::NAN, Quad::INFINITY]);
+    );
+
+    // exact log tests
+    test_all_exact!(
+        log_exact_8_2:
+            qd!(3),
+            qd!(8).log(qd!(2));
+        log_exact_eighth_2:
+            qd!(-3),
+            qd!(0.125).log(qd!(2));
+        log_exact_8_half:
+            qd!(-3),
+            qd!(8).log(qd!(0.5));
+        log_exact_1000_10:
+            qd!(3),
+            qd!(1000).log(qd!(10));
+        log_exact_243_3:
+            qd!(5),
+            qd!(243).log(qd!(3));
+        log_exact_2_2:
+            qd!(1),
+            qd!(2).log(qd!(2));
+        log10_exact_30:
+            qd!(30),
+            qd!(10).powi(30).log10();
+        log2_exact_1024:
+            qd!(10),
+            qd!(1024).log2();
+        log2_exact_neg_100:
+            qd!(-100),
+            qd!(2).powi(-100).log2();
+    );
+    test_all_near!(
+        log_inexact_1001_10:
+            qd!("3.0004340774793186406689213877779888660200037751774867729013649474"),
+            qd!(1001).log(qd!(10));
+    );
 }
@@ -48,6 +48,58 @@ impl Quad {
                     }
                 };
 
+                match j {
+                    0 => (s, c),
+                    1 => (c, -s),
+                    3 => (-c, s),
+                    _ => (-s, -c),
+                }
+            }
+        }
+    }
+
+    /// Simultaneously computes the sine and cosine of the `Quad` multiplied by π, i.e.,
+    /// `sin(πx)` and `cos(πx)`. Both values come from a single argument reduction.
+    ///
+    /// The reduction is done on the argument itself rather than on the product with π, and
+    /// it's exact. This means that the results are accurate no matter how large the
+    /// argument is, and integers and half-integers produce exact zeros and ones (possibly
+    /// negative). This makes this function a better choice than [`sin_cos`] for generating
+    /// rotation angles and FFT twiddle factors, which are naturally expressed as fractions
+    /// of a half turn.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let (sin_x, cos_x) = qd!(0.25).sin_cos_pi();
+    /// let expected = Quad::FRAC_1_SQRT_2;
+    ///
+    /// assert!((sin_x - expected).abs() < qd!(1e-60));
+    /// assert!((cos_x - expected).abs() < qd!(1e-60));
+    ///
+    /// let (sin_y, cos_y) = qd!(1.5).sin_cos_pi();
+    /// assert!(sin_y == -Quad::ONE);
+    /// assert!(cos_y == Quad::ZERO);
+    /// ```
+    ///
+    /// [`sin_cos`]: #method.sin_cos
+    pub fn sin_cos_pi(self) -> (Quad, Quad) {
+        match self.pre_sin_cos_pi() {
+            Some(r) => r,
+            None => {
+                // Reducing modulo 2 and then to the nearest multiple of 1/2 only subtracts
+                // nearby integers and half-integers, so neither step loses any bits
+                let r = self - c::mul_pwr2(c::mul_pwr2(self, 0.5).round(), 2.0);
+                let q = c::mul_pwr2(r, 2.0).round();
+                let t = r - c::mul_pwr2(q, 0.5);
+                let j = q.0 as i32;
+
+                let (s, c) = if t.is_zero() {
+                    (Quad::ZERO, Quad::ONE)
+                } else {
+                    (t * Quad::PI).sin_cos()
+                };
+
                 match j {
                     0 => (s, c),
                     1 => (c, -s),
@@ -92,7 +144,7 @@ impl Quad {
                     match j {
                         0 => sin_taylor(t),
                         1 => cos_taylor(t),
-                        -1 => -cos_taylor(t),
+                        3 => -cos_taylor(t),
                         _ => -sin_taylor(t),
                     }
                 } else {
@@ -104,14 +156,14 @@ impl Quad {
                         match j {
                             0 => u * sin_t + v * cos_t,
                             1 => u * cos_t - v * sin_t,
-                            -1 => -u * cos_t + v * sin_t,
+                            3 => -u * cos_t + v * sin_t,
                             _ => -u * sin_t - v * cos_t,
                         }
                     } else {
                         match j {
                             0 => u * sin_t - v * cos_t,
                             1 => u * cos_t + v * sin_t,
-                            -1 => -u * cos_t - v * sin_t,
+                            3 => -u * cos_t - v * sin_t,
                             _ => -u * sin_t + v * cos_t,
                         }
                     }
@@ -145,7 +197,7 @@ impl Quad {
                     match j {
                         0 => cos_taylor(t),
                         1 => -sin_taylor(t),
-                        -1 => sin_taylor(t),
+                        3 => sin_taylor(t),
                         _ => -cos_taylor(t),
                     }
                 } else {
@@ -157,14 +209,14 @@ impl Quad {
                         match j {
                             0 => u * cos_t - v * sin_t,
                             1 => -u * sin_t - v * cos_t,
-                            -1 => u * sin_t + v * cos_t,
+                            3 => u * sin_t + v * cos_t,
                             _ => -u * cos_t + v * sin_t,
                         }
                     } else {
                         match j {
                             0 => u * cos_t + v * sin_t,
                             1 => v * cos_t - u * sin_t,
-                            -1 => u * sin_t - v * cos_t,
+                            3 => u * sin_t - v * cos_t,
                             _ => -u * cos_t - v * sin_t,
                         }
                     }
@@ -344,6 +396,80 @@ impl Quad {
         self.atan2(Quad::ONE)
     }
 
+    /// Reduces the `Quad` modulo 2π, returning a value in the range [-π, π].
+    ///
+    /// This is the same reduction that the trigonometric functions use internally. It
+    /// subtracts the nearest multiple of [`TAU`], which carries the full precision of a
+    /// `Quad`, so the absolute error of the result is roughly the size of the argument
+    /// times [`EPSILON`].
+    ///
+    /// Infinite and `NaN` arguments produce [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(7).rem_2pi();
+    /// let expected = qd!(7) - Quad::TAU;
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`TAU`]: #associatedconstant.TAU
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn rem_2pi(self) -> Quad {
+        match self.pre_rem_2pi() {
+            Some(r) => r,
+            None => {
+                let z = (self / Quad::TAU).round();
+                self - z * Quad::TAU
+            }
+        }
+    }
+
+    /// Reduces the `Quad` to an angle in the range [-π/4, π/4] and the quadrant that it
+    /// was reduced from.
+    ///
+    /// The result is a pair `(q, r)` where `q` is in the range [0, 3] and x is congruent to
+    /// r + qπ/2 modulo 2π. The reduction is done by [`rem_2pi`] followed by the removal of
+    /// the nearest multiple of π/2, which is the same reduction that the trigonometric
+    /// functions use internally, and it can be used to build other periodic functions in
+    /// the same way. For example, the sine of x is sin r, cos r, -sin r, or -cos r for the
+    /// four quadrants.
+    ///
+    /// Infinite and `NaN` arguments produce a quadrant of 0 and a reduced angle of
+    /// [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let (q, r) = qd!(10).reduce_angle();
+    /// assert!(q == 2);
+    ///
+    /// // 10 = r + 2(π/2) + 2π
+    /// let expected = qd!(10) - qd!(3) * Quad::PI;
+    /// let diff = (r - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// // so sin 10 = -sin r
+    /// let diff = (qd!(10).sin() + r.sin()).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`rem_2pi`]: #method.rem_2pi
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn reduce_angle(self) -> (i32, Quad) {
+        let r = self.rem_2pi();
+        if r.is_nan() {
+            (0, r)
+        } else {
+            let q = (r.0 / Quad::FRAC_PI_2.0 + 0.5).floor();
+            let t = r - Quad(q, 0.0, 0.0, 0.0) * Quad::FRAC_PI_2;
+            ((q as i32).rem_euclid(4), t)
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -364,6 +490,26 @@ impl Quad {
         }
     }
 
+    #[inline]
+    fn pre_sin_cos_pi(&self) -> Option<(Quad, Quad)> {
+        if self.is_zero() {
+            Some((*self, Quad::ONE))
+        } else if !self.is_finite() {
+            Some((Quad::NAN, Quad::NAN))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_rem_2pi(&self) -> Option<Quad> {
+        if !self.is_finite() {
+            Some(Quad::NAN)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_sin(&self) -> Option<Quad> {
         if self.is_zero() {
@@ -534,17 +680,11 @@ fn sincos_taylor(a: Quad) -> (Quad, Quad) {
 #[inline]
 #[allow(clippy::many_single_char_names)]
 fn reduce(a: Quad) -> (i32, i32, Quad) {
-    // reduce modulo 2π
-    let z = (a / Quad::TAU).round();
-    let r = a - z * Quad::TAU;
-
-    // reduce modulo π/2
-    let mut q = (r.0 / Quad::FRAC_PI_2.0 + 0.5).floor();
-    let mut t = r - Quad(q, 0.0, 0.0, 0.0) * Quad::FRAC_PI_2;
-    let j = q as i32;
+    // reduce modulo 2π and then modulo π/2
+    let (j, mut t) = a.reduce_angle();
 
     // reduce modulo π/1024
-    q = (t.0 / FRAC_PI_1024.0 + 0.5).floor();
+    let q = (t.0 / FRAC_PI_1024.0 + 0.5).floor();
     t -= Quad(q, 0.0, 0.0, 0.0) * FRAC_PI_1024;
     let k = q as i32;
 
@@ -847,6 +987,114 @@ mod tests {
             Quad::NAN.sin_cos().1;
     );
 
+    // sin_cos_pi tests
+    test_all_near!(
+        sin_cos_pi_eighth_sin:
+            qd!("0.3826834323650897717284599840303988667613445624856270414338006356"),
+            qd!(0.125).sin_cos_pi().0;
+        sin_cos_pi_eighth_cos:
+            qd!("0.9238795325112867561281831893967882868224166258636424861150977313"),
+            qd!(0.125).sin_cos_pi().1;
+        sin_cos_pi_three_eighths_sin:
+            qd!("0.9238795325112867561281831893967882868224166258636424861150977313"),
+            qd!(0.375).sin_cos_pi().0;
+        sin_cos_pi_three_eighths_cos:
+            qd!("0.3826834323650897717284599840303988667613445624856270414338006356"),
+            qd!(0.375).sin_cos_pi().1;
+        sin_cos_pi_neg_five_eighths_sin:
+            qd!("-0.9238795325112867561281831893967882868224166258636424861150977313"),
+            qd!(-0.625).sin_cos_pi().0;
+        sin_cos_pi_neg_five_eighths_cos:
+            qd!("-0.3826834323650897717284599840303988667613445624856270414338006356"),
+            qd!(-0.625).sin_cos_pi().1;
+        sin_cos_pi_two_and_seven_eighths_sin:
+            qd!("0.3826834323650897717284599840303988667613445624856270414338006356"),
+            qd!(2.875).sin_cos_pi().0;
+        sin_cos_pi_two_and_seven_eighths_cos:
+            qd!("-0.9238795325112867561281831893967882868224166258636424861150977313"),
+            qd!(2.875).sin_cos_pi().1;
+        sin_cos_pi_thousand_one_sixteenth_sin:
+            qd!("-0.1950903220161282678482848684770222409276916177519548077545020895"),
+            qd!(1001.0625).sin_cos_pi().0;
+        sin_cos_pi_thousand_one_sixteenth_cos:
+            qd!("-0.9807852804032304491261822361342390369739337308933360950029160885"),
+            qd!(1001.0625).sin_cos_pi().1;
+        sin_cos_pi_neg_three_and_five_sixteenths_sin:
+            qd!("0.8314696123025452370787883776179057567385608119872499634461245902"),
+            qd!(-3.3125).sin_cos_pi().0;
+        sin_cos_pi_neg_three_and_five_sixteenths_cos:
+            qd!("-0.5555702330196022247428308139485328743749371907548040459241535282"),
+            qd!(-3.3125).sin_cos_pi().1;
+        sin_cos_pi_large_sin:
+            qd!("0.9238795325112867561281831893967882868224166258636424861150977313"),
+            Quad::new(1e20, 0.375, 0.0, 0.0).sin_cos_pi().0;
+        sin_cos_pi_large_cos:
+            qd!("0.3826834323650897717284599840303988667613445624856270414338006356"),
+            Quad::new(1e20, 0.375, 0.0, 0.0).sin_cos_pi().1;
+    );
+    test_all_exact!(
+        sin_cos_pi_zero_sin:
+            Quad::ZERO,
+            Quad::ZERO.sin_cos_pi().0;
+        sin_cos_pi_zero_cos:
+            Quad::ONE,
+            Quad::ZERO.sin_cos_pi().1;
+        sin_cos_pi_neg_zero_sin:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.sin_cos_pi().0;
+        sin_cos_pi_neg_zero_cos:
+            Quad::ONE,
+            Quad::NEG_ZERO.sin_cos_pi().1;
+        sin_cos_pi_half_sin:
+            Quad::ONE,
+            qd!(0.5).sin_cos_pi().0;
+        sin_cos_pi_half_cos:
+            Quad::ZERO,
+            qd!(0.5).sin_cos_pi().1;
+        sin_cos_pi_one_sin:
+            Quad::ZERO,
+            Quad::ONE.sin_cos_pi().0;
+        sin_cos_pi_one_cos:
+            Quad::NEG_ONE,
+            Quad::ONE.sin_cos_pi().1;
+        sin_cos_pi_three_halves_sin:
+            Quad::NEG_ONE,
+            qd!(1.5).sin_cos_pi().0;
+        sin_cos_pi_three_halves_cos:
+            Quad::ZERO,
+            qd!(1.5).sin_cos_pi().1;
+        sin_cos_pi_neg_half_sin:
+            Quad::NEG_ONE,
+            qd!(-0.5).sin_cos_pi().0;
+        sin_cos_pi_neg_half_cos:
+            Quad::ZERO,
+            qd!(-0.5).sin_cos_pi().1;
+        sin_cos_pi_two_sin:
+            Quad::ZERO,
+            qd!(2).sin_cos_pi().0;
+        sin_cos_pi_two_cos:
+            Quad::ONE,
+            qd!(2).sin_cos_pi().1;
+        sin_cos_pi_inf_sin:
+            Quad::NAN,
+            Quad::INFINITY.sin_cos_pi().0;
+        sin_cos_pi_inf_cos:
+            Quad::NAN,
+            Quad::INFINITY.sin_cos_pi().1;
+        sin_cos_pi_neg_inf_sin:
+            Quad::NAN,
+            Quad::NEG_INFINITY.sin_cos_pi().0;
+        sin_cos_pi_neg_inf_cos:
+            Quad::NAN,
+            Quad::NEG_INFINITY.sin_cos_pi().1;
+        sin_cos_pi_nan_sin:
+            Quad::NAN,
+            Quad::NAN.sin_cos_pi().0;
+        sin_cos_pi_nan_cos:
+            Quad::NAN,
+            Quad::NAN.sin_cos_pi().1;
+    );
+
     // tan tests
     test_all_near!(
         tan_zero:
@@ -1143,4 +1391,70 @@ mod tests {
             Quad::NAN,
             Quad::NAN.atan();
     );
+
+    // rem_2pi tests
+    test_all_near!(
+        rem_2pi_7:
+            qd!("0.716814692820413523074713233440994231605661201249788358050110815384"),
+            qd!(7).rem_2pi();
+        rem_2pi_neg_5:
+            qd!("1.28318530717958647692528676655900576839433879875021164194988918462"),
+            qd!(-5).rem_2pi();
+        rem_2pi_100:
+            qd!("-0.53096491487338363080458826494409229430942078000338627119822695385"),
+            qd!(100).rem_2pi();
+        rem_2pi_small:
+            Quad::FRAC_PI_3,
+            Quad::FRAC_PI_3.rem_2pi();
+    );
+    test_all_exact!(
+        rem_2pi_zero:
+            Quad::ZERO,
+            Quad::ZERO.rem_2pi();
+        rem_2pi_inf:
+            Quad::NAN,
+            Quad::INFINITY.rem_2pi();
+        rem_2pi_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.rem_2pi();
+        rem_2pi_nan:
+            Quad::NAN,
+            Quad::NAN.rem_2pi();
+    );
+
+    // reduce_angle tests
+    test_all_near!(
+        reduce_angle_10:
+            qd!("0.575222039230620284612069850161491347408491801874682537075166223077"),
+            qd!(10).reduce_angle().1;
+        reduce_angle_neg_5:
+            qd!("-0.287611019615310142306034925080745673704245900937341268537583111538"),
+            qd!(-5).reduce_angle().1;
+        reduce_angle_100:
+            qd!("-0.53096491487338363080458826494409229430942078000338627119822695385"),
+            qd!(100).reduce_angle().1;
+    );
+    test_all_exact!(
+        reduce_angle_10_quadrant:
+            2,
+            qd!(10).reduce_angle().0;
+        reduce_angle_neg_5_quadrant:
+            1,
+            qd!(-5).reduce_angle().0;
+        reduce_angle_100_quadrant:
+            0,
+            qd!(100).reduce_angle().0;
+        reduce_angle_neg_2_quadrant:
+            3,
+            qd!(-2).reduce_angle().0;
+        reduce_angle_inf_quadrant:
+            0,
+            Quad::INFINITY.reduce_angle().0;
+        reduce_angle_inf:
+            Quad::NAN,
+            Quad::INFINITY.reduce_angle().1;
+        reduce_angle_nan:
+            Quad::NAN,
+            Quad::NAN.reduce_angle().1;
+    );
 }
@@ -0,0 +1,638 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Random [`Double`]s and [`Quad`]s, using the `rand` crate.
+//!
+//! This module is enabled by the `rand` feature. It implements `rand`'s [`Standard`] and
+//! [`Open01`] distributions for `Double` and `Quad`, and it makes them [`SampleUniform`] so
+//! that they work with [`Uniform`] and [`Rng::gen_range`]. It also has its own [`Normal`],
+//! [`LogNormal`], and [`Exp`] distributions.
+//!
+//! With the `rand_distr` feature, `Double` and `Quad` also implement `rand_distr`'s
+//! `StandardNormal` and `Exp1`, with the same full-precision samples. That's all that
+//! `rand_distr`'s own `Normal`, `LogNormal`, and `Exp` need (along with the `Float`
+//! implementations from the `num-traits` feature), and `Gamma` and the distributions built
+//! on it need only those and `Open01`.
+//!
+//! The `f64` implementations of these distributions only produce 53 random bits, which
+//! would leave everything past the first component of a `Double` or a `Quad` empty. These
+//! fill every component instead: a `Double` gets 106 random bits and a `Quad` gets 212,
+//! so that the numbers are evenly spaced over the interval at the full precision of the
+//! type. That matters for Monte Carlo work at extended precision, where the error of the
+//! result can't be any smaller than the granularity of the random numbers that go into it.
+//!
+//! # Examples
+//! ```
+//! use qd::{qd, Quad};
+//! use rand::distributions::Uniform;
+//! use rand::rngs::StdRng;
+//! use rand::{Rng, SeedableRng};
+//!
+//! let mut rng = StdRng::seed_from_u64(1729);
+//!
+//! let x: Quad = rng.gen();
+//! assert!(x >= qd!(0) && x < qd!(1));
+//! // The last component is random too
+//! assert_ne!(x[3], 0.0);
+//!
+//! let range = Uniform::new(qd!(-1), qd!(1));
+//! let y = rng.sample(range);
+//! assert!(y >= qd!(-1) && y < qd!(1));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`Standard`]: rand::distributions::Standard
+//! [`Open01`]: rand::distributions::Open01
+//! [`SampleUniform`]: rand::distributions::uniform::SampleUniform
+//! [`Uniform`]: rand::distributions::Uniform
+//! [`Rng::gen_range`]: rand::Rng::gen_range
+//! [`Normal`]: crate::random::Normal
+//! [`LogNormal`]: crate::random::LogNormal
+//! [`Exp`]: crate::random::Exp
+
+use crate::double::Double;
+use crate::multi::MultiFloat;
+use crate::precision::MultiPrecision;
+use crate::quad::Quad;
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::{Distribution, Open01, Standard};
+use rand::Rng;
+#[cfg(feature = "rand_distr")]
+use rand_distr::{Exp1, StandardNormal};
+use std::fmt::{self, Display, Formatter};
+
+// 2^-53, the spacing of 53-bit random fractions in [0, 1)
+const SCALE: f64 = 1.0 / (1u64 << 53) as f64;
+
+/// The sampler that implements [`Uniform`] for [`Double`]s.
+///
+/// It's created by [`Uniform::new`] and [`Uniform::new_inclusive`] (or by `rand`'s range
+/// sampling methods) rather than directly. A number from the range is the lower bound
+/// plus a full-precision [`Standard`] sample scaled by the width of the range, so numbers
+/// are evenly spaced over the range but can't be any more closely spaced than the
+/// precision of the bounds allows.
+///
+/// # Panics
+/// Creating the sampler panics if either bound isn't finite, if the width of the range
+/// isn't finite, or if the lower bound is greater than the upper one (or equal to it,
+/// unless the range is inclusive).
+///
+/// [`Double`]: crate::Double
+/// [`Uniform`]: rand::distributions::Uniform
+/// [`Uniform::new`]: rand::distributions::Uniform::new
+/// [`Uniform::new_inclusive`]: rand::distributions::Uniform::new_inclusive
+/// [`Standard`]: rand::distributions::Standard
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformDouble {
+    low: Double,
+    high: Double,
+    scale: Double,
+    inclusive: bool,
+}
+
+/// The sampler that implements [`Uniform`] for [`Quad`]s.
+///
+/// It's created by [`Uniform::new`] and [`Uniform::new_inclusive`] (or by `rand`'s range
+/// sampling methods) rather than directly. A number from the range is the lower bound
+/// plus a full-precision [`Standard`] sample scaled by the width of the range, so numbers
+/// are evenly spaced over the range but can't be any more closely spaced than the
+/// precision of the bounds allows.
+///
+/// # Panics
+/// Creating the sampler panics if either bound isn't finite, if the width of the range
+/// isn't finite, or if the lower bound is greater than the upper one (or equal to it,
+/// unless the range is inclusive).
+///
+/// [`Quad`]: crate::Quad
+/// [`Uniform`]: rand::distributions::Uniform
+/// [`Uniform::new`]: rand::distributions::Uniform::new
+/// [`Uniform::new_inclusive`]: rand::distributions::Uniform::new_inclusive
+/// [`Standard`]: rand::distributions::Standard
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformQuad {
+    low: Quad,
+    high: Quad,
+    scale: Quad,
+    inclusive: bool,
+}
+
+macro_rules! impl_rand {
+    ($($t:ident $uniform:ident $n:literal),*) => {$(
+        impl Distribution<$t> for Standard {
+            /// Generates a number in [0, 1) with every bit of every component random.
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                // Each component holds the next 53 bits of the fraction. The components
+                // don't overlap, so collecting them into a normalized number is exact.
+                let mut parts = [0.0; $n];
+                let mut scale = 1.0;
+                for part in parts.iter_mut() {
+                    scale *= SCALE;
+                    *part = (rng.next_u64() >> 11) as f64 * scale;
+                }
+                $t::from(MultiFloat::<$n>::from_parts(&parts))
+            }
+        }
+
+        impl Distribution<$t> for Open01 {
+            /// Generates a number in (0, 1) with every bit of every component random.
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                // Zero comes up once in 2^(53n) samples, so this essentially never loops
+                loop {
+                    let x: $t = Standard.sample(rng);
+                    if x != $t::ZERO {
+                        return x;
+                    }
+                }
+            }
+        }
+
+        impl $uniform {
+            fn create(low: $t, high: $t, inclusive: bool) -> $uniform {
+                assert!(low.is_finite(), "Uniform::new called with non-finite lower bound");
+                assert!(high.is_finite(), "Uniform::new called with non-finite upper bound");
+                if inclusive {
+                    assert!(low <= high, "Uniform::new_inclusive called with `low > high`");
+                } else {
+                    assert!(low < high, "Uniform::new called with `low >= high`");
+                }
+                let scale = high - low;
+                assert!(scale.is_finite(), "Uniform::new: range overflow");
+                $uniform {
+                    low,
+                    high,
+                    scale,
+                    inclusive,
+                }
+            }
+        }
+
+        impl UniformSampler for $uniform {
+            type X = $t;
+
+            fn new<B1, B2>(low: B1, high: B2) -> $uniform
+            where
+                B1: SampleBorrow<$t> + Sized,
+                B2: SampleBorrow<$t> + Sized,
+            {
+                $uniform::create(*low.borrow(), *high.borrow(), false)
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> $uniform
+            where
+                B1: SampleBorrow<$t> + Sized,
+                B2: SampleBorrow<$t> + Sized,
+            {
+                $uniform::create(*low.borrow(), *high.borrow(), true)
+            }
+
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                // Rounding can carry the scaled sample up to (or past) the upper bound,
+                // which is rejected unless it's included in the range
+                loop {
+                    let u: $t = Standard.sample(rng);
+                    let x = self.low + self.scale * u;
+                    if x < self.high || (self.inclusive && x == self.high) {
+                        return x;
+                    }
+                }
+            }
+        }
+
+        impl SampleUniform for $t {
+            type Sampler = $uniform;
+        }
+    )*};
+}
+
+impl_rand!(Double UniformDouble 2, Quad UniformQuad 4);
+
+/// The reasons that the parameters of a distribution can be invalid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterError {
+    /// The mean (or the mean of the logarithm, for [`LogNormal`]) isn't finite.
+    ///
+    /// [`LogNormal`]: crate::random::LogNormal
+    MeanNotFinite,
+    /// The standard deviation is negative or isn't finite.
+    BadStdDev,
+    /// The rate of an exponential distribution isn't positive.
+    BadLambda,
+}
+
+impl Display for ParameterError {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let description = match self {
+            ParameterError::MeanNotFinite => "mean is not finite",
+            ParameterError::BadStdDev => "standard deviation is negative or not finite",
+            ParameterError::BadLambda => "lambda is not positive",
+        };
+        description.fmt(f)
+    }
+}
+
+/// The normal distribution with a given mean and standard deviation, for [`Double`]s and
+/// [`Quad`]s.
+///
+/// Samples are generated with the Box–Muller transform from two full-precision [`Open01`]
+/// samples. The smallest of those samples is 2<sup>-106</sup> for a `Double` and
+/// 2<sup>-212</sup> for a `Quad`, so samples can come from as far out as 12 or 17
+/// standard deviations from the mean. The same transform with `f64` samples can't get past
+/// about 8.5.
+///
+/// # Examples
+/// ```
+/// use qd::random::Normal;
+/// use qd::{qd, Quad};
+/// use rand::distributions::Distribution;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(1729);
+/// let normal = Normal::new(qd!(10), qd!(2)).unwrap();
+/// let sum = (0..1000).map(|_| normal.sample(&mut rng)).fold(qd!(0), |a, b| a + b);
+/// let mean = sum / qd!(1000);
+/// assert!((mean - qd!(10)).abs() < qd!(0.5));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+/// [`Open01`]: rand::distributions::Open01
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normal<T: MultiPrecision> {
+    mean: T,
+    std_dev: T,
+}
+
+impl<T: MultiPrecision> Normal<T> {
+    /// Creates a new normal distribution.
+    ///
+    /// # Errors
+    /// Returns an error if the mean isn't finite or if the standard deviation is negative
+    /// or isn't finite.
+    pub fn new(mean: T, std_dev: T) -> Result<Normal<T>, ParameterError> {
+        if !mean.is_finite() {
+            Err(ParameterError::MeanNotFinite)
+        } else if !std_dev.is_finite() || std_dev < T::ZERO {
+            Err(ParameterError::BadStdDev)
+        } else {
+            Ok(Normal { mean, std_dev })
+        }
+    }
+
+    /// Returns the mean of the distribution.
+    pub fn mean(&self) -> T {
+        self.mean
+    }
+
+    /// Returns the standard deviation of the distribution.
+    pub fn std_dev(&self) -> T {
+        self.std_dev
+    }
+}
+
+/// The log-normal distribution, whose logarithm has a normal distribution with a given
+/// mean and standard deviation, for [`Double`]s and [`Quad`]s.
+///
+/// Samples are the exponentials of [`Normal`] samples.
+///
+/// # Examples
+/// ```
+/// use qd::random::LogNormal;
+/// use qd::{dd, Double};
+/// use rand::distributions::Distribution;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(1729);
+/// let log_normal = LogNormal::new(dd!(0), dd!(1)).unwrap();
+/// assert!(log_normal.sample(&mut rng) > dd!(0));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+/// [`Normal`]: crate::random::Normal
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogNormal<T: MultiPrecision> {
+    normal: Normal<T>,
+}
+
+impl<T: MultiPrecision> LogNormal<T> {
+    /// Creates a new log-normal distribution from the mean and the standard deviation of
+    /// its logarithm.
+    ///
+    /// # Errors
+    /// Returns an error if the mean isn't finite or if the standard deviation is negative
+    /// or isn't finite.
+    pub fn new(mu: T, sigma: T) -> Result<LogNormal<T>, ParameterError> {
+        Ok(LogNormal {
+            normal: Normal::new(mu, sigma)?,
+        })
+    }
+}
+
+/// The exponential distribution with a given rate, for [`Double`]s and [`Quad`]s.
+///
+/// Samples are generated by inverting the cumulative distribution function, `-ln(u) / λ`,
+/// with `u` a full-precision [`Open01`] sample. Since `u` can be as small as
+/// 2<sup>-106</sup> for a `Double` and 2<sup>-212</sup> for a `Quad`, samples can be as
+/// large as 73.5 / λ or 146.9 / λ, where inverting an `f64` sample stops at about 36.7 / λ.
+///
+/// # Examples
+/// ```
+/// use qd::random::Exp;
+/// use qd::{qd, Quad};
+/// use rand::distributions::Distribution;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(1729);
+/// let exp = Exp::new(qd!(4)).unwrap();
+/// let sum = (0..1000).map(|_| exp.sample(&mut rng)).fold(qd!(0), |a, b| a + b);
+/// let mean = sum / qd!(1000);
+/// assert!((mean - qd!(0.25)).abs() < qd!(0.05));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+/// [`Open01`]: rand::distributions::Open01
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Exp<T: MultiPrecision> {
+    lambda: T,
+}
+
+impl<T: MultiPrecision> Exp<T> {
+    /// Creates a new exponential distribution with rate `lambda`.
+    ///
+    /// # Errors
+    /// Returns an error if `lambda` isn't positive (or is NaN). An infinite rate is
+    /// allowed and always produces 0.
+    pub fn new(lambda: T) -> Result<Exp<T>, ParameterError> {
+        if lambda > T::ZERO {
+            Ok(Exp { lambda })
+        } else {
+            Err(ParameterError::BadLambda)
+        }
+    }
+
+    /// Returns the rate of the distribution.
+    pub fn lambda(&self) -> T {
+        self.lambda
+    }
+}
+
+// Samples of the standard normal distribution and the exponential distribution with a rate
+// of 1, which the distributions above (and those in `rand_distr`) shift and scale.
+trait Unit: Sized {
+    fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> Self;
+    fn exp1<R: Rng + ?Sized>(rng: &mut R) -> Self;
+}
+
+macro_rules! impl_distr {
+    ($($t:ident),*) => {$(
+        impl Unit for $t {
+            fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> $t {
+                let u: $t = Open01.sample(rng);
+                let v: $t = Standard.sample(rng);
+                let r = (-u.ln().mul_pwr2(2.0)).sqrt();
+                // cos(2πv), with the argument reduction done exactly on v itself
+                let (_, cos) = v.mul_pwr2(2.0).sin_cos_pi();
+                r * cos
+            }
+
+            fn exp1<R: Rng + ?Sized>(rng: &mut R) -> $t {
+                let u: $t = Open01.sample(rng);
+                -u.ln()
+            }
+        }
+
+        impl Distribution<$t> for Normal<$t> {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                self.mean + self.std_dev * $t::standard_normal(rng)
+            }
+        }
+
+        impl Distribution<$t> for LogNormal<$t> {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                let x: $t = self.normal.sample(rng);
+                x.exp()
+            }
+        }
+
+        impl Distribution<$t> for Exp<$t> {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                let x = $t::exp1(rng);
+                if self.lambda.is_infinite() {
+                    $t::ZERO
+                } else {
+                    x / self.lambda
+                }
+            }
+        }
+
+        #[cfg(feature = "rand_distr")]
+        impl Distribution<$t> for StandardNormal {
+            /// Generates a number from the standard normal distribution, the same way that
+            /// [`Normal`] does.
+            ///
+            /// [`Normal`]: crate::random::Normal
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                $t::standard_normal(rng)
+            }
+        }
+
+        #[cfg(feature = "rand_distr")]
+        impl Distribution<$t> for Exp1 {
+            /// Generates a number from the exponential distribution with a rate of 1, the
+            /// same way that [`Exp`] does.
+            ///
+            /// [`Exp`]: crate::random::Exp
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                $t::exp1(rng)
+            }
+        }
+    )*};
+}
+
+impl_distr!(Double, Quad);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::Moments;
+    use crate::{dd, qd};
+    use rand::distributions::Uniform;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn double_standard() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let xs: Vec<Double> = (0..10000).map(|_| rng.gen()).collect();
+        assert!(xs.iter().all(|&x| x >= dd!(0) && x < dd!(1)));
+        // Nearly every sample should have a distinct second component
+        let mut lows: Vec<u64> = xs.iter().map(|x| x[1].to_bits()).collect();
+        lows.sort_unstable();
+        lows.dedup();
+        assert!(lows.len() > 9990);
+        let mean = xs.iter().fold(dd!(0), |acc, &x| acc + x) / dd!(10000);
+        assert!((mean - dd!(0.5)).abs() < dd!(0.01));
+    }
+
+    #[test]
+    fn quad_standard() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let xs: Vec<Quad> = (0..10000).map(|_| rng.gen()).collect();
+        assert!(xs.iter().all(|&x| x >= qd!(0) && x < qd!(1)));
+        let mut lows: Vec<u64> = xs.iter().map(|x| x[3].to_bits()).collect();
+        lows.sort_unstable();
+        lows.dedup();
+        assert!(lows.len() > 9990);
+        let mean = xs.iter().fold(qd!(0), |acc, &x| acc + x) / qd!(10000);
+        assert!((mean - qd!(0.5)).abs() < qd!(0.01));
+    }
+
+    #[test]
+    fn open01() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..1000 {
+            let x: Double = rng.sample(Open01);
+            assert!(x > dd!(0) && x < dd!(1));
+            let y: Quad = rng.sample(Open01);
+            assert!(y > qd!(0) && y < qd!(1));
+        }
+    }
+
+    #[test]
+    fn uniform() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let range = Uniform::new(dd!(-3), dd!(5));
+        let inclusive = Uniform::new_inclusive(qd!(2), qd!(2));
+        for _ in 0..1000 {
+            let x = rng.sample(range);
+            assert!(x >= dd!(-3) && x < dd!(5));
+            assert_eq!(rng.sample(inclusive), qd!(2));
+            let y = rng.gen_range(Quad::PI..Quad::E * qd!(2));
+            assert!(y >= Quad::PI && y < Quad::E * qd!(2));
+        }
+
+        // A range narrower than an f64 ulp still gets full-precision samples
+        let low = dd!(1);
+        let high = dd!(1) + Double::new(1e-20, 0.0);
+        let narrow = Uniform::new(low, high);
+        let samples: Vec<Double> = (0..100).map(|_| rng.sample(narrow)).collect();
+        assert!(samples.iter().all(|&x| x >= low && x < high));
+        assert!(samples.windows(2).any(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn uniform_empty() {
+        Uniform::new(qd!(1), qd!(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn uniform_infinite() {
+        Uniform::new(dd!(0), Double::INFINITY);
+    }
+
+    #[test]
+    fn normal() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let normal = Normal::new(qd!(-2), qd!(3)).unwrap();
+        let xs: Vec<Quad> = (0..20000).map(|_| normal.sample(&mut rng)).collect();
+        let moments: Moments<Quad> = xs.iter().collect();
+        assert!((moments.mean() - qd!(-2)).abs() < qd!(0.1));
+        assert!((moments.std_dev() - qd!(3)).abs() < qd!(0.1));
+        assert!(moments.skewness().abs() < qd!(0.1));
+        assert!(moments.kurtosis().abs() < qd!(0.2));
+
+        let degenerate = Normal::new(dd!(1), dd!(0)).unwrap();
+        assert_eq!(degenerate.sample(&mut rng), dd!(1));
+        assert_eq!(normal.mean(), qd!(-2));
+        assert_eq!(normal.std_dev(), qd!(3));
+
+        assert_eq!(
+            Normal::new(Double::NAN, dd!(1)),
+            Err(ParameterError::MeanNotFinite)
+        );
+        assert_eq!(Normal::new(dd!(0), dd!(-1)), Err(ParameterError::BadStdDev));
+        assert_eq!(
+            Normal::new(qd!(0), Quad::INFINITY),
+            Err(ParameterError::BadStdDev)
+        );
+    }
+
+    #[test]
+    fn log_normal() {
+        let mut rng = StdRng::seed_from_u64(6);
+        let log_normal = LogNormal::new(dd!(1), dd!(0.5)).unwrap();
+        let logs: Moments<Double> = (0..20000)
+            .map(|_| {
+                let x: Double = log_normal.sample(&mut rng);
+                assert!(x > dd!(0));
+                x.ln()
+            })
+            .collect();
+        assert!((logs.mean() - dd!(1)).abs() < dd!(0.05));
+        assert!((logs.std_dev() - dd!(0.5)).abs() < dd!(0.05));
+        assert_eq!(
+            LogNormal::new(dd!(0), dd!(-1)),
+            Err(ParameterError::BadStdDev)
+        );
+    }
+
+    #[test]
+    fn exp() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let exp = Exp::new(dd!(0.5)).unwrap();
+        let xs: Moments<Double> = (0..20000)
+            .map(|_| {
+                let x: Double = exp.sample(&mut rng);
+                assert!(x > dd!(0));
+                x
+            })
+            .collect();
+        assert!((xs.mean() - dd!(2)).abs() < dd!(0.1));
+        assert!((xs.std_dev() - dd!(2)).abs() < dd!(0.1));
+        assert_eq!(exp.lambda(), dd!(0.5));
+
+        let infinite = Exp::new(Quad::INFINITY).unwrap();
+        assert_eq!(infinite.sample(&mut rng), qd!(0));
+        assert_eq!(Exp::new(dd!(0)), Err(ParameterError::BadLambda));
+        assert_eq!(Exp::new(Double::NAN), Err(ParameterError::BadLambda));
+        assert_eq!(
+            ParameterError::BadLambda.to_string(),
+            "lambda is not positive"
+        );
+    }
+
+    #[cfg(feature = "rand_distr")]
+    #[test]
+    fn rand_distr_distributions() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let normal = rand_distr::Normal::new(qd!(-2), qd!(3)).unwrap();
+        let xs: Moments<Quad> = (0..20000).map(|_| normal.sample(&mut rng)).collect();
+        assert!((xs.mean() - qd!(-2)).abs() < qd!(0.1));
+        assert!((xs.std_dev() - qd!(3)).abs() < qd!(0.1));
+
+        let exp = rand_distr::Exp::new(dd!(0.5)).unwrap();
+        let xs: Moments<Double> = (0..20000).map(|_| exp.sample(&mut rng)).collect();
+        assert!((xs.mean() - dd!(2)).abs() < dd!(0.1));
+        assert!((xs.std_dev() - dd!(2)).abs() < dd!(0.1));
+
+        // Shape 3 and scale 2, for a mean of 6 and a variance of 12
+        let gamma = rand_distr::Gamma::new(dd!(3), dd!(2)).unwrap();
+        let xs: Moments<Double> = (0..20000).map(|_| gamma.sample(&mut rng)).collect();
+        assert!((xs.mean() - dd!(6)).abs() < dd!(0.1));
+        assert!((xs.variance() - dd!(12)).abs() < dd!(0.5));
+
+        // Standard normal samples have every component filled in, as with `Normal`
+        let x: Quad = rng.sample(rand_distr::StandardNormal);
+        assert_ne!(x[3], 0.0);
+    }
+}
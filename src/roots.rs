@@ -0,0 +1,583 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Finding the roots of functions of [`Double`]s and [`Quad`]s.
+//!
+//! Each of the root finders here takes a closure that evaluates a function (and, for some
+//! of them, its derivatives) and works with either [`Double`]s or [`Quad`]s through the
+//! [`MultiPrecision`] trait.
+//!
+//! * [`newton`] and [`halley`] start from a single guess and use the first derivative, or
+//!   the first two derivatives, to converge quadratically or cubically to a nearby root.
+//!   They're the fastest when a good guess and the derivatives are available, but they
+//!   can wander off or fail to converge from a bad guess.
+//! * [`brent`] and [`bisection`] need an interval whose endpoints bracket a root, meaning
+//!   that the function has opposite signs at them, and are then guaranteed to find a root
+//!   in that interval. Brent's method combines bisection with interpolation and is
+//!   usually nearly as fast as Newton's method; plain bisection is slow but never fails.
+//!
+//! How closely the root is found and how long to keep trying are set with [`Options`].
+//! A root finder that doesn't succeed returns a [`RootError`] that says why.
+//!
+//! # Examples
+//! ```
+//! use qd::roots::{brent, newton, Options};
+//! use qd::{dd, qd, Double, Quad};
+//!
+//! // The cube root of 2, from its derivative
+//! let x = newton(|x| (x * x * x - dd!(2), dd!(3) * x * x), dd!(1), Options::default());
+//! assert!((x.unwrap() - dd!(2).cbrt()).abs() < dd!(1e-31));
+//!
+//! // The fixed point of the cosine, from a bracket
+//! let x = brent(|x: Quad| x.cos() - x, qd!(0), qd!(1), Options::default()).unwrap();
+//! assert!((x.cos() - x).abs() < qd!(1e-62));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`MultiPrecision`]: crate::MultiPrecision
+//! [`newton`]: crate::roots::newton
+//! [`halley`]: crate::roots::halley
+//! [`brent`]: crate::roots::brent
+//! [`bisection`]: crate::roots::bisection
+//! [`Options`]: crate::roots::Options
+//! [`RootError`]: crate::roots::RootError
+
+use crate::precision::MultiPrecision;
+use std::fmt::{self, Display, Formatter};
+
+/// The tolerance and the iteration limit of a root finder.
+///
+/// A root finder stops successfully when its estimate of the root is known to within
+/// `ulps` units in the last place, and it gives up after evaluating the function
+/// `max_iterations` times. The default is 4 ulps and 500 iterations.
+///
+/// A tolerance in ulps shrinks along with the root, so a root at or very near zero could
+/// never be reached by it. `abs_tolerance` sets a smallest tolerance for that case. When
+/// it's `None`, as it is by default, the smallest tolerance is `ulps` units in the last
+/// place of the largest endpoint of the bracket (for [`brent`] and [`bisection`]) or of
+/// the starting guess (for [`newton`] and [`halley`]), times `f64::EPSILON`. That only
+/// matters for roots that are many orders of magnitude closer to zero than the search
+/// started, and it's still reached within the default number of iterations.
+///
+/// # Examples
+/// ```
+/// # use qd::roots::Options;
+/// let options = Options::new(1, 50);
+/// assert_eq!(options.ulps, 1);
+/// assert_eq!(Options::default(), Options::new(4, 500));
+///
+/// let options = Options::default().with_abs_tolerance(1e-100);
+/// assert_eq!(options.abs_tolerance, Some(1e-100));
+/// ```
+///
+/// [`newton`]: fn.newton.html
+/// [`halley`]: fn.halley.html
+/// [`brent`]: fn.brent.html
+/// [`bisection`]: fn.bisection.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Options {
+    /// The number of ulps of the root that it must be known to within.
+    pub ulps: u32,
+    /// The maximum number of iterations before the root finder gives up.
+    pub max_iterations: usize,
+    /// The smallest absolute tolerance, or `None` to take it from the scale of the search.
+    pub abs_tolerance: Option<f64>,
+}
+
+impl Options {
+    /// Creates new root finder options with a tolerance in ulps and an iteration limit.
+    pub const fn new(ulps: u32, max_iterations: usize) -> Options {
+        Options {
+            ulps,
+            max_iterations,
+            abs_tolerance: None,
+        }
+    }
+
+    /// Returns the options with the given smallest absolute tolerance.
+    pub const fn with_abs_tolerance(self, abs_tolerance: f64) -> Options {
+        Options {
+            abs_tolerance: Some(abs_tolerance),
+            ..self
+        }
+    }
+
+    // The smallest tolerance for a search on the scale of `scale`.
+    fn floor<T: MultiPrecision>(self, scale: T) -> T {
+        match self.abs_tolerance {
+            Some(tol) => T::from(tol),
+            None => scale.abs().precision_ulp() * T::from(self.ulps as f64 * f64::EPSILON),
+        }
+    }
+
+    // The tolerance for a root near `x`, which is never less than `floor`.
+    fn tolerance<T: MultiPrecision>(self, x: T, floor: T) -> T {
+        let tol = x.precision_ulp() * T::from(self.ulps as f64);
+        if tol < floor {
+            floor
+        } else {
+            tol
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options::new(4, 500)
+    }
+}
+
+/// The reasons that a root finder can fail.
+///
+/// The variants that carry a value give the last estimate of the root before the failure,
+/// which can be useful for deciding what to do next.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RootError<T> {
+    /// The function has the same sign at both ends of the interval, so it's not known to
+    /// have a root between them.
+    NotBracketed,
+    /// The derivative became zero, so the next estimate can't be calculated.
+    ZeroDerivative(T),
+    /// The function or the estimate of the root became infinite or NaN.
+    NotFinite(T),
+    /// The root wasn't found within the tolerance in the allowed number of iterations.
+    NoConvergence(T),
+}
+
+impl<T> Display for RootError<T> {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let description = match self {
+            RootError::NotBracketed => "root is not bracketed",
+            RootError::ZeroDerivative(_) => "derivative is zero",
+            RootError::NotFinite(_) => "function is not finite",
+            RootError::NoConvergence(_) => "root finder did not converge",
+        };
+        description.fmt(f)
+    }
+}
+
+/// Finds a root of a function with Newton's method.
+///
+/// The closure returns the value of the function and its first derivative at its
+/// argument. Starting from `x0`, each step moves the estimate of the root by `-f / f'`,
+/// and the root is found when the step is within the tolerance of [`Options`].
+///
+/// Convergence is quadratic, doubling the number of correct digits with every step, as
+/// long as the guess is close enough to a simple root.
+///
+/// # Examples
+/// ```
+/// # use qd::roots::{newton, Options};
+/// # use qd::{qd, Quad};
+/// let x = newton(|x| (x * x - qd!(2), qd!(2) * x), qd!(1), Options::default()).unwrap();
+/// assert!((x - Quad::SQRT_2).abs() < qd!(1e-62));
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub fn newton<T, F>(f: F, x0: T, options: Options) -> Result<T, RootError<T>>
+where
+    T: MultiPrecision,
+    F: Fn(T) -> (T, T),
+{
+    iterate(x0, options, |x| {
+        let (fx, dfx) = f(x);
+        if fx == T::ZERO {
+            Ok(T::ZERO)
+        } else if dfx == T::ZERO {
+            Err(RootError::ZeroDerivative(x))
+        } else {
+            Ok(fx / dfx)
+        }
+    })
+}
+
+/// Finds a root of a function with Halley's method.
+///
+/// The closure returns the value of the function and its first and second derivatives at
+/// its argument. Starting from `x0`, each step moves the estimate of the root by
+/// `-2ff' / (2f'² - ff'')`, and the root is found when the step is within the tolerance of
+/// [`Options`].
+///
+/// Convergence is cubic, tripling the number of correct digits with every step, as long
+/// as the guess is close enough to a simple root.
+///
+/// # Examples
+/// ```
+/// # use qd::roots::{halley, Options};
+/// # use qd::{dd, Double};
+/// let f = |x: Double| (x.exp() - dd!(2), x.exp(), x.exp());
+/// let x = halley(f, dd!(1), Options::default()).unwrap();
+/// assert!((x - Double::LN_2).abs() < dd!(1e-31));
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub fn halley<T, F>(f: F, x0: T, options: Options) -> Result<T, RootError<T>>
+where
+    T: MultiPrecision,
+    F: Fn(T) -> (T, T, T),
+{
+    iterate(x0, options, |x| {
+        let (fx, dfx, d2fx) = f(x);
+        if fx == T::ZERO {
+            return Ok(T::ZERO);
+        }
+        let denom = (dfx * dfx).mul_pwr2(2.0) - fx * d2fx;
+        if denom == T::ZERO {
+            Err(RootError::ZeroDerivative(x))
+        } else {
+            Ok((fx * dfx).mul_pwr2(2.0) / denom)
+        }
+    })
+}
+
+/// Finds a root of a function in an interval with Brent's method.
+///
+/// The function must have opposite signs at `a` and `b` (or be zero at one of them). Each
+/// step narrows down the interval by inverse quadratic interpolation or by the secant
+/// method when that's making good progress, and by bisection when it isn't, so the root is
+/// always found but usually much faster than by bisection alone. It's found when it's known
+/// to within the tolerance of [`Options`].
+///
+/// # Examples
+/// ```
+/// # use qd::roots::{brent, Options};
+/// # use qd::{dd, Double};
+/// let x = brent(|x: Double| x.sin(), dd!(3), dd!(4), Options::default()).unwrap();
+/// assert!((x - Double::PI).abs() < dd!(1e-31));
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub fn brent<T, F>(f: F, a: T, b: T, options: Options) -> Result<T, RootError<T>>
+where
+    T: MultiPrecision,
+    F: Fn(T) -> T,
+{
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if let Some(r) = check_bracket(a, fa, b, fb) {
+        return r;
+    }
+    let floor = options.floor(if a.abs() < b.abs() { b } else { a });
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+
+    for _ in 0..options.max_iterations {
+        if !fb.is_finite() {
+            return Err(RootError::NotFinite(b));
+        }
+        if fb == T::ZERO {
+            return Ok(b);
+        }
+        if fb.is_sign_negative() == fc.is_sign_negative() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tol = options.tolerance(b, floor).mul_pwr2(0.5);
+        let m = (c - b).mul_pwr2(0.5);
+        if m.abs() <= tol {
+            return Ok(b);
+        }
+
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            // Interpolate, with the secant method if there are only two distinct points and
+            // inverse quadratic interpolation otherwise
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (m.mul_pwr2(2.0) * s, T::ONE - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (m.mul_pwr2(2.0) * q * (q - r) - (b - a) * (r - T::ONE)),
+                    (q - T::ONE) * (r - T::ONE) * (s - T::ONE),
+                )
+            };
+            if p > T::ZERO {
+                q = -q;
+            } else {
+                p = -p;
+            }
+            // Accept the interpolation only if it falls well within the interval and is
+            // converging faster than bisection would
+            let min1 = T::from(3.0) * m * q - (tol * q).abs();
+            let min2 = (e * q).abs();
+            if p.mul_pwr2(2.0) < if min1 < min2 { min1 } else { min2 } {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = d;
+            }
+        } else {
+            d = m;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b = if d.abs() > tol {
+            b + d
+        } else if m > T::ZERO {
+            b + tol
+        } else {
+            b - tol
+        };
+        fb = f(b);
+    }
+    Err(RootError::NoConvergence(b))
+}
+
+/// Finds a root of a function in an interval by bisection.
+///
+/// The function must have opposite signs at `a` and `b` (or be zero at one of them). Each
+/// step halves the interval, keeping the half whose endpoints still have opposite signs,
+/// until the root is known to within the tolerance of [`Options`] or there are no more
+/// numbers between the endpoints. This always works, but it only gains one bit of the root
+/// with each step, so a small absolute tolerance for a root very close to zero can take
+/// more iterations than the default allows.
+///
+/// # Examples
+/// ```
+/// # use qd::roots::{bisection, Options};
+/// # use qd::{qd, Quad};
+/// let x = bisection(|x| x * x - qd!(2), qd!(0), qd!(2), Options::default()).unwrap();
+/// assert!((x - Quad::SQRT_2).abs() < qd!(1e-62));
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub fn bisection<T, F>(f: F, a: T, b: T, options: Options) -> Result<T, RootError<T>>
+where
+    T: MultiPrecision,
+    F: Fn(T) -> T,
+{
+    let (mut a, mut b) = (a, b);
+    let fa = f(a);
+    let fb = f(b);
+    if let Some(r) = check_bracket(a, fa, b, fb) {
+        return r;
+    }
+    let negative = fa.is_sign_negative();
+    let floor = options.floor(if a.abs() < b.abs() { b } else { a });
+
+    for _ in 0..options.max_iterations {
+        let mid = a.mul_pwr2(0.5) + b.mul_pwr2(0.5);
+        if (b - a).abs() <= options.tolerance(mid, floor) || mid == a || mid == b {
+            return Ok(mid);
+        }
+        let fm = f(mid);
+        if fm.is_nan() {
+            return Err(RootError::NotFinite(mid));
+        }
+        if fm == T::ZERO {
+            return Ok(mid);
+        }
+        if fm.is_sign_negative() == negative {
+            a = mid;
+        } else {
+            b = mid;
+        }
+    }
+    Err(RootError::NoConvergence(a.mul_pwr2(0.5) + b.mul_pwr2(0.5)))
+}
+
+// Checks the function values at the ends of a bracket, returning the result if one of them
+// is a root or if the interval doesn't bracket a root, and `None` if it does.
+fn check_bracket<T: MultiPrecision>(a: T, fa: T, b: T, fb: T) -> Option<Result<T, RootError<T>>> {
+    if fa.is_nan() {
+        Some(Err(RootError::NotFinite(a)))
+    } else if fb.is_nan() {
+        Some(Err(RootError::NotFinite(b)))
+    } else if fa == T::ZERO {
+        Some(Ok(a))
+    } else if fb == T::ZERO {
+        Some(Ok(b))
+    } else if fa.is_sign_negative() == fb.is_sign_negative() {
+        Some(Err(RootError::NotBracketed))
+    } else {
+        None
+    }
+}
+
+// Runs Newton-like iterations, where `step` returns the amount to subtract from the current
+// estimate of the root.
+fn iterate<T, S>(x0: T, options: Options, step: S) -> Result<T, RootError<T>>
+where
+    T: MultiPrecision,
+    S: Fn(T) -> Result<T, RootError<T>>,
+{
+    let floor = options.floor(x0);
+    let mut x = x0;
+    for _ in 0..options.max_iterations {
+        let dx = step(x)?;
+        let next = x - dx;
+        if !next.is_finite() {
+            return Err(RootError::NotFinite(x));
+        }
+        if dx.abs() <= options.tolerance(next, floor) {
+            return Ok(next);
+        }
+        x = next;
+    }
+    Err(RootError::NoConvergence(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd, Double, Quad};
+
+    // The fixed point of the cosine, from mpmath
+    const DOTTIE: &str = "0.73908513321516064165531208767387340401341175890075746496568063577";
+
+    #[test]
+    fn double_newton() {
+        let f = |x: Double| (x * x - dd!(2), dd!(2) * x);
+        let x = newton(f, dd!(1), Options::default()).unwrap();
+        assert!((x - Double::SQRT_2).abs() <= Double::SQRT_2.precision_ulp() * dd!(4));
+        let x = newton(f, dd!(-1), Options::default()).unwrap();
+        assert!((x + Double::SQRT_2).abs() <= Double::SQRT_2.precision_ulp() * dd!(4));
+    }
+
+    #[test]
+    fn quad_newton() {
+        let f = |x: Quad| (x * x - qd!(2), qd!(2) * x);
+        let x = newton(f, qd!(1), Options::default()).unwrap();
+        assert!((x - Quad::SQRT_2).abs() <= Quad::SQRT_2.precision_ulp() * qd!(4));
+    }
+
+    #[test]
+    fn newton_errors() {
+        let f = |x: Double| (x * x + dd!(1), dd!(2) * x);
+        assert_eq!(
+            newton(f, dd!(0), Options::default()),
+            Err(RootError::ZeroDerivative(dd!(0)))
+        );
+        assert!(matches!(
+            newton(f, dd!(0.5), Options::new(4, 20)),
+            Err(RootError::NoConvergence(_))
+        ));
+        let f = |x: Double| (x.exp() - dd!(2), dd!(1e-300));
+        assert!(matches!(
+            newton(f, dd!(1), Options::default()),
+            Err(RootError::NotFinite(_))
+        ));
+        assert_eq!(
+            newton(|x| (x, dd!(0)), dd!(0), Options::default()),
+            Ok(dd!(0))
+        );
+    }
+
+    #[test]
+    fn halley_cube_root() {
+        let f = |x: Quad| (x * x * x - qd!(2), qd!(3) * x * x, qd!(6) * x);
+        let x = halley(f, qd!(1), Options::default()).unwrap();
+        assert!((x - qd!(2).cbrt()).abs() < qd!(1e-62));
+
+        let f = |x: Double| (x * x * x - dd!(2), dd!(3) * x * x, dd!(6) * x);
+        let x = halley(f, dd!(1), Options::default()).unwrap();
+        assert!((x - dd!(2).cbrt()).abs() < dd!(1e-31));
+    }
+
+    #[test]
+    fn brent_dottie() {
+        let x = brent(|x: Quad| x.cos() - x, qd!(0), qd!(1), Options::default()).unwrap();
+        assert!((x - Quad::from(DOTTIE)).abs() < qd!(1e-62));
+        let x = brent(|x: Double| x.cos() - x, dd!(0), dd!(1), Options::default()).unwrap();
+        assert!((x - Double::from(DOTTIE)).abs() < dd!(1e-31));
+    }
+
+    #[test]
+    fn brent_errors() {
+        let f = |x: Double| x * x + dd!(1);
+        assert_eq!(
+            brent(f, dd!(-1), dd!(1), Options::default()),
+            Err(RootError::NotBracketed)
+        );
+        assert_eq!(brent(|x| x, dd!(0), dd!(1), Options::default()), Ok(dd!(0)));
+        assert!(matches!(
+            brent(|x: Quad| x.cos() - x, qd!(0), qd!(1), Options::new(4, 3)),
+            Err(RootError::NoConvergence(_))
+        ));
+    }
+
+    #[test]
+    fn bisection_roots() {
+        let f = |x: Double| x * x * x - dd!(2);
+        let x = bisection(f, dd!(0), dd!(2), Options::default()).unwrap();
+        assert!((x - dd!(2).cbrt()).abs() < dd!(1e-31));
+
+        let f = |x: Quad| x.cos() - x;
+        let x = bisection(f, qd!(1), qd!(0), Options::default()).unwrap();
+        assert!((x - Quad::from(DOTTIE)).abs() < qd!(1e-62));
+
+        assert_eq!(
+            bisection(|x| x, qd!(-1), qd!(1), Options::default()),
+            Ok(qd!(0))
+        );
+        assert_eq!(
+            bisection(|x| x * x + qd!(1), qd!(-1), qd!(2), Options::default()),
+            Err(RootError::NotBracketed)
+        );
+        assert!(matches!(
+            bisection(|x| x - qd!(0.1), qd!(0), qd!(1), Options::new(4, 10)),
+            Err(RootError::NoConvergence(_))
+        ));
+    }
+
+    #[test]
+    fn roots_at_zero() {
+        let options = Options::default();
+        let x = bisection(|x| x.sin(), dd!(-1), dd!(2), options).unwrap();
+        assert!(x.abs() < dd!(1e-45));
+        let x = brent(|x| x * x * x, dd!(-1), dd!(2), options).unwrap();
+        assert!(x.abs() < dd!(1e-45));
+        let x = brent(|x: Quad| x.sin(), qd!(-1), qd!(2), options).unwrap();
+        assert!(x.abs() < qd!(1e-76));
+        let x = bisection(|x: Quad| x * x * x, qd!(-2), qd!(1), options).unwrap();
+        assert!(x.abs() < qd!(1e-76));
+        let x = newton(|x| (x * x * x, dd!(3) * x * x), dd!(1), options).unwrap();
+        assert!(x.abs() < dd!(1e-45));
+
+        let options = Options::default().with_abs_tolerance(1e-100);
+        let x = bisection(
+            |x| x.sin(),
+            dd!(-1),
+            dd!(2),
+            Options {
+                max_iterations: 1000,
+                ..options
+            },
+        );
+        assert!(x.unwrap().abs() <= dd!(1e-100));
+        let x = brent(|x| x - dd!(1e-60), dd!(-1), dd!(1), options).unwrap();
+        assert!((x - dd!(1e-60)).abs() <= dd!(1e-100));
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            RootError::<Double>::NotBracketed.to_string(),
+            "root is not bracketed"
+        );
+        assert_eq!(
+            RootError::NoConvergence(dd!(1)).to_string(),
+            "root finder did not converge"
+        );
+    }
+}
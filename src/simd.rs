@@ -0,0 +1,1034 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Batched [`Double`]s that are operated on four at a time.
+//!
+//! A single double-double operation is a long chain of dependent `f64` operations, so on
+//! its own it can't keep a modern processor's floating-point units busy. Code that does
+//! the same thing to many independent numbers (Monte Carlo simulations, ray tracers, and
+//! the like) can do better by running several of those chains side by side.
+//!
+//! [`Doublex4`] stores four `Double`s in *structure-of-arrays* form: the first components
+//! of all four in one `wide::f64x4` vector and the second components in another. Each
+//! step of the addition, subtraction, multiplication, and division algorithms is then a
+//! single vector instruction (or two, on processors without 256-bit vectors). Compiling
+//! with `-C target-cpu=native` lets `wide` use the widest vector instructions the processor
+//! has, including FMA; without FMA, products are split into halves as they are with the
+//! `no_fma` feature, which is still much faster than the software `mul_add` that the
+//! scalar operators fall back to.
+//!
+//! Every lane of a result is exactly the same as the result of the corresponding scalar
+//! operation, including for infinities, NaNs, and zeros. Lanes that the vector algorithms
+//! can't handle exactly the way the scalar ones do (special values, and for
+//! multiplication and division, numbers larger than 2<sup>400</sup> or smaller than
+//! 2<sup>-400</sup> in magnitude) are redone with the scalar operators, so batches that
+//! are full of them aren't any faster.
+//!
+//! There's no quad-double counterpart. `Quad` addition merges the components of its
+//! operands in order of magnitude and its renormalization skips zero components, so the
+//! lanes of a batch would rarely take the same path through either one.
+//!
+//! For data that's already in slices, [`add_slices`], [`mul_slices`], and [`fma_slices`]
+//! run whole slices of `Double`s through `Doublex4` arithmetic, and [`widen_slice`] turns a
+//! slice of `f64`s into `Double`s.
+//!
+//! This module is only available with the `simd` feature.
+//!
+//! # Examples
+//! ```
+//! use qd::simd::Doublex4;
+//! use qd::{dd, Double};
+//!
+//! let x = Doublex4::from([dd!(1), dd!(2), dd!(3), dd!(4)]);
+//! let y = Doublex4::splat(Double::PI);
+//! let z = x * y;
+//!
+//! assert!(z.extract(2) == dd!(3) * Double::PI);
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Doublex4`]: struct.Doublex4.html
+//! [`add_slices`]: fn.add_slices.html
+//! [`mul_slices`]: fn.mul_slices.html
+//! [`fma_slices`]: fn.fma_slices.html
+//! [`widen_slice`]: fn.widen_slice.html
+
+use crate::double::Double;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use wide::{f64x4, CmpGe, CmpLe};
+
+/// Four [`Double`]s that are operated on together.
+///
+/// The first components of the four numbers are stored in one vector and the second
+/// components in another. Addition, subtraction, multiplication, and division run the
+/// same algorithms as the `Double` operators on all four lanes at once; lanes whose
+/// operands need special-case handling are then recomputed with the scalar operators so
+/// that the results are identical.
+///
+/// # Examples
+/// ```
+/// use qd::simd::Doublex4;
+/// use qd::{dd, Double};
+///
+/// let mut x = Doublex4::splat(dd!(1));
+/// x /= Doublex4::from([dd!(1), dd!(2), dd!(3), dd!(4)]);
+///
+/// let [a, b, c, d] = x.to_array();
+/// assert!(a == dd!(1));
+/// assert!(b == dd!(0.5));
+/// assert!(c == dd!(1) / dd!(3));
+/// assert!(d == dd!(0.25));
+/// ```
+///
+/// [`Double`]: crate::Double
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Doublex4(f64x4, f64x4);
+
+impl Doublex4 {
+    /// The number of `Double`s in a `Doublex4`.
+    pub const LANES: usize = 4;
+
+    /// Creates a `Doublex4` with every lane set to the same `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::Double;
+    ///
+    /// let x = Doublex4::splat(Double::E);
+    /// assert!(x.to_array() == [Double::E; 4]);
+    /// ```
+    #[inline]
+    pub fn splat(x: Double) -> Doublex4 {
+        Doublex4(f64x4::splat(x.hi()), f64x4::splat(x.lo()))
+    }
+
+    /// Returns the `Double` in one lane.
+    ///
+    /// # Panics
+    /// Panics if `lane` is not less than [`LANES`].
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::{dd, Double};
+    ///
+    /// let x = Doublex4::from([dd!(1), dd!(2), dd!(3), dd!(4)]);
+    /// assert!(x.extract(1) == dd!(2));
+    /// ```
+    ///
+    /// [`LANES`]: #associatedconstant.LANES
+    #[inline]
+    pub fn extract(&self, lane: usize) -> Double {
+        Double::new(self.0.as_array_ref()[lane], self.1.as_array_ref()[lane])
+    }
+
+    /// Returns a copy of this `Doublex4` with the `Double` in one lane replaced.
+    ///
+    /// # Panics
+    /// Panics if `lane` is not less than [`LANES`].
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::{dd, Double};
+    ///
+    /// let x = Doublex4::splat(dd!(1)).replace(3, Double::PI);
+    /// assert!(x.to_array() == [dd!(1), dd!(1), dd!(1), Double::PI]);
+    /// ```
+    ///
+    /// [`LANES`]: #associatedconstant.LANES
+    #[inline]
+    pub fn replace(mut self, lane: usize, x: Double) -> Doublex4 {
+        self.0.as_array_mut()[lane] = x.hi();
+        self.1.as_array_mut()[lane] = x.lo();
+        self
+    }
+
+    /// Returns the `Double`s in all of the lanes as an array.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::{dd, Double};
+    ///
+    /// let x = Doublex4::from([dd!(1), dd!(2), dd!(3), dd!(4)]) + Doublex4::splat(dd!(1));
+    /// assert!(x.to_array() == [dd!(2), dd!(3), dd!(4), dd!(5)]);
+    /// ```
+    #[inline]
+    pub fn to_array(&self) -> [Double; 4] {
+        let (a, b) = (self.0.to_array(), self.1.to_array());
+        [
+            Double::new(a[0], b[0]),
+            Double::new(a[1], b[1]),
+            Double::new(a[2], b[2]),
+            Double::new(a[3], b[3]),
+        ]
+    }
+
+    // Takes `r`, the result of a vector algorithm applied to this `Doublex4` and `other`,
+    // and redoes the lanes that aren't set in `ok` with `scalar`. The vector algorithm
+    // must be the one that `scalar` uses for the lanes that are set, so that they match
+    // `scalar` too. Lanes are rarely anything but ordinary numbers, so the redoing is kept
+    // out of line, which leaves the operators small enough to be inlined.
+    #[inline]
+    fn patch(
+        self,
+        other: Doublex4,
+        r: Doublex4,
+        ok: f64x4,
+        scalar: fn(Double, Double) -> Double,
+    ) -> Doublex4 {
+        let mask = ok.move_mask();
+        if mask == 0b1111 {
+            r
+        } else {
+            self.redo(other, r, mask, scalar)
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn redo(
+        self,
+        other: Doublex4,
+        mut r: Doublex4,
+        mask: i32,
+        scalar: fn(Double, Double) -> Double,
+    ) -> Doublex4 {
+        for i in 0..4 {
+            if mask & (1 << i) == 0 {
+                r = r.replace(i, scalar(self.extract(i), other.extract(i)));
+            }
+        }
+        r
+    }
+}
+
+// The bounds of the numbers that multiplication and division are done on in the vector
+// lanes. Products and quotients of numbers in this range can't overflow or underflow, not
+// even in the intermediate steps, so splitting the factors in `two_prod` is exact.
+const RANGE_MIN: f64 = 3.8725919148493183e-121; // = 2^-400
+const RANGE_MAX: f64 = 2.5822498780869086e+120; // = 2^400
+
+// Splits a number into two halves of 26 bits each, like `common::primitive::split`.
+#[cfg(not(all(target_feature = "avx", target_feature = "fma")))]
+const SPLIT_FACTOR: f64 = 134217729.0; // = 2^27 + 1
+
+// Which lanes of both `a` and `b` hold numbers in the range that multiplication and
+// division are done on. Zeros, infinities, and NaNs are all out of range.
+#[inline]
+fn in_range(a: f64x4, b: f64x4) -> f64x4 {
+    let (a, b) = (a.abs(), b.abs());
+    let (min, max) = (f64x4::splat(RANGE_MIN), f64x4::splat(RANGE_MAX));
+    a.cmp_ge(min) & a.cmp_le(max) & b.cmp_ge(min) & b.cmp_le(max)
+}
+
+// Which lanes of both `a` and `b` hold finite numbers. This is a floating-point comparison
+// rather than the bit twiddling of `f64x4::is_finite`, which needs 64-bit integer
+// comparisons that older processors don't have in their vector units.
+#[inline]
+fn finite(a: f64x4, b: f64x4) -> f64x4 {
+    let max = f64x4::splat(f64::MAX);
+    a.abs().cmp_le(max) & b.abs().cmp_le(max)
+}
+
+// Negates each lane by flipping its sign bit. (`wide` negates by subtracting from zero,
+// which turns a negative zero into a positive one.)
+#[inline]
+fn neg(x: f64x4) -> f64x4 {
+    x ^ f64x4::splat(-0.0)
+}
+
+// Lane kernels. These are the primitives in `common::primitive` and the fast paths of the
+// `Double` operators, with each operation applied to four lanes at once. They perform the
+// same operations in the same order, so each lane gets exactly the same result as the
+// scalar code.
+
+#[inline]
+fn quick_two_sum(a: f64x4, b: f64x4) -> (f64x4, f64x4) {
+    let s = a + b;
+    let e = b - (s - a);
+    (s, e)
+}
+
+#[inline]
+fn two_sum(a: f64x4, b: f64x4) -> (f64x4, f64x4) {
+    let s = a + b;
+    let v = s - a;
+    let e = (a - (s - v)) + (b - v);
+    (s, e)
+}
+
+#[inline]
+fn two_diff(a: f64x4, b: f64x4) -> (f64x4, f64x4) {
+    let s = a - b;
+    let v = s - a;
+    let e = (a - (s - v)) - (b + v);
+    (s, e)
+}
+
+// `wide` only fuses the multiplication and addition in `mul_sub` when FMA is available.
+#[cfg(all(target_feature = "avx", target_feature = "fma"))]
+#[inline]
+fn two_prod(a: f64x4, b: f64x4) -> (f64x4, f64x4) {
+    let p = a * b;
+    let e = a.mul_sub(b, p);
+    (p, e)
+}
+
+// Without FMA, the error is the same one that the scalar `two_prod` finds with FMA, as
+// long as nothing overflows or underflows along the way.
+#[cfg(not(all(target_feature = "avx", target_feature = "fma")))]
+#[inline]
+fn two_prod(a: f64x4, b: f64x4) -> (f64x4, f64x4) {
+    let split = |a: f64x4| {
+        let t = a * SPLIT_FACTOR;
+        let hi = t - (t - a);
+        (hi, a - hi)
+    };
+    let p = a * b;
+    let (ahi, alo) = split(a);
+    let (bhi, blo) = split(b);
+    let e = ahi * bhi - p + ahi * blo + alo * bhi + alo * blo;
+    (p, e)
+}
+
+#[inline]
+fn add_accurate(a: Doublex4, b: Doublex4) -> Doublex4 {
+    let (s0, e0) = two_sum(a.0, b.0);
+    let (s1, e1) = two_sum(a.1, b.1);
+    let (s2, e2) = quick_two_sum(s0, s1 + e0);
+    let (a, b) = quick_two_sum(s2, e1 + e2);
+    Doublex4(a, b)
+}
+
+#[inline]
+fn add_fast(a: Doublex4, b: Doublex4) -> Doublex4 {
+    let (s, e) = two_sum(a.0, b.0);
+    let (a, b) = quick_two_sum(s, e + a.1 + b.1);
+    Doublex4(a, b)
+}
+
+#[inline]
+fn sub_accurate(a: Doublex4, b: Doublex4) -> Doublex4 {
+    let (s0, e0) = two_diff(a.0, b.0);
+    let (s1, e1) = two_diff(a.1, b.1);
+    let (s2, e2) = quick_two_sum(s0, s1 + e0);
+    let (a, b) = quick_two_sum(s2, e1 + e2);
+    Doublex4(a, b)
+}
+
+#[inline]
+fn mul(a: Doublex4, b: Doublex4) -> Doublex4 {
+    let (p, e) = two_prod(a.0, b.0);
+    let (a, b) = quick_two_sum(p, e + a.0 * b.1 + a.1 * b.0);
+    Doublex4(a, b)
+}
+
+#[inline]
+fn mul_f64(a: Doublex4, b: f64x4) -> Doublex4 {
+    let (p, e) = two_prod(a.0, b);
+    let (a, b) = quick_two_sum(p, e + a.1 * b);
+    Doublex4(a, b)
+}
+
+#[inline]
+fn div_accurate(a: Doublex4, b: Doublex4) -> Doublex4 {
+    let q1 = a.0 / b.0;
+    let r = add_accurate(a, -mul_f64(b, q1));
+
+    let q2 = r.0 / b.0;
+    let r = add_accurate(r, -mul_f64(b, q2));
+
+    let q3 = r.0 / b.0;
+
+    // `common::utils::renorm3`
+    let (u, v) = quick_two_sum(q1, q2);
+    let (s, w) = quick_two_sum(q3, u);
+    let (a, b) = quick_two_sum(s, v + w);
+    Doublex4(a, b)
+}
+
+#[inline]
+fn div_fast(a: Doublex4, b: Doublex4) -> Doublex4 {
+    let q1 = a.0 / b.0;
+    let r = add_fast(a, -mul_f64(b, q1));
+    let q2 = r.0 / b.0;
+
+    let (a, b) = quick_two_sum(q1, q2);
+    Doublex4(a, b)
+}
+
+// The sums and products that the `+` and `*` operators start from, along with the lanes
+// that are right as they are. The rest have to be redone with the scalar operators.
+
+#[inline]
+fn add_lanes(a: Doublex4, b: Doublex4) -> (Doublex4, f64x4) {
+    let r = if cfg!(feature = "fast_arith") {
+        add_fast(a, b)
+    } else {
+        add_accurate(a, b)
+    };
+    (r, finite(a.0, b.0))
+}
+
+#[inline]
+fn mul_lanes(a: Doublex4, b: Doublex4) -> (Doublex4, f64x4) {
+    (mul(a, b), in_range(a.0, b.0))
+}
+
+impl From<[Double; 4]> for Doublex4 {
+    /// Creates a `Doublex4` with one `Double` from an array in each lane.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::{dd, Double};
+    ///
+    /// let x = Doublex4::from([dd!(1), dd!(2), dd!(3), dd!(4)]);
+    /// assert!(x.extract(0) == dd!(1));
+    /// ```
+    #[inline]
+    fn from(a: [Double; 4]) -> Doublex4 {
+        Doublex4(
+            f64x4::from([a[0].hi(), a[1].hi(), a[2].hi(), a[3].hi()]),
+            f64x4::from([a[0].lo(), a[1].lo(), a[2].lo(), a[3].lo()]),
+        )
+    }
+}
+
+impl From<Doublex4> for [Double; 4] {
+    /// Creates an array from the `Double`s in the lanes of a `Doublex4`.
+    ///
+    /// This is the same as calling [`Doublex4::to_array`].
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::{dd, Double};
+    ///
+    /// let a: [Double; 4] = Doublex4::splat(dd!(2)).into();
+    /// assert!(a == [dd!(2); 4]);
+    /// ```
+    #[inline]
+    fn from(x: Doublex4) -> [Double; 4] {
+        x.to_array()
+    }
+}
+
+impl Add for Doublex4 {
+    type Output = Doublex4;
+
+    /// Adds each lane of this `Doublex4` to the same lane of another.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::Double;
+    ///
+    /// let x = Doublex4::splat(Double::PI) + Doublex4::splat(Double::E);
+    /// assert!(x.extract(0) == Double::PI + Double::E);
+    /// ```
+    #[inline]
+    fn add(self, other: Doublex4) -> Doublex4 {
+        let (r, ok) = add_lanes(self, other);
+        self.patch(other, r, ok, |a, b| a + b)
+    }
+}
+
+impl Sub for Doublex4 {
+    type Output = Doublex4;
+
+    /// Subtracts each lane of another `Doublex4` from the same lane of this one.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::Double;
+    ///
+    /// let x = Doublex4::splat(Double::PI) - Doublex4::splat(Double::E);
+    /// assert!(x.extract(0) == Double::PI - Double::E);
+    /// ```
+    #[inline]
+    fn sub(self, other: Doublex4) -> Doublex4 {
+        let r = if cfg!(feature = "fast_arith") {
+            add_fast(self, -other)
+        } else {
+            sub_accurate(self, other)
+        };
+        self.patch(other, r, finite(self.0, other.0), |a, b| a - b)
+    }
+}
+
+impl Mul for Doublex4 {
+    type Output = Doublex4;
+
+    /// Multiplies each lane of this `Doublex4` by the same lane of another.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::Double;
+    ///
+    /// let x = Doublex4::splat(Double::PI) * Doublex4::splat(Double::E);
+    /// assert!(x.extract(0) == Double::PI * Double::E);
+    /// ```
+    #[inline]
+    fn mul(self, other: Doublex4) -> Doublex4 {
+        let (r, ok) = mul_lanes(self, other);
+        self.patch(other, r, ok, |a, b| a * b)
+    }
+}
+
+impl Div for Doublex4 {
+    type Output = Doublex4;
+
+    /// Divides each lane of this `Doublex4` by the same lane of another.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::Double;
+    ///
+    /// let x = Doublex4::splat(Double::PI) / Doublex4::splat(Double::E);
+    /// assert!(x.extract(0) == Double::PI / Double::E);
+    /// ```
+    #[inline]
+    fn div(self, other: Doublex4) -> Doublex4 {
+        let r = if cfg!(feature = "fast_arith") {
+            div_fast(self, other)
+        } else {
+            div_accurate(self, other)
+        };
+        self.patch(other, r, in_range(self.0, other.0), |a, b| a / b)
+    }
+}
+
+impl Neg for Doublex4 {
+    type Output = Doublex4;
+
+    /// Negates each lane of this `Doublex4`.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::Double;
+    ///
+    /// let x = -Doublex4::splat(Double::PI);
+    /// assert!(x.extract(0) == -Double::PI);
+    /// ```
+    #[inline]
+    fn neg(self) -> Doublex4 {
+        Doublex4(neg(self.0), neg(self.1))
+    }
+}
+
+impl AddAssign for Doublex4 {
+    /// Adds each lane of another `Doublex4` to the same lane of this one, modifying this
+    /// one to equal the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::Double;
+    ///
+    /// let mut x = Doublex4::splat(Double::PI);
+    /// x += Doublex4::splat(Double::E);
+    /// assert!(x.extract(0) == Double::PI + Double::E);
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Doublex4) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Doublex4 {
+    /// Subtracts each lane of another `Doublex4` from the same lane of this one, modifying
+    /// this one to equal the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::Double;
+    ///
+    /// let mut x = Doublex4::splat(Double::PI);
+    /// x -= Doublex4::splat(Double::E);
+    /// assert!(x.extract(0) == Double::PI - Double::E);
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: Doublex4) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Doublex4 {
+    /// Multiplies each lane of this `Doublex4` by the same lane of another, modifying this
+    /// one to equal the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::Double;
+    ///
+    /// let mut x = Doublex4::splat(Double::PI);
+    /// x *= Doublex4::splat(Double::E);
+    /// assert!(x.extract(0) == Double::PI * Double::E);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Doublex4) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for Doublex4 {
+    /// Divides each lane of this `Doublex4` by the same lane of another, modifying this one
+    /// to equal the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use qd::simd::Doublex4;
+    /// use qd::Double;
+    ///
+    /// let mut x = Doublex4::splat(Double::PI);
+    /// x /= Doublex4::splat(Double::E);
+    /// assert!(x.extract(0) == Double::PI / Double::E);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Doublex4) {
+        *self = *self / other;
+    }
+}
+
+/// Adds two slices of [`Double`]s element by element, writing the sums into `out`.
+///
+/// The slices are processed four elements at a time with [`Doublex4`], and any elements
+/// left over at the end are added one at a time. Each element of `out` is exactly the same
+/// as the result of adding the corresponding elements with `+`.
+///
+/// # Panics
+/// Panics if the three slices are not all the same length.
+///
+/// # Examples
+/// ```
+/// use qd::simd::add_slices;
+/// use qd::{dd, Double};
+///
+/// let a = [dd!(1), dd!(2), dd!(3), dd!(4), dd!(5)];
+/// let b = [Double::PI; 5];
+/// let mut out = [Double::ZERO; 5];
+/// add_slices(&a, &b, &mut out);
+///
+/// assert!(out[4] == dd!(5) + Double::PI);
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Doublex4`]: struct.Doublex4.html
+pub fn add_slices(a: &[Double], b: &[Double], out: &mut [Double]) {
+    binary_slices(a, b, out, add_lanes, |x, y| x + y);
+}
+
+/// Multiplies two slices of [`Double`]s element by element, writing the products into
+/// `out`.
+///
+/// The slices are processed four elements at a time with [`Doublex4`], and any elements
+/// left over at the end are multiplied one at a time. Each element of `out` is exactly the
+/// same as the result of multiplying the corresponding elements with `*`.
+///
+/// # Panics
+/// Panics if the three slices are not all the same length.
+///
+/// # Examples
+/// ```
+/// use qd::simd::mul_slices;
+/// use qd::{dd, Double};
+///
+/// let a = [dd!(1), dd!(2), dd!(3), dd!(4), dd!(5)];
+/// let b = [Double::PI; 5];
+/// let mut out = [Double::ZERO; 5];
+/// mul_slices(&a, &b, &mut out);
+///
+/// assert!(out[4] == dd!(5) * Double::PI);
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Doublex4`]: struct.Doublex4.html
+pub fn mul_slices(a: &[Double], b: &[Double], out: &mut [Double]) {
+    binary_slices(a, b, out, mul_lanes, |x, y| x * y);
+}
+
+/// Multiplies two slices of [`Double`]s element by element and adds a third slice to the
+/// products, writing the results into `out`.
+///
+/// Each element of `out` is exactly the same as `a[i] * b[i] + c[i]`. The multiplication
+/// and addition are fused into a single pass over the slices, but they are still rounded
+/// separately; for a single rounding, use [`Double::mul_add`] on each element instead,
+/// which is considerably slower.
+///
+/// # Panics
+/// Panics if the four slices are not all the same length.
+///
+/// # Examples
+/// ```
+/// use qd::simd::fma_slices;
+/// use qd::{dd, Double};
+///
+/// let a = [dd!(1), dd!(2), dd!(3), dd!(4), dd!(5)];
+/// let b = [Double::PI; 5];
+/// let c = [Double::E; 5];
+/// let mut out = [Double::ZERO; 5];
+/// fma_slices(&a, &b, &c, &mut out);
+///
+/// assert!(out[4] == dd!(5) * Double::PI + Double::E);
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Double::mul_add`]: crate::Double::mul_add
+pub fn fma_slices(a: &[Double], b: &[Double], c: &[Double], out: &mut [Double]) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    assert_eq!(a.len(), c.len(), "slices must have the same length");
+    assert_eq!(a.len(), out.len(), "slices must have the same length");
+
+    let n = a.len() - a.len() % 4;
+    for i in (0..n).step_by(4) {
+        let (p, mul_ok) = mul_lanes(load(&a[i..i + 4]), load(&b[i..i + 4]));
+        let (r, add_ok) = add_lanes(p, load(&c[i..i + 4]));
+        store(&mut out[i..i + 4], r);
+        redo(mul_ok & add_ok, |k| {
+            out[i + k] = a[i + k] * b[i + k] + c[i + k]
+        });
+    }
+    for i in n..a.len() {
+        out[i] = a[i] * b[i] + c[i];
+    }
+}
+
+/// Converts a slice of `f64`s into [`Double`]s, writing them into `out`.
+///
+/// Each `f64` becomes the first component of a `Double` with a second component of zero,
+/// so the `Double` has exactly the same value as the `f64`. This is different from
+/// `Double::from(f64)`, which reads the `f64` as the decimal number it displays as, and is
+/// much faster.
+///
+/// # Panics
+/// Panics if the two slices are not the same length.
+///
+/// # Examples
+/// ```
+/// use qd::simd::widen_slice;
+/// use qd::{dd, Double};
+///
+/// let a = [1.0, 0.5, 0.1];
+/// let mut out = [Double::ZERO; 3];
+/// widen_slice(&a, &mut out);
+///
+/// assert!(out[1] == dd!(0.5));
+/// assert!(out[2][0] == 0.1);
+/// assert!(out[2][1] == 0.0);
+/// ```
+///
+/// [`Double`]: crate::Double
+pub fn widen_slice(a: &[f64], out: &mut [Double]) {
+    assert_eq!(a.len(), out.len(), "slices must have the same length");
+    for (x, r) in a.iter().zip(out.iter_mut()) {
+        *r = Double::new(*x, 0.0);
+    }
+}
+
+// Loads four `Double`s from the start of a slice into a `Doublex4`.
+#[inline]
+fn load(a: &[Double]) -> Doublex4 {
+    Doublex4::from([a[0], a[1], a[2], a[3]])
+}
+
+// Stores the four `Double`s in a `Doublex4` at the start of a slice.
+#[inline]
+fn store(out: &mut [Double], x: Doublex4) {
+    let (hi, lo) = (x.0.as_array_ref(), x.1.as_array_ref());
+    for (k, r) in out[..4].iter_mut().enumerate() {
+        *r = Double::new(hi[k], lo[k]);
+    }
+}
+
+// Calls `scalar` with the index of each lane that isn't set in `ok`. The slice functions
+// redo those lanes from the slices themselves rather than through `Doublex4::patch`, which
+// would need the operands of the vector operation kept in memory just in case.
+#[inline]
+fn redo(ok: f64x4, mut scalar: impl FnMut(usize)) {
+    let mask = ok.move_mask();
+    if mask != 0b1111 {
+        for k in 0..4 {
+            if mask & (1 << k) == 0 {
+                scalar(k);
+            }
+        }
+    }
+}
+
+// Applies a binary operation to two slices, four elements at a time with `vector` and then
+// one at a time with `scalar` for the lanes that `vector` can't do and the elements left
+// over.
+#[inline]
+fn binary_slices(
+    a: &[Double],
+    b: &[Double],
+    out: &mut [Double],
+    vector: fn(Doublex4, Doublex4) -> (Doublex4, f64x4),
+    scalar: fn(Double, Double) -> Double,
+) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    assert_eq!(a.len(), out.len(), "slices must have the same length");
+
+    let n = a.len() - a.len() % 4;
+    for i in (0..n).step_by(4) {
+        let (r, ok) = vector(load(&a[i..i + 4]), load(&b[i..i + 4]));
+        store(&mut out[i..i + 4], r);
+        redo(ok, |k| out[i + k] = scalar(a[i + k], b[i + k]));
+    }
+    for i in n..a.len() {
+        out[i] = scalar(a[i], b[i]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Numbers that exercise both the lane kernels and the special cases.
+    fn doubles() -> Vec<Double> {
+        vec![
+            Double::PI,
+            -Double::E,
+            Double::LN_2,
+            Double::from(1e300),
+            Double::from(-1e-300),
+            Double::from(3),
+            Double::from(6),
+            Double::from(1) / Double::from(3),
+            Double::new(2f64.powi(400), 0.0),
+            Double::new(2f64.powi(-401), 0.0),
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double::ZERO,
+            Double::NEG_ZERO,
+            Double::INFINITY,
+            Double::NEG_INFINITY,
+            Double::NAN,
+        ]
+    }
+
+    fn same_double(a: Double, b: Double) -> bool {
+        if a.is_nan() || b.is_nan() {
+            a.is_nan() && b.is_nan()
+        } else {
+            a[0].to_bits() == b[0].to_bits() && a[1].to_bits() == b[1].to_bits()
+        }
+    }
+
+    type VectorOp = fn(Doublex4, Doublex4) -> Doublex4;
+    type ScalarOp = fn(Double, Double) -> Double;
+
+    fn check_doubles(vector: VectorOp, scalar: ScalarOp) {
+        let values = doubles();
+        for x in &values {
+            for chunk in values.chunks(4) {
+                let mut ys = [Double::ONE; 4];
+                ys[..chunk.len()].copy_from_slice(chunk);
+
+                let r = vector(Doublex4::splat(*x), Doublex4::from(ys));
+                for (i, y) in ys.iter().enumerate() {
+                    assert!(
+                        same_double(r.extract(i), scalar(*x, *y)),
+                        "{:?} and {:?}: {:?}",
+                        x,
+                        y,
+                        r.extract(i)
+                    );
+                }
+            }
+        }
+    }
+
+    // Ordinary numbers of every magnitude that the vector lanes handle and a little past
+    // them, with random low components, from a simple linear congruential generator
+    fn random_doubles(n: usize) -> Vec<Double> {
+        let mut state = 1u64;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            state >> 11
+        };
+        let unit = |bits: u64| bits as f64 / (1u64 << 53) as f64;
+        (0..n)
+            .map(|_| {
+                let bits = next();
+                let exp = (bits % 841) as i32 - 420;
+                let hi = (1.0 + unit(next())) * 2f64.powi(exp);
+                let hi = if bits & 1 == 1 { -hi } else { hi };
+                let lo = hi * (unit(next()) - 0.5) * 2f64.powi(-53);
+                Double::new(hi, lo)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn random_lanes() {
+        let xs = random_doubles(4000);
+        let ops: [(VectorOp, ScalarOp); 4] = [
+            (|a, b| a + b, |a, b| a + b),
+            (|a, b| a - b, |a, b| a - b),
+            (|a, b| a * b, |a, b| a * b),
+            (|a, b| a / b, |a, b| a / b),
+        ];
+        for (vector, scalar) in ops.iter() {
+            for (a, b) in xs.chunks(4).zip(xs.chunks(4).rev()) {
+                let a = [a[0], a[1], a[2], a[3]];
+                let b = [b[0], b[1], b[2], b[3]];
+                let r = vector(Doublex4::from(a), Doublex4::from(b));
+                for i in 0..4 {
+                    let expected = scalar(a[i], b[i]);
+                    assert!(
+                        same_double(r.extract(i), expected),
+                        "{:?} and {:?}: {:?}, not {:?}",
+                        a[i],
+                        b[i],
+                        r.extract(i),
+                        expected
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn double_splat() {
+        assert!(Doublex4::splat(Double::PI).to_array() == [Double::PI; 4]);
+    }
+
+    #[test]
+    fn double_round_trip() {
+        let a = [Double::PI, Double::E, Double::LN_2, Double::LN_10];
+        assert!(<[Double; 4]>::from(Doublex4::from(a)) == a);
+    }
+
+    #[test]
+    fn double_replace() {
+        let x = Doublex4::default().replace(2, Double::E);
+        assert!(x.to_array() == [Double::ZERO, Double::ZERO, Double::E, Double::ZERO]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn double_extract_out_of_range() {
+        Doublex4::default().extract(Doublex4::LANES);
+    }
+
+    #[test]
+    fn double_add() {
+        check_doubles(|a, b| a + b, |a, b| a + b);
+    }
+
+    #[test]
+    fn double_sub() {
+        check_doubles(|a, b| a - b, |a, b| a - b);
+    }
+
+    #[test]
+    fn double_mul() {
+        check_doubles(|a, b| a * b, |a, b| a * b);
+    }
+
+    #[test]
+    fn double_div() {
+        check_doubles(|a, b| a / b, |a, b| a / b);
+    }
+
+    #[test]
+    fn double_neg() {
+        let x = -Doublex4::from([Double::PI, Double::ZERO, Double::INFINITY, Double::NAN]);
+        assert!(same_double(x.extract(0), -Double::PI));
+        assert!(same_double(x.extract(1), -Double::ZERO));
+        assert!(same_double(x.extract(2), -Double::INFINITY));
+        assert!(x.extract(3).is_nan());
+    }
+
+    #[test]
+    fn double_assign() {
+        let y = Doublex4::from([Double::PI, Double::E, Double::LN_2, Double::LN_10]);
+        let mut x = Doublex4::splat(Double::from(7));
+        x += y;
+        x *= y;
+        x -= y;
+        x /= y;
+        let expected = (((Doublex4::splat(Double::from(7)) + y) * y) - y) / y;
+        for i in 0..4 {
+            assert!(same_double(x.extract(i), expected.extract(i)));
+        }
+    }
+
+    fn slice_values(n: usize) -> Vec<Double> {
+        let values = doubles();
+        (0..n).map(|i| values[i % values.len()]).collect()
+    }
+
+    #[test]
+    fn slices_add() {
+        for n in 0..12 {
+            let a = slice_values(n);
+            let b: Vec<Double> = a.iter().rev().copied().collect();
+            let mut out = vec![Double::ZERO; n];
+            add_slices(&a, &b, &mut out);
+            for i in 0..n {
+                assert!(same_double(out[i], a[i] + b[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn slices_mul() {
+        for n in 0..12 {
+            let a = slice_values(n);
+            let b: Vec<Double> = a.iter().rev().copied().collect();
+            let mut out = vec![Double::ZERO; n];
+            mul_slices(&a, &b, &mut out);
+            for i in 0..n {
+                assert!(same_double(out[i], a[i] * b[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn slices_fma() {
+        for n in 0..12 {
+            let a = slice_values(n);
+            let b: Vec<Double> = a.iter().rev().copied().collect();
+            let c = vec![Double::E; n];
+            let mut out = vec![Double::ZERO; n];
+            fma_slices(&a, &b, &c, &mut out);
+            for i in 0..n {
+                assert!(same_double(out[i], a[i] * b[i] + c[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn slices_widen() {
+        let a = [0.1, -0.0, f64::INFINITY, f64::MAX];
+        let mut out = [Double::ONE; 4];
+        widen_slice(&a, &mut out);
+        for i in 0..4 {
+            assert!(out[i][0].to_bits() == a[i].to_bits());
+            assert!(out[i][1] == 0.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn slices_length_mismatch() {
+        let mut out = [Double::ZERO; 3];
+        add_slices(&[Double::ONE; 3], &[Double::ONE; 2], &mut out);
+    }
+}
@@ -0,0 +1,669 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Statistics of streams of [`Double`]s and [`Quad`]s.
+//!
+//! [`Moments`] calculates the mean, variance, skewness, and kurtosis of a sequence of
+//! numbers in one pass, updating its state as each number arrives without storing the
+//! numbers themselves. The updates are Welford's, which avoid the catastrophic
+//! cancellation of the textbook sum-of-squares formulas, but over millions of values even
+//! they pile up enough rounding error to cost an `f64` accumulator most of its digits. With
+//! the state carried in a `Double` or a `Quad`, the results are accurate to the last digit
+//! of an `f64` long after that.
+//!
+//! Accumulators that were fed different parts of a sequence (on different threads, for
+//! instance) can be combined with [`merge`] into the accumulator for the whole sequence.
+//!
+//! For numbers that are already in a slice, there are also functions for statistics that
+//! can't be calculated in one pass: [`weighted_mean`], [`weighted_variance`],
+//! [`covariance`], [`median`], and [`quantile`].
+//!
+//! # Examples
+//! ```
+//! use qd::stats::Moments;
+//! use qd::{dd, Double};
+//!
+//! // Readings with a large offset, which is the worst case for the naive formulas
+//! let readings = (0..1000).map(|i| dd!(1e9) + Double::from((i % 10) as f64));
+//! let moments: Moments<Double> = readings.collect();
+//!
+//! assert_eq!(moments.count(), 1000);
+//! assert!((moments.mean() - dd!(1000000004.5)).abs() < dd!(1e-20));
+//! assert!((moments.population_variance() - dd!(8.25)).abs() < dd!(1e-22));
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`Moments`]: crate::stats::Moments
+//! [`merge`]: crate::stats::Moments::merge
+//! [`weighted_mean`]: crate::stats::weighted_mean
+//! [`weighted_variance`]: crate::stats::weighted_variance
+//! [`covariance`]: crate::stats::covariance
+//! [`median`]: crate::stats::median
+//! [`quantile`]: crate::stats::quantile
+
+use crate::precision::MultiPrecision;
+use std::iter::FromIterator;
+
+/// An accumulator for the count, mean, variance, skewness, and kurtosis of a stream of
+/// [`Double`]s or [`Quad`]s.
+///
+/// Numbers are added with [`push`] or with `Extend`, and the statistics can be read at any
+/// time. Statistics that aren't defined for the numbers seen so far (the mean of no numbers
+/// or the variance of one, for example) are NaN.
+///
+/// # Examples
+/// ```
+/// use qd::stats::Moments;
+/// use qd::{qd, Quad};
+///
+/// let mut moments = Moments::new();
+/// moments.extend(vec![qd!(2), qd!(4), qd!(4), qd!(4), qd!(5), qd!(5), qd!(7), qd!(9)]);
+/// assert!((moments.mean() - qd!(5)).abs() < qd!(1e-60));
+/// assert!((moments.population_variance() - qd!(4)).abs() < qd!(1e-60));
+/// assert!((moments.population_std_dev() - qd!(2)).abs() < qd!(1e-60));
+/// ```
+///
+/// [`Double`]: crate::Double
+/// [`Quad`]: crate::Quad
+/// [`push`]: #method.push
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Moments<T: MultiPrecision> {
+    count: u64,
+    mean: T,
+    // The sums of the second, third, and fourth powers of the differences from the mean
+    m2: T,
+    m3: T,
+    m4: T,
+}
+
+impl<T: MultiPrecision> Moments<T> {
+    /// Creates a new accumulator that hasn't seen any numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::stats::Moments;
+    /// # use qd::Double;
+    /// let moments = Moments::<Double>::new();
+    /// assert_eq!(moments.count(), 0);
+    /// assert!(moments.mean().is_nan());
+    /// ```
+    pub fn new() -> Moments<T> {
+        Moments {
+            count: 0,
+            mean: T::ZERO,
+            m2: T::ZERO,
+            m3: T::ZERO,
+            m4: T::ZERO,
+        }
+    }
+
+    /// Adds a number to the accumulator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::stats::Moments;
+    /// # use qd::{dd, Double};
+    /// let mut moments = Moments::new();
+    /// moments.push(dd!(1));
+    /// moments.push(dd!(2));
+    /// assert_eq!(moments.mean(), dd!(1.5));
+    /// ```
+    pub fn push(&mut self, x: T) {
+        let n1 = T::from(self.count as f64);
+        self.count += 1;
+        let n = T::from(self.count as f64);
+
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * n1;
+
+        self.mean = self.mean + delta_n;
+        self.m4 = self.m4
+            + term * delta_n2 * (n * n - T::from(3.0) * n + T::from(3.0))
+            + T::from(6.0) * delta_n2 * self.m2
+            - T::from(4.0) * delta_n * self.m3;
+        self.m3 = self.m3 + term * delta_n * (n - T::from(2.0)) - T::from(3.0) * delta_n * self.m2;
+        self.m2 = self.m2 + term;
+    }
+
+    /// Combines the statistics of another accumulator into this one, so that this one has
+    /// the statistics of all of the numbers that both have seen.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::stats::Moments;
+    /// # use qd::{dd, Double};
+    /// let mut a: Moments<Double> = vec![dd!(1), dd!(2)].into_iter().collect();
+    /// let b: Moments<Double> = vec![dd!(3), dd!(4), dd!(5)].into_iter().collect();
+    /// a.merge(&b);
+    /// assert_eq!(a.count(), 5);
+    /// assert_eq!(a.mean(), dd!(3));
+    /// assert_eq!(a.variance(), dd!(2.5));
+    /// ```
+    pub fn merge(&mut self, other: &Moments<T>) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let na = T::from(self.count as f64);
+        let nb = T::from(other.count as f64);
+        self.count += other.count;
+        let n = T::from(self.count as f64);
+
+        let delta = other.mean - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let nab = na * nb;
+
+        let m2 = self.m2 + other.m2 + delta * delta_n * nab;
+        let m3 = self.m3
+            + other.m3
+            + delta * delta_n2 * nab * (na - nb)
+            + T::from(3.0) * delta_n * (na * other.m2 - nb * self.m2);
+        let m4 = self.m4
+            + other.m4
+            + delta * delta_n2 * delta_n * nab * (na * na - nab + nb * nb)
+            + T::from(6.0) * delta_n2 * (na * na * other.m2 + nb * nb * self.m2)
+            + T::from(4.0) * delta_n * (na * other.m3 - nb * self.m3);
+
+        self.mean = self.mean + delta_n * nb;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+    }
+
+    /// Returns the number of numbers that the accumulator has seen.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean of the numbers, or NaN if there are none.
+    pub fn mean(&self) -> T {
+        if self.count == 0 {
+            T::NAN
+        } else {
+            self.mean
+        }
+    }
+
+    /// Returns the sample variance of the numbers, or NaN if there are fewer than two.
+    ///
+    /// This is the unbiased estimate of the variance of a population that the numbers are
+    /// a sample of, dividing by one less than the count.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::stats::Moments;
+    /// # use qd::{dd, Double};
+    /// let moments: Moments<Double> = (1..=4).map(|i| Double::from(i as f64)).collect();
+    /// let diff = (moments.variance() - dd!(5) / dd!(3)).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    pub fn variance(&self) -> T {
+        if self.count < 2 {
+            T::NAN
+        } else {
+            self.m2 / T::from((self.count - 1) as f64)
+        }
+    }
+
+    /// Returns the population variance of the numbers, or NaN if there are none.
+    ///
+    /// This is the variance of the numbers themselves, dividing by the count.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::stats::Moments;
+    /// # use qd::{dd, Double};
+    /// let moments: Moments<Double> = (1..=4).map(|i| Double::from(i as f64)).collect();
+    /// assert_eq!(moments.population_variance(), dd!(1.25));
+    /// ```
+    pub fn population_variance(&self) -> T {
+        if self.count == 0 {
+            T::NAN
+        } else {
+            self.m2 / T::from(self.count as f64)
+        }
+    }
+
+    /// Returns the sample standard deviation of the numbers, the square root of the
+    /// [`variance`], or NaN if there are fewer than two.
+    ///
+    /// [`variance`]: #method.variance
+    pub fn std_dev(&self) -> T {
+        self.variance().sqrt()
+    }
+
+    /// Returns the population standard deviation of the numbers, the square root of the
+    /// [`population_variance`], or NaN if there are none.
+    ///
+    /// [`population_variance`]: #method.population_variance
+    pub fn population_std_dev(&self) -> T {
+        self.population_variance().sqrt()
+    }
+
+    /// Returns the skewness of the numbers, or NaN if there are none or if they're all
+    /// the same.
+    ///
+    /// This is the population skewness, the third central moment divided by the cube of
+    /// the population standard deviation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::stats::Moments;
+    /// # use qd::{qd, Quad};
+    /// let moments: Moments<Quad> = vec![qd!(1), qd!(2), qd!(3)].into_iter().collect();
+    /// assert_eq!(moments.skewness(), qd!(0));
+    ///
+    /// let moments: Moments<Quad> = vec![qd!(0), qd!(0), qd!(3)].into_iter().collect();
+    /// let diff = (moments.skewness() - qd!(0.5).sqrt()).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn skewness(&self) -> T {
+        if self.count == 0 || self.m2 == T::ZERO {
+            T::NAN
+        } else {
+            T::from(self.count as f64).sqrt() * self.m3 / (self.m2 * self.m2.sqrt())
+        }
+    }
+
+    /// Returns the excess kurtosis of the numbers, or NaN if there are none or if they're
+    /// all the same.
+    ///
+    /// This is the population kurtosis, the fourth central moment divided by the square of
+    /// the population variance, minus 3 so that a normal distribution has a kurtosis of 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::stats::Moments;
+    /// # use qd::{qd, Quad};
+    /// let moments: Moments<Quad> = vec![qd!(-1), qd!(1)].into_iter().collect();
+    /// assert_eq!(moments.kurtosis(), qd!(-2));
+    /// ```
+    pub fn kurtosis(&self) -> T {
+        if self.count == 0 || self.m2 == T::ZERO {
+            T::NAN
+        } else {
+            T::from(self.count as f64) * self.m4 / (self.m2 * self.m2) - T::from(3.0)
+        }
+    }
+}
+
+impl<T: MultiPrecision> Default for Moments<T> {
+    fn default() -> Moments<T> {
+        Moments::new()
+    }
+}
+
+impl<T: MultiPrecision> Extend<T> for Moments<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<'a, T: MultiPrecision> Extend<&'a T> for Moments<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        for &x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<T: MultiPrecision> FromIterator<T> for Moments<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Moments<T> {
+        let mut moments = Moments::new();
+        moments.extend(iter);
+        moments
+    }
+}
+
+impl<'a, T: MultiPrecision> FromIterator<&'a T> for Moments<T> {
+    fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> Moments<T> {
+        let mut moments = Moments::new();
+        moments.extend(iter);
+        moments
+    }
+}
+
+/// Calculates the weighted mean of a slice of numbers.
+///
+/// The weights don't need to add up to 1; they're normalized by their sum. This returns NaN
+/// if the slice is empty or if the weights add up to zero.
+///
+/// # Panics
+/// Panics if there aren't as many weights as numbers.
+///
+/// # Examples
+/// ```
+/// # use qd::stats::weighted_mean;
+/// # use qd::{dd, Double};
+/// let values = [dd!(1), dd!(2), dd!(4)];
+/// let weights = [dd!(3), dd!(1), dd!(1)];
+/// assert!((weighted_mean(&values, &weights) - dd!(1.8)).abs() < dd!(1e-30));
+/// ```
+pub fn weighted_mean<T: MultiPrecision>(values: &[T], weights: &[T]) -> T {
+    assert_eq!(
+        values.len(),
+        weights.len(),
+        "values and weights have different lengths"
+    );
+    let total = sum(weights.iter().copied());
+    if total == T::ZERO {
+        return T::NAN;
+    }
+    sum(values.iter().zip(weights.iter()).map(|(&x, &w)| x * w)) / total
+}
+
+/// Calculates the weighted variance of a slice of numbers.
+///
+/// This is the population variance with each number counted in proportion to its weight:
+/// the weighted mean of the squared differences between the numbers and their
+/// [`weighted_mean`]. With all of the weights equal to 1, it's the same as
+/// [`Moments::population_variance`]. This returns NaN if the slice is empty or if the
+/// weights add up to zero.
+///
+/// # Panics
+/// Panics if there aren't as many weights as numbers.
+///
+/// # Examples
+/// ```
+/// # use qd::stats::weighted_variance;
+/// # use qd::{dd, Double};
+/// let values = [dd!(1), dd!(2), dd!(4)];
+/// let weights = [dd!(3), dd!(1), dd!(1)];
+/// assert!((weighted_variance(&values, &weights) - dd!(1.36)).abs() < dd!(1e-30));
+/// ```
+///
+/// [`weighted_mean`]: fn.weighted_mean.html
+/// [`Moments::population_variance`]: struct.Moments.html#method.population_variance
+pub fn weighted_variance<T: MultiPrecision>(values: &[T], weights: &[T]) -> T {
+    let mean = weighted_mean(values, weights);
+    if mean.is_nan() {
+        return T::NAN;
+    }
+    let total = sum(weights.iter().copied());
+    let squares = values.iter().zip(weights.iter()).map(|(&x, &w)| {
+        let d = x - mean;
+        w * d * d
+    });
+    sum(squares) / total
+}
+
+/// Calculates the sample covariance of two slices of numbers, or NaN if they have fewer
+/// than two numbers.
+///
+/// Like [`Moments::variance`], this divides by one less than the number of pairs, and the
+/// covariance of a slice with itself is its sample variance.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+/// ```
+/// # use qd::stats::covariance;
+/// # use qd::{qd, Quad};
+/// let xs = [qd!(1), qd!(2), qd!(3)];
+/// let ys = [qd!(6), qd!(4), qd!(2)];
+/// assert!((covariance(&xs, &ys) - qd!(-2)).abs() < qd!(1e-60));
+/// ```
+///
+/// [`Moments::variance`]: struct.Moments.html#method.variance
+pub fn covariance<T: MultiPrecision>(xs: &[T], ys: &[T]) -> T {
+    assert_eq!(xs.len(), ys.len(), "slices have different lengths");
+    let len = xs.len();
+    if len < 2 {
+        return T::NAN;
+    }
+    let n = T::from(len as f64);
+    let x_mean = sum(xs.iter().copied()) / n;
+    let y_mean = sum(ys.iter().copied()) / n;
+    let products = xs
+        .iter()
+        .zip(ys.iter())
+        .map(|(&x, &y)| (x - x_mean) * (y - y_mean));
+    sum(products) / T::from((len - 1) as f64)
+}
+
+/// Finds the median of a slice of numbers, or NaN if the slice is empty or contains NaN.
+///
+/// If the slice has an even number of elements, this is the mean of the two in the
+/// middle. This is the same as [`quantile`] with `p` equal to 0.5.
+///
+/// # Examples
+/// ```
+/// # use qd::stats::median;
+/// # use qd::{dd, Double};
+/// assert_eq!(median(&[dd!(3), dd!(1), dd!(2)]), dd!(2));
+/// assert_eq!(median(&[dd!(4), dd!(1), dd!(3), dd!(2)]), dd!(2.5));
+/// ```
+///
+/// [`quantile`]: fn.quantile.html
+pub fn median<T: MultiPrecision>(values: &[T]) -> T {
+    quantile(values, T::from(0.5))
+}
+
+/// Finds the `p`-quantile of a slice of numbers.
+///
+/// The quantile is found by selecting the elements on either side of position `p * (n -
+/// 1)` in the sorted slice (without actually sorting it) and interpolating linearly
+/// between them, which is the default in most statistics packages. Since the selection is
+/// exact, a quantile that falls on an element is that element, with no rounding at all.
+///
+/// This returns NaN if the slice is empty, if it contains NaN, or if `p` isn't between 0
+/// and 1.
+///
+/// # Examples
+/// ```
+/// # use qd::stats::quantile;
+/// # use qd::{qd, Quad};
+/// let values: Vec<Quad> = (1..=5).map(|i| Quad::from(i as f64)).collect();
+/// assert_eq!(quantile(&values, qd!(0)), qd!(1));
+/// assert_eq!(quantile(&values, qd!(0.25)), qd!(2));
+/// assert_eq!(quantile(&values, qd!(0.375)), qd!(2.5));
+/// assert_eq!(quantile(&values, qd!(1)), qd!(5));
+/// ```
+pub fn quantile<T: MultiPrecision>(values: &[T], p: T) -> T {
+    if values.is_empty() || values.iter().any(|x| x.is_nan()) || !(p >= T::ZERO && p <= T::ONE) {
+        return T::NAN;
+    }
+    let h = p * T::from((values.len() - 1) as f64);
+    // Rounding h to an f64 can push it up to the next integer, which is corrected here
+    let rounded: f64 = h.into();
+    let mut k = rounded.floor() as usize;
+    if T::from(k as f64) > h {
+        k -= 1;
+    }
+    let frac = h - T::from(k as f64);
+
+    let mut sorted = values.to_vec();
+    let (_, &mut lower, upper) = sorted.select_nth_unstable_by(k, |a, b| a.partial_cmp(b).unwrap());
+    if frac == T::ZERO {
+        return lower;
+    }
+    let next = upper
+        .iter()
+        .fold(None, |acc: Option<T>, &x| match acc {
+            Some(m) if m <= x => Some(m),
+            _ => Some(x),
+        })
+        .unwrap();
+    lower + frac * (next - lower)
+}
+
+// The sum of a sequence of numbers.
+fn sum<T: MultiPrecision, I: Iterator<Item = T>>(iter: I) -> T {
+    iter.fold(T::ZERO, |acc, x| acc + x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dd, qd, Double, Quad};
+
+    // The central moments of a slice, calculated the slow way with two passes
+    fn two_pass<T: MultiPrecision>(xs: &[T]) -> (T, T, T, T) {
+        let n = T::from(xs.len() as f64);
+        let mean = xs.iter().fold(T::ZERO, |acc, &x| acc + x) / n;
+        let moment = |k: usize| {
+            xs.iter().fold(T::ZERO, |acc, &x| {
+                let d = x - mean;
+                (1..k).fold(d, |p, _| p * d) + acc
+            }) / n
+        };
+        (mean, moment(2), moment(3), moment(4))
+    }
+
+    fn data() -> Vec<Quad> {
+        // A deterministic, lopsided sequence
+        (1..=500)
+            .map(|i| {
+                let x = Quad::from(((i * 7919) % 1009) as f64) / qd!(64);
+                x * x / qd!(100) + qd!(1e6)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        let moments = Moments::<Double>::default();
+        assert_eq!(moments.count(), 0);
+        assert!(moments.mean().is_nan());
+        assert!(moments.population_variance().is_nan());
+        assert!(moments.variance().is_nan());
+        assert!(moments.skewness().is_nan());
+        assert!(moments.kurtosis().is_nan());
+
+        let one: Moments<Double> = vec![dd!(3)].into_iter().collect();
+        assert_eq!(one.mean(), dd!(3));
+        assert_eq!(one.population_variance(), dd!(0));
+        assert!(one.variance().is_nan());
+        assert!(one.skewness().is_nan());
+    }
+
+    #[test]
+    fn against_two_pass() {
+        let xs = data();
+        let (mean, m2, m3, m4) = two_pass(&xs);
+        let moments: Moments<Quad> = xs.iter().collect();
+        let close = |a: Quad, b: Quad| (a - b).abs() <= b.abs() * qd!(1e-55);
+
+        assert_eq!(moments.count(), 500);
+        assert!(close(moments.mean(), mean));
+        assert!(close(moments.population_variance(), m2));
+        assert!(close(moments.variance(), m2 * qd!(500) / qd!(499)));
+        assert!(close(moments.skewness(), m3 / (m2 * m2.sqrt())));
+        assert!(close(moments.kurtosis(), m4 / (m2 * m2) - qd!(3)));
+    }
+
+    #[test]
+    fn merged() {
+        let xs = data();
+        let whole: Moments<Quad> = xs.iter().collect();
+        let close = |a: Quad, b: Quad| (a - b).abs() <= b.abs() * qd!(1e-55);
+
+        for &split in &[0, 1, 137, 250, 499, 500] {
+            let mut a: Moments<Quad> = xs[..split].iter().collect();
+            let b: Moments<Quad> = xs[split..].iter().collect();
+            a.merge(&b);
+            assert_eq!(a.count(), whole.count());
+            assert!(close(a.mean(), whole.mean()));
+            assert!(close(a.variance(), whole.variance()));
+            assert!(close(a.skewness(), whole.skewness()));
+            assert!(close(a.kurtosis(), whole.kurtosis()));
+        }
+    }
+
+    #[test]
+    fn offset_stream() {
+        // A million samples alternating around a huge offset, which an f64 Welford
+        // accumulator gets wrong in the fourth digit of the variance
+        let mut moments = Moments::new();
+        for i in 0..1_000_000 {
+            let x = if i % 2 == 0 { dd!(0.1) } else { dd!(-0.1) };
+            moments.push(dd!(1e12) + x);
+        }
+        assert!((moments.mean() - dd!(1e12)).abs() < dd!(1e-15));
+        assert!((moments.population_variance() - dd!(0.01)).abs() < dd!(1e-20));
+        assert!(moments.skewness().abs() < dd!(1e-15));
+        assert!((moments.kurtosis() - dd!(-2)).abs() < dd!(1e-15));
+    }
+
+    #[test]
+    fn weighted() {
+        let xs = data();
+        let ones = vec![qd!(1); xs.len()];
+        let moments: Moments<Quad> = xs.iter().collect();
+        let close = |a: Quad, b: Quad| (a - b).abs() <= b.abs() * qd!(1e-55);
+        assert!(close(weighted_mean(&xs, &ones), moments.mean()));
+        assert!(close(
+            weighted_variance(&xs, &ones),
+            moments.population_variance()
+        ));
+
+        // Integer weights are the same as repeating the values
+        let ws: Vec<Quad> = (0..xs.len()).map(|i| Quad::from((i % 3) as f64)).collect();
+        let repeated: Moments<Quad> = xs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, x)| std::iter::repeat_n(x, i % 3))
+            .collect();
+        assert!(close(weighted_mean(&xs, &ws), repeated.mean()));
+        assert!(close(
+            weighted_variance(&xs, &ws),
+            repeated.population_variance()
+        ));
+
+        assert!(weighted_mean::<Double>(&[], &[]).is_nan());
+        assert!(weighted_variance(&[dd!(1)], &[dd!(0)]).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn weighted_mismatch() {
+        weighted_mean(&[dd!(1), dd!(2)], &[dd!(1)]);
+    }
+
+    #[test]
+    fn covariances() {
+        let xs = data();
+        let moments: Moments<Quad> = xs.iter().collect();
+        let diff = (covariance(&xs, &xs) - moments.variance()).abs();
+        assert!(diff <= moments.variance() * qd!(1e-55));
+
+        let ys: Vec<Quad> = xs.iter().map(|&x| qd!(3) - x.mul_pwr2(2.0)).collect();
+        let diff = (covariance(&xs, &ys) + moments.variance().mul_pwr2(2.0)).abs();
+        assert!(diff <= moments.variance() * qd!(1e-54));
+
+        assert!(covariance(&[dd!(1)], &[dd!(2)]).is_nan());
+    }
+
+    #[test]
+    fn quantiles() {
+        let xs: Vec<Double> = (0..101).rev().map(|i| Double::from(i as f64)).collect();
+        assert_eq!(median(&xs), dd!(50));
+        assert_eq!(quantile(&xs, dd!(0.9)), dd!(90));
+        assert_eq!(quantile(&xs, dd!(0)), dd!(0));
+        assert_eq!(quantile(&xs, dd!(1)), dd!(100));
+        assert!((quantile(&xs, dd!(0.123)) - dd!(12.3)).abs() < dd!(1e-30));
+
+        // Distinct elements closer together than an f64 can distinguish
+        let tiny = Double::new(1.0, 1e-20);
+        let ys = vec![tiny, dd!(1), tiny + tiny.ulp()];
+        assert_eq!(median(&ys), tiny);
+        assert_eq!(median(&[qd!(7)]), qd!(7));
+
+        assert!(median::<Quad>(&[]).is_nan());
+        assert!(median(&[dd!(1), Double::NAN]).is_nan());
+        assert!(quantile(&xs, dd!(1.5)).is_nan());
+        assert!(quantile(&xs, Double::NAN).is_nan());
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Assertions for testing code that computes with [`Double`]s and [`Quad`]s.
+//!
+//! Extended-precision results are rarely exact, so tests of numerical code usually have to
+//! check that a result is *close enough* to the expected value instead of equal to it. This
+//! module provides the same kinds of checks that this crate's own tests use, as macros for
+//! downstream tests:
+//!
+//! * [`assert_close!`] checks that two numbers agree to the full precision of their type,
+//!   give or take the last digit or so.
+//! * [`assert_precision!`] checks that two numbers agree to a given number of significant
+//!   decimal digits.
+//! * [`assert_ulps!`] checks that two numbers are within a given number of [ULPs] of each
+//!   other.
+//!
+//! All of them work with either `Double`s or `Quad`s (both arguments have to be the same
+//! type), and all of them accept an optional custom message, just like `assert!`. When an
+//! assertion fails, the panic message shows both numbers in full, their difference, the
+//! tolerance it was checked against, and the components of each number.
+//!
+//! An expected `NaN` only matches an actual `NaN`, and an expected infinity only matches
+//! the same infinity.
+//!
+//! # Examples
+//! ```
+//! use qd::{assert_close, assert_precision, assert_ulps, dd, qd, Double, Quad};
+//!
+//! let third = Double::ONE / dd!(3);
+//! assert_close!(dd!("0.3333333333333333333333333333333333"), third);
+//! assert_precision!(qd!(22) / qd!(7), Quad::PI, 3, "pi is about 22/7");
+//! assert_ulps!(third, dd!(1) - dd!(2) / dd!(3), 2);
+//! ```
+//!
+//! [`Double`]: crate::Double
+//! [`Quad`]: crate::Quad
+//! [`assert_close!`]: crate::assert_close
+//! [`assert_precision!`]: crate::assert_precision
+//! [`assert_ulps!`]: crate::assert_ulps
+//! [ULPs]: crate::Double::precision_ulp
+
+use crate::precision::MultiPrecision;
+use std::fmt::{Arguments, LowerExp};
+
+/// Asserts that two `Double`s or `Quad`s are equal to the full precision of their type.
+///
+/// This is the same as [`assert_precision!`] with the number of digits set to the type's
+/// `DIGITS` (31 for `Double` and 62 for `Quad`), so the numbers may differ by a unit or so
+/// in their last decimal digit. A custom message can follow the numbers, with the same
+/// arguments as `format!`.
+///
+/// # Panics
+/// Panics if the numbers differ by more than that, with a message that shows both numbers
+/// and their difference.
+///
+/// # Examples
+/// ```
+/// # use qd::{assert_close, dd, qd, Quad};
+/// assert_close!(dd!(2).sqrt() * dd!(2).sqrt(), dd!(2));
+/// assert_close!(Quad::E.ln(), qd!(1), "ln e should be {}", 1);
+/// ```
+///
+/// ```should_panic
+/// # use qd::{assert_close, dd};
+/// assert_close!(dd!(0.1), dd!(0.1) + dd!(1e-25));
+/// ```
+///
+/// [`assert_precision!`]: crate::assert_precision
+#[macro_export]
+macro_rules! assert_close {
+    ($expected:expr, $actual:expr $(,)?) => {
+        $crate::testing::__assert_digits($expected, $actual, None, None)
+    };
+    ($expected:expr, $actual:expr, $($arg:tt)+) => {
+        $crate::testing::__assert_digits(
+            $expected,
+            $actual,
+            None,
+            Some(format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Asserts that two `Double`s or `Quad`s agree to a number of significant decimal digits.
+///
+/// The assertion passes if the numbers differ by less than one unit in the last of those
+/// digits of the expected number (the first argument). For example, when checking three
+/// digits against an expected 3.14, the actual number has to be within 0.01 of it. An
+/// expected zero is treated as though its leading digit is in the ones place. A custom
+/// message can follow the number of digits, with the same arguments as `format!`.
+///
+/// # Panics
+/// Panics if the numbers don't agree to that many digits, with a message that shows both
+/// numbers and their difference.
+///
+/// # Examples
+/// ```
+/// # use qd::{assert_precision, dd, qd, Quad};
+/// assert_precision!(dd!(3.14), dd!(3.149), 3);
+/// assert_precision!(qd!(355) / qd!(113), Quad::PI, 7, "Zu Chongzhi's approximation");
+/// ```
+///
+/// ```should_panic
+/// # use qd::{assert_precision, dd};
+/// assert_precision!(dd!(3.14), dd!(3.15), 3);
+/// ```
+#[macro_export]
+macro_rules! assert_precision {
+    ($expected:expr, $actual:expr, $digits:expr $(,)?) => {
+        $crate::testing::__assert_digits($expected, $actual, Some($digits), None)
+    };
+    ($expected:expr, $actual:expr, $digits:expr, $($arg:tt)+) => {
+        $crate::testing::__assert_digits(
+            $expected,
+            $actual,
+            Some($digits),
+            Some(format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Asserts that two `Double`s or `Quad`s are within a number of ULPs of each other.
+///
+/// The ULP (unit in the last place) is that of the expected number (the first argument),
+/// as returned by its `precision_ulp` method, and the assertion passes if the numbers
+/// differ by no more than that many of them. Zero ULPs means the numbers have to be equal.
+/// A custom message can follow the number of ULPs, with the same arguments as `format!`.
+///
+/// # Panics
+/// Panics if the numbers are further apart than that, with a message that shows both
+/// numbers and their difference.
+///
+/// # Examples
+/// ```
+/// # use qd::{assert_ulps, dd, Double};
+/// let x = Double::PI;
+/// assert_ulps!(x, x + x.precision_ulp() * dd!(2), 2);
+/// assert_ulps!(x, x, 0, "a number is always 0 ULPs from itself");
+/// ```
+///
+/// ```should_panic
+/// # use qd::{assert_ulps, dd, Double};
+/// let x = Double::PI;
+/// assert_ulps!(x, x + x.precision_ulp() * dd!(2), 1);
+/// ```
+#[macro_export]
+macro_rules! assert_ulps {
+    ($expected:expr, $actual:expr, $ulps:expr $(,)?) => {
+        $crate::testing::__assert_ulps($expected, $actual, $ulps, None)
+    };
+    ($expected:expr, $actual:expr, $ulps:expr, $($arg:tt)+) => {
+        $crate::testing::__assert_ulps(
+            $expected,
+            $actual,
+            $ulps,
+            Some(format_args!($($arg)+)),
+        )
+    };
+}
+
+// The implementations of the macros above. They're functions rather than macro bodies so
+// that the arguments are type-checked as `Double`s or `Quad`s and evaluated only once, and
+// `#[track_caller]` makes a failure point at the assertion instead of here.
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_digits<T>(expected: T, actual: T, digits: Option<u32>, msg: Option<Arguments>)
+where
+    T: MultiPrecision + LowerExp,
+{
+    let digits = digits.unwrap_or(T::DIGITS);
+    let lead: f64 = expected.into();
+    let mag = if lead == 0.0 || !lead.is_finite() {
+        0
+    } else {
+        lead.abs().log10().floor() as i64
+    };
+    let tolerance = T::from(10.0).powi(mag + 1 - digits as i64);
+    let diff = (expected - actual).abs();
+    if !matches(expected, actual, diff < tolerance) {
+        let what = format!("numbers don't agree to {} digits", digits);
+        fail(&what, expected, actual, diff, tolerance, msg);
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_ulps<T>(expected: T, actual: T, ulps: u32, msg: Option<Arguments>)
+where
+    T: MultiPrecision + LowerExp,
+{
+    let tolerance = expected.precision_ulp() * T::from(ulps as f64);
+    let diff = (expected - actual).abs();
+    if !matches(expected, actual, diff <= tolerance) {
+        let what = format!("numbers aren't within {} ULPs", ulps);
+        fail(&what, expected, actual, diff, tolerance, msg);
+    }
+}
+
+// Decides whether an assertion passes. `NaN`s and infinities have to match exactly, since
+// their differences are meaningless; everything else passes if it's `close`.
+fn matches<T: MultiPrecision>(expected: T, actual: T, close: bool) -> bool {
+    if expected.is_nan() {
+        actual.is_nan()
+    } else if expected.is_infinite() {
+        expected == actual
+    } else {
+        close
+    }
+}
+
+#[track_caller]
+fn fail<T>(what: &str, expected: T, actual: T, diff: T, tolerance: T, msg: Option<Arguments>) -> !
+where
+    T: MultiPrecision + LowerExp,
+{
+    let custom = match msg {
+        Some(args) => format!(": {}", args),
+        None => String::new(),
+    };
+    panic!(
+        concat!(
+            "assertion failed: {}{}\n",
+            "\n",
+            "Expected:  {}\n",
+            "Actual:    {}\n",
+            "\n",
+            "Delta:     {:e}\n",
+            "Tolerance: {:e}\n",
+            "\n",
+            "Components:\n",
+            "  Expected: {:?}\n",
+            "  Actual:   {:?}\n",
+        ),
+        what, custom, expected, actual, diff, tolerance, expected, actual
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dd, qd, Double, Quad};
+
+    #[test]
+    fn close() {
+        assert_close!(
+            Double::ONE / dd!(3),
+            dd!("0.333333333333333333333333333333333")
+        );
+        assert_close!(Quad::ONE / qd!(7) * qd!(7), Quad::ONE);
+        assert_close!(Double::ZERO, dd!(1e-35));
+        assert_close!(Double::NAN, Double::NAN);
+        assert_close!(
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY,
+            "with a {}",
+            "message"
+        );
+    }
+
+    #[test]
+    fn precision() {
+        assert_precision!(dd!(3.14), Double::PI, 3);
+        assert_precision!(dd!(-1234.5), dd!(-1234.46), 5);
+        assert_precision!(qd!(1e-100), qd!(1.01e-100), 2);
+        assert_precision!(Quad::ZERO, qd!(0.01), 2);
+    }
+
+    #[test]
+    fn ulps() {
+        let x = Quad::E;
+        assert_ulps!(x, x, 0);
+        assert_ulps!(x, x.next_down(), 1);
+        assert_ulps!(x.next_up().next_up().next_up(), x, 3,);
+        assert_ulps!(Double::INFINITY, Double::INFINITY, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "numbers don't agree to 31 digits: custom 1")]
+    fn close_fail() {
+        assert_close!(Double::ONE, Double::ONE + dd!(1e-29), "custom {}", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "numbers don't agree to 3 digits")]
+    fn precision_fail() {
+        assert_precision!(qd!(3.14), qd!(3.15), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "numbers aren't within 2 ULPs")]
+    fn ulps_fail() {
+        let x = Double::PI;
+        assert_ulps!(x, x + x.precision_ulp() * dd!(3), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_fail() {
+        assert_close!(Quad::NAN, Quad::ONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_actual_fail() {
+        assert_ulps!(Double::ONE, Double::NAN, 1000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn infinity_fail() {
+        assert_close!(Double::INFINITY, Double::MAX);
+    }
+}